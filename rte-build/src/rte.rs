@@ -41,6 +41,14 @@ lazy_static! {
         }
         return libs;
     };
+    pub static ref RTE_VERSION: String = {
+        let libdpdk = pkg_config::Config::new()
+            .cargo_metadata(false)
+            .env_metadata(true)
+            .probe("libdpdk")
+            .expect("RTE_VERSION - Failed to get information of libdpdk.pc");
+        libdpdk.version
+    };
     pub static ref RTE_PMD_LIBS: std::vec::Vec<std::string::String> = {
         let libdpdk = pkg_config::Config::new()
             .cargo_metadata(true)
@@ -74,9 +74,57 @@ fn gen_rte_binding<S: AsRef<str>>(_rte_sdk_dir: impl Iterator<Item = S>, dest_pa
     fs::copy("src/raw.rs", dest_path).expect("copy binding file");
 }
 
+/// The DPDK release of the pregenerated binding file.
+const BUNDLED_RTE_VERSION: &str = "20.11";
+
+/// Return the DPDK LTS release selected by the `dpdk-*` features.
+fn target_rte_version() -> &'static str {
+    let versions = [
+        (cfg!(feature = "dpdk-19_11"), "19.11"),
+        (cfg!(feature = "dpdk-20_11"), "20.11"),
+        (cfg!(feature = "dpdk-21_11"), "21.11"),
+        (cfg!(feature = "dpdk-22_11"), "22.11"),
+    ]
+    .iter()
+    .filter(|&&(enabled, _)| enabled)
+    .map(|&(_, version)| version)
+    .collect::<Vec<_>>();
+
+    match versions.as_slice() {
+        [] => BUNDLED_RTE_VERSION,
+        [version] => *version,
+        _ => panic!(
+            "only one of the `dpdk-*` features can be enabled, got {}",
+            versions.join(", ")
+        ),
+    }
+}
+
+fn check_rte_version() {
+    let target = target_rte_version();
+
+    info!("targeting DPDK {}, found DPDK {}", target, *RTE_VERSION);
+
+    if target != BUNDLED_RTE_VERSION && !cfg!(feature = "gen") {
+        panic!(
+            "the pregenerated binding is based on DPDK {}, enable the `gen` feature to build against DPDK {}",
+            BUNDLED_RTE_VERSION, target
+        );
+    }
+
+    if !RTE_VERSION.starts_with(target) {
+        println!(
+            "cargo:warning=targeting DPDK {}, but found DPDK {} installed",
+            target, *RTE_VERSION
+        );
+    }
+}
+
 fn main() {
     pretty_env_logger::init();
 
+    check_rte_version();
+
     // let rte_sdk_dir = RTE_SDK.join(RTE_TARGET.as_str());
 
     // info!("using DPDK @ {:?}", rte_sdk_dir);
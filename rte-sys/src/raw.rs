@@ -631,6 +631,145 @@ pub const RTE_MEMZONE_NAMESIZE: u32 = 32;
 pub const RTE_TAILQ_RING_NAME: &'static [u8; 9usize] = b"RTE_RING\0";
 pub const RTE_RING_MZ_PREFIX: &'static [u8; 4usize] = b"RG_\0";
 pub const RTE_RING_SZ_MASK: u32 = 2147483647;
+pub const RTE_QSBR_THRID_INVALID: u32 = 4294967295;
+pub const RTE_RCU_QSBR_DQ_NAMESIZE: u32 = 32;
+pub const RTE_RCU_QSBR_DQ_MT_UNSAFE: u32 = 1;
+pub const RTE_HASH_RCU_DQ_RECLAIM_MAX: u32 = 16;
+pub const RTE_TAILQ_STACK_NAME: &'static [u8; 10usize] = b"RTE_STACK\0";
+pub const RTE_STACK_MZ_PREFIX: &'static [u8; 5usize] = b"STK_\0";
+pub const RTE_STACK_F_LF: u32 = 1;
+pub const RTE_RED_SCALING: u32 = 10;
+pub const RTE_RED_S: u32 = 1024;
+pub const RTE_RED_MAX_TH_MAX: u32 = 1023;
+pub const RTE_RED_WQ_LOG2_MIN: u32 = 1;
+pub const RTE_RED_WQ_LOG2_MAX: u32 = 12;
+pub const RTE_RED_MAXP_INV_MIN: u32 = 1;
+pub const RTE_RED_MAXP_INV_MAX: u32 = 255;
+pub const RTE_RED_2POW16: u32 = 65536;
+pub const RTE_RED_INT16_NBITS: u32 = 15;
+pub const RTE_RED_WQ_LOG2_NUM: u32 = 12;
+pub const RTE_HASH_ENTRIES_MAX: u32 = 1073741824;
+pub const RTE_HASH_NAMESIZE: u32 = 32;
+pub const RTE_HASH_LOOKUP_BULK_MAX: u32 = 64;
+pub const RTE_HASH_LOOKUP_MULTI_MAX: u32 = 64;
+pub const RTE_HASH_EXTRA_FLAGS_TRANS_MEM_SUPPORT: u32 = 1;
+pub const RTE_HASH_EXTRA_FLAGS_MULTI_WRITER_ADD: u32 = 2;
+pub const RTE_HASH_EXTRA_FLAGS_RW_CONCURRENCY: u32 = 4;
+pub const RTE_HASH_EXTRA_FLAGS_EXT_TABLE: u32 = 8;
+pub const RTE_HASH_EXTRA_FLAGS_NO_FREE_ON_DEL: u32 = 16;
+pub const RTE_HASH_EXTRA_FLAGS_RW_CONCURRENCY_LF: u32 = 32;
+pub const RTE_FIB_MAXDEPTH: u32 = 32;
+pub const RTE_LPM_NAMESIZE: u32 = 32;
+pub const RTE_LPM_MAX_DEPTH: u32 = 32;
+pub const RTE_LPM_LOOKUP_SUCCESS: u32 = 16777216;
+pub const RTE_LPM6_MAX_DEPTH: u32 = 128;
+pub const RTE_LPM6_IPV6_ADDR_SIZE: u32 = 16;
+pub const RTE_LPM6_NAMESIZE: u32 = 32;
+pub const RTE_FIB6_IPV6_ADDR_SIZE: u32 = 16;
+pub const RTE_FIB6_MAXDEPTH: u32 = 128;
+pub const RTE_RIB6_IPV6_ADDR_SIZE: u32 = 16;
+pub const RTE_ACL_MAX_DIM: u32 = 64;
+pub const RTE_ACL_MAX_FIELDS: u32 = 64;
+pub const RTE_ACL_NAMESIZE: u32 = 32;
+pub const RTE_ACL_MAX_CATEGORIES: u32 = 16;
+pub const RTE_ACL_RESULTS_MULTIPLIER: u32 = 4;
+pub const RTE_CRYPTODEV_NAME_MAX_LEN: u32 = 64;
+pub const RTE_CRYPTODEV_FF_SYMMETRIC_CRYPTO: u32 = 1;
+pub const RTE_CRYPTODEV_FF_ASYMMETRIC_CRYPTO: u32 = 2;
+pub const RTE_CRYPTODEV_FF_SYM_OPERATION_CHAINING: u32 = 4;
+pub const RTE_CRYPTODEV_FF_CPU_SSE: u32 = 8;
+pub const RTE_CRYPTODEV_FF_CPU_AVX: u32 = 16;
+pub const RTE_CRYPTODEV_FF_CPU_AVX2: u32 = 32;
+pub const RTE_CRYPTODEV_FF_CPU_AESNI: u32 = 64;
+pub const RTE_CRYPTODEV_FF_HW_ACCELERATED: u32 = 128;
+pub const RTE_CRYPTODEV_FF_CPU_AVX512: u32 = 256;
+pub const RTE_CRYPTODEV_FF_IN_PLACE_SGL: u32 = 512;
+pub const RTE_CRYPTODEV_FF_OOP_SGL_IN_SGL_OUT: u32 = 1024;
+pub const RTE_CRYPTODEV_FF_OOP_SGL_IN_LB_OUT: u32 = 2048;
+pub const RTE_CRYPTODEV_FF_OOP_LB_IN_SGL_OUT: u32 = 4096;
+pub const RTE_CRYPTODEV_FF_OOP_LB_IN_LB_OUT: u32 = 8192;
+pub const RTE_CRYPTODEV_FF_CPU_NEON: u32 = 16384;
+pub const RTE_CRYPTODEV_FF_CPU_ARM_CE: u32 = 32768;
+pub const RTE_CRYPTODEV_FF_SECURITY: u32 = 65536;
+pub const RTE_CRYPTODEV_FF_RSA_PRIV_OP_KEY_EXP: u32 = 131072;
+pub const RTE_CRYPTODEV_FF_RSA_PRIV_OP_KEY_QT: u32 = 262144;
+pub const RTE_CRYPTODEV_FF_DIGEST_ENCRYPTED: u32 = 524288;
+pub const RTE_CRYPTODEV_FF_ASYM_SESSIONLESS: u32 = 1048576;
+pub const RTE_CRYPTODEV_FF_SYM_CPU_CRYPTO: u32 = 2097152;
+pub const RTE_CRYPTODEV_FF_SYM_SESSIONLESS: u32 = 4194304;
+pub const RTE_CRYPTODEV_FF_NON_BYTE_ALIGNED_DATA: u32 = 8388608;
+pub const RTE_CRYPTODEV_FF_SYM_RAW_DP: u32 = 16777216;
+pub const RTE_EVENT_DEV_CAP_QUEUE_QOS: u32 = 1;
+pub const RTE_EVENT_DEV_CAP_EVENT_QOS: u32 = 2;
+pub const RTE_EVENT_DEV_CAP_DISTRIBUTED_SCHED: u32 = 4;
+pub const RTE_EVENT_DEV_CAP_QUEUE_ALL_TYPES: u32 = 8;
+pub const RTE_EVENT_DEV_CAP_BURST_MODE: u32 = 16;
+pub const RTE_EVENT_DEV_CAP_IMPLICIT_RELEASE_DISABLE: u32 = 32;
+pub const RTE_EVENT_DEV_CAP_NONSEQ_MODE: u32 = 64;
+pub const RTE_EVENT_DEV_CAP_RUNTIME_PORT_LINK: u32 = 128;
+pub const RTE_EVENT_DEV_CAP_MULTIPLE_QUEUE_PORT: u32 = 256;
+pub const RTE_EVENT_DEV_PRIORITY_HIGHEST: u32 = 0;
+pub const RTE_EVENT_DEV_PRIORITY_NORMAL: u32 = 128;
+pub const RTE_EVENT_DEV_PRIORITY_LOWEST: u32 = 255;
+pub const RTE_EVENT_DEV_CFG_PER_DEQUEUE_TIMEOUT: u32 = 1;
+pub const RTE_EVENT_QUEUE_CFG_ALL_TYPES: u32 = 1;
+pub const RTE_EVENT_QUEUE_CFG_SINGLE_LINK: u32 = 2;
+pub const RTE_EVENT_PORT_CFG_DISABLE_IMPL_REL: u32 = 1;
+pub const RTE_EVENT_PORT_CFG_SINGLE_LINK: u32 = 2;
+pub const RTE_SCHED_TYPE_ORDERED: u32 = 0;
+pub const RTE_SCHED_TYPE_ATOMIC: u32 = 1;
+pub const RTE_SCHED_TYPE_PARALLEL: u32 = 2;
+pub const RTE_EVENT_TYPE_ETHDEV: u32 = 0;
+pub const RTE_EVENT_TYPE_CRYPTODEV: u32 = 1;
+pub const RTE_EVENT_TYPE_TIMER: u32 = 2;
+pub const RTE_EVENT_TYPE_CPU: u32 = 3;
+pub const RTE_EVENT_TYPE_ETH_RX_ADAPTER: u32 = 4;
+pub const RTE_EVENT_TYPE_MAX: u32 = 16;
+pub const RTE_EVENT_OP_NEW: u32 = 0;
+pub const RTE_EVENT_OP_FORWARD: u32 = 1;
+pub const RTE_EVENT_OP_RELEASE: u32 = 2;
+pub const RTE_EVENT_ETH_RX_ADAPTER_CAP_INTERNAL_PORT: u32 = 1;
+pub const RTE_EVENT_ETH_RX_ADAPTER_CAP_MULTI_EVENTQ: u32 = 2;
+pub const RTE_EVENT_ETH_RX_ADAPTER_CAP_OVERRIDE_FLOW_ID: u32 = 4;
+pub const RTE_EVENT_ETH_RX_ADAPTER_QUEUE_FLOW_ID_VALID: u32 = 1;
+pub const RTE_GRO_MAX_BURST_ITEM_NUM: u32 = 128;
+pub const RTE_GRO_TYPE_MAX_NUM: u32 = 64;
+pub const RTE_GRO_TYPE_SUPPORT_NUM: u32 = 4;
+pub const RTE_GRO_TCP_IPV4_INDEX: u32 = 0;
+pub const RTE_GRO_TCP_IPV4: u32 = 1;
+pub const RTE_GRO_IPV4_VXLAN_TCP_IPV4_INDEX: u32 = 1;
+pub const RTE_GRO_IPV4_VXLAN_TCP_IPV4: u32 = 2;
+pub const RTE_GRO_UDP_IPV4_INDEX: u32 = 2;
+pub const RTE_GRO_UDP_IPV4: u32 = 4;
+pub const RTE_GRO_IPV4_VXLAN_UDP_IPV4_INDEX: u32 = 3;
+pub const RTE_GRO_IPV4_VXLAN_UDP_IPV4: u32 = 8;
+pub const RTE_GSO_SEG_SIZE_MIN: u32 = 256;
+pub const RTE_GSO_FLAG_IPID_FIXED: u32 = 1;
+pub const RTE_SCHED_QUEUES_PER_PIPE: u32 = 16;
+pub const RTE_SCHED_BE_QUEUES_PER_PIPE: u32 = 4;
+pub const RTE_SCHED_TRAFFIC_CLASSES_PER_PIPE: u32 = 13;
+pub const RTE_SCHED_TRAFFIC_CLASS_BE: u32 = 12;
+pub const RTE_SCHED_FRAME_OVERHEAD_DEFAULT: u32 = 24;
+pub const RTE_TM_ETH_FRAMING_OVERHEAD: u32 = 20;
+pub const RTE_TM_ETH_FRAMING_OVERHEAD_FCS: u32 = 24;
+pub const RTE_TM_WRED_PROFILE_ID_NONE: u32 = 4294967295;
+pub const RTE_TM_SHAPER_PROFILE_ID_NONE: u32 = 4294967295;
+pub const RTE_TM_NODE_ID_NULL: u32 = 4294967295;
+pub const RTE_TM_NODE_LEVEL_ID_ANY: u32 = 4294967295;
+pub const RTE_METRICS_MAX_METRICS: u32 = 256;
+pub const RTE_METRICS_MAX_NAME_LEN: u32 = 64;
+pub const RTE_METRICS_GLOBAL: i32 = -1;
+pub const RTE_PDUMP_FLAG_RX: u32 = 1;
+pub const RTE_PDUMP_FLAG_TX: u32 = 2;
+pub const RTE_PDUMP_FLAG_RXTX: u32 = 3;
+pub const RTE_KVARGS_MAX: u32 = 32;
+pub const RTE_KVARGS_PAIRS_DELIM: &'static [u8; 2usize] = b",\0";
+pub const RTE_KVARGS_KV_DELIM: &'static [u8; 2usize] = b"=\0";
+pub const CFG_NAME_LEN: u32 = 64;
+pub const CFG_VALUE_LEN: u32 = 256;
+pub const CFG_DEFAULT_COMMENT_CHARACTER: u8 = 59u8;
+pub const CFG_FLAG_GLOBAL_SECTION: u32 = 1;
+pub const CFG_FLAG_EMPTY_VALUES: u32 = 2;
 pub const RTE_MEMPOOL_HEADER_COOKIE1: i64 = -4982197544707871147;
 pub const RTE_MEMPOOL_HEADER_COOKIE2: i64 = -941548164385788331;
 pub const RTE_MEMPOOL_TRAILER_COOKIE: i64 = -5921418378119291987;
@@ -2057,6 +2196,42 @@ extern "C" {
     #[doc = " Dump the stack of the calling core to the console."]
     pub fn rte_dump_stack();
 }
+extern "C" {
+    #[doc = " Dump out memory in a special hex dump format."]
+    #[doc = ""]
+    #[doc = " @param f"]
+    #[doc = "   A pointer to a file for output"]
+    #[doc = " @param title"]
+    #[doc = "   If not NULL this string is printed as a header to the output."]
+    #[doc = " @param buf"]
+    #[doc = "   This is the buffer address to print out."]
+    #[doc = " @param len"]
+    #[doc = "   The number of bytes to dump out"]
+    pub fn rte_hexdump(
+        f: *mut FILE,
+        title: *const ::std::os::raw::c_char,
+        buf: *const ::std::os::raw::c_void,
+        len: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    #[doc = " Dump out memory in a hex format with colons between bytes."]
+    #[doc = ""]
+    #[doc = " @param f"]
+    #[doc = "   A pointer to a file for output"]
+    #[doc = " @param title"]
+    #[doc = "   If not NULL this string is printed as a header to the output."]
+    #[doc = " @param buf"]
+    #[doc = "   This is the buffer address to print out."]
+    #[doc = " @param len"]
+    #[doc = "   The number of bytes to dump out"]
+    pub fn rte_memdump(
+        f: *mut FILE,
+        title: *const ::std::os::raw::c_char,
+        buf: *const ::std::os::raw::c_void,
+        len: ::std::os::raw::c_uint,
+    );
+}
 #[doc = " The rte_rwlock_t type."]
 #[doc = ""]
 #[doc = " cnt is -1 when write lock is held, and > 0 when read locks are held."]
@@ -4432,6 +4607,176 @@ fn bindgen_test_layout_rte_spinlock_recursive_t() {
         )
     );
 }
+#[doc = " The rte_ticketlock_t type."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_ticketlock_t {
+    pub tickets: u32,
+    pub s: rte_ticketlock_t__bindgen_ty_1,
+    _bindgen_union_align: u32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_ticketlock_t__bindgen_ty_1 {
+    pub current: u16,
+    pub next: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_ticketlock_t__bindgen_ty_1() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_ticketlock_t__bindgen_ty_1>(),
+        4usize,
+        concat!("Size of: ", stringify!(rte_ticketlock_t__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_ticketlock_t__bindgen_ty_1>(),
+        2usize,
+        concat!("Alignment of ", stringify!(rte_ticketlock_t__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_t__bindgen_ty_1>())).current as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_ticketlock_t__bindgen_ty_1),
+            "::",
+            stringify!(current)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_t__bindgen_ty_1>())).next as *const _ as usize },
+        2usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_ticketlock_t__bindgen_ty_1),
+            "::",
+            stringify!(next)
+        )
+    );
+}
+#[test]
+fn bindgen_test_layout_rte_ticketlock_t() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_ticketlock_t>(),
+        4usize,
+        concat!("Size of: ", stringify!(rte_ticketlock_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_ticketlock_t>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_ticketlock_t))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_t>())).tickets as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(rte_ticketlock_t), "::", stringify!(tickets))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_t>())).s as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(rte_ticketlock_t), "::", stringify!(s))
+    );
+}
+impl Default for rte_ticketlock_t {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " The rte_ticketlock_recursive_t type."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_ticketlock_recursive_t {
+    #[doc = "< the actual ticketlock"]
+    pub tlock: rte_ticketlock_t,
+    #[doc = "< core id using lock, TICKET_LOCK_INVALID_ID for unused"]
+    pub user: ::std::os::raw::c_int,
+    #[doc = "< count of time this lock has been called"]
+    pub count: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_rte_ticketlock_recursive_t() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_ticketlock_recursive_t>(),
+        12usize,
+        concat!("Size of: ", stringify!(rte_ticketlock_recursive_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_ticketlock_recursive_t>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_ticketlock_recursive_t))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_recursive_t>())).tlock as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_ticketlock_recursive_t),
+            "::",
+            stringify!(tlock)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_recursive_t>())).user as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_ticketlock_recursive_t),
+            "::",
+            stringify!(user)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_ticketlock_recursive_t>())).count as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_ticketlock_recursive_t),
+            "::",
+            stringify!(count)
+        )
+    );
+}
+impl Default for rte_ticketlock_recursive_t {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " The rte_mcslock_t type."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mcslock {
+    pub next: *mut rte_mcslock,
+    pub locked: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_rte_mcslock() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mcslock>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_mcslock))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mcslock>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mcslock))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_mcslock>())).next as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(rte_mcslock), "::", stringify!(next))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_mcslock>())).locked as *const _ as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(rte_mcslock), "::", stringify!(locked))
+    );
+}
+impl Default for rte_mcslock {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub type rte_mcslock_t = rte_mcslock;
 pub mod rte_cpu_flag_t {
     #[doc = " Enumeration of all CPU features supported"]
     pub type Type = ::std::os::raw::c_uint;
@@ -36588,6 +36933,6721 @@ extern "C" {
 extern "C" {
     pub fn cmdline_stdin_exit(cl: *mut cmdline);
 }
+#[doc = " RTE Quiescent State variable structure."]
+#[doc = " This structure has two elements that vary in size based on the"]
+#[doc = " 'max_threads' parameter."]
+#[doc = " 1) Quiescent state counter array"]
+#[doc = " 2) Register thread ID array"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rcu_qsbr {
+    _unused: [u8; 0],
+}
+#[doc = " Call back function called to free the resources."]
+#[doc = ""]
+#[doc = " @param p"]
+#[doc = "   Pointer provided while creating the defer queue"]
+#[doc = " @param e"]
+#[doc = "   Pointer to the resource data stored on the defer queue"]
+#[doc = " @param n"]
+#[doc = "   Number of resources to free. Currently, this is set to 1."]
+pub type rte_rcu_qsbr_free_resource_t = ::std::option::Option<
+    unsafe extern "C" fn(p: *mut ::std::os::raw::c_void, e: *mut ::std::os::raw::c_void, n: ::std::os::raw::c_uint),
+>;
+#[doc = " Parameters used when creating the defer queue."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rcu_qsbr_dq_parameters {
+    #[doc = "< Name of the queue."]
+    pub name: *const ::std::os::raw::c_char,
+    #[doc = "< Flags to control API behaviors"]
+    pub flags: u32,
+    #[doc = "< Number of entries in queue. Typically, this will be"]
+    #[doc = " the same as the maximum number of entries supported in the"]
+    #[doc = " lock free data structure."]
+    #[doc = " Data structures with unbounded number of entries is not"]
+    #[doc = " supported currently."]
+    pub size: u32,
+    #[doc = "< Size (in bytes) of each element in the defer queue."]
+    #[doc = " This has to be multiple of 4B."]
+    pub esize: u32,
+    #[doc = "< The application can trigger the reclamation"]
+    #[doc = " automatically when the queue has more entries than"]
+    #[doc = " this limit."]
+    pub trigger_reclaim_limit: u32,
+    #[doc = "< When automatic reclamation is enabled, reclaim at the max"]
+    #[doc = " these many resources. This should contain a valid value, if"]
+    #[doc = " auto reclamation is on. Setting this to 'size' or greater will"]
+    #[doc = " reclaim all possible resources currently on the defer queue."]
+    pub max_reclaim_size: u32,
+    #[doc = "< Function to call to free the resource."]
+    pub free_fn: rte_rcu_qsbr_free_resource_t,
+    #[doc = "< Pointer passed to the free function. Typically, this is the"]
+    #[doc = " pointer to the data structure to which the resource to free"]
+    #[doc = " belongs. This can be NULL."]
+    pub p: *mut ::std::os::raw::c_void,
+    #[doc = "< RCU QSBR variable to use for this defer queue"]
+    pub v: *mut rte_rcu_qsbr,
+}
+#[test]
+fn bindgen_test_layout_rte_rcu_qsbr_dq_parameters() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_rcu_qsbr_dq_parameters>(),
+        48usize,
+        concat!("Size of: ", stringify!(rte_rcu_qsbr_dq_parameters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_rcu_qsbr_dq_parameters>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_rcu_qsbr_dq_parameters))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_rcu_qsbr_dq_parameters>())).free_fn as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_rcu_qsbr_dq_parameters),
+            "::",
+            stringify!(free_fn)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_rcu_qsbr_dq_parameters>())).v as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_rcu_qsbr_dq_parameters),
+            "::",
+            stringify!(v)
+        )
+    );
+}
+impl Default for rte_rcu_qsbr_dq_parameters {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rcu_qsbr_dq {
+    _unused: [u8; 0],
+}
+extern "C" {
+    #[doc = " Return the size of the memory occupied by a Quiescent State variable."]
+    #[doc = ""]
+    #[doc = " @param max_threads"]
+    #[doc = "   Maximum number of threads reporting quiescent state on this variable."]
+    #[doc = " @return"]
+    #[doc = "   On success - size of memory in bytes required for this QS variable."]
+    #[doc = "   On error - 1 with error code set in rte_errno."]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EINVAL - max_threads is 0"]
+    pub fn rte_rcu_qsbr_get_memsize(max_threads: u32) -> usize;
+}
+extern "C" {
+    #[doc = " Initialize a Quiescent State (QS) variable."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param max_threads"]
+    #[doc = "   Maximum number of threads reporting quiescent state on this variable."]
+    #[doc = "   This should be the same value as passed to rte_rcu_qsbr_get_memsize."]
+    #[doc = " @return"]
+    #[doc = "   On success - 0"]
+    #[doc = "   On error - 1 with error code set in rte_errno."]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EINVAL - max_threads is 0 or 'v' is NULL."]
+    pub fn rte_rcu_qsbr_init(v: *mut rte_rcu_qsbr, max_threads: u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Register a reader thread to report its quiescent state"]
+    #[doc = " on a QS variable."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread"]
+    #[doc = " safe."]
+    #[doc = " Any reader thread that wants to report its quiescent state must"]
+    #[doc = " call this API. This can be called during initialization or as part"]
+    #[doc = " of the packet processing loop."]
+    #[doc = ""]
+    #[doc = " Note that rte_rcu_qsbr_thread_online must be called before the"]
+    #[doc = " thread updates its quiescent state using rte_rcu_qsbr_quiescent."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Reader thread with this thread ID will report its quiescent state on"]
+    #[doc = "   the QS variable. thread_id is a value between 0 and (max_threads - 1)."]
+    #[doc = "   'max_threads' is the parameter passed in 'rte_rcu_qsbr_init' API."]
+    pub fn rte_rcu_qsbr_thread_register(v: *mut rte_rcu_qsbr, thread_id: ::std::os::raw::c_uint)
+        -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Remove a reader thread, from the list of threads reporting their"]
+    #[doc = " quiescent state on a QS variable."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread safe."]
+    #[doc = " This API can be called from the reader threads during shutdown."]
+    #[doc = " Ongoing quiescent state queries will stop waiting for the status from this"]
+    #[doc = " unregistered reader thread."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Reader thread with this thread ID will stop reporting its quiescent"]
+    #[doc = "   state on the QS variable."]
+    pub fn rte_rcu_qsbr_thread_unregister(
+        v: *mut rte_rcu_qsbr,
+        thread_id: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Wait till the reader threads have entered quiescent state."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread safe."]
+    #[doc = " This API can be thought of as a wrapper around rte_rcu_qsbr_start and"]
+    #[doc = " rte_rcu_qsbr_check APIs."]
+    #[doc = ""]
+    #[doc = " If this API is called from multiple threads, only one of"]
+    #[doc = " those threads can be reporting the quiescent state status on a"]
+    #[doc = " given QS variable."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Thread ID of the caller if it is registered to report quiescent state"]
+    #[doc = "   on this QS variable (i.e. the calling thread is also acting as"]
+    #[doc = "   a reader). For other cases, use RTE_QSBR_THRID_INVALID."]
+    pub fn rte_rcu_qsbr_synchronize(v: *mut rte_rcu_qsbr, thread_id: ::std::os::raw::c_uint);
+}
+extern "C" {
+    #[doc = " Dump the details of a single QS variables to a file."]
+    #[doc = ""]
+    #[doc = " It is NOT multi-thread safe."]
+    #[doc = ""]
+    #[doc = " @param f"]
+    #[doc = "   A pointer to a file for output"]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @return"]
+    #[doc = "   On success - 0"]
+    #[doc = "   On error - 1 with error code set in rte_errno."]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EINVAL - NULL parameters are passed"]
+    pub fn rte_rcu_qsbr_dump(f: *mut FILE, v: *mut rte_rcu_qsbr) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Create a queue used to store the data structure elements that can"]
+    #[doc = " be freed later. This queue is referred to as 'defer queue'."]
+    #[doc = ""]
+    #[doc = " @param params"]
+    #[doc = "   Parameters to create a defer queue."]
+    #[doc = " @return"]
+    #[doc = "   On success - Valid pointer to defer queue"]
+    #[doc = "   On error - NULL"]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EINVAL - NULL parameters are passed"]
+    #[doc = "   - ENOMEM - Not enough memory"]
+    pub fn rte_rcu_qsbr_dq_create(params: *const rte_rcu_qsbr_dq_parameters) -> *mut rte_rcu_qsbr_dq;
+}
+extern "C" {
+    #[doc = " Enqueue one resource to the defer queue and start the grace period."]
+    #[doc = " The resource will be freed later after at least one grace period"]
+    #[doc = " is over."]
+    #[doc = ""]
+    #[doc = " If the defer queue is full, it will attempt to reclaim resources."]
+    #[doc = " It will also reclaim resources at regular intervals to avoid"]
+    #[doc = " the defer queue from growing too big."]
+    #[doc = ""]
+    #[doc = " @param dq"]
+    #[doc = "   Defer queue to allocate an entry from."]
+    #[doc = " @param e"]
+    #[doc = "   Pointer to resource data to copy to the defer queue. The size of"]
+    #[doc = "   the data to copy is equal to the element size provided when the"]
+    #[doc = "   defer queue was created."]
+    #[doc = " @return"]
+    #[doc = "   On success - 0"]
+    #[doc = "   On error - 1 with rte_errno set to"]
+    #[doc = "   - EINVAL - NULL parameters are passed"]
+    #[doc = "   - ENOSPC - Defer queue is full. This condition can not happen"]
+    #[doc = "\t\tif the defer queue size is equal (or larger) than the"]
+    #[doc = "\t\tnumber of elements in the data structure."]
+    pub fn rte_rcu_qsbr_dq_enqueue(dq: *mut rte_rcu_qsbr_dq, e: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Free resources from the defer queue."]
+    #[doc = ""]
+    #[doc = " @param dq"]
+    #[doc = "   Defer queue to free an entry from."]
+    #[doc = " @param n"]
+    #[doc = "   Maximum number of resources to free."]
+    #[doc = " @param freed"]
+    #[doc = "   Number of resources that were freed."]
+    #[doc = " @param pending"]
+    #[doc = "   Number of resources pending on the defer queue. This number might not"]
+    #[doc = "   be accurate if multi-thread safety is configured."]
+    #[doc = " @param available"]
+    #[doc = "   Number of resources that can be added to the defer queue."]
+    #[doc = "   This number might not be accurate if multi-thread safety is configured."]
+    #[doc = " @return"]
+    #[doc = "   On successful reclamation of at least 1 resource - 0"]
+    #[doc = "   On error - 1 with rte_errno set to"]
+    #[doc = "   - EINVAL - NULL parameters are passed"]
+    pub fn rte_rcu_qsbr_dq_reclaim(
+        dq: *mut rte_rcu_qsbr_dq,
+        n: ::std::os::raw::c_uint,
+        freed: *mut ::std::os::raw::c_uint,
+        pending: *mut ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a defer queue."]
+    #[doc = ""]
+    #[doc = " It tries to reclaim all the resources on the defer queue."]
+    #[doc = " If any of the resources have not completed the grace period"]
+    #[doc = " the reclamation stops and returns immediately. The rest of"]
+    #[doc = " the resources are not reclaimed and the defer queue is not"]
+    #[doc = " freed."]
+    #[doc = ""]
+    #[doc = " @param dq"]
+    #[doc = "   Defer queue to delete."]
+    #[doc = " @return"]
+    #[doc = "   On success - 0"]
+    #[doc = "   On error - 1"]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EAGAIN - Some of the resources have not completed at least 1 grace"]
+    #[doc = "\t\tperiod, try again."]
+    pub fn rte_rcu_qsbr_dq_delete(dq: *mut rte_rcu_qsbr_dq) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_hash {
+    _unused: [u8; 0],
+}
+#[doc = " Type of function used to free data stored in the key."]
+#[doc = " Required when using internal RCU to allow application to free key-data once"]
+#[doc = " the key is returned to the the ring of free key-slots."]
+pub type rte_hash_free_key_data =
+    ::std::option::Option<unsafe extern "C" fn(p: *mut ::std::os::raw::c_void, key_data: *mut ::std::os::raw::c_void)>;
+pub mod rte_hash_qsbr_mode {
+    #[doc = " HASH RCU QSBR integration modes."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " Create defer queue for reclaim."]
+    pub const RTE_HASH_QSBR_MODE_DQ: Type = 0;
+    #[doc = " Use blocking mode reclaim. No defer queue created."]
+    pub const RTE_HASH_QSBR_MODE_SYNC: Type = 1;
+}
+#[doc = " HASH RCU QSBR configuration structure."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_hash_rcu_config {
+    #[doc = "< RCU QSBR variable."]
+    pub v: *mut rte_rcu_qsbr,
+    pub mode: rte_hash_qsbr_mode::Type,
+    #[doc = "< RCU defer queue size."]
+    #[doc = " default: total hash table entries."]
+    pub dq_size: u32,
+    #[doc = "< Threshold to trigger auto reclaim."]
+    pub trigger_reclaim_limit: u32,
+    #[doc = "< Max entries to reclaim in one go."]
+    #[doc = " default: RTE_HASH_RCU_DQ_RECLAIM_MAX."]
+    pub max_reclaim_size: u32,
+    #[doc = "< Pointer passed to the free function. Typically, this is the"]
+    #[doc = " pointer to the data structure to which the resource to free"]
+    #[doc = " (key-data) belongs. This can be NULL."]
+    pub key_data_ptr: *mut ::std::os::raw::c_void,
+    #[doc = "< Function to call to free the resource (key-data)."]
+    pub free_key_data_func: rte_hash_free_key_data,
+}
+#[test]
+fn bindgen_test_layout_rte_hash_rcu_config() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_hash_rcu_config>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_hash_rcu_config))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_hash_rcu_config>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_hash_rcu_config))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_hash_rcu_config>())).key_data_ptr as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(rte_hash_rcu_config),
+            "::",
+            stringify!(key_data_ptr)
+        )
+    );
+}
+impl Default for rte_hash_rcu_config {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Associate RCU QSBR variable with a Hash object."]
+    #[doc = " This API should be called to enable the integrated RCU QSBR support and"]
+    #[doc = " should be called immediately after creating the Hash object."]
+    #[doc = ""]
+    #[doc = " @param h"]
+    #[doc = "   the hash object to add RCU QSBR"]
+    #[doc = " @param cfg"]
+    #[doc = "   RCU QSBR configuration"]
+    #[doc = " @return"]
+    #[doc = "   On success - 0"]
+    #[doc = "   On error - 1 with error code set in rte_errno."]
+    #[doc = "   Possible rte_errno codes are:"]
+    #[doc = "   - EINVAL - invalid pointer"]
+    #[doc = "   - EEXIST - already added QSBR"]
+    #[doc = "   - ENOMEM - memory allocation failure"]
+    pub fn rte_hash_rcu_qsbr_add(h: *mut rte_hash, cfg: *mut rte_hash_rcu_config) -> ::std::os::raw::c_int;
+}
+#[doc = " Signature of key that is stored internally."]
+pub type hash_sig_t = u32;
+#[doc = " Type of function that can be used for calculating the hash value."]
+pub type rte_hash_function = ::std::option::Option<
+    unsafe extern "C" fn(key: *const ::std::os::raw::c_void, key_len: u32, init_val: u32) -> u32,
+>;
+#[doc = " Type of function used to compare the hash key."]
+pub type rte_hash_cmp_eq_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        key1: *const ::std::os::raw::c_void,
+        key2: *const ::std::os::raw::c_void,
+        key_len: usize,
+    ) -> ::std::os::raw::c_int,
+>;
+#[doc = " Parameters used when creating the hash table."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_hash_parameters {
+    #[doc = "< Name of the hash."]
+    pub name: *const ::std::os::raw::c_char,
+    #[doc = "< Total hash table entries."]
+    pub entries: u32,
+    #[doc = "< Unused field. Should be set to 0"]
+    pub reserved: u32,
+    #[doc = "< Length of hash key."]
+    pub key_len: u32,
+    #[doc = "< Primary Hash function used to calculate hash."]
+    pub hash_func: rte_hash_function,
+    #[doc = "< Init value used by hash_func."]
+    pub hash_func_init_val: u32,
+    #[doc = "< NUMA Socket ID for memory."]
+    pub socket_id: ::std::os::raw::c_int,
+    #[doc = "< Indicate if additional parameters are present."]
+    pub extra_flag: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_hash_parameters() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_hash_parameters>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_hash_parameters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_hash_parameters>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_hash_parameters))
+    );
+}
+impl Default for rte_hash_parameters {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Create a new hash table."]
+    #[doc = ""]
+    #[doc = " @param params"]
+    #[doc = "   Parameters used to create and initialise the hash table."]
+    #[doc = " @return"]
+    #[doc = "   Pointer to hash table structure that is used in future hash table"]
+    #[doc = "   operations, or NULL on error, with error code set in rte_errno."]
+    pub fn rte_hash_create(params: *const rte_hash_parameters) -> *mut rte_hash;
+}
+extern "C" {
+    #[doc = " Set a new hash compare function other than the default one."]
+    pub fn rte_hash_set_cmp_func(h: *mut rte_hash, func: rte_hash_cmp_eq_t);
+}
+extern "C" {
+    #[doc = " Find an existing hash table object and return a pointer to it."]
+    pub fn rte_hash_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_hash;
+}
+extern "C" {
+    #[doc = " De-allocate all memory used by hash table."]
+    pub fn rte_hash_free(h: *mut rte_hash);
+}
+extern "C" {
+    #[doc = " Reset all hash structure, by zeroing all entries."]
+    pub fn rte_hash_reset(h: *mut rte_hash);
+}
+extern "C" {
+    #[doc = " Return the number of keys in the hash table"]
+    pub fn rte_hash_count(h: *const rte_hash) -> i32;
+}
+extern "C" {
+    #[doc = " Add a key-value pair to an existing hash table."]
+    #[doc = " This operation is not multi-thread safe"]
+    #[doc = " and should only be called from one thread by default."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0: Added successfully"]
+    #[doc = "   - -EINVAL: Invalid parameters."]
+    #[doc = "   - -ENOSPC: There is no space in the hash for this key."]
+    pub fn rte_hash_add_key_data(
+        h: *const rte_hash,
+        key: *const ::std::os::raw::c_void,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Add a key-value pair with a pre-computed hash value"]
+    #[doc = " to an existing hash table."]
+    pub fn rte_hash_add_key_with_hash_data(
+        h: *const rte_hash,
+        key: *const ::std::os::raw::c_void,
+        sig: hash_sig_t,
+        data: *mut ::std::os::raw::c_void,
+    ) -> i32;
+}
+extern "C" {
+    #[doc = " Add a key to an existing hash table. This operation is not multi-thread safe"]
+    #[doc = " and should only be called from one thread by default."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOSPC if there is no space in the hash for this key."]
+    #[doc = "   - A positive value that can be used by the caller as an offset into an"]
+    #[doc = "     array of user data. This value is unique for this key. This"]
+    #[doc = "     unique key id may be larger than the user specified entry count"]
+    #[doc = "     when RTE_HASH_EXTRA_FLAGS_MULTI_WRITER_ADD flag is set."]
+    pub fn rte_hash_add_key(h: *const rte_hash, key: *const ::std::os::raw::c_void) -> i32;
+}
+extern "C" {
+    #[doc = " Add a key to an existing hash table."]
+    #[doc = " This operation is not multi-thread safe"]
+    #[doc = " and should only be called from one thread by default."]
+    pub fn rte_hash_add_key_with_hash(h: *const rte_hash, key: *const ::std::os::raw::c_void, sig: hash_sig_t) -> i32;
+}
+extern "C" {
+    #[doc = " Remove a key from an existing hash table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOENT if the key is not found."]
+    #[doc = "   - A positive value that can be used by the caller as an offset into an"]
+    #[doc = "     array of user data. This value is unique for this key, and is the same"]
+    #[doc = "     value that was returned when the key was added."]
+    pub fn rte_hash_del_key(h: *const rte_hash, key: *const ::std::os::raw::c_void) -> i32;
+}
+extern "C" {
+    #[doc = " Remove a key from an existing hash table."]
+    pub fn rte_hash_del_key_with_hash(h: *const rte_hash, key: *const ::std::os::raw::c_void, sig: hash_sig_t) -> i32;
+}
+extern "C" {
+    #[doc = " Find a key in the hash table given the position."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0 if retrieved successfully"]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOENT if no valid key is found in the given position."]
+    pub fn rte_hash_get_key_with_position(
+        h: *const rte_hash,
+        position: i32,
+        key: *mut *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Free a hash key in the hash table given the position"]
+    #[doc = " of the key. This operation is not multi-thread safe and should"]
+    #[doc = " only be called from one thread by default. If"]
+    #[doc = " RTE_HASH_EXTRA_FLAGS_NO_FREE_ON_DEL is not set, the key is freed by"]
+    #[doc = " rte_hash_del_key."]
+    pub fn rte_hash_free_key_with_position(h: *const rte_hash, position: i32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Find a key-value pair in the hash table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - A positive value that can be used by the caller as an offset into an"]
+    #[doc = "     array of user data. This value is unique for this key, and is the same"]
+    #[doc = "     value that was returned when the key was added."]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOENT if the key is not found."]
+    pub fn rte_hash_lookup_data(
+        h: *const rte_hash,
+        key: *const ::std::os::raw::c_void,
+        data: *mut *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Find a key-value pair with a pre-computed hash value"]
+    #[doc = " to an existing hash table."]
+    pub fn rte_hash_lookup_with_hash_data(
+        h: *const rte_hash,
+        key: *const ::std::os::raw::c_void,
+        sig: hash_sig_t,
+        data: *mut *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Find a key in the hash table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOENT if the key is not found."]
+    #[doc = "   - A positive value that can be used by the caller as an offset into an"]
+    #[doc = "     array of user data. This value is unique for this key, and is the same"]
+    #[doc = "     value that was returned when the key was added."]
+    pub fn rte_hash_lookup(h: *const rte_hash, key: *const ::std::os::raw::c_void) -> i32;
+}
+extern "C" {
+    #[doc = " Find a key in the hash table."]
+    pub fn rte_hash_lookup_with_hash(h: *const rte_hash, key: *const ::std::os::raw::c_void, sig: hash_sig_t) -> i32;
+}
+extern "C" {
+    #[doc = " Calc a hash value by key."]
+    pub fn rte_hash_hash(h: *const rte_hash, key: *const ::std::os::raw::c_void) -> hash_sig_t;
+}
+extern "C" {
+    #[doc = " Find multiple keys in the hash table."]
+    #[doc = ""]
+    #[doc = " @param hit_mask"]
+    #[doc = "   Output containing a bitmask with all successful lookups."]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL if there's an error, otherwise number of successful lookups."]
+    pub fn rte_hash_lookup_bulk_data(
+        h: *const rte_hash,
+        keys: *mut *const ::std::os::raw::c_void,
+        num_keys: u32,
+        hit_mask: *mut u64,
+        data: *mut *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Find multiple keys in the hash table."]
+    #[doc = ""]
+    #[doc = " @param positions"]
+    #[doc = "   Output containing a list of values, corresponding to the list of keys that"]
+    #[doc = "   can be used by the caller as an offset into an array of user data. These"]
+    #[doc = "   values are unique for each key, and are the same values that were returned"]
+    #[doc = "   when each key was added. If a key in the list was not found, then -ENOENT"]
+    #[doc = "   will be the value."]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL if there's an error, otherwise 0."]
+    pub fn rte_hash_lookup_bulk(
+        h: *const rte_hash,
+        keys: *mut *const ::std::os::raw::c_void,
+        num_keys: u32,
+        positions: *mut i32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Iterate through the hash table, returning key-value pairs."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   Position where key was stored, if successful."]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOENT if end of the hash table."]
+    pub fn rte_hash_iterate(
+        h: *const rte_hash,
+        key: *mut *const ::std::os::raw::c_void,
+        data: *mut *mut ::std::os::raw::c_void,
+        next: *mut u32,
+    ) -> i32;
+}
+#[doc = " The RTE stack structure contains the LIFO structure itself, plus metadata"]
+#[doc = " such as its name and memzone pointer."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_stack {
+    _unused: [u8; 0],
+}
+extern "C" {
+    #[doc = " Create a new stack named *name* in memory."]
+    #[doc = ""]
+    #[doc = " This function uses ``memzone_reserve()`` to allocate memory for a stack of"]
+    #[doc = " size *count*. The behavior of the stack is controlled by the *flags*."]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "   The name of the stack."]
+    #[doc = " @param count"]
+    #[doc = "   The size of the stack."]
+    #[doc = " @param socket_id"]
+    #[doc = "   The *socket_id* argument is the socket identifier in case of"]
+    #[doc = "   NUMA. The value can be *SOCKET_ID_ANY* if there is no NUMA"]
+    #[doc = "   constraint for the reserved zone."]
+    #[doc = " @param flags"]
+    #[doc = "   An OR of the following:"]
+    #[doc = "    - RTE_STACK_F_LF: If this flag is set, the stack uses lock-free"]
+    #[doc = "      variants of the push and pop functions. Otherwise, it achieves"]
+    #[doc = "      thread-safety using a lock."]
+    #[doc = " @return"]
+    #[doc = "   On success, the pointer to the new allocated stack. NULL on error with"]
+    #[doc = "    rte_errno set appropriately. Possible errno values include:"]
+    #[doc = "    - ENOSPC - the maximum number of memzones has already been allocated"]
+    #[doc = "    - EEXIST - a stack with the same name already exists"]
+    #[doc = "    - ENOMEM - insufficient memory to create the stack"]
+    #[doc = "    - ENAMETOOLONG - name size exceeds RTE_STACK_NAMESIZE"]
+    #[doc = "    - ENOTSUP - platform does not support given flags combination."]
+    pub fn rte_stack_create(
+        name: *const ::std::os::raw::c_char,
+        count: ::std::os::raw::c_uint,
+        socket_id: ::std::os::raw::c_int,
+        flags: u32,
+    ) -> *mut rte_stack;
+}
+extern "C" {
+    #[doc = " Free all memory used by the stack."]
+    #[doc = ""]
+    #[doc = " @param s"]
+    #[doc = "   Stack to free"]
+    pub fn rte_stack_free(s: *mut rte_stack);
+}
+extern "C" {
+    #[doc = " Lookup a stack by its name."]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "   The name of the stack."]
+    #[doc = " @return"]
+    #[doc = "   The pointer to the stack matching the name, or NULL if not found,"]
+    #[doc = "   with rte_errno set appropriately. Possible rte_errno values include:"]
+    #[doc = "    - ENOENT - Stack with name *name* not found."]
+    #[doc = "    - EINVAL - *name* pointer is NULL."]
+    pub fn rte_stack_lookup(name: *const ::std::os::raw::c_char) -> *mut rte_stack;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_reciprocal {
+    pub m: u32,
+    pub sh1: u8,
+    pub sh2: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_reciprocal() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_reciprocal>(),
+        8usize,
+        concat!("Size of: ", stringify!(rte_reciprocal))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_reciprocal>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_reciprocal))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal>())).m as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal), "::", stringify!(m))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal>())).sh1 as *const _ as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal), "::", stringify!(sh1))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal>())).sh2 as *const _ as usize },
+        5usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal), "::", stringify!(sh2))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_reciprocal_u64 {
+    pub m: u64,
+    pub sh1: u8,
+    pub sh2: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_reciprocal_u64() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_reciprocal_u64>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_reciprocal_u64))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_reciprocal_u64>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_reciprocal_u64))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal_u64>())).m as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal_u64), "::", stringify!(m))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal_u64>())).sh1 as *const _ as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal_u64), "::", stringify!(sh1))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<rte_reciprocal_u64>())).sh2 as *const _ as usize },
+        9usize,
+        concat!("Offset of field: ", stringify!(rte_reciprocal_u64), "::", stringify!(sh2))
+    );
+}
+extern "C" {
+    pub fn rte_reciprocal_value(d: u32) -> rte_reciprocal;
+}
+extern "C" {
+    pub fn rte_reciprocal_value_u64(d: u64) -> rte_reciprocal_u64;
+}
+pub mod rte_net_crc_type {
+    #[doc = " CRC types"]
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_NET_CRC16_CCITT: Type = 0;
+    pub const RTE_NET_CRC32_ETH: Type = 1;
+    pub const RTE_NET_CRC_REQS: Type = 2;
+}
+pub mod rte_net_crc_alg {
+    #[doc = " CRC compute algorithm"]
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_NET_CRC_SCALAR: Type = 0;
+    pub const RTE_NET_CRC_SSE42: Type = 1;
+    pub const RTE_NET_CRC_NEON: Type = 2;
+}
+extern "C" {
+    #[doc = " This API set the CRC computation algorithm (i.e. scalar version,"]
+    #[doc = " x86 64-bit sse4.2 intrinsic version, etc.) and internal data"]
+    #[doc = " structure."]
+    #[doc = ""]
+    #[doc = " @param alg"]
+    #[doc = "   This parameter is used to select the CRC implementation version."]
+    #[doc = "   - RTE_NET_CRC_SCALAR"]
+    #[doc = "   - RTE_NET_CRC_SSE42 (Use 64-bit SSE4.2 intrinsic)"]
+    #[doc = "   - RTE_NET_CRC_NEON (Use ARM Neon intrinsic)"]
+    pub fn rte_net_crc_set_alg(alg: rte_net_crc_alg::Type);
+}
+extern "C" {
+    #[doc = " CRC compute API"]
+    #[doc = ""]
+    #[doc = " @param data"]
+    #[doc = "   Pointer to the packet data for CRC computation"]
+    #[doc = " @param data_len"]
+    #[doc = "   Data length for CRC computation"]
+    #[doc = " @param type"]
+    #[doc = "   CRC type (enum rte_net_crc_type)"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   CRC value"]
+    pub fn rte_net_crc_calc(
+        data: *const ::std::os::raw::c_void,
+        data_len: u32,
+        type_: rte_net_crc_type::Type,
+    ) -> u32;
+}
+pub mod rte_hypervisor {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_HYPERVISOR_NONE: Type = 0;
+    pub const RTE_HYPERVISOR_KVM: Type = 1;
+    pub const RTE_HYPERVISOR_HYPERV: Type = 2;
+    pub const RTE_HYPERVISOR_VMWARE: Type = 3;
+    pub const RTE_HYPERVISOR_UNKNOWN: Type = 4;
+}
+extern "C" {
+    #[doc = " Get the id of hypervisor it is running on."]
+    pub fn rte_hypervisor_get() -> rte_hypervisor::Type;
+}
+extern "C" {
+    #[doc = " Get the name of a given hypervisor id."]
+    pub fn rte_hypervisor_get_name(id: rte_hypervisor::Type) -> *const ::std::os::raw::c_char;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_swx_pipeline {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_swx_ctl_pipeline {
+    _unused: [u8; 0],
+}
+#[doc = " Packet."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_pkt {
+    #[doc = " Opaque packet handle."]
+    pub handle: *mut ::std::os::raw::c_void,
+    #[doc = " Buffer where the packet is stored."]
+    pub pkt: *mut u8,
+    #[doc = " Packet buffer offset of the first packet byte."]
+    pub offset: u32,
+    #[doc = " Packet length in bytes."]
+    pub length: u32,
+}
+impl Default for rte_swx_pkt {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Input port statistics counters."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_in_stats {
+    #[doc = " Number of packets."]
+    pub n_pkts: u64,
+    #[doc = " Number of bytes."]
+    pub n_bytes: u64,
+    #[doc = " Number of empty polls."]
+    pub n_empty: u64,
+}
+pub type rte_swx_port_in_create_t =
+    ::std::option::Option<unsafe extern "C" fn(args: *mut ::std::os::raw::c_void) -> *mut ::std::os::raw::c_void>;
+pub type rte_swx_port_in_free_t = ::std::option::Option<unsafe extern "C" fn(port: *mut ::std::os::raw::c_void)>;
+pub type rte_swx_port_in_pkt_rx_t = ::std::option::Option<
+    unsafe extern "C" fn(port: *mut ::std::os::raw::c_void, pkt: *mut rte_swx_pkt) -> ::std::os::raw::c_int,
+>;
+pub type rte_swx_port_in_stats_read_t = ::std::option::Option<
+    unsafe extern "C" fn(port: *mut ::std::os::raw::c_void, stats: *mut rte_swx_port_in_stats),
+>;
+#[doc = " Input port operations."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_in_ops {
+    #[doc = " Create port object."]
+    pub create: rte_swx_port_in_create_t,
+    #[doc = " Free port object."]
+    pub free: rte_swx_port_in_free_t,
+    #[doc = " Packet reception."]
+    pub pkt_rx: rte_swx_port_in_pkt_rx_t,
+    #[doc = " Statistics counters read."]
+    pub stats_read: rte_swx_port_in_stats_read_t,
+}
+#[doc = " Output port statistics counters."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_out_stats {
+    #[doc = " Number of packets."]
+    pub n_pkts: u64,
+    #[doc = " Number of bytes."]
+    pub n_bytes: u64,
+}
+pub type rte_swx_port_out_create_t =
+    ::std::option::Option<unsafe extern "C" fn(args: *mut ::std::os::raw::c_void) -> *mut ::std::os::raw::c_void>;
+pub type rte_swx_port_out_free_t = ::std::option::Option<unsafe extern "C" fn(port: *mut ::std::os::raw::c_void)>;
+pub type rte_swx_port_out_pkt_tx_t =
+    ::std::option::Option<unsafe extern "C" fn(port: *mut ::std::os::raw::c_void, pkt: *mut rte_swx_pkt)>;
+pub type rte_swx_port_out_flush_t = ::std::option::Option<unsafe extern "C" fn(port: *mut ::std::os::raw::c_void)>;
+pub type rte_swx_port_out_stats_read_t = ::std::option::Option<
+    unsafe extern "C" fn(port: *mut ::std::os::raw::c_void, stats: *mut rte_swx_port_out_stats),
+>;
+#[doc = " Output port operations."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_out_ops {
+    #[doc = " Create port object."]
+    pub create: rte_swx_port_out_create_t,
+    #[doc = " Free port object."]
+    pub free: rte_swx_port_out_free_t,
+    #[doc = " Packet transmission."]
+    pub pkt_tx: rte_swx_port_out_pkt_tx_t,
+    #[doc = " Packet flush."]
+    pub flush: rte_swx_port_out_flush_t,
+    #[doc = " Statistics counters read."]
+    pub stats_read: rte_swx_port_out_stats_read_t,
+}
+#[doc = " Ethernet device input port (reader) creation parameters."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_ethdev_reader_params {
+    #[doc = " Name of a valid and fully configured Ethernet device."]
+    pub dev_name: *const ::std::os::raw::c_char,
+    #[doc = " Ethernet device receive queue ID."]
+    pub queue_id: u16,
+    #[doc = " Ethernet device receive burst size."]
+    pub burst_size: u32,
+}
+impl Default for rte_swx_port_ethdev_reader_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Ethernet device output port (writer) creation parameters."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_port_ethdev_writer_params {
+    #[doc = " Name of a valid and fully configured Ethernet device."]
+    pub dev_name: *const ::std::os::raw::c_char,
+    #[doc = " Ethernet device transmit queue ID."]
+    pub queue_id: u16,
+    #[doc = " Ethernet device transmit burst size."]
+    pub burst_size: u32,
+}
+impl Default for rte_swx_port_ethdev_writer_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Ethernet device reader operations."]
+    pub static mut rte_swx_port_ethdev_reader_ops: rte_swx_port_in_ops;
+}
+extern "C" {
+    #[doc = " Ethernet device writer operations."]
+    pub static mut rte_swx_port_ethdev_writer_ops: rte_swx_port_out_ops;
+}
+pub mod rte_swx_table_match_type {
+    #[doc = " Match type."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " Wildcard Match (WM)."]
+    pub const RTE_SWX_TABLE_MATCH_WILDCARD: Type = 0;
+    #[doc = " Longest Prefix Match (LPM)."]
+    pub const RTE_SWX_TABLE_MATCH_LPM: Type = 1;
+    #[doc = " Exact Match (EM)."]
+    pub const RTE_SWX_TABLE_MATCH_EXACT: Type = 2;
+}
+#[doc = " Table creation parameters."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_table_params {
+    #[doc = " Table match type."]
+    pub match_type: rte_swx_table_match_type::Type,
+    #[doc = " Key size in bytes."]
+    pub key_size: u32,
+    #[doc = " Offset of the first byte of the key within the key buffer."]
+    pub key_offset: u32,
+    #[doc = " Mask of *key_size* bytes logically laid over the bytes at positions"]
+    #[doc = " *key_offset* .. (*key_offset* + *key_size* - 1) of the key buffer."]
+    pub key_mask0: *mut u8,
+    #[doc = " Maximum size (in bytes) of the action data."]
+    pub action_data_size: u32,
+    #[doc = " Maximum number of keys to be stored in the table together with their"]
+    #[doc = " associated data."]
+    pub n_keys_max: u32,
+}
+impl Default for rte_swx_table_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Table entry."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_table_entry {
+    #[doc = " Used to facilitate the membership of this table entry to a"]
+    #[doc = " linked list."]
+    pub node: rte_swx_table_entry__bindgen_ty_1,
+    #[doc = " Key value for the current entry."]
+    pub key: *mut u8,
+    #[doc = " Key mask for the current entry."]
+    pub key_mask: *mut u8,
+    #[doc = " Action ID for the current entry."]
+    pub action_id: u64,
+    #[doc = " Action data for the current entry."]
+    pub action_data: *mut u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_table_entry__bindgen_ty_1 {
+    pub tqe_next: *mut rte_swx_table_entry,
+    pub tqe_prev: *mut *mut rte_swx_table_entry,
+}
+impl Default for rte_swx_table_entry__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_swx_table_entry {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " List of table entries."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_swx_table_entry_list {
+    pub tqh_first: *mut rte_swx_table_entry,
+    pub tqh_last: *mut *mut rte_swx_table_entry,
+}
+impl Default for rte_swx_table_entry_list {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub type rte_swx_table_footprint_get_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        params: *mut rte_swx_table_params,
+        entries: *mut rte_swx_table_entry_list,
+        args: *const ::std::os::raw::c_char,
+    ) -> u64,
+>;
+pub type rte_swx_table_mailbox_size_get_t = ::std::option::Option<unsafe extern "C" fn() -> u64>;
+pub type rte_swx_table_create_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        params: *mut rte_swx_table_params,
+        entries: *mut rte_swx_table_entry_list,
+        args: *const ::std::os::raw::c_char,
+        numa_node: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type rte_swx_table_add_t = ::std::option::Option<
+    unsafe extern "C" fn(table: *mut ::std::os::raw::c_void, entry: *mut rte_swx_table_entry) -> ::std::os::raw::c_int,
+>;
+pub type rte_swx_table_delete_t = ::std::option::Option<
+    unsafe extern "C" fn(table: *mut ::std::os::raw::c_void, entry: *mut rte_swx_table_entry) -> ::std::os::raw::c_int,
+>;
+pub type rte_swx_table_lookup_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        table: *mut ::std::os::raw::c_void,
+        mailbox: *mut ::std::os::raw::c_void,
+        key: *mut *mut u8,
+        action_id: *mut u64,
+        action_data: *mut *mut u8,
+        hit: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type rte_swx_table_free_t = ::std::option::Option<unsafe extern "C" fn(table: *mut ::std::os::raw::c_void)>;
+#[doc = " Table type operations."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_swx_table_ops {
+    #[doc = " Table memory footprint."]
+    pub footprint_get: rte_swx_table_footprint_get_t,
+    #[doc = " Table mailbox size."]
+    pub mailbox_size_get: rte_swx_table_mailbox_size_get_t,
+    #[doc = " Table creation."]
+    pub create: rte_swx_table_create_t,
+    #[doc = " Incremental table entry add."]
+    pub add: rte_swx_table_add_t,
+    #[doc = " Incremental table entry delete."]
+    pub del: rte_swx_table_delete_t,
+    #[doc = " Table lookup."]
+    pub lkp: rte_swx_table_lookup_t,
+    #[doc = " Table free."]
+    pub free: rte_swx_table_free_t,
+}
+extern "C" {
+    #[doc = " Exact match table operations."]
+    pub static mut rte_swx_table_exact_match_ops: rte_swx_table_ops;
+}
+extern "C" {
+    #[doc = " Pipeline configure"]
+    #[doc = ""]
+    #[doc = " @param[out] p"]
+    #[doc = "   Pipeline handle. Must point to valid memory. Contains valid pipeline handle"]
+    #[doc = "   when the function returns successfully."]
+    #[doc = " @param[in] numa_node"]
+    #[doc = "   Non-Uniform Memory Access (NUMA) node."]
+    #[doc = " @return"]
+    #[doc = "   0 on success or the following error codes otherwise:"]
+    #[doc = "   -EINVAL: Invalid argument;"]
+    #[doc = "   -ENOMEM: Not enough space/cannot allocate memory."]
+    pub fn rte_swx_pipeline_config(p: *mut *mut rte_swx_pipeline, numa_node: ::std::os::raw::c_int)
+        -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline input port type register"]
+    pub fn rte_swx_pipeline_port_in_type_register(
+        p: *mut rte_swx_pipeline,
+        name: *const ::std::os::raw::c_char,
+        ops: *mut rte_swx_port_in_ops,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline input port configure"]
+    pub fn rte_swx_pipeline_port_in_config(
+        p: *mut rte_swx_pipeline,
+        port_id: u32,
+        port_type_name: *const ::std::os::raw::c_char,
+        args: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline output port type register"]
+    pub fn rte_swx_pipeline_port_out_type_register(
+        p: *mut rte_swx_pipeline,
+        name: *const ::std::os::raw::c_char,
+        ops: *mut rte_swx_port_out_ops,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline output port configure"]
+    pub fn rte_swx_pipeline_port_out_config(
+        p: *mut rte_swx_pipeline,
+        port_id: u32,
+        port_type_name: *const ::std::os::raw::c_char,
+        args: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline table type register"]
+    pub fn rte_swx_pipeline_table_type_register(
+        p: *mut rte_swx_pipeline,
+        name: *const ::std::os::raw::c_char,
+        match_type: rte_swx_table_match_type::Type,
+        ops: *mut rte_swx_table_ops,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline build"]
+    #[doc = ""]
+    #[doc = " Once called, the pipeline configuration is no longer allowed to change."]
+    pub fn rte_swx_pipeline_build(p: *mut rte_swx_pipeline) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline build from specification file"]
+    #[doc = ""]
+    #[doc = " @param[in] p"]
+    #[doc = "   Pipeline handle."]
+    #[doc = " @param[in] spec"]
+    #[doc = "   Pipeline specification file."]
+    #[doc = " @param[out] err_line"]
+    #[doc = "   In case of error and non-NULL, the line number within the *spec* file where"]
+    #[doc = "   the error occurred. The first line number in the file is 1."]
+    #[doc = " @param[out] err_msg"]
+    #[doc = "   In case of error and non-NULL, the error message."]
+    pub fn rte_swx_pipeline_build_from_spec(
+        p: *mut rte_swx_pipeline,
+        spec: *mut FILE,
+        err_line: *mut u32,
+        err_msg: *mut *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline run"]
+    pub fn rte_swx_pipeline_run(p: *mut rte_swx_pipeline, n_instructions: u32);
+}
+extern "C" {
+    #[doc = " Pipeline flush"]
+    pub fn rte_swx_pipeline_flush(p: *mut rte_swx_pipeline);
+}
+extern "C" {
+    #[doc = " Pipeline free"]
+    pub fn rte_swx_pipeline_free(p: *mut rte_swx_pipeline);
+}
+extern "C" {
+    #[doc = " Input port statistics counters read"]
+    pub fn rte_swx_ctl_pipeline_port_in_stats_read(
+        p: *mut rte_swx_pipeline,
+        port_id: u32,
+        stats: *mut rte_swx_port_in_stats,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Output port statistics counters read"]
+    pub fn rte_swx_ctl_pipeline_port_out_stats_read(
+        p: *mut rte_swx_pipeline,
+        port_id: u32,
+        stats: *mut rte_swx_port_out_stats,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline control create"]
+    pub fn rte_swx_ctl_pipeline_create(p: *mut rte_swx_pipeline) -> *mut rte_swx_ctl_pipeline;
+}
+extern "C" {
+    #[doc = " Pipeline table entry add"]
+    #[doc = ""]
+    #[doc = " Schedule entry for addition to table or update as part of the next commit"]
+    #[doc = " operation."]
+    pub fn rte_swx_ctl_pipeline_table_entry_add(
+        ctl: *mut rte_swx_ctl_pipeline,
+        table_name: *const ::std::os::raw::c_char,
+        entry: *mut rte_swx_table_entry,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline table default entry add"]
+    pub fn rte_swx_ctl_pipeline_table_default_entry_add(
+        ctl: *mut rte_swx_ctl_pipeline,
+        table_name: *const ::std::os::raw::c_char,
+        entry: *mut rte_swx_table_entry,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline table entry delete"]
+    pub fn rte_swx_ctl_pipeline_table_entry_delete(
+        ctl: *mut rte_swx_ctl_pipeline,
+        table_name: *const ::std::os::raw::c_char,
+        entry: *mut rte_swx_table_entry,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline commit"]
+    #[doc = ""]
+    #[doc = " Perform all the scheduled table work."]
+    pub fn rte_swx_ctl_pipeline_commit(
+        ctl: *mut rte_swx_ctl_pipeline,
+        abort_on_fail: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline abort"]
+    #[doc = ""]
+    #[doc = " Discard all the scheduled table work."]
+    pub fn rte_swx_ctl_pipeline_abort(ctl: *mut rte_swx_ctl_pipeline);
+}
+extern "C" {
+    #[doc = " Pipeline table entry read"]
+    #[doc = ""]
+    #[doc = " Read table entry from string."]
+    pub fn rte_swx_ctl_pipeline_table_entry_read(
+        ctl: *mut rte_swx_ctl_pipeline,
+        table_name: *const ::std::os::raw::c_char,
+        string: *const ::std::os::raw::c_char,
+    ) -> *mut rte_swx_table_entry;
+}
+extern "C" {
+    #[doc = " Pipeline table print to file"]
+    pub fn rte_swx_ctl_pipeline_table_fprintf(
+        f: *mut FILE,
+        ctl: *mut rte_swx_ctl_pipeline,
+        table_name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Pipeline control free"]
+    pub fn rte_swx_ctl_pipeline_free(ctl: *mut rte_swx_ctl_pipeline);
+}
+extern "C" {
+    #[doc = " Soft NIC run."]
+    #[doc = ""]
+    #[doc = " @param port_id"]
+    #[doc = "    Port ID of the Soft NIC device."]
+    #[doc = " @return"]
+    #[doc = "    Zero on success, error code otherwise."]
+    pub fn rte_pmd_softnic_run(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Soft NIC manage."]
+    #[doc = ""]
+    #[doc = " Process the pending CLI requests of the management connection."]
+    #[doc = ""]
+    #[doc = " @param port_id"]
+    #[doc = "    Port ID of the Soft NIC device."]
+    #[doc = " @return"]
+    #[doc = "    Zero on success, error code otherwise."]
+    pub fn rte_pmd_softnic_manage(port_id: u16) -> ::std::os::raw::c_int;
+}
+#[doc = " RED configuration parameters passed by user"]
+#[doc = ""]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_red_params {
+    #[doc = "< Minimum threshold for queue (max_th)"]
+    pub min_th: u16,
+    #[doc = "< Maximum threshold for queue (max_th)"]
+    pub max_th: u16,
+    #[doc = "< Inverse of packet marking probability maximum value (maxp = 1 / maxp_inv)"]
+    pub maxp_inv: u16,
+    #[doc = "< Negated log2 of queue weight (wq = 1 / (2 ^ wq_log2))"]
+    pub wq_log2: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_red_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_red_params>(),
+        8usize,
+        concat!("Size of: ", stringify!(rte_red_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_red_params>(),
+        2usize,
+        concat!("Alignment of ", stringify!(rte_red_params))
+    );
+}
+#[doc = " RED configuration parameters"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_red_config {
+    #[doc = "< min_th scaled in fixed-point format"]
+    pub min_th: u32,
+    #[doc = "< max_th scaled in fixed-point format"]
+    pub max_th: u32,
+    #[doc = "< pre-calculated constant value"]
+    pub pa_const: u32,
+    #[doc = "< maxp_inv"]
+    pub maxp_inv: u8,
+    #[doc = "< wq_log2"]
+    pub wq_log2: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_red_config() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_red_config>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_red_config))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_red_config>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_red_config))
+    );
+}
+#[doc = " RED run-time data"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_red {
+    #[doc = "< Average queue size (avg), scaled in fixed-point format"]
+    pub avg: u32,
+    #[doc = "< Number of packets since last marked packet (count)"]
+    pub count: u32,
+    #[doc = "< Start of the queue idle time (q_time)"]
+    pub q_time: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_red() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_red>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_red))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_red>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_red))
+    );
+}
+extern "C" {
+    #[doc = " @brief Initialises run-time data"]
+    #[doc = ""]
+    #[doc = " @param red [in,out] data pointer to RED runtime data"]
+    #[doc = ""]
+    #[doc = " @return Operation status"]
+    #[doc = " @retval 0 success"]
+    #[doc = " @retval !0 error"]
+    pub fn rte_red_rt_data_init(red: *mut rte_red) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @brief Configures a single RED configuration parameter structure."]
+    #[doc = ""]
+    #[doc = " @param red_cfg [in,out] config pointer to a RED configuration parameter structure"]
+    #[doc = " @param wq_log2 [in]  log2 of the filter weight, valid range is:"]
+    #[doc = "             RTE_RED_WQ_LOG2_MIN <= wq_log2 <= RTE_RED_WQ_LOG2_MAX"]
+    #[doc = " @param min_th [in] queue minimum threshold in number of packets"]
+    #[doc = " @param max_th [in] queue maximum threshold in number of packets"]
+    #[doc = " @param maxp_inv [in] inverse maximum mark probability"]
+    #[doc = ""]
+    #[doc = " @return Operation status"]
+    #[doc = " @retval 0 success"]
+    #[doc = " @retval !0 error"]
+    pub fn rte_red_config_init(
+        red_cfg: *mut rte_red_config,
+        wq_log2: u16,
+        min_th: u16,
+        max_th: u16,
+        maxp_inv: u16,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_fib {
+    _unused: [u8; 0],
+}
+pub mod rte_fib_type {
+    #[doc = " Type of FIB struct"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< RIB tree based FIB"]
+    pub const RTE_FIB_DUMMY: Type = 0;
+    #[doc = "< DIR24_8 based FIB"]
+    pub const RTE_FIB_DIR24_8: Type = 1;
+    pub const RTE_FIB_TYPE_MAX: Type = 2;
+}
+pub mod rte_fib_dir24_8_nh_sz {
+    #[doc = " Size of nexthop (1 << nh_sz) bits for DIR24_8 based FIB"]
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_FIB_DIR24_8_1B: Type = 0;
+    pub const RTE_FIB_DIR24_8_2B: Type = 1;
+    pub const RTE_FIB_DIR24_8_4B: Type = 2;
+    pub const RTE_FIB_DIR24_8_8B: Type = 3;
+}
+pub mod rte_fib_lookup_type {
+    #[doc = " Type of lookup function implementation"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Selects the best implementation based on the max simd bitwidth"]
+    pub const RTE_FIB_LOOKUP_DEFAULT: Type = 0;
+    #[doc = "< Macro based lookup function"]
+    pub const RTE_FIB_LOOKUP_DIR24_8_SCALAR_MACRO: Type = 1;
+    #[doc = "< Lookup implementation using inlined functions"]
+    #[doc = " for different next hop sizes"]
+    pub const RTE_FIB_LOOKUP_DIR24_8_SCALAR_INLINE: Type = 2;
+    #[doc = "< Unified lookup function for all next hop sizes"]
+    pub const RTE_FIB_LOOKUP_DIR24_8_SCALAR_UNI: Type = 3;
+    #[doc = "< Vector implementation using AVX512"]
+    pub const RTE_FIB_LOOKUP_DIR24_8_VECTOR_AVX512: Type = 4;
+}
+#[doc = " FIB configuration structure"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_fib_conf {
+    pub type_: rte_fib_type::Type,
+    #[doc = " Default value returned on lookup if there is no route"]
+    pub default_nh: u64,
+    pub max_routes: ::std::os::raw::c_int,
+    pub __bindgen_anon_1: rte_fib_conf__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_fib_conf__bindgen_ty_1 {
+    pub dir24_8: rte_fib_conf__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_fib_conf__bindgen_ty_1__bindgen_ty_1 {
+    pub nh_sz: rte_fib_dir24_8_nh_sz::Type,
+    pub num_tbl8: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_fib_conf() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_fib_conf>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_fib_conf))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_fib_conf>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_fib_conf))
+    );
+}
+impl Default for rte_fib_conf__bindgen_ty_1__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_fib_conf__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_fib_conf {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Create FIB"]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "  FIB name"]
+    #[doc = " @param socket_id"]
+    #[doc = "  NUMA socket ID for FIB table memory allocation"]
+    #[doc = " @param conf"]
+    #[doc = "  Structure containing the configuration"]
+    #[doc = " @return"]
+    #[doc = "  Handle to the FIB object on success"]
+    #[doc = "  NULL otherwise with rte_errno set to an appropriate values."]
+    pub fn rte_fib_create(
+        name: *const ::std::os::raw::c_char,
+        socket_id: ::std::os::raw::c_int,
+        conf: *mut rte_fib_conf,
+    ) -> *mut rte_fib;
+}
+extern "C" {
+    #[doc = " Find an existing FIB object and return a pointer to it."]
+    pub fn rte_fib_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_fib;
+}
+extern "C" {
+    #[doc = " Free an FIB object."]
+    pub fn rte_fib_free(fib: *mut rte_fib);
+}
+extern "C" {
+    #[doc = " Add a route to the FIB."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_fib_add(fib: *mut rte_fib, ip: u32, depth: u8, next_hop: u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a rule from the FIB."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_fib_delete(fib: *mut rte_fib, ip: u32, depth: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Lookup multiple IP addresses in the FIB."]
+    #[doc = ""]
+    #[doc = " @param ips"]
+    #[doc = "   Array of IPs to be looked up in the FIB"]
+    #[doc = " @param next_hops"]
+    #[doc = "   Next hop of the most specific rule found for IP."]
+    #[doc = "   This is an array of eight byte values."]
+    #[doc = "   If the lookup for the given IP failed, then corresponding element would"]
+    #[doc = "   contain default nexthop value configured for a FIB."]
+    #[doc = " @param n"]
+    #[doc = "   Number of elements in ips (and next_hops) array to lookup."]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, otherwise 0"]
+    pub fn rte_fib_lookup_bulk(
+        fib: *mut rte_fib,
+        ips: *mut u32,
+        next_hops: *mut u64,
+        n: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Set lookup function based on type"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    -EINVAL on failure"]
+    #[doc = "    0 on success"]
+    pub fn rte_fib_select_lookup(fib: *mut rte_fib, type_: rte_fib_lookup_type::Type) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_lpm {
+    _unused: [u8; 0],
+}
+#[doc = " LPM configuration structure."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_lpm_config {
+    #[doc = "< Max number of rules."]
+    pub max_rules: u32,
+    #[doc = "< Number of tbl8s to allocate."]
+    pub number_tbl8s: u32,
+    #[doc = "< This field is currently unused."]
+    pub flags: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_rte_lpm_config() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_lpm_config>(),
+        12usize,
+        concat!("Size of: ", stringify!(rte_lpm_config))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_lpm_config>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_lpm_config))
+    );
+}
+extern "C" {
+    #[doc = " Create an LPM object."]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "   LPM object name"]
+    #[doc = " @param socket_id"]
+    #[doc = "   NUMA socket ID for LPM table memory allocation"]
+    #[doc = " @param config"]
+    #[doc = "   Structure containing the configuration"]
+    #[doc = " @return"]
+    #[doc = "   Handle to LPM object on success, NULL otherwise with rte_errno set"]
+    #[doc = "   to an appropriate values."]
+    pub fn rte_lpm_create(
+        name: *const ::std::os::raw::c_char,
+        socket_id: ::std::os::raw::c_int,
+        config: *const rte_lpm_config,
+    ) -> *mut rte_lpm;
+}
+extern "C" {
+    #[doc = " Find an existing LPM object and return a pointer to it."]
+    pub fn rte_lpm_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_lpm;
+}
+extern "C" {
+    #[doc = " Free an LPM object."]
+    pub fn rte_lpm_free(lpm: *mut rte_lpm);
+}
+extern "C" {
+    #[doc = " Add a rule to the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_lpm_add(lpm: *mut rte_lpm, ip: u32, depth: u8, next_hop: u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Check if a rule is present in the LPM table,"]
+    #[doc = " and provide its next hop if it is."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   1 if the rule exists, 0 if it does not, a negative value on failure"]
+    pub fn rte_lpm_is_rule_present(lpm: *mut rte_lpm, ip: u32, depth: u8, next_hop: *mut u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a rule from the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_lpm_delete(lpm: *mut rte_lpm, ip: u32, depth: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete all rules from the LPM table."]
+    pub fn rte_lpm_delete_all(lpm: *mut rte_lpm);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_lpm6 {
+    _unused: [u8; 0],
+}
+#[doc = " LPM configuration structure."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_lpm6_config {
+    #[doc = "< Max number of rules."]
+    pub max_rules: u32,
+    #[doc = "< Number of tbl8s to allocate."]
+    pub number_tbl8s: u32,
+    #[doc = "< This field is currently unused."]
+    pub flags: ::std::os::raw::c_int,
+}
+extern "C" {
+    #[doc = " Create an LPM object."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   Handle to LPM object on success, NULL otherwise with rte_errno set"]
+    #[doc = "   to an appropriate values."]
+    pub fn rte_lpm6_create(
+        name: *const ::std::os::raw::c_char,
+        socket_id: ::std::os::raw::c_int,
+        config: *const rte_lpm6_config,
+    ) -> *mut rte_lpm6;
+}
+extern "C" {
+    #[doc = " Find an existing LPM object and return a pointer to it."]
+    pub fn rte_lpm6_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_lpm6;
+}
+extern "C" {
+    #[doc = " Free an LPM object."]
+    pub fn rte_lpm6_free(lpm: *mut rte_lpm6);
+}
+extern "C" {
+    #[doc = " Add a rule to the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_lpm6_add(lpm: *mut rte_lpm6, ip: *const u8, depth: u8, next_hop: u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Check if a rule is present in the LPM table,"]
+    #[doc = " and provide its next hop if it is."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   1 if the rule exists, 0 if it does not, a negative value on failure"]
+    pub fn rte_lpm6_is_rule_present(
+        lpm: *mut rte_lpm6,
+        ip: *const u8,
+        depth: u8,
+        next_hop: *mut u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a rule from the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_lpm6_delete(lpm: *mut rte_lpm6, ip: *const u8, depth: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a rule from the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise."]
+    pub fn rte_lpm6_delete_bulk_func(
+        lpm: *mut rte_lpm6,
+        ips: *mut [u8; 16usize],
+        depths: *mut u8,
+        n: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete all rules from the LPM table."]
+    pub fn rte_lpm6_delete_all(lpm: *mut rte_lpm6);
+}
+extern "C" {
+    #[doc = " Lookup an IP into the LPM table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, -ENOENT on lookup miss, 0 on lookup hit"]
+    pub fn rte_lpm6_lookup(lpm: *const rte_lpm6, ip: *const u8, next_hop: *mut u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Lookup multiple IP addresses in an LPM table."]
+    #[doc = ""]
+    #[doc = " @param next_hops"]
+    #[doc = "   Next hop of the most specific rule found for IP,"]
+    #[doc = "   or -1 if the lookup of the IP missed."]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, otherwise 0"]
+    pub fn rte_lpm6_lookup_bulk_func(
+        lpm: *const rte_lpm6,
+        ips: *mut [u8; 16usize],
+        next_hops: *mut i32,
+        n: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rib {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rib_node {
+    _unused: [u8; 0],
+}
+pub mod rte_rib_get_nxt_flag {
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " flag to get all subroutes in a RIB tree"]
+    pub const RTE_RIB_GET_NXT_ALL: Type = 0;
+    #[doc = " flag to get first matched subroutes in a RIB tree"]
+    pub const RTE_RIB_GET_NXT_COVER: Type = 1;
+}
+extern "C" {
+    #[doc = " Lookup an IP into the RIB structure"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  pointer to struct rte_rib_node on success"]
+    #[doc = "  NULL otherwise"]
+    pub fn rte_rib_lookup(rib: *mut rte_rib, ip: u32) -> *mut rte_rib_node;
+}
+extern "C" {
+    #[doc = " Lookup less specific route into the RIB structure"]
+    pub fn rte_rib_lookup_parent(ent: *mut rte_rib_node) -> *mut rte_rib_node;
+}
+extern "C" {
+    #[doc = " Lookup prefix into the RIB structure"]
+    pub fn rte_rib_lookup_exact(rib: *mut rte_rib, ip: u32, depth: u8) -> *mut rte_rib_node;
+}
+extern "C" {
+    #[doc = " Retrieve next more specific prefix from the RIB"]
+    #[doc = " that is covered by ip/depth supernet in an ascending order"]
+    #[doc = ""]
+    #[doc = " @param last"]
+    #[doc = "  pointer to the last returned prefix to get next prefix"]
+    #[doc = "  or"]
+    #[doc = "  NULL to get first more specific prefix"]
+    #[doc = " @param flag"]
+    #[doc = "  -RTE_RIB_GET_NXT_ALL"]
+    #[doc = "   get all prefixes from subtrie"]
+    #[doc = "  -RTE_RIB_GET_NXT_COVER"]
+    #[doc = "   get only first more specific prefix even if it have more specifics"]
+    pub fn rte_rib_get_nxt(
+        rib: *mut rte_rib,
+        ip: u32,
+        depth: u8,
+        last: *mut rte_rib_node,
+        flag: ::std::os::raw::c_int,
+    ) -> *mut rte_rib_node;
+}
+extern "C" {
+    #[doc = " Get an ip from rte_rib_node"]
+    pub fn rte_rib_get_ip(node: *const rte_rib_node, ip: *mut u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get a depth from rte_rib_node"]
+    pub fn rte_rib_get_depth(node: *const rte_rib_node, depth: *mut u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get nexthop from rte_rib_node"]
+    pub fn rte_rib_get_nh(node: *const rte_rib_node, nh: *mut u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get pointer to the dataplane specific struct"]
+    pub fn rte_fib_get_rib(fib: *mut rte_fib) -> *mut rte_rib;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rib6 {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_rib6_node {
+    _unused: [u8; 0],
+}
+pub mod rte_rib6_get_nxt_flag {
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " flag to get all subroutes in a RIB tree"]
+    pub const RTE_RIB6_GET_NXT_ALL: Type = 0;
+    #[doc = " flag to get first matched subroutes in a RIB tree"]
+    pub const RTE_RIB6_GET_NXT_COVER: Type = 1;
+}
+extern "C" {
+    #[doc = " Lookup an IP into the RIB structure"]
+    pub fn rte_rib6_lookup(rib: *mut rte_rib6, ip: *const u8) -> *mut rte_rib6_node;
+}
+extern "C" {
+    #[doc = " Lookup less specific route into the RIB structure"]
+    pub fn rte_rib6_lookup_parent(ent: *mut rte_rib6_node) -> *mut rte_rib6_node;
+}
+extern "C" {
+    #[doc = " Lookup prefix into the RIB structure"]
+    pub fn rte_rib6_lookup_exact(rib: *mut rte_rib6, ip: *const u8, depth: u8) -> *mut rte_rib6_node;
+}
+extern "C" {
+    #[doc = " Retrieve next more specific prefix from the RIB"]
+    #[doc = " that is covered by ip/depth supernet in an ascending order"]
+    pub fn rte_rib6_get_nxt(
+        rib: *mut rte_rib6,
+        ip: *const u8,
+        depth: u8,
+        last: *mut rte_rib6_node,
+        flag: ::std::os::raw::c_int,
+    ) -> *mut rte_rib6_node;
+}
+extern "C" {
+    #[doc = " Get an ip from rte_rib6_node"]
+    pub fn rte_rib6_get_ip(node: *const rte_rib6_node, ip: *mut u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get a depth from rte_rib6_node"]
+    pub fn rte_rib6_get_depth(node: *const rte_rib6_node, depth: *mut u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get nexthop from rte_rib6_node"]
+    pub fn rte_rib6_get_nh(node: *const rte_rib6_node, nh: *mut u64) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_fib6 {
+    _unused: [u8; 0],
+}
+pub mod rte_fib6_type {
+    #[doc = " Type of FIB struct"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< RIB6 tree based FIB"]
+    pub const RTE_FIB6_DUMMY: Type = 0;
+    #[doc = "< TRIE based fib"]
+    pub const RTE_FIB6_TRIE: Type = 1;
+    pub const RTE_FIB6_TYPE_MAX: Type = 2;
+}
+pub mod rte_fib_trie_nh_sz {
+    #[doc = " Size of nexthop (1 << nh_sz) bits"]
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_FIB6_TRIE_2B: Type = 1;
+    pub const RTE_FIB6_TRIE_4B: Type = 2;
+    pub const RTE_FIB6_TRIE_8B: Type = 3;
+}
+#[doc = " FIB configuration structure"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_fib6_conf {
+    pub type_: rte_fib6_type::Type,
+    #[doc = " Default value returned on lookup if there is no route"]
+    pub default_nh: u64,
+    pub max_routes: ::std::os::raw::c_int,
+    pub __bindgen_anon_1: rte_fib6_conf__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_fib6_conf__bindgen_ty_1 {
+    pub trie: rte_fib6_conf__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_fib6_conf__bindgen_ty_1__bindgen_ty_1 {
+    pub nh_sz: rte_fib_trie_nh_sz::Type,
+    pub num_tbl8: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_fib6_conf() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_fib6_conf>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_fib6_conf))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_fib6_conf>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_fib6_conf))
+    );
+}
+impl Default for rte_fib6_conf__bindgen_ty_1__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_fib6_conf__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_fib6_conf {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Create FIB"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  Handle to FIB object on success"]
+    #[doc = "  NULL otherwise with rte_errno set to an appropriate values."]
+    pub fn rte_fib6_create(
+        name: *const ::std::os::raw::c_char,
+        socket_id: ::std::os::raw::c_int,
+        conf: *mut rte_fib6_conf,
+    ) -> *mut rte_fib6;
+}
+extern "C" {
+    #[doc = " Find an existing FIB object and return a pointer to it."]
+    pub fn rte_fib6_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_fib6;
+}
+extern "C" {
+    #[doc = " Free an FIB object."]
+    pub fn rte_fib6_free(fib: *mut rte_fib6);
+}
+extern "C" {
+    #[doc = " Add a route to the FIB."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_fib6_add(fib: *mut rte_fib6, ip: *const u8, depth: u8, next_hop: u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete a rule from the FIB."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, negative value otherwise"]
+    pub fn rte_fib6_delete(fib: *mut rte_fib6, ip: *const u8, depth: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Lookup multiple IP addresses in the FIB."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, otherwise 0"]
+    pub fn rte_fib6_lookup_bulk(
+        fib: *mut rte_fib6,
+        ips: *mut [u8; 16usize],
+        next_hops: *mut u64,
+        n: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get pointer to the RIB6"]
+    pub fn rte_fib6_get_rib(fib: *mut rte_fib6) -> *mut rte_rib6;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_acl_ctx {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_acl_field_types {
+    pub u8_: u8,
+    pub u16_: u16,
+    pub u32_: u32,
+    pub u64_: u64,
+}
+impl Default for rte_acl_field_types {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod _bindgen_ty_15 {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_ACL_FIELD_TYPE_MASK: Type = 0;
+    pub const RTE_ACL_FIELD_TYPE_RANGE: Type = 1;
+    pub const RTE_ACL_FIELD_TYPE_BITMASK: Type = 2;
+}
+#[doc = " ACL Field definition."]
+#[doc = " Each field in the ACL rule has an associate definition."]
+#[doc = " It defines the type of field, its size, its offset in the input buffer,"]
+#[doc = " the field index, and the input index."]
+#[doc = " For performance reasons, the inner loop of the search function is unrolled"]
+#[doc = " to process four input bytes at a time. This requires the input to be grouped"]
+#[doc = " into sets of 4 consecutive bytes. The loop processes the first input byte as"]
+#[doc = " part of the setup and then subsequent bytes must be in groups of 4"]
+#[doc = " consecutive bytes."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct rte_acl_field_def {
+    #[doc = "< type - RTE_ACL_FIELD_TYPE_*."]
+    pub type_: u8,
+    #[doc = "< size of field 1,2,4, or 8."]
+    pub size: u8,
+    #[doc = "< index of field inside the rule."]
+    pub field_index: u8,
+    #[doc = "< 0-N input index."]
+    pub input_index: u8,
+    #[doc = "< offset to start of field."]
+    pub offset: u32,
+}
+#[doc = " ACL build configuration."]
+#[doc = " Defines the fields of an ACL trie and number of categories to build with."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_acl_config {
+    #[doc = "< Number of categories to build with."]
+    pub num_categories: u32,
+    #[doc = "< Number of field definitions."]
+    pub num_fields: u32,
+    pub defs: [rte_acl_field_def; 64usize],
+    #[doc = "< max memory limit for internal run-time structures."]
+    pub max_size: usize,
+}
+#[test]
+fn bindgen_test_layout_rte_acl_config() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_acl_config>(),
+        528usize,
+        concat!("Size of: ", stringify!(rte_acl_config))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_acl_config>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_acl_config))
+    );
+}
+impl Default for rte_acl_config {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Defines the value of a field for a rule."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_acl_field {
+    #[doc = "< a 1,2,4, or 8 byte value of the field."]
+    pub value: rte_acl_field_types,
+    #[doc = "< depending on field type:"]
+    #[doc = " mask -> 1.2.3.4/32 value=0x1020304, mask_range=32,"]
+    #[doc = " range -> 0 : 65535 value=0, mask_range=65535,"]
+    #[doc = " bitmask -> 0x06/0xff value=6, mask_range=0xff."]
+    pub mask_range: rte_acl_field_types,
+}
+#[test]
+fn bindgen_test_layout_rte_acl_field() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_acl_field>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_acl_field))
+    );
+}
+impl Default for rte_acl_field {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod _bindgen_ty_16 {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const RTE_ACL_TYPE_SHIFT: Type = 29;
+    pub const RTE_ACL_MAX_INDEX: Type = 536870911;
+    pub const RTE_ACL_MAX_PRIORITY: Type = 536870911;
+    pub const RTE_ACL_MIN_PRIORITY: Type = 1;
+}
+#[doc = " Miscellaneous data for ACL rule."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct rte_acl_rule_data {
+    #[doc = "< Mask of categories for that rule."]
+    pub category_mask: u32,
+    #[doc = "< Priority for that rule."]
+    pub priority: i32,
+    #[doc = "< Associated with the rule user data."]
+    pub userdata: u32,
+}
+#[repr(C)]
+pub struct rte_acl_rule {
+    pub data: rte_acl_rule_data,
+    pub field: __IncompleteArrayField<rte_acl_field>,
+}
+#[test]
+fn bindgen_test_layout_rte_acl_rule() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_acl_rule>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_acl_rule))
+    );
+}
+#[doc = " Parameters used when creating the ACL context."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct rte_acl_param {
+    #[doc = "< Name of the ACL context."]
+    pub name: *const ::std::os::raw::c_char,
+    #[doc = "< Socket ID to allocate memory for."]
+    pub socket_id: ::std::os::raw::c_int,
+    #[doc = "< Size of each ACL rule."]
+    pub rule_size: u32,
+    #[doc = "< Maximum number of ACL rules."]
+    pub max_rule_num: u32,
+}
+impl Default for rte_acl_param {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Create a new ACL context."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   Pointer to ACL context structure that is used in future ACL"]
+    #[doc = "   operations, or NULL on error, with error code set in rte_errno."]
+    pub fn rte_acl_create(param: *const rte_acl_param) -> *mut rte_acl_ctx;
+}
+extern "C" {
+    #[doc = " Find an existing ACL context object and return a pointer to it."]
+    pub fn rte_acl_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_acl_ctx;
+}
+extern "C" {
+    #[doc = " De-allocate all memory used by ACL context."]
+    pub fn rte_acl_free(ctx: *mut rte_acl_ctx);
+}
+extern "C" {
+    #[doc = " Add rules to an existing ACL context."]
+    #[doc = " This function is not multi-thread safe."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -ENOMEM if there is no space in the ACL context for these rules."]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - Zero if operation completed successfully."]
+    pub fn rte_acl_add_rules(ctx: *mut rte_acl_ctx, rules: *const rte_acl_rule, num: u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete all rules from the ACL context."]
+    #[doc = " This function is not multi-thread safe."]
+    #[doc = " Note that internal run-time structures are not affected."]
+    pub fn rte_acl_reset_rules(ctx: *mut rte_acl_ctx);
+}
+extern "C" {
+    #[doc = " Analyze set of rules and build required internal run-time structures."]
+    #[doc = " This function is not multi-thread safe."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -ENOMEM if couldn't allocate enough memory."]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - Negative error code if operation failed."]
+    #[doc = "   - Zero if operation completed successfully."]
+    pub fn rte_acl_build(ctx: *mut rte_acl_ctx, cfg: *const rte_acl_config) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete all rules from the ACL context and"]
+    #[doc = " destroy all internal run-time structures."]
+    #[doc = " This function is not multi-thread safe."]
+    pub fn rte_acl_reset(ctx: *mut rte_acl_ctx);
+}
+pub mod rte_acl_classify_alg {
+    #[doc = "  Available implementations of ACL classify."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< default classify method."]
+    pub const RTE_ACL_CLASSIFY_DEFAULT: Type = 0;
+    #[doc = "< generic implementation."]
+    pub const RTE_ACL_CLASSIFY_SCALAR: Type = 1;
+    #[doc = "< requires SSE4.1 support."]
+    pub const RTE_ACL_CLASSIFY_SSE: Type = 2;
+    #[doc = "< requires AVX2 support."]
+    pub const RTE_ACL_CLASSIFY_AVX2: Type = 3;
+    #[doc = "< requires NEON support."]
+    pub const RTE_ACL_CLASSIFY_NEON: Type = 4;
+    #[doc = "< requires ALTIVEC support."]
+    pub const RTE_ACL_CLASSIFY_ALTIVEC: Type = 5;
+    #[doc = "< requires AVX512 support."]
+    pub const RTE_ACL_CLASSIFY_AVX512X16: Type = 6;
+    #[doc = "< requires AVX512 support."]
+    pub const RTE_ACL_CLASSIFY_AVX512X32: Type = 7;
+}
+extern "C" {
+    #[doc = " Perform search for a matching ACL rule for each input data buffer."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   zero on successful completion."]
+    #[doc = "   -EINVAL for incorrect arguments."]
+    pub fn rte_acl_classify(
+        ctx: *const rte_acl_ctx,
+        data: *mut *const u8,
+        results: *mut u32,
+        num: u32,
+        categories: u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Perform search using specified algorithm for a matching ACL rule for"]
+    #[doc = " each input data buffer."]
+    pub fn rte_acl_classify_alg(
+        ctx: *const rte_acl_ctx,
+        data: *mut *const u8,
+        results: *mut u32,
+        num: u32,
+        categories: u32,
+        alg: rte_acl_classify_alg::Type,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Set the classify algorithm for the ACL context."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -EINVAL if the parameters are invalid."]
+    #[doc = "   - -ENOTSUP requested algorithm is not supported."]
+    #[doc = "   - Zero if operation completed successfully."]
+    pub fn rte_acl_set_ctx_classify(ctx: *mut rte_acl_ctx, alg: rte_acl_classify_alg::Type) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Dump an ACL context structure to the console."]
+    pub fn rte_acl_dump(ctx: *const rte_acl_ctx);
+}
+extern "C" {
+    #[doc = " Dump all ACL context structures to the console."]
+    pub fn rte_acl_list_dump();
+}
+pub mod rte_crypto_cipher_algorithm {
+    #[doc = " Symmetric Cipher Algorithms"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< NULL cipher algorithm. No mode applies to the NULL algorithm."]
+    pub const RTE_CRYPTO_CIPHER_NULL: Type = 1;
+    #[doc = "< Triple DES algorithm in CBC mode"]
+    pub const RTE_CRYPTO_CIPHER_3DES_CBC: Type = 2;
+    #[doc = "< Triple DES algorithm in CTR mode"]
+    pub const RTE_CRYPTO_CIPHER_3DES_CTR: Type = 3;
+    #[doc = "< Triple DES algorithm in ECB mode"]
+    pub const RTE_CRYPTO_CIPHER_3DES_ECB: Type = 4;
+    #[doc = "< AES algorithm in CBC mode"]
+    pub const RTE_CRYPTO_CIPHER_AES_CBC: Type = 5;
+    #[doc = "< AES algorithm in Counter mode"]
+    pub const RTE_CRYPTO_CIPHER_AES_CTR: Type = 6;
+    #[doc = "< AES algorithm in ECB mode"]
+    pub const RTE_CRYPTO_CIPHER_AES_ECB: Type = 7;
+    #[doc = "< AES algorithm in F8 mode"]
+    pub const RTE_CRYPTO_CIPHER_AES_F8: Type = 8;
+    #[doc = "< AES algorithm, XTS mode"]
+    pub const RTE_CRYPTO_CIPHER_AES_XTS: Type = 9;
+    #[doc = "< (A)RC4 cipher algorithm"]
+    pub const RTE_CRYPTO_CIPHER_ARC4: Type = 10;
+    #[doc = "< KASUMI algorithm in F8 mode"]
+    pub const RTE_CRYPTO_CIPHER_KASUMI_F8: Type = 11;
+    #[doc = "< SNOW 3G algorithm in UEA2 mode"]
+    pub const RTE_CRYPTO_CIPHER_SNOW3G_UEA2: Type = 12;
+    #[doc = "< ZUC algorithm in EEA3 mode"]
+    pub const RTE_CRYPTO_CIPHER_ZUC_EEA3: Type = 13;
+    #[doc = "< DES algorithm in CBC mode"]
+    pub const RTE_CRYPTO_CIPHER_DES_CBC: Type = 14;
+    #[doc = "< AES algorithm using modes required by DOCSIS Baseline Privacy Plus Spec."]
+    pub const RTE_CRYPTO_CIPHER_AES_DOCSISBPI: Type = 15;
+    #[doc = "< DES algorithm using modes required by DOCSIS Baseline Privacy Plus Spec."]
+    pub const RTE_CRYPTO_CIPHER_DES_DOCSISBPI: Type = 16;
+    pub const RTE_CRYPTO_CIPHER_LIST_END: Type = 17;
+}
+pub mod rte_crypto_cipher_operation {
+    #[doc = " Symmetric Cipher Direction"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Encrypt cipher operation"]
+    pub const RTE_CRYPTO_CIPHER_OP_ENCRYPT: Type = 0;
+    #[doc = "< Decrypt cipher operation"]
+    pub const RTE_CRYPTO_CIPHER_OP_DECRYPT: Type = 1;
+}
+#[doc = " Symmetric Cipher Setup Data."]
+#[doc = ""]
+#[doc = " This structure contains data relating to Cipher (Encryption and Decryption)"]
+#[doc = "  use to create a session."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_cipher_xform {
+    #[doc = "< This parameter determines if the cipher operation is an encrypt or"]
+    #[doc = " a decrypt operation."]
+    pub op: rte_crypto_cipher_operation::Type,
+    #[doc = "< Cipher algorithm"]
+    pub algo: rte_crypto_cipher_algorithm::Type,
+    pub key: rte_crypto_cipher_xform__bindgen_ty_1,
+    pub iv: rte_crypto_cipher_xform__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_cipher_xform__bindgen_ty_1 {
+    #[doc = "< pointer to key data"]
+    pub data: *const u8,
+    #[doc = "< key length in bytes"]
+    pub length: u16,
+}
+impl Default for rte_crypto_cipher_xform__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_cipher_xform__bindgen_ty_2 {
+    #[doc = "< Starting point for Initialisation Vector or Counter,"]
+    #[doc = " specified as number of bytes from start of crypto"]
+    #[doc = " operation (rte_crypto_op)."]
+    pub offset: u16,
+    #[doc = "< Length of valid IV data."]
+    pub length: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_cipher_xform() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_cipher_xform>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_crypto_cipher_xform))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_cipher_xform>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_cipher_xform))
+    );
+}
+impl Default for rte_crypto_cipher_xform {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_crypto_auth_algorithm {
+    #[doc = " Symmetric Authentication / Hash Algorithms"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< NULL hash algorithm."]
+    pub const RTE_CRYPTO_AUTH_NULL: Type = 1;
+    #[doc = "< AES-CBC-MAC algorithm. Only 128-bit keys are supported."]
+    pub const RTE_CRYPTO_AUTH_AES_CBC_MAC: Type = 2;
+    #[doc = "< AES CMAC algorithm."]
+    pub const RTE_CRYPTO_AUTH_AES_CMAC: Type = 3;
+    #[doc = "< AES GMAC algorithm."]
+    pub const RTE_CRYPTO_AUTH_AES_GMAC: Type = 4;
+    #[doc = "< AES XCBC algorithm."]
+    pub const RTE_CRYPTO_AUTH_AES_XCBC_MAC: Type = 5;
+    #[doc = "< KASUMI algorithm in F9 mode."]
+    pub const RTE_CRYPTO_AUTH_KASUMI_F9: Type = 6;
+    #[doc = "< MD5 algorithm"]
+    pub const RTE_CRYPTO_AUTH_MD5: Type = 7;
+    #[doc = "< HMAC using MD5 algorithm"]
+    pub const RTE_CRYPTO_AUTH_MD5_HMAC: Type = 8;
+    #[doc = "< 160 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA1: Type = 9;
+    #[doc = "< HMAC using 160 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA1_HMAC: Type = 10;
+    #[doc = "< 224 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA224: Type = 11;
+    #[doc = "< HMAC using 224 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA224_HMAC: Type = 12;
+    #[doc = "< 256 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA256: Type = 13;
+    #[doc = "< HMAC using 256 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA256_HMAC: Type = 14;
+    #[doc = "< 384 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA384: Type = 15;
+    #[doc = "< HMAC using 384 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA384_HMAC: Type = 16;
+    #[doc = "< 512 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA512: Type = 17;
+    #[doc = "< HMAC using 512 bit SHA algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA512_HMAC: Type = 18;
+    #[doc = "< SNOW 3G algorithm in UIA2 mode."]
+    pub const RTE_CRYPTO_AUTH_SNOW3G_UIA2: Type = 19;
+    #[doc = "< ZUC algorithm in EIA3 mode"]
+    pub const RTE_CRYPTO_AUTH_ZUC_EIA3: Type = 20;
+    #[doc = "< 224 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_224: Type = 21;
+    #[doc = "< HMAC using 224 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_224_HMAC: Type = 22;
+    #[doc = "< 256 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_256: Type = 23;
+    #[doc = "< HMAC using 256 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_256_HMAC: Type = 24;
+    #[doc = "< 384 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_384: Type = 25;
+    #[doc = "< HMAC using 384 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_384_HMAC: Type = 26;
+    #[doc = "< 512 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_512: Type = 27;
+    #[doc = "< HMAC using 512 bit SHA3 algorithm."]
+    pub const RTE_CRYPTO_AUTH_SHA3_512_HMAC: Type = 28;
+    pub const RTE_CRYPTO_AUTH_LIST_END: Type = 29;
+}
+pub mod rte_crypto_auth_operation {
+    #[doc = " Symmetric Authentication / Hash Operations"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Verify authentication digest"]
+    pub const RTE_CRYPTO_AUTH_OP_VERIFY: Type = 0;
+    #[doc = "< Generate authentication digest"]
+    pub const RTE_CRYPTO_AUTH_OP_GENERATE: Type = 1;
+}
+#[doc = " Authentication / Hash transform data."]
+#[doc = ""]
+#[doc = " This structure contains data relating to an authentication/hash crypto"]
+#[doc = " transforms. The fields op, algo and digest_length are common to all"]
+#[doc = " authentication transforms and MUST be set."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_auth_xform {
+    #[doc = "< Authentication operation type"]
+    pub op: rte_crypto_auth_operation::Type,
+    #[doc = "< Authentication algorithm selection"]
+    pub algo: rte_crypto_auth_algorithm::Type,
+    pub key: rte_crypto_auth_xform__bindgen_ty_1,
+    pub iv: rte_crypto_auth_xform__bindgen_ty_2,
+    #[doc = "< Length of the digest to be returned."]
+    pub digest_length: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_auth_xform__bindgen_ty_1 {
+    #[doc = "< pointer to key data"]
+    pub data: *const u8,
+    #[doc = "< key length in bytes"]
+    pub length: u16,
+}
+impl Default for rte_crypto_auth_xform__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_auth_xform__bindgen_ty_2 {
+    #[doc = "< Starting point for Initialisation Vector or Counter,"]
+    #[doc = " specified as number of bytes from start of crypto"]
+    #[doc = " operation (rte_crypto_op)."]
+    pub offset: u16,
+    #[doc = "< Length of valid IV data."]
+    pub length: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_auth_xform() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_auth_xform>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_crypto_auth_xform))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_auth_xform>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_auth_xform))
+    );
+}
+impl Default for rte_crypto_auth_xform {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_crypto_aead_algorithm {
+    #[doc = " Symmetric AEAD Algorithms"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< AES algorithm in CCM mode."]
+    pub const RTE_CRYPTO_AEAD_AES_CCM: Type = 1;
+    #[doc = "< AES algorithm in GCM mode."]
+    pub const RTE_CRYPTO_AEAD_AES_GCM: Type = 2;
+    #[doc = "< Chacha20 cipher with poly1305 authenticator"]
+    pub const RTE_CRYPTO_AEAD_CHACHA20_POLY1305: Type = 3;
+    pub const RTE_CRYPTO_AEAD_LIST_END: Type = 4;
+}
+pub mod rte_crypto_aead_operation {
+    #[doc = " Symmetric AEAD Operations"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Encrypt and generate digest"]
+    pub const RTE_CRYPTO_AEAD_OP_ENCRYPT: Type = 0;
+    #[doc = "< Verify digest and decrypt"]
+    pub const RTE_CRYPTO_AEAD_OP_DECRYPT: Type = 1;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_aead_xform {
+    #[doc = "< AEAD operation type"]
+    pub op: rte_crypto_aead_operation::Type,
+    #[doc = "< AEAD algorithm selection"]
+    pub algo: rte_crypto_aead_algorithm::Type,
+    pub key: rte_crypto_aead_xform__bindgen_ty_1,
+    pub iv: rte_crypto_aead_xform__bindgen_ty_2,
+    #[doc = "< Length of the digest to be returned."]
+    pub digest_length: u16,
+    #[doc = "< The length of the additional authenticated data (AAD) in bytes."]
+    pub aad_length: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_aead_xform__bindgen_ty_1 {
+    #[doc = "< pointer to key data"]
+    pub data: *const u8,
+    #[doc = "< key length in bytes"]
+    pub length: u16,
+}
+impl Default for rte_crypto_aead_xform__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_aead_xform__bindgen_ty_2 {
+    #[doc = "< Starting point for Initialisation Vector or Counter,"]
+    #[doc = " specified as number of bytes from start of crypto"]
+    #[doc = " operation (rte_crypto_op)."]
+    pub offset: u16,
+    #[doc = "< Length of valid IV data."]
+    pub length: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_aead_xform() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_aead_xform>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_crypto_aead_xform))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_aead_xform>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_aead_xform))
+    );
+}
+impl Default for rte_crypto_aead_xform {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_crypto_sym_xform_type {
+    #[doc = " Crypto transformation types"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< No xform specified"]
+    pub const RTE_CRYPTO_SYM_XFORM_NOT_SPECIFIED: Type = 0;
+    #[doc = "< Authentication xform"]
+    pub const RTE_CRYPTO_SYM_XFORM_AUTH: Type = 1;
+    #[doc = "< Cipher xform"]
+    pub const RTE_CRYPTO_SYM_XFORM_CIPHER: Type = 2;
+    #[doc = "< AEAD xform"]
+    pub const RTE_CRYPTO_SYM_XFORM_AEAD: Type = 3;
+}
+#[doc = " Symmetric crypto transform structure."]
+#[doc = ""]
+#[doc = " This is used to specify the crypto transforms required, multiple transforms"]
+#[doc = " can be chained together to specify a chain transforms such as authentication"]
+#[doc = " then cipher, or cipher then authentication. Each transform structure can"]
+#[doc = " hold a single transform, the type field is used to specify which transform"]
+#[doc = " is contained within the union"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_crypto_sym_xform {
+    #[doc = "< next xform in chain"]
+    pub next: *mut rte_crypto_sym_xform,
+    #[doc = "< xform type"]
+    pub type_: rte_crypto_sym_xform_type::Type,
+    pub __bindgen_anon_1: rte_crypto_sym_xform__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_crypto_sym_xform__bindgen_ty_1 {
+    #[doc = "< Authentication / hash xform"]
+    pub auth: rte_crypto_auth_xform,
+    #[doc = "< Cipher xform"]
+    pub cipher: rte_crypto_cipher_xform,
+    #[doc = "< AEAD xform"]
+    pub aead: rte_crypto_aead_xform,
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_sym_xform() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_sym_xform>(),
+        48usize,
+        concat!("Size of: ", stringify!(rte_crypto_sym_xform))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_sym_xform>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_sym_xform))
+    );
+}
+impl Default for rte_crypto_sym_xform__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_crypto_sym_xform {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_cryptodev_sym_session {
+    _unused: [u8; 0],
+}
+#[doc = " Symmetric Cryptographic Operation."]
+#[doc = ""]
+#[doc = " This structure contains data relating to performing symmetric cryptographic"]
+#[doc = " processing on a referenced mbuf data buffer."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_crypto_sym_op {
+    #[doc = "< source mbuf"]
+    pub m_src: *mut rte_mbuf,
+    #[doc = "< destination mbuf"]
+    pub m_dst: *mut rte_mbuf,
+    pub __bindgen_anon_1: rte_crypto_sym_op__bindgen_ty_1,
+    pub __bindgen_anon_2: rte_crypto_sym_op__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_crypto_sym_op__bindgen_ty_1 {
+    #[doc = "< Handle for the initialised session context"]
+    pub session: *mut rte_cryptodev_sym_session,
+    #[doc = "< Session-less API crypto operation parameters"]
+    pub xform: *mut rte_crypto_sym_xform,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_crypto_sym_op__bindgen_ty_2 {
+    pub aead: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1,
+    pub __bindgen_anon_1: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1 {
+    pub data: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1,
+    pub digest: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_2,
+    pub aad: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_3,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = "< Starting point for AEAD processing, specified as number of bytes from start of packet in source buffer."]
+    pub offset: u32,
+    #[doc = "< The message length, in bytes, of the source buffer on which the AEAD processing will be computed."]
+    pub length: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_2 {
+    #[doc = "< This points to the location where the digest result should be inserted (in the case of digest generation) or where the purported digest exists (in the case of digest verification)."]
+    pub data: *mut u8,
+    #[doc = "< Physical address of digest"]
+    pub phys_addr: rte_iova_t,
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_2 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_3 {
+    #[doc = "< Pointer to Additional Authenticated Data (AAD) needed for authenticated cipher mechanisms (CCM and GCM)"]
+    pub data: *mut u8,
+    #[doc = "< physical address"]
+    pub phys_addr: rte_iova_t,
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1__bindgen_ty_3 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2 {
+    pub cipher: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1,
+    pub auth: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1 {
+    pub data: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = "< Starting point for cipher processing, specified as number of bytes from start of packet in source buffer."]
+    pub offset: u32,
+    #[doc = "< The message length, in bytes, of the source buffer on which the cipher processing will be computed."]
+    pub length: u32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2 {
+    pub data: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1,
+    pub digest: rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2__bindgen_ty_1 {
+    #[doc = "< Starting point for hash processing, specified as number of bytes from start of packet in source buffer."]
+    pub offset: u32,
+    #[doc = "< The message length, in bytes, of the source buffer on which the hash processing will be computed."]
+    pub length: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2 {
+    #[doc = "< This points to the location where the digest result should be inserted (in the case of digest generation) or where the purported digest exists (in the case of digest verification)."]
+    pub data: *mut u8,
+    #[doc = "< Physical address of digest"]
+    pub phys_addr: rte_iova_t,
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2__bindgen_ty_2 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_sym_op() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_sym_op>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_crypto_sym_op))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_sym_op>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_sym_op))
+    );
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_crypto_sym_op__bindgen_ty_2 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for rte_crypto_sym_op {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_crypto_op_type {
+    #[doc = " Crypto operation types"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Undefined operation type"]
+    pub const RTE_CRYPTO_OP_TYPE_UNDEFINED: Type = 0;
+    #[doc = "< Symmetric operation"]
+    pub const RTE_CRYPTO_OP_TYPE_SYMMETRIC: Type = 1;
+    #[doc = "< Asymmetric operation"]
+    pub const RTE_CRYPTO_OP_TYPE_ASYMMETRIC: Type = 2;
+}
+pub mod rte_crypto_op_status {
+    #[doc = " Status of crypto operation"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Operation completed successfully"]
+    pub const RTE_CRYPTO_OP_STATUS_SUCCESS: Type = 0;
+    #[doc = "< Operation has not yet been processed by a crypto device"]
+    pub const RTE_CRYPTO_OP_STATUS_NOT_PROCESSED: Type = 1;
+    #[doc = "< Authentication verification failed"]
+    pub const RTE_CRYPTO_OP_STATUS_AUTH_FAILED: Type = 2;
+    #[doc = "< Operation failed due to invalid session arguments"]
+    pub const RTE_CRYPTO_OP_STATUS_INVALID_SESSION: Type = 3;
+    #[doc = "< Operation failed due to invalid arguments in request"]
+    pub const RTE_CRYPTO_OP_STATUS_INVALID_ARGS: Type = 4;
+    #[doc = "< Error handling operation"]
+    pub const RTE_CRYPTO_OP_STATUS_ERROR: Type = 5;
+}
+pub mod rte_crypto_op_sess_type {
+    #[doc = " Crypto operation session type. This is used to specify whether a crypto"]
+    #[doc = " operation has session structure attached for immutable parameters or if all"]
+    #[doc = " operation information is included in the operation data structure."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Session based crypto operation"]
+    pub const RTE_CRYPTO_OP_WITH_SESSION: Type = 0;
+    #[doc = "< Session-less crypto operation"]
+    pub const RTE_CRYPTO_OP_SESSIONLESS: Type = 1;
+    #[doc = "< Security session crypto operation"]
+    pub const RTE_CRYPTO_OP_SECURITY_SESSION: Type = 2;
+}
+#[doc = " Cryptographic Operation."]
+#[doc = ""]
+#[doc = " This structure contains data relating to performing cryptographic"]
+#[doc = " operations. This operation structure is used to contain any operation which"]
+#[doc = " is supported by the cryptodev API, PMDs should check the type parameter to"]
+#[doc = " verify that the operation is a support function of the device. Crypto"]
+#[doc = " operations are enqueued and dequeued in crypto PMDs using the"]
+#[doc = " rte_cryptodev_enqueue_burst() / rte_cryptodev_dequeue_burst() ."]
+#[repr(C)]
+pub struct rte_crypto_op {
+    pub __bindgen_anon_1: rte_crypto_op__bindgen_ty_1,
+    #[doc = "< crypto operation mempool which operation is allocated from"]
+    pub mempool: *mut rte_mempool,
+    #[doc = "< physical address of crypto operation"]
+    pub phys_addr: rte_iova_t,
+    pub __bindgen_anon_2: rte_crypto_op__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_crypto_op__bindgen_ty_1 {
+    pub raw: u64,
+    pub __bindgen_anon_1: rte_crypto_op__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_crypto_op__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = "< operation type"]
+    pub type_: u8,
+    #[doc = "< operation status - this is reset to"]
+    #[doc = " RTE_CRYPTO_OP_STATUS_NOT_PROCESSED on allocation"]
+    #[doc = " from mempool and will be set to"]
+    #[doc = " RTE_CRYPTO_OP_STATUS_SUCCESS after crypto operation"]
+    #[doc = " is successfully processed by a crypto PMD"]
+    pub status: u8,
+    #[doc = "< operation session type"]
+    pub sess_type: u8,
+    #[doc = "< Reserved bytes to fill 64 bits for"]
+    #[doc = " future additions"]
+    pub reserved: [u8; 3usize],
+    #[doc = "< Offset to indicate start of private data (if any)."]
+    #[doc = " The offset is counted from the start of the"]
+    #[doc = " rte_crypto_op including IV."]
+    #[doc = " The private data may be used by the application"]
+    #[doc = " to store information which should remain untouched"]
+    #[doc = " in the library/driver"]
+    pub private_data_offset: u16,
+}
+#[repr(C)]
+pub struct rte_crypto_op__bindgen_ty_2 {
+    #[doc = "< Symmetric operation parameters"]
+    pub sym: __IncompleteArrayField<rte_crypto_sym_op>,
+}
+#[test]
+fn bindgen_test_layout_rte_crypto_op() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_crypto_op>(),
+        24usize,
+        concat!("Size of: ", stringify!(rte_crypto_op))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_crypto_op>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_crypto_op))
+    );
+}
+impl Default for rte_crypto_op__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Creates a crypto operation pool"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - On success pointer to mempool"]
+    #[doc = "  - On failure NULL"]
+    pub fn rte_crypto_op_pool_create(
+        name: *const ::std::os::raw::c_char,
+        type_: rte_crypto_op_type::Type,
+        nb_elts: ::std::os::raw::c_uint,
+        cache_size: ::std::os::raw::c_uint,
+        priv_size: u16,
+        socket_id: ::std::os::raw::c_int,
+    ) -> *mut rte_mempool;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_cryptodev_capabilities {
+    _unused: [u8; 0],
+}
+#[doc = "  Crypto device information"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_cryptodev_info {
+    #[doc = "< Driver name."]
+    pub driver_name: *const ::std::os::raw::c_char,
+    #[doc = "< Driver identifier"]
+    pub driver_id: u8,
+    #[doc = "< Generic device information."]
+    pub device: *mut rte_device,
+    #[doc = "< Feature flags exposes HW/SW features for the given device"]
+    pub feature_flags: u64,
+    #[doc = "< Array of devices supported capabilities"]
+    pub capabilities: *const rte_cryptodev_capabilities,
+    #[doc = "< Maximum number of queues pairs supported by device."]
+    pub max_nb_queue_pairs: ::std::os::raw::c_uint,
+    #[doc = "< Minimum mbuf headroom required by device"]
+    pub min_mbuf_headroom_req: u16,
+    #[doc = "< Minimum mbuf tailroom required by device"]
+    pub min_mbuf_tailroom_req: u16,
+    pub sym: rte_cryptodev_info__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_cryptodev_info__bindgen_ty_1 {
+    #[doc = "< Maximum number of sessions supported by device."]
+    #[doc = " If 0, the device does not have any limitation in"]
+    #[doc = " number of sessions that can be used."]
+    pub max_nb_sessions: ::std::os::raw::c_uint,
+}
+#[test]
+fn bindgen_test_layout_rte_cryptodev_info() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_cryptodev_info>(),
+        56usize,
+        concat!("Size of: ", stringify!(rte_cryptodev_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_cryptodev_info>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_cryptodev_info))
+    );
+}
+impl Default for rte_cryptodev_info {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Crypto device configuration structure"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_cryptodev_config {
+    #[doc = "< Socket to allocate resources on"]
+    pub socket_id: ::std::os::raw::c_int,
+    #[doc = "< Number of queue pairs to configure on device"]
+    pub nb_queue_pairs: u16,
+    #[doc = "< Feature flags to be disabled. Only the following features are"]
+    #[doc = " allowed to be disabled,"]
+    #[doc = "  - RTE_CRYPTODEV_FF_SYMMETRIC_CRYPTO"]
+    #[doc = "  - RTE_CRYPTODEV_FF_ASYMMETRIC_CRYPTO"]
+    #[doc = "  - RTE_CRYTPODEV_FF_SECURITY"]
+    pub ff_disable: u64,
+}
+#[doc = " Crypto device queue pair configuration structure."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_cryptodev_qp_conf {
+    #[doc = "< Number of descriptors per queue pair"]
+    pub nb_descriptors: u32,
+    #[doc = "< The sessions mempool"]
+    pub mp_session: *mut rte_mempool,
+    #[doc = "< The private session mempool"]
+    pub mp_session_private: *mut rte_mempool,
+}
+impl Default for rte_cryptodev_qp_conf {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Crypto Device statistics"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_cryptodev_stats {
+    #[doc = "< Count of all operations enqueued"]
+    pub enqueued_count: u64,
+    #[doc = "< Count of all operations dequeued"]
+    pub dequeued_count: u64,
+    #[doc = "< Total error count on operations enqueued"]
+    pub enqueue_err_count: u64,
+    #[doc = "< Total error count on operations dequeued"]
+    pub dequeue_err_count: u64,
+}
+extern "C" {
+    #[doc = " Get the device identifier for the named crypto device."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Returns crypto device identifier on success."]
+    #[doc = "   - Return -1 on failure to find named crypto device."]
+    pub fn rte_cryptodev_get_dev_id(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get the crypto device name given a device identifier."]
+    pub fn rte_cryptodev_name_get(dev_id: u8) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " Get the total number of crypto devices that have been successfully"]
+    #[doc = " initialised."]
+    pub fn rte_cryptodev_count() -> u8;
+}
+extern "C" {
+    #[doc = " Get number of crypto device defined type."]
+    pub fn rte_cryptodev_device_count_by_driver(driver_id: u8) -> u8;
+}
+extern "C" {
+    #[doc = " Get number and identifiers of attached crypto devices that"]
+    #[doc = " use the same crypto driver."]
+    pub fn rte_cryptodev_devices_get(
+        driver_name: *const ::std::os::raw::c_char,
+        devices: *mut u8,
+        nb_devices: u8,
+    ) -> u8;
+}
+extern "C" {
+    #[doc = " Check if dev_id of device is valid."]
+    pub fn rte_cryptodev_is_valid_dev(dev_id: u8) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the NUMA socket to which a device is connected"]
+    pub fn rte_cryptodev_socket_id(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Configure a device."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0: Success, device configured."]
+    #[doc = "   - <0: Error code returned by the driver configuration function."]
+    pub fn rte_cryptodev_configure(dev_id: u8, config: *mut rte_cryptodev_config) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Start an device."]
+    pub fn rte_cryptodev_start(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Stop an device. The device can be restarted with a call to"]
+    #[doc = " rte_cryptodev_start()"]
+    pub fn rte_cryptodev_stop(dev_id: u8);
+}
+extern "C" {
+    #[doc = " Close an device. The device cannot be restarted!"]
+    pub fn rte_cryptodev_close(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Allocate and set up a receive queue pair for a device."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0: Success, queue pair correctly set up."]
+    #[doc = "   - <0: Queue pair configuration failed"]
+    pub fn rte_cryptodev_queue_pair_setup(
+        dev_id: u8,
+        queue_pair_id: u16,
+        qp_conf: *const rte_cryptodev_qp_conf,
+        socket_id: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get the number of queue pairs on a specific crypto device"]
+    pub fn rte_cryptodev_queue_pair_count(dev_id: u8) -> u16;
+}
+extern "C" {
+    #[doc = " Retrieve the general I/O statistics of a device."]
+    pub fn rte_cryptodev_stats_get(dev_id: u8, stats: *mut rte_cryptodev_stats) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Reset the general I/O statistics of a device."]
+    pub fn rte_cryptodev_stats_reset(dev_id: u8);
+}
+extern "C" {
+    #[doc = " Retrieve the contextual information of a device."]
+    pub fn rte_cryptodev_info_get(dev_id: u8, dev_info: *mut rte_cryptodev_info);
+}
+extern "C" {
+    #[doc = " Provide driver identifier."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  The driver type identifier or -1 if no driver found"]
+    pub fn rte_cryptodev_driver_id_get(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Provide driver name."]
+    pub fn rte_cryptodev_driver_name_get(driver_id: u8) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " Create a symmetric session mempool."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - On success return size of the session"]
+    #[doc = "  - On failure returns 0"]
+    pub fn rte_cryptodev_sym_session_pool_create(
+        name: *const ::std::os::raw::c_char,
+        nb_elts: u32,
+        elt_size: u32,
+        cache_size: u32,
+        priv_size: u16,
+        socket_id: ::std::os::raw::c_int,
+    ) -> *mut rte_mempool;
+}
+extern "C" {
+    #[doc = " Create symmetric crypto session header (generic with no private data)"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - On success return pointer to sym-session"]
+    #[doc = "  - On failure returns NULL"]
+    pub fn rte_cryptodev_sym_session_create(mempool: *mut rte_mempool) -> *mut rte_cryptodev_sym_session;
+}
+extern "C" {
+    #[doc = " Frees symmetric crypto session header, after checking that all"]
+    #[doc = " the device private data has been freed, returning it"]
+    #[doc = " to its original mempool."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - 0 if successful."]
+    #[doc = "  - -EINVAL if session is NULL."]
+    #[doc = "  - -EBUSY if not all device private data has been freed."]
+    pub fn rte_cryptodev_sym_session_free(sess: *mut rte_cryptodev_sym_session) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Fill out private data for the device id, based on its device type."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - On success, zero."]
+    #[doc = "  - -EINVAL if input parameters are invalid."]
+    #[doc = "  - -ENOTSUP if crypto device does not support the crypto transform or"]
+    #[doc = "    does not support symmetric operations."]
+    #[doc = "  - -ENOMEM if the private session could not be allocated."]
+    pub fn rte_cryptodev_sym_session_init(
+        dev_id: u8,
+        sess: *mut rte_cryptodev_sym_session,
+        xforms: *mut rte_crypto_sym_xform,
+        mempool: *mut rte_mempool,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Frees private data for the device id, based on its device type,"]
+    #[doc = " returning it to its mempool. It is the application's responsibility"]
+    #[doc = " to ensure that private session data is not cleared while there are"]
+    #[doc = " still in-flight operations using it."]
+    pub fn rte_cryptodev_sym_session_clear(dev_id: u8, sess: *mut rte_cryptodev_sym_session) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get the size of the header session, for all registered drivers excluding"]
+    #[doc = " the user data size."]
+    pub fn rte_cryptodev_sym_get_header_session_size() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Get the size of the private symmetric session data"]
+    #[doc = " for a device."]
+    pub fn rte_cryptodev_sym_get_private_session_size(dev_id: u8) -> ::std::os::raw::c_uint;
+}
+#[doc = " Event device information"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rte_event_dev_info {
+    #[doc = "< Event driver name"]
+    pub driver_name: *const ::std::os::raw::c_char,
+    #[doc = "< Device information"]
+    pub dev: *mut rte_device,
+    #[doc = "< Minimum supported global dequeue timeout(ns) by this device"]
+    pub min_dequeue_timeout_ns: u32,
+    #[doc = "< Maximum supported global dequeue timeout(ns) by this device"]
+    pub max_dequeue_timeout_ns: u32,
+    #[doc = "< Configured global dequeue timeout(ns) for this device"]
+    pub dequeue_timeout_ns: u32,
+    #[doc = "< Maximum event_queues supported by this device"]
+    pub max_event_queues: u8,
+    #[doc = "< Maximum supported flows in an event queue by this device"]
+    pub max_event_queue_flows: u32,
+    #[doc = "< Maximum number of event queue priority levels by this device."]
+    #[doc = " Valid when the device has RTE_EVENT_DEV_CAP_QUEUE_QOS capability"]
+    pub max_event_queue_priority_levels: u8,
+    #[doc = "< Maximum number of event priority levels by this device."]
+    #[doc = " Valid when the device has RTE_EVENT_DEV_CAP_EVENT_QOS capability"]
+    pub max_event_priority_levels: u8,
+    #[doc = "< Maximum number of event ports supported by this device"]
+    pub max_event_ports: u8,
+    #[doc = "< Maximum number of events can be dequeued at a time from an"]
+    #[doc = " event port by this device."]
+    #[doc = " A device that does not support bulk dequeue will set this as 1."]
+    pub max_event_port_dequeue_depth: u8,
+    #[doc = "< Maximum number of events can be enqueued at a time from an"]
+    #[doc = " event port by this device."]
+    #[doc = " A device that does not support bulk enqueue will set this as 1."]
+    pub max_event_port_enqueue_depth: u32,
+    #[doc = "< Maximum number of queues that can be linked to a single event"]
+    #[doc = " port by this device."]
+    pub max_event_port_links: u8,
+    #[doc = "< A *closed system* event dev has a limit on the number of events it"]
+    #[doc = " can manage at a time. An *open system* event dev does not have a"]
+    #[doc = " limit and will specify this as -1."]
+    pub max_num_events: i32,
+    #[doc = "< Event device capabilities(RTE_EVENT_DEV_CAP_)"]
+    pub event_dev_cap: u32,
+    #[doc = "< Maximum number of event ports and queues that are optimized for"]
+    #[doc = " (and only capable of) single-link configurations supported by this"]
+    #[doc = " device. These ports and queues are not accounted for in"]
+    #[doc = " max_event_ports or max_event_queues."]
+    pub max_single_link_event_port_queue_pairs: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_event_dev_info() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_dev_info>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_event_dev_info))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_dev_info>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_event_dev_info))
+    );
+}
+impl Default for rte_event_dev_info {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Event device configuration structure"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_event_dev_config {
+    #[doc = "< rte_event_dequeue_burst() timeout on this device."]
+    #[doc = " This value should be in the range of *min_dequeue_timeout_ns* and"]
+    #[doc = " *max_dequeue_timeout_ns* which previously provided in"]
+    #[doc = " rte_event_dev_info_get()"]
+    #[doc = " The value 0 is allowed, in which case, default dequeue timeout used."]
+    pub dequeue_timeout_ns: u32,
+    #[doc = "< In a *closed system* this field is the limit on maximum number of"]
+    #[doc = " events that can be inflight in the eventdev at a given time. The"]
+    #[doc = " limit is required to ensure that the finite space in a closed system"]
+    #[doc = " is not overwhelmed. The value cannot exceed the *max_num_events*"]
+    #[doc = " as provided by rte_event_dev_info_get()."]
+    #[doc = " This value should be set to -1 for *open system*."]
+    pub nb_events_limit: i32,
+    #[doc = "< Number of event queues to configure on this device."]
+    pub nb_event_queues: u8,
+    #[doc = "< Number of event ports to configure on this device."]
+    pub nb_event_ports: u8,
+    #[doc = "< Number of flows for any event queue on this device."]
+    pub nb_event_queue_flows: u32,
+    #[doc = "< Maximum number of events can be dequeued at a time from an"]
+    #[doc = " event port by this device."]
+    pub nb_event_port_dequeue_depth: u32,
+    #[doc = "< Maximum number of events can be enqueued at a time from an"]
+    #[doc = " event port by this device."]
+    pub nb_event_port_enqueue_depth: u32,
+    #[doc = "< Event device config flags(RTE_EVENT_DEV_CFG_)"]
+    pub event_dev_cfg: u32,
+    #[doc = "< Number of event ports and queues that will be singly-linked to"]
+    #[doc = " each other."]
+    pub nb_single_link_event_port_queues: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_event_dev_config() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_dev_config>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_event_dev_config))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_dev_config>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_event_dev_config))
+    );
+}
+#[doc = " Event queue configuration structure"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_event_queue_conf {
+    #[doc = "< The maximum number of active flows this queue can track at any"]
+    #[doc = " given time."]
+    pub nb_atomic_flows: u32,
+    #[doc = "< The maximum number of outstanding events waiting to be"]
+    #[doc = " reordered by this queue."]
+    pub nb_atomic_order_sequences: u32,
+    #[doc = "< Queue cfg flags(EVENT_QUEUE_CFG_)"]
+    pub event_queue_cfg: u32,
+    #[doc = "< Queue schedule type(RTE_SCHED_TYPE_*)."]
+    pub schedule_type: u8,
+    #[doc = "< Priority for this event queue relative to other event queues."]
+    pub priority: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_event_queue_conf() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_queue_conf>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_event_queue_conf))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_queue_conf>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_event_queue_conf))
+    );
+}
+#[doc = " Event port configuration structure"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_event_port_conf {
+    #[doc = "< A backpressure threshold for new event enqueues on this port."]
+    #[doc = " Use for *closed system* event dev where event capacity is limited,"]
+    #[doc = " and cannot exceed the capacity of the event dev."]
+    pub new_event_threshold: i32,
+    #[doc = "< Configure number of bulk dequeues for this event port."]
+    pub dequeue_depth: u16,
+    #[doc = "< Configure number of bulk enqueues for this event port."]
+    pub enqueue_depth: u16,
+    #[doc = "< Port cfg flags(EVENT_PORT_CFG_)"]
+    pub event_port_cfg: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_event_port_conf() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_port_conf>(),
+        12usize,
+        concat!("Size of: ", stringify!(rte_event_port_conf))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_port_conf>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_event_port_conf))
+    );
+}
+#[doc = " The generic *rte_event* structure to hold the event attributes"]
+#[doc = " for dequeue and enqueue operation"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_event {
+    pub __bindgen_anon_1: rte_event__bindgen_ty_1,
+    pub __bindgen_anon_2: rte_event__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_event__bindgen_ty_1 {
+    #[doc = " Event attributes for dequeue or enqueue operation"]
+    pub event: u64,
+    pub __bindgen_anon_1: rte_event__bindgen_ty_1__bindgen_ty_1,
+    _bindgen_union_align: u64,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_event__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize], u32>,
+    pub _bitfield_2: __BindgenBitfieldUnit<[u8; 1usize], u8>,
+    #[doc = "< Targeted event queue identifier for the enqueue or"]
+    #[doc = " dequeue operation."]
+    pub queue_id: u8,
+    #[doc = "< Event priority relative to other events in the"]
+    #[doc = " event queue."]
+    pub priority: u8,
+    #[doc = "< Implementation specific opaque value."]
+    pub impl_opaque: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_event__bindgen_ty_1__bindgen_ty_1() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event__bindgen_ty_1__bindgen_ty_1>(),
+        8usize,
+        concat!("Size of: ", stringify!(rte_event__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event__bindgen_ty_1__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(rte_event__bindgen_ty_1__bindgen_ty_1))
+    );
+}
+impl rte_event__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub fn flow_id(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 20u8) as u32) }
+    }
+    #[inline]
+    pub fn set_flow_id(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 20u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn sub_event_type(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(20usize, 8u8) as u32) }
+    }
+    #[inline]
+    pub fn set_sub_event_type(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(20usize, 8u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn event_type(&self) -> u32 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(28usize, 4u8) as u32) }
+    }
+    #[inline]
+    pub fn set_event_type(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::std::mem::transmute(val);
+            self._bitfield_1.set(28usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        flow_id: u32,
+        sub_event_type: u32,
+        event_type: u32,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize], u32> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize], u32> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 20u8, {
+            let flow_id: u32 = unsafe { ::std::mem::transmute(flow_id) };
+            flow_id as u64
+        });
+        __bindgen_bitfield_unit.set(20usize, 8u8, {
+            let sub_event_type: u32 = unsafe { ::std::mem::transmute(sub_event_type) };
+            sub_event_type as u64
+        });
+        __bindgen_bitfield_unit.set(28usize, 4u8, {
+            let event_type: u32 = unsafe { ::std::mem::transmute(event_type) };
+            event_type as u64
+        });
+        __bindgen_bitfield_unit
+    }
+    #[inline]
+    pub fn op(&self) -> u8 {
+        unsafe { ::std::mem::transmute(self._bitfield_2.get(0usize, 2u8) as u8) }
+    }
+    #[inline]
+    pub fn set_op(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::std::mem::transmute(val);
+            self._bitfield_2.set(0usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn rsvd(&self) -> u8 {
+        unsafe { ::std::mem::transmute(self._bitfield_2.get(2usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_rsvd(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::std::mem::transmute(val);
+            self._bitfield_2.set(2usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn sched_type(&self) -> u8 {
+        unsafe { ::std::mem::transmute(self._bitfield_2.get(6usize, 2u8) as u8) }
+    }
+    #[inline]
+    pub fn set_sched_type(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::std::mem::transmute(val);
+            self._bitfield_2.set(6usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_2(op: u8, rsvd: u8, sched_type: u8) -> __BindgenBitfieldUnit<[u8; 1usize], u8> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize], u8> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 2u8, {
+            let op: u8 = unsafe { ::std::mem::transmute(op) };
+            op as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 4u8, {
+            let rsvd: u8 = unsafe { ::std::mem::transmute(rsvd) };
+            rsvd as u64
+        });
+        __bindgen_bitfield_unit.set(6usize, 2u8, {
+            let sched_type: u8 = unsafe { ::std::mem::transmute(sched_type) };
+            sched_type as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+impl Default for rte_event__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_event__bindgen_ty_2 {
+    #[doc = "< data/payload of the event"]
+    pub u64_: u64,
+    #[doc = "< Opaque event pointer"]
+    pub event_ptr: *mut ::std::os::raw::c_void,
+    #[doc = "< mbuf pointer if dequeued event is associated with mbuf"]
+    pub mbuf: *mut rte_mbuf,
+    _bindgen_union_align: u64,
+}
+impl Default for rte_event__bindgen_ty_2 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[test]
+fn bindgen_test_layout_rte_event() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_event))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_event))
+    );
+}
+impl Default for rte_event {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Get the total number of event devices that have been successfully"]
+    #[doc = " initialised."]
+    pub fn rte_event_dev_count() -> u8;
+}
+extern "C" {
+    #[doc = " Get the device identifier for the named event device."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   Returns event device identifier on success."]
+    #[doc = "   - <0: Failure to find named event device."]
+    pub fn rte_event_dev_get_dev_id(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Return the NUMA socket to which a device is connected."]
+    pub fn rte_event_dev_socket_id(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the contextual information of an event device."]
+    pub fn rte_event_dev_info_get(dev_id: u8, dev_info: *mut rte_event_dev_info) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Configure an event device."]
+    #[doc = ""]
+    #[doc = " This function must be invoked first before any other function in the"]
+    #[doc = " API. This function can also be re-invoked when a device is in the"]
+    #[doc = " stopped state."]
+    pub fn rte_event_dev_configure(dev_id: u8, dev_conf: *const rte_event_dev_config) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the default configuration information of an event queue designated"]
+    #[doc = " by its *queue_id* from the event driver for an event device."]
+    pub fn rte_event_queue_default_conf_get(
+        dev_id: u8,
+        queue_id: u8,
+        queue_conf: *mut rte_event_queue_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Allocate and set up an event queue for an event device."]
+    pub fn rte_event_queue_setup(
+        dev_id: u8,
+        queue_id: u8,
+        queue_conf: *const rte_event_queue_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the default configuration information of an event port designated"]
+    #[doc = " by its *port_id* from the event driver for an event device."]
+    pub fn rte_event_port_default_conf_get(
+        dev_id: u8,
+        port_id: u8,
+        port_conf: *mut rte_event_port_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Allocate and set up an event port for an event device."]
+    pub fn rte_event_port_setup(
+        dev_id: u8,
+        port_id: u8,
+        port_conf: *const rte_event_port_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Link multiple source event queues supplied in *queues* to the destination"]
+    #[doc = " event port designated by its *port_id* with associated service priority"]
+    #[doc = " supplied in *priorities* on the event device designated by its *dev_id*."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = " The number of links actually established. The return value can be less than"]
+    #[doc = " the value of the *nb_links* parameter when the implementation has the"]
+    #[doc = " limitation on specific queue to port link establishment or if invalid"]
+    #[doc = " parameters are specified in *queues*"]
+    pub fn rte_event_port_link(
+        dev_id: u8,
+        port_id: u8,
+        queues: *const u8,
+        priorities: *const u8,
+        nb_links: u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Unlink multiple source event queues supplied in *queues* from the destination"]
+    #[doc = " event port designated by its *port_id* on the event device designated"]
+    #[doc = " by its *dev_id*."]
+    pub fn rte_event_port_unlink(dev_id: u8, port_id: u8, queues: *mut u8, nb_unlinks: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the list of source event queues and its associated service priority"]
+    #[doc = " linked to the destination event port designated by its *port_id*"]
+    #[doc = " on the event device designated by its *dev_id*."]
+    pub fn rte_event_port_links_get(
+        dev_id: u8,
+        port_id: u8,
+        queues: *mut u8,
+        priorities: *mut u8,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the service ID of the event dev. If the adapter doesn't use"]
+    #[doc = " a rte_service function, this function returns -ESRCH."]
+    pub fn rte_event_dev_service_id_get(dev_id: u8, service_id: *mut u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Start an event device."]
+    pub fn rte_event_dev_start(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Stop an event device."]
+    pub fn rte_event_dev_stop(dev_id: u8);
+}
+extern "C" {
+    #[doc = " Close an event device. The device cannot be restarted!"]
+    pub fn rte_event_dev_close(dev_id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Converts nanoseconds to *timeout_ticks* value for rte_event_dequeue_burst()"]
+    pub fn rte_event_dequeue_timeout_ticks(dev_id: u8, ns: u64, timeout_ticks: *mut u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Dump internal information about *dev_id* to the FILE* provided in *f*."]
+    pub fn rte_event_dev_dump(dev_id: u8, f: *mut FILE) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the event device's ethdev Rx adapter capabilities for the"]
+    #[doc = " specified ethernet port"]
+    pub fn rte_event_eth_rx_adapter_caps_get(dev_id: u8, eth_port_id: u16, caps: *mut u32) -> ::std::os::raw::c_int;
+}
+#[doc = " Rx queue configuration structure"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_event_eth_rx_adapter_queue_conf {
+    #[doc = "< Flags for handling received packets"]
+    #[doc = " @see RTE_EVENT_ETH_RX_ADAPTER_QUEUE_FLOW_ID_VALID"]
+    pub rx_queue_flags: u32,
+    #[doc = "< Relative polling frequency of ethernet receive queue when the"]
+    #[doc = " adapter uses a service core function for ethernet to event device"]
+    #[doc = " transfers. If it is set to zero, the Rx queue is interrupt driven"]
+    #[doc = " (unless rx queue interrupts are not enabled for the ethernet"]
+    #[doc = " device)."]
+    pub servicing_weight: u16,
+    #[doc = "  The values from the following event fields will be used when"]
+    #[doc = "  queuing mbuf events:"]
+    #[doc = "   - event_queue_id: Targeted event queue ID for received packets."]
+    #[doc = "   - event_priority: Event priority of packets from this Rx queue in"]
+    #[doc = "                     the event queue relative to other events."]
+    #[doc = "   - sched_type: Scheduling type for packets from this Rx queue."]
+    #[doc = "   - flow_id: If the RTE_ETH_RX_EVENT_ADAPTER_QUEUE_FLOW_ID_VALID bit"]
+    #[doc = "\t\tis set in rx_queue_flags, this flow_id is used for all"]
+    #[doc = "\t\tpackets received from this queue. Otherwise the flow ID"]
+    #[doc = "\t\tis set to the RSS hash of the src and dst IPv4/6"]
+    #[doc = "\t\taddresses."]
+    #[doc = ""]
+    #[doc = " The event adapter sets ev.event_type to RTE_EVENT_TYPE_ETHDEV in the"]
+    #[doc = " enqueued event."]
+    pub ev: rte_event,
+}
+#[test]
+fn bindgen_test_layout_rte_event_eth_rx_adapter_queue_conf() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_eth_rx_adapter_queue_conf>(),
+        24usize,
+        concat!("Size of: ", stringify!(rte_event_eth_rx_adapter_queue_conf))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_eth_rx_adapter_queue_conf>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_event_eth_rx_adapter_queue_conf))
+    );
+}
+impl Default for rte_event_eth_rx_adapter_queue_conf {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " A structure used to retrieve statistics for an eth rx adapter instance."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_event_eth_rx_adapter_stats {
+    #[doc = "< Receive queue poll count"]
+    pub rx_poll_count: u64,
+    #[doc = "< Received packet count"]
+    pub rx_packets: u64,
+    #[doc = "< Eventdev enqueue count"]
+    pub rx_enq_count: u64,
+    #[doc = "< Eventdev enqueue retry count"]
+    pub rx_enq_retry: u64,
+    #[doc = "< Rx enqueue start timestamp"]
+    pub rx_enq_start_ts: u64,
+    #[doc = "< Cycles for which the service is blocked by the event device,"]
+    #[doc = " i.e, the service fails to enqueue to the event device."]
+    pub rx_enq_block_cycles: u64,
+    #[doc = "< Rx enqueue block end timestamp"]
+    pub rx_enq_end_ts: u64,
+    #[doc = "< Received packet count for interrupt mode Rx queues"]
+    pub rx_intr_packets: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_event_eth_rx_adapter_stats() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_event_eth_rx_adapter_stats>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_event_eth_rx_adapter_stats))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_event_eth_rx_adapter_stats>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_event_eth_rx_adapter_stats))
+    );
+}
+extern "C" {
+    #[doc = " Create a new ethernet Rx event adapter with the specified identifier."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0: Success"]
+    #[doc = "   - <0: Error code on failure"]
+    pub fn rte_event_eth_rx_adapter_create(
+        id: u8,
+        dev_id: u8,
+        port_config: *mut rte_event_port_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Free an event adapter"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0: Success"]
+    #[doc = "   - <0: Error code on failure, If the adapter still has Rx queues"]
+    #[doc = "      added to it, the function returns -EBUSY."]
+    pub fn rte_event_eth_rx_adapter_free(id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Add receive queue to an event adapter. After a queue has been"]
+    #[doc = " added to the event adapter, the result of the application calling"]
+    #[doc = " rte_eth_rx_burst(eth_dev_id, rx_queue_id, ..) is undefined."]
+    #[doc = ""]
+    #[doc = " @param rx_queue_id"]
+    #[doc = "  Ethernet device receive queue index."]
+    #[doc = "  If rx_queue_id is -1, then all Rx queues configured for"]
+    #[doc = "  the device are added."]
+    pub fn rte_event_eth_rx_adapter_queue_add(
+        id: u8,
+        eth_dev_id: u16,
+        rx_queue_id: i32,
+        conf: *const rte_event_eth_rx_adapter_queue_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Delete receive queue from an event adapter."]
+    #[doc = ""]
+    #[doc = " @param rx_queue_id"]
+    #[doc = "  Ethernet device receive queue index."]
+    #[doc = "  If rx_queue_id is -1, then all Rx queues configured for"]
+    #[doc = "  the device are deleted."]
+    pub fn rte_event_eth_rx_adapter_queue_del(id: u8, eth_dev_id: u16, rx_queue_id: i32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Start ethernet Rx event adapter"]
+    pub fn rte_event_eth_rx_adapter_start(id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Stop  ethernet Rx event adapter"]
+    pub fn rte_event_eth_rx_adapter_stop(id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve statistics for an adapter"]
+    pub fn rte_event_eth_rx_adapter_stats_get(
+        id: u8,
+        stats: *mut rte_event_eth_rx_adapter_stats,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Reset statistics for an adapter."]
+    pub fn rte_event_eth_rx_adapter_stats_reset(id: u8) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve the service ID of an adapter. If the adapter doesn't use"]
+    #[doc = " a rte_service function, this function returns -ESRCH."]
+    pub fn rte_event_eth_rx_adapter_service_id_get(id: u8, service_id: *mut u32) -> ::std::os::raw::c_int;
+}
+#[doc = " Signature of callback back function called when an alarm goes off."]
+pub type rte_eal_alarm_callback = ::std::option::Option<unsafe extern "C" fn(arg: *mut ::std::os::raw::c_void)>;
+extern "C" {
+    #[doc = " Function to set a callback to be triggered when us microseconds"]
+    #[doc = " have expired. Accuracy of timing to the microsecond is not guaranteed. The"]
+    #[doc = " alarm function will not be called *before* the requested time, but may"]
+    #[doc = " be called a short period of time afterwards."]
+    #[doc = " The alarm handler will be called only once. There is no need to call"]
+    #[doc = " \"rte_eal_alarm_cancel\" from within the callback function."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0 on success"]
+    #[doc = "   - On error, a negative value."]
+    pub fn rte_eal_alarm_set(
+        us: u64,
+        cb: rte_eal_alarm_callback,
+        cb_arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Function to cancel an alarm callback which has been registered before. If"]
+    #[doc = " used outside alarm callback it wait for all callbacks to finish execution."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    - value greater than 0 and rte_errno not changed - returned value is"]
+    #[doc = "      the number of canceled alarm callback functions"]
+    #[doc = "    - value greater or equal 0 and rte_errno set to EINPROGRESS, at least one"]
+    #[doc = "      alarm could not be canceled because cancellation was requested from alarm"]
+    #[doc = "      callback context. Returned value is the number of successfully canceled"]
+    #[doc = "      alarm callbacks"]
+    #[doc = "    -  0 and rte_errno set to ENOENT - no alarm was found"]
+    #[doc = "    - -1 and rte_errno set to EINVAL - invalid parameter (NULL callback)"]
+    pub fn rte_eal_alarm_cancel(
+        cb_fn: rte_eal_alarm_callback,
+        cb_arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+#[doc = " Structure used to create GRO context objects or used to pass"]
+#[doc = " application-determined parameters to rte_gro_reassemble_burst()."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_gro_param {
+    #[doc = "< desired GRO types"]
+    pub gro_types: u64,
+    #[doc = "< max flow number"]
+    pub max_flow_num: u16,
+    #[doc = "< max packet number per flow"]
+    pub max_item_per_flow: u16,
+    #[doc = "< socket index for allocating GRO related data structures,"]
+    #[doc = " like reassembly tables. When use rte_gro_reassemble_burst(),"]
+    #[doc = " applications don't need to set this value."]
+    pub socket_id: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_gro_param() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_gro_param>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_gro_param))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_gro_param>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_gro_param))
+    );
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " This function create a GRO context object, which is used to merge"]
+    #[doc = " packets in rte_gro_reassemble()."]
+    #[doc = ""]
+    #[doc = " @param param"]
+    #[doc = "  applications use it to pass needed parameters to create a GRO"]
+    #[doc = "  context object."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  if create successfully, return a pointer which points to the GRO"]
+    #[doc = "  context object. Otherwise, return NULL."]
+    pub fn rte_gro_ctx_create(param: *const rte_gro_param) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " This function destroys a GRO context object."]
+    #[doc = ""]
+    #[doc = " @param ctx"]
+    #[doc = "  pointer points to a GRO context object."]
+    pub fn rte_gro_ctx_destroy(ctx: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    #[doc = " This is one of the main reassembly APIs, which merges numbers of"]
+    #[doc = " packets at a time. It doesn't check if input packets have correct"]
+    #[doc = " checksums and doesn't re-calculate checksums for merged packets."]
+    #[doc = " It assumes the packets are complete (i.e., MF==0 && frag_off==0),"]
+    #[doc = " when IP fragmentation is possible (i.e., DF==0). The GROed packets"]
+    #[doc = " are returned as soon as the function finishes."]
+    #[doc = ""]
+    #[doc = " @param pkts"]
+    #[doc = "  Pointer array pointing to the packets to reassemble. Besides, it"]
+    #[doc = "  keeps MBUF addresses for the GROed packets."]
+    #[doc = " @param nb_pkts"]
+    #[doc = "  The number of packets to reassemble"]
+    #[doc = " @param param"]
+    #[doc = "  Application-determined parameters for reassembling packets."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  The number of packets after been GROed. If no packets are merged,"]
+    #[doc = "  the return value is equals to nb_pkts."]
+    pub fn rte_gro_reassemble_burst(pkts: *mut *mut rte_mbuf, nb_pkts: u16, param: *const rte_gro_param) -> u16;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Reassembly function, which tries to merge input packets with the"]
+    #[doc = " existed packets in the reassembly tables of a given GRO context."]
+    #[doc = " It doesn't check if input packets have correct checksums and doesn't"]
+    #[doc = " re-calculate checksums for merged packets. Additionally, it assumes"]
+    #[doc = " the packets are complete (i.e., MF==0 && frag_off==0), when IP"]
+    #[doc = " fragmentation is possible (i.e., DF==0)."]
+    #[doc = ""]
+    #[doc = " If the input packets have invalid parameters (e.g. no data payload,"]
+    #[doc = " unsupported GRO types), they are returned to applications. Otherwise,"]
+    #[doc = " they are either merged or inserted into the table. Applications need"]
+    #[doc = " to flush packets from the tables by flush API, if they want to get the"]
+    #[doc = " GROed packets."]
+    #[doc = ""]
+    #[doc = " @param pkts"]
+    #[doc = "  Packets to reassemble. It's also used to store the unprocessed packets."]
+    #[doc = " @param nb_pkts"]
+    #[doc = "  The number of packets to reassemble"]
+    #[doc = " @param ctx"]
+    #[doc = "  GRO context object pointer"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  The number of unprocessed packets."]
+    pub fn rte_gro_reassemble(pkts: *mut *mut rte_mbuf, nb_pkts: u16, ctx: *mut ::std::os::raw::c_void) -> u16;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " This function flushes the timeout packets from the reassembly tables"]
+    #[doc = " of desired GRO types. The max number of flushed packets is the"]
+    #[doc = " element number of 'out'."]
+    #[doc = ""]
+    #[doc = " Additionally, the flushed packets may have incorrect checksums, since"]
+    #[doc = " this function doesn't re-calculate checksums for merged packets."]
+    #[doc = ""]
+    #[doc = " @param ctx"]
+    #[doc = "  GRO context object pointer."]
+    #[doc = " @param timeout_cycles"]
+    #[doc = "  The max TTL for packets in reassembly tables, measured in nanosecond."]
+    #[doc = " @param gro_types"]
+    #[doc = "  This function flushes packets whose GRO types are specified by"]
+    #[doc = "  gro_types."]
+    #[doc = " @param out"]
+    #[doc = "  Pointer array used to keep flushed packets."]
+    #[doc = " @param max_nb_out"]
+    #[doc = "  The element number of 'out'. It's also the max number of timeout"]
+    #[doc = "  packets that can be flushed finally."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  The number of flushed packets."]
+    pub fn rte_gro_timeout_flush(
+        ctx: *mut ::std::os::raw::c_void,
+        timeout_cycles: u64,
+        gro_types: u64,
+        out: *mut *mut rte_mbuf,
+        max_nb_out: u16,
+    ) -> u16;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " This function returns the number of packets in all reassembly tables"]
+    #[doc = " of a given GRO context."]
+    #[doc = ""]
+    #[doc = " @param ctx"]
+    #[doc = "  GRO context object pointer."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  The number of packets in the tables."]
+    pub fn rte_gro_get_pkt_count(ctx: *mut ::std::os::raw::c_void) -> u64;
+}
+#[doc = " GSO context structure."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_gso_ctx {
+    #[doc = "< MBUF pool for allocating direct buffers, which are used"]
+    #[doc = " to store packet headers for GSO segments."]
+    pub direct_pool: *mut rte_mempool,
+    #[doc = "< MBUF pool for allocating indirect buffers, which are used"]
+    #[doc = " to locate packet payloads for GSO segments. The indirect"]
+    #[doc = " buffer doesn't contain any data, but simply points to an"]
+    #[doc = " offset within the packet to segment."]
+    pub indirect_pool: *mut rte_mempool,
+    #[doc = "< the bit mask of required GSO types. The GSO library"]
+    #[doc = " uses the same macros as that of describing device TX"]
+    #[doc = " offloading capabilities (i.e. DEV_TX_OFFLOAD_*_TSO) for"]
+    #[doc = " gso_types."]
+    #[doc = ""]
+    #[doc = " For example, if applications want to segment TCP/IPv4"]
+    #[doc = " packets, set DEV_TX_OFFLOAD_TCP_TSO in gso_types."]
+    pub gso_types: u64,
+    #[doc = "< maximum size of an output GSO segment, including packet"]
+    #[doc = " header and payload, measured in bytes. Must exceed"]
+    #[doc = " RTE_GSO_SEG_SIZE_MIN."]
+    pub gso_size: u16,
+    #[doc = "< flag that indicates the final status of the IPv4 ID fields"]
+    #[doc = " in the GSO segments. Applications can use"]
+    #[doc = " RTE_GSO_FLAG_IPID_FIXED to make the IPv4 ID field fixed."]
+    pub flag: u8,
+}
+#[test]
+fn bindgen_test_layout_rte_gso_ctx() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_gso_ctx>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_gso_ctx))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_gso_ctx>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_gso_ctx))
+    );
+}
+impl Default for rte_gso_ctx {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Segmentation function, which supports processing of both single- and"]
+    #[doc = " multi- MBUF packets."]
+    #[doc = ""]
+    #[doc = " Note that we refer to the packets that are segmented from the input"]
+    #[doc = " packet as 'GSO segments'. rte_gso_segment() doesn't check if the"]
+    #[doc = " input packet has correct checksums, and doesn't update checksums for"]
+    #[doc = " output GSO segments. Additionally, it doesn't process IP fragment"]
+    #[doc = " packets."]
+    #[doc = ""]
+    #[doc = " Before calling rte_gso_segment(), applications must set proper ol_flags"]
+    #[doc = " for the packet. The GSO library uses the same macros as that of TSO."]
+    #[doc = " For example, set PKT_TX_TCP_SEG and PKT_TX_IPV4 in ol_flags to segment"]
+    #[doc = " a TCP/IPv4 packet. If rte_gso_segment() succeeds, the PKT_TX_TCP_SEG"]
+    #[doc = " flag is removed for all GSO segments and the input packet."]
+    #[doc = ""]
+    #[doc = " Each of the newly-created GSO segments is organized as a two-segment"]
+    #[doc = " MBUF, where the first segment is a standard MBUF, which stores a copy"]
+    #[doc = " of packet header, and the second is an indirect MBUF which points to"]
+    #[doc = " a section of data in the input packet. Since each GSO segment has"]
+    #[doc = " multiple MBUFs (i.e. typically 2 MBUFs), the driver of the interface which"]
+    #[doc = " the GSO segments are sent to should support transmission of multi-segment"]
+    #[doc = " packets."]
+    #[doc = ""]
+    #[doc = " If the input packet is GSO'd, all the indirect segments are attached to the"]
+    #[doc = " input packet."]
+    #[doc = ""]
+    #[doc = " rte_gso_segment() will not free the input packet no matter whether it is"]
+    #[doc = " GSO'd or not, the application should free it after calling rte_gso_segment()."]
+    #[doc = ""]
+    #[doc = " If the memory space in pkts_out or MBUF pools is insufficient, this"]
+    #[doc = " function fails, and it returns (-1) * errno. Otherwise, GSO succeeds,"]
+    #[doc = " and this function returns the number of output GSO segments filled in"]
+    #[doc = " pkts_out."]
+    #[doc = ""]
+    #[doc = " @param pkt"]
+    #[doc = "  The packet mbuf to segment."]
+    #[doc = " @param ctx"]
+    #[doc = "  GSO context object pointer."]
+    #[doc = " @param pkts_out"]
+    #[doc = "  Pointer array used to store the MBUF addresses of output GSO"]
+    #[doc = "  segments, when rte_gso_segment() succeeds."]
+    #[doc = " @param nb_pkts_out"]
+    #[doc = "  The max number of items that pkts_out can keep."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - The number of GSO segments filled in pkts_out on success."]
+    #[doc = "  - Return 0 if it does not need to be GSO'd."]
+    #[doc = "  - Return -ENOMEM if run out of memory in MBUF pools."]
+    #[doc = "  - Return -EINVAL for invalid parameters."]
+    pub fn rte_gso_segment(
+        pkt: *mut rte_mbuf,
+        ctx: *const rte_gso_ctx,
+        pkts_out: *mut *mut rte_mbuf,
+        nb_pkts_out: u16,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_reorder_buffer {
+    _unused: [u8; 0],
+}
+pub type rte_reorder_seqn_t = u32;
+extern "C" {
+    pub static mut rte_reorder_seqn_dynfield_offset: ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Create a new reorder buffer instance"]
+    #[doc = ""]
+    #[doc = " Allocate memory and initialize a new reorder buffer in that"]
+    #[doc = " memory, returning a pointer to the new reorder buffer."]
+    #[doc = " The sequence number dynamic field of the mbufs is registered as well."]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "   Name to be given to the reorder buffer instance."]
+    #[doc = " @param socket_id"]
+    #[doc = "   The NUMA node on which the memory for the reorder buffer"]
+    #[doc = "   instance is to be reserved."]
+    #[doc = " @param size"]
+    #[doc = "   Max number of elements that can be stored in the reorder buffer"]
+    #[doc = " @return"]
+    #[doc = "   The initialized reorder buffer instance, or NULL on error"]
+    #[doc = "   On error case, rte_errno will be set appropriately:"]
+    #[doc = "    - ENOMEM - no appropriate memory area found in which to create memzone"]
+    #[doc = "    - EINVAL - invalid parameters"]
+    pub fn rte_reorder_create(
+        name: *const ::std::os::raw::c_char,
+        socket_id: ::std::os::raw::c_uint,
+        size: ::std::os::raw::c_uint,
+    ) -> *mut rte_reorder_buffer;
+}
+extern "C" {
+    #[doc = " Find an existing reorder buffer instance"]
+    #[doc = " and return a pointer to it."]
+    #[doc = ""]
+    #[doc = " @param name"]
+    #[doc = "   Name of the reorder buffer instance as passed to rte_reorder_create()"]
+    #[doc = " @return"]
+    #[doc = "   Pointer to reorder buffer instance or NULL if object not found with rte_errno"]
+    #[doc = "   set appropriately. Possible rte_errno values include:"]
+    #[doc = "    - ENOENT - required entry not available to return."]
+    pub fn rte_reorder_find_existing(name: *const ::std::os::raw::c_char) -> *mut rte_reorder_buffer;
+}
+extern "C" {
+    #[doc = " Reset the given reorder buffer instance with initial values."]
+    #[doc = ""]
+    #[doc = " @param b"]
+    #[doc = "   Reorder buffer instance which has to be reset"]
+    pub fn rte_reorder_reset(b: *mut rte_reorder_buffer);
+}
+extern "C" {
+    #[doc = " Free reorder buffer instance."]
+    #[doc = ""]
+    #[doc = " @param b"]
+    #[doc = "   reorder buffer instance"]
+    #[doc = " @return"]
+    #[doc = "   None"]
+    pub fn rte_reorder_free(b: *mut rte_reorder_buffer);
+}
+extern "C" {
+    #[doc = " Insert given mbuf in reorder buffer in its correct position"]
+    #[doc = ""]
+    #[doc = " The given mbuf is to be reordered relative to other mbufs in the system."]
+    #[doc = " The mbuf must contain a sequence number which is then used to place"]
+    #[doc = " the buffer in the correct position in the reorder buffer. Reordered"]
+    #[doc = " packets can later be taken from the reorder buffer using the"]
+    #[doc = " rte_reorder_drain() API."]
+    #[doc = ""]
+    #[doc = " @param b"]
+    #[doc = "   Reorder buffer where the mbuf has to be inserted."]
+    #[doc = " @param mbuf"]
+    #[doc = "   mbuf of packet that needs to be inserted in reorder buffer."]
+    #[doc = " @return"]
+    #[doc = "   0 on success"]
+    #[doc = "   -1 on error"]
+    #[doc = "   On error case, rte_errno will be set appropriately:"]
+    #[doc = "    - ENOSPC - Cannot move existing mbufs from reorder buffer to accommodate"]
+    #[doc = "      early mbuf, but it can be accommodated by performing drain and then insert."]
+    #[doc = "    - ERANGE - Too early or late mbuf which is vastly out of range of expected"]
+    #[doc = "      window should be ignored without any handling."]
+    pub fn rte_reorder_insert(b: *mut rte_reorder_buffer, mbuf: *mut rte_mbuf) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Fetch reordered buffers"]
+    #[doc = ""]
+    #[doc = " Returns a set of in-order buffers from the reorder buffer structure. Gaps"]
+    #[doc = " may be present in the sequence numbers of the mbuf if packets have been"]
+    #[doc = " delayed too long before reaching the reorder window, or have been previously"]
+    #[doc = " dropped by the system."]
+    #[doc = ""]
+    #[doc = " @param b"]
+    #[doc = "   Reorder buffer instance from which packets are to be drained"]
+    #[doc = " @param mbufs"]
+    #[doc = "   array of mbufs where reordered packets will be inserted from reorder buffer"]
+    #[doc = " @param max_mbufs"]
+    #[doc = "   the number of elements in the mbufs array."]
+    #[doc = " @return"]
+    #[doc = "   number of mbuf pointers written to mbufs. 0 <= N < max_mbufs."]
+    pub fn rte_reorder_drain(
+        b: *mut rte_reorder_buffer,
+        mbufs: *mut *mut rte_mbuf,
+        max_mbufs: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+pub mod rte_color {
+    #[doc = " Color"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Green"]
+    pub const RTE_COLOR_GREEN: Type = 0;
+    #[doc = "< Yellow"]
+    pub const RTE_COLOR_YELLOW: Type = 1;
+    #[doc = "< Red"]
+    pub const RTE_COLOR_RED: Type = 2;
+    #[doc = "< Number of colors"]
+    pub const RTE_COLORS: Type = 3;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_sched_port {
+    _unused: [u8; 0],
+}
+#[doc = " Pipe configuration parameters. The period and credits_per_period"]
+#[doc = " parameters are measured in bytes, with one byte meaning the time"]
+#[doc = " duration associated with the transmission of one byte on the"]
+#[doc = " physical medium of the output port, with pipe or pipe traffic class"]
+#[doc = " rate (measured as percentage of output port rate) determined as"]
+#[doc = " credits_per_period divided by period. One credit represents one"]
+#[doc = " byte."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_sched_pipe_params {
+    #[doc = " Token bucket rate (measured in bytes per second)"]
+    pub tb_rate: u64,
+    #[doc = " Token bucket size (measured in credits)"]
+    pub tb_size: u64,
+    #[doc = " Traffic class rates (measured in bytes per second)"]
+    pub tc_rate: [u64; 13usize],
+    #[doc = " Enforcement period (measured in milliseconds)"]
+    pub tc_period: u64,
+    #[doc = " Best-effort traffic class oversubscription weight"]
+    pub tc_ov_weight: u8,
+    #[doc = " WRR weights of best-effort traffic class queues"]
+    pub wrr_weights: [u8; 4usize],
+}
+#[test]
+fn bindgen_test_layout_rte_sched_pipe_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_sched_pipe_params>(),
+        136usize,
+        concat!("Size of: ", stringify!(rte_sched_pipe_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_sched_pipe_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_sched_pipe_params))
+    );
+}
+#[doc = " Subport configuration parameters. The period and credits_per_period"]
+#[doc = " parameters are measured in bytes, with one byte meaning the time"]
+#[doc = " duration associated with the transmission of one byte on the"]
+#[doc = " physical medium of the output port, with pipe or pipe traffic class"]
+#[doc = " rate (measured as percentage of output port rate) determined as"]
+#[doc = " credits_per_period divided by period. One credit represents one"]
+#[doc = " byte."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_sched_subport_params {
+    #[doc = " Token bucket rate (measured in bytes per second)"]
+    pub tb_rate: u64,
+    #[doc = " Token bucket size (measured in credits)"]
+    pub tb_size: u64,
+    #[doc = " Traffic class rates (measured in bytes per second)"]
+    pub tc_rate: [u64; 13usize],
+    #[doc = " Enforcement period for rates (measured in milliseconds)"]
+    pub tc_period: u64,
+    #[doc = " Number of subport pipes."]
+    #[doc = " The subport can enable/allocate fewer pipes than the maximum"]
+    #[doc = " number set through struct port_params::n_max_pipes_per_subport,"]
+    #[doc = " as needed, to avoid memory allocation for the queues of the"]
+    #[doc = " pipes that are not really needed."]
+    pub n_pipes_per_subport_enabled: u32,
+    #[doc = " Packet queue size for each traffic class."]
+    #[doc = " All the pipes within the same subport share the similar"]
+    #[doc = " configuration for the queues."]
+    pub qsize: [u16; 13usize],
+    #[doc = " Pipe profile table."]
+    #[doc = " Every pipe is configured using one of the profiles from this table."]
+    pub pipe_profiles: *mut rte_sched_pipe_params,
+    #[doc = " Profiles in the pipe profile table"]
+    pub n_pipe_profiles: u32,
+    #[doc = " Max allowed profiles in the pipe profile table"]
+    pub n_max_pipe_profiles: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_sched_subport_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_sched_subport_params>(),
+        176usize,
+        concat!("Size of: ", stringify!(rte_sched_subport_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_sched_subport_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_sched_subport_params))
+    );
+}
+impl Default for rte_sched_subport_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Subport statistics"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_sched_subport_stats {
+    #[doc = " Number of packets successfully written"]
+    pub n_pkts_tc: [u64; 13usize],
+    #[doc = " Number of packets dropped"]
+    pub n_pkts_tc_dropped: [u64; 13usize],
+    #[doc = " Number of bytes successfully written for each traffic class"]
+    pub n_bytes_tc: [u64; 13usize],
+    #[doc = " Number of bytes dropped for each traffic class"]
+    pub n_bytes_tc_dropped: [u64; 13usize],
+}
+#[test]
+fn bindgen_test_layout_rte_sched_subport_stats() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_sched_subport_stats>(),
+        416usize,
+        concat!("Size of: ", stringify!(rte_sched_subport_stats))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_sched_subport_stats>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_sched_subport_stats))
+    );
+}
+#[doc = " Queue statistics"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_sched_queue_stats {
+    #[doc = "< Packets successfully written"]
+    pub n_pkts: u64,
+    #[doc = "< Packets dropped"]
+    pub n_pkts_dropped: u64,
+    #[doc = "< Bytes successfully written"]
+    pub n_bytes: u64,
+    #[doc = "< Bytes dropped"]
+    pub n_bytes_dropped: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_sched_queue_stats() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_sched_queue_stats>(),
+        32usize,
+        concat!("Size of: ", stringify!(rte_sched_queue_stats))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_sched_queue_stats>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_sched_queue_stats))
+    );
+}
+#[doc = " Port configuration parameters."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_sched_port_params {
+    #[doc = " Name of the port to be associated"]
+    pub name: *const ::std::os::raw::c_char,
+    #[doc = " CPU socket ID"]
+    pub socket: ::std::os::raw::c_int,
+    #[doc = " Output port rate (measured in bytes per second)"]
+    pub rate: u64,
+    #[doc = " Maximum Ethernet frame size (measured in bytes)."]
+    #[doc = " Should not include the framing overhead."]
+    pub mtu: u32,
+    #[doc = " Framing overhead per packet (measured in bytes)"]
+    pub frame_overhead: u32,
+    #[doc = " Number of subports"]
+    pub n_subports_per_port: u32,
+    #[doc = " subport pipes - max pipes per subport"]
+    pub n_pipes_per_subport: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_sched_port_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_sched_port_params>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_sched_port_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_sched_port_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_sched_port_params))
+    );
+}
+impl Default for rte_sched_port_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler port configuration"]
+    #[doc = ""]
+    #[doc = " @param params"]
+    #[doc = "   Port scheduler configuration parameter structure"]
+    #[doc = " @return"]
+    #[doc = "   Handle to port scheduler instance upon success or NULL otherwise."]
+    pub fn rte_sched_port_config(params: *mut rte_sched_port_params) -> *mut rte_sched_port;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler port free"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    pub fn rte_sched_port_free(port: *mut rte_sched_port);
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler pipe profile add"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param subport_id"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param params"]
+    #[doc = "   Pipe profile parameters"]
+    #[doc = " @param pipe_profile_id"]
+    #[doc = "   Set to valid profile id when profile is added successfully."]
+    #[doc = " @return"]
+    #[doc = "   0 upon success, error code otherwise"]
+    pub fn rte_sched_subport_pipe_profile_add(
+        port: *mut rte_sched_port,
+        subport_id: u32,
+        params: *mut rte_sched_pipe_params,
+        pipe_profile_id: *mut u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler subport configuration"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param subport_id"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param params"]
+    #[doc = "   Subport configuration parameters"]
+    #[doc = " @return"]
+    #[doc = "   0 upon success, error code otherwise"]
+    pub fn rte_sched_subport_config(
+        port: *mut rte_sched_port,
+        subport_id: u32,
+        params: *mut rte_sched_subport_params,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler pipe configuration"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param subport_id"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param pipe_id"]
+    #[doc = "   Pipe ID within subport"]
+    #[doc = " @param pipe_profile"]
+    #[doc = "   ID of subport-level pre-configured pipe profile"]
+    #[doc = " @return"]
+    #[doc = "   0 upon success, error code otherwise"]
+    pub fn rte_sched_pipe_config(
+        port: *mut rte_sched_port,
+        subport_id: u32,
+        pipe_id: u32,
+        pipe_profile: i32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler subport statistics read"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param subport_id"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param stats"]
+    #[doc = "   Pointer to pre-allocated subport statistics structure where the statistics"]
+    #[doc = "   counters should be stored"]
+    #[doc = " @param tc_ov"]
+    #[doc = "   Pointer to pre-allocated RTE_SCHED_TRAFFIC_CLASSES_PER_PIPE-entry array"]
+    #[doc = "   where the oversubscription status for each of the subport traffic classes"]
+    #[doc = "   should be stored."]
+    #[doc = " @return"]
+    #[doc = "   0 upon success, error code otherwise"]
+    pub fn rte_sched_subport_read_stats(
+        port: *mut rte_sched_port,
+        subport_id: u32,
+        stats: *mut rte_sched_subport_stats,
+        tc_ov: *mut u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler queue statistics read"]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param queue_id"]
+    #[doc = "   Queue ID within port scheduler"]
+    #[doc = " @param stats"]
+    #[doc = "   Pointer to pre-allocated subport statistics structure where the statistics"]
+    #[doc = "   counters should be stored"]
+    #[doc = " @param qlen"]
+    #[doc = "   Pointer to pre-allocated variable where the current queue length"]
+    #[doc = "   should be stored."]
+    #[doc = " @return"]
+    #[doc = "   0 upon success, error code otherwise"]
+    pub fn rte_sched_queue_read_stats(
+        port: *mut rte_sched_port,
+        queue_id: u32,
+        stats: *mut rte_sched_queue_stats,
+        qlen: *mut u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Scheduler hierarchy path write to packet descriptor. Typically"]
+    #[doc = " called by the packet classification stage."]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param pkt"]
+    #[doc = "   Packet descriptor handle"]
+    #[doc = " @param subport"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param pipe"]
+    #[doc = "   Pipe ID within subport"]
+    #[doc = " @param traffic_class"]
+    #[doc = "   Traffic class ID within pipe (0 .. RTE_SCHED_TRAFFIC_CLASS_BE)"]
+    #[doc = " @param queue"]
+    #[doc = "   Queue ID within pipe traffic class, 0 for high priority TCs, and"]
+    #[doc = "   0 .. (RTE_SCHED_BE_QUEUES_PER_PIPE - 1) for best-effort TC"]
+    #[doc = " @param color"]
+    #[doc = "   Packet color set"]
+    pub fn rte_sched_port_pkt_write(
+        port: *mut rte_sched_port,
+        pkt: *mut rte_mbuf,
+        subport: u32,
+        pipe: u32,
+        traffic_class: u32,
+        queue: u32,
+        color: rte_color::Type,
+    );
+}
+extern "C" {
+    #[doc = " Scheduler hierarchy path read from packet descriptor (struct rte_mbuf). Typically"]
+    #[doc = " called as part of the hierarchical scheduler enqueue operation. The subport,"]
+    #[doc = " pipe, traffic class and queue parameters need to be pre-allocated by the caller."]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param pkt"]
+    #[doc = "   Packet descriptor handle"]
+    #[doc = " @param subport"]
+    #[doc = "   Subport ID"]
+    #[doc = " @param pipe"]
+    #[doc = "   Pipe ID within subport"]
+    #[doc = " @param traffic_class"]
+    #[doc = "   Traffic class ID within pipe (0 .. RTE_SCHED_TRAFFIC_CLASS_BE)"]
+    #[doc = " @param queue"]
+    #[doc = "   Queue ID within pipe traffic class, 0 for high priority TCs, and"]
+    #[doc = "   0 .. (RTE_SCHED_BE_QUEUES_PER_PIPE - 1) for best-effort TC"]
+    pub fn rte_sched_port_pkt_read_tree_path(
+        port: *mut rte_sched_port,
+        pkt: *const rte_mbuf,
+        subport: *mut u32,
+        pipe: *mut u32,
+        traffic_class: *mut u32,
+        queue: *mut u32,
+    );
+}
+extern "C" {
+    pub fn rte_sched_port_pkt_read_color(pkt: *const rte_mbuf) -> rte_color::Type;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler port enqueue. Writes up to n_pkts to port"]
+    #[doc = " scheduler and returns the number of packets actually written. For"]
+    #[doc = " each packet, the port scheduler queue to write the packet to is"]
+    #[doc = " identified by reading the hierarchy path from the packet"]
+    #[doc = " descriptor; if the queue is full or congested and the packet is not"]
+    #[doc = " written to the queue, then the packet is automatically dropped"]
+    #[doc = " without any action required from the caller."]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param pkts"]
+    #[doc = "   Array storing the packet descriptor handles"]
+    #[doc = " @param n_pkts"]
+    #[doc = "   Number of packets to enqueue from the pkts array into the port scheduler"]
+    #[doc = " @return"]
+    #[doc = "   Number of packets successfully enqueued"]
+    pub fn rte_sched_port_enqueue(
+        port: *mut rte_sched_port,
+        pkts: *mut *mut rte_mbuf,
+        n_pkts: u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Hierarchical scheduler port dequeue. Reads up to n_pkts from the"]
+    #[doc = " port scheduler and stores them in the pkts array and returns the"]
+    #[doc = " number of packets actually read.  The pkts array needs to be"]
+    #[doc = " pre-allocated by the caller with at least n_pkts entries."]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "   Handle to port scheduler instance"]
+    #[doc = " @param pkts"]
+    #[doc = "   Pre-allocated packet descriptor array where the packets dequeued"]
+    #[doc = "   from the port"]
+    #[doc = "   scheduler should be stored"]
+    #[doc = " @param n_pkts"]
+    #[doc = "   Number of packets to dequeue from the port scheduler"]
+    #[doc = " @return"]
+    #[doc = "   Number of packets successfully dequeued and placed in the pkts array"]
+    pub fn rte_sched_port_dequeue(
+        port: *mut rte_sched_port,
+        pkts: *mut *mut rte_mbuf,
+        n_pkts: u32,
+    ) -> ::std::os::raw::c_int;
+}
+pub mod rte_mtr_stats_type {
+    #[doc = " Statistics counter type"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " Number of packets passed as green by the policer."]
+    pub const RTE_MTR_STATS_N_PKTS_GREEN: Type = 1;
+    #[doc = " Number of packets passed as yellow by the policer."]
+    pub const RTE_MTR_STATS_N_PKTS_YELLOW: Type = 2;
+    #[doc = " Number of packets passed as red by the policer."]
+    pub const RTE_MTR_STATS_N_PKTS_RED: Type = 4;
+    #[doc = " Number of packets dropped by the policer."]
+    pub const RTE_MTR_STATS_N_PKTS_DROPPED: Type = 8;
+    #[doc = " Number of bytes passed as green by the policer."]
+    pub const RTE_MTR_STATS_N_BYTES_GREEN: Type = 16;
+    #[doc = " Number of bytes passed as yellow by the policer."]
+    pub const RTE_MTR_STATS_N_BYTES_YELLOW: Type = 32;
+    #[doc = " Number of bytes passed as red by the policer."]
+    pub const RTE_MTR_STATS_N_BYTES_RED: Type = 64;
+    #[doc = " Number of bytes dropped by the policer."]
+    pub const RTE_MTR_STATS_N_BYTES_DROPPED: Type = 128;
+}
+#[doc = " Statistics counters"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_mtr_stats {
+    #[doc = " Number of packets passed by the policer (per color)."]
+    pub n_pkts: [u64; 3usize],
+    #[doc = " Number of bytes passed by the policer (per color)."]
+    pub n_bytes: [u64; 3usize],
+    #[doc = " Number of packets dropped by the policer."]
+    pub n_pkts_dropped: u64,
+    #[doc = " Number of bytes passed by the policer."]
+    pub n_bytes_dropped: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_mtr_stats() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mtr_stats>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_mtr_stats))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mtr_stats>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mtr_stats))
+    );
+}
+pub mod rte_mtr_algorithm {
+    #[doc = " Traffic metering algorithms"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " No traffic metering performed, the output color is the same as the input"]
+    #[doc = " color for every input packet. The meter of the MTR object is working in"]
+    #[doc = " pass-through mode, having same effect as meter disable."]
+    #[doc = " @see rte_mtr_meter_disable()"]
+    pub const RTE_MTR_NONE: Type = 0;
+    #[doc = " Single Rate Three Color Marker (srTCM) - IETF RFC 2697."]
+    pub const RTE_MTR_SRTCM_RFC2697: Type = 1;
+    #[doc = " Two Rate Three Color Marker (trTCM) - IETF RFC 2698."]
+    pub const RTE_MTR_TRTCM_RFC2698: Type = 2;
+    #[doc = " Two Rate Three Color Marker (trTCM) - IETF RFC 4115."]
+    pub const RTE_MTR_TRTCM_RFC4115: Type = 3;
+}
+#[doc = " Meter profile"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_mtr_meter_profile {
+    #[doc = " Traffic metering algorithm."]
+    pub alg: rte_mtr_algorithm::Type,
+    pub __bindgen_anon_1: rte_mtr_meter_profile__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mtr_meter_profile__bindgen_ty_1 {
+    #[doc = " Items only valid when *alg* is set to srTCM - RFC 2697."]
+    pub srtcm_rfc2697: rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_1,
+    #[doc = " Items only valid when *alg* is set to trTCM - RFC 2698."]
+    pub trtcm_rfc2698: rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_2,
+    #[doc = " Items only valid when *alg* is set to trTCM - RFC 4115."]
+    pub trtcm_rfc4115: rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_3,
+    _bindgen_union_align: [u64; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = " Committed Information Rate (CIR) (bytes/second)."]
+    pub cir: u64,
+    #[doc = " Committed Burst Size (CBS) (bytes)."]
+    pub cbs: u64,
+    #[doc = " Excess Burst Size (EBS) (bytes)."]
+    pub ebs: u64,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_2 {
+    #[doc = " Committed Information Rate (CIR) (bytes/second)."]
+    pub cir: u64,
+    #[doc = " Peak Information Rate (PIR) (bytes/second)."]
+    pub pir: u64,
+    #[doc = " Committed Burst Size (CBS) (byes)."]
+    pub cbs: u64,
+    #[doc = " Peak Burst Size (PBS) (bytes)."]
+    pub pbs: u64,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_3 {
+    #[doc = " Committed Information Rate (CIR) (bytes/second)."]
+    pub cir: u64,
+    #[doc = " Excess Information Rate (EIR) (bytes/second)."]
+    pub eir: u64,
+    #[doc = " Committed Burst Size (CBS) (byes)."]
+    pub cbs: u64,
+    #[doc = " Excess Burst Size (EBS) (bytes)."]
+    pub ebs: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_mtr_meter_profile() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mtr_meter_profile>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_mtr_meter_profile))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mtr_meter_profile>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mtr_meter_profile))
+    );
+}
+impl Default for rte_mtr_meter_profile {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_mtr_policer_action {
+    #[doc = " Policer actions"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " Recolor the packet as green."]
+    pub const MTR_POLICER_ACTION_COLOR_GREEN: Type = 0;
+    #[doc = " Recolor the packet as yellow."]
+    pub const MTR_POLICER_ACTION_COLOR_YELLOW: Type = 1;
+    #[doc = " Recolor the packet as red."]
+    pub const MTR_POLICER_ACTION_COLOR_RED: Type = 2;
+    #[doc = " Drop the packet."]
+    pub const MTR_POLICER_ACTION_DROP: Type = 3;
+}
+#[doc = " Parameters for each traffic metering & policing object"]
+#[doc = ""]
+#[doc = " @see enum rte_mtr_stats_type"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mtr_params {
+    #[doc = " Meter profile ID."]
+    pub meter_profile_id: u32,
+    #[doc = " Meter input color in case of MTR object chaining. When non-zero: if"]
+    #[doc = " a previous MTR object is enabled in the same flow, then the color"]
+    #[doc = " determined by the latest MTR object in the same flow is used as the"]
+    #[doc = " input color by the current MTR object, otherwise the current MTR"]
+    #[doc = " object uses the *dscp_table* to determine the input color. When zero:"]
+    #[doc = " the color determined by any previous MTR object in same flow is"]
+    #[doc = " ignored by the current MTR object, which uses the *dscp_table* to"]
+    #[doc = " determine the input color."]
+    pub use_prev_mtr_color: ::std::os::raw::c_int,
+    #[doc = " Meter input color. When non-NULL: it points to a pre-allocated and"]
+    #[doc = " pre-populated table with exactly 64 elements providing the input"]
+    #[doc = " color for each value of the IPv4/IPv6 Differentiated Services Code"]
+    #[doc = " Point (DSCP) input packet field. When NULL: it is equivalent to"]
+    #[doc = " setting this parameter to an all-green populated table (i.e. table"]
+    #[doc = " with all the 64 elements set to green color). The color blind mode"]
+    #[doc = " is configured by setting *use_prev_mtr_color* to 0 and *dscp_table*"]
+    #[doc = " to either NULL or to an all-green populated table. When"]
+    #[doc = " *use_prev_mtr_color* is non-zero value or when *dscp_table* contains"]
+    #[doc = " at least one yellow or red color element, then the color aware mode"]
+    #[doc = " is configured."]
+    pub dscp_table: *mut rte_color::Type,
+    #[doc = " Non-zero to enable the meter, zero to disable the meter at the time"]
+    #[doc = " of MTR object creation. Ignored when the meter profile indicated by"]
+    #[doc = " *meter_profile_id* is set to NONE."]
+    #[doc = " @see rte_mtr_meter_disable()"]
+    pub meter_enable: ::std::os::raw::c_int,
+    #[doc = " Policer actions (per meter output color)."]
+    pub action: [rte_mtr_policer_action::Type; 3usize],
+    #[doc = " Set of stats counters to be enabled."]
+    #[doc = " @see enum rte_mtr_stats_type"]
+    pub stats_mask: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_mtr_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mtr_params>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_mtr_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mtr_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mtr_params))
+    );
+}
+impl Default for rte_mtr_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_mtr_error_type {
+    #[doc = " Verbose error types."]
+    #[doc = ""]
+    #[doc = " Most of them provide the type of the object referenced by struct"]
+    #[doc = " rte_mtr_error::cause."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< No error."]
+    pub const RTE_MTR_ERROR_TYPE_NONE: Type = 0;
+    #[doc = "< Cause unspecified."]
+    pub const RTE_MTR_ERROR_TYPE_UNSPECIFIED: Type = 1;
+    pub const RTE_MTR_ERROR_TYPE_METER_PROFILE_ID: Type = 2;
+    pub const RTE_MTR_ERROR_TYPE_METER_PROFILE: Type = 3;
+    pub const RTE_MTR_ERROR_TYPE_MTR_ID: Type = 4;
+    pub const RTE_MTR_ERROR_TYPE_MTR_PARAMS: Type = 5;
+    pub const RTE_MTR_ERROR_TYPE_POLICER_ACTION_GREEN: Type = 6;
+    pub const RTE_MTR_ERROR_TYPE_POLICER_ACTION_YELLOW: Type = 7;
+    pub const RTE_MTR_ERROR_TYPE_POLICER_ACTION_RED: Type = 8;
+    pub const RTE_MTR_ERROR_TYPE_STATS_MASK: Type = 9;
+    pub const RTE_MTR_ERROR_TYPE_STATS: Type = 10;
+    pub const RTE_MTR_ERROR_TYPE_SHARED: Type = 11;
+}
+#[doc = " Verbose error structure definition."]
+#[doc = ""]
+#[doc = " This object is normally allocated by applications and set by PMDs, the"]
+#[doc = " message points to a constant string which does not need to be freed by"]
+#[doc = " the application, however its pointer can be considered valid only as long"]
+#[doc = " as its associated DPDK port remains configured. Closing the underlying"]
+#[doc = " device or unloading the PMD invalidates it."]
+#[doc = ""]
+#[doc = " Both cause and message may be NULL regardless of the error type."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mtr_error {
+    #[doc = "< Cause field and error type."]
+    pub type_: rte_mtr_error_type::Type,
+    #[doc = "< Object responsible for the error."]
+    pub cause: *const ::std::os::raw::c_void,
+    #[doc = "< Human-readable error message."]
+    pub message: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_rte_mtr_error() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mtr_error>(),
+        24usize,
+        concat!("Size of: ", stringify!(rte_mtr_error))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mtr_error>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mtr_error))
+    );
+}
+impl Default for rte_mtr_error {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " MTR capabilities"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_mtr_capabilities {
+    #[doc = " Maximum number of MTR objects."]
+    pub n_max: u32,
+    #[doc = " Maximum number of MTR objects that can be shared by multiple flows."]
+    #[doc = " The value of zero indicates that shared MTR objects are not"]
+    #[doc = " supported. The maximum value is *n_max*."]
+    pub n_shared_max: u32,
+    #[doc = " When non-zero, this flag indicates that all the MTR objects that"]
+    #[doc = " cannot be shared by multiple flows have identical capability set."]
+    pub identical: ::std::os::raw::c_int,
+    #[doc = " When non-zero, this flag indicates that all the MTR objects that"]
+    #[doc = " can be shared by multiple flows have identical capability set."]
+    pub shared_identical: ::std::os::raw::c_int,
+    #[doc = " Maximum number of flows that can share the same MTR object. The"]
+    #[doc = " value of zero is invalid. The value of 1 means that shared MTR"]
+    #[doc = " objects not supported."]
+    pub shared_n_flows_per_mtr_max: u32,
+    #[doc = " Maximum number of MTR objects that can be part of the same flow. The"]
+    #[doc = " value of zero is invalid. The value of 1 indicates that MTR object"]
+    #[doc = " chaining is not supported. The maximum value is *n_max*."]
+    pub chaining_n_mtrs_per_flow_max: u32,
+    #[doc = " When non-zero, it indicates that the packet color identified by one"]
+    #[doc = " MTR object can be used as the packet input color by any subsequent"]
+    #[doc = " MTR object from the same flow. When zero, it indicates that the"]
+    #[doc = " color determined by one MTR object is always ignored by any"]
+    #[doc = " subsequent MTR object from the same flow. Only valid when MTR"]
+    #[doc = " chaining is supported, i.e. *chaining_n_mtrs_per_flow_max* is"]
+    #[doc = " greater than 1. When non-zero, it also means that the color aware"]
+    #[doc = " mode is supported by at least one metering algorithm."]
+    pub chaining_use_prev_mtr_color_supported: ::std::os::raw::c_int,
+    #[doc = " When non-zero, it indicates that the packet color identified by one"]
+    #[doc = " MTR object is always used as the packet input color by any"]
+    #[doc = " subsequent MTR object that is part of the same flow. When zero, it"]
+    #[doc = " indicates that whether the color determined by one MTR object is"]
+    #[doc = " either ignored or used as the packet input color by any subsequent"]
+    #[doc = " MTR object from the same flow is individually configurable for each"]
+    #[doc = " MTR object. Only valid when *chaining_use_prev_mtr_color_supported*"]
+    #[doc = " is non-zero."]
+    pub chaining_use_prev_mtr_color_enforced: ::std::os::raw::c_int,
+    #[doc = " Maximum number of MTR objects that can have their meter configured"]
+    #[doc = " to run the srTCM RFC 2697 algorithm. The value of 0 indicates this"]
+    #[doc = " metering algorithm is not supported. The maximum value is *n_max*."]
+    pub meter_srtcm_rfc2697_n_max: u32,
+    #[doc = " Maximum number of MTR objects that can have their meter configured"]
+    #[doc = " to run the trTCM RFC 2698 algorithm. The value of 0 indicates this"]
+    #[doc = " metering algorithm is not supported. The maximum value is *n_max*."]
+    pub meter_trtcm_rfc2698_n_max: u32,
+    #[doc = " Maximum number of MTR objects that can have their meter configured"]
+    #[doc = " to run the trTCM RFC 4115 algorithm. The value of 0 indicates this"]
+    #[doc = " metering algorithm is not supported. The maximum value is *n_max*."]
+    pub meter_trtcm_rfc4115_n_max: u32,
+    #[doc = " Maximum traffic rate that can be metered by a single MTR object. For"]
+    #[doc = " srTCM RFC 2697, this is the maximum CIR rate. For trTCM RFC 2698,"]
+    #[doc = " this is the maximum PIR rate. For trTCM RFC 4115, this is the maximum"]
+    #[doc = " value for the sum of PIR and EIR rates."]
+    pub meter_rate_max: u64,
+    #[doc = " When non-zero, it indicates that color aware mode is supported for"]
+    #[doc = " the srTCM RFC 2697 metering algorithm."]
+    pub color_aware_srtcm_rfc2697_supported: ::std::os::raw::c_int,
+    #[doc = " When non-zero, it indicates that color aware mode is supported for"]
+    #[doc = " the trTCM RFC 2698 metering algorithm."]
+    pub color_aware_trtcm_rfc2698_supported: ::std::os::raw::c_int,
+    #[doc = " When non-zero, it indicates that color aware mode is supported for"]
+    #[doc = " the trTCM RFC 4115 metering algorithm."]
+    pub color_aware_trtcm_rfc4115_supported: ::std::os::raw::c_int,
+    #[doc = " When non-zero, it indicates that the policer packet recolor actions"]
+    #[doc = " are supported."]
+    #[doc = " @see enum rte_mtr_policer_action"]
+    pub policer_action_recolor_supported: ::std::os::raw::c_int,
+    #[doc = " When non-zero, it indicates that the policer packet drop action is"]
+    #[doc = " supported."]
+    #[doc = " @see enum rte_mtr_policer_action"]
+    pub policer_action_drop_supported: ::std::os::raw::c_int,
+    #[doc = " Set of supported statistics counter types."]
+    #[doc = " @see enum rte_mtr_stats_type"]
+    pub stats_mask: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_mtr_capabilities() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_mtr_capabilities>(),
+        88usize,
+        concat!("Size of: ", stringify!(rte_mtr_capabilities))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_mtr_capabilities>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_mtr_capabilities))
+    );
+}
+extern "C" {
+    #[doc = " MTR capabilities get"]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[out] cap"]
+    #[doc = "   MTR capabilities. Needs to be pre-allocated and valid."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_capabilities_get(
+        port_id: u16,
+        cap: *mut rte_mtr_capabilities,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Meter profile add"]
+    #[doc = ""]
+    #[doc = " Create a new meter profile with ID set to *meter_profile_id*. The new profile"]
+    #[doc = " is used to create one or several MTR objects."]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] meter_profile_id"]
+    #[doc = "   ID for the new meter profile. Needs to be unused by any of the existing"]
+    #[doc = " meter profiles added for the current port."]
+    #[doc = " @param[in] profile"]
+    #[doc = "   Meter profile parameters. Needs to be pre-allocated and valid."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_profile_add(
+        port_id: u16,
+        meter_profile_id: u32,
+        profile: *mut rte_mtr_meter_profile,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Meter profile delete"]
+    #[doc = ""]
+    #[doc = " Delete an existing meter profile. This operation fails when there is"]
+    #[doc = " currently at least one user (i.e. MTR object) of this profile."]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] meter_profile_id"]
+    #[doc = "   Meter profile ID. Needs to be the valid."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_profile_delete(
+        port_id: u16,
+        meter_profile_id: u32,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object create"]
+    #[doc = ""]
+    #[doc = " Create a new MTR object for the current port. This object is run as part of"]
+    #[doc = " associated flow action for traffic metering and policing."]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be unused by any of the existing MTR objects."]
+    #[doc = " created for the current port."]
+    #[doc = " @param[in] params"]
+    #[doc = "   MTR object params. Needs to be pre-allocated and valid."]
+    #[doc = " @param[in] shared"]
+    #[doc = "   Non-zero when this MTR object can be shared by multiple flows, zero when"]
+    #[doc = " this MTR object can be used by a single flow."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_create(
+        port_id: u16,
+        mtr_id: u32,
+        params: *mut rte_mtr_params,
+        shared: ::std::os::raw::c_int,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object destroy"]
+    #[doc = ""]
+    #[doc = " Delete an existing MTR object. This operation fails when there is currently"]
+    #[doc = " at least one user (i.e. flow) of this MTR object."]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " created for the current port."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_destroy(
+        port_id: u16,
+        mtr_id: u32,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object meter disable"]
+    #[doc = ""]
+    #[doc = " Disable the meter of an existing MTR object. In disabled state, the meter of"]
+    #[doc = " the current MTR object works in pass-through mode, meaning that for each"]
+    #[doc = " input packet the meter output color is always the same as the input color."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_disable(
+        port_id: u16,
+        mtr_id: u32,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object meter enable"]
+    #[doc = ""]
+    #[doc = " Enable the meter of an existing MTR object. If the MTR object has its meter"]
+    #[doc = " already enabled, then this operation has no effect."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_enable(
+        port_id: u16,
+        mtr_id: u32,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object meter profile update"]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " @param[in] meter_profile_id"]
+    #[doc = "   Meter profile ID for the current MTR object. Needs to be valid."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_profile_update(
+        port_id: u16,
+        mtr_id: u32,
+        meter_profile_id: u32,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object DSCP table update"]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " @param[in] dscp_table"]
+    #[doc = "   When non-NULL: it points to a pre-allocated and pre-populated table with"]
+    #[doc = "   exactly 64 elements providing the input color for each value of the"]
+    #[doc = " IPv4/IPv6 Differentiated Services Code Point (DSCP) input packet field."]
+    #[doc = " When NULL: it is equivalent to setting this parameter to an \"all-green\""]
+    #[doc = " populated table (i.e. table with all the 64 elements set to green color)."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_meter_dscp_table_update(
+        port_id: u16,
+        mtr_id: u32,
+        dscp_table: *mut rte_color::Type,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object policer actions update"]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " @param[in] action_mask"]
+    #[doc = "   Bit mask indicating which policer actions need to be updated. One or more"]
+    #[doc = "   policer actions can be updated in a single function invocation. To update"]
+    #[doc = "   the policer action associated with color C, bit (1 << C) needs to be set in"]
+    #[doc = "   *action_mask* and element at position C in the *actions* array needs to be"]
+    #[doc = "   valid."]
+    #[doc = " @param[in] actions"]
+    #[doc = "   Pre-allocated and pre-populated array of policer actions."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_mtr_policer_actions_update(
+        port_id: u16,
+        mtr_id: u32,
+        action_mask: u32,
+        actions: *mut rte_mtr_policer_action::Type,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object enabled statistics counters update"]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " @param[in] stats_mask"]
+    #[doc = "   Mask of statistics counter types to be enabled for the current MTR object."]
+    #[doc = " Any statistics counter type not included in this set is to be disabled for"]
+    #[doc = " the current MTR object."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    #[doc = ""]
+    #[doc = " @see enum rte_mtr_stats_type"]
+    pub fn rte_mtr_stats_update(
+        port_id: u16,
+        mtr_id: u32,
+        stats_mask: u64,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " MTR object statistics counters read"]
+    #[doc = ""]
+    #[doc = " @param[in] port_id"]
+    #[doc = "   The port identifier of the Ethernet device."]
+    #[doc = " @param[in] mtr_id"]
+    #[doc = "   MTR object ID. Needs to be valid."]
+    #[doc = " @param[out] stats"]
+    #[doc = "   When non-NULL, it contains the current value for the statistics counters"]
+    #[doc = "   enabled for the current MTR object."]
+    #[doc = " @param[out] stats_mask"]
+    #[doc = "   When non-NULL, it contains the mask of statistics counter types that are"]
+    #[doc = "   currently enabled for this MTR object, indicating which of the counters"]
+    #[doc = "   retrieved with the *stats* structure are valid."]
+    #[doc = " @param[in] clear"]
+    #[doc = "   When this parameter has a non-zero value, the statistics counters are"]
+    #[doc = "   cleared (i.e. set to zero) immediately after they have been read,"]
+    #[doc = "   otherwise the statistics counters are left untouched."]
+    #[doc = " @param[out] error"]
+    #[doc = "   Error details. Filled in only on error, when not NULL."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    #[doc = ""]
+    #[doc = " @see enum rte_mtr_stats_type"]
+    pub fn rte_mtr_stats_read(
+        port_id: u16,
+        mtr_id: u32,
+        stats: *mut rte_mtr_stats,
+        stats_mask: *mut u64,
+        clear: ::std::os::raw::c_int,
+        error: *mut rte_mtr_error,
+    ) -> ::std::os::raw::c_int;
+}
+pub mod rte_tm_stats_type {
+    #[doc = " Node statistics counter type"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = " Number of packets scheduled from current node."]
+    pub const RTE_TM_STATS_N_PKTS: Type = 1;
+    #[doc = " Number of bytes scheduled from current node."]
+    pub const RTE_TM_STATS_N_BYTES: Type = 2;
+    #[doc = " Number of green packets dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_PKTS_GREEN_DROPPED: Type = 4;
+    #[doc = " Number of yellow packets dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_PKTS_YELLOW_DROPPED: Type = 8;
+    #[doc = " Number of red packets dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_PKTS_RED_DROPPED: Type = 16;
+    #[doc = " Number of green bytes dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_BYTES_GREEN_DROPPED: Type = 32;
+    #[doc = " Number of yellow bytes dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_BYTES_YELLOW_DROPPED: Type = 64;
+    #[doc = " Number of red bytes dropped by current leaf node."]
+    pub const RTE_TM_STATS_N_BYTES_RED_DROPPED: Type = 128;
+    #[doc = " Number of packets currently waiting in the packet queue of current leaf node."]
+    pub const RTE_TM_STATS_N_PKTS_QUEUED: Type = 256;
+    #[doc = " Number of bytes currently waiting in the packet queue of current leaf node."]
+    pub const RTE_TM_STATS_N_BYTES_QUEUED: Type = 512;
+}
+#[doc = " Node statistics counters"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_node_stats {
+    #[doc = " Number of packets scheduled from current node."]
+    pub n_pkts: u64,
+    #[doc = " Number of bytes scheduled from current node."]
+    pub n_bytes: u64,
+    pub leaf: rte_tm_node_stats__bindgen_ty_1,
+}
+#[doc = " Statistics counters for leaf nodes only."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_node_stats__bindgen_ty_1 {
+    #[doc = " Number of packets dropped by current leaf node per each"]
+    #[doc = " color."]
+    pub n_pkts_dropped: [u64; 3usize],
+    #[doc = " Number of bytes dropped by current leaf node per each"]
+    #[doc = " color."]
+    pub n_bytes_dropped: [u64; 3usize],
+    #[doc = " Number of packets currently waiting in the packet queue of"]
+    #[doc = " current leaf node."]
+    pub n_pkts_queued: u64,
+    #[doc = " Number of bytes currently waiting in the packet queue of"]
+    #[doc = " current leaf node."]
+    pub n_bytes_queued: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_node_stats() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_node_stats>(),
+        80usize,
+        concat!("Size of: ", stringify!(rte_tm_node_stats))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_node_stats>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_node_stats))
+    );
+}
+pub mod rte_tm_cman_mode {
+    #[doc = " Congestion management (CMAN) mode"]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< Tail drop"]
+    pub const RTE_TM_CMAN_TAIL_DROP: Type = 0;
+    #[doc = "< Head drop"]
+    pub const RTE_TM_CMAN_HEAD_DROP: Type = 1;
+    #[doc = "< Weighted Random Early Detection (WRED)"]
+    pub const RTE_TM_CMAN_WRED: Type = 2;
+}
+#[doc = " Random Early Detection (RED) profile"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_red_params {
+    #[doc = " Minimum queue threshold"]
+    pub min_th: u64,
+    #[doc = " Maximum queue threshold"]
+    pub max_th: u64,
+    #[doc = " Inverse of packet marking probability maximum value (maxp), i.e."]
+    #[doc = " maxp_inv = 1 / maxp"]
+    pub maxp_inv: u16,
+    #[doc = " Negated log2 of queue weight (wq), i.e. wq = 1 / (2 ^ wq_log2)"]
+    pub wq_log2: u16,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_red_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_red_params>(),
+        24usize,
+        concat!("Size of: ", stringify!(rte_tm_red_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_red_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_red_params))
+    );
+}
+#[doc = " Weighted RED (WRED) profile"]
+#[doc = ""]
+#[doc = " Multiple WRED contexts can share the same WRED profile. Each leaf node with"]
+#[doc = " WRED enabled as its congestion management mode has zero or one private WRED"]
+#[doc = " context (only one leaf node using it) and/or zero, one or several shared"]
+#[doc = " WRED contexts (multiple leaf nodes use the same WRED context). A private"]
+#[doc = " WRED context is used to perform congestion management for a single leaf"]
+#[doc = " node, while a shared WRED context is used to perform congestion management"]
+#[doc = " for a group of leaf nodes."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_wred_params {
+    #[doc = " One set of RED parameters per packet color"]
+    pub red_params: [rte_tm_red_params; 3usize],
+    #[doc = " When non-zero, the *min_th* and *max_th* thresholds are specified"]
+    #[doc = " in packets (WRED packet mode). When zero, the *min_th* and *max_th*"]
+    #[doc = " thresholds are specified in bytes (WRED byte mode)"]
+    pub packet_mode: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_wred_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_wred_params>(),
+        80usize,
+        concat!("Size of: ", stringify!(rte_tm_wred_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_wred_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_wred_params))
+    );
+}
+#[doc = " Token bucket"]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_token_bucket {
+    #[doc = "< Token bucket rate (bytes per second or packets per second)"]
+    pub rate: u64,
+    #[doc = "< Token bucket size (bytes or packets), a.k.a. max burst size"]
+    pub size: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_token_bucket() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_token_bucket>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_tm_token_bucket))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_token_bucket>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_token_bucket))
+    );
+}
+#[doc = " Shaper (rate limiter) profile"]
+#[doc = ""]
+#[doc = " Multiple shaper instances can share the same shaper profile. Each node has"]
+#[doc = " zero or one private shaper (only one node using it) and/or zero, one or"]
+#[doc = " several shared shapers (multiple nodes use the same shaper instance)."]
+#[doc = " A private shaper is used to perform traffic shaping for a single node, while"]
+#[doc = " a shared shaper is used to perform traffic shaping for a group of nodes."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct rte_tm_shaper_params {
+    #[doc = " Committed token bucket"]
+    pub committed: rte_tm_token_bucket,
+    #[doc = " Peak token bucket"]
+    pub peak: rte_tm_token_bucket,
+    #[doc = " Signed value to be added to the length of each packet for the"]
+    #[doc = " purpose of shaping. Can be used to correct the packet length with"]
+    #[doc = " the framing overhead bytes that are also consumed on the wire (e.g."]
+    #[doc = " RTE_TM_ETH_FRAMING_OVERHEAD_FCS)."]
+    #[doc = " This field is ignored when the profile enables packet mode."]
+    pub pkt_length_adjust: i32,
+    #[doc = " When zero, the byte mode is enabled for the current profile, so the"]
+    #[doc = " *rate* and *size* fields in both the committed and peak token buckets"]
+    #[doc = " are specified in bytes per second and bytes, respectively."]
+    #[doc = " When non-zero, the packet mode is enabled for the current profile,"]
+    #[doc = " so the *rate* and *size* fields in both the committed and peak token"]
+    #[doc = " buckets are specified in packets per second and packets,"]
+    #[doc = " respectively."]
+    pub packet_mode: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_shaper_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_shaper_params>(),
+        40usize,
+        concat!("Size of: ", stringify!(rte_tm_shaper_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_shaper_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_shaper_params))
+    );
+}
+#[doc = " Node parameters"]
+#[doc = ""]
+#[doc = " Each non-leaf node has multiple inputs (its children nodes) and single output"]
+#[doc = " (which is input to its parent node). It arbitrates its inputs using Strict"]
+#[doc = " Priority (SP) and Weighted Fair Queuing (WFQ) algorithms to schedule input"]
+#[doc = " packets to its output while observing its shaping (rate limiting)"]
+#[doc = " constraints."]
+#[doc = ""]
+#[doc = " Each leaf node sits on top of a TX queue of the current Ethernet port. Hence,"]
+#[doc = " the leaf nodes are predefined, with their node IDs set to 0 .. (N-1), where N"]
+#[doc = " is the number of TX queues configured for the current Ethernet port. The"]
+#[doc = " non-leaf nodes have their IDs generated by the application."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_tm_node_params {
+    #[doc = " Shaper profile for the private shaper. The absence of the private"]
+    #[doc = " shaper for the current node is indicated by setting this parameter"]
+    #[doc = " to RTE_TM_SHAPER_PROFILE_ID_NONE."]
+    pub shaper_profile_id: u32,
+    #[doc = " User allocated array of valid shared shaper IDs."]
+    pub shared_shaper_id: *mut u32,
+    #[doc = " Number of shared shaper IDs in the *shared_shaper_id* array."]
+    pub n_shared_shapers: u32,
+    pub __bindgen_anon_1: rte_tm_node_params__bindgen_ty_1,
+    #[doc = " Mask of statistics counter types to be enabled for this node. This"]
+    #[doc = " needs to be a subset of the statistics counter types available for"]
+    #[doc = " the current node. Any statistics counter type not included in this"]
+    #[doc = " set is to be disabled for the current node."]
+    #[doc = " @see enum rte_tm_stats_type"]
+    pub stats_mask: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_tm_node_params__bindgen_ty_1 {
+    #[doc = " Parameters only valid for non-leaf nodes."]
+    pub nonleaf: rte_tm_node_params__bindgen_ty_1__bindgen_ty_1,
+    #[doc = " Parameters only valid for leaf nodes."]
+    pub leaf: rte_tm_node_params__bindgen_ty_1__bindgen_ty_2,
+    _bindgen_union_align: [u64; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_tm_node_params__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = " WFQ weight mode for each SP priority. When NULL, it"]
+    #[doc = " indicates that WFQ is to be used for all priorities."]
+    #[doc = " When non-NULL, it points to a pre-allocated array of"]
+    #[doc = " *n_sp_priorities* values, with non-zero value for"]
+    #[doc = " byte-mode and zero for packet-mode."]
+    pub wfq_weight_mode: *mut ::std::os::raw::c_int,
+    #[doc = " Number of SP priorities."]
+    pub n_sp_priorities: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_tm_node_params__bindgen_ty_1__bindgen_ty_2 {
+    #[doc = " Congestion management mode"]
+    pub cman: rte_tm_cman_mode::Type,
+    #[doc = " WRED parameters (only valid when *cman* is set to"]
+    #[doc = " WRED)."]
+    pub wred: rte_tm_node_params__bindgen_ty_1__bindgen_ty_2__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_tm_node_params__bindgen_ty_1__bindgen_ty_2__bindgen_ty_1 {
+    #[doc = " WRED profile for private WRED context. The"]
+    #[doc = " absence of a private WRED context for the"]
+    #[doc = " current leaf node is indicated by value"]
+    #[doc = " RTE_TM_WRED_PROFILE_ID_NONE."]
+    pub wred_profile_id: u32,
+    #[doc = " User allocated array of shared WRED context"]
+    #[doc = " IDs. When set to NULL, it indicates that the"]
+    #[doc = " current leaf node should not currently be"]
+    #[doc = " part of any shared WRED contexts."]
+    pub shared_wred_context_id: *mut u32,
+    #[doc = " Number of elements in the"]
+    #[doc = " *shared_wred_context_id* array. Only valid"]
+    #[doc = " when *shared_wred_context_id* is non-NULL,"]
+    #[doc = " in which case it should be non-zero."]
+    pub n_shared_wred_contexts: u32,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_node_params() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_node_params>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_tm_node_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_node_params>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_node_params))
+    );
+}
+impl Default for rte_tm_node_params {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+pub mod rte_tm_error_type {
+    #[doc = " Verbose error types."]
+    #[doc = ""]
+    #[doc = " Most of them provide the type of the object referenced by struct"]
+    #[doc = " rte_tm_error::cause."]
+    pub type Type = ::std::os::raw::c_uint;
+    #[doc = "< No error."]
+    pub const RTE_TM_ERROR_TYPE_NONE: Type = 0;
+    #[doc = "< Cause unspecified."]
+    pub const RTE_TM_ERROR_TYPE_UNSPECIFIED: Type = 1;
+    pub const RTE_TM_ERROR_TYPE_CAPABILITIES: Type = 2;
+    pub const RTE_TM_ERROR_TYPE_LEVEL_ID: Type = 3;
+    pub const RTE_TM_ERROR_TYPE_WRED_PROFILE: Type = 4;
+    pub const RTE_TM_ERROR_TYPE_WRED_PROFILE_GREEN: Type = 5;
+    pub const RTE_TM_ERROR_TYPE_WRED_PROFILE_YELLOW: Type = 6;
+    pub const RTE_TM_ERROR_TYPE_WRED_PROFILE_RED: Type = 7;
+    pub const RTE_TM_ERROR_TYPE_WRED_PROFILE_ID: Type = 8;
+    pub const RTE_TM_ERROR_TYPE_SHARED_WRED_CONTEXT_ID: Type = 9;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE: Type = 10;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_COMMITTED_RATE: Type = 11;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_COMMITTED_SIZE: Type = 12;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PEAK_RATE: Type = 13;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PEAK_SIZE: Type = 14;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PKT_ADJUST_LEN: Type = 15;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PACKET_MODE: Type = 16;
+    pub const RTE_TM_ERROR_TYPE_SHAPER_PROFILE_ID: Type = 17;
+    pub const RTE_TM_ERROR_TYPE_SHARED_SHAPER_ID: Type = 18;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARENT_NODE_ID: Type = 19;
+    pub const RTE_TM_ERROR_TYPE_NODE_PRIORITY: Type = 20;
+    pub const RTE_TM_ERROR_TYPE_NODE_WEIGHT: Type = 21;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS: Type = 22;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_SHAPER_PROFILE_ID: Type = 23;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_SHARED_SHAPER_ID: Type = 24;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SHARED_SHAPERS: Type = 25;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_WFQ_WEIGHT_MODE: Type = 26;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SP_PRIORITIES: Type = 27;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_CMAN: Type = 28;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_WRED_PROFILE_ID: Type = 29;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_SHARED_WRED_CONTEXT_ID: Type = 30;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SHARED_WRED_CONTEXTS: Type = 31;
+    pub const RTE_TM_ERROR_TYPE_NODE_PARAMS_STATS: Type = 32;
+    pub const RTE_TM_ERROR_TYPE_NODE_ID: Type = 33;
+}
+#[doc = " Verbose error structure definition."]
+#[doc = ""]
+#[doc = " This object is normally allocated by applications and set by PMDs, the"]
+#[doc = " message points to a constant string which does not need to be freed by"]
+#[doc = " the application, however its pointer can be considered valid only as long"]
+#[doc = " as its associated DPDK port remains configured. Closing the underlying"]
+#[doc = " device or unloading the PMD invalidates it."]
+#[doc = ""]
+#[doc = " Both cause and message may be NULL regardless of the error type."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_tm_error {
+    #[doc = "< Cause field and error type."]
+    pub type_: rte_tm_error_type::Type,
+    #[doc = "< Object responsible for the error."]
+    pub cause: *const ::std::os::raw::c_void,
+    #[doc = "< Human-readable error message."]
+    pub message: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_rte_tm_error() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_tm_error>(),
+        24usize,
+        concat!("Size of: ", stringify!(rte_tm_error))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_tm_error>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_tm_error))
+    );
+}
+impl Default for rte_tm_error {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Get number of leaf nodes"]
+    #[doc = ""]
+    #[doc = " Each leaf node sits on top of a TX queue of the current Ethernet port."]
+    #[doc = " Therefore, the set of leaf nodes is predefined, their number is always equal"]
+    #[doc = " to N (where N is the number of TX queues configured for the current port)"]
+    #[doc = " and their IDs are 0 .. (N-1)."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_get_number_of_leaf_nodes(
+        port_id: u16,
+        n_leaf_nodes: *mut u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Node type (i.e. leaf or non-leaf) get"]
+    #[doc = ""]
+    #[doc = " The leaf nodes have predefined IDs in the range of 0 .. (N-1), where N is"]
+    #[doc = " the number of TX queues of the current Ethernet port. The non-leaf nodes"]
+    #[doc = " have their IDs generated by the application outside of the above range,"]
+    #[doc = " which is reserved for leaf nodes."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_type_get(
+        port_id: u16,
+        node_id: u32,
+        is_leaf: *mut ::std::os::raw::c_int,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager WRED profile add"]
+    #[doc = ""]
+    #[doc = " Create a new WRED profile with ID set to *wred_profile_id*. The new profile"]
+    #[doc = " is used to create one or several WRED contexts."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_wred_profile_add(
+        port_id: u16,
+        wred_profile_id: u32,
+        profile: *mut rte_tm_wred_params,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager WRED profile delete"]
+    #[doc = ""]
+    #[doc = " Delete an existing WRED profile. This operation fails when there is"]
+    #[doc = " currently at least one user (i.e. WRED context) of this WRED profile."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_wred_profile_delete(
+        port_id: u16,
+        wred_profile_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager shaper profile add"]
+    #[doc = ""]
+    #[doc = " Create a new shaper profile with ID set to *shaper_profile_id*. The new"]
+    #[doc = " shaper profile is used to create one or several shapers."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_shaper_profile_add(
+        port_id: u16,
+        shaper_profile_id: u32,
+        profile: *mut rte_tm_shaper_params,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager shaper profile delete"]
+    #[doc = ""]
+    #[doc = " Delete an existing shaper profile. This operation fails when there is"]
+    #[doc = " currently at least one user (i.e. shaper) of this shaper profile."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_shaper_profile_delete(
+        port_id: u16,
+        shaper_profile_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager shared shaper add/update"]
+    #[doc = ""]
+    #[doc = " When *shared_shaper_id* is invalid, a new shared shaper with this ID is"]
+    #[doc = " created by using the shaper profile identified by *shaper_profile_id*."]
+    #[doc = ""]
+    #[doc = " When *shared_shaper_id* is valid, this shared shaper is no longer using the"]
+    #[doc = " shaper profile it was previously using, but it is now using the shaper"]
+    #[doc = " profile identified by *shaper_profile_id*."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_shared_shaper_add_update(
+        port_id: u16,
+        shared_shaper_id: u32,
+        shaper_profile_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager shared shaper delete"]
+    #[doc = ""]
+    #[doc = " Delete an existing shared shaper. This operation fails when there is"]
+    #[doc = " currently at least one user (i.e. hierarchy node) of this shared shaper."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_shared_shaper_delete(
+        port_id: u16,
+        shared_shaper_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node add"]
+    #[doc = ""]
+    #[doc = " Create new node and connect it as child of an existing node. The new node is"]
+    #[doc = " further identified by *node_id*, which needs to be unused by any of the"]
+    #[doc = " existing nodes. The parent node is identified by *parent_node_id*, which"]
+    #[doc = " needs to be the valid ID of an existing non-leaf node. The parent node is"]
+    #[doc = " going to use the provided SP *priority* and WFQ *weight* to schedule its new"]
+    #[doc = " child node."]
+    #[doc = ""]
+    #[doc = " This function has to be called for both leaf and non-leaf nodes. In the case"]
+    #[doc = " of leaf nodes (i.e. *node_id* is within the range of 0 .. (N-1), with N as"]
+    #[doc = " the number of configured TX queues of the current port), the leaf node is"]
+    #[doc = " configured rather than created (as the set of leaf nodes is predefined) and"]
+    #[doc = " it is also connected as child of an existing node."]
+    #[doc = ""]
+    #[doc = " The first node that is added becomes the root node and all the nodes that"]
+    #[doc = " are subsequently added have to be added as descendants of the root node. The"]
+    #[doc = " parent of the root node has to be specified as RTE_TM_NODE_ID_NULL and there"]
+    #[doc = " can only be one node with this parent ID (i.e. the root node). Further"]
+    #[doc = " restrictions for root node: needs to be non-leaf, its private shaper profile"]
+    #[doc = " needs to be valid and single rate, cannot use any shared shapers."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_add(
+        port_id: u16,
+        node_id: u32,
+        parent_node_id: u32,
+        priority: u32,
+        weight: u32,
+        level_id: u32,
+        params: *mut rte_tm_node_params,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node delete"]
+    #[doc = ""]
+    #[doc = " Delete an existing node. This operation fails when this node currently has"]
+    #[doc = " at least one user (i.e. child node)."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_delete(
+        port_id: u16,
+        node_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node suspend"]
+    #[doc = ""]
+    #[doc = " Suspend an existing node. While the node is in suspended state, no packet is"]
+    #[doc = " scheduled from this node and its descendants. The node exits the suspended"]
+    #[doc = " state through the node resume operation."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_suspend(
+        port_id: u16,
+        node_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node resume"]
+    #[doc = ""]
+    #[doc = " Resume an existing node that is currently in suspended state. The node"]
+    #[doc = " entered the suspended state as result of a previous node suspend operation."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_resume(
+        port_id: u16,
+        node_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager hierarchy commit"]
+    #[doc = ""]
+    #[doc = " This function is called during the port initialization phase (before the"]
+    #[doc = " Ethernet port is started) to freeze the start-up hierarchy."]
+    #[doc = ""]
+    #[doc = " This function typically performs the following steps:"]
+    #[doc = "    a) It validates the start-up hierarchy that was previously defined for the"]
+    #[doc = "       current port through successive rte_tm_node_add() invocations;"]
+    #[doc = "    b) Assuming successful validation, it performs all the necessary port"]
+    #[doc = "       specific configuration operations to install the specified hierarchy on"]
+    #[doc = "       the current port, with immediate effect once the port is started."]
+    #[doc = ""]
+    #[doc = " This function fails when the currently configured hierarchy is not supported"]
+    #[doc = " by the Ethernet port, in which case the user can abort or try out another"]
+    #[doc = " hierarchy configuration (e.g. a hierarchy with less leaf nodes), which can be"]
+    #[doc = " built from scratch (when *clear_on_fail* is enabled) or by modifying the"]
+    #[doc = " existing hierarchy configuration (when *clear_on_fail* is disabled)."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_hierarchy_commit(
+        port_id: u16,
+        clear_on_fail: ::std::os::raw::c_int,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node parent update"]
+    #[doc = ""]
+    #[doc = " This function may be used to move a node and its children to a different"]
+    #[doc = " parent.  Additionally, if the new parent is the same as the current parent,"]
+    #[doc = " this function will update the priority/weight of an existing node."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_parent_update(
+        port_id: u16,
+        node_id: u32,
+        parent_node_id: u32,
+        priority: u32,
+        weight: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager node private shaper update"]
+    #[doc = ""]
+    #[doc = " Restriction for the root node: its private shaper profile needs to be valid"]
+    #[doc = " and single rate."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    pub fn rte_tm_node_shaper_update(
+        port_id: u16,
+        node_id: u32,
+        shaper_profile_id: u32,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Traffic manager read stats counters for specific node"]
+    #[doc = " @return"]
+    #[doc = "   0 on success, non-zero error code otherwise."]
+    #[doc = ""]
+    #[doc = " @see enum rte_tm_stats_type"]
+    pub fn rte_tm_node_stats_read(
+        port_id: u16,
+        node_id: u32,
+        stats: *mut rte_tm_node_stats,
+        stats_mask: *mut u64,
+        clear: ::std::os::raw::c_int,
+        error: *mut rte_tm_error,
+    ) -> ::std::os::raw::c_int;
+}
+#[doc = " Metric name."]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_metric_name {
+    #[doc = " String describing metric"]
+    pub name: [::std::os::raw::c_char; 64usize],
+}
+#[test]
+fn bindgen_test_layout_rte_metric_name() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_metric_name>(),
+        64usize,
+        concat!("Size of: ", stringify!(rte_metric_name))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_metric_name>(),
+        1usize,
+        concat!("Alignment of ", stringify!(rte_metric_name))
+    );
+}
+impl Default for rte_metric_name {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Metric value."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct rte_metric_value {
+    #[doc = " Numeric identifier of metric."]
+    pub key: u16,
+    #[doc = " Value for metric"]
+    pub value: u64,
+}
+#[test]
+fn bindgen_test_layout_rte_metric_value() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_metric_value>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_metric_value))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_metric_value>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_metric_value))
+    );
+}
+extern "C" {
+    #[doc = " Initializes metric module. This function must be called from"]
+    #[doc = " a primary process before metrics are used."]
+    #[doc = ""]
+    #[doc = " @param socket_id"]
+    #[doc = "   Socket to use for shared memory allocation."]
+    pub fn rte_metrics_init(socket_id: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " Deinitialize metric module. This function must be called from"]
+    #[doc = " a primary process after all the metrics usage is over, to"]
+    #[doc = "  release the shared memory."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  -EINVAL - invalid parameter."]
+    #[doc = "  -EIO: Error, unable to access metrics shared memory"]
+    #[doc = "    (rte_metrics_init() not called)"]
+    #[doc = "  0 - success"]
+    pub fn rte_metrics_deinit() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Register a metric, making it available as a reporting parameter."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - Zero or positive: Success (index key of new metric)"]
+    #[doc = "  - -EIO: Error, unable to access metrics shared memory"]
+    #[doc = "    (rte_metrics_init() not called)"]
+    #[doc = "  - -EINVAL: Error, invalid parameters"]
+    #[doc = "  - -ENOMEM: Error, maximum metrics reached"]
+    pub fn rte_metrics_reg_name(name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Register a set of metrics."]
+    #[doc = ""]
+    #[doc = " This is a bulk version of rte_metrics_reg_names() and aside from"]
+    #[doc = " handling multiple keys at once is functionally identical."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Zero or positive: Success (index key of start of set)"]
+    #[doc = "   - -EIO: Error, unable to access metrics shared memory"]
+    #[doc = "     (rte_metrics_init() not called)"]
+    #[doc = "   - -EINVAL: Error, invalid parameters"]
+    #[doc = "   - -ENOMEM: Error, maximum metrics reached"]
+    pub fn rte_metrics_reg_names(
+        names: *const *const ::std::os::raw::c_char,
+        cnt_names: u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get metric name-key lookup table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Positive value above capacity: error, *names* is too small."]
+    #[doc = "     Return value is required size."]
+    #[doc = "   - Positive value equal or less than capacity: Success. Return"]
+    #[doc = "     value is number of elements filled in."]
+    #[doc = "   - Negative value: error."]
+    pub fn rte_metrics_get_names(names: *mut rte_metric_name, capacity: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get metric value table."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Positive value above capacity: error, *values* is too small."]
+    #[doc = "     Return value is required size."]
+    #[doc = "   - Positive value equal or less than capacity: Success. Return"]
+    #[doc = "     value is number of elements filled in."]
+    #[doc = "   - Negative value: error."]
+    pub fn rte_metrics_get_values(
+        port_id: ::std::os::raw::c_int,
+        values: *mut rte_metric_value,
+        capacity: u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Updates a metric"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -EIO if unable to access shared metrics memory"]
+    #[doc = "   - Zero on success"]
+    pub fn rte_metrics_update_value(port_id: ::std::os::raw::c_int, key: u16, value: u64) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Updates a metric set. Note that it is an error to try to"]
+    #[doc = " update across a set boundary."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - -ERANGE if count exceeds metric set size"]
+    #[doc = "   - -EIO if unable to access shared metrics memory"]
+    #[doc = "   - Zero on success"]
+    pub fn rte_metrics_update_values(
+        port_id: ::std::os::raw::c_int,
+        key: u16,
+        values: *const u64,
+        count: u32,
+    ) -> ::std::os::raw::c_int;
+}
+#[doc = " Note: This function pointer is for future flow based latency stats"]
+#[doc = "  implementation."]
+#[doc = ""]
+#[doc = " Function type used for identifting flow types of a Rx packet."]
+pub type rte_latency_stats_flow_type_fn = ::std::option::Option<
+    unsafe extern "C" fn(pkt: *mut rte_mbuf, user_cb: *mut ::std::os::raw::c_void) -> u16,
+>;
+extern "C" {
+    #[doc = "  Registers Rx/Tx callbacks for each active port, queue."]
+    #[doc = ""]
+    #[doc = " @param samp_intvl"]
+    #[doc = "  Sampling time period in nano seconds, at which packet"]
+    #[doc = "  should be marked with time stamp."]
+    #[doc = " @param user_cb"]
+    #[doc = "  Note: This param is for future flow based latency stats"]
+    #[doc = "  implementation."]
+    #[doc = "  User callback to be called to get flow types of a packet."]
+    #[doc = "  Used for flow based latency calculation."]
+    #[doc = "  If the value is NULL, global stats will be calculated,"]
+    #[doc = "  else flow based latency stats will be calculated."]
+    #[doc = "  For now just pass on the NULL value."]
+    #[doc = " @return"]
+    #[doc = "   -1     : On error"]
+    #[doc = "   -ENOMEM: On error"]
+    #[doc = "    0     : On success"]
+    pub fn rte_latencystats_init(
+        samp_intvl: u64,
+        user_cb: rte_latency_stats_flow_type_fn,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Calculates the latency and jitter values internally, exposing the updated"]
+    #[doc = " values via *rte_latencystats_get* or the rte_metrics API."]
+    #[doc = " @return:"]
+    #[doc = "  0      : on Success"]
+    #[doc = "  < 0    : Error in updating values."]
+    pub fn rte_latencystats_update() -> i32;
+}
+extern "C" {
+    #[doc = "  Removes registered Rx/Tx callbacks for each active port, queue."]
+    #[doc = ""]
+    #[doc = "  @return"]
+    #[doc = "   -1: On error"]
+    #[doc = "    0: On success"]
+    pub fn rte_latencystats_uninit() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve names of latency statistics"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - positive value lower or equal to size: success. The return value"]
+    #[doc = "     is the number of entries filled in the stats table."]
+    #[doc = "   - positive value higher than size: error, the given statistics table"]
+    #[doc = "     is too small. The return value corresponds to the size that should"]
+    #[doc = "     be given to succeed. The entries in the table are not valid and"]
+    #[doc = "     shall not be used by the caller."]
+    pub fn rte_latencystats_get_names(names: *mut rte_metric_name, size: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Retrieve latency statistics."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - positive value lower or equal to size: success. The return value"]
+    #[doc = "     is the number of entries filled in the stats table."]
+    #[doc = "   - positive value higher than size: error, the given statistics table"]
+    #[doc = "     is too small. The return value corresponds to the size that should"]
+    #[doc = "     be given to succeed. The entries in the table are not valid and"]
+    #[doc = "     shall not be used by the caller."]
+    #[doc = "   - negative value on error."]
+    pub fn rte_latencystats_get(values: *mut rte_metric_value, size: u16) -> ::std::os::raw::c_int;
+}
+#[doc = "  Bitrate statistics data structure."]
+#[doc = "  This data structure is intentionally opaque."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_stats_bitrates {
+    _unused: [u8; 0],
+}
+extern "C" {
+    #[doc = " Allocate a bitrate statistics structure"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Pointer to structure on success"]
+    #[doc = "   - NULL on error (zmalloc failure)"]
+    pub fn rte_stats_bitrate_create() -> *mut rte_stats_bitrates;
+}
+extern "C" {
+    #[doc = " Free bitrate statistics structure"]
+    pub fn rte_stats_bitrate_free(bitrate_data: *mut rte_stats_bitrates);
+}
+extern "C" {
+    #[doc = " Register bitrate statistics with the metric library."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   Zero on success"]
+    #[doc = "   Negative on error"]
+    pub fn rte_stats_bitrate_reg(bitrate_data: *mut rte_stats_bitrates) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Calculate statistics for current time window. The period with which"]
+    #[doc = " this function is called should be the intended sampling window width."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - Zero on success"]
+    #[doc = "   - Negative value on error"]
+    pub fn rte_stats_bitrate_calc(bitrate_data: *mut rte_stats_bitrates, port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Initialize packet capturing handling"]
+    #[doc = ""]
+    #[doc = " Register the IPC action for communication with target (primary) process."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    0 on success, -1 on error"]
+    pub fn rte_pdump_init() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Un initialize packet capturing handling"]
+    #[doc = ""]
+    #[doc = " Unregister the IPC action for communication with target (primary) process."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    0 on success, -1 on error"]
+    pub fn rte_pdump_uninit() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Enables packet capturing on given port and queue."]
+    #[doc = ""]
+    #[doc = " @param port"]
+    #[doc = "  port on which packet capturing should be enabled."]
+    #[doc = " @param queue"]
+    #[doc = "  queue of a given port on which packet capturing should be enabled."]
+    #[doc = "  users should pass on value UINT16_MAX to enable packet capturing on all"]
+    #[doc = "  queues of a given port."]
+    #[doc = " @param flags"]
+    #[doc = "  flags specifies RTE_PDUMP_FLAG_RX/RTE_PDUMP_FLAG_TX/RTE_PDUMP_FLAG_RXTX"]
+    #[doc = "  on which packet capturing should be enabled for a given port and queue."]
+    #[doc = " @param ring"]
+    #[doc = "  ring on which captured packets will be enqueued for user."]
+    #[doc = " @param mp"]
+    #[doc = "  mempool on to which original packets will be mirrored or duplicated."]
+    #[doc = " @param filter"]
+    #[doc = "  place holder for packet filtering."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    0 on success, -1 on error, rte_errno is set accordingly."]
+    pub fn rte_pdump_enable(
+        port: u16,
+        queue: u16,
+        flags: u32,
+        ring: *mut rte_ring,
+        mp: *mut rte_mempool,
+        filter: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Disables packet capturing on given port and queue."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "    0 on success, -1 on error, rte_errno is set accordingly."]
+    pub fn rte_pdump_disable(port: u16, queue: u16, flags: u32) -> ::std::os::raw::c_int;
+}
+pub mod power_management_env {
+    pub type Type = ::std::os::raw::c_uint;
+    pub const PM_ENV_NOT_SET: Type = 0;
+    pub const PM_ENV_ACPI_CPUFREQ: Type = 1;
+    pub const PM_ENV_KVM_VM: Type = 2;
+    pub const PM_ENV_PSTATE_CPUFREQ: Type = 3;
+}
+extern "C" {
+    #[doc = " Set the default power management implementation. If this is not called prior"]
+    #[doc = " to rte_power_init(), then auto-detect of the environment will take place."]
+    #[doc = " It is thread safe. New env can be set only in uninitialized state"]
+    #[doc = " (thus rte_power_unset_env must be called if different env was already set)."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - 0 on success."]
+    #[doc = "  - Negative on error."]
+    pub fn rte_power_set_env(env: power_management_env::Type) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Unset the global environment configuration."]
+    #[doc = " This can only be called after all threads have completed."]
+    pub fn rte_power_unset_env();
+}
+extern "C" {
+    #[doc = " Get the default power management implementation."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  power_management_env The configured environment."]
+    pub fn rte_power_get_env() -> power_management_env::Type;
+}
+extern "C" {
+    #[doc = " Initialize power management for a specific lcore. If rte_power_set_env() has"]
+    #[doc = " not been called then an auto-detect of the environment will start and"]
+    #[doc = " initialise the corresponding resources."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - 0 on success."]
+    #[doc = "  - Negative on error."]
+    pub fn rte_power_init(lcore_id: ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Exit power management on a specific lcore. This will call the environment"]
+    #[doc = " dependent exit function."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "  - 0 on success."]
+    #[doc = "  - Negative on error."]
+    pub fn rte_power_exit(lcore_id: ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+#[doc = " Get the available frequencies of a specific lcore."]
+#[doc = " Function pointer definition. Review each environments"]
+#[doc = " specific documentation for usage."]
+pub type rte_power_freqs_t = ::std::option::Option<
+    unsafe extern "C" fn(lcore_id: ::std::os::raw::c_uint, freqs: *mut u32, num: u32) -> u32,
+>;
+extern "C" {
+    pub static mut rte_power_freqs: rte_power_freqs_t;
+}
+#[doc = " Return the current index of available frequencies of a specific lcore."]
+#[doc = " Function pointer definition. Review each environments"]
+#[doc = " specific documentation for usage."]
+pub type rte_power_get_freq_t = ::std::option::Option<unsafe extern "C" fn(lcore_id: ::std::os::raw::c_uint) -> u32>;
+extern "C" {
+    pub static mut rte_power_get_freq: rte_power_get_freq_t;
+}
+#[doc = " Set the new frequency for a specific lcore by indicating the index of"]
+#[doc = " available frequencies."]
+#[doc = " Function pointer definition. Review each environments"]
+#[doc = " specific documentation for usage."]
+pub type rte_power_set_freq_t = ::std::option::Option<
+    unsafe extern "C" fn(lcore_id: ::std::os::raw::c_uint, index: u32) -> ::std::os::raw::c_int,
+>;
+extern "C" {
+    pub static mut rte_power_set_freq: rte_power_set_freq_t;
+}
+#[doc = " Function pointer definition for generic frequency change functions. Review"]
+#[doc = " each environments specific documentation for usage."]
+#[doc = ""]
+#[doc = " @return"]
+#[doc = "  - 1 on success with frequency changed."]
+#[doc = "  - 0 on success without frequency changed."]
+#[doc = "  - Negative on error."]
+pub type rte_power_freq_change_t =
+    ::std::option::Option<unsafe extern "C" fn(lcore_id: ::std::os::raw::c_uint) -> ::std::os::raw::c_int>;
+extern "C" {
+    pub static mut rte_power_freq_up: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_freq_down: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_freq_max: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_freq_min: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_turbo_status: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_freq_enable_turbo: rte_power_freq_change_t;
+}
+extern "C" {
+    pub static mut rte_power_freq_disable_turbo: rte_power_freq_change_t;
+}
+#[doc = " Callback prototype used by rte_kvargs_process()."]
+#[doc = ""]
+#[doc = " @param key"]
+#[doc = "   The key to consider, it will not be NULL."]
+#[doc = " @param value"]
+#[doc = "   The value corresponding to the key, it may be NULL (e.g. only with key)"]
+#[doc = " @param opaque"]
+#[doc = "   An opaque pointer coming from the caller."]
+#[doc = " @return"]
+#[doc = "   - >=0 handle key success."]
+#[doc = "   - <0 on error."]
+pub type arg_handler_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        key: *const ::std::os::raw::c_char,
+        value: *const ::std::os::raw::c_char,
+        opaque: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+#[doc = " A key/value association"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_kvargs_pair {
+    #[doc = "< the name (key) of the association"]
+    pub key: *mut ::std::os::raw::c_char,
+    #[doc = "< the value associated to that key"]
+    pub value: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_rte_kvargs_pair() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_kvargs_pair>(),
+        16usize,
+        concat!("Size of: ", stringify!(rte_kvargs_pair))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_kvargs_pair>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_kvargs_pair))
+    );
+}
+impl Default for rte_kvargs_pair {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[doc = " Store a list of key/value associations"]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_kvargs {
+    #[doc = "< copy of the argument string"]
+    pub str_: *mut ::std::os::raw::c_char,
+    #[doc = "< number of entries in the list"]
+    pub count: ::std::os::raw::c_uint,
+    #[doc = "< list of key/values"]
+    pub pairs: [rte_kvargs_pair; 32usize],
+}
+#[test]
+fn bindgen_test_layout_rte_kvargs() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_kvargs>(),
+        528usize,
+        concat!("Size of: ", stringify!(rte_kvargs))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_kvargs>(),
+        8usize,
+        concat!("Alignment of ", stringify!(rte_kvargs))
+    );
+}
+impl Default for rte_kvargs {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Allocate a rte_kvargs and store key/value associations from a string"]
+    #[doc = ""]
+    #[doc = " The function allocates and fills a rte_kvargs structure from a given"]
+    #[doc = " string whose format is key1=value1,key2=value2,..."]
+    #[doc = ""]
+    #[doc = " The structure can be freed with rte_kvargs_free()."]
+    #[doc = ""]
+    #[doc = " @param args"]
+    #[doc = "   The input string containing the key/value associations"]
+    #[doc = " @param valid_keys"]
+    #[doc = "   A list of valid keys (table of const char *, the last must be NULL)."]
+    #[doc = "   This argument is ignored if NULL"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - A pointer to an allocated rte_kvargs structure on success"]
+    #[doc = "   - NULL on error"]
+    pub fn rte_kvargs_parse(
+        args: *const ::std::os::raw::c_char,
+        valid_keys: *const *const ::std::os::raw::c_char,
+    ) -> *mut rte_kvargs;
+}
+extern "C" {
+    #[doc = " Allocate a rte_kvargs and store key/value associations from a string."]
+    #[doc = " This version will consider any byte from valid_ends as a possible"]
+    #[doc = " terminating character, and will not parse beyond any of their occurrence."]
+    #[doc = ""]
+    #[doc = " The function allocates and fills an rte_kvargs structure from a given"]
+    #[doc = " string whose format is key1=value1,key2=value2,..."]
+    #[doc = ""]
+    #[doc = " The structure can be freed with rte_kvargs_free()."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - A pointer to an allocated rte_kvargs structure on success"]
+    #[doc = "   - NULL on error"]
+    pub fn rte_kvargs_parse_delim(
+        args: *const ::std::os::raw::c_char,
+        valid_keys: *const *const ::std::os::raw::c_char,
+        valid_ends: *const ::std::os::raw::c_char,
+    ) -> *mut rte_kvargs;
+}
+extern "C" {
+    #[doc = " Free a rte_kvargs structure"]
+    #[doc = ""]
+    #[doc = " Free a rte_kvargs structure previously allocated with"]
+    #[doc = " rte_kvargs_parse()."]
+    pub fn rte_kvargs_free(kvlist: *mut rte_kvargs);
+}
+extern "C" {
+    #[doc = " Call a handler function for each key/value matching the key"]
+    #[doc = ""]
+    #[doc = " For each key/value association that matches the given key, calls the"]
+    #[doc = " handler function with the for a given arg_name passing the value on the"]
+    #[doc = " dictionary for that key and a given extra argument."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - 0 on success"]
+    #[doc = "   - Negative on error"]
+    pub fn rte_kvargs_process(
+        kvlist: *const rte_kvargs,
+        key_match: *const ::std::os::raw::c_char,
+        handler: arg_handler_t,
+        opaque_arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Count the number of associations matching the given key"]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   The number of entries"]
+    pub fn rte_kvargs_count(
+        kvlist: *const rte_kvargs,
+        key_match: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_uint;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_cfgfile {
+    _unused: [u8; 0],
+}
+#[doc = " Defines the parameters of a configuration file."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct rte_cfgfile_parameters {
+    #[doc = " Config file comment character; one of \'!\', \'#\', \'%\', \';\', \'@\'"]
+    pub comment_character: ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_rte_cfgfile_parameters() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_cfgfile_parameters>(),
+        1usize,
+        concat!("Size of: ", stringify!(rte_cfgfile_parameters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_cfgfile_parameters>(),
+        1usize,
+        concat!("Alignment of ", stringify!(rte_cfgfile_parameters))
+    );
+}
+#[doc = " Configuration file entry"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_cfgfile_entry {
+    #[doc = "< Name"]
+    pub name: [::std::os::raw::c_char; 64usize],
+    #[doc = "< Value"]
+    pub value: [::std::os::raw::c_char; 256usize],
+}
+#[test]
+fn bindgen_test_layout_rte_cfgfile_entry() {
+    assert_eq!(
+        ::std::mem::size_of::<rte_cfgfile_entry>(),
+        320usize,
+        concat!("Size of: ", stringify!(rte_cfgfile_entry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<rte_cfgfile_entry>(),
+        1usize,
+        concat!("Alignment of ", stringify!(rte_cfgfile_entry))
+    );
+}
+impl Default for rte_cfgfile_entry {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[doc = " Open config file"]
+    #[doc = ""]
+    #[doc = " @param filename"]
+    #[doc = "   Config file name"]
+    #[doc = " @param flags"]
+    #[doc = "   Config file flags"]
+    #[doc = " @return"]
+    #[doc = "   Handle to configuration file on success, NULL otherwise"]
+    pub fn rte_cfgfile_load(filename: *const ::std::os::raw::c_char, flags: ::std::os::raw::c_int) -> *mut rte_cfgfile;
+}
+extern "C" {
+    #[doc = " Open config file with specified optional parameters."]
+    #[doc = ""]
+    #[doc = " @param filename"]
+    #[doc = "   Config file name"]
+    #[doc = " @param flags"]
+    #[doc = "   Config file flags"]
+    #[doc = " @param params"]
+    #[doc = "   Additional configuration attributes.  Must be configured with desired"]
+    #[doc = "   values prior to invoking this API."]
+    #[doc = " @return"]
+    #[doc = "   Handle to configuration file on success, NULL otherwise"]
+    pub fn rte_cfgfile_load_with_params(
+        filename: *const ::std::os::raw::c_char,
+        flags: ::std::os::raw::c_int,
+        params: *const rte_cfgfile_parameters,
+    ) -> *mut rte_cfgfile;
+}
+extern "C" {
+    #[doc = " Create new cfgfile instance with empty sections and entries"]
+    #[doc = ""]
+    #[doc = " @param flags"]
+    #[doc = "   - CFG_FLAG_GLOBAL_SECTION"]
+    #[doc = "     Indicates that the file supports key value entries before the first"]
+    #[doc = "     defined section.  These entries can be accessed in the \"GLOBAL\""]
+    #[doc = "     section."]
+    #[doc = "   - CFG_FLAG_EMPTY_VALUES"]
+    #[doc = "     Indicates that file supports key value entries where the value can"]
+    #[doc = "     be zero length (e.g., \"key=\")."]
+    #[doc = " @return"]
+    #[doc = "   Handle to cfgfile instance on success, NULL otherwise"]
+    pub fn rte_cfgfile_create(flags: ::std::os::raw::c_int) -> *mut rte_cfgfile;
+}
+extern "C" {
+    #[doc = " Add section in cfgfile instance."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Pointer to the cfgfile structure."]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name which will be add to cfgfile."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, -ENOMEM if can't add section"]
+    pub fn rte_cfgfile_add_section(cfg: *mut rte_cfgfile, sectionname: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Add entry to specified section in cfgfile instance."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Pointer to the cfgfile structure."]
+    #[doc = " @param sectionname"]
+    #[doc = "   Given section name to add an entry."]
+    #[doc = " @param entryname"]
+    #[doc = "   Entry name to add."]
+    #[doc = " @param entryvalue"]
+    #[doc = "   Entry value to add."]
+    #[doc = " @return"]
+    #[doc = "   0 on success, -EEXIST if entry already exist, -EINVAL if bad argument"]
+    pub fn rte_cfgfile_add_entry(
+        cfg: *mut rte_cfgfile,
+        sectionname: *const ::std::os::raw::c_char,
+        entryname: *const ::std::os::raw::c_char,
+        entryvalue: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Update value of specified entry name in given section in config file"]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @param entryname"]
+    #[doc = "   Entry name to look for the value change"]
+    #[doc = " @param entryvalue"]
+    #[doc = "   New entry value. Can be also an empty string if CFG_FLAG_EMPTY_VALUES = 1"]
+    #[doc = " @return"]
+    #[doc = "   0 on success, -EINVAL if bad argument"]
+    pub fn rte_cfgfile_set_entry(
+        cfg: *mut rte_cfgfile,
+        sectionname: *const ::std::os::raw::c_char,
+        entryname: *const ::std::os::raw::c_char,
+        entryvalue: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Save object cfgfile to file on disc"]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file structure"]
+    #[doc = " @param filename"]
+    #[doc = "   File name to save data"]
+    #[doc = " @return"]
+    #[doc = "   0 on success, errno otherwise"]
+    pub fn rte_cfgfile_save(cfg: *mut rte_cfgfile, filename: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get number of sections in config file"]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sec_name"]
+    #[doc = "   Section name"]
+    #[doc = " @param length"]
+    #[doc = "   Maximum section name length"]
+    #[doc = " @return"]
+    #[doc = "   Number of sections"]
+    pub fn rte_cfgfile_num_sections(cfg: *mut rte_cfgfile, sec_name: *const ::std::os::raw::c_char, length: usize) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get name of all config file sections."]
+    #[doc = ""]
+    #[doc = " Fills in the array sections with the name of all the sections in the file"]
+    #[doc = " (up to the number of max_sections sections)."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sections"]
+    #[doc = "   Array containing section names after successful invocation. Each element"]
+    #[doc = "   of this array should be preallocated by the user with at least"]
+    #[doc = "   CFG_NAME_LEN characters."]
+    #[doc = " @param max_sections"]
+    #[doc = "   Maximum number of section names to be stored in sections array"]
+    #[doc = " @return"]
+    #[doc = "   Number of populated sections names"]
+    pub fn rte_cfgfile_sections(
+        cfg: *mut rte_cfgfile,
+        sections: *mut *mut ::std::os::raw::c_char,
+        max_sections: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Check if given section exists in config file"]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @return"]
+    #[doc = "   TRUE (value different than 0) if section exists, FALSE (value 0) otherwise"]
+    pub fn rte_cfgfile_has_section(cfg: *mut rte_cfgfile, sectionname: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get number of entries in given config file section"]
+    #[doc = ""]
+    #[doc = " If multiple sections have the given name this function operates on the"]
+    #[doc = " first one."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @return"]
+    #[doc = "   Number of entries in section on success, -1 otherwise"]
+    pub fn rte_cfgfile_section_num_entries(cfg: *mut rte_cfgfile, sectionname: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get section entries as key-value pairs"]
+    #[doc = ""]
+    #[doc = " If multiple sections have the given name this function operates on the"]
+    #[doc = " first one."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @param entries"]
+    #[doc = "   Pre-allocated array of at least max_entries entries where the section"]
+    #[doc = "   entries are stored as key-value pair after successful invocation"]
+    #[doc = " @param max_entries"]
+    #[doc = "   Maximum number of section entries to be stored in entries array"]
+    #[doc = " @return"]
+    #[doc = "   Number of entries populated on success, -1 otherwise"]
+    pub fn rte_cfgfile_section_entries(
+        cfg: *mut rte_cfgfile,
+        sectionname: *const ::std::os::raw::c_char,
+        entries: *mut rte_cfgfile_entry,
+        max_entries: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get value of the named entry in named config file section"]
+    #[doc = ""]
+    #[doc = " If multiple sections have the given name this function operates on the"]
+    #[doc = " first one."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @param entryname"]
+    #[doc = "   Entry name"]
+    #[doc = " @return"]
+    #[doc = "   Entry value on success, NULL otherwise"]
+    pub fn rte_cfgfile_get_entry(
+        cfg: *mut rte_cfgfile,
+        sectionname: *const ::std::os::raw::c_char,
+        entryname: *const ::std::os::raw::c_char,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " Check if given entry exists in named config file section"]
+    #[doc = ""]
+    #[doc = " If multiple sections have the given name this function operates on the"]
+    #[doc = " first one."]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @param sectionname"]
+    #[doc = "   Section name"]
+    #[doc = " @param entryname"]
+    #[doc = "   Entry name"]
+    #[doc = " @return"]
+    #[doc = "   TRUE (value different than 0) if entry exists, FALSE (value 0) otherwise"]
+    pub fn rte_cfgfile_has_entry(
+        cfg: *mut rte_cfgfile,
+        sectionname: *const ::std::os::raw::c_char,
+        entryname: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Close config file"]
+    #[doc = ""]
+    #[doc = " @param cfg"]
+    #[doc = "   Config file"]
+    #[doc = " @return"]
+    #[doc = "   0 on success, -1 if close failed"]
+    pub fn rte_cfgfile_close(cfg: *mut rte_cfgfile) -> ::std::os::raw::c_int;
+}
 extern "C" {
     #[doc = " Seed the pseudo-random generator."]
     #[doc = ""]
@@ -38125,6 +45185,1031 @@ extern "C" {
     #[doc = "   -ENOSPC: not enough headroom in mbuf"]
     pub fn _rte_vlan_insert(m: *mut *mut rte_mbuf) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    #[doc = " Copy bytes from one location to another. The locations must not overlap."]
+    #[doc = ""]
+    #[doc = " @param dst"]
+    #[doc = "   Pointer to the destination of the data."]
+    #[doc = " @param src"]
+    #[doc = "   Pointer to the source data."]
+    #[doc = " @param n"]
+    #[doc = "   Number of bytes to copy."]
+    #[doc = " @return"]
+    #[doc = "   Pointer to the destination data."]
+    pub fn _rte_memcpy(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        n: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    #[doc = " Initialize the rwlock to an unlocked state."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    pub fn _rte_rwlock_init(rwl: *mut rte_rwlock_t);
+}
+extern "C" {
+    #[doc = " Take a read lock. Loop until the lock is held."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    pub fn _rte_rwlock_read_lock(rwl: *mut rte_rwlock_t);
+}
+extern "C" {
+    #[doc = " Try to take a read lock."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    #[doc = " @return"]
+    #[doc = "   - zero if the lock is successfully taken"]
+    #[doc = "   - -EBUSY if lock could not be acquired for reading because a"]
+    #[doc = "     writer holds the lock"]
+    pub fn _rte_rwlock_read_trylock(rwl: *mut rte_rwlock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Release a read lock."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    pub fn _rte_rwlock_read_unlock(rwl: *mut rte_rwlock_t);
+}
+extern "C" {
+    #[doc = " Take a write lock. Loop until the lock is held."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    pub fn _rte_rwlock_write_lock(rwl: *mut rte_rwlock_t);
+}
+extern "C" {
+    #[doc = " Try to take a write lock."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    #[doc = " @return"]
+    #[doc = "   - zero if the lock is successfully taken"]
+    #[doc = "   - -EBUSY if lock could not be acquired for writing because"]
+    #[doc = "     it was already locked for reading or writing"]
+    pub fn _rte_rwlock_write_trylock(rwl: *mut rte_rwlock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Release a write lock."]
+    #[doc = ""]
+    #[doc = " @param rwl"]
+    #[doc = "   A pointer to the rwlock structure."]
+    pub fn _rte_rwlock_write_unlock(rwl: *mut rte_rwlock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Initialize the ticketlock to an unlocked state."]
+    #[doc = ""]
+    #[doc = " @param tl"]
+    #[doc = "   A pointer to the ticketlock."]
+    pub fn _rte_ticketlock_init(tl: *mut rte_ticketlock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Take the ticketlock."]
+    #[doc = ""]
+    #[doc = " @param tl"]
+    #[doc = "   A pointer to the ticketlock."]
+    pub fn _rte_ticketlock_lock(tl: *mut rte_ticketlock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Release the ticketlock."]
+    #[doc = ""]
+    #[doc = " @param tl"]
+    #[doc = "   A pointer to the ticketlock."]
+    pub fn _rte_ticketlock_unlock(tl: *mut rte_ticketlock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Try to take the lock."]
+    #[doc = ""]
+    #[doc = " @param tl"]
+    #[doc = "   A pointer to the ticketlock."]
+    #[doc = " @return"]
+    #[doc = "   1 if the lock is successfully taken; 0 otherwise."]
+    pub fn _rte_ticketlock_trylock(tl: *mut rte_ticketlock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Test if the lock is taken."]
+    #[doc = ""]
+    #[doc = " @param tl"]
+    #[doc = "   A pointer to the ticketlock."]
+    #[doc = " @return"]
+    #[doc = "   1 if the lock is currently taken; 0 otherwise."]
+    pub fn _rte_ticketlock_is_locked(tl: *mut rte_ticketlock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Initialize the recursive ticketlock to an unlocked state."]
+    #[doc = ""]
+    #[doc = " @param tlr"]
+    #[doc = "   A pointer to the recursive ticketlock."]
+    pub fn _rte_ticketlock_recursive_init(tlr: *mut rte_ticketlock_recursive_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Take the recursive ticketlock."]
+    #[doc = ""]
+    #[doc = " @param tlr"]
+    #[doc = "   A pointer to the recursive ticketlock."]
+    pub fn _rte_ticketlock_recursive_lock(tlr: *mut rte_ticketlock_recursive_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Release the recursive ticketlock."]
+    #[doc = ""]
+    #[doc = " @param tlr"]
+    #[doc = "   A pointer to the recursive ticketlock."]
+    pub fn _rte_ticketlock_recursive_unlock(tlr: *mut rte_ticketlock_recursive_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Try to take the recursive lock."]
+    #[doc = ""]
+    #[doc = " @param tlr"]
+    #[doc = "   A pointer to the recursive ticketlock."]
+    #[doc = " @return"]
+    #[doc = "   1 if the lock is successfully taken; 0 otherwise."]
+    pub fn _rte_ticketlock_recursive_trylock(tlr: *mut rte_ticketlock_recursive_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Acquire the MCS lock."]
+    #[doc = ""]
+    #[doc = " @param msl"]
+    #[doc = "   A pointer to the pointer of a MCS lock."]
+    #[doc = "   When the lock is initialized or declared, the msl pointer should be"]
+    #[doc = "   set to NULL."]
+    #[doc = " @param me"]
+    #[doc = "   A pointer to a new node of MCS lock. Each CPU/thread acquiring the"]
+    #[doc = "   lock should use its 'own node'."]
+    pub fn _rte_mcslock_lock(msl: *mut *mut rte_mcslock_t, me: *mut rte_mcslock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Release the MCS lock."]
+    #[doc = ""]
+    #[doc = " @param msl"]
+    #[doc = "   A pointer to the pointer of a MCS lock."]
+    #[doc = "   When the lock is initialized or declared, the msl pointer should be"]
+    #[doc = "   set to NULL."]
+    #[doc = " @param me"]
+    #[doc = "   A pointer to a new node of MCS lock. Each CPU/thread acquiring the"]
+    #[doc = "   lock should use its 'own node'."]
+    pub fn _rte_mcslock_unlock(msl: *mut *mut rte_mcslock_t, me: *mut rte_mcslock_t);
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Try to take the lock."]
+    #[doc = ""]
+    #[doc = " @param msl"]
+    #[doc = "   A pointer to the pointer of a MCS lock."]
+    #[doc = "   When the lock is initialized or declared, the msl pointer should be"]
+    #[doc = "   set to NULL."]
+    #[doc = " @param me"]
+    #[doc = "   A pointer to a new node of MCS lock. Each CPU/thread acquiring the"]
+    #[doc = "   lock should use its 'own node'."]
+    #[doc = " @return"]
+    #[doc = "   1 if the lock is successfully taken; 0 otherwise."]
+    pub fn _rte_mcslock_trylock(msl: *mut *mut rte_mcslock_t, me: *mut rte_mcslock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @warning"]
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Test if the lock is taken."]
+    #[doc = ""]
+    #[doc = " @param msl"]
+    #[doc = "   A pointer to a MCS lock node."]
+    #[doc = " @return"]
+    #[doc = "   1 if the lock is currently taken; 0 otherwise."]
+    pub fn _rte_mcslock_is_locked(msl: *mut rte_mcslock_t) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Add a registered reader thread, to the list of threads reporting their"]
+    #[doc = " quiescent state on a QS variable."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread"]
+    #[doc = " safe."]
+    #[doc = ""]
+    #[doc = " Any registered reader thread that wants to report its quiescent state must"]
+    #[doc = " call this API before calling rte_rcu_qsbr_quiescent. This can be called"]
+    #[doc = " during initialization or as part of the packet processing loop."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Reader thread id"]
+    pub fn _rte_rcu_qsbr_thread_online(v: *mut rte_rcu_qsbr, thread_id: ::std::os::raw::c_uint);
+}
+extern "C" {
+    #[doc = " Remove a registered reader thread from the list of threads reporting their"]
+    #[doc = " quiescent state on a QS variable."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread"]
+    #[doc = " safe."]
+    #[doc = ""]
+    #[doc = " This can be called during initialization or as part of the packet"]
+    #[doc = " processing loop."]
+    #[doc = ""]
+    #[doc = " The reader thread must call rte_rcu_qsbr_thread_offline API, before"]
+    #[doc = " calling any functions that block, to ensure that rte_rcu_qsbr_check"]
+    #[doc = " API does not wait indefinitely for the reader thread to update its QS."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Reader thread id"]
+    pub fn _rte_rcu_qsbr_thread_offline(v: *mut rte_rcu_qsbr, thread_id: ::std::os::raw::c_uint);
+}
+extern "C" {
+    #[doc = " Ask the reader threads to report the quiescent state"]
+    #[doc = " status."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread"]
+    #[doc = " safe and can be called from worker threads."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @return"]
+    #[doc = "   - This is the token for this call of the API. This should be"]
+    #[doc = "     passed to rte_rcu_qsbr_check API."]
+    pub fn _rte_rcu_qsbr_start(v: *mut rte_rcu_qsbr) -> u64;
+}
+extern "C" {
+    #[doc = " Update quiescent state for a reader thread."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread safe."]
+    #[doc = " All the reader threads registered to report their quiescent state"]
+    #[doc = " on the QS variable must call this API."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param thread_id"]
+    #[doc = "   Update the quiescent state for the reader with this thread ID."]
+    pub fn _rte_rcu_qsbr_quiescent(v: *mut rte_rcu_qsbr, thread_id: ::std::os::raw::c_uint);
+}
+extern "C" {
+    #[doc = " Checks if all the reader threads have entered the quiescent state"]
+    #[doc = " referenced by token."]
+    #[doc = ""]
+    #[doc = " This is implemented as a lock-free function. It is multi-thread"]
+    #[doc = " safe and can be called from the worker threads as well."]
+    #[doc = ""]
+    #[doc = " If this API is called with 'wait' set to true, the following"]
+    #[doc = " factors must be considered:"]
+    #[doc = ""]
+    #[doc = " 1) If the calling thread is also reporting the status on the"]
+    #[doc = " same QS variable, it must update the quiescent state status, before"]
+    #[doc = " calling this API."]
+    #[doc = ""]
+    #[doc = " 2) In addition, while calling from multiple threads, only"]
+    #[doc = " one of those threads can be reporting the quiescent state status"]
+    #[doc = " on a given QS variable."]
+    #[doc = ""]
+    #[doc = " @param v"]
+    #[doc = "   QS variable"]
+    #[doc = " @param t"]
+    #[doc = "   Token returned by rte_rcu_qsbr_start API"]
+    #[doc = " @param wait"]
+    #[doc = "   If true, block till all the reader threads have completed entering"]
+    #[doc = "   the quiescent state referenced by token 't'."]
+    #[doc = " @return"]
+    #[doc = "   - 0 if all reader threads have NOT passed through specified number"]
+    #[doc = "     of quiescent states."]
+    #[doc = "   - 1 if all reader threads have passed through specified number"]
+    #[doc = "     of quiescent states."]
+    pub fn _rte_rcu_qsbr_check(v: *mut rte_rcu_qsbr, t: u64, wait: bool) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Push several objects on the stack (MT-safe)."]
+    #[doc = ""]
+    #[doc = " @param s"]
+    #[doc = "   A pointer to the stack structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to push on the stack from the obj_table."]
+    #[doc = " @return"]
+    #[doc = "   Actual number of objects pushed (either 0 or *n*)."]
+    pub fn _rte_stack_push(
+        s: *mut rte_stack,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Pop several objects from the stack (MT-safe)."]
+    #[doc = ""]
+    #[doc = " @param s"]
+    #[doc = "   A pointer to the stack structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to pull from the stack."]
+    #[doc = " @return"]
+    #[doc = "   Actual number of objects popped (either 0 or *n*)."]
+    pub fn _rte_stack_pop(
+        s: *mut rte_stack,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the number of used entries in a stack."]
+    #[doc = ""]
+    #[doc = " @param s"]
+    #[doc = "   A pointer to the stack structure."]
+    #[doc = " @return"]
+    #[doc = "   The number of used entries in the stack."]
+    pub fn _rte_stack_count(s: *mut rte_stack) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the number of free entries in a stack."]
+    #[doc = ""]
+    #[doc = " @param s"]
+    #[doc = "   A pointer to the stack structure."]
+    #[doc = " @return"]
+    #[doc = "   The number of free entries in the stack."]
+    pub fn _rte_stack_free_count(s: *mut rte_stack) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " @brief Decides if new packet should be enqeued or dropped"]
+    #[doc = " Updates run time data based on new queue size value."]
+    #[doc = " Based on new queue average and RED configuration parameters"]
+    #[doc = " gives verdict whether to enqueue or drop the packet."]
+    #[doc = ""]
+    #[doc = " @param red_cfg [in] config pointer to a RED configuration parameter structure"]
+    #[doc = " @param red [in,out] data pointer to RED runtime data"]
+    #[doc = " @param q [in] updated queue size in packets"]
+    #[doc = " @param time [in] current time stamp"]
+    #[doc = ""]
+    #[doc = " @return Operation status"]
+    #[doc = " @retval 0 enqueue the packet"]
+    #[doc = " @retval 1 drop the packet based on max threshold criteria"]
+    #[doc = " @retval 2 drop the packet based on mark probability criteria"]
+    pub fn _rte_red_enqueue(
+        red_cfg: *const rte_red_config,
+        red: *mut rte_red,
+        q: ::std::os::raw::c_uint,
+        time: u64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " @brief Callback to records time that queue became empty"]
+    #[doc = ""]
+    #[doc = " @param red [in,out] data pointer to RED runtime data"]
+    #[doc = " @param time [in] current time stamp"]
+    pub fn _rte_red_mark_queue_empty(red: *mut rte_red, time: u64);
+}
+extern "C" {
+    #[doc = " The most generic version, hashes an arbitrary sequence"]
+    #[doc = " of bytes.  No alignment or length assumptions are made about"]
+    #[doc = " the input key."]
+    #[doc = ""]
+    #[doc = " @param key"]
+    #[doc = "   Key to calculate hash of."]
+    #[doc = " @param length"]
+    #[doc = "   Length of key in bytes."]
+    #[doc = " @param initval"]
+    #[doc = "   Initialising value of hash."]
+    #[doc = " @return"]
+    #[doc = "   Calculated hash value."]
+    pub fn _rte_jhash(key: *const ::std::os::raw::c_void, length: u32, initval: u32) -> u32;
+}
+extern "C" {
+    #[doc = " Calculate CRC32 hash on user-supplied byte array."]
+    #[doc = ""]
+    #[doc = " @param data"]
+    #[doc = "   Data to perform hash on."]
+    #[doc = " @param data_len"]
+    #[doc = "   How many bytes to use to calculate hash value."]
+    #[doc = " @param init_val"]
+    #[doc = "   Value to initialise hash generator."]
+    #[doc = " @return"]
+    #[doc = "   32bit calculated hash value."]
+    pub fn _rte_hash_crc(data: *const ::std::os::raw::c_void, data_len: u32, init_val: u32) -> u32;
+}
+extern "C" {
+    #[doc = " Get the standard output stream, which is used to dump to the console."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   The stdout stream of the C library."]
+    pub fn _rte_stdout() -> *mut FILE;
+}
+extern "C" {
+    #[doc = " Pause CPU execution for a short while"]
+    #[doc = ""]
+    #[doc = " This call is intended for tight loops which poll a shared resource or wait"]
+    #[doc = " for an event. A short pause within the loop may reduce the power consumption."]
+    pub fn _rte_pause();
+}
+extern "C" {
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Wait for *addr to be updated with a 16-bit expected value, with a relaxed"]
+    #[doc = " memory ordering model meaning the loads around this API can be reordered."]
+    #[doc = ""]
+    #[doc = " @param addr"]
+    #[doc = "  A pointer to the memory location."]
+    #[doc = " @param expected"]
+    #[doc = "  A 16-bit expected value to be in the memory location."]
+    #[doc = " @param memorder"]
+    #[doc = "  Two different memory orders that can be specified:"]
+    #[doc = "  __ATOMIC_ACQUIRE and __ATOMIC_RELAXED. These map to"]
+    #[doc = "  C++11 memory orders with the same names, see the C++11 standard or"]
+    #[doc = "  the GCC wiki on atomic synchronization for detailed definition."]
+    pub fn _rte_wait_until_equal_16(addr: *mut u16, expected: u16, memorder: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Wait for *addr to be updated with a 32-bit expected value, with a relaxed"]
+    #[doc = " memory ordering model meaning the loads around this API can be reordered."]
+    #[doc = ""]
+    #[doc = " @param addr"]
+    #[doc = "  A pointer to the memory location."]
+    #[doc = " @param expected"]
+    #[doc = "  A 32-bit expected value to be in the memory location."]
+    #[doc = " @param memorder"]
+    #[doc = "  Two different memory orders that can be specified:"]
+    #[doc = "  __ATOMIC_ACQUIRE and __ATOMIC_RELAXED. These map to"]
+    #[doc = "  C++11 memory orders with the same names, see the C++11 standard or"]
+    #[doc = "  the GCC wiki on atomic synchronization for detailed definition."]
+    pub fn _rte_wait_until_equal_32(addr: *mut u32, expected: u32, memorder: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " @b EXPERIMENTAL: this API may change without prior notice"]
+    #[doc = ""]
+    #[doc = " Wait for *addr to be updated with a 64-bit expected value, with a relaxed"]
+    #[doc = " memory ordering model meaning the loads around this API can be reordered."]
+    #[doc = ""]
+    #[doc = " @param addr"]
+    #[doc = "  A pointer to the memory location."]
+    #[doc = " @param expected"]
+    #[doc = "  A 64-bit expected value to be in the memory location."]
+    #[doc = " @param memorder"]
+    #[doc = "  Two different memory orders that can be specified:"]
+    #[doc = "  __ATOMIC_ACQUIRE and __ATOMIC_RELAXED. These map to"]
+    #[doc = "  C++11 memory orders with the same names, see the C++11 standard or"]
+    #[doc = "  the GCC wiki on atomic synchronization for detailed definition."]
+    pub fn _rte_wait_until_equal_64(addr: *mut u64, expected: u64, memorder: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (multi-producers safe)."]
+    #[doc = ""]
+    #[doc = " This function enqueues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_mp_enqueue_bulk(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (NOT multi-producers safe)."]
+    #[doc = ""]
+    #[doc = " This function enqueues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_sp_enqueue_bulk(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (the producer mode of the ring)."]
+    #[doc = ""]
+    #[doc = " This function enqueues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_enqueue_bulk(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (multi-consumers safe)."]
+    #[doc = ""]
+    #[doc = " This function dequeues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_mc_dequeue_bulk(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (NOT multi-consumers safe)."]
+    #[doc = ""]
+    #[doc = " This function dequeues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_sc_dequeue_bulk(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (the consumer mode of the ring)."]
+    #[doc = ""]
+    #[doc = " This function dequeues either all or none of the objects."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_dequeue_bulk(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (multi-producers safe)."]
+    #[doc = ""]
+    #[doc = " This function enqueues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_mp_enqueue_burst(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (NOT multi-producers safe)."]
+    #[doc = ""]
+    #[doc = " This function enqueues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_sp_enqueue_burst(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Enqueue several objects on the ring (the producer mode of the ring)."]
+    #[doc = ""]
+    #[doc = " This function enqueues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects)."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to add in the ring from the obj_table."]
+    #[doc = " @param free_space"]
+    #[doc = "   if non-NULL, returns the amount of space in the ring after the"]
+    #[doc = "   enqueue operation has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects enqueued."]
+    pub fn _rte_ring_enqueue_burst(
+        r: *mut rte_ring,
+        obj_table: *const *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        free_space: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (multi-consumers safe)."]
+    #[doc = ""]
+    #[doc = " This function dequeues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_mc_dequeue_burst(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (NOT multi-consumers safe)."]
+    #[doc = ""]
+    #[doc = " This function dequeues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_sc_dequeue_burst(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Dequeue several objects from the ring (the consumer mode of the ring)."]
+    #[doc = ""]
+    #[doc = " This function dequeues as many objects as possible."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @param obj_table"]
+    #[doc = "   A pointer to a table of void * pointers (objects) that will be filled."]
+    #[doc = " @param n"]
+    #[doc = "   The number of objects to dequeue from the ring to the obj_table."]
+    #[doc = " @param available"]
+    #[doc = "   If non-NULL, returns the number of remaining ring entries after the"]
+    #[doc = "   dequeue has finished."]
+    #[doc = " @return"]
+    #[doc = "   - n: Actual number of objects dequeued."]
+    pub fn _rte_ring_dequeue_burst(
+        r: *mut rte_ring,
+        obj_table: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_uint,
+        available: *mut ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the number of entries in a ring."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   The number of entries in the ring."]
+    pub fn _rte_ring_count(r: *const rte_ring) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the number of free entries in a ring."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   The number of free entries in the ring."]
+    pub fn _rte_ring_free_count(r: *const rte_ring) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Test if a ring is full."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   - 1: The ring is full."]
+    #[doc = "   - 0: The ring is not full."]
+    pub fn _rte_ring_full(r: *const rte_ring) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Test if a ring is empty."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   - 1: The ring is empty."]
+    #[doc = "   - 0: The ring is not empty."]
+    pub fn _rte_ring_empty(r: *const rte_ring) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Return the size of the ring."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   The size of the data store used by the ring."]
+    pub fn _rte_ring_get_size(r: *const rte_ring) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Return the number of elements which can be stored in the ring."]
+    #[doc = ""]
+    #[doc = " @param r"]
+    #[doc = "   A pointer to the ring structure."]
+    #[doc = " @return"]
+    #[doc = "   The usable size of the ring."]
+    pub fn _rte_ring_get_capacity(r: *const rte_ring) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " Lookup an IP into the LPM table."]
+    #[doc = ""]
+    #[doc = " @param lpm"]
+    #[doc = "   LPM object handle"]
+    #[doc = " @param ip"]
+    #[doc = "   IP to be looked up in the LPM table"]
+    #[doc = " @param next_hop"]
+    #[doc = "   Next hop of the most specific rule found for IP (valid on lookup hit only)"]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, -ENOENT on lookup miss, 0 on lookup hit"]
+    pub fn _rte_lpm_lookup(lpm: *mut rte_lpm, ip: u32, next_hop: *mut u32) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Lookup multiple IP addresses in an LPM table."]
+    #[doc = ""]
+    #[doc = " @param lpm"]
+    #[doc = "   LPM object handle"]
+    #[doc = " @param ips"]
+    #[doc = "   Array of IPs to be looked up in the LPM table"]
+    #[doc = " @param next_hops"]
+    #[doc = "   Next hop of the most specific rule found for IP (valid on lookup hit only)."]
+    #[doc = "   This is an array of four byte values. The most significant byte in each"]
+    #[doc = "   value says whether the lookup was successful (bitmask"]
+    #[doc = "   RTE_LPM_LOOKUP_SUCCESS is set)."]
+    #[doc = " @param n"]
+    #[doc = "   Number of elements in ips (and next_hops) array to lookup."]
+    #[doc = " @return"]
+    #[doc = "   -EINVAL for incorrect arguments, otherwise 0"]
+    pub fn _rte_lpm_lookup_bulk(
+        lpm: *const rte_lpm,
+        ips: *const u32,
+        next_hops: *mut u32,
+        n: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Lookup four IP addresses in an LPM table."]
+    #[doc = ""]
+    #[doc = " @param lpm"]
+    #[doc = "   LPM object handle"]
+    #[doc = " @param ip"]
+    #[doc = "   Four IPs to be looked up in the LPM table"]
+    #[doc = " @param hop"]
+    #[doc = "   Next hop of the most specific rule found for IP (valid on lookup hit only)."]
+    #[doc = "   This is an 4 elements array of four byte values."]
+    #[doc = "   If the lookup was successful for the given IP, then least significant byte"]
+    #[doc = "   of the corresponding element is the  actual next hop and the most"]
+    #[doc = "   significant byte is zero."]
+    #[doc = "   If the lookup for the given IP failed, then corresponding element would"]
+    #[doc = "   contain default value, see description of then next parameter."]
+    #[doc = " @param defv"]
+    #[doc = "   Default value to populate into corresponding element of hop[] array,"]
+    #[doc = "   if lookup would fail."]
+    pub fn _rte_lpm_lookupx4(lpm: *const rte_lpm, ip: *const u32, hop: *mut u32, defv: u32);
+}
+extern "C" {
+    #[doc = " Enqueue a burst of operations for processing on a crypto device."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = " The number of operations actually enqueued on the crypto device. The return"]
+    #[doc = " value can be less than the value of the *nb_ops* parameter when the"]
+    #[doc = " crypto devices queue is full or if invalid parameters are specified in"]
+    #[doc = " a *rte_crypto_op*."]
+    pub fn _rte_cryptodev_enqueue_burst(dev_id: u8, qp_id: u16, ops: *mut *mut rte_crypto_op, nb_ops: u16) -> u16;
+}
+extern "C" {
+    #[doc = " Dequeue a burst of processed crypto operations from a queue on the crypto"]
+    #[doc = " device. The dequeued operation are stored in *rte_crypto_op* structures"]
+    #[doc = " whose pointers are supplied in the *ops* array."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   - The number of operations actually dequeued, which is the number"]
+    #[doc = "   of pointers to *rte_crypto_op* structures effectively supplied to the"]
+    #[doc = "   *ops* array."]
+    pub fn _rte_cryptodev_dequeue_burst(dev_id: u8, qp_id: u16, ops: *mut *mut rte_crypto_op, nb_ops: u16) -> u16;
+}
+extern "C" {
+    #[doc = " Allocate a crypto operation from a mempool with default parameters set"]
+    #[doc = ""]
+    #[doc = " @returns"]
+    #[doc = " - On success returns a valid rte_crypto_op structure"]
+    #[doc = " - On failure returns NULL"]
+    pub fn _rte_crypto_op_alloc(mempool: *mut rte_mempool, type_: rte_crypto_op_type::Type) -> *mut rte_crypto_op;
+}
+extern "C" {
+    #[doc = " Bulk allocate crypto operations from a mempool with default parameters set"]
+    #[doc = ""]
+    #[doc = " @returns"]
+    #[doc = " - nb_ops if the number of operations requested were allocated."]
+    #[doc = " - 0 if the requested number of ops are not available."]
+    #[doc = "   None are allocated in this case."]
+    pub fn _rte_crypto_op_bulk_alloc(
+        mempool: *mut rte_mempool,
+        type_: rte_crypto_op_type::Type,
+        ops: *mut *mut rte_crypto_op,
+        nb_ops: u16,
+    ) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    #[doc = " free crypto operation structure"]
+    #[doc = " If operation has been allocate from a rte_mempool, then the operation will"]
+    #[doc = " be returned to the mempool."]
+    pub fn _rte_crypto_op_free(op: *mut rte_crypto_op);
+}
+extern "C" {
+    #[doc = " Attach a symmetric session to a crypto operation"]
+    pub fn _rte_crypto_op_attach_sym_session(
+        op: *mut rte_crypto_op,
+        sess: *mut rte_cryptodev_sym_session,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Enqueue a burst of events objects or an event object supplied in *rte_event*"]
+    #[doc = " structure on an  event device designated by its *dev_id* through the event"]
+    #[doc = " port specified by *port_id*."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   The number of event objects actually enqueued on the event device. The"]
+    #[doc = "   return value can be less than the value of the *nb_events* parameter when"]
+    #[doc = "   the event devices queue is full or if invalid parameters are specified in a"]
+    #[doc = "   *rte_event*."]
+    pub fn _rte_event_enqueue_burst(dev_id: u8, port_id: u8, ev: *const rte_event, nb_events: u16) -> u16;
+}
+extern "C" {
+    #[doc = " Enqueue a burst of events objects of operation type *RTE_EVENT_OP_NEW*"]
+    #[doc = " on an event device designated by its *dev_id* through the event port"]
+    #[doc = " specified by *port_id*."]
+    pub fn _rte_event_enqueue_new_burst(dev_id: u8, port_id: u8, ev: *const rte_event, nb_events: u16) -> u16;
+}
+extern "C" {
+    #[doc = " Enqueue a burst of events objects of operation type *RTE_EVENT_OP_FORWARD*"]
+    #[doc = " on an event device designated by its *dev_id* through the event port"]
+    #[doc = " specified by *port_id*."]
+    pub fn _rte_event_enqueue_forward_burst(dev_id: u8, port_id: u8, ev: *const rte_event, nb_events: u16) -> u16;
+}
+extern "C" {
+    #[doc = " Dequeue a burst of events objects or an event object from the event port"]
+    #[doc = " designated by its *event_port_id*, on an event device designated"]
+    #[doc = " by its *dev_id*."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = " The number of event objects actually dequeued from the port. The return"]
+    #[doc = " value can be less than the value of the *nb_events* parameter when the"]
+    #[doc = " event port's queue is not full."]
+    pub fn _rte_event_dequeue_burst(
+        dev_id: u8,
+        port_id: u8,
+        ev: *mut rte_event,
+        nb_events: u16,
+        timeout_ticks: u64,
+    ) -> u16;
+}
+extern "C" {
+    #[doc = " Process the pseudo-header checksum of an IPv4 header."]
+    #[doc = ""]
+    #[doc = " The checksum field must be set to 0 by the caller."]
+    #[doc = ""]
+    #[doc = " Depending on the ol_flags, the pseudo-header checksum expected by the"]
+    #[doc = " drivers is not the same. For instance, when TSO is enabled, the IP"]
+    #[doc = " payload length must not be included in the packet."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   The non-complemented checksum to set in the L4 header."]
+    pub fn _rte_ipv4_phdr_cksum(ipv4_hdr: *const rte_ipv4_hdr, ol_flags: u64) -> u16;
+}
+extern "C" {
+    #[doc = " Process the pseudo-header checksum of an IPv6 header."]
+    #[doc = ""]
+    #[doc = " Depending on the ol_flags, the pseudo-header checksum expected by the"]
+    #[doc = " drivers is not the same. For instance, when TSO is enabled, the IPv6"]
+    #[doc = " payload length must not be included in the packet."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   The non-complemented checksum to set in the L4 header."]
+    pub fn _rte_ipv6_phdr_cksum(ipv6_hdr: *const rte_ipv6_hdr, ol_flags: u64) -> u16;
+}
+extern "C" {
+    #[doc = " Prepare pseudo header checksum"]
+    #[doc = ""]
+    #[doc = " This function prepares pseudo header checksum for TSO and non-TSO tcp/udp in"]
+    #[doc = " provided mbufs packet data and based on the requested offload flags."]
+    #[doc = ""]
+    #[doc = " - for non-TSO tcp/udp packets full pseudo-header checksum is counted and set"]
+    #[doc = "   in packet data,"]
+    #[doc = " - for TSO the IP payload length is not included in pseudo header."]
+    #[doc = ""]
+    #[doc = " This function expects that used headers are in the first data segment of"]
+    #[doc = " mbuf, are not fragmented and can be safely modified."]
+    #[doc = ""]
+    #[doc = " @return"]
+    #[doc = "   0 if checksum is initialized properly"]
+    pub fn _rte_net_intel_cksum_flags_prepare(m: *mut rte_mbuf, ol_flags: u64) -> ::std::os::raw::c_int;
+}
 pub type __builtin_va_list = [__va_list_tag; 1usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -10,35 +10,61 @@
 #include <rte_cycles.h>
 #include <rte_dev.h>
 #include <rte_errno.h>
+#include <rte_hypervisor.h>
 #include <rte_keepalive.h>
+#include <rte_kvargs.h>
 #include <rte_launch.h>
 #include <rte_lcore.h>
 #include <rte_log.h>
 #include <rte_malloc.h>
 #include <rte_memcpy.h>
 #include <rte_memory.h>
+#include <rte_pause.h>
 #include <rte_per_lcore.h>
 #include <rte_prefetch.h>
+#include <rte_reciprocal.h>
+#include <rte_rwlock.h>
 #include <rte_spinlock.h>
+#include <rte_ticketlock.h>
+#include <rte_mcslock.h>
+#include <rte_rcu_qsbr.h>
 #include <rte_version.h>
 
 // Core Components
 #include <rte_ring.h>
 #include <rte_mempool.h>
 #include <rte_mbuf.h>
+#include <rte_stack.h>
+
+#include <rte_hash.h>
+#include <rte_fib.h>
+#include <rte_lpm.h>
+#include <rte_lpm6.h>
+#include <rte_fib6.h>
+#include <rte_rib.h>
+#include <rte_rib6.h>
+#include <rte_acl.h>
+#include <rte_cryptodev.h>
+#include <rte_eventdev.h>
+#include <rte_event_eth_rx_adapter.h>
 
 #include <rte_timer.h>
 #include <rte_malloc.h>
 #include <rte_debug.h>
+#include <rte_hexdump.h>
 
 #include <rte_eal_memconfig.h>
 #include <rte_eal.h>
+#include <rte_alarm.h>
 
 #include <rte_interrupts.h>
 #include <rte_pci.h>
 #include <rte_ethdev.h>
+#include <rte_mtr.h>
+#include <rte_tm.h>
 #include <rte_kni.h>
 #include <rte_eth_bond.h>
+#include <rte_eth_softnic.h>
 
 #include <rte_ether.h>
 #include <rte_arp.h>
@@ -48,6 +74,39 @@
 #include <rte_udp.h>
 #include <rte_sctp.h>
 #include <rte_vxlan.h>
+#include <rte_net.h>
+#include <rte_net_crc.h>
+#include <rte_gro.h>
+#include <rte_gso.h>
+#include <rte_reorder.h>
+
+// QoS
+#include <rte_red.h>
+#include <rte_sched.h>
+
+// Statistics
+#include <rte_metrics.h>
+#include <rte_latencystats.h>
+#include <rte_bitrate.h>
+
+// Packet capture
+#include <rte_pdump.h>
+#if RTE_VERSION >= RTE_VERSION_NUM(21, 11, 0, 0)
+#include <rte_pcapng.h>
+#endif
+
+// Power management
+#include <rte_power.h>
+#if RTE_VERSION >= RTE_VERSION_NUM(21, 11, 0, 0)
+#include <rte_power_pmd_mgmt.h>
+#endif
+
+#include <rte_swx_pipeline.h>
+#include <rte_swx_ctl.h>
+#include <rte_swx_port_ethdev.h>
+#include <rte_swx_table_em.h>
+
+#include <rte_cfgfile.h>
 
 #include <cmdline_rdline.h>
 #include <cmdline_parse.h>
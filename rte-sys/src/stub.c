@@ -508,3 +508,372 @@ int
 _rte_vlan_insert(struct rte_mbuf **m) {
     return rte_vlan_insert(m);
 }
+
+void *
+_rte_memcpy(void *dst, const void *src, size_t n) {
+    return rte_memcpy(dst, src, n);
+}
+
+void
+_rte_rwlock_init(rte_rwlock_t *rwl) {
+    rte_rwlock_init(rwl);
+}
+
+void
+_rte_rwlock_read_lock(rte_rwlock_t *rwl) {
+    rte_rwlock_read_lock(rwl);
+}
+
+int
+_rte_rwlock_read_trylock(rte_rwlock_t *rwl) {
+    return rte_rwlock_read_trylock(rwl);
+}
+
+void
+_rte_rwlock_read_unlock(rte_rwlock_t *rwl) {
+    rte_rwlock_read_unlock(rwl);
+}
+
+void
+_rte_rwlock_write_lock(rte_rwlock_t *rwl) {
+    rte_rwlock_write_lock(rwl);
+}
+
+int
+_rte_rwlock_write_trylock(rte_rwlock_t *rwl) {
+    return rte_rwlock_write_trylock(rwl);
+}
+
+void
+_rte_rwlock_write_unlock(rte_rwlock_t *rwl) {
+    rte_rwlock_write_unlock(rwl);
+}
+
+void
+_rte_ticketlock_init(rte_ticketlock_t *tl) {
+    rte_ticketlock_init(tl);
+}
+
+void
+_rte_ticketlock_lock(rte_ticketlock_t *tl) {
+    rte_ticketlock_lock(tl);
+}
+
+void
+_rte_ticketlock_unlock(rte_ticketlock_t *tl) {
+    rte_ticketlock_unlock(tl);
+}
+
+int
+_rte_ticketlock_trylock(rte_ticketlock_t *tl) {
+    return rte_ticketlock_trylock(tl);
+}
+
+int
+_rte_ticketlock_is_locked(rte_ticketlock_t *tl) {
+    return rte_ticketlock_is_locked(tl);
+}
+
+void
+_rte_ticketlock_recursive_init(rte_ticketlock_recursive_t *tlr) {
+    rte_ticketlock_recursive_init(tlr);
+}
+
+void
+_rte_ticketlock_recursive_lock(rte_ticketlock_recursive_t *tlr) {
+    rte_ticketlock_recursive_lock(tlr);
+}
+
+void
+_rte_ticketlock_recursive_unlock(rte_ticketlock_recursive_t *tlr) {
+    rte_ticketlock_recursive_unlock(tlr);
+}
+
+int
+_rte_ticketlock_recursive_trylock(rte_ticketlock_recursive_t *tlr) {
+    return rte_ticketlock_recursive_trylock(tlr);
+}
+
+void
+_rte_mcslock_lock(rte_mcslock_t **msl, rte_mcslock_t *me) {
+    rte_mcslock_lock(msl, me);
+}
+
+void
+_rte_mcslock_unlock(rte_mcslock_t **msl, rte_mcslock_t *me) {
+    rte_mcslock_unlock(msl, me);
+}
+
+int
+_rte_mcslock_trylock(rte_mcslock_t **msl, rte_mcslock_t *me) {
+    return rte_mcslock_trylock(msl, me);
+}
+
+int
+_rte_mcslock_is_locked(rte_mcslock_t *msl) {
+    return rte_mcslock_is_locked(msl);
+}
+
+void
+_rte_rcu_qsbr_thread_online(struct rte_rcu_qsbr *v, unsigned int thread_id) {
+    rte_rcu_qsbr_thread_online(v, thread_id);
+}
+
+void
+_rte_rcu_qsbr_thread_offline(struct rte_rcu_qsbr *v, unsigned int thread_id) {
+    rte_rcu_qsbr_thread_offline(v, thread_id);
+}
+
+uint64_t
+_rte_rcu_qsbr_start(struct rte_rcu_qsbr *v) {
+    return rte_rcu_qsbr_start(v);
+}
+
+void
+_rte_rcu_qsbr_quiescent(struct rte_rcu_qsbr *v, unsigned int thread_id) {
+    rte_rcu_qsbr_quiescent(v, thread_id);
+}
+
+int
+_rte_rcu_qsbr_check(struct rte_rcu_qsbr *v, uint64_t t, bool wait) {
+    return rte_rcu_qsbr_check(v, t, wait);
+}
+
+unsigned int
+_rte_stack_push(struct rte_stack *s, void * const *obj_table, unsigned int n) {
+    return rte_stack_push(s, obj_table, n);
+}
+
+unsigned int
+_rte_stack_pop(struct rte_stack *s, void **obj_table, unsigned int n) {
+    return rte_stack_pop(s, obj_table, n);
+}
+
+unsigned int
+_rte_stack_count(struct rte_stack *s) {
+    return rte_stack_count(s);
+}
+
+unsigned int
+_rte_stack_free_count(struct rte_stack *s) {
+    return rte_stack_free_count(s);
+}
+
+int
+_rte_red_enqueue(const struct rte_red_config *red_cfg,
+	struct rte_red *red, const unsigned q, const uint64_t time) {
+    return rte_red_enqueue(red_cfg, red, q, time);
+}
+
+void
+_rte_red_mark_queue_empty(struct rte_red *red, const uint64_t time) {
+    rte_red_mark_queue_empty(red, time);
+}
+
+uint32_t
+_rte_jhash(const void *key, uint32_t length, uint32_t initval) {
+    return rte_jhash(key, length, initval);
+}
+
+uint32_t
+_rte_hash_crc(const void *data, uint32_t data_len, uint32_t init_val) {
+    return rte_hash_crc(data, data_len, init_val);
+}
+
+FILE *
+_rte_stdout(void) {
+    return stdout;
+}
+
+void
+_rte_pause(void) {
+    rte_pause();
+}
+
+void
+_rte_wait_until_equal_16(volatile uint16_t *addr, uint16_t expected, int memorder) {
+    rte_wait_until_equal_16(addr, expected, memorder);
+}
+
+void
+_rte_wait_until_equal_32(volatile uint32_t *addr, uint32_t expected, int memorder) {
+    rte_wait_until_equal_32(addr, expected, memorder);
+}
+
+void
+_rte_wait_until_equal_64(volatile uint64_t *addr, uint64_t expected, int memorder) {
+    rte_wait_until_equal_64(addr, expected, memorder);
+}
+
+unsigned int
+_rte_ring_mp_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_mp_enqueue_bulk(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_sp_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_sp_enqueue_bulk(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_enqueue_bulk(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_mc_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_mc_dequeue_bulk(r, obj_table, n, available);
+}
+
+unsigned int
+_rte_ring_sc_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_sc_dequeue_bulk(r, obj_table, n, available);
+}
+
+unsigned int
+_rte_ring_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_dequeue_bulk(r, obj_table, n, available);
+}
+
+unsigned int
+_rte_ring_mp_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_mp_enqueue_burst(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_sp_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_sp_enqueue_burst(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, unsigned int *free_space) {
+    return rte_ring_enqueue_burst(r, obj_table, n, free_space);
+}
+
+unsigned int
+_rte_ring_mc_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_mc_dequeue_burst(r, obj_table, n, available);
+}
+
+unsigned int
+_rte_ring_sc_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_sc_dequeue_burst(r, obj_table, n, available);
+}
+
+unsigned int
+_rte_ring_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, unsigned int *available) {
+    return rte_ring_dequeue_burst(r, obj_table, n, available);
+}
+
+unsigned
+_rte_ring_count(const struct rte_ring *r) {
+    return rte_ring_count(r);
+}
+
+unsigned
+_rte_ring_free_count(const struct rte_ring *r) {
+    return rte_ring_free_count(r);
+}
+
+int
+_rte_ring_full(const struct rte_ring *r) {
+    return rte_ring_full(r);
+}
+
+int
+_rte_ring_empty(const struct rte_ring *r) {
+    return rte_ring_empty(r);
+}
+
+unsigned int
+_rte_ring_get_size(const struct rte_ring *r) {
+    return rte_ring_get_size(r);
+}
+
+unsigned int
+_rte_ring_get_capacity(const struct rte_ring *r) {
+    return rte_ring_get_capacity(r);
+}
+
+int
+_rte_lpm_lookup(struct rte_lpm *lpm, uint32_t ip, uint32_t *next_hop) {
+    return rte_lpm_lookup(lpm, ip, next_hop);
+}
+
+int
+_rte_lpm_lookup_bulk(const struct rte_lpm *lpm, const uint32_t *ips, uint32_t *next_hops, unsigned int n) {
+    return rte_lpm_lookup_bulk(lpm, ips, next_hops, n);
+}
+
+void
+_rte_lpm_lookupx4(const struct rte_lpm *lpm, const uint32_t ip[4], uint32_t hop[4], uint32_t defv) {
+    rte_lpm_lookupx4(lpm, vect_loadu_sil128((xmm_t *)(uintptr_t)ip), hop, defv);
+}
+
+uint16_t
+_rte_cryptodev_enqueue_burst(uint8_t dev_id, uint16_t qp_id, struct rte_crypto_op **ops, uint16_t nb_ops) {
+    return rte_cryptodev_enqueue_burst(dev_id, qp_id, ops, nb_ops);
+}
+
+uint16_t
+_rte_cryptodev_dequeue_burst(uint8_t dev_id, uint16_t qp_id, struct rte_crypto_op **ops, uint16_t nb_ops) {
+    return rte_cryptodev_dequeue_burst(dev_id, qp_id, ops, nb_ops);
+}
+
+struct rte_crypto_op *
+_rte_crypto_op_alloc(struct rte_mempool *mempool, enum rte_crypto_op_type type) {
+    return rte_crypto_op_alloc(mempool, type);
+}
+
+unsigned
+_rte_crypto_op_bulk_alloc(struct rte_mempool *mempool,
+                          enum rte_crypto_op_type type,
+                          struct rte_crypto_op **ops,
+                          uint16_t nb_ops) {
+    return rte_crypto_op_bulk_alloc(mempool, type, ops, nb_ops);
+}
+
+void
+_rte_crypto_op_free(struct rte_crypto_op *op) {
+    rte_crypto_op_free(op);
+}
+
+int
+_rte_crypto_op_attach_sym_session(struct rte_crypto_op *op, struct rte_cryptodev_sym_session *sess) {
+    return rte_crypto_op_attach_sym_session(op, sess);
+}
+
+uint16_t
+_rte_event_enqueue_burst(uint8_t dev_id, uint8_t port_id, const struct rte_event ev[], uint16_t nb_events) {
+    return rte_event_enqueue_burst(dev_id, port_id, ev, nb_events);
+}
+
+uint16_t
+_rte_event_enqueue_new_burst(uint8_t dev_id, uint8_t port_id, const struct rte_event ev[], uint16_t nb_events) {
+    return rte_event_enqueue_new_burst(dev_id, port_id, ev, nb_events);
+}
+
+uint16_t
+_rte_event_enqueue_forward_burst(uint8_t dev_id, uint8_t port_id, const struct rte_event ev[], uint16_t nb_events) {
+    return rte_event_enqueue_forward_burst(dev_id, port_id, ev, nb_events);
+}
+
+uint16_t
+_rte_event_dequeue_burst(uint8_t dev_id, uint8_t port_id, struct rte_event ev[], uint16_t nb_events, uint64_t timeout_ticks) {
+    return rte_event_dequeue_burst(dev_id, port_id, ev, nb_events, timeout_ticks);
+}
+
+uint16_t
+_rte_ipv4_phdr_cksum(const struct rte_ipv4_hdr *ipv4_hdr, uint64_t ol_flags) {
+    return rte_ipv4_phdr_cksum(ipv4_hdr, ol_flags);
+}
+
+uint16_t
+_rte_ipv6_phdr_cksum(const struct rte_ipv6_hdr *ipv6_hdr, uint64_t ol_flags) {
+    return rte_ipv6_phdr_cksum(ipv6_hdr, ol_flags);
+}
+
+int
+_rte_net_intel_cksum_flags_prepare(struct rte_mbuf *m, uint64_t ol_flags) {
+    return rte_net_intel_cksum_flags_prepare(m, ol_flags);
+}
@@ -8,19 +8,23 @@ extern crate rte;
 
 use std::cell::RefCell;
 use std::env;
-use std::mem;
+use std::mem::{self, MaybeUninit};
 use std::net;
 use std::ptr::NonNull;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
 
-use rte::arp::{RTE_ARP_HRD_ETHER, RTE_ARP_OP_REPLY, RTE_ARP_OP_REQUEST};
+use rte::arp::{ArpHdrExt, RTE_ARP_HRD_ETHER, RTE_ARP_OP_REPLY, RTE_ARP_OP_REQUEST};
 use rte::bond::BondedDevice;
+use rte::byteorder::{be16, be32};
 use rte::ethdev::EthDevice;
-use rte::ether::{RTE_ETHER_TYPE_IPV4, ETHER_ADDR_LEN, RTE_ETHER_TYPE_ARP};
+use rte::ether::{EtherHdrExt, VlanHdrExt, ETHER_ADDR_LEN};
+use rte::ip::Ipv4HdrExt;
 use rte::lcore::RTE_MAX_LCORE;
 use rte::mbuf::MBufPool;
 use rte::memory::AsMutRef;
+use rte::utils::FromRaw;
 use rte::*;
 
 const EXIT_FAILURE: i32 = -1;
@@ -50,22 +54,20 @@ struct AppConfig {
     bond_mac_addr: ether::EtherAddr,
     bonded_port_id: PortId,
     pktmbuf_pool: mempool::MemoryPool,
+    // the queues of the bonded port, shared by lcore_main and the `send` command
+    queues: Mutex<(ethdev::RxQueue<'static>, ethdev::TxQueue)>,
     port_packets: [AtomicUsize; 4],
 }
 
-impl Default for AppConfig {
-    fn default() -> Self {
-        unsafe { mem::zeroed() }
-    }
-}
-
 impl AppConfig {
     fn is_running(&self) -> bool {
         self.lcore_main_is_running.load(Ordering::Relaxed)
     }
 
     fn start(&self) {
-        launch::remote_launch(lcore_main, Some(self), self.lcore_main_core_id).expect("Cannot launch task");
+        // the configuration is shared with the lcore, which is waited before the configuration is dropped
+        unsafe { launch::remote_launch_unchecked(move || lcore_main(self), self.lcore_main_core_id) }
+            .expect("Cannot launch task");
 
         self.lcore_main_is_running.store(true, Ordering::Relaxed);
 
@@ -82,7 +84,7 @@ impl AppConfig {
     }
 }
 
-fn slave_port_init(port_id: ethdev::PortId, port_conf: &ethdev::EthConf, pktmbuf_pool: &mut mempool::MemoryPool) {
+fn slave_port_init(port_id: ethdev::PortId, port_conf: &ethdev::EthConf, pktmbuf_pool: &mempool::MemoryPool) {
     info!("Setup port {}", port_id);
 
     let dev = port_id;
@@ -90,11 +92,11 @@ fn slave_port_init(port_id: ethdev::PortId, port_conf: &ethdev::EthConf, pktmbuf
     dev.configure(1, 1, &port_conf)
         .expect(&format!("fail to configure device: port={}", port_id));
 
-    // init one RX queue
+    // init one RX queue, which is polled by the bonded port
     dev.rx_queue_setup(0, RTE_RX_DESC_DEFAULT, None, pktmbuf_pool)
         .expect(&format!("fail to setup device rx queue: port={}", port_id));
 
-    // init one TX queue on each port
+    // init one TX queue on each port, which is used by the bonded port
     dev.tx_queue_setup(0, RTE_TX_DESC_DEFAULT, None)
         .expect(&format!("fail to setup device tx queue: port={}", port_id));
 
@@ -109,8 +111,8 @@ fn slave_port_init(port_id: ethdev::PortId, port_conf: &ethdev::EthConf, pktmbuf
 fn bond_port_init(
     slave_count: u16,
     port_conf: &ethdev::EthConf,
-    pktmbuf_pool: &mut mempool::MemoryPool,
-) -> ethdev::PortId {
+    pktmbuf_pool: &'static mempool::MemoryPool,
+) -> (ethdev::PortId, ethdev::RxQueue<'static>, ethdev::TxQueue) {
     let dev = bond::create("bond0", bond::BondMode::AdaptiveLB, 0).expect("Faled to create bond port");
 
     let bonded_port_id = dev;
@@ -119,15 +121,17 @@ fn bond_port_init(
         .expect(&format!("fail to configure device: port={}", bonded_port_id));
 
     // init one RX queue
-    dev.rx_queue_setup(0, RTE_RX_DESC_DEFAULT, None, pktmbuf_pool)
+    let rxq = dev
+        .rx_queue_setup(0, RTE_RX_DESC_DEFAULT, None, pktmbuf_pool)
         .expect(&format!("fail to setup device rx queue: port={}", bonded_port_id));
 
     // init one TX queue on each port
-    dev.tx_queue_setup(0, RTE_TX_DESC_DEFAULT, None)
+    let txq = dev
+        .tx_queue_setup(0, RTE_TX_DESC_DEFAULT, None)
         .expect(&format!("fail to setup device tx queue: port={}", bonded_port_id));
 
     for slave_port_id in 0..slave_count {
-        dev.add_slave(slave_port_id).expect(&format!(
+        dev.add_slave(ethdev::PortId(slave_port_id)).expect(&format!(
             "Oooops! adding slave {} to bond {} failed!",
             slave_port_id, bonded_port_id
         ));
@@ -141,38 +145,40 @@ fn bond_port_init(
 
     info!("Bonded port {} MAC: {}", bonded_port_id, dev.mac_addr());
 
-    dev
+    (dev, rxq, txq)
 }
 
-fn strip_vlan_hdr(ether_hdr: *const ether::EtherHdr) -> (*const libc::c_void, u16) {
+fn strip_vlan_hdr(ether_hdr: *const ether::EtherHdr) -> (*const libc::c_void, be16) {
     unsafe {
-        if (*ether_hdr).ether_type != ether::ETHER_TYPE_VLAN_BE {
-            (ether_hdr.offset(1) as *const libc::c_void, (*ether_hdr).ether_type)
+        if (*ether_hdr).ether_type() != ether::ETHER_TYPE_VLAN_BE {
+            (ether_hdr.offset(1) as *const libc::c_void, (*ether_hdr).ether_type())
         } else {
             let mut vlan_hdr = ether_hdr.offset(1) as *const ether::VlanHdr;
 
-            while (*vlan_hdr).eth_proto == ether::ETHER_TYPE_VLAN_BE {
+            while (*vlan_hdr).eth_proto() == ether::ETHER_TYPE_VLAN_BE {
                 vlan_hdr = vlan_hdr.offset(1);
             }
 
             debug!("VLAN taged frame, offset: {}", vlan_hdr as usize - ether_hdr as usize);
 
-            (vlan_hdr.offset(1) as *const libc::c_void, (*vlan_hdr).eth_proto)
+            (vlan_hdr.offset(1) as *const libc::c_void, (*vlan_hdr).eth_proto())
         }
     }
 }
 
 // Main thread that does the work, reading from INPUT_PORT and writing to OUTPUT_PORT
-fn lcore_main(app_conf: Option<&AppConfig>) -> i32 {
+fn lcore_main(app_conf: &AppConfig) -> i32 {
     debug!("lcore_main is starting @ lcore {}", lcore::current().unwrap());
 
-    let app_conf = app_conf.unwrap();
     let dev = app_conf.bonded_port_id;
-    let mut pkts: [Option<mbuf::MBuf>; MAX_PKT_BURST] = unsafe { mem::zeroed() };
-    let bond_ip = u32::from(app_conf.bond_ip).to_be();
+    let mut pkts: [MaybeUninit<mbuf::RawMBufPtr>; MAX_PKT_BURST] = unsafe { MaybeUninit::uninit().assume_init() };
+    let bond_ip = be32::new(u32::from(app_conf.bond_ip));
 
     while app_conf.lcore_main_is_running.load(Ordering::Relaxed) {
-        let rx_cnt = dev.rx_burst(0, &mut pkts[..]);
+        let mut queues = app_conf.queues.lock().unwrap();
+        let (ref mut rxq, ref mut txq) = *queues;
+        let rx_pkts = rxq.rx_burst(&mut pkts[..]);
+        let rx_cnt = rx_pkts.len();
 
         // If didn't receive any packets, wait and go to next iteration
         if rx_cnt == 0 {
@@ -186,8 +192,8 @@ fn lcore_main(app_conf: Option<&AppConfig>) -> i32 {
         app_conf.port_packets[0].fetch_add(rx_cnt, Ordering::Relaxed);
 
         // Search incoming data for ARP packets and prepare response
-        for pkt in pkts.into_iter().take(rx_cnt) {
-            if let Some(m) = pkt {
+        for &mut pkt in rx_pkts {
+            if let Some(m) = mbuf::MBuf::from_raw(pkt) {
                 let mut p = m.mtod::<ether::EtherHdr>();
                 let ether_hdr = unsafe { p.as_mut() };
                 let (next_hdr, next_proto) = strip_vlan_hdr(ether_hdr);
@@ -197,15 +203,15 @@ fn lcore_main(app_conf: Option<&AppConfig>) -> i32 {
                         app_conf.port_packets[1].fetch_add(1, Ordering::Relaxed);
 
                         if let Some(mut arp_hdr) = (next_hdr as *mut arp::ArpHdr).as_mut_ref() {
-                            if arp_hdr.arp_data.arp_tip == bond_ip {
+                            if arp_hdr.target_ip() == bond_ip {
                                 debug!(
                                     "received ARP {:x} packet from {}",
-                                    arp_hdr.arp_opcode.to_le(),
+                                    arp_hdr.opcode(),
                                     ether::EtherAddr::from(arp_hdr.arp_data.arp_sha)
                                 );
 
-                                if arp_hdr.arp_opcode == (RTE_ARP_OP_REQUEST as u16).to_be() {
-                                    arp_hdr.arp_opcode = (RTE_ARP_OP_REPLY as u16).to_be();
+                                if arp_hdr.opcode() == be16::new(RTE_ARP_OP_REQUEST as u16) {
+                                    arp_hdr.set_opcode(be16::new(RTE_ARP_OP_REPLY as u16));
 
                                     ether::EtherAddr::copy(
                                         &ether_hdr.s_addr.addr_bytes,
@@ -222,10 +228,12 @@ fn lcore_main(app_conf: Option<&AppConfig>) -> i32 {
                                         &mut arp_hdr.arp_data.arp_sha.addr_bytes,
                                     );
 
-                                    arp_hdr.arp_data.arp_tip = arp_hdr.arp_data.arp_sip;
-                                    arp_hdr.arp_data.arp_sip = bond_ip;
+                                    let sender_ip = arp_hdr.sender_ip();
 
-                                    let _ = dev.tx_burst(0, &mut [m]);
+                                    arp_hdr.set_target_ip(sender_ip);
+                                    arp_hdr.set_sender_ip(bond_ip);
+
+                                    let _ = txq.tx_burst(&mut [m]);
                                 }
                             }
                         }
@@ -234,16 +242,21 @@ fn lcore_main(app_conf: Option<&AppConfig>) -> i32 {
                         app_conf.port_packets[2].fetch_add(1, Ordering::Relaxed);
 
                         if let Some(mut ipv4_hdr) = (next_hdr as *mut ip::Ipv4Hdr).as_mut_ref() {
-                            if ipv4_hdr.dst_addr == bond_ip {
-                                debug!("received IP packet from {}", net::Ipv4Addr::from(ipv4_hdr.src_addr));
+                            if ipv4_hdr.dst_addr() == bond_ip {
+                                debug!(
+                                    "received IP packet from {}",
+                                    net::Ipv4Addr::from(ipv4_hdr.src_addr().get())
+                                );
 
                                 ether::EtherAddr::copy(&ether_hdr.s_addr.addr_bytes, &mut ether_hdr.d_addr.addr_bytes);
                                 ether::EtherAddr::copy(&app_conf.bond_mac_addr, &mut ether_hdr.s_addr.addr_bytes);
 
-                                ipv4_hdr.dst_addr = ipv4_hdr.src_addr;
-                                ipv4_hdr.src_addr = bond_ip;
+                                let src_addr = ipv4_hdr.src_addr();
+
+                                ipv4_hdr.set_dst_addr(src_addr);
+                                ipv4_hdr.set_src_addr(bond_ip);
 
-                                let _ = dev.tx_burst(0, &mut [m]);
+                                let _ = txq.tx_burst(&mut [m]);
                             }
                         }
                     }
@@ -280,7 +293,7 @@ impl CmdActionResult {
                 let mut p = m.mtod::<ether::EtherHdr>();
                 {
                     let ether_hdr = unsafe { p.as_mut() };
-                    ether_hdr.ether_type = (RTE_ETHER_TYPE_ARP as u16).to_be();
+                    ether_hdr.set_ether_type(ether::ETHER_TYPE_ARP_BE);
 
                     ether::EtherAddr::copy(&app_conf.bond_mac_addr, &mut ether_hdr.s_addr.addr_bytes);
                     ether::EtherAddr::copy(&ether::EtherAddr::broadcast(), &mut ether_hdr.d_addr.addr_bytes);
@@ -289,19 +302,19 @@ impl CmdActionResult {
                 let mut p = unsafe { NonNull::new_unchecked(p.as_ptr().add(1) as *mut arp::ArpHdr) };
                 let arp_hdr = unsafe { p.as_mut() };
 
-                arp_hdr.arp_hardware = (RTE_ARP_HRD_ETHER as u16).to_be();
-                arp_hdr.arp_protocol = (RTE_ETHER_TYPE_IPV4 as u16).to_be();
+                arp_hdr.set_hardware(be16::new(RTE_ARP_HRD_ETHER as u16));
+                arp_hdr.set_protocol(ether::ETHER_TYPE_IPV4_BE);
                 arp_hdr.arp_hlen = ETHER_ADDR_LEN as u8;
                 arp_hdr.arp_plen = mem::size_of::<u32>() as u8;
-                arp_hdr.arp_opcode = (RTE_ARP_OP_REQUEST as u16).to_be();
+                arp_hdr.set_opcode(be16::new(RTE_ARP_OP_REQUEST as u16));
 
                 ether::EtherAddr::copy(&app_conf.bond_mac_addr, &mut arp_hdr.arp_data.arp_sha.addr_bytes);
                 ether::EtherAddr::copy(&ether::EtherAddr::zeroed(), &mut arp_hdr.arp_data.arp_tha.addr_bytes);
 
-                arp_hdr.arp_data.arp_sip = u32::from(app_conf.bond_ip).to_be();
-                arp_hdr.arp_data.arp_tip = u32::from(ip).to_be();
+                arp_hdr.set_sender_ip(be32::new(u32::from(app_conf.bond_ip)));
+                arp_hdr.set_target_ip(be32::new(u32::from(ip)));
 
-                if app_conf.bonded_port_id.tx_burst(0, &mut [m]) == 1 {
+                if app_conf.queues.lock().unwrap().1.tx_burst(&mut [m]) == 1 {
                     debug!("send ARP request to {}", ip);
                 }
             }
@@ -396,9 +409,7 @@ impl CmdActionResult {
     }
 }
 
-fn prompt(app_conf: AppConfig) {
-    let app_conf = Rc::new(RefCell::new(app_conf));
-
+fn prompt(app_conf: Rc<RefCell<AppConfig>>) {
     let cmd_obj_action_send = TOKEN_STRING_INITIALIZER!(CmdActionResult, action, "send");
     let cmd_obj_ip = TOKEN_IPV4_INITIALIZER!(CmdActionResult, ip);
     let cmd_obj_action_start = TOKEN_STRING_INITIALIZER!(CmdActionResult, action, "start");
@@ -475,16 +486,18 @@ fn main() {
         info!("found {} ports", nb_ports);
     }
 
-    // create the mbuf pool
-    let mut pktmbuf_pool = mbuf::pool_create(
-        "mbuf_pool",
-        NB_MBUF,
-        MEMPOOL_CACHE_SZ,
-        0,
-        mbuf::RTE_MBUF_DEFAULT_BUF_SIZE as u16,
-        rte::socket_id() as i32,
-    )
-    .expect("fail to initial mbuf pool");
+    // create the mbuf pool, which is used by the queues until the application exits
+    let pktmbuf_pool: &'static mempool::MemoryPool = Box::leak(Box::new(
+        mbuf::pool_create(
+            "mbuf_pool",
+            NB_MBUF,
+            MEMPOOL_CACHE_SZ,
+            0,
+            mbuf::RTE_MBUF_DEFAULT_BUF_SIZE as u16,
+            rte::socket_id() as i32,
+        )
+        .expect("fail to initial mbuf pool"),
+    ));
 
     let port_conf = ethdev::EthConf {
         rx_adv_conf: Some(ethdev::RxAdvConf {
@@ -499,13 +512,13 @@ fn main() {
 
     // initialize all ports
     for portid in 0..nb_ports {
-        slave_port_init(portid, &port_conf, &mut pktmbuf_pool);
+        slave_port_init(ethdev::PortId(portid), &port_conf, pktmbuf_pool);
     }
 
-    let bonded_dev = bond_port_init(nb_ports, &port_conf, &mut pktmbuf_pool);
+    let (bonded_dev, rxq, txq) = bond_port_init(nb_ports, &port_conf, pktmbuf_pool);
 
     // check state of lcores
-    lcore::foreach_slave(|lcore_id| {
+    lcore::foreach_worker(|lcore_id| {
         if lcore_id.state() != launch::State::Wait {
             eal::exit(-libc::EBUSY, "lcores not ready");
         }
@@ -518,19 +531,22 @@ fn main() {
         eal::exit(-libc::EPERM, "missing slave core");
     }
 
-    let app_conf = AppConfig {
+    // the configuration must not be moved after lcore_main has been launched
+    let app_conf = Rc::new(RefCell::new(AppConfig {
         bond_ip: net::Ipv4Addr::new(10, 0, 0, 7),
         bond_mac_addr: bonded_dev.mac_addr(),
         bonded_port_id: bonded_dev.portid(),
         lcore_main_is_running: AtomicBool::new(true),
         lcore_main_core_id: slave_core_id,
-        pktmbuf_pool,
-        ..AppConfig::default()
-    };
+        // another handle of the mbuf pool, which is borrowed by the queues
+        pktmbuf_pool: mempool::MemoryPool::lookup("mbuf_pool").expect("fail to lookup mbuf pool"),
+        queues: Mutex::new((rxq, txq)),
+        port_packets: Default::default(),
+    }));
 
-    app_conf.start();
+    app_conf.borrow().start();
 
-    prompt(app_conf);
+    prompt(app_conf.clone());
 
     launch::mp_wait_lcore();
 }
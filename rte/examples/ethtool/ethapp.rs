@@ -61,7 +61,7 @@ struct CmdIntParams {
 
 impl CmdIntParams {
     fn dev(&self) -> ethdev::PortId {
-        self.port as ethdev::PortId
+        ethdev::PortId(self.port as u16)
     }
 
     fn open(&mut self, cl: &CmdLine, app_cfg: Option<&AppConfig>) {
@@ -163,14 +163,14 @@ struct CmdIntMtuParams {
 
 impl CmdIntMtuParams {
     fn dev(&self) -> ethdev::PortId {
-        self.port as ethdev::PortId
+        ethdev::PortId(self.port as u16)
     }
 
     fn mtu_list(&mut self, cl: &CmdLine, app_cfg: Option<&AppConfig>) {
         debug!("execute list `{}` command for port {}", self.cmd, self.port);
 
         for portid in 0..app_cfg.unwrap().ports.len() {
-            let dev = portid as ethdev::PortId;
+            let dev = ethdev::PortId(portid as u16);
 
             cl.println(format!("Port {} MTU: {}", portid, dev.mtu().unwrap()))
                 .unwrap();
@@ -212,14 +212,14 @@ struct CmdIntMacParams {
 
 impl CmdIntMacParams {
     fn dev(&self) -> ethdev::PortId {
-        self.port as ethdev::PortId
+        ethdev::PortId(self.port as u16)
     }
 
     fn list(&mut self, cl: &CmdLine, app_cfg: Option<&AppConfig>) {
         debug!("execute list `{}` command for port {}", self.cmd, self.port);
 
         for portid in 0..app_cfg.unwrap().ports.len() {
-            let dev = portid as ethdev::PortId;
+            let dev = ethdev::PortId(portid as u16);
 
             cl.println(format!("Port {} MAC Address: {}", portid, dev.mac_addr()))
                 .unwrap();
@@ -276,7 +276,7 @@ struct CmdVlanParams {
 
 impl CmdVlanParams {
     fn dev(&self) -> ethdev::PortId {
-        self.port as ethdev::PortId
+        ethdev::PortId(self.port as u16)
     }
 
     fn change(&mut self, cl: &CmdLine, _: Option<&c_void>) {
@@ -309,7 +309,7 @@ impl CmdVlanParams {
     }
 }
 
-pub fn main(app_cfg: &mut AppConfig) {
+pub fn main(app_cfg: &AppConfig) {
     // Parameter-less commands
     let pcmd_quit_token_cmd = TOKEN_STRING_INITIALIZER!(CmdGetParams, cmd, "quit");
     let pcmd_drvinfo_token_cmd = TOKEN_STRING_INITIALIZER!(CmdGetParams, cmd, "drvinfo");
@@ -1,5 +1,6 @@
 use std::mem;
 use std::result;
+use std::sync::atomic::AtomicBool;
 use std::sync::Mutex;
 
 use rte::ethdev::EthDevice;
@@ -11,8 +12,7 @@ pub const MAX_PORTS: u16 = RTE_MAX_ETHPORTS as u16;
 pub const MAX_BURST_LENGTH: usize = 32;
 
 pub struct TxQueuePort {
-    pub cnt_unsent: usize,
-    pub buf_frames: [Option<mbuf::MBuf>; MAX_BURST_LENGTH],
+    pub buf_frames: mbuf::MBufBatch<MAX_BURST_LENGTH>,
 }
 
 pub struct AppPort {
@@ -21,18 +21,27 @@ pub struct AppPort {
     pub port_id: u8,
     pub port_active: bool,
     pub port_dirty: bool,
-    pub pkt_pool: mempool::MemoryPool,
+    pub queues: Option<(ethdev::RxQueue<'static>, ethdev::TxQueue)>,
 }
 
 impl Default for AppPort {
     fn default() -> Self {
-        unsafe { mem::zeroed() }
+        AppPort {
+            mac_addr: ether::EtherAddr::zeroed(),
+            txq: TxQueuePort {
+                buf_frames: mbuf::MBufBatch::new(),
+            },
+            port_id: 0,
+            port_active: false,
+            port_dirty: false,
+            queues: None,
+        }
     }
 }
 
 pub struct AppConfig {
     pub ports: Vec<Mutex<AppPort>>,
-    pub exit_now: bool,
+    pub exit_now: AtomicBool,
 }
 
 impl Default for AppConfig {
@@ -45,7 +54,7 @@ impl AppConfig {
     pub fn new(ports: u32) -> AppConfig {
         AppConfig {
             ports: (0..ports).map(|_| Mutex::new(AppPort::default())).collect(),
-            exit_now: false,
+            exit_now: AtomicBool::new(false),
         }
     }
 
@@ -53,7 +62,7 @@ impl AppConfig {
     where
         F: Fn(&mut AppPort, ethdev::PortId) -> result::Result<T, String>,
     {
-        match self.ports.iter().nth(port as usize) {
+        match self.ports.iter().nth(usize::from(port)) {
             Some(mutex) => {
                 if !port.is_valid() {
                     Err(format!("port {} is invalid", port))
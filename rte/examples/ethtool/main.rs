@@ -8,6 +8,7 @@ mod ethapp;
 mod ethtool;
 
 use std::env;
+use std::sync::atomic::Ordering;
 
 use rte::ethdev::EthDevice;
 use rte::*;
@@ -29,20 +30,23 @@ fn setup_ports(app_cfg: &mut AppConfig) {
         if let Ok(mut guard) = mutex.lock() {
             let app_port: &mut AppPort = &mut *guard;
 
-            let dev = portid as ethdev::PortId;
+            let dev = ethdev::PortId(portid as u16);
             let dev_info = dev.info();
 
             let size_pktpool = dev_info.rx_desc_lim.nb_max + dev_info.tx_desc_lim.nb_max + PKTPOOL_EXTRA_SIZE;
 
-            app_port.pkt_pool = mbuf::pool_create(
-                &format!("pkt_pool_{}", portid),
-                size_pktpool as u32,
-                PKTPOOL_CACHE,
-                0,
-                mbuf::RTE_MBUF_DEFAULT_BUF_SIZE as u16,
-                rte::socket_id() as i32,
-            )
-            .expect("create mbuf pool failed");
+            // the pool is used by the RX queue until the application exits
+            let pkt_pool: &'static mempool::MemoryPool = Box::leak(Box::new(
+                mbuf::pool_create(
+                    &format!("pkt_pool_{}", portid),
+                    size_pktpool as u32,
+                    PKTPOOL_CACHE,
+                    0,
+                    mbuf::RTE_MBUF_DEFAULT_BUF_SIZE as u16,
+                    rte::socket_id() as i32,
+                )
+                .expect("create mbuf pool failed"),
+            ));
 
             println!("Init port {}..\n", portid);
 
@@ -54,13 +58,17 @@ fn setup_ports(app_cfg: &mut AppConfig) {
                 .expect(&format!("fail to configure device: port={}", portid));
 
             // init one RX queue
-            dev.rx_queue_setup(0, PORT_RX_QUEUE_SIZE, None, &mut app_port.pkt_pool)
+            let rxq = dev
+                .rx_queue_setup(0, PORT_RX_QUEUE_SIZE, None, pkt_pool)
                 .expect(&format!("fail to setup device rx queue: port={}", portid));
 
             // init one TX queue on each port
-            dev.tx_queue_setup(0, PORT_TX_QUEUE_SIZE, None)
+            let txq = dev
+                .tx_queue_setup(0, PORT_TX_QUEUE_SIZE, None)
                 .expect(&format!("fail to setup device tx queue: port={}", portid));
 
+            app_port.queues = Some((rxq, txq));
+
             // Start device
             dev.start().expect(&format!("fail to start device: port={}", portid));
 
@@ -76,10 +84,8 @@ fn process_frame(mac_addr: &ether::EtherAddr, frame: &mbuf::MBuf) {
     ether::EtherAddr::copy(mac_addr, &mut ether_hdr.s_addr.addr_bytes);
 }
 
-fn slave_main(app_cfg: Option<&mut AppConfig>) -> i32 {
-    let app_cfg = app_cfg.unwrap();
-
-    while !app_cfg.exit_now {
+fn slave_main(app_cfg: &AppConfig) -> i32 {
+    while !app_cfg.exit_now.load(Ordering::Relaxed) {
         for (portid, mutex) in app_cfg.ports.iter().enumerate() {
             // Check that port is active and unlocked
             if let Ok(mut guard) = mutex.try_lock() {
@@ -89,7 +95,7 @@ fn slave_main(app_cfg: Option<&mut AppConfig>) -> i32 {
                     continue;
                 }
 
-                let dev = portid as ethdev::PortId;
+                let dev = ethdev::PortId(portid as u16);
 
                 // MAC address was updated
                 if app_port.port_dirty {
@@ -97,27 +103,25 @@ fn slave_main(app_cfg: Option<&mut AppConfig>) -> i32 {
                     app_port.port_dirty = false;
                 }
 
+                let (rxq, dev_txq) = match app_port.queues {
+                    Some((ref mut rxq, ref mut txq)) => (rxq, txq),
+                    None => continue,
+                };
                 let txq = &mut app_port.txq;
 
                 // Incoming frames
-                let cnt_recv_frames = dev.rx_burst(0, &mut txq.buf_frames[txq.cnt_unsent..]);
+                let cnt_unsent = txq.buf_frames.len();
+                let cnt_recv_frames = rxq.rx_burst_batch(&mut txq.buf_frames);
 
                 if cnt_recv_frames > 0 {
-                    let frames = &txq.buf_frames[txq.cnt_unsent..txq.cnt_unsent + cnt_recv_frames];
-                    for frame in frames {
-                        process_frame(&app_port.mac_addr, frame.as_ref().unwrap());
+                    for frame in &txq.buf_frames[cnt_unsent..] {
+                        process_frame(&app_port.mac_addr, frame);
                     }
-
-                    txq.cnt_unsent += cnt_recv_frames
                 }
 
                 // Outgoing frames
-                if txq.cnt_unsent > 0 {
-                    let cnt_sent = dev.tx_burst(0, &mut txq.buf_frames[..txq.cnt_unsent]);
-
-                    for i in cnt_sent..txq.cnt_unsent {
-                        txq.buf_frames[i - cnt_sent] = txq.buf_frames[i].take();
-                    }
+                if !txq.buf_frames.is_empty() {
+                    dev_txq.tx_burst_batch(&mut txq.buf_frames);
                 }
             }
         }
@@ -161,11 +165,14 @@ fn main() {
     // Assume there is an available slave..
     let lcore_id = lcore::current().unwrap().next().unwrap();
 
-    launch::remote_launch(slave_main, Some(&mut app_cfg), lcore_id).unwrap();
+    let app_cfg = &app_cfg;
+
+    // the slave lcore is waited before the configuration is dropped
+    unsafe { launch::remote_launch_unchecked(move || slave_main(app_cfg), lcore_id) }.unwrap();
 
-    ethapp::main(&mut app_cfg);
+    ethapp::main(app_cfg);
 
-    app_cfg.exit_now = true;
+    app_cfg.exit_now.store(true, Ordering::Relaxed);
 
     launch::mp_wait_lcore();
 }
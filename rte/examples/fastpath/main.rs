@@ -0,0 +1,140 @@
+//! Benchmark the inlined fast path against the out-of-line C shims.
+//!
+//! It runs on a null port, which receives and drops the packets without any hardware, e.g.
+//!
+//! cargo run --example fastpath -- -l 0 --no-pci --vdev=net_null0
+//!
+#[macro_use]
+extern crate log;
+extern crate pretty_env_logger;
+extern crate rte;
+
+use std::env;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+use rte::ethdev::{EthDevice, EthDeviceInfo};
+use rte::memory::SOCKET_ID_ANY;
+use rte::mempool::MemoryPoolFlags;
+use rte::utils::AsRaw;
+use rte::*;
+
+const ITERATIONS: u64 = 10_000_000;
+
+const MAX_PKT_BURST: usize = 32;
+
+const NB_MBUF: u32 = 8191;
+const MEMPOOL_CACHE_SIZE: u32 = 256;
+
+const NB_RXD: u16 = 1024;
+const NB_TXD: u16 = 1024;
+
+// Run the closure `ITERATIONS` times, and return the average cycles of an iteration.
+fn bench<F: FnMut()>(name: &str, mut f: F) -> u64 {
+    let start = rte::rdtsc();
+
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    let cycles = (rte::rdtsc() - start) / ITERATIONS;
+
+    println!("{:<32} {:>6} cycles", name, cycles);
+
+    cycles
+}
+
+fn main() {
+    pretty_env_logger::init();
+
+    let args: Vec<String> = env::args().collect();
+
+    eal::init(&args).expect("fail to initial EAL");
+
+    let pktmbuf_pool = mbuf::pool_create(
+        "mbuf_pool",
+        NB_MBUF,
+        MEMPOOL_CACHE_SIZE,
+        0,
+        mbuf::RTE_MBUF_DEFAULT_BUF_SIZE as u16,
+        rte::socket_id() as i32,
+    )
+    .expect("fail to create mbuf pool");
+
+    let dev = ethdev::devices()
+        .next()
+        .expect("no available port, add a null port with `--vdev=net_null0`");
+    let port_id = dev.portid();
+
+    debug!("benchmark port #{} with `{}` driver", port_id, dev.info().driver_name());
+
+    dev.configure(1, 1, &ethdev::EthConf::default())
+        .expect(&format!("fail to configure device: port={}", port_id));
+
+    let mut rxq = dev
+        .rx_queue_setup(0, NB_RXD, None, &pktmbuf_pool)
+        .expect(&format!("fail to setup device rx queue: port={}", port_id));
+    let mut txq = dev
+        .tx_queue_setup(0, NB_TXD, None)
+        .expect(&format!("fail to setup device tx queue: port={}", port_id));
+
+    dev.start().expect(&format!("fail to start device: port={}", port_id));
+
+    let mut pkts = [MaybeUninit::<mbuf::RawMBufPtr>::uninit(); MAX_PKT_BURST];
+
+    bench("rx burst (shim)", || unsafe {
+        let n = ffi::_rte_eth_rx_burst(port_id.0, 0, pkts.as_mut_ptr() as *mut _, MAX_PKT_BURST as u16);
+
+        mbuf::free_burst(slice::from_raw_parts_mut(pkts.as_mut_ptr() as *mut _, n as usize));
+    });
+
+    bench("rx burst (inline)", || {
+        let rx_pkts = rxq.rx_burst(&mut pkts);
+
+        mbuf::free_burst(rx_pkts);
+    });
+
+    bench("rx + tx burst (shim)", || unsafe {
+        let n = ffi::_rte_eth_rx_burst(port_id.0, 0, pkts.as_mut_ptr() as *mut _, MAX_PKT_BURST as u16);
+        let rx_pkts = slice::from_raw_parts_mut(pkts.as_mut_ptr() as *mut mbuf::RawMBufPtr, n as usize);
+        let n = ffi::_rte_eth_tx_burst(port_id.0, 0, rx_pkts.as_mut_ptr(), rx_pkts.len() as u16);
+
+        mbuf::free_burst(&mut rx_pkts[n as usize..]);
+    });
+
+    bench("rx + tx burst (inline)", || {
+        let rx_pkts = rxq.rx_burst(&mut pkts);
+
+        txq.tx_burst_and_free_rest(rx_pkts);
+    });
+
+    let pool = mempool::MemPool::<u64>::create(
+        "bench_pool",
+        NB_MBUF,
+        MEMPOOL_CACHE_SIZE,
+        SOCKET_ID_ANY,
+        MemoryPoolFlags::empty(),
+    )
+    .expect("fail to create mempool");
+
+    bench("mempool get + put (shim)", || unsafe {
+        let mut obj = ptr::null_mut();
+
+        if ffi::_rte_mempool_get(pool.as_raw_mut(), &mut obj) == 0 {
+            (obj as *mut u64).write(0);
+
+            ffi::_rte_mempool_put(pool.as_raw_mut(), obj);
+        }
+    });
+
+    bench("mempool get + put (inline)", || {
+        drop(pool.get(0));
+    });
+
+    dev.stop();
+
+    drop((rxq, txq));
+
+    dev.close().expect(&format!("fail to close device: port={}", port_id));
+}
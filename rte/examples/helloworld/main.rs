@@ -1,11 +1,10 @@
 extern crate rte;
 
 use std::env;
-use std::os::raw::c_void;
 
 use rte::*;
 
-fn lcore_hello(_: Option<c_void>) -> i32 {
+fn lcore_hello() -> i32 {
     println!("hello from core {}", lcore::current().unwrap());
 
     0
@@ -16,13 +15,13 @@ fn main() {
 
     eal::init(&args).expect("Cannot init EAL");
 
-    // call lcore_hello() on every slave lcore
-    lcore::foreach_slave(|lcore_id| {
-        launch::remote_launch(lcore_hello, None, lcore_id).expect("Cannot launch task");
+    // call lcore_hello() on every worker lcore
+    lcore::foreach_worker(|lcore_id| {
+        launch::remote_launch(lcore_hello, lcore_id).expect("Cannot launch task");
     });
 
-    // call it on master lcore too
-    lcore_hello(None);
+    // call it on main lcore too
+    lcore_hello();
 
     launch::mp_wait_lcore();
 }
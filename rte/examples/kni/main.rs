@@ -13,18 +13,20 @@ use std::env;
 use std::fmt;
 use std::io;
 use std::io::prelude::*;
-use std::mem;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
 use std::path::Path;
 use std::process;
 use std::ptr;
 use std::result;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
 use anyhow::Result;
 use nix::sys::signal;
 
 use rte::ethdev::EthDevice;
-use rte::ffi::{RTE_ETHER_MAX_LEN, RTE_MAX_ETHPORTS, RTE_PKTMBUF_HEADROOM};
+use rte::ffi::{RTE_MAX_ETHPORTS, RTE_PKTMBUF_HEADROOM};
 use rte::lcore::RTE_MAX_LCORE;
 use rte::*;
 
@@ -40,10 +42,10 @@ const MBUF_DATA_SZ: u32 = MAX_PACKET_SZ + RTE_PKTMBUF_HEADROOM;
 const NB_MBUF: u32 = 8192;
 
 // How many packets to attempt to read from NIC in one go
-const PKT_BURST_SZ: u32 = 32;
+const PKT_BURST_SZ: usize = 32;
 
 // How many objects (mbufs) to keep in per-lcore mempool cache
-const MEMPOOL_CACHE_SZ: u32 = PKT_BURST_SZ;
+const MEMPOOL_CACHE_SZ: u32 = PKT_BURST_SZ as u32;
 
 // Number of RX ring descriptors
 const NB_RXD: u16 = 128;
@@ -51,15 +53,8 @@ const NB_RXD: u16 = 128;
 // Number of TX ring descriptors
 const NB_TXD: u16 = 512;
 
-// Total octets in ethernet header
-const KNI_ENET_HEADER_SIZE: u32 = 14;
-
-// Total octets in the FCS
-const KNI_ENET_FCS_SIZE: u32 = 4;
-
 const KNI_MAX_KTHREAD: usize = 32;
 
-#[repr(C)]
 #[derive(Clone, Debug)]
 struct kni_port_params {
     // Port ID
@@ -87,6 +82,44 @@ struct Conf {
     port_params: [Option<kni_port_params>; RTE_MAX_ETHPORTS as usize],
 }
 
+// kni device statistics array
+static KNI_STATS: [kni::InterfaceStats; RTE_MAX_ETHPORTS as usize] = [KNI_STATS_INIT; RTE_MAX_ETHPORTS as usize];
+
+const KNI_STATS_INIT: kni::InterfaceStats = kni::InterfaceStats::new();
+
+static mut KNI_PORT_PARAMS: *const [Option<kni_port_params>; RTE_MAX_ETHPORTS as usize] = ptr::null();
+
+static KNI_STOP: AtomicBool = AtomicBool::new(false);
+
+// Print out statistics on packets handled
+fn kni_print_stats(port_params: &[Option<kni_port_params>]) {
+    println!(
+        "\n**KNI example application statistics**\n\
+         ======  ==============  ============  ============  ============  ============\n \
+         Port    Lcore(RX/TX)    rx_packets    rx_dropped    tx_packets    tx_dropped\n\
+         ------  --------------  ------------  ------------  ------------  ------------"
+    );
+
+    for (portid, param) in port_params.iter().enumerate() {
+        if let Some(param) = param {
+            let stats = KNI_STATS[portid].snapshot();
+
+            println!(
+                "{:7} {:10}/{:2} {:13} {:13} {:13} {:13}",
+                portid,
+                param.lcore_rx,
+                param.lcore_tx,
+                stats.rx_packets,
+                stats.rx_dropped,
+                stats.tx_packets,
+                stats.tx_dropped
+            );
+        }
+    }
+
+    println!("======  ==============  ============  ============  ============  ============");
+}
+
 impl fmt::Debug for Conf {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for conf in self.port_params.iter().flatten() {
@@ -159,22 +192,22 @@ impl Conf {
 extern "C" fn handle_sigint(sig: libc::c_int) {
     match signal::Signal::try_from(sig).unwrap() {
         // When we receive a USR1 signal, print stats
-        signal::SIGUSR1 => unsafe {
-            kni_print_stats();
-        },
+        signal::SIGUSR1 => {
+            if let Some(port_params) = unsafe { KNI_PORT_PARAMS.as_ref() } {
+                kni_print_stats(port_params);
+            }
+        }
         // When we receive a USR2 signal, reset stats
         signal::SIGUSR2 => {
-            unsafe {
-                kni_stats = mem::zeroed();
+            for stats in &KNI_STATS[..] {
+                stats.reset();
             }
 
             println!("**Statistics have been reset**");
         }
         // When we receive a TERM or SIGINT signal, stop kni processing
         signal::SIGINT | signal::SIGTERM => {
-            unsafe {
-                kni_stop = 1;
-            }
+            KNI_STOP.store(true, Ordering::Relaxed);
 
             println!("SIGINT or SIGTERM is received, and the KNI processing is going to stop\n");
         }
@@ -286,8 +319,16 @@ fn init_kni(conf: &Conf) -> Result<()> {
     kni::init(num_of_kni_ports as usize)
 }
 
+// The queues of a port, which are taken by the RX and TX lcores of the port
+type PortQueues<'a> = Mutex<(Option<ethdev::RxQueue<'a>>, Option<ethdev::TxQueue>)>;
+
 // Initialise a single port on an Ethernet device
-fn init_port(conf: &Conf, dev: ethdev::PortId, port_conf: &ethdev::EthConf, pktmbuf_pool: &mut mempool::MemoryPool) {
+fn init_port<'a>(
+    conf: &Conf,
+    dev: ethdev::PortId,
+    port_conf: &ethdev::EthConf,
+    pktmbuf_pool: &'a mempool::MemoryPool,
+) -> (ethdev::RxQueue<'a>, ethdev::TxQueue) {
     let portid = dev.portid();
 
     // Initialise device and RX/TX queues
@@ -297,11 +338,13 @@ fn init_port(conf: &Conf, dev: ethdev::PortId, port_conf: &ethdev::EthConf, pktm
         .expect(&format!("fail to configure device: port={}", portid));
 
     // init one RX queue
-    dev.rx_queue_setup(0, NB_RXD, None, pktmbuf_pool)
+    let rxq = dev
+        .rx_queue_setup(0, NB_RXD, None, pktmbuf_pool)
         .expect(&format!("fail to setup device rx queue: port={}", portid));
 
     // init one TX queue on each port
-    dev.tx_queue_setup(0, NB_TXD, None)
+    let txq = dev
+        .tx_queue_setup(0, NB_TXD, None)
         .expect(&format!("fail to setup device tx queue: port={}", portid));
 
     // Start device
@@ -312,9 +355,15 @@ fn init_port(conf: &Conf, dev: ethdev::PortId, port_conf: &ethdev::EthConf, pktm
     if conf.promiscuous_on {
         dev.promiscuous_enable();
     }
+
+    (rxq, txq)
 }
 
 extern "C" fn kni_change_mtu(port_id: u16, new_mtu: libc::c_uint) -> libc::c_int {
+    utils::catch_panic("kni_change_mtu", -libc::EFAULT, || change_mtu(port_id, new_mtu))
+}
+
+fn change_mtu(port_id: u16, new_mtu: libc::c_uint) -> libc::c_int {
     debug!("port {} change MTU to {}", port_id, new_mtu);
 
     let nb_sys_ports = ethdev::count();
@@ -325,34 +374,12 @@ extern "C" fn kni_change_mtu(port_id: u16, new_mtu: libc::c_uint) -> libc::c_int
         return -libc::EINVAL;
     }
 
-    if new_mtu > RTE_ETHER_MAX_LEN {
-        let dev = port_id as ethdev::PortId;
-
-        dev.stop();
-
-        // Set new MTU
-        let mut port_conf = ethdev::EthConf::default();
-
-        let mut rxmode: ethdev::EthRxMode = Default::default();
-
-        rxmode.max_rx_pkt_len = new_mtu + KNI_ENET_HEADER_SIZE + KNI_ENET_FCS_SIZE;
-
-        port_conf.rxmode = Some(rxmode);
-
-        if let Err(err) = dev.configure(1, 1, &port_conf) {
-            error!("Fail to reconfigure port {}, {}", port_id, err);
-
-            if let Some(&RteError(errno)) = err.downcast_ref::<RteError>() {
-                return errno;
-            }
-        }
-
-        if let Err(err) = dev.start() {
-            error!("Failed to start port {}, {}", port_id, err);
+    // the queues are polled by the lcores, so the port can't be reconfigured with the new MTU
+    if let Err(err) = ethdev::PortId(port_id).set_mtu(new_mtu as u16) {
+        error!("Fail to set MTU of port {}, {}", port_id, err);
 
-            if let Some(&RteError(errno)) = err.downcast_ref::<RteError>() {
-                return errno;
-            }
+        if let Some(&RteError(errno)) = err.downcast_ref::<RteError>() {
+            return errno;
         }
     }
 
@@ -360,6 +387,12 @@ extern "C" fn kni_change_mtu(port_id: u16, new_mtu: libc::c_uint) -> libc::c_int
 }
 
 extern "C" fn kni_config_network_interface(port_id: u16, if_up: u8) -> libc::c_int {
+    utils::catch_panic("kni_config_network_interface", -libc::EFAULT, || {
+        config_network_interface(port_id, if_up)
+    })
+}
+
+fn config_network_interface(port_id: u16, if_up: u8) -> libc::c_int {
     debug!(
         "port {} change status to {}",
         port_id,
@@ -374,7 +407,7 @@ extern "C" fn kni_config_network_interface(port_id: u16, if_up: u8) -> libc::c_i
         return -libc::EINVAL;
     }
 
-    let dev = port_id as ethdev::PortId;
+    let dev = ethdev::PortId(port_id);
 
     dev.stop();
 
@@ -392,6 +425,12 @@ extern "C" fn kni_config_network_interface(port_id: u16, if_up: u8) -> libc::c_i
 }
 
 extern "C" fn kni_config_mac_address(port_id: u16, mac_addr: *mut u8) -> libc::c_int {
+    utils::catch_panic("kni_config_mac_address", -libc::EFAULT, || {
+        config_mac_address(port_id, mac_addr)
+    })
+}
+
+fn config_mac_address(port_id: u16, mac_addr: *mut u8) -> libc::c_int {
     debug!(
         "port {} change mac address to {}",
         port_id,
@@ -402,6 +441,12 @@ extern "C" fn kni_config_mac_address(port_id: u16, mac_addr: *mut u8) -> libc::c
 }
 
 extern "C" fn kni_config_promiscusity(port_id: u16, on: u8) -> libc::c_int {
+    utils::catch_panic("kni_config_promiscusity", -libc::EFAULT, || {
+        config_promiscusity(port_id, on)
+    })
+}
+
+fn config_promiscusity(port_id: u16, on: u8) -> libc::c_int {
     debug!(
         "port {} change promiscusity to {}",
         port_id,
@@ -412,6 +457,12 @@ extern "C" fn kni_config_promiscusity(port_id: u16, on: u8) -> libc::c_int {
 }
 
 extern "C" fn kni_config_allmulticast(port_id: u16, on: u8) -> libc::c_int {
+    utils::catch_panic("kni_config_allmulticast", -libc::EFAULT, || {
+        config_allmulticast(port_id, on)
+    })
+}
+
+fn config_allmulticast(port_id: u16, on: u8) -> libc::c_int {
     debug!(
         "port {} change allmulticast to {}",
         port_id,
@@ -423,7 +474,7 @@ extern "C" fn kni_config_allmulticast(port_id: u16, on: u8) -> libc::c_int {
 fn kni_alloc(conf: &mut Conf, dev: ethdev::PortId, pktmbuf_pool: &mut mempool::MemoryPool) {
     let portid = dev.portid();
 
-    if let Some(ref mut param) = conf.port_params[portid as usize] {
+    if let Some(ref mut param) = conf.port_params[usize::from(portid)] {
         param.nb_kni = cmp::max(param.nb_lcore_k, 1);
 
         for i in 0..param.nb_kni {
@@ -436,7 +487,7 @@ fn kni_alloc(conf: &mut Conf, dev: ethdev::PortId, pktmbuf_pool: &mut mempool::M
             let mut conf = kni::KniDeviceConf::default();
 
             conf.name = name.as_str();
-            conf.group_id = portid as u16;
+            conf.group_id = portid.0;
             conf.mbuf_size = MAX_PACKET_SZ;
 
             let mut kni = (if i == 0 {
@@ -451,7 +502,7 @@ fn kni_alloc(conf: &mut Conf, dev: ethdev::PortId, pktmbuf_pool: &mut mempool::M
                 // conf.pci_id = dev.id;
 
                 let ops = kni::KniDeviceOps {
-                    port_id: portid,
+                    port_id: portid.0,
                     change_mtu: Some(kni_change_mtu),
                     config_network_if: Some(kni_config_network_interface),
                     config_mac_address: Some(kni_config_mac_address),
@@ -476,7 +527,7 @@ fn kni_alloc(conf: &mut Conf, dev: ethdev::PortId, pktmbuf_pool: &mut mempool::M
 }
 
 fn kni_free_kni(conf: &Conf, dev: ethdev::PortId) {
-    if let Some(ref param) = conf.port_params[dev as usize] {
+    if let Some(ref param) = conf.port_params[usize::from(dev)] {
         for kni in &param.kni[..param.nb_kni as usize] {
             let _ = kni::KniDevice::from_raw(*kni);
         }
@@ -493,7 +544,7 @@ fn check_all_ports_link_status(enabled_devices: &Vec<ethdev::PortId>) {
     const MAX_CHECK_TIME: usize = 90;
 
     for _ in 0..MAX_CHECK_TIME {
-        if unsafe { kni_stop != 0 } {
+        if KNI_STOP.load(Ordering::Relaxed) {
             break;
         }
 
@@ -526,37 +577,64 @@ fn check_all_ports_link_status(enabled_devices: &Vec<ethdev::PortId>) {
     }
 }
 
-#[repr(C)]
-struct Struct_kni_interface_stats {
-    // number of pkts received from NIC, and sent to KNI
-    rx_packets: libc::uint64_t,
+// the KNI devices are owned by the configuration, and released by `kni_free_kni`
+fn kni_devices(param: &kni_port_params) -> Vec<ManuallyDrop<kni::KniDevice>> {
+    param.kni[..param.nb_kni as usize]
+        .iter()
+        .map(|&kni| ManuallyDrop::new(kni::KniDevice::from_raw(kni)))
+        .collect()
+}
 
-    // number of pkts received from NIC, but failed to send to KNI
-    rx_dropped: libc::uint64_t,
+// Interface to burst rx and enqueue mbufs into rx_q
+fn kni_ingress(param: &kni_port_params, mut rxq: ethdev::RxQueue) -> i32 {
+    let port_id = rxq.port_id();
+    let stats = &KNI_STATS[usize::from(port_id)];
+    let knis = kni_devices(param);
+    let mut pkts_burst: [MaybeUninit<mbuf::RawMBufPtr>; PKT_BURST_SZ] = unsafe { MaybeUninit::uninit().assume_init() };
 
-    // number of pkts received from KNI, and sent to NIC
-    tx_packets: libc::uint64_t,
+    while !KNI_STOP.load(Ordering::Relaxed) {
+        for kni in &knis {
+            // Burst rx from eth
+            let pkts = rxq.rx_burst(&mut pkts_burst);
 
-    // number of pkts received from KNI, but failed to send to NIC
-    tx_dropped: libc::uint64_t,
-}
+            // Burst tx to kni
+            let num = kni.tx_burst(pkts);
 
-#[link(name = "kni_core")]
-extern "C" {
-    static mut kni_stop: libc::c_int;
+            stats.add_rx(num, pkts.len() - num);
 
-    static mut kni_port_params_array: *const *mut kni_port_params;
+            let _ = kni.handle_requests();
 
-    static mut kni_stats: [Struct_kni_interface_stats; RTE_MAX_ETHPORTS as usize];
+            // Free mbufs not tx to kni interface
+            mbuf::free_burst(&mut pkts[num..]);
+        }
+    }
 
-    fn kni_print_stats();
+    0
+}
 
-    fn kni_ingress(param: *const kni_port_params) -> libc::c_int;
+// Interface to dequeue mbufs from tx_q and burst tx
+fn kni_egress(param: &kni_port_params, mut txq: ethdev::TxQueue) -> i32 {
+    let port_id = txq.port_id();
+    let stats = &KNI_STATS[usize::from(port_id)];
+    let knis = kni_devices(param);
+    let mut pkts_burst = [ptr::null_mut(); PKT_BURST_SZ];
 
-    fn kni_egress(param: *const kni_port_params) -> libc::c_int;
+    while !KNI_STOP.load(Ordering::Relaxed) {
+        for kni in &knis {
+            // Burst rx from kni
+            let num = kni.rx_burst(&mut pkts_burst);
+
+            // Burst tx to eth
+            let nb_tx = txq.tx_burst_and_free_rest(&mut pkts_burst[..num]);
+
+            stats.add_tx(nb_tx, num - nb_tx);
+        }
+    }
+
+    0
 }
 
-fn main_loop(conf: Option<&Conf>) -> i32 {
+fn main_loop(conf: &Conf, queues: &[PortQueues]) -> i32 {
     enum LcoreType<'a> {
         Rx(&'a kni_port_params),
         Tx(&'a kni_port_params),
@@ -566,7 +644,7 @@ fn main_loop(conf: Option<&Conf>) -> i32 {
     let mut lcore_type: Option<LcoreType> = None;
 
     for portid in ethdev::devices() {
-        if let Some(ref param) = conf.unwrap().port_params[portid as usize] {
+        if let Some(ref param) = conf.port_params[usize::from(portid)] {
             if lcore_id == param.lcore_rx {
                 lcore_type = Some(LcoreType::Rx(param));
                 break;
@@ -583,12 +661,16 @@ fn main_loop(conf: Option<&Conf>) -> i32 {
         Some(LcoreType::Rx(param)) => {
             info!("Lcore {} is reading from port {}", param.lcore_rx, param.port_id);
 
-            unsafe { kni_ingress(param) }
+            let rxq = queues[param.port_id as usize].lock().unwrap().0.take().unwrap();
+
+            kni_ingress(param, rxq)
         }
         Some(LcoreType::Tx(param)) => {
             info!("Lcore {} is writing from port {}", param.lcore_tx, param.port_id);
 
-            unsafe { kni_egress(param) }
+            let txq = queues[param.port_id as usize].lock().unwrap().1.take().unwrap();
+
+            kni_egress(param, txq)
         }
         _ => {
             info!("Lcore {} has nothing to do", lcore_id);
@@ -615,12 +697,8 @@ fn main() {
     // Parse application arguments (after the EAL ones)
     let mut conf = parse_args(&opt_args).expect("Could not parse input parameters");
 
-    unsafe {
-        kni_port_params_array = conf.port_params.as_ptr() as *const _;
-    }
-
     // create the mbuf pool
-    let mut pktmbuf_pool = mbuf::pool_create(
+    let pktmbuf_pool = mbuf::pool_create(
         "mbuf_pool",
         NB_MBUF,
         MEMPOOL_CACHE_SZ,
@@ -631,7 +709,7 @@ fn main() {
     .expect("fail to initial mbuf pool");
 
     let enabled_devices: Vec<ethdev::PortId> = ethdev::devices()
-        .filter(|dev| ((1 << dev.portid()) & conf.enabled_port_mask) != 0)
+        .filter(|dev| ((1 << dev.portid().0) & conf.enabled_port_mask) != 0)
         .collect();
 
     if enabled_devices.is_empty() {
@@ -643,20 +721,34 @@ fn main() {
 
     // Initialise each port
     let port_conf = ethdev::EthConf::default();
+    let queues: Vec<PortQueues> = (0..RTE_MAX_ETHPORTS).map(|_| Mutex::new((None, None))).collect();
+    // another handle of the mbuf pool for the KNI devices, since the pool is borrowed by the RX queues
+    let mut kni_pktmbuf_pool = mempool::MemoryPool::lookup("mbuf_pool").expect("fail to lookup mbuf pool");
 
     for dev in &enabled_devices {
-        init_port(&conf, dev.portid(), &port_conf, &mut pktmbuf_pool);
+        let (rxq, txq) = init_port(&conf, dev.portid(), &port_conf, &pktmbuf_pool);
+
+        *queues[usize::from(dev.portid())].lock().unwrap() = (Some(rxq), Some(txq));
 
-        kni_alloc(&mut conf, dev.portid(), &mut pktmbuf_pool);
+        kni_alloc(&mut conf, dev.portid(), &mut kni_pktmbuf_pool);
     }
 
     check_all_ports_link_status(&enabled_devices);
 
+    unsafe {
+        KNI_PORT_PARAMS = &conf.port_params;
+    }
+
     // launch per-lcore init on every lcore
-    launch::mp_remote_launch(main_loop, Some(&conf), false).unwrap();
+    // all the lcores are waited before the configuration is dropped
+    unsafe { launch::mp_remote_launch_unchecked(|| main_loop(&conf, &queues), false) }.unwrap();
 
     launch::mp_wait_lcore();
 
+    unsafe {
+        KNI_PORT_PARAMS = ptr::null();
+    }
+
     // Release resources
     for dev in &enabled_devices {
         kni_free_kni(&conf, dev.portid());
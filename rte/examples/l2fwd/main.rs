@@ -1,13 +1,10 @@
 #[macro_use]
 extern crate log;
 extern crate getopts;
-extern crate libc;
-extern crate nix;
 extern crate pretty_env_logger;
 extern crate rte;
 
 use std::clone::Clone;
-use std::convert::TryFrom;
 use std::env;
 use std::io;
 use std::io::prelude::*;
@@ -15,13 +12,14 @@ use std::mem;
 use std::path::Path;
 use std::process;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use nix::sys::signal;
-
-use rte::ethdev::{EthDevice, EthDeviceInfo, TxBuffer};
+use rte::ethdev::{BufferedTxQueue, EthDevice, EthDeviceInfo, PortCounters, PortStats};
+use rte::ether::{EtherAddr, EtherHdr, EtherHdrExt};
 use rte::ffi::RTE_MAX_ETHPORTS;
 use rte::lcore::RTE_MAX_LCORE;
-use rte::memory::AsMutRef;
+use rte::utils::IntoRaw;
 use rte::*;
 
 const EXIT_FAILURE: i32 = -1;
@@ -30,8 +28,11 @@ const MAX_PKT_BURST: usize = 32;
 
 const MAX_RX_QUEUE_PER_LCORE: u32 = 16;
 
+// TX drain every ~100us
+const BURST_TX_DRAIN: Duration = Duration::from_micros(100);
+
 // A tsc-based timer responsible for triggering statistics printout
-const TIMER_MILLISECOND: i64 = 2000000; /* around 1ms at 2 Ghz */
+const TIMER_MILLISECOND: u64 = 2000000; /* around 1ms at 2 Ghz */
 const MAX_TIMER_PERIOD: u32 = 86400; /* 1 day max */
 
 const NB_MBUF: u32 = 2048;
@@ -51,16 +52,38 @@ struct Conf {
     nb_txd: u16,
 
     queue_conf: [LcoreQueueConf; RTE_MAX_LCORE as usize],
+
+    // mask of enabled ports
+    enabled_port_mask: u32,
+
+    // ethernet addresses of ports
+    ports_eth_addr: [EtherAddr; RTE_MAX_ETHPORTS as usize],
+
+    // list of enabled ports
+    dst_ports: [ethdev::PortId; RTE_MAX_ETHPORTS as usize],
+
+    // per-port statistics
+    port_counters: Vec<Arc<PortCounters>>,
+
+    // statistics printout period in TSC cycles, 0 to disable
+    timer_period: u64,
 }
 
+// The queues of a port, which are taken by the lcores which receive from or send to the port
+type PortQueues<'a> = Mutex<(Option<ethdev::RxQueue<'a>>, Option<ethdev::TxQueue>)>;
+
 impl Default for Conf {
     fn default() -> Self {
-        let mut conf: Self = unsafe { mem::zeroed() };
-
-        conf.nb_rxd = RTE_TEST_RX_DESC_DEFAULT;
-        conf.nb_txd = RTE_TEST_TX_DESC_DEFAULT;
-
-        return conf;
+        Conf {
+            nb_rxd: RTE_TEST_RX_DESC_DEFAULT,
+            nb_txd: RTE_TEST_TX_DESC_DEFAULT,
+            queue_conf: unsafe { mem::zeroed() },
+            enabled_port_mask: 0,
+            ports_eth_addr: Default::default(),
+            dst_ports: [ethdev::PortId(0); RTE_MAX_ETHPORTS as usize],
+            port_counters: (0..RTE_MAX_ETHPORTS).map(|_| Arc::default()).collect(),
+            timer_period: 0,
+        }
     }
 }
 
@@ -150,7 +173,7 @@ fn check_all_ports_link_status(enabled_devices: &Vec<ethdev::PortId>) {
     const MAX_CHECK_TIME: usize = 90;
 
     for _ in 0..MAX_CHECK_TIME {
-        if unsafe { l2fwd_force_quit != 0 } {
+        if eal::should_stop() {
             break;
         }
 
@@ -183,65 +206,141 @@ fn check_all_ports_link_status(enabled_devices: &Vec<ethdev::PortId>) {
     }
 }
 
-#[link(name = "l2fwd_core")]
-extern "C" {
-    static mut l2fwd_force_quit: libc::c_int;
+// Print out statistics on packets dropped
+fn print_stats(conf: &Conf) {
+    let mut total = PortStats::default();
 
-    static mut l2fwd_enabled_port_mask: libc::uint32_t;
+    // Clear screen and move to top left
+    print!("\x1b[2J\x1b[1;1H");
 
-    static mut l2fwd_ports_eth_addr: [[libc::uint8_t; 6usize]; RTE_MAX_ETHPORTS as usize];
+    print!("\nPort statistics ====================================");
 
-    static mut l2fwd_dst_ports: [libc::uint32_t; RTE_MAX_ETHPORTS as usize];
+    for portid in 0..RTE_MAX_ETHPORTS as usize {
+        // skip disabled ports
+        if (conf.enabled_port_mask & (1 << portid)) == 0 {
+            continue;
+        }
 
-    static mut l2fwd_tx_buffers: [*mut rte::ffi::rte_eth_dev_tx_buffer; RTE_MAX_ETHPORTS as usize];
+        let stats = conf.port_counters[portid].stats();
 
-    static mut l2fwd_timer_period: libc::int64_t;
+        print!(
+            "\nStatistics for port {} ------------------------------\
+             \nPackets sent: {:>24}\
+             \nPackets received: {:>20}\
+             \nPackets dropped: {:>21}",
+            portid, stats.tx, stats.rx, stats.dropped
+        );
+
+        total += stats;
+    }
 
-    fn l2fwd_main_loop(rx_port_list: *const libc::uint32_t, n_rx_port: libc::c_uint) -> libc::c_int;
+    print!(
+        "\nAggregate statistics ===============================\
+         \nTotal packets sent: {:>18}\
+         \nTotal packets received: {:>14}\
+         \nTotal packets dropped: {:>15}",
+        total.tx, total.rx, total.dropped
+    );
+    println!("\n====================================================");
 }
 
-fn l2fwd_launch_one_lcore(conf: Option<&Conf>) -> i32 {
-    let lcore_id = lcore::current().unwrap();
-    let qconf = &conf.unwrap().queue_conf[*lcore_id as usize];
+fn l2fwd_simple_forward(conf: &Conf, pkt: mbuf::MBuf, txq: &mut BufferedTxQueue) {
+    let dst_port = txq.port_id();
 
-    if qconf.n_rx_port == 0 {
-        info!("lcore {} has nothing to do", lcore_id);
+    pkt.prefetch_data();
 
-        return -1;
+    let eth = unsafe { pkt.mtod::<EtherHdr>().as_mut() };
+
+    // 02:00:00:00:00:xx
+    eth.rewrite_addrs(
+        &EtherAddr::new(0x02, 0, 0, 0, 0, dst_port.0 as u8),
+        &conf.ports_eth_addr[usize::from(dst_port)],
+    );
+
+    // the packet is owned by the TX buffer once it has been sent
+    txq.send(pkt.into_raw());
+}
+
+// main processing loop
+fn l2fwd_main_loop(conf: &Conf, queues: &[PortQueues], rx_port_list: &[u32]) -> i32 {
+    let is_main = lcore::current().map_or(false, |id| id.is_main());
+
+    let mut rxqs = Vec::with_capacity(rx_port_list.len());
+    let mut txqs = Vec::with_capacity(rx_port_list.len());
+
+    for &portid in rx_port_list {
+        let dst_port = conf.dst_ports[portid as usize];
+
+        let counters = conf.port_counters[usize::from(dst_port)].clone();
+
+        let rxq = queues[portid as usize].lock().unwrap().0.take().unwrap();
+        let txq = queues[usize::from(dst_port)].lock().unwrap().1.take().unwrap();
+
+        rxq.check_socket();
+
+        rxqs.push(rxq);
+        txqs.push(
+            BufferedTxQueue::new(txq, MAX_PKT_BURST, counters)
+                .expect(&format!("fail to allocate buffer for tx: port={}", dst_port)),
+        );
     }
 
-    info!("entering main loop on lcore {}", lcore_id);
+    let mut drain = TscInterval::new(BURST_TX_DRAIN);
+    let mut timer_tsc = 0;
 
-    for portid in &qconf.rx_port_list[..qconf.n_rx_port as usize] {
-        info!(" -- lcoreid={} portid={}", lcore_id, portid);
+    while !eal::should_stop() {
+        // TX burst queue drain
+        if let Some(diff_tsc) = drain.tick(rdtsc()) {
+            for txq in &mut txqs {
+                txq.flush();
+            }
+
+            // if timer is enabled
+            if conf.timer_period > 0 {
+                // advance the timer
+                timer_tsc += diff_tsc;
+
+                // if timer has reached its timeout, do this only on main core
+                if timer_tsc >= conf.timer_period && is_main {
+                    print_stats(conf);
+
+                    // reset the timer
+                    timer_tsc = 0;
+                }
+            }
+        }
+
+        // Read packet from RX queues
+        for (rxq, txq) in rxqs.iter_mut().zip(txqs.iter_mut()) {
+            let pkts = rxq.rx_burst_owned::<MAX_PKT_BURST>();
+
+            conf.port_counters[usize::from(rxq.port_id())].add_rx(pkts.len());
+
+            for m in pkts {
+                l2fwd_simple_forward(conf, m, txq);
+            }
+        }
     }
 
-    unsafe { l2fwd_main_loop(qconf.rx_port_list.as_ptr(), qconf.n_rx_port) }
+    0
 }
 
-extern "C" fn handle_sigint(sig: libc::c_int) {
-    match signal::Signal::try_from(sig).unwrap() {
-        signal::SIGINT | signal::SIGTERM => unsafe {
-            println!("Signal {} received, preparing to exit...", sig);
+fn l2fwd_launch_one_lcore(conf: &Conf, queues: &[PortQueues], lcore_id: lcore::Id) -> i32 {
+    let qconf = &conf.queue_conf[*lcore_id as usize];
 
-            l2fwd_force_quit = 1;
-        },
-        _ => info!("unexpect signo: {}", sig),
+    if qconf.n_rx_port == 0 {
+        info!("lcore {} has nothing to do", lcore_id);
+
+        return -1;
     }
-}
 
-fn handle_signals() -> nix::Result<()> {
-    let sig_action = signal::SigAction::new(
-        signal::SigHandler::Handler(handle_sigint),
-        signal::SaFlags::empty(),
-        signal::SigSet::empty(),
-    );
-    unsafe {
-        try!(signal::sigaction(signal::SIGINT, &sig_action));
-        try!(signal::sigaction(signal::SIGTERM, &sig_action));
+    info!("entering main loop on lcore {}", lcore_id);
+
+    for portid in &qconf.rx_port_list[..qconf.n_rx_port as usize] {
+        info!(" -- lcoreid={} portid={}", lcore_id, portid);
     }
 
-    Ok(())
+    l2fwd_main_loop(conf, queues, &qconf.rx_port_list[..qconf.n_rx_port as usize])
 }
 
 fn prepare_args(args: &mut Vec<String>) -> (Vec<String>, Vec<String>) {
@@ -261,7 +360,7 @@ fn prepare_args(args: &mut Vec<String>) -> (Vec<String>, Vec<String>) {
 fn main() {
     pretty_env_logger::init();
 
-    handle_signals().expect("fail to handle signals");
+    eal::handle_stop_signals().expect("fail to handle signals");
 
     let mut args: Vec<String> = env::args().collect();
 
@@ -271,16 +370,16 @@ fn main() {
 
     let (enabled_port_mask, rx_queue_per_lcore, timer_period_seconds) = parse_args(&opt_args);
 
-    unsafe {
-        l2fwd_enabled_port_mask = enabled_port_mask;
-        l2fwd_timer_period = timer_period_seconds as i64 * TIMER_MILLISECOND * 1000;
-    }
+    let mut conf = Conf::default();
+
+    conf.enabled_port_mask = enabled_port_mask;
+    conf.timer_period = timer_period_seconds as u64 * TIMER_MILLISECOND * 1000;
 
     // init EAL
     eal::init(&eal_args).expect("fail to initial EAL");
 
     // create the mbuf pool
-    let mut l2fwd_pktmbuf_pool = mbuf::pool_create(
+    let l2fwd_pktmbuf_pool = mbuf::pool_create(
         "mbuf_pool",
         NB_MBUF,
         32,
@@ -291,14 +390,14 @@ fn main() {
     .unwrap();
 
     let enabled_devices: Vec<ethdev::PortId> = ethdev::devices()
-        .filter(|dev| ((1 << dev.portid()) & enabled_port_mask) != 0)
+        .filter(|dev| ((1 << dev.portid().0) & enabled_port_mask) != 0)
         .collect();
 
     if enabled_devices.is_empty() {
         eal::exit(EXIT_FAILURE, "All available ports are disabled. Please set portmask.\n");
     }
 
-    let mut last_port = 0;
+    let mut last_port = ethdev::PortId(0);
     let mut nb_ports_in_mask = 0;
 
     // Each logical core is assigned a dedicated TX queue on each port.
@@ -306,10 +405,8 @@ fn main() {
         let portid = dev.portid();
 
         if (nb_ports_in_mask % 2) != 0 {
-            unsafe {
-                l2fwd_dst_ports[portid as usize] = last_port as u32;
-                l2fwd_dst_ports[last_port as usize] = portid as u32;
-            }
+            conf.dst_ports[usize::from(portid)] = last_port;
+            conf.dst_ports[usize::from(last_port)] = portid;
         } else {
             last_port = portid;
         }
@@ -324,13 +421,9 @@ fn main() {
     if (nb_ports_in_mask % 2) != 0 {
         println!("Notice: odd number of ports in portmask.");
 
-        unsafe {
-            l2fwd_dst_ports[last_port as usize] = last_port as u32;
-        }
+        conf.dst_ports[usize::from(last_port)] = last_port;
     }
 
-    let mut conf = Conf::default();
-
     let mut rx_lcore_id = lcore::id(0);
 
     // Initialize the port/queue configuration of each logical core
@@ -350,17 +443,18 @@ fn main() {
         // Assigned a new logical core in the loop above.
         let qconf = &mut conf.queue_conf[*rx_lcore_id as usize];
 
-        qconf.rx_port_list[qconf.n_rx_port as usize] = portid as u32;
+        qconf.rx_port_list[qconf.n_rx_port as usize] = u32::from(portid.0);
         qconf.n_rx_port += 1;
 
         println!("Lcore {}: RX port {}", rx_lcore_id, portid);
     }
 
     let port_conf = ethdev::EthConf::default();
+    let queues: Vec<PortQueues> = (0..RTE_MAX_ETHPORTS).map(|_| Mutex::new((None, None))).collect();
 
     // Initialise each port
     for dev in &enabled_devices {
-        let portid = dev.portid() as usize;
+        let portid = usize::from(dev.portid());
 
         // init port
         print!("Initializing port {}... ", portid);
@@ -370,29 +464,19 @@ fn main() {
 
         let mac_addr = dev.mac_addr();
 
-        unsafe {
-            l2fwd_ports_eth_addr[portid] = *mac_addr.octets();
-        }
+        conf.ports_eth_addr[portid] = mac_addr;
 
         // init one RX queue
-        dev.rx_queue_setup(0, conf.nb_rxd, None, &mut l2fwd_pktmbuf_pool)
+        let rxq = dev
+            .rx_queue_setup(0, conf.nb_rxd, None, &l2fwd_pktmbuf_pool)
             .expect(&format!("fail to setup device rx queue: port={}", portid));
 
         // init one TX queue on each port
-        dev.tx_queue_setup(0, conf.nb_txd, None)
+        let txq = dev
+            .tx_queue_setup(0, conf.nb_txd, None)
             .expect(&format!("fail to setup device tx queue: port={}", portid));
 
-        // Initialize TX buffers
-        let buf = ethdev::alloc_buffer(MAX_PKT_BURST, dev.socket_id())
-            .as_mut_ref()
-            .expect(&format!("fail to allocate buffer for tx: port={}", portid));
-
-        buf.count_err_packets()
-            .expect(&format!("failt to set error callback for tx buffer: port={}", portid));
-
-        unsafe {
-            l2fwd_tx_buffers[portid] = buf;
-        }
+        *queues[portid].lock().unwrap() = (Some(rxq), Some(txq));
 
         // Start device
         dev.start().expect(&format!("fail to start device: port={}", portid));
@@ -413,21 +497,8 @@ fn main() {
 
     check_all_ports_link_status(&enabled_devices);
 
-    // launch per-lcore init on every lcore
-    launch::mp_remote_launch(l2fwd_launch_one_lcore, Some(&conf), false).unwrap();
-
-    launch::mp_wait_lcore();
-
-    for dev in &enabled_devices {
-        print!("Closing port {}...", dev.portid());
-        dev.stop();
-        dev.close();
-        println!(" Done");
-
-        if let Some(buf) = (unsafe { l2fwd_tx_buffers[dev.portid() as usize] }).as_mut_ref() {
-            buf.free();
-        }
-    }
+    // launch per-lcore init on every lcore, and close the ports after all the lcores exit
+    eal::run(|lcore_id| l2fwd_launch_one_lcore(&conf, &queues, lcore_id)).unwrap();
 
     println!("Bye...");
 }
@@ -0,0 +1,464 @@
+//!
+//! RTE ACL
+//!
+//! The ACL library classifies the input buffers against a set of rules,
+//! each rule matches several fields of the input and carries a priority and the user data.
+//!
+//! The fields of the input are described by the field definitions,
+//! which are used to build the run-time structures after the rules were added.
+//!
+use std::mem;
+use std::net::Ipv4Addr;
+use std::ptr::{self, NonNull};
+use std::slice;
+
+use anyhow::{anyhow, Result};
+use libc;
+
+use errors::{AsResult, ErrorKind::OsError};
+use ffi;
+use memory::SocketId;
+use utils::{AsCString, AsRaw};
+
+pub use ffi::{RTE_ACL_MAX_CATEGORIES, RTE_ACL_MAX_FIELDS, RTE_ACL_NAMESIZE, RTE_ACL_RESULTS_MULTIPLIER};
+
+/// The maximum priority of a rule.
+pub const RTE_ACL_MAX_PRIORITY: i32 = ffi::_bindgen_ty_16::RTE_ACL_MAX_PRIORITY as i32;
+/// The minimum priority of a rule.
+pub const RTE_ACL_MIN_PRIORITY: i32 = ffi::_bindgen_ty_16::RTE_ACL_MIN_PRIORITY as i32;
+
+pub type RawAclCtx = ffi::rte_acl_ctx;
+
+/// The type of a field.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    /// The value matches the prefix of the given length, e.g. an IP address with the prefix length.
+    Mask = ffi::_bindgen_ty_15::RTE_ACL_FIELD_TYPE_MASK as u8,
+    /// The value is in the inclusive range, e.g. a port range.
+    Range = ffi::_bindgen_ty_15::RTE_ACL_FIELD_TYPE_RANGE as u8,
+    /// The value matches under the bit mask, e.g. the protocol or the TCP flags.
+    Bitmask = ffi::_bindgen_ty_15::RTE_ACL_FIELD_TYPE_BITMASK as u8,
+}
+
+/// The definition of a field in the input buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldDef {
+    /// The type of the field.
+    pub ty: FieldType,
+    /// The size of the field, 1, 2, 4 or 8 bytes.
+    pub size: u8,
+    /// The index of the field in the rule.
+    pub field_index: u8,
+    /// The index of the 4 consecutive bytes group which the field belongs to.
+    pub input_index: u8,
+    /// The offset of the field in the input buffer.
+    pub offset: u32,
+}
+
+impl FieldDef {
+    pub fn new(ty: FieldType, size: u8, field_index: u8, input_index: u8, offset: u32) -> Self {
+        FieldDef {
+            ty,
+            size,
+            field_index,
+            input_index,
+            offset,
+        }
+    }
+}
+
+impl From<FieldDef> for ffi::rte_acl_field_def {
+    fn from(def: FieldDef) -> Self {
+        ffi::rte_acl_field_def {
+            type_: def.ty as u8,
+            size: def.size,
+            field_index: def.field_index,
+            input_index: def.input_index,
+            offset: def.offset,
+        }
+    }
+}
+
+/// The value of a field in a rule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Field {
+    /// The value of the field.
+    pub value: u64,
+    /// The prefix length, the upper bound of the range or the bit mask, depending on the field type.
+    pub mask_range: u64,
+}
+
+impl Field {
+    /// Match the value with the prefix of `len` bits.
+    pub fn mask(value: u64, len: u32) -> Self {
+        Field {
+            value,
+            mask_range: len.into(),
+        }
+    }
+
+    /// Match the values in the range `lo..=hi`.
+    pub fn range(lo: u64, hi: u64) -> Self {
+        Field {
+            value: lo,
+            mask_range: hi,
+        }
+    }
+
+    /// Match the value under the bit mask.
+    pub fn bitmask(value: u64, mask: u64) -> Self {
+        Field {
+            value,
+            mask_range: mask,
+        }
+    }
+
+    /// Match the IPv4 prefix.
+    pub fn ipv4(prefix: Ipv4Addr, len: u8) -> Self {
+        Self::mask(u32::from(prefix).into(), len.into())
+    }
+
+    fn to_raw(self) -> ffi::rte_acl_field {
+        ffi::rte_acl_field {
+            value: ffi::rte_acl_field_types { u64_: self.value },
+            mask_range: ffi::rte_acl_field_types { u64_: self.mask_range },
+        }
+    }
+}
+
+/// A rule of an ACL context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    /// The categories which the rule belongs to.
+    pub category_mask: u32,
+    /// The priority of the rule, the higher one wins when several rules match.
+    pub priority: i32,
+    /// The user data returned on match, which must not be zero.
+    pub userdata: u32,
+    /// The fields of the rule, in the order of the field index.
+    pub fields: Vec<Field>,
+}
+
+impl Rule {
+    /// Create a rule of the first category.
+    pub fn new(userdata: u32, priority: i32) -> Self {
+        Rule {
+            category_mask: 1,
+            priority,
+            userdata,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Set the categories which the rule belongs to.
+    pub fn categories(mut self, category_mask: u32) -> Self {
+        self.category_mask = category_mask;
+        self
+    }
+
+    /// Append a field to the rule.
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+}
+
+/// The raw rules with `num_fields` fields, the rule data takes the place of a field ahead of the fields.
+fn rules_to_raw(rules: &[Rule], num_fields: usize) -> Vec<ffi::rte_acl_field> {
+    let mut raw = vec![ffi::rte_acl_field::default(); rules.len() * (num_fields + 1)];
+
+    for (rule, chunk) in rules.iter().zip(raw.chunks_mut(num_fields + 1)) {
+        let data = ffi::rte_acl_rule_data {
+            category_mask: rule.category_mask,
+            priority: rule.priority,
+            userdata: rule.userdata,
+        };
+
+        unsafe { ptr::write(chunk.as_mut_ptr() as *mut ffi::rte_acl_rule_data, data) }
+
+        for (field, raw) in rule.fields.iter().zip(&mut chunk[1..]) {
+            *raw = field.to_raw();
+        }
+    }
+
+    raw
+}
+
+/// The configuration to build an ACL context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AclConfig {
+    /// The number of categories, which must be 1 or a multiple of `RTE_ACL_RESULTS_MULTIPLIER`.
+    pub num_categories: u32,
+    /// The definitions of the fields.
+    pub defs: Vec<FieldDef>,
+    /// The memory limit of the run-time structures, 0 for no limit.
+    pub max_size: usize,
+}
+
+impl Default for AclConfig {
+    fn default() -> Self {
+        AclConfig {
+            num_categories: 1,
+            defs: Vec::new(),
+            max_size: 0,
+        }
+    }
+}
+
+impl AclConfig {
+    /// Create a configuration of one category.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of categories.
+    pub fn categories(mut self, num_categories: u32) -> Self {
+        self.num_categories = num_categories;
+        self
+    }
+
+    /// Append a field definition.
+    pub fn field(mut self, def: FieldDef) -> Self {
+        self.defs.push(def);
+        self
+    }
+
+    /// Set the memory limit of the run-time structures.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// The minimum length of the input buffer which covers all the fields.
+    pub fn input_len(&self) -> usize {
+        self.defs
+            .iter()
+            .map(|def| def.offset as usize + def.size as usize)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn to_raw(&self) -> Result<ffi::rte_acl_config> {
+        if self.defs.len() > RTE_ACL_MAX_FIELDS as usize {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        let mut conf = ffi::rte_acl_config {
+            num_categories: self.num_categories,
+            num_fields: self.defs.len() as u32,
+            max_size: self.max_size,
+            ..Default::default()
+        };
+
+        for (def, raw) in self.defs.iter().zip(conf.defs.iter_mut()) {
+            *raw = (*def).into();
+        }
+
+        Ok(conf)
+    }
+}
+
+/// The available implementations of the classification.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassifyAlg {
+    Default = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_DEFAULT,
+    Scalar = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_SCALAR,
+    Sse = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_SSE,
+    Avx2 = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_AVX2,
+    Neon = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_NEON,
+    Altivec = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_ALTIVEC,
+    Avx512x16 = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_AVX512X16,
+    Avx512x32 = ffi::rte_acl_classify_alg::RTE_ACL_CLASSIFY_AVX512X32,
+}
+
+/// An ACL context.
+#[derive(Debug)]
+pub struct Acl {
+    raw: NonNull<RawAclCtx>,
+    num_fields: usize,
+    input_len: usize,
+}
+
+unsafe impl Send for Acl {}
+unsafe impl Sync for Acl {}
+
+impl Drop for Acl {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_acl_free(self.raw.as_ptr()) }
+    }
+}
+
+impl AsRaw for Acl {
+    type Raw = RawAclCtx;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.raw.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.raw.as_ptr()
+    }
+}
+
+impl Acl {
+    /// Create an ACL context named `name` for up to `max_rule_num` rules with `num_fields` fields.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, num_fields: usize, max_rule_num: u32) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let param = ffi::rte_acl_param {
+            name: name.as_ptr(),
+            socket_id,
+            rule_size: (mem::size_of::<ffi::rte_acl_rule>() + mem::size_of::<ffi::rte_acl_field>() * num_fields) as u32,
+            max_rule_num,
+        };
+
+        unsafe { ffi::rte_acl_create(&param) }.as_result().map(|raw| Acl {
+            raw,
+            num_fields,
+            input_len: 0,
+        })
+    }
+
+    /// Add the rules to the context, the context must be built again to use them.
+    pub fn add_rules(&mut self, rules: &[Rule]) -> Result<()> {
+        if rules.iter().any(|rule| rule.fields.len() > self.num_fields) {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        let raw = rules_to_raw(rules, self.num_fields);
+
+        rte_check!(unsafe {
+            ffi::rte_acl_add_rules(self.raw.as_ptr(), raw.as_ptr() as *const _, rules.len() as u32)
+        }; err => {
+            anyhow!("fail to add {} rules", rules.len())
+        })
+    }
+
+    /// Delete all the rules, the run-time structures are not affected.
+    pub fn reset_rules(&mut self) {
+        unsafe { ffi::rte_acl_reset_rules(self.raw.as_ptr()) }
+    }
+
+    /// Build the run-time structures from the rules.
+    pub fn build(&mut self, conf: &AclConfig) -> Result<()> {
+        let raw = conf.to_raw()?;
+
+        rte_check!(unsafe { ffi::rte_acl_build(self.raw.as_ptr(), &raw) }; ok => {
+            self.input_len = conf.input_len();
+        })
+    }
+
+    /// Delete all the rules and destroy the run-time structures.
+    pub fn reset(&mut self) {
+        unsafe { ffi::rte_acl_reset(self.raw.as_ptr()) }
+
+        self.input_len = 0;
+    }
+
+    /// Set the implementation of the classification.
+    pub fn set_classify_alg(&mut self, alg: ClassifyAlg) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_acl_set_ctx_classify(self.raw.as_ptr(), alg as u32) })
+    }
+
+    /// Classify the input buffer in the first category, return the user data of the matched rule.
+    #[inline]
+    pub fn classify(&self, data: &[u8]) -> Option<u32> {
+        let mut result = 0;
+
+        self.classify_burst(&[data], slice::from_mut(&mut result), 1).ok()?;
+
+        if result == 0 {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Classify the input buffers in `categories` categories.
+    ///
+    /// The results of each input buffer are stored in `categories` consecutive entries,
+    /// which are the user data of the matched rule, or 0 if no rule matched.
+    #[inline]
+    pub fn classify_burst(&self, data: &[&[u8]], results: &mut [u32], categories: u32) -> Result<()> {
+        if results.len() < data.len() * categories as usize || data.iter().any(|buf| buf.len() < self.input_len) {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        let mut ptrs = data.iter().map(|buf| buf.as_ptr()).collect::<Vec<_>>();
+
+        rte_check!(unsafe {
+            ffi::rte_acl_classify(
+                self.raw.as_ptr(),
+                ptrs.as_mut_ptr(),
+                results.as_mut_ptr(),
+                data.len() as u32,
+                categories,
+            )
+        })
+    }
+
+    /// Dump the context to the console.
+    pub fn dump(&self) {
+        unsafe { ffi::rte_acl_dump(self.raw.as_ptr()) }
+    }
+}
+
+/// Dump all the ACL contexts to the console.
+pub fn list_dump() {
+    unsafe { ffi::rte_acl_list_dump() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_to_raw() {
+        let rules = [
+            Rule::new(1, 1)
+                .field(Field::bitmask(6, 0xff))
+                .field(Field::ipv4(Ipv4Addr::new(192, 168, 0, 0), 16)),
+            Rule::new(2, 2).categories(3).field(Field::range(80, 443)),
+        ];
+
+        let raw = rules_to_raw(&rules, 3);
+
+        assert_eq!(raw.len(), 8);
+        assert_eq!(
+            mem::size_of::<ffi::rte_acl_field>() * 4,
+            mem::size_of::<ffi::rte_acl_rule>() + mem::size_of::<ffi::rte_acl_field>() * 3
+        );
+
+        let data = unsafe { &*(raw[4..].as_ptr() as *const ffi::rte_acl_rule_data) };
+
+        assert_eq!(data.category_mask, 3);
+        assert_eq!(data.priority, 2);
+        assert_eq!(data.userdata, 2);
+
+        unsafe {
+            assert_eq!(raw[1].value.u64_, 6);
+            assert_eq!(raw[2].value.u64_, 0xc0a8_0000);
+            assert_eq!(raw[2].mask_range.u64_, 16);
+            assert_eq!(raw[5].mask_range.u64_, 443);
+            assert_eq!(raw[7].value.u64_, 0);
+        }
+    }
+
+    #[test]
+    fn test_config() {
+        let conf = AclConfig::new()
+            .field(FieldDef::new(FieldType::Bitmask, 1, 0, 0, 9))
+            .field(FieldDef::new(FieldType::Mask, 4, 1, 1, 12))
+            .field(FieldDef::new(FieldType::Mask, 4, 2, 2, 16));
+
+        assert_eq!(conf.input_len(), 20);
+
+        let raw = conf.to_raw().unwrap();
+
+        assert_eq!(raw.num_categories, 1);
+        assert_eq!(raw.num_fields, 3);
+        assert_eq!(raw.defs[1].offset, 12);
+        assert_eq!(raw.defs[2].type_, FieldType::Mask as u8);
+    }
+}
@@ -1,5 +1,7 @@
 use ffi;
 
+use byteorder::{be16, be32};
+
 pub use ffi::{
     RTE_ARP_HRD_ETHER, RTE_ARP_OP_INVREPLY, RTE_ARP_OP_INVREQUEST, RTE_ARP_OP_REPLY, RTE_ARP_OP_REQUEST, RTE_ARP_OP_REVREPLY, RTE_ARP_OP_REVREQUEST,
 };
@@ -9,3 +11,78 @@ pub type ArpIpv4 = ffi::rte_arp_ipv4;
 
 /// ARP header.
 pub type ArpHdr = ffi::rte_arp_hdr;
+
+/// The fields of the ARP header in the network byte order.
+pub trait ArpHdrExt {
+    /// The format of the hardware address.
+    fn hardware(&self) -> be16;
+
+    /// Set the format of the hardware address, e.g. `RTE_ARP_HRD_ETHER`.
+    fn set_hardware(&mut self, hardware: be16);
+
+    /// The format of the protocol address.
+    fn protocol(&self) -> be16;
+
+    /// Set the format of the protocol address, e.g. `RTE_ETHER_TYPE_IPV4`.
+    fn set_protocol(&mut self, protocol: be16);
+
+    /// The ARP opcode, e.g. `RTE_ARP_OP_REQUEST`.
+    fn opcode(&self) -> be16;
+
+    /// Set the ARP opcode.
+    fn set_opcode(&mut self, opcode: be16);
+
+    /// The sender IP address.
+    fn sender_ip(&self) -> be32;
+
+    /// Set the sender IP address.
+    fn set_sender_ip(&mut self, addr: be32);
+
+    /// The target IP address.
+    fn target_ip(&self) -> be32;
+
+    /// Set the target IP address.
+    fn set_target_ip(&mut self, addr: be32);
+}
+
+impl ArpHdrExt for ArpHdr {
+    fn hardware(&self) -> be16 {
+        be16::from_raw(self.arp_hardware)
+    }
+
+    fn set_hardware(&mut self, hardware: be16) {
+        self.arp_hardware = hardware.raw()
+    }
+
+    fn protocol(&self) -> be16 {
+        be16::from_raw(self.arp_protocol)
+    }
+
+    fn set_protocol(&mut self, protocol: be16) {
+        self.arp_protocol = protocol.raw()
+    }
+
+    fn opcode(&self) -> be16 {
+        be16::from_raw(self.arp_opcode)
+    }
+
+    fn set_opcode(&mut self, opcode: be16) {
+        self.arp_opcode = opcode.raw()
+    }
+
+    fn sender_ip(&self) -> be32 {
+        be32::from_raw(self.arp_data.arp_sip)
+    }
+
+    fn set_sender_ip(&mut self, addr: be32) {
+        self.arp_data.arp_sip = addr.raw()
+    }
+
+    fn target_ip(&self) -> be32 {
+        be32::from_raw(self.arp_data.arp_tip)
+    }
+
+    fn set_target_ip(&mut self, addr: be32) {
+        self.arp_data.arp_tip = addr.raw()
+    }
+}
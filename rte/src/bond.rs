@@ -112,7 +112,7 @@ impl From<u8> for TransmitPolicy {
 pub fn create(name: &str, mode: BondMode, socket_id: SocketId) -> Result<ethdev::PortId> {
     let port_id = unsafe { ffi::rte_eth_bond_create(try!(to_cptr!(name)), mode as u8, socket_id as u8) };
 
-    rte_check!(port_id; ok => { port_id as ethdev::PortId })
+    rte_check!(port_id; ok => { ethdev::PortId(port_id as u16) })
 }
 
 /// Free a bonded rte_eth_dev device
@@ -162,81 +162,81 @@ pub trait BondedDevice {
 impl BondedDevice for ethdev::PortId {
     fn add_slave(&self, slave: ethdev::PortId) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_slave_add(*self, slave)
+            ffi::rte_eth_bond_slave_add(self.0, slave.0)
         }; ok => { self })
     }
 
     fn remove_slave(&self, slave: ethdev::PortId) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_slave_remove(*self, slave)
+            ffi::rte_eth_bond_slave_remove(self.0, slave.0)
         }; ok => { self })
     }
 
     fn mode(&self) -> Result<BondMode> {
-        let mode = unsafe { ffi::rte_eth_bond_mode_get(*self) };
+        let mode = unsafe { ffi::rte_eth_bond_mode_get(self.0) };
 
         rte_check!(mode; ok => { BondMode::from(mode as u8) })
     }
 
     fn set_mode(&self, mode: BondMode) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_mode_set(*self, mode as u8)
+            ffi::rte_eth_bond_mode_set(self.0, mode as u8)
         }; ok => { self })
     }
 
     fn primary(&self) -> Result<ethdev::PortId> {
-        let portid = unsafe { ffi::rte_eth_bond_primary_get(*self) };
+        let portid = unsafe { ffi::rte_eth_bond_primary_get(self.0) };
 
-        rte_check!(portid; ok => { portid as ethdev::PortId })
+        rte_check!(portid; ok => { ethdev::PortId(portid as u16) })
     }
 
     fn set_primary(&self, dev: ethdev::PortId) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_primary_set(*self, dev)
+            ffi::rte_eth_bond_primary_set(self.0, dev.0)
         }; ok => { self })
     }
 
     fn slaves(&self) -> Result<Vec<ethdev::PortId>> {
         let mut slaves = [0u16; ffi::RTE_MAX_ETHPORTS as usize];
 
-        let num = unsafe { ffi::rte_eth_bond_slaves_get(*self, slaves.as_mut_ptr(), slaves.len() as u16) };
+        let num = unsafe { ffi::rte_eth_bond_slaves_get(self.0, slaves.as_mut_ptr(), slaves.len() as u16) };
 
         rte_check!(num; ok => {
-            Vec::from(&slaves[..num as usize])
+            slaves[..num as usize].iter().cloned().map(ethdev::PortId).collect()
         })
     }
 
     fn active_slaves(&self) -> Result<Vec<ethdev::PortId>> {
         let mut slaves = [0u16; ffi::RTE_MAX_ETHPORTS as usize];
 
-        let num = unsafe { ffi::rte_eth_bond_slaves_get(*self, slaves.as_mut_ptr(), slaves.len() as u16) };
+        let num = unsafe { ffi::rte_eth_bond_active_slaves_get(self.0, slaves.as_mut_ptr(), slaves.len() as u16) };
 
         rte_check!(num; ok => {
-            Vec::from(&slaves[..num as usize])
+            slaves[..num as usize].iter().cloned().map(ethdev::PortId).collect()
         })
     }
 
     fn set_mac_addr(&self, mac_addr: &ether::EtherAddr) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_mac_address_set(*self, mac_addr.octets().as_ptr() as * mut _)
+            ffi::rte_eth_bond_mac_address_set(self.0, mac_addr.octets().as_ptr() as * mut _)
         }; ok => { self })
     }
 
     fn reset_mac_addr(&self) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_mac_address_reset(*self)
+            ffi::rte_eth_bond_mac_address_reset(self.0)
         }; ok => { self })
     }
 
     fn xmit_policy(&self) -> Result<TransmitPolicy> {
-        let policy = unsafe { ffi::rte_eth_bond_xmit_policy_get(*self) };
+        let policy = unsafe { ffi::rte_eth_bond_xmit_policy_get(self.0) };
 
         rte_check!(policy; ok => { TransmitPolicy::from(policy as u8) })
     }
 
     fn set_xmit_policy(&self, policy: TransmitPolicy) -> Result<&Self> {
         rte_check!(unsafe {
-            ffi::rte_eth_bond_xmit_policy_set(*self, policy as u8)
+            ffi::rte_eth_bond_xmit_policy_set(self.0, policy as u8)
         }; ok => { self })
     }
 }
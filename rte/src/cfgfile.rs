@@ -0,0 +1,331 @@
+//!
+//! RTE Configuration File
+//!
+//! The INI-style configuration files used by the DPDK sample applications, e.g. the QoS scheduler profiles.
+//!
+//! ```ini
+//! [port]
+//! frame overhead = 24
+//!
+//! [pipe profile 0]
+//! tb rate = 305175
+//! tc 12 wrr weights = 1 1 1 1
+//! ```
+//!
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+
+use errors::ErrorKind::{InvalidConfigFile, OsError};
+use ffi;
+use utils::{AsCString, AsRaw};
+
+/// The maximum length of the section and entry names, including the trailing NUL.
+pub const NAME_LEN: usize = ffi::CFG_NAME_LEN as usize;
+
+/// The maximum length of the entry values, including the trailing NUL.
+pub const VALUE_LEN: usize = ffi::CFG_VALUE_LEN as usize;
+
+/// The default comment character.
+pub const DEFAULT_COMMENT_CHARACTER: char = ffi::CFG_DEFAULT_COMMENT_CHARACTER as char;
+
+/// The section holding the entries before the first defined section.
+pub const GLOBAL_SECTION: &str = "GLOBAL";
+
+bitflags! {
+    /// The flags of the configuration file.
+    pub struct Flags: u32 {
+        /// The entries before the first defined section are in the `GLOBAL` section.
+        const GLOBAL_SECTION = ffi::CFG_FLAG_GLOBAL_SECTION;
+        /// The entries may have the empty values, e.g. `key=`.
+        const EMPTY_VALUES = ffi::CFG_FLAG_EMPTY_VALUES;
+    }
+}
+
+pub type RawCfgFile = ffi::rte_cfgfile;
+
+/// A configuration file loaded in memory.
+#[derive(Debug)]
+pub struct CfgFile(NonNull<RawCfgFile>);
+
+unsafe impl Send for CfgFile {}
+
+impl Drop for CfgFile {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_cfgfile_close(self.0.as_ptr()) };
+    }
+}
+
+impl AsRaw for CfgFile {
+    type Raw = RawCfgFile;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+fn path_to_cstring<P: AsRef<Path>>(path: P) -> Result<CString> {
+    Ok(CString::new(path.as_ref().as_os_str().as_bytes())?)
+}
+
+fn check(ret: c_int) -> Result<()> {
+    if ret < 0 {
+        Err(OsError(-ret).into())
+    } else {
+        Ok(())
+    }
+}
+
+fn as_string(s: &[c_char]) -> String {
+    unsafe { CStr::from_ptr(s.as_ptr()) }.to_string_lossy().into_owned()
+}
+
+impl CfgFile {
+    /// Load the configuration file.
+    pub fn load<P: AsRef<Path>>(path: P, flags: Flags) -> Result<Self> {
+        Self::load_with_comment(path, flags, DEFAULT_COMMENT_CHARACTER)
+    }
+
+    /// Load the configuration file with the comment character, one of `!`, `#`, `%`, `;` or `@`.
+    pub fn load_with_comment<P: AsRef<Path>>(path: P, flags: Flags, comment: char) -> Result<Self> {
+        let filename = path_to_cstring(&path)?;
+        let params = ffi::rte_cfgfile_parameters {
+            comment_character: comment as c_char,
+        };
+
+        let p = unsafe { ffi::rte_cfgfile_load_with_params(filename.as_ptr(), flags.bits as c_int, &params) };
+
+        NonNull::new(p)
+            .map(CfgFile)
+            .ok_or_else(|| InvalidConfigFile(path.as_ref().display().to_string()).into())
+    }
+
+    /// Create an empty configuration file in memory.
+    pub fn create(flags: Flags) -> Result<Self> {
+        NonNull::new(unsafe { ffi::rte_cfgfile_create(flags.bits as c_int) })
+            .map(CfgFile)
+            .ok_or_else(|| anyhow!("fail to create config file"))
+    }
+
+    /// Save the configuration file to the disk.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let filename = path_to_cstring(path)?;
+
+        check(unsafe { ffi::rte_cfgfile_save(self.0.as_ptr(), filename.as_ptr()) })
+    }
+
+    /// Add a section.
+    pub fn add_section(&mut self, section: &str) -> Result<()> {
+        let section = section.as_cstring();
+
+        check(unsafe { ffi::rte_cfgfile_add_section(self.0.as_ptr(), section.as_ptr()) })
+    }
+
+    /// Add an entry to the section, which fails if the entry already exists.
+    pub fn add_entry<T: ToString>(&mut self, section: &str, entry: &str, value: T) -> Result<()> {
+        let section = section.as_cstring();
+        let entry = entry.as_cstring();
+        let value = value.to_string().as_cstring();
+
+        check(unsafe { ffi::rte_cfgfile_add_entry(self.0.as_ptr(), section.as_ptr(), entry.as_ptr(), value.as_ptr()) })
+    }
+
+    /// Update the value of the entry in the section.
+    pub fn set_entry<T: ToString>(&mut self, section: &str, entry: &str, value: T) -> Result<()> {
+        let section = section.as_cstring();
+        let entry = entry.as_cstring();
+        let value = value.to_string().as_cstring();
+
+        check(unsafe { ffi::rte_cfgfile_set_entry(self.0.as_ptr(), section.as_ptr(), entry.as_ptr(), value.as_ptr()) })
+    }
+
+    /// The number of the sections whose names start with the prefix.
+    pub fn num_sections(&self, prefix: &str) -> usize {
+        let name = prefix.as_cstring();
+
+        unsafe { ffi::rte_cfgfile_num_sections(self.0.as_ptr(), name.as_ptr(), prefix.len()) as usize }
+    }
+
+    /// The names of all the sections in order.
+    pub fn sections(&self) -> Vec<String> {
+        let n = self.num_sections("");
+        let mut names = vec![[0 as c_char; NAME_LEN]; n];
+        let mut ptrs = names.iter_mut().map(|name| name.as_mut_ptr()).collect::<Vec<_>>();
+
+        let n = unsafe { ffi::rte_cfgfile_sections(self.0.as_ptr(), ptrs.as_mut_ptr(), ptrs.len() as c_int) };
+
+        names.truncate(n.max(0) as usize);
+        names.iter().map(|name| as_string(name)).collect()
+    }
+
+    /// Test if the section exists.
+    pub fn has_section(&self, section: &str) -> bool {
+        let section = section.as_cstring();
+
+        unsafe { ffi::rte_cfgfile_has_section(self.0.as_ptr(), section.as_ptr()) != 0 }
+    }
+
+    /// The entries of the first section of the name in order.
+    pub fn entries(&self, section: &str) -> Result<Vec<(String, String)>> {
+        let name = section.as_cstring();
+
+        let n = unsafe { ffi::rte_cfgfile_section_num_entries(self.0.as_ptr(), name.as_ptr()) };
+        if n < 0 {
+            return Err(InvalidConfigFile(format!("section `{}` not found", section)).into());
+        }
+
+        let mut entries = vec![ffi::rte_cfgfile_entry::default(); n as usize];
+        let n = unsafe {
+            ffi::rte_cfgfile_section_entries(
+                self.0.as_ptr(),
+                name.as_ptr(),
+                entries.as_mut_ptr(),
+                entries.len() as c_int,
+            )
+        };
+        if n < 0 {
+            return Err(InvalidConfigFile(format!("section `{}` not found", section)).into());
+        }
+
+        entries.truncate(n as usize);
+
+        Ok(entries
+            .iter()
+            .map(|entry| (as_string(&entry.name), as_string(&entry.value)))
+            .collect())
+    }
+
+    /// Test if the entry exists in the section.
+    pub fn has_entry(&self, section: &str, entry: &str) -> bool {
+        let section = section.as_cstring();
+        let entry = entry.as_cstring();
+
+        unsafe { ffi::rte_cfgfile_has_entry(self.0.as_ptr(), section.as_ptr(), entry.as_ptr()) != 0 }
+    }
+
+    /// The value of the entry in the section.
+    pub fn get(&self, section: &str, entry: &str) -> Option<&str> {
+        let section = section.as_cstring();
+        let entry = entry.as_cstring();
+
+        let p = unsafe { ffi::rte_cfgfile_get_entry(self.0.as_ptr(), section.as_ptr(), entry.as_ptr()) };
+
+        if p.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(p) }.to_str().ok()
+        }
+    }
+
+    /// Parse the value of the entry in the section, return `None` if the entry is not present.
+    pub fn get_as<T>(&self, section: &str, entry: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.get(section, entry)
+            .map(|value| {
+                value.trim().parse().map_err(|err| {
+                    InvalidConfigFile(format!(
+                        "invalid value of `{}` in [{}], {}, {}",
+                        entry, section, value, err
+                    ))
+                    .into()
+                })
+            })
+            .transpose()
+    }
+
+    /// Parse the value of the entry in the section, return `default` if the entry is not present.
+    pub fn get_or<T>(&self, section: &str, entry: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.get_as(section, entry).map(|value| value.unwrap_or(default))
+    }
+
+    /// Parse the whitespace separated values of the entry in the section, e.g. `tc 12 wrr weights = 1 1 1 1`.
+    pub fn get_list<T>(&self, section: &str, entry: &str) -> Result<Option<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.get(section, entry)
+            .map(|value| {
+                value
+                    .split_whitespace()
+                    .map(|s| {
+                        s.parse().map_err(|err| {
+                            InvalidConfigFile(format!("invalid value of `{}` in [{}], {}, {}", entry, section, s, err))
+                                .into()
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_cfgfile() {
+        let path = env::temp_dir().join(format!("rte-cfgfile-{}.ini", process::id()));
+
+        fs::write(
+            &path,
+            "; QoS scheduler profile\nverbose = 1\n\n[port]\nframe overhead = 24\n\n[pipe profile 0]\ntb rate = 305175\ntc 12 wrr weights = 1 2 4 8\n\n[pipe profile 1]\ntb rate = 1000\n",
+        )
+        .unwrap();
+
+        let cfg = CfgFile::load(&path, Flags::GLOBAL_SECTION).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            cfg.sections(),
+            vec![GLOBAL_SECTION, "port", "pipe profile 0", "pipe profile 1"]
+        );
+        assert_eq!(cfg.num_sections("pipe profile"), 2);
+        assert!(cfg.has_section("port"));
+        assert!(!cfg.has_section("subport"));
+        assert!(cfg.has_entry("port", "frame overhead"));
+        assert_eq!(cfg.get("port", "frame overhead"), Some("24"));
+        assert_eq!(cfg.get_as::<u32>(GLOBAL_SECTION, "verbose").unwrap(), Some(1));
+        assert_eq!(cfg.get_as::<u32>("port", "mtu").unwrap(), None);
+        assert_eq!(cfg.get_or::<u32>("port", "mtu", 1522).unwrap(), 1522);
+        assert_eq!(
+            cfg.get_list::<u8>("pipe profile 0", "tc 12 wrr weights").unwrap(),
+            Some(vec![1, 2, 4, 8])
+        );
+        assert_eq!(
+            cfg.entries("pipe profile 1").unwrap(),
+            vec![("tb rate".to_owned(), "1000".to_owned())]
+        );
+        assert!(cfg.entries("subport").is_err());
+
+        let mut cfg = CfgFile::create(Flags::empty()).unwrap();
+
+        cfg.add_section("port").unwrap();
+        cfg.add_entry("port", "frame overhead", 24).unwrap();
+        assert!(cfg.add_entry("port", "frame overhead", 24).is_err());
+        cfg.set_entry("port", "frame overhead", 20).unwrap();
+        assert_eq!(cfg.get_as::<u32>("port", "frame overhead").unwrap(), Some(20));
+    }
+}
@@ -16,6 +16,7 @@ use ffi;
 
 use errors::{AsResult, ErrorKind::CmdLineParseError};
 use ether;
+use utils::catch_panic;
 
 pub type RawTokenHeader = ffi::cmdline_token_hdr;
 pub type RawTokenPtr = *const RawTokenHeader;
@@ -354,19 +355,32 @@ struct InstHandlerContext<T, D> {
     data: Option<D>,
 }
 
-unsafe extern "C" fn _inst_handler_stub<T, D>(inst: *mut c_void, cl: *mut RawCmdLine, ctxt: *mut c_void) {
-    let ctxt = Box::from_raw(ctxt as *mut InstHandlerContext<T, D>);
+unsafe extern "C" fn _inst_handler_stub<T, D: Clone>(inst: *mut c_void, cl: *mut RawCmdLine, ctxt: *mut c_void) {
+    // the context is called for each parsed command, and freed when the instruction is dropped
+    let ctxt = &*(ctxt as *const InstHandlerContext<T, D>);
 
-    (ctxt.handler)((inst as *mut T).as_mut().unwrap(), &CmdLine::Borrowed(cl), ctxt.data);
+    catch_panic("cmdline handler", (), || {
+        (ctxt.handler)(
+            (inst as *mut T).as_mut().unwrap(),
+            &CmdLine::Borrowed(cl),
+            ctxt.data.clone(),
+        )
+    });
+}
+
+unsafe fn _inst_handler_free<T, D>(ctxt: *mut c_void) {
+    drop(Box::from_raw(ctxt as *mut InstHandlerContext<T, D>))
 }
 
 pub type RawInstPtr = *mut ffi::cmdline_inst;
 
-pub struct Inst(RawInstPtr);
+pub struct Inst(RawInstPtr, unsafe fn(*mut c_void));
 
 impl Drop for Inst {
     fn drop(&mut self) {
         unsafe {
+            (self.1)((*self.0).data);
+
             libc::free((*self.0).help_str as *mut libc::c_void);
             libc::free(self.0 as *mut libc::c_void);
         }
@@ -379,7 +393,12 @@ impl Inst {
     }
 }
 
-pub fn inst<T, D>(handler: InstHandler<T, D>, data: Option<D>, help: &'static str, tokens: &[&Token<T>]) -> Inst {
+pub fn inst<T, D: Clone>(
+    handler: InstHandler<T, D>,
+    data: Option<D>,
+    help: &'static str,
+    tokens: &[&Token<T>],
+) -> Inst {
     unsafe {
         let help_str = libc::calloc(1, help.len() + 1) as *mut c_char;
 
@@ -405,7 +424,7 @@ pub fn inst<T, D>(handler: InstHandler<T, D>, data: Option<D>, help: &'static st
             tokens.len(),
         );
 
-        Inst(inst)
+        Inst(inst, _inst_handler_free::<T, D>)
     }
 }
 
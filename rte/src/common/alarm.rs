@@ -0,0 +1,74 @@
+//!
+//! RTE Alarm
+//!
+//! The alarm callbacks are called once by the EAL interrupt thread after the delay has expired,
+//! which is suitable for the periodic housekeeping without a dedicated lcore, e.g. flushing the statistics.
+//!
+//! The callbacks must not block, since all the alarms and interrupts are handled by the same thread.
+//!
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use errors::ErrorKind::OsError;
+use ffi;
+use utils::catch_panic;
+
+type AlarmCallback = Box<dyn FnOnce() + Send>;
+
+lazy_static! {
+    static ref ALARMS: Mutex<HashMap<usize, AlarmCallback>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_ALARM_ID: AtomicUsize = AtomicUsize::new(1);
+
+unsafe extern "C" fn alarm_callback_stub(arg: *mut c_void) {
+    let callback = ALARMS.lock().unwrap().remove(&(arg as usize));
+
+    if let Some(callback) = callback {
+        catch_panic("alarm callback", (), callback)
+    }
+}
+
+/// An alarm which has been set.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Alarm(usize);
+
+impl Alarm {
+    /// Cancel the alarm, return `false` if the alarm has already gone off.
+    ///
+    /// If the callback is being executed by the interrupt thread, wait for it to finish.
+    pub fn cancel(self) -> bool {
+        unsafe { ffi::rte_eal_alarm_cancel(Some(alarm_callback_stub), self.0 as *mut _) };
+
+        ALARMS.lock().unwrap().remove(&self.0).is_some()
+    }
+}
+
+/// Set a callback to be called once after the delay has expired, with the microsecond resolution.
+///
+/// The callback is dropped without being called if the alarm is canceled.
+pub fn set<F>(delay: Duration, callback: F) -> Result<Alarm>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let id = NEXT_ALARM_ID.fetch_add(1, Ordering::Relaxed);
+
+    ALARMS.lock().unwrap().insert(id, Box::new(callback));
+
+    let us = delay.as_secs() * 1_000_000 + u64::from(delay.subsec_micros());
+
+    let ret = unsafe { ffi::rte_eal_alarm_set(us, Some(alarm_callback_stub), id as *mut _) };
+
+    if ret == 0 {
+        Ok(Alarm(id))
+    } else {
+        ALARMS.lock().unwrap().remove(&id);
+
+        Err(OsError(-ret).into())
+    }
+}
@@ -1,9 +1,14 @@
+use std::ops::Range;
+use std::os::raw::c_void;
 use std::ptr::NonNull;
 
 use anyhow::Result;
+use libc;
 
-use errors::AsResult;
+use errors::{AsResult, RteError};
 use ffi;
+use malloc;
+use memory::SocketId;
 use utils::AsRaw;
 
 pub type Position = u32;
@@ -33,15 +38,26 @@ pub type RawBitmapPtr = *mut ffi::rte_bitmap;
 ///  serialization of the bit set/clear and bitmap scan operations needs to be
 ///  enforced by the caller, while the bit get operation does not require locking
 ///  the bitmap.
-#[repr(transparent)]
 #[derive(Debug)]
-pub struct Bitmap(NonNull<RawBitmap>);
+pub struct Bitmap {
+    raw: NonNull<RawBitmap>,
+    // the memory allocated by `Bitmap::new()`, which is freed on drop.
+    mem: Option<NonNull<c_void>>,
+}
+
+// The bit get operation can run in parallel with the writer, which needs `&mut self`.
+unsafe impl Send for Bitmap {}
+unsafe impl Sync for Bitmap {}
 
 impl Drop for Bitmap {
     fn drop(&mut self) {
         unsafe {
             ffi::_rte_bitmap_free(self.as_raw_mut());
         }
+
+        if let Some(mem) = self.mem.take() {
+            malloc::free(mem.as_ptr());
+        }
     }
 }
 
@@ -49,11 +65,11 @@ impl AsRaw for Bitmap {
     type Raw = RawBitmap;
 
     fn as_raw(&self) -> *const Self::Raw {
-        self.0.as_ptr()
+        self.raw.as_ptr()
     }
 
     fn as_raw_mut(&self) -> *mut Self::Raw {
-        self.0.as_ptr() as *mut _
+        self.raw.as_ptr() as *mut _
     }
 }
 
@@ -63,11 +79,37 @@ impl Bitmap {
         unsafe { ffi::_rte_bitmap_get_memory_footprint(bits) }
     }
 
+    /// Create a bitmap of `bits` bits, which memory is allocated on the socket and freed on drop.
+    pub fn new(bits: u32, socket_id: SocketId) -> Result<Self> {
+        let mem_size = Self::memory_footprint(bits);
+        let mem = NonNull::new(malloc::zmalloc_socket(
+            "bitmap",
+            mem_size as usize,
+            ffi::RTE_CACHE_LINE_SIZE,
+            socket_id,
+        ))
+        .ok_or(RteError(libc::ENOMEM))?;
+
+        match unsafe { ffi::_rte_bitmap_init(bits, mem.as_ptr() as *mut u8, mem_size) }.as_result() {
+            Ok(raw) => Ok(Bitmap { raw, mem: Some(mem) }),
+            Err(err) => {
+                malloc::free(mem.as_ptr());
+
+                Err(err)
+            }
+        }
+    }
+
     /// Bitmap initialization
-    pub fn init(bits: u32, mem: *mut u8, mem_size: u32) -> Result<Self> {
-        unsafe { ffi::_rte_bitmap_init(bits, mem, mem_size) }
+    ///
+    /// # Safety
+    ///
+    /// The memory must be cache line aligned, at least `memory_footprint(bits)` bytes,
+    /// and outlive the bitmap.
+    pub unsafe fn init(bits: u32, mem: *mut u8, mem_size: u32) -> Result<Self> {
+        ffi::_rte_bitmap_init(bits, mem, mem_size)
             .as_result()
-            .map(Bitmap)
+            .map(|raw| Bitmap { raw, mem: None })
     }
 
     /// Bitmap reset
@@ -100,8 +142,32 @@ impl Bitmap {
         unsafe { ffi::_rte_bitmap_clear(self.as_raw_mut(), pos) }
     }
 
+    /// Set the bits in the range.
+    pub fn set_range(&mut self, range: Range<Position>) {
+        let mut pos = range.start;
+
+        while pos < range.end {
+            if pos.is_multiple_of(Slab::BITS) && range.end - pos >= Slab::BITS {
+                self.set_slab(pos, Slab::MAX);
+                pos += Slab::BITS;
+            } else {
+                self.set(pos);
+                pos += 1;
+            }
+        }
+    }
+
+    /// Clear the bits in the range.
+    pub fn clear_range(&mut self, range: Range<Position>) {
+        for pos in range {
+            self.clear(pos)
+        }
+    }
+
     /// Bitmap scan (with automatic wrap-around)
-    pub fn scan(&self) -> Option<(Position, Slab)> {
+    ///
+    /// The scan updates the internal scan position, so only the writer can do it.
+    pub fn scan(&mut self) -> Option<(Position, Slab)> {
         let mut pos = 0;
         let mut slab = 0;
 
@@ -111,4 +177,50 @@ impl Bitmap {
             Some((pos, slab))
         }
     }
+
+    /// Iterate over the positions of the set bits, with the bitmap scan.
+    ///
+    /// The iteration starts from the current scan position and wraps around.
+    pub fn iter_set_bits(&mut self) -> SetBits<'_> {
+        SetBits {
+            bitmap: self,
+            first: None,
+            pos: 0,
+            slab: 0,
+        }
+    }
+}
+
+/// An iterator over the positions of the set bits of a bitmap.
+pub struct SetBits<'a> {
+    bitmap: &'a mut Bitmap,
+    first: Option<Position>,
+    pos: Position,
+    slab: Slab,
+}
+
+impl<'a> Iterator for SetBits<'a> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slab == 0 {
+            let (pos, slab) = self.bitmap.scan()?;
+
+            match self.first {
+                // the scan has wrapped around to the first slab.
+                Some(first) if first == pos => return None,
+                Some(_) => {}
+                None => self.first = Some(pos),
+            }
+
+            self.pos = pos;
+            self.slab = slab;
+        }
+
+        let bit = self.slab.trailing_zeros();
+
+        self.slab &= self.slab - 1;
+
+        Some(self.pos + bit)
+    }
 }
@@ -0,0 +1,218 @@
+//!
+//! Bit operations.
+//!
+//! The relaxed operations access the word with volatile loads and stores,
+//! which is neither atomic nor ordered, and should only be used by a single writer.
+//! The atomic operations work on the atomic words with the given memory ordering.
+//!
+use std::ptr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+pub use super::math::{bsf32, bsf64, fls_u32, fls_u64};
+
+/// Get the count of 1-bits in `v`.
+#[inline]
+pub fn popcount32(v: u32) -> u32 {
+    v.count_ones()
+}
+
+/// Get the count of 1-bits in `v`.
+#[inline]
+pub fn popcount64(v: u64) -> u32 {
+    v.count_ones()
+}
+
+/// Get the count of leading 0-bits in `v`.
+#[inline]
+pub fn clz32(v: u32) -> u32 {
+    v.leading_zeros()
+}
+
+/// Get the count of leading 0-bits in `v`.
+#[inline]
+pub fn clz64(v: u64) -> u32 {
+    v.leading_zeros()
+}
+
+/// Get the count of trailing 0-bits in `v`.
+#[inline]
+pub fn ctz32(v: u32) -> u32 {
+    v.trailing_zeros()
+}
+
+/// Get the count of trailing 0-bits in `v`.
+#[inline]
+pub fn ctz64(v: u64) -> u32 {
+    v.trailing_zeros()
+}
+
+macro_rules! bitops {
+    ($ty:ty, $atomic:ty, $bits:expr,
+     $get:ident, $set:ident, $clear:ident, $test_and_set:ident, $test_and_clear:ident,
+     $atomic_test:ident, $atomic_set:ident, $atomic_clear:ident, $atomic_flip:ident,
+     $atomic_test_and_set:ident, $atomic_test_and_clear:ident) => {
+        /// Get the target bit from the word, with a volatile load.
+        #[inline]
+        pub fn $get(nr: u32, addr: &$ty) -> bool {
+            debug_assert!(nr < $bits);
+
+            unsafe { ptr::read_volatile(addr) & (1 << nr) != 0 }
+        }
+
+        /// Set the target bit in the word, with a volatile load and store.
+        #[inline]
+        pub fn $set(nr: u32, addr: &mut $ty) {
+            debug_assert!(nr < $bits);
+
+            unsafe { ptr::write_volatile(addr, ptr::read_volatile(addr) | (1 << nr)) }
+        }
+
+        /// Clear the target bit in the word, with a volatile load and store.
+        #[inline]
+        pub fn $clear(nr: u32, addr: &mut $ty) {
+            debug_assert!(nr < $bits);
+
+            unsafe { ptr::write_volatile(addr, ptr::read_volatile(addr) & !(1 << nr)) }
+        }
+
+        /// Set the target bit in the word, and return its old value.
+        #[inline]
+        pub fn $test_and_set(nr: u32, addr: &mut $ty) -> bool {
+            let old = $get(nr, addr);
+
+            $set(nr, addr);
+
+            old
+        }
+
+        /// Clear the target bit in the word, and return its old value.
+        #[inline]
+        pub fn $test_and_clear(nr: u32, addr: &mut $ty) -> bool {
+            let old = $get(nr, addr);
+
+            $clear(nr, addr);
+
+            old
+        }
+
+        /// Test the target bit of the atomic word.
+        #[inline]
+        pub fn $atomic_test(nr: u32, addr: &$atomic, order: Ordering) -> bool {
+            debug_assert!(nr < $bits);
+
+            addr.load(order) & (1 << nr) != 0
+        }
+
+        /// Set the target bit of the atomic word.
+        #[inline]
+        pub fn $atomic_set(nr: u32, addr: &$atomic, order: Ordering) {
+            $atomic_test_and_set(nr, addr, order);
+        }
+
+        /// Clear the target bit of the atomic word.
+        #[inline]
+        pub fn $atomic_clear(nr: u32, addr: &$atomic, order: Ordering) {
+            $atomic_test_and_clear(nr, addr, order);
+        }
+
+        /// Flip the target bit of the atomic word, and return its old value.
+        #[inline]
+        pub fn $atomic_flip(nr: u32, addr: &$atomic, order: Ordering) -> bool {
+            debug_assert!(nr < $bits);
+
+            addr.fetch_xor(1 << nr, order) & (1 << nr) != 0
+        }
+
+        /// Set the target bit of the atomic word, and return its old value.
+        #[inline]
+        pub fn $atomic_test_and_set(nr: u32, addr: &$atomic, order: Ordering) -> bool {
+            debug_assert!(nr < $bits);
+
+            addr.fetch_or(1 << nr, order) & (1 << nr) != 0
+        }
+
+        /// Clear the target bit of the atomic word, and return its old value.
+        #[inline]
+        pub fn $atomic_test_and_clear(nr: u32, addr: &$atomic, order: Ordering) -> bool {
+            debug_assert!(nr < $bits);
+
+            addr.fetch_and(!(1 << nr), order) & (1 << nr) != 0
+        }
+    };
+}
+
+bitops!(
+    u32,
+    AtomicU32,
+    32,
+    bit_relaxed_get32,
+    bit_relaxed_set32,
+    bit_relaxed_clear32,
+    bit_relaxed_test_and_set32,
+    bit_relaxed_test_and_clear32,
+    bit_atomic_test32,
+    bit_atomic_set32,
+    bit_atomic_clear32,
+    bit_atomic_flip32,
+    bit_atomic_test_and_set32,
+    bit_atomic_test_and_clear32
+);
+
+bitops!(
+    u64,
+    AtomicU64,
+    64,
+    bit_relaxed_get64,
+    bit_relaxed_set64,
+    bit_relaxed_clear64,
+    bit_relaxed_test_and_set64,
+    bit_relaxed_test_and_clear64,
+    bit_atomic_test64,
+    bit_atomic_set64,
+    bit_atomic_clear64,
+    bit_atomic_flip64,
+    bit_atomic_test_and_set64,
+    bit_atomic_test_and_clear64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relaxed() {
+        let mut w = 0u32;
+
+        assert!(!bit_relaxed_test_and_set32(3, &mut w));
+        assert!(bit_relaxed_get32(3, &w));
+        assert_eq!(w, 0b1000);
+        assert!(bit_relaxed_test_and_clear32(3, &mut w));
+        assert!(!bit_relaxed_get32(3, &w));
+
+        let mut w = 0u64;
+
+        bit_relaxed_set64(63, &mut w);
+        assert_eq!(w, 1 << 63);
+        bit_relaxed_clear64(63, &mut w);
+        assert_eq!(w, 0);
+    }
+
+    #[test]
+    fn test_atomic() {
+        let w = AtomicU64::new(0);
+
+        assert!(!bit_atomic_test_and_set64(40, &w, Ordering::AcqRel));
+        assert!(bit_atomic_test64(40, &w, Ordering::Acquire));
+        assert!(bit_atomic_flip64(40, &w, Ordering::AcqRel));
+        assert_eq!(w.load(Ordering::Relaxed), 0);
+
+        let w = AtomicU32::new(0);
+
+        bit_atomic_set32(0, &w, Ordering::Release);
+        bit_atomic_set32(31, &w, Ordering::Release);
+        assert_eq!(popcount32(w.load(Ordering::Relaxed)), 2);
+        assert!(bit_atomic_test_and_clear32(31, &w, Ordering::AcqRel));
+        bit_atomic_clear32(0, &w, Ordering::Release);
+        assert_eq!(w.load(Ordering::Relaxed), 0);
+    }
+}
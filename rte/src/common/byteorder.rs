@@ -1,6 +1,143 @@
+//!
+//! Byte order conversions.
+//!
+//! The `be16`, `be32` and `be64` types hold the fields of the network headers in the network byte order,
+//! so the comparisons between the header fields and the host values are checked by the compiler.
+//!
+use std::fmt;
+
 #[macro_export]
 macro_rules! rte_cpu_to_be_16 {
     ($n:expr) => {
         (($n >> 8) & 0xFF) | (($n & 0xFF) << 8)
     };
 }
+
+/// Convert a 16-bit value from the CPU order to big endian.
+#[inline]
+pub const fn rte_cpu_to_be_16(x: u16) -> u16 {
+    x.to_be()
+}
+
+/// Convert a 32-bit value from the CPU order to big endian.
+#[inline]
+pub const fn rte_cpu_to_be_32(x: u32) -> u32 {
+    x.to_be()
+}
+
+/// Convert a 64-bit value from the CPU order to big endian.
+#[inline]
+pub const fn rte_cpu_to_be_64(x: u64) -> u64 {
+    x.to_be()
+}
+
+/// Convert a 16-bit value from big endian to the CPU order.
+#[inline]
+pub const fn rte_be_to_cpu_16(x: u16) -> u16 {
+    u16::from_be(x)
+}
+
+/// Convert a 32-bit value from big endian to the CPU order.
+#[inline]
+pub const fn rte_be_to_cpu_32(x: u32) -> u32 {
+    u32::from_be(x)
+}
+
+/// Convert a 64-bit value from big endian to the CPU order.
+#[inline]
+pub const fn rte_be_to_cpu_64(x: u64) -> u64 {
+    u64::from_be(x)
+}
+
+macro_rules! big_endian {
+    ($name:ident, $ty:ty, $to_be:ident, $to_cpu:ident) => {
+        /// A value stored in the network byte order.
+        #[allow(non_camel_case_types)]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name($ty);
+
+        impl $name {
+            /// Convert a value in the CPU order.
+            #[inline]
+            pub const fn new(v: $ty) -> Self {
+                $name($to_be(v))
+            }
+
+            /// Wrap a raw value which is already in the network byte order.
+            #[inline]
+            pub const fn from_raw(raw: $ty) -> Self {
+                $name(raw)
+            }
+
+            /// The raw value in the network byte order.
+            #[inline]
+            pub const fn raw(self) -> $ty {
+                self.0
+            }
+
+            /// The value in the CPU order.
+            #[inline]
+            pub const fn get(self) -> $ty {
+                $to_cpu(self.0)
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(v: $ty) -> Self {
+                $name::new(v)
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(v: $name) -> Self {
+                v.get()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}({:#x})", stringify!($name), self.get())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+big_endian!(be16, u16, rte_cpu_to_be_16, rte_be_to_cpu_16);
+big_endian!(be32, u32, rte_cpu_to_be_32, rte_be_to_cpu_32);
+big_endian!(be64, u64, rte_cpu_to_be_64, rte_be_to_cpu_64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big_endian() {
+        let v = be16::new(0x0800);
+
+        assert_eq!(v.raw(), 0x0800u16.to_be());
+        assert_eq!(v.get(), 0x0800);
+        assert_eq!(be16::from_raw(0x0800u16.to_be()), v);
+        assert_eq!(u16::from(v), 0x0800);
+        assert_eq!(format!("{:?}", v), "be16(0x800)");
+
+        let v = be32::from(0x0a00_0001);
+
+        assert_eq!(v.raw().to_ne_bytes(), [10, 0, 0, 1]);
+        assert_eq!(v.to_string(), "167772161");
+
+        assert_eq!(be64::new(1).raw().to_ne_bytes(), [0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Architecture specific API to determine available CPU features at runtime.
+//!
+use std::ffi::CStr;
+use std::fmt;
+
+use anyhow::Result;
+use itertools::Itertools;
+use num_traits::FromPrimitive;
+
+use errors::ErrorKind;
+use ffi;
+
+/// Enumeration of all CPU features supported
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive)]
+pub enum CpuFlag {
+    Sse3 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSE3,
+    Pclmulqdq = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PCLMULQDQ,
+    Dtes64 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DTES64,
+    Monitor = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MONITOR,
+    DsCpl = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DS_CPL,
+    Vmx = ffi::rte_cpu_flag_t::RTE_CPUFLAG_VMX,
+    Smx = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SMX,
+    Eist = ffi::rte_cpu_flag_t::RTE_CPUFLAG_EIST,
+    Tm2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_TM2,
+    Ssse3 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSSE3,
+    CnxtId = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CNXT_ID,
+    Fma = ffi::rte_cpu_flag_t::RTE_CPUFLAG_FMA,
+    Cmpxchg16b = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CMPXCHG16B,
+    Xtpr = ffi::rte_cpu_flag_t::RTE_CPUFLAG_XTPR,
+    Pdcm = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PDCM,
+    Pcid = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PCID,
+    Dca = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DCA,
+    Sse4_1 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSE4_1,
+    Sse4_2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSE4_2,
+    X2apic = ffi::rte_cpu_flag_t::RTE_CPUFLAG_X2APIC,
+    Movbe = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MOVBE,
+    Popcnt = ffi::rte_cpu_flag_t::RTE_CPUFLAG_POPCNT,
+    TscDeadline = ffi::rte_cpu_flag_t::RTE_CPUFLAG_TSC_DEADLINE,
+    Aes = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AES,
+    Xsave = ffi::rte_cpu_flag_t::RTE_CPUFLAG_XSAVE,
+    Osxsave = ffi::rte_cpu_flag_t::RTE_CPUFLAG_OSXSAVE,
+    Avx = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX,
+    F16c = ffi::rte_cpu_flag_t::RTE_CPUFLAG_F16C,
+    Rdrand = ffi::rte_cpu_flag_t::RTE_CPUFLAG_RDRAND,
+    Hypervisor = ffi::rte_cpu_flag_t::RTE_CPUFLAG_HYPERVISOR,
+    Fpu = ffi::rte_cpu_flag_t::RTE_CPUFLAG_FPU,
+    Vme = ffi::rte_cpu_flag_t::RTE_CPUFLAG_VME,
+    De = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DE,
+    Pse = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PSE,
+    Tsc = ffi::rte_cpu_flag_t::RTE_CPUFLAG_TSC,
+    Msr = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MSR,
+    Pae = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PAE,
+    Mce = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MCE,
+    Cx8 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CX8,
+    Apic = ffi::rte_cpu_flag_t::RTE_CPUFLAG_APIC,
+    Sep = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SEP,
+    Mtrr = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MTRR,
+    Pge = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PGE,
+    Mca = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MCA,
+    Cmov = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CMOV,
+    Pat = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PAT,
+    Pse36 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PSE36,
+    Psn = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PSN,
+    Clfsh = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CLFSH,
+    Ds = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DS,
+    Acpi = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ACPI,
+    Mmx = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MMX,
+    Fxsr = ffi::rte_cpu_flag_t::RTE_CPUFLAG_FXSR,
+    Sse = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSE,
+    Sse2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SSE2,
+    Ss = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SS,
+    Htt = ffi::rte_cpu_flag_t::RTE_CPUFLAG_HTT,
+    Tm = ffi::rte_cpu_flag_t::RTE_CPUFLAG_TM,
+    Pbe = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PBE,
+    Digtemp = ffi::rte_cpu_flag_t::RTE_CPUFLAG_DIGTEMP,
+    Trbobst = ffi::rte_cpu_flag_t::RTE_CPUFLAG_TRBOBST,
+    Arat = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ARAT,
+    Pln = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PLN,
+    Ecmd = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ECMD,
+    Ptm = ffi::rte_cpu_flag_t::RTE_CPUFLAG_PTM,
+    MperfAperfMsr = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MPERF_APERF_MSR,
+    Acnt2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ACNT2,
+    EnergyEff = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ENERGY_EFF,
+    Fsgsbase = ffi::rte_cpu_flag_t::RTE_CPUFLAG_FSGSBASE,
+    Bmi1 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_BMI1,
+    Hle = ffi::rte_cpu_flag_t::RTE_CPUFLAG_HLE,
+    Avx2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX2,
+    Smep = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SMEP,
+    Bmi2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_BMI2,
+    Erms = ffi::rte_cpu_flag_t::RTE_CPUFLAG_ERMS,
+    Invpcid = ffi::rte_cpu_flag_t::RTE_CPUFLAG_INVPCID,
+    Rtm = ffi::rte_cpu_flag_t::RTE_CPUFLAG_RTM,
+    Avx512f = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512F,
+    Rdseed = ffi::rte_cpu_flag_t::RTE_CPUFLAG_RDSEED,
+    LahfSahf = ffi::rte_cpu_flag_t::RTE_CPUFLAG_LAHF_SAHF,
+    Lzcnt = ffi::rte_cpu_flag_t::RTE_CPUFLAG_LZCNT,
+    Syscall = ffi::rte_cpu_flag_t::RTE_CPUFLAG_SYSCALL,
+    Xd = ffi::rte_cpu_flag_t::RTE_CPUFLAG_XD,
+    Pg1Gb = ffi::rte_cpu_flag_t::RTE_CPUFLAG_1GB_PG,
+    Rdtscp = ffi::rte_cpu_flag_t::RTE_CPUFLAG_RDTSCP,
+    Em64t = ffi::rte_cpu_flag_t::RTE_CPUFLAG_EM64T,
+    Invtsc = ffi::rte_cpu_flag_t::RTE_CPUFLAG_INVTSC,
+    Avx512dq = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512DQ,
+    Avx512ifma = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512IFMA,
+    Avx512cd = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512CD,
+    Avx512bw = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512BW,
+    Avx512vl = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VL,
+    Avx512vbmi = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VBMI,
+    Avx512vbmi2 = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VBMI2,
+    Gfni = ffi::rte_cpu_flag_t::RTE_CPUFLAG_GFNI,
+    Vaes = ffi::rte_cpu_flag_t::RTE_CPUFLAG_VAES,
+    Vpclmulqdq = ffi::rte_cpu_flag_t::RTE_CPUFLAG_VPCLMULQDQ,
+    Avx512vnni = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VNNI,
+    Avx512bitalg = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512BITALG,
+    Avx512vpopcntdq = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VPOPCNTDQ,
+    Cldemote = ffi::rte_cpu_flag_t::RTE_CPUFLAG_CLDEMOTE,
+    Movdiri = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MOVDIRI,
+    Movdir64b = ffi::rte_cpu_flag_t::RTE_CPUFLAG_MOVDIR64B,
+    Avx512vp2intersect = ffi::rte_cpu_flag_t::RTE_CPUFLAG_AVX512VP2INTERSECT,
+    Waitpkg = ffi::rte_cpu_flag_t::RTE_CPUFLAG_WAITPKG,
+}
+
+impl CpuFlag {
+    /// Get name of CPU flag
+    pub fn name(self) -> &'static str {
+        let p = unsafe { ffi::rte_cpu_get_flag_name(self as u32) };
+
+        if p.is_null() {
+            "unknown"
+        } else {
+            unsafe { CStr::from_ptr(p).to_str().unwrap_or("unknown") }
+        }
+    }
+
+    /// Check the CPU flag availability
+    pub fn is_enabled(self) -> bool {
+        unsafe { ffi::rte_cpu_get_flag_enabled(self as u32) == 1 }
+    }
+}
+
+impl fmt::Display for CpuFlag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// All the CPU flags available on the current CPU.
+pub fn enabled_flags() -> impl Iterator<Item = CpuFlag> {
+    (0..ffi::rte_cpu_flag_t::RTE_CPUFLAG_NUMFLAGS)
+        .flat_map(CpuFlag::from_u32)
+        .filter(|flag| flag.is_enabled())
+}
+
+/// Check that the current CPU supports all the `flags`.
+///
+/// Return an error listing the missing flags, so the application can refuse to start on unsupported machines.
+pub fn require(flags: &[CpuFlag]) -> Result<()> {
+    let missing = flags.iter().filter(|flag| !flag.is_enabled()).join(", ");
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(ErrorKind::UnsupportedCpuFlags(missing).into())
+    }
+}
+
+/// Check that the current CPU supports the CPU features that were specified at compile time.
+pub fn is_supported() -> bool {
+    unsafe { ffi::rte_cpu_is_supported() != 0 }
+}
@@ -34,3 +34,44 @@ pub fn rdtsc() -> u64 {
 pub fn rdtsc_precise() -> u64 {
     unsafe { ffi::_rte_rdtsc_precise() }
 }
+
+/// A periodic interval measured by the TSC, e.g. to drain the TX buffers in a run-to-completion loop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TscInterval {
+    period: u64,
+    last: u64,
+}
+
+impl TscInterval {
+    /// Create an interval of the period, which is rounded up to the microseconds.
+    pub fn new(period: Duration) -> Self {
+        let us = period.as_secs() * 1_000_000 + u64::from(period.subsec_nanos() + 999) / 1000;
+
+        Self::from_cycles((get_tsc_hz() + 999_999) / 1_000_000 * us)
+    }
+
+    /// Create an interval of the period in TSC cycles.
+    pub fn from_cycles(period: u64) -> Self {
+        TscInterval { period, last: rdtsc() }
+    }
+
+    /// The period in TSC cycles.
+    pub fn period(&self) -> u64 {
+        self.period
+    }
+
+    /// Check the interval with the current TSC,
+    /// return the cycles elapsed since the last tick if the period has elapsed.
+    #[inline]
+    pub fn tick(&mut self, now: u64) -> Option<u64> {
+        let elapsed = now.wrapping_sub(self.last);
+
+        if elapsed > self.period {
+            self.last = now;
+
+            Some(elapsed)
+        } else {
+            None
+        }
+    }
+}
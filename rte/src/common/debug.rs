@@ -1,4 +1,15 @@
+use std::os::raw::{c_char, c_void};
+use std::os::unix::io::AsRawFd;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Result;
+use cfile;
+use libc;
+
+use errors::AsResult;
+use ethdev::{EthDevice, PortId, QueueId};
 use ffi;
+use utils::AsCString;
 
 /// Dump the stack of the calling core to the console.
 pub fn dump_stack() {
@@ -10,6 +21,148 @@ pub fn dump_registers() {
     unimplemented!("rte_dump_registers is unimplemented")
 }
 
+/// Dump the buffer in the hex format with the title to the console.
+pub fn hexdump(title: &str, buf: &[u8]) {
+    unsafe {
+        let f = ffi::_rte_stdout();
+
+        dump_buf(ffi::rte_hexdump, f, title, buf);
+
+        libc::fflush(f as *mut libc::FILE);
+    }
+}
+
+/// Dump the buffer in the hex format with the title to the stream.
+pub fn hexdump_to<S: AsRawFd>(s: &S, title: &str, buf: &[u8]) {
+    if let Ok(f) = cfile::fdopen(s, "w") {
+        unsafe { dump_buf(ffi::rte_hexdump, f.stream() as *mut ffi::FILE, title, buf) }
+    }
+}
+
+/// Dump the buffer in the hex format with colons between bytes with the title to the console.
+pub fn memdump(title: &str, buf: &[u8]) {
+    unsafe {
+        let f = ffi::_rte_stdout();
+
+        dump_buf(ffi::rte_memdump, f, title, buf);
+
+        libc::fflush(f as *mut libc::FILE);
+    }
+}
+
+type DumpFn = unsafe extern "C" fn(*mut ffi::FILE, *const c_char, *const c_void, u32);
+
+unsafe fn dump_buf(dump: DumpFn, f: *mut ffi::FILE, title: &str, buf: &[u8]) {
+    let title = title.as_cstring();
+
+    dump(f, title.as_ptr(), buf.as_ptr() as *const c_void, buf.len() as u32)
+}
+
+/// Dump the first packets received on a port to the console, to diagnose the malformed packets.
+///
+/// A RX callback is installed on each RX queue of the port, which dumps the packets
+/// until `count` packets were dumped, and it is removed when the `RxDump` is dropped.
+/// The RX callbacks must be enabled in the DPDK build, which is the default.
+pub struct RxDump {
+    port_id: PortId,
+    callbacks: Vec<(QueueId, *const ffi::rte_eth_rxtx_callback)>,
+    ctxt: Box<RxDumpContext>,
+}
+
+struct RxDumpContext {
+    remaining: AtomicUsize,
+    dump_len: u32,
+}
+
+impl Drop for RxDump {
+    fn drop(&mut self) {
+        for &(queue_id, cb) in &self.callbacks {
+            unsafe {
+                ffi::rte_eth_remove_rx_callback(self.port_id.0, queue_id, cb);
+            }
+        }
+    }
+}
+
+impl RxDump {
+    /// Dump the first `count` packets received on all the RX queues of the port,
+    /// and at most `dump_len` bytes of each packet data.
+    ///
+    /// The port should be configured, and the `RxDump` should be dropped after
+    /// the lcores stopped polling the port, since the callback may be still in use.
+    pub fn new(port_id: PortId, count: usize, dump_len: usize) -> Result<Self> {
+        let mut dump = RxDump {
+            port_id,
+            callbacks: Vec::new(),
+            ctxt: Box::new(RxDumpContext {
+                remaining: AtomicUsize::new(count),
+                dump_len: dump_len as u32,
+            }),
+        };
+
+        for queue_id in 0..port_id.info().nb_rx_queues {
+            // the installed callbacks are removed on error when `dump` is dropped.
+            let cb = unsafe {
+                ffi::rte_eth_add_rx_callback(
+                    port_id.0,
+                    queue_id,
+                    Some(dump_rx_packets),
+                    &*dump.ctxt as *const RxDumpContext as *mut c_void,
+                ) as *mut ffi::rte_eth_rxtx_callback
+            }
+            .as_result()?;
+
+            dump.callbacks.push((queue_id, cb.as_ptr()));
+        }
+
+        Ok(dump)
+    }
+
+    /// The number of the packets to be dumped.
+    pub fn remaining(&self) -> usize {
+        self.ctxt.remaining.load(Ordering::Relaxed)
+    }
+}
+
+unsafe extern "C" fn dump_rx_packets(
+    port_id: u16,
+    queue_id: u16,
+    pkts: *mut *mut ffi::rte_mbuf,
+    nb_pkts: u16,
+    _max_pkts: u16,
+    arg: *mut c_void,
+) -> u16 {
+    let ctxt = &*(arg as *const RxDumpContext);
+    let f = ffi::_rte_stdout();
+    let mut dumped = false;
+
+    for i in 0..nb_pkts as usize {
+        if ctxt
+            .remaining
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+            .is_err()
+        {
+            break;
+        }
+
+        libc::printf(
+            b"port %u queue %u packet:\n\0".as_ptr() as *const c_char,
+            u32::from(port_id),
+            u32::from(queue_id),
+        );
+
+        ffi::rte_pktmbuf_dump(f, *pkts.add(i), ctxt.dump_len);
+
+        dumped = true;
+    }
+
+    if dumped {
+        libc::fflush(f as *mut libc::FILE);
+    }
+
+    nb_pkts
+}
+
 /// Provide notification of a critical non-recoverable error and stop.
 #[macro_export]
 macro_rules! rte_panic {
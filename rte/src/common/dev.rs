@@ -2,15 +2,17 @@
 //!
 //! This file manages the list of device drivers.
 //!
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::mem;
 use std::os::raw::{c_char, c_void};
+use std::thread;
 
 use anyhow::Result;
+use libc;
 
-use errors::AsResult;
+use errors::{AsResult, ErrorKind::OsError};
 use ffi::{self, rte_dev_event_type::*};
-use utils::AsCString;
+use utils::{catch_panic, AsCString};
 
 /// The device event type.
 #[repr(u32)]
@@ -79,7 +81,7 @@ pub fn hotplug_remove(busname: &str, devname: &str) -> Result<()> {
         .map(|_| ())
 }
 
-pub type EventCallback<T> = fn(devname: &str, Event, Option<T>);
+pub type EventCallback<T> = fn(devname: &str, Event, Option<&T>);
 
 struct EventContext<T> {
     callback: EventCallback<T>,
@@ -88,18 +90,63 @@ struct EventContext<T> {
 
 unsafe extern "C" fn event_stub<T>(devname: *const c_char, event: ffi::rte_dev_event_type::Type, arg: *mut c_void) {
     let devname = CStr::from_ptr(devname);
-    let ctxt = Box::from_raw(arg as *mut EventContext<T>);
+    let ctxt = &*(arg as *const EventContext<T>);
 
-    (ctxt.callback)(devname.to_str().unwrap(), mem::transmute(event), ctxt.arg)
+    catch_panic("device event callback", (), || {
+        (ctxt.callback)(devname.to_str().unwrap(), mem::transmute(event), ctxt.arg.as_ref())
+    })
+}
+
+/// The callback registered for the events of a device, which is unregistered when dropped.
+pub struct EventCallbackHandle<T> {
+    devname: CString,
+    ctxt: *mut EventContext<T>,
+}
+
+unsafe impl<T: Send + Sync> Send for EventCallbackHandle<T> {}
+
+impl<T> Drop for EventCallbackHandle<T> {
+    fn drop(&mut self) {
+        loop {
+            let ret = unsafe {
+                ffi::rte_dev_event_callback_unregister(
+                    self.devname.as_ptr(),
+                    Some(event_stub::<T>),
+                    self.ctxt as *mut c_void,
+                )
+            };
+
+            // the callback is being executed by the interrupt thread
+            if ret == -libc::EAGAIN {
+                thread::yield_now();
+            } else {
+                break;
+            }
+        }
+
+        unsafe { drop(Box::from_raw(self.ctxt)) };
+    }
 }
 
 ///  It registers the callback for the specific device.
 ///  Multiple callbacks cal be registered at the same time.
-pub fn event_callback_register<T>(devname: &str, callback: EventCallback<T>, arg: Option<T>) -> Result<()> {
+///
+///  The callback is called in the interrupt thread until the returned handle is dropped.
+pub fn event_callback_register<T: Send + Sync>(
+    devname: &str,
+    callback: EventCallback<T>,
+    arg: Option<T>,
+) -> Result<EventCallbackHandle<T>> {
     let devname = devname.as_cstring();
     let ctxt = Box::into_raw(Box::new(EventContext::<T> { callback, arg }));
 
-    unsafe { ffi::rte_dev_event_callback_register(devname.as_ptr(), Some(event_stub::<T>), ctxt as *mut _) }
-        .as_result()
-        .map(|_| ())
+    let ret = unsafe { ffi::rte_dev_event_callback_register(devname.as_ptr(), Some(event_stub::<T>), ctxt as *mut _) };
+
+    if ret < 0 {
+        unsafe { drop(Box::from_raw(ctxt)) };
+
+        Err(OsError(-ret).into())
+    } else {
+        Ok(EventCallbackHandle { devname, ctxt })
+    }
 }
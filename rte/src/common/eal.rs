@@ -1,15 +1,22 @@
 use std::ffi::CStr;
 use std::fmt;
 use std::mem;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_int};
 use std::path::PathBuf;
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
 use anyhow::Result;
+use libc;
 
 use ffi::{self, rte_proc_type_t::*};
 
-use errors::{AsResult};
+use errors::{os_error, AsResult, ErrorKind};
+use ethdev::{self, EthDevice};
+use launch;
+use lcore;
 use utils::AsCString;
 
 // pub use common::config;
@@ -91,7 +98,7 @@ pub fn iopl_init() -> Result<()> {
 
 /// Initialize the Environment Abstraction Layer (EAL).
 ///
-/// This function is to be executed on the MASTER lcore only,
+/// This function is to be executed on the MAIN lcore only,
 /// as soon as possible in the application's main() function.
 ///
 /// The function finishes the initialization process before main() is called.
@@ -165,3 +172,630 @@ pub fn runtime_dir() -> PathBuf {
             .into_owned()
     })
 }
+
+/// IOVA mapping mode.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum IovaMode {
+    /// DMA using the device default mode.
+    DontCare = ffi::rte_iova_mode::RTE_IOVA_DC,
+    /// DMA using physical address.
+    PhysicalAddress = ffi::rte_iova_mode::RTE_IOVA_PA,
+    /// DMA using virtual address.
+    VirtualAddress = ffi::rte_iova_mode::RTE_IOVA_VA,
+}
+
+/// Get the IOVA mode used by EAL.
+pub fn iova_mode() -> IovaMode {
+    unsafe { mem::transmute(ffi::rte_eal_iova_mode()) }
+}
+
+/// The lcores to run on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Cores {
+    /// The list of lcores to run on, `-l <list>`.
+    List(Vec<u32>),
+    /// The hexadecimal bitmask of the lcores to run on, `-c <mask>`.
+    Mask(u64),
+}
+
+impl Cores {
+    fn contains(&self, lcore_id: u32) -> bool {
+        match *self {
+            Cores::List(ref cores) => cores.contains(&lcore_id),
+            Cores::Mask(mask) => lcore_id < 64 && mask & (1 << lcore_id) != 0,
+        }
+    }
+}
+
+/// The EAL configuration, which will be converted to the EAL arguments.
+#[derive(Clone, Debug, Default)]
+pub struct EalConfig {
+    /// The program name passed as the first argument.
+    pub app_name: Option<String>,
+    /// The lcores to run on.
+    pub cores: Option<Cores>,
+    /// The lcore ID used as the main lcore.
+    pub main_lcore: Option<u32>,
+    /// The number of memory channels per processor socket.
+    pub memory_channels: Option<u32>,
+    /// The amount of memory to preallocate at startup in megabytes.
+    pub memory: Option<usize>,
+    /// The amount of memory to preallocate on each NUMA socket in megabytes.
+    pub socket_mem: Vec<usize>,
+    /// The IOVA mapping mode.
+    pub iova_mode: Option<IovaMode>,
+    /// Use the specified hugetlbfs directory instead of the autodetected ones.
+    pub huge_dir: Option<PathBuf>,
+    /// Use anonymous memory instead of the hugepages.
+    pub no_huge: bool,
+    /// Do not create any shared data structures and run entirely in memory.
+    pub in_memory: bool,
+    /// The prefix for the hugepage filenames, used to run multiple independent DPDK primary/secondary processes.
+    pub file_prefix: Option<String>,
+    /// The type of the current process.
+    pub proc_type: Option<ProcType>,
+    /// The virtual devices to add, in the `<driver><id>[,key=val,...]` format.
+    pub vdevs: Vec<String>,
+    /// The PCI devices to probe, all the other devices will be skipped.
+    pub allow_list: Vec<String>,
+    /// The PCI devices to skip probing.
+    pub block_list: Vec<String>,
+    /// Disable the PCI bus.
+    pub no_pci: bool,
+    /// The log levels, in the `[<type-match>:]<level>` format.
+    pub log_level: Vec<String>,
+    /// The extra arguments passed to EAL as is.
+    pub extra_args: Vec<String>,
+}
+
+macro_rules! invalid_config {
+    ($fmt:expr) => {
+        return Err(ErrorKind::InvalidEalConfig($fmt.to_owned()).into())
+    };
+    ($fmt:expr, $($arg:tt)*) => {
+        return Err(ErrorKind::InvalidEalConfig(format!($fmt, $($arg)*)).into())
+    };
+}
+
+impl EalConfig {
+    /// Validate the configuration before passing it to EAL.
+    pub fn validate(&self) -> Result<()> {
+        match self.cores {
+            Some(Cores::List(ref cores)) => {
+                if cores.is_empty() {
+                    invalid_config!("the lcore list is empty");
+                }
+
+                for (i, &lcore_id) in cores.iter().enumerate() {
+                    if lcore_id >= ffi::RTE_MAX_LCORE {
+                        invalid_config!(
+                            "lcore {} exceeds the maximum lcore {}",
+                            lcore_id,
+                            ffi::RTE_MAX_LCORE - 1
+                        );
+                    }
+                    if cores[..i].contains(&lcore_id) {
+                        invalid_config!("lcore {} is listed more than once", lcore_id);
+                    }
+                }
+            }
+            Some(Cores::Mask(0)) => invalid_config!("the lcore mask is empty"),
+            _ => {}
+        }
+
+        if let Some(main_lcore) = self.main_lcore {
+            if main_lcore >= ffi::RTE_MAX_LCORE {
+                invalid_config!(
+                    "main lcore {} exceeds the maximum lcore {}",
+                    main_lcore,
+                    ffi::RTE_MAX_LCORE - 1
+                );
+            }
+
+            match self.cores {
+                Some(ref cores) if !cores.contains(main_lcore) => {
+                    invalid_config!("main lcore {} is not in the lcores to run on", main_lcore)
+                }
+                _ => {}
+            }
+        }
+
+        if self.memory_channels == Some(0) {
+            invalid_config!("the number of memory channels must be positive");
+        }
+
+        if self.memory.is_some() && !self.socket_mem.is_empty() {
+            invalid_config!("the memory and the per-socket memory can't be used at the same time");
+        }
+
+        if self.socket_mem.len() > ffi::RTE_MAX_NUMA_NODES as usize {
+            invalid_config!(
+                "the per-socket memory is specified for {} sockets, but at most {} sockets are supported",
+                self.socket_mem.len(),
+                ffi::RTE_MAX_NUMA_NODES
+            );
+        }
+
+        if self.no_huge {
+            if !self.socket_mem.is_empty() {
+                invalid_config!("the per-socket memory can't be used without the hugepages");
+            }
+            if self.huge_dir.is_some() {
+                invalid_config!("the hugetlbfs directory can't be used without the hugepages");
+            }
+        }
+
+        if let Some(ref huge_dir) = self.huge_dir {
+            if !huge_dir.is_dir() {
+                invalid_config!("the hugetlbfs directory {:?} doesn't exist", huge_dir);
+            }
+        }
+
+        if self.in_memory && self.proc_type == Some(ProcType::Secondary) {
+            invalid_config!("the in-memory mode can't be used by the secondary process");
+        }
+
+        if self.proc_type == Some(ProcType::Invalid) {
+            invalid_config!("invalid process type");
+        }
+
+        if !self.allow_list.is_empty() && !self.block_list.is_empty() {
+            invalid_config!("the allow list and the block list can't be used at the same time");
+        }
+
+        if self.no_pci && !(self.allow_list.is_empty() && self.block_list.is_empty()) {
+            invalid_config!("the PCI devices can't be allowed or blocked when the PCI bus is disabled");
+        }
+
+        if let Some(vdev) = self
+            .vdevs
+            .iter()
+            .find(|vdev| vdev.split(',').next().unwrap().is_empty())
+        {
+            invalid_config!("the virtual device {:?} has no driver name", vdev);
+        }
+
+        Ok(())
+    }
+
+    /// Convert the configuration to the EAL arguments, including the program name.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.app_name.clone().unwrap_or_else(|| "rte".to_owned())];
+
+        match self.cores {
+            Some(Cores::List(ref cores)) => {
+                args.push("-l".to_owned());
+                args.push(
+                    cores
+                        .iter()
+                        .map(|lcore_id| lcore_id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                );
+            }
+            Some(Cores::Mask(mask)) => {
+                args.push("-c".to_owned());
+                args.push(format!("{:#x}", mask));
+            }
+            None => {}
+        }
+
+        if let Some(main_lcore) = self.main_lcore {
+            if cfg!(feature = "dpdk-19_11") {
+                args.push(format!("--master-lcore={}", main_lcore));
+            } else {
+                args.push(format!("--main-lcore={}", main_lcore));
+            }
+        }
+        if let Some(channels) = self.memory_channels {
+            args.push("-n".to_owned());
+            args.push(channels.to_string());
+        }
+        if let Some(memory) = self.memory {
+            args.push("-m".to_owned());
+            args.push(memory.to_string());
+        }
+        if !self.socket_mem.is_empty() {
+            args.push(format!(
+                "--socket-mem={}",
+                self.socket_mem
+                    .iter()
+                    .map(|mem| mem.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ));
+        }
+        match self.iova_mode {
+            Some(IovaMode::PhysicalAddress) => args.push("--iova-mode=pa".to_owned()),
+            Some(IovaMode::VirtualAddress) => args.push("--iova-mode=va".to_owned()),
+            _ => {}
+        }
+        if let Some(ref huge_dir) = self.huge_dir {
+            args.push(format!("--huge-dir={}", huge_dir.display()));
+        }
+        if self.no_huge {
+            args.push("--no-huge".to_owned());
+        }
+        if self.in_memory {
+            args.push("--in-memory".to_owned());
+        }
+        if let Some(ref prefix) = self.file_prefix {
+            args.push(format!("--file-prefix={}", prefix));
+        }
+        match self.proc_type {
+            Some(ProcType::Auto) => args.push("--proc-type=auto".to_owned()),
+            Some(ProcType::Primary) => args.push("--proc-type=primary".to_owned()),
+            Some(ProcType::Secondary) => args.push("--proc-type=secondary".to_owned()),
+            _ => {}
+        }
+        for vdev in &self.vdevs {
+            args.push(format!("--vdev={}", vdev));
+        }
+        for dev in &self.allow_list {
+            args.push(if cfg!(feature = "dpdk-19_11") { "-w" } else { "-a" }.to_owned());
+            args.push(dev.clone());
+        }
+        for dev in &self.block_list {
+            args.push("-b".to_owned());
+            args.push(dev.clone());
+        }
+        if self.no_pci {
+            args.push("--no-pci".to_owned());
+        }
+        for level in &self.log_level {
+            args.push(format!("--log-level={}", level));
+        }
+
+        args.extend(self.extra_args.iter().cloned());
+
+        args
+    }
+}
+
+/// Initialize the Environment Abstraction Layer (EAL) with the typed configuration.
+///
+/// The configuration is validated before initializing EAL,
+/// see `init()` for the arguments based initialization.
+pub fn init_with(conf: &EalConfig) -> Result<i32> {
+    conf.validate()?;
+
+    init(&conf.to_args())
+}
+
+/// The builder of the EAL configuration, which initializes EAL with the typed settings.
+///
+/// ```no_run
+/// use rte::eal;
+///
+/// eal::Builder::new()
+///     .core_list(&[0, 1, 2])
+///     .memory_channels(4)
+///     .vdev("net_null0")
+///     .no_pci()
+///     .init()
+///     .expect("fail to initialize EAL");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    conf: EalConfig,
+}
+
+impl From<EalConfig> for Builder {
+    fn from(conf: EalConfig) -> Self {
+        Builder { conf }
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// The program name passed as the first argument.
+    pub fn app_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.conf.app_name = Some(name.into());
+        self
+    }
+
+    /// The list of lcores to run on.
+    pub fn core_list(mut self, cores: &[u32]) -> Self {
+        self.conf.cores = Some(Cores::List(cores.to_vec()));
+        self
+    }
+
+    /// The hexadecimal bitmask of the lcores to run on.
+    pub fn core_mask(mut self, mask: u64) -> Self {
+        self.conf.cores = Some(Cores::Mask(mask));
+        self
+    }
+
+    /// The lcore ID used as the main lcore.
+    pub fn main_lcore(mut self, lcore_id: u32) -> Self {
+        self.conf.main_lcore = Some(lcore_id);
+        self
+    }
+
+    /// The number of memory channels per processor socket.
+    pub fn memory_channels(mut self, channels: u32) -> Self {
+        self.conf.memory_channels = Some(channels);
+        self
+    }
+
+    /// The amount of memory to preallocate at startup in megabytes.
+    pub fn memory(mut self, megabytes: usize) -> Self {
+        self.conf.memory = Some(megabytes);
+        self
+    }
+
+    /// The amount of memory to preallocate on each NUMA socket in megabytes.
+    pub fn socket_mem(mut self, megabytes: &[usize]) -> Self {
+        self.conf.socket_mem = megabytes.to_vec();
+        self
+    }
+
+    /// The IOVA mapping mode.
+    pub fn iova_mode(mut self, mode: IovaMode) -> Self {
+        self.conf.iova_mode = Some(mode);
+        self
+    }
+
+    /// Use the hugetlbfs directory instead of the autodetected ones.
+    pub fn huge_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.conf.huge_dir = Some(dir.into());
+        self
+    }
+
+    /// Use anonymous memory instead of the hugepages.
+    pub fn no_huge(mut self) -> Self {
+        self.conf.no_huge = true;
+        self
+    }
+
+    /// Do not create any shared data structures and run entirely in memory.
+    pub fn in_memory(mut self) -> Self {
+        self.conf.in_memory = true;
+        self
+    }
+
+    /// The prefix for the hugepage filenames.
+    pub fn file_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.conf.file_prefix = Some(prefix.into());
+        self
+    }
+
+    /// The type of the current process.
+    pub fn proc_type(mut self, proc_type: ProcType) -> Self {
+        self.conf.proc_type = Some(proc_type);
+        self
+    }
+
+    /// Add a virtual device in the `<driver><id>[,key=val,...]` format.
+    pub fn vdev<S: Into<String>>(mut self, vdev: S) -> Self {
+        self.conf.vdevs.push(vdev.into());
+        self
+    }
+
+    /// Add a PCI device to probe, all the other devices will be skipped.
+    pub fn allow<S: Into<String>>(mut self, dev: S) -> Self {
+        self.conf.allow_list.push(dev.into());
+        self
+    }
+
+    /// Add a PCI device to skip probing.
+    pub fn block<S: Into<String>>(mut self, dev: S) -> Self {
+        self.conf.block_list.push(dev.into());
+        self
+    }
+
+    /// Disable the PCI bus.
+    pub fn no_pci(mut self) -> Self {
+        self.conf.no_pci = true;
+        self
+    }
+
+    /// Add a log level in the `[<type-match>:]<level>` format.
+    pub fn log_level<S: Into<String>>(mut self, level: S) -> Self {
+        self.conf.log_level.push(level.into());
+        self
+    }
+
+    /// Add an extra argument passed to EAL as is.
+    pub fn arg<S: Into<String>>(mut self, arg: S) -> Self {
+        self.conf.extra_args.push(arg.into());
+        self
+    }
+
+    /// Validate and return the configuration.
+    pub fn build(self) -> Result<EalConfig> {
+        self.conf.validate()?;
+
+        Ok(self.conf)
+    }
+
+    /// Initialize EAL with the configuration.
+    pub fn init(self) -> Result<i32> {
+        init_with(&self.conf)
+    }
+}
+
+static STOP: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref EXIT_HANDLERS: Mutex<Vec<Box<dyn FnOnce() + Send>>> = Mutex::new(Vec::new());
+}
+
+/// Check if the application has been requested to stop, e.g. by SIGINT or SIGTERM.
+///
+/// The main loops should check it periodically, and return when it becomes true.
+#[inline]
+pub fn should_stop() -> bool {
+    STOP.load(Ordering::Relaxed)
+}
+
+/// Request the main loops to stop.
+pub fn request_stop() {
+    STOP.store(true, Ordering::Relaxed)
+}
+
+/// Register a function to release a resource when `run()` exits.
+///
+/// The functions are called in the reverse order of the registration, after all the ports have been closed.
+pub fn at_exit<F: FnOnce() + Send + 'static>(f: F) {
+    EXIT_HANDLERS.lock().unwrap().push(Box::new(f))
+}
+
+extern "C" fn handle_stop_signal(_signum: c_int) {
+    request_stop()
+}
+
+/// Install the SIGINT and SIGTERM handlers, which request the main loops to stop.
+pub fn handle_stop_signals() -> Result<()> {
+    let handler = handle_stop_signal as extern "C" fn(c_int) as libc::sighandler_t;
+
+    for &signum in &[libc::SIGINT, libc::SIGTERM] {
+        if unsafe { libc::signal(signum, handler) } == libc::SIG_ERR {
+            return Err(os_error().into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the main loop on all the lcores until it returns, e.g. after `should_stop()` becomes true.
+///
+/// The SIGINT and SIGTERM handlers are installed before launching the main loop.
+/// After all the lcores finish, the ports are stopped and closed,
+/// and the resources registered by `at_exit()` are released.
+///
+/// Return the first non-zero value returned by the main loops.
+///
+/// To be executed on the MAIN lcore only.
+pub fn run<F>(main_loop: F) -> Result<i32>
+where
+    F: Fn(lcore::Id) -> i32 + Sync,
+{
+    handle_stop_signals()?;
+
+    let workers: Vec<_> = lcore::enabled().into_iter().filter(|id| !id.is_main()).collect();
+    let mut launched = Ok(());
+    let mut ret = 0;
+
+    // the launched lcores are joined before the scope returns, even if the main loop panics
+    let results = launch::scope(|s| {
+        for &worker_id in &workers {
+            let main_loop = &main_loop;
+
+            if let Err(err) = s.spawn_on(worker_id, move || main_loop(worker_id)) {
+                // the launched lcores should stop before returning the error
+                request_stop();
+
+                launched = Err(err);
+                return;
+            }
+        }
+
+        let _guard = StopOnUnwind;
+
+        ret = main_loop(lcore::main());
+    });
+
+    for result in results {
+        match result {
+            Ok(code) if ret == 0 => ret = code,
+            Ok(_) => {}
+            Err(err) => {
+                error!("{}", err);
+
+                if ret == 0 {
+                    ret = -1;
+                }
+            }
+        }
+    }
+
+    shutdown();
+
+    launched.map(|_| ret)
+}
+
+// Request the worker lcores to stop if the main loop panics, so they can be joined while unwinding.
+struct StopOnUnwind;
+
+impl Drop for StopOnUnwind {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            request_stop()
+        }
+    }
+}
+
+// Stop and close all the ports, then release the registered resources.
+fn shutdown() {
+    for port_id in ethdev::devices().filter(|port_id| port_id.is_valid()) {
+        debug!("closing port {}", port_id);
+
+        if let Err(err) = port_id.stop().close() {
+            warn!("fail to close port {}, {}", port_id, err);
+        }
+    }
+
+    let handlers = mem::take(&mut *EXIT_HANDLERS.lock().unwrap());
+
+    for f in handlers.into_iter().rev() {
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let conf = Builder::new()
+            .app_name("test")
+            .core_list(&[0, 2])
+            .main_lcore(2)
+            .memory_channels(4)
+            .socket_mem(&[1024, 512])
+            .iova_mode(IovaMode::VirtualAddress)
+            .proc_type(ProcType::Primary)
+            .vdev("net_null0")
+            .allow("0000:02:00.0")
+            .log_level("pmd.net.*:debug")
+            .arg("--no-telemetry")
+            .build()
+            .unwrap();
+
+        let main_lcore = if cfg!(feature = "dpdk-19_11") {
+            "--master-lcore=2"
+        } else {
+            "--main-lcore=2"
+        };
+        let allow = if cfg!(feature = "dpdk-19_11") { "-w" } else { "-a" };
+
+        assert_eq!(
+            conf.to_args(),
+            vec![
+                "test",
+                "-l",
+                "0,2",
+                main_lcore,
+                "-n",
+                "4",
+                "--socket-mem=1024,512",
+                "--iova-mode=va",
+                "--proc-type=primary",
+                "--vdev=net_null0",
+                allow,
+                "0000:02:00.0",
+                "--log-level=pmd.net.*:debug",
+                "--no-telemetry",
+            ]
+        );
+
+        assert!(Builder::new().core_list(&[0]).main_lcore(1).build().is_err());
+        assert!(Builder::new().allow("0000:02:00.0").no_pci().build().is_err());
+    }
+}
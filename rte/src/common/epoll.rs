@@ -0,0 +1,372 @@
+//!
+//! An event loop over the RTE epoll API.
+//!
+//! The loop multiplexes the RX queue interrupts, the timers and the user file descriptors
+//! on a single epoll instance, and dispatches the events to the registered closures.
+//!
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::RawFd;
+use std::ptr;
+use std::time::Duration;
+
+use anyhow::Result;
+use libc;
+
+use errors::{os_error, RteError};
+use ffi;
+
+/// The identifier of a source registered on the event loop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Token(usize);
+
+bitflags! {
+    /// The readiness a user file descriptor is interested in.
+    pub struct Interest: u32 {
+        const READABLE = libc::EPOLLIN as u32;
+        const WRITABLE = libc::EPOLLOUT as u32;
+        const PRIORITY = libc::EPOLLPRI as u32;
+        const EDGE_TRIGGERED = libc::EPOLLET as u32;
+    }
+}
+
+enum Source {
+    RxQueue {
+        port_id: u16,
+        queue_id: u16,
+        callback: Box<dyn FnMut(u16, u16)>,
+    },
+    Fd {
+        fd: RawFd,
+        // the event is referenced by the epoll instance until it is deleted.
+        event: Box<ffi::rte_epoll_event>,
+        callback: Box<dyn FnMut(RawFd)>,
+    },
+    Timer {
+        fd: RawFd,
+        event: Box<ffi::rte_epoll_event>,
+        callback: Box<dyn FnMut(u64)>,
+    },
+}
+
+/// An event loop dispatching the RX queue interrupts, the timers and the user file descriptors.
+pub struct EventLoop {
+    epfd: RawFd,
+    sources: Vec<Option<Source>>,
+    events: Vec<ffi::rte_epoll_event>,
+}
+
+impl Drop for EventLoop {
+    fn drop(&mut self) {
+        for idx in 0..self.sources.len() {
+            let _ = self.remove(Token(idx));
+        }
+
+        unsafe {
+            libc::close(self.epfd);
+        }
+    }
+}
+
+impl EventLoop {
+    /// Create an event loop, which can dispatch at most `max_events` events in each round.
+    pub fn new(max_events: usize) -> Result<Self> {
+        let epfd = unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) };
+
+        if epfd < 0 {
+            return Err(os_error().into());
+        }
+
+        Ok(EventLoop {
+            epfd,
+            sources: vec![],
+            events: vec![unsafe { mem::zeroed() }; max_events.max(1)],
+        })
+    }
+
+    /// The file descriptor of the epoll instance.
+    pub fn as_raw_fd(&self) -> RawFd {
+        self.epfd
+    }
+
+    /// Register the interrupt of an RX queue, the callback is called with the port and queue ID.
+    ///
+    /// The RX interrupt must be enabled with `intr_conf.rxq` when configuring the device.
+    /// The interrupt is disabled while the callback runs, so the callback should drain the queue.
+    pub fn add_rx_queue<F>(&mut self, port_id: u16, queue_id: u16, callback: F) -> Result<Token>
+    where
+        F: FnMut(u16, u16) + 'static,
+    {
+        let token = self.next_token();
+        let ret = unsafe {
+            ffi::rte_eth_dev_rx_intr_ctl_q(
+                port_id,
+                queue_id,
+                self.epfd,
+                ffi::RTE_INTR_EVENT_ADD as c_int,
+                token_data(token),
+            )
+        };
+
+        if ret != 0 {
+            return Err(RteError(ret).into());
+        }
+
+        let ret = unsafe { ffi::rte_eth_dev_rx_intr_enable(port_id, queue_id) };
+
+        if ret != 0 {
+            unsafe {
+                ffi::rte_eth_dev_rx_intr_ctl_q(
+                    port_id,
+                    queue_id,
+                    self.epfd,
+                    ffi::RTE_INTR_EVENT_DEL as c_int,
+                    ptr::null_mut(),
+                );
+            }
+
+            return Err(RteError(ret).into());
+        }
+
+        Ok(self.insert(
+            token,
+            Source::RxQueue {
+                port_id,
+                queue_id,
+                callback: Box::new(callback),
+            },
+        ))
+    }
+
+    /// Register a user file descriptor, the callback is called with the file descriptor when it is ready.
+    ///
+    /// The file descriptor is still owned by the caller, and must outlive the registration.
+    pub fn add_fd<F>(&mut self, fd: RawFd, interest: Interest, callback: F) -> Result<Token>
+    where
+        F: FnMut(RawFd) + 'static,
+    {
+        let token = self.next_token();
+        let event = self.ctl_add(fd, interest, token)?;
+
+        Ok(self.insert(
+            token,
+            Source::Fd {
+                fd,
+                event,
+                callback: Box::new(callback),
+            },
+        ))
+    }
+
+    /// Register a timer which expires after `delay`, and then every `interval` if it isn't zero.
+    ///
+    /// The callback is called with the number of expirations since it was last called.
+    pub fn add_timer<F>(&mut self, delay: Duration, interval: Duration, callback: F) -> Result<Token>
+    where
+        F: FnMut(u64) + 'static,
+    {
+        let fd = unsafe { libc::timerfd_create(libc::CLOCK_MONOTONIC, libc::TFD_NONBLOCK | libc::TFD_CLOEXEC) };
+
+        if fd < 0 {
+            return Err(os_error().into());
+        }
+
+        // a zero delay would disarm the timer
+        let delay = if delay == Duration::from_secs(0) {
+            Duration::from_nanos(1)
+        } else {
+            delay
+        };
+        let spec = libc::itimerspec {
+            it_interval: timespec(interval),
+            it_value: timespec(delay),
+        };
+
+        if unsafe { libc::timerfd_settime(fd, 0, &spec, ptr::null_mut()) } < 0 {
+            let err = os_error();
+
+            unsafe {
+                libc::close(fd);
+            }
+
+            return Err(err.into());
+        }
+
+        let token = self.next_token();
+        let event = match self.ctl_add(fd, Interest::READABLE, token) {
+            Ok(event) => event,
+            Err(err) => {
+                unsafe {
+                    libc::close(fd);
+                }
+
+                return Err(err);
+            }
+        };
+
+        Ok(self.insert(
+            token,
+            Source::Timer {
+                fd,
+                event,
+                callback: Box::new(callback),
+            },
+        ))
+    }
+
+    /// Remove a registered source, the timer will be closed.
+    pub fn remove(&mut self, token: Token) -> Result<()> {
+        let source = match self.sources.get_mut(token.0).and_then(Option::take) {
+            Some(source) => source,
+            None => return Ok(()),
+        };
+
+        match source {
+            Source::RxQueue { port_id, queue_id, .. } => {
+                let ret = unsafe {
+                    ffi::rte_eth_dev_rx_intr_disable(port_id, queue_id);
+                    ffi::rte_eth_dev_rx_intr_ctl_q(
+                        port_id,
+                        queue_id,
+                        self.epfd,
+                        ffi::RTE_INTR_EVENT_DEL as c_int,
+                        ptr::null_mut(),
+                    )
+                };
+
+                if ret != 0 {
+                    return Err(RteError(ret).into());
+                }
+            }
+            Source::Fd { fd, mut event, .. } => {
+                if unsafe { ffi::rte_epoll_ctl(self.epfd, libc::EPOLL_CTL_DEL, fd, &mut *event) } != 0 {
+                    return Err(os_error().into());
+                }
+            }
+            Source::Timer { fd, mut event, .. } => {
+                let ret = unsafe { ffi::rte_epoll_ctl(self.epfd, libc::EPOLL_CTL_DEL, fd, &mut *event) };
+                let err = os_error();
+
+                unsafe {
+                    libc::close(fd);
+                }
+
+                if ret != 0 {
+                    return Err(err.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Test if the event loop has no registered sources.
+    pub fn is_empty(&self) -> bool {
+        self.sources.iter().all(Option::is_none)
+    }
+
+    /// Wait for the events and dispatch them, return the number of dispatched events.
+    ///
+    /// Wait forever if `timeout` is `None`.
+    pub fn run_once(&mut self, timeout: Option<Duration>) -> Result<usize> {
+        let timeout = timeout.map_or(-1, |d| d.as_millis().min(c_int::max_value() as u128) as c_int);
+        let n =
+            unsafe { ffi::rte_epoll_wait(self.epfd, self.events.as_mut_ptr(), self.events.len() as c_int, timeout) };
+
+        if n < 0 {
+            return Err(os_error().into());
+        }
+
+        for i in 0..n as usize {
+            let idx = (self.events[i].epdata.data as usize).wrapping_sub(1);
+
+            match self.sources.get_mut(idx) {
+                Some(&mut Some(Source::RxQueue {
+                    port_id,
+                    queue_id,
+                    ref mut callback,
+                })) => unsafe {
+                    ffi::rte_eth_dev_rx_intr_disable(port_id, queue_id);
+
+                    callback(port_id, queue_id);
+
+                    ffi::rte_eth_dev_rx_intr_enable(port_id, queue_id);
+                },
+                Some(&mut Some(Source::Fd {
+                    fd, ref mut callback, ..
+                })) => callback(fd),
+                Some(&mut Some(Source::Timer {
+                    fd, ref mut callback, ..
+                })) => {
+                    let mut expirations = 0u64;
+
+                    let ret =
+                        unsafe { libc::read(fd, &mut expirations as *mut u64 as *mut c_void, mem::size_of::<u64>()) };
+
+                    if ret == mem::size_of::<u64>() as isize {
+                        callback(expirations);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(n as usize)
+    }
+
+    /// Dispatch the events until `cond` returns `false` or no source is registered.
+    pub fn run_while<F>(&mut self, timeout: Option<Duration>, mut cond: F) -> Result<()>
+    where
+        F: FnMut() -> bool,
+    {
+        while cond() && !self.is_empty() {
+            self.run_once(timeout)?;
+        }
+
+        Ok(())
+    }
+
+    fn next_token(&self) -> Token {
+        Token(
+            self.sources
+                .iter()
+                .position(Option::is_none)
+                .unwrap_or_else(|| self.sources.len()),
+        )
+    }
+
+    fn insert(&mut self, token: Token, source: Source) -> Token {
+        if token.0 == self.sources.len() {
+            self.sources.push(Some(source));
+        } else {
+            self.sources[token.0] = Some(source);
+        }
+
+        token
+    }
+
+    fn ctl_add(&self, fd: RawFd, interest: Interest, token: Token) -> Result<Box<ffi::rte_epoll_event>> {
+        let mut event: Box<ffi::rte_epoll_event> = Box::new(unsafe { mem::zeroed() });
+
+        event.epdata.event = interest.bits;
+        event.epdata.data = token_data(token);
+
+        if unsafe { ffi::rte_epoll_ctl(self.epfd, libc::EPOLL_CTL_ADD, fd, &mut *event) } != 0 {
+            Err(os_error().into())
+        } else {
+            Ok(event)
+        }
+    }
+}
+
+// the user data is never null, which can't be distinguished from the unset data.
+fn token_data(token: Token) -> *mut c_void {
+    (token.0 + 1) as *mut c_void
+}
+
+fn timespec(d: Duration) -> libc::timespec {
+    libc::timespec {
+        tv_sec: d.as_secs() as libc::time_t,
+        tv_nsec: d.subsec_nanos() as libc::c_long,
+    }
+}
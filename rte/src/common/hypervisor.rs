@@ -0,0 +1,50 @@
+//! Hypervisor detection
+
+use std::ffi::CStr;
+use std::fmt;
+
+use num_traits::FromPrimitive;
+
+use ffi;
+
+/// The hypervisor the application is running on.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum Hypervisor {
+    /// Running on the bare metal.
+    None = ffi::rte_hypervisor::RTE_HYPERVISOR_NONE,
+    Kvm = ffi::rte_hypervisor::RTE_HYPERVISOR_KVM,
+    HyperV = ffi::rte_hypervisor::RTE_HYPERVISOR_HYPERV,
+    VMware = ffi::rte_hypervisor::RTE_HYPERVISOR_VMWARE,
+    Unknown = ffi::rte_hypervisor::RTE_HYPERVISOR_UNKNOWN,
+}
+
+impl Hypervisor {
+    /// Get the hypervisor it is running on.
+    pub fn get() -> Self {
+        Hypervisor::from_u32(unsafe { ffi::rte_hypervisor_get() }).unwrap_or(Hypervisor::Unknown)
+    }
+
+    /// Get the name of the hypervisor.
+    pub fn name(self) -> &'static str {
+        unsafe { CStr::from_ptr(ffi::rte_hypervisor_get_name(self as u32)) }
+            .to_str()
+            .unwrap_or("unknown")
+    }
+
+    /// Test if it is running on a virtual machine.
+    pub fn is_virtual(self) -> bool {
+        self != Hypervisor::None
+    }
+}
+
+impl fmt::Display for Hypervisor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Get the hypervisor it is running on.
+pub fn hypervisor() -> Hypervisor {
+    Hypervisor::get()
+}
@@ -0,0 +1,134 @@
+//!
+//! RTE Interrupt
+//!
+//! The interrupt callbacks are called by the EAL interrupt thread when the file descriptor of the handle is readable,
+//! and the RX queue interrupts can be waited on an epoll instance instead of busy polling the queues.
+//!
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::RawFd;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use libc;
+
+use errors::{os_error, ErrorKind::OsError};
+use ffi;
+use utils::catch_panic;
+
+pub type RawIntrHandle = ffi::rte_intr_handle;
+pub type RawEpollEvent = ffi::rte_epoll_event;
+
+/// Use the epoll instance of the current thread.
+pub const EPOLL_PER_THREAD: RawFd = ffi::RTE_EPOLL_PER_THREAD;
+
+/// The operation of the interrupt event on an epoll instance.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntrOp {
+    Add = ffi::RTE_INTR_EVENT_ADD,
+    Del = ffi::RTE_INTR_EVENT_DEL,
+}
+
+/// The interrupt handle of an external file descriptor, which is still owned by the caller.
+pub struct IntrHandle(Box<RawIntrHandle>);
+
+impl IntrHandle {
+    /// Create an interrupt handle for the file descriptor, e.g. an eventfd or a timerfd.
+    pub fn from_fd(fd: RawFd) -> Self {
+        let mut handle: Box<RawIntrHandle> = Box::new(unsafe { mem::zeroed() });
+
+        handle.__bindgen_anon_1.__bindgen_anon_1.fd = fd;
+        handle.type_ = ffi::rte_intr_handle_type::RTE_INTR_HANDLE_EXT;
+
+        IntrHandle(handle)
+    }
+
+    /// The file descriptor of the interrupt handle.
+    pub fn fd(&self) -> RawFd {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.fd }
+    }
+
+    /// Register a callback which is called by the interrupt thread when the file descriptor is readable.
+    ///
+    /// The callback must consume the event, otherwise it will be called again immediately.
+    pub fn callback_register<F>(&self, callback: F) -> Result<IntrCallback>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let ctx = Box::into_raw(Box::new(Box::new(callback) as IntrCallbackFn));
+
+        let ret = unsafe { ffi::rte_intr_callback_register(&*self.0, Some(intr_callback_stub), ctx as *mut _) };
+
+        if ret == 0 {
+            Ok(IntrCallback { handle: self, ctx })
+        } else {
+            unsafe { drop(Box::from_raw(ctx)) };
+
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Enable the interrupts of the handle.
+    pub fn enable(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_intr_enable(&*self.0) }; ok => { self })
+    }
+
+    /// Disable the interrupts of the handle.
+    pub fn disable(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_intr_disable(&*self.0) }; ok => { self })
+    }
+}
+
+type IntrCallbackFn = Box<dyn FnMut() + Send>;
+
+unsafe extern "C" fn intr_callback_stub(arg: *mut c_void) {
+    let callback = &mut *(arg as *mut IntrCallbackFn);
+
+    catch_panic("interrupt callback", (), callback)
+}
+
+/// A registered interrupt callback, which is unregistered when dropped.
+pub struct IntrCallback<'a> {
+    handle: &'a IntrHandle,
+    ctx: *mut IntrCallbackFn,
+}
+
+impl<'a> Drop for IntrCallback<'a> {
+    fn drop(&mut self) {
+        loop {
+            let ret = unsafe {
+                ffi::rte_intr_callback_unregister(&*self.handle.0, Some(intr_callback_stub), self.ctx as *mut _)
+            };
+
+            // the callback is being executed by the interrupt thread
+            if ret == -libc::EAGAIN {
+                thread::yield_now();
+            } else {
+                break;
+            }
+        }
+
+        unsafe { drop(Box::from_raw(self.ctx)) };
+    }
+}
+
+/// The epoll instance of the current thread, which is created on the first use.
+pub fn thread_epfd() -> RawFd {
+    unsafe { ffi::rte_intr_tls_epfd() }
+}
+
+/// Wait for the events on the epoll instance, return the ready events at the head of `events`.
+///
+/// Wait forever if `timeout` is `None`.
+pub fn epoll_wait(epfd: RawFd, events: &mut [RawEpollEvent], timeout: Option<Duration>) -> Result<&[RawEpollEvent]> {
+    let timeout = timeout.map_or(-1, |d| d.as_millis().min(c_int::max_value() as u128) as c_int);
+    let n = unsafe { ffi::rte_epoll_wait(epfd, events.as_mut_ptr(), events.len() as c_int, timeout) };
+
+    if n < 0 {
+        Err(os_error().into())
+    } else {
+        Ok(&events[..n as usize])
+    }
+}
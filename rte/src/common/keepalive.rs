@@ -1,6 +1,7 @@
 use std::mem;
 use std::os::raw::{c_int, c_void};
 use std::ptr::{self, NonNull};
+use std::sync::Mutex;
 
 use anyhow::Result;
 
@@ -10,7 +11,7 @@ use ffi::{
     rte_keepalive_state::{self, *},
 };
 use lcore;
-use utils::AsRaw;
+use utils::{catch_panic, AsRaw};
 
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
@@ -34,45 +35,70 @@ impl From<rte_keepalive_state::Type> for State {
 ///
 /// Receives a data pointer passed to rte_keepalive_create() and the id of the
 /// failed core.
-pub type FailureCallback<T> = fn(Option<T>, lcore::Id);
+pub type FailureCallback<T> = fn(Option<&T>, lcore::Id);
 
 /// Keepalive relay callback.
 ///
 ///  Receives a data pointer passed to rte_keepalive_register_relay_callback(),
 ///  the id of the core for which state is to be forwarded, and details of the
 ///  current core state.
-pub type RelayCallback<T> = fn(Option<T>, lcore::Id, State, u64);
+pub type RelayCallback<T> = fn(Option<&T>, lcore::Id, State, u64);
 
 pub type RawKeepalive = ffi::rte_keepalive;
 pub type RawKeepalivePtr = *mut ffi::rte_keepalive;
 
-#[repr(transparent)]
 #[derive(Debug)]
-pub struct Keepalive(NonNull<RawKeepalive>);
+pub struct Keepalive {
+    raw: NonNull<RawKeepalive>,
+    // The contexts of the callbacks, which may be called until the keepalive is dropped.
+    contexts: Mutex<Vec<(*mut c_void, unsafe fn(*mut c_void))>>,
+}
+
+// Each lcore marks itself alive in its own slot.
+unsafe impl Send for Keepalive {}
+unsafe impl Sync for Keepalive {}
+
+impl Drop for Keepalive {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_free(self.as_raw_mut() as *mut _) };
+
+        for (ctxt, free) in self.contexts.get_mut().unwrap().drain(..) {
+            unsafe { free(ctxt) }
+        }
+    }
+}
 
 impl AsRaw for Keepalive {
     type Raw = RawKeepalive;
 
     fn as_raw(&self) -> *const Self::Raw {
-        self.0.as_ptr()
+        self.raw.as_ptr()
     }
 
     fn as_raw_mut(&self) -> *mut Self::Raw {
-        self.0.as_ptr()
+        self.raw.as_ptr()
     }
 }
 
-pub fn create<T>(callback: FailureCallback<T>, arg: Option<T>) -> Result<Keepalive> {
+pub fn create<T: Send + Sync>(callback: FailureCallback<T>, arg: Option<T>) -> Result<Keepalive> {
     Keepalive::new(callback, arg)
 }
 
 impl Keepalive {
-    pub fn new<T>(callback: FailureCallback<T>, arg: Option<T>) -> Result<Self> {
+    pub fn new<T: Send + Sync>(callback: FailureCallback<T>, arg: Option<T>) -> Result<Self> {
         let ctxt = Box::into_raw(Box::new(FailureContext { callback, arg }));
 
         unsafe { ffi::rte_keepalive_create(Some(failure_stub::<T>), ctxt as *mut _) }
             .as_result()
-            .map(Keepalive)
+            .map(|raw| Keepalive {
+                raw,
+                contexts: Mutex::new(vec![(ctxt as *mut _, free_context::<FailureContext<T>>)]),
+            })
+            .map_err(|err| {
+                unsafe { free_context::<FailureContext<T>>(ctxt as *mut _) };
+
+                err
+            })
     }
 
     /// Checks & handles keepalive state of monitored cores.
@@ -106,10 +132,16 @@ impl Keepalive {
     /// The complement of the 'dead core' callback. This is called when a
     /// core is known to be alive, and is intended for cases when an app
     /// needs to know 'liveness' beyond just knowing when a core has died.
-    pub fn register_relay_callback<T>(&self, callback: RelayCallback<T>, arg: Option<T>) {
+    pub fn register_relay_callback<T: Send + Sync>(&self, callback: RelayCallback<T>, arg: Option<T>) {
         let ctxt = Box::into_raw(Box::new(RelayContext { callback, arg }));
 
         unsafe { ffi::rte_keepalive_register_relay_callback(self.as_raw_mut(), Some(relay_stub::<T>), ctxt as *mut _) }
+
+        // the replaced relay callback may still be running in `dispatch_pings()`
+        self.contexts
+            .lock()
+            .unwrap()
+            .push((ctxt as *mut _, free_context::<RelayContext<T>>));
     }
 }
 
@@ -119,9 +151,11 @@ struct FailureContext<T> {
 }
 
 unsafe extern "C" fn failure_stub<T>(data: *mut c_void, id_core: c_int) {
-    let ctxt = Box::from_raw(data as *mut FailureContext<T>);
+    let ctxt = &*(data as *const FailureContext<T>);
 
-    (ctxt.callback)(ctxt.arg, lcore::id(id_core as u32))
+    catch_panic("keepalive failure callback", (), || {
+        (ctxt.callback)(ctxt.arg.as_ref(), lcore::id(id_core as u32))
+    })
 }
 
 struct RelayContext<T> {
@@ -135,7 +169,18 @@ unsafe extern "C" fn relay_stub<T>(
     core_state: rte_keepalive_state::Type,
     last_seen: u64,
 ) {
-    let ctxt = Box::from_raw(data as *mut RelayContext<T>);
+    let ctxt = &*(data as *const RelayContext<T>);
+
+    catch_panic("keepalive relay callback", (), || {
+        (ctxt.callback)(
+            ctxt.arg.as_ref(),
+            lcore::id(id_core as u32),
+            core_state.into(),
+            last_seen,
+        )
+    })
+}
 
-    (ctxt.callback)(ctxt.arg, lcore::id(id_core as u32), core_state.into(), last_seen)
+unsafe fn free_context<C>(ctxt: *mut c_void) {
+    drop(Box::from_raw(ctxt as *mut C))
 }
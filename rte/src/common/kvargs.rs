@@ -0,0 +1,274 @@
+//!
+//! RTE Argument parsing
+//!
+//! The key/value arguments in the format of `key1=value1,key2=value2,...`,
+//! which are used by the PMDs to parse the device arguments, e.g. `net_pcap0,iface=eth0`.
+//!
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::{self, NonNull};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+
+use ffi;
+use utils::{catch_panic, AsCString, AsRaw};
+
+/// The maximum number of the key/value pairs.
+pub const MAX: usize = ffi::RTE_KVARGS_MAX as usize;
+
+pub type RawKvArgs = ffi::rte_kvargs;
+
+/// A list of the key/value pairs.
+#[derive(Debug)]
+pub struct KvArgs(NonNull<RawKvArgs>);
+
+unsafe impl Send for KvArgs {}
+unsafe impl Sync for KvArgs {}
+
+impl Drop for KvArgs {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_kvargs_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for KvArgs {
+    type Raw = RawKvArgs;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl FromStr for KvArgs {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        KvArgs::parse(s, None)
+    }
+}
+
+fn key_ptrs(keys: &[&str]) -> (Vec<CString>, Vec<*const c_char>) {
+    let keys = keys.iter().map(|key| key.as_cstring()).collect::<Vec<_>>();
+    let ptrs = keys
+        .iter()
+        .map(|key| key.as_ptr())
+        .chain(Some(ptr::null()))
+        .collect::<Vec<_>>();
+
+    (keys, ptrs)
+}
+
+fn as_str<'a>(s: *const c_char) -> &'a str {
+    if s.is_null() {
+        ""
+    } else {
+        unsafe { CStr::from_ptr(s) }.to_str().unwrap()
+    }
+}
+
+impl KvArgs {
+    /// Parse the key/value pairs, which fails if any key is not in `valid_keys`.
+    pub fn parse<S: AsRef<str>>(args: S, valid_keys: Option<&[&str]>) -> Result<Self> {
+        let args = args.as_ref().as_cstring();
+        let (_keys, ptrs) = valid_keys.map(key_ptrs).unwrap_or_default();
+
+        let p =
+            unsafe { ffi::rte_kvargs_parse(args.as_ptr(), if ptrs.is_empty() { ptr::null() } else { ptrs.as_ptr() }) };
+
+        NonNull::new(p)
+            .map(KvArgs)
+            .ok_or_else(|| anyhow!("invalid arguments, {}", args.to_string_lossy()))
+    }
+
+    /// Parse the key/value pairs until any character of `valid_ends`, e.g. `;` or `/`.
+    pub fn parse_delim<S: AsRef<str>>(args: S, valid_keys: Option<&[&str]>, valid_ends: &str) -> Result<Self> {
+        let args = args.as_ref().as_cstring();
+        let valid_ends = valid_ends.as_cstring();
+        let (_keys, ptrs) = valid_keys.map(key_ptrs).unwrap_or_default();
+
+        let p = unsafe {
+            ffi::rte_kvargs_parse_delim(
+                args.as_ptr(),
+                if ptrs.is_empty() { ptr::null() } else { ptrs.as_ptr() },
+                valid_ends.as_ptr(),
+            )
+        };
+
+        NonNull::new(p)
+            .map(KvArgs)
+            .ok_or_else(|| anyhow!("invalid arguments, {}", args.to_string_lossy()))
+    }
+
+    fn pairs(&self) -> &[ffi::rte_kvargs_pair] {
+        let kvargs = unsafe { self.0.as_ref() };
+
+        &kvargs.pairs[..kvargs.count as usize]
+    }
+
+    /// The number of the key/value pairs.
+    pub fn len(&self) -> usize {
+        self.pairs().len()
+    }
+
+    /// Test if there is no key/value pair.
+    pub fn is_empty(&self) -> bool {
+        self.pairs().is_empty()
+    }
+
+    /// Iterate the key/value pairs in order, the value of a key without `=` is empty.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs().iter().map(|pair| (as_str(pair.key), as_str(pair.value)))
+    }
+
+    /// The number of the pairs of the key.
+    pub fn count(&self, key: &str) -> usize {
+        let key = key.as_cstring();
+
+        unsafe { ffi::rte_kvargs_count(self.0.as_ptr(), key.as_ptr()) as usize }
+    }
+
+    /// Test if the key is present.
+    pub fn contains(&self, key: &str) -> bool {
+        self.iter().any(|(k, _)| k == key)
+    }
+
+    /// The value of the last pair of the key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().filter(|&(k, _)| k == key).map(|(_, v)| v).last()
+    }
+
+    /// The values of all the pairs of the key.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter().filter(move |&(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Parse the value of the last pair of the key, return `None` if the key is not present.
+    pub fn get_as<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|err| anyhow!("invalid value of `{}`, {}, {}", key, value, err))
+            })
+            .transpose()
+    }
+
+    /// Parse the value of the last pair of the key as a boolean flag, a key without value is `true`.
+    pub fn get_flag(&self, key: &str) -> Result<bool> {
+        match self.get(key) {
+            None => Ok(false),
+            Some("") | Some("1") | Some("y") | Some("yes") | Some("on") | Some("true") => Ok(true),
+            Some("0") | Some("n") | Some("no") | Some("off") | Some("false") => Ok(false),
+            Some(value) => Err(anyhow!("invalid value of `{}`, {}", key, value)),
+        }
+    }
+
+    /// Call the handler with each pair of the key, or all the pairs if `key` is `None`.
+    ///
+    /// The iteration stops at the first error returned by the handler.
+    pub fn process<F>(&self, key: Option<&str>, handler: F) -> Result<()>
+    where
+        F: FnMut(&str, &str) -> Result<()>,
+    {
+        let key = key.map(|key| key.as_cstring());
+        let mut ctx = ProcessContext { handler, err: None };
+
+        let ret = unsafe {
+            ffi::rte_kvargs_process(
+                self.0.as_ptr(),
+                key.as_ref().map_or(ptr::null(), |key| key.as_ptr()),
+                Some(process_stub::<F>),
+                &mut ctx as *mut _ as *mut c_void,
+            )
+        };
+
+        match ctx.err {
+            Some(err) => Err(err),
+            None if ret < 0 => Err(anyhow!("failed to process arguments")),
+            None => Ok(()),
+        }
+    }
+}
+
+struct ProcessContext<F> {
+    handler: F,
+    err: Option<Error>,
+}
+
+unsafe extern "C" fn process_stub<F>(key: *const c_char, value: *const c_char, opaque: *mut c_void) -> c_int
+where
+    F: FnMut(&str, &str) -> Result<()>,
+{
+    let ctx = &mut *(opaque as *mut ProcessContext<F>);
+
+    catch_panic("kvargs handler", -1, || {
+        match (ctx.handler)(as_str(key), as_str(value)) {
+            Ok(_) => 0,
+            Err(err) => {
+                ctx.err = Some(err);
+
+                -1
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kvargs() {
+        let args = KvArgs::parse(
+            "iface=eth0,queues=4,iface=eth1,promisc=1",
+            Some(&["iface", "queues", "promisc"]),
+        )
+        .unwrap();
+
+        assert_eq!(args.len(), 4);
+        assert_eq!(args.count("iface"), 2);
+        assert_eq!(args.get("iface"), Some("eth1"));
+        assert_eq!(args.get_all("iface").collect::<Vec<_>>(), vec!["eth0", "eth1"]);
+        assert_eq!(args.get_as::<u16>("queues").unwrap(), Some(4));
+        assert_eq!(args.get_as::<u16>("mtu").unwrap(), None);
+        assert!(args.get_as::<u16>("iface").is_err());
+        assert!(args.get_flag("promisc").unwrap());
+        assert!(!args.get_flag("mtu").unwrap());
+
+        let mut ifaces = vec![];
+
+        args.process(Some("iface"), |_, value| {
+            ifaces.push(value.to_owned());
+
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(ifaces, vec!["eth0", "eth1"]);
+        assert!(args
+            .process(None, |key, _| if key == "queues" {
+                Err(anyhow!("stop"))
+            } else {
+                Ok(())
+            })
+            .is_err());
+
+        assert!(KvArgs::parse("iface=eth0,mtu=1500", Some(&["iface"])).is_err());
+
+        let args = KvArgs::parse_delim("iface=eth0,queues=4/extra", None, "/").unwrap();
+
+        assert_eq!(
+            args.iter().collect::<Vec<_>>(),
+            vec![("iface", "eth0"), ("queues", "4")]
+        );
+    }
+}
@@ -1,13 +1,20 @@
 //! Launch tasks on other lcores
 //!
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::mem;
 use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use ffi;
+use libc;
 use num_traits::FromPrimitive;
 
-use errors::AsResult;
+use errors::ErrorKind::OsError;
 use lcore;
+use utils::{catch_panic, panic_message};
 
 /// State of an lcore.
 #[repr(u32)]
@@ -24,43 +31,188 @@ impl From<ffi::rte_lcore_state_t::Type> for State {
     }
 }
 
-// Definition of a remote launch function.
-pub type LcoreFunc<T> = fn(Option<T>) -> i32;
+type LcoreFunc<'a> = Box<dyn FnOnce() -> i32 + 'a>;
 
-struct LcoreContext<T> {
-    callback: LcoreFunc<T>,
-    arg: Option<T>,
+unsafe extern "C" fn lcore_stub(arg: *mut c_void) -> c_int {
+    let callback = Box::from_raw(arg as *mut LcoreFunc);
+
+    catch_panic("lcore function", -1, callback)
+}
+
+/// Launch a closure on another lcore.
+///
+/// To be executed on the MAIN lcore only.
+pub fn remote_launch<F>(callback: F, worker_id: lcore::Id) -> Result<()>
+where
+    F: FnOnce() -> i32 + Send + 'static,
+{
+    unsafe { remote_launch_unchecked(callback, worker_id) }
 }
 
-unsafe extern "C" fn lcore_stub<T>(arg: *mut c_void) -> c_int {
-    let ctxt = Box::from_raw(arg as *mut LcoreContext<T>);
+/// Launch a closure which may borrow the data of the caller on another lcore.
+///
+/// # Safety
+///
+/// The caller must wait for the lcore to finish before the borrowed data is dropped,
+/// and the data must be safe to be accessed from the lcore.
+pub unsafe fn remote_launch_unchecked<'a, F>(callback: F, worker_id: lcore::Id) -> Result<()>
+where
+    F: FnOnce() -> i32 + 'a,
+{
+    let ctxt = Box::into_raw(Box::new(Box::new(callback) as LcoreFunc<'a>));
+
+    let ret = ffi::rte_eal_remote_launch(Some(lcore_stub), ctxt as *mut c_void, *worker_id);
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        drop(Box::from_raw(ctxt));
+
+        Err(OsError(-ret).into())
+    }
+}
 
-    (ctxt.callback)(ctxt.arg)
+/// Launch a closure on all the worker lcores, and then on the MAIN lcore unless `skip_main` is set.
+///
+/// Each lcore calls its own clone of the closure.
+///
+/// To be executed on the MAIN lcore only.
+pub fn mp_remote_launch<F>(callback: F, skip_main: bool) -> Result<()>
+where
+    F: FnOnce() -> i32 + Clone + Send + 'static,
+{
+    unsafe { mp_remote_launch_unchecked(callback, skip_main) }
 }
 
-/// Launch a function on another lcore.
+/// Launch a closure which may borrow the data of the caller on all the lcores.
+///
+/// # Safety
 ///
-/// To be executed on the MASTER lcore only.
-pub fn remote_launch<T>(callback: LcoreFunc<T>, arg: Option<T>, slave_id: lcore::Id) -> Result<()> {
-    let ctxt = Box::into_raw(Box::new(LcoreContext::<T> { callback, arg })) as *mut c_void;
+/// The caller must wait for all the lcores to finish before the borrowed data is dropped,
+/// and the data must be safe to be accessed from the lcores.
+pub unsafe fn mp_remote_launch_unchecked<'a, F>(callback: F, skip_main: bool) -> Result<()>
+where
+    F: FnOnce() -> i32 + Clone + 'a,
+{
+    let workers: Vec<_> = lcore::enabled().into_iter().filter(|id| !id.is_main()).collect();
+
+    // check that no lcore is busy before launching any of them
+    if workers.iter().any(|id| id.state() != State::Wait) {
+        return Err(OsError(libc::EBUSY).into());
+    }
+
+    for worker_id in workers {
+        remote_launch_unchecked(callback.clone(), worker_id)?;
+    }
+
+    if !skip_main {
+        callback();
+    }
 
-    unsafe { ffi::rte_eal_remote_launch(Some(lcore_stub::<T>), ctxt, *slave_id) }
-        .as_result()
-        .map(|_| ())
+    Ok(())
 }
 
-/// Launch a function on all lcores.
-pub fn mp_remote_launch<T>(callback: LcoreFunc<T>, arg: Option<T>, skip_master: bool) -> Result<()> {
-    let ctxt = Box::into_raw(Box::new(LcoreContext::<T> { callback, arg })) as *mut c_void;
-    let call_main = if skip_master {
-        ffi::rte_rmt_call_main_t::SKIP_MAIN
-    } else {
-        ffi::rte_rmt_call_main_t::CALL_MAIN
+// The panic message of the closure launched on the lcore, if it panicked.
+type PanicSlot = Arc<Mutex<Option<String>>>;
+
+/// A scope to launch the closures borrowing the data outside of it on the worker lcores.
+pub struct Scope<'env> {
+    workers: RefCell<Vec<(lcore::Id, PanicSlot)>>,
+    // invariant over 'env, so the borrowed data can't be shortened
+    _marker: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'env> Drop for Scope<'env> {
+    fn drop(&mut self) {
+        self.join();
+    }
+}
+
+impl<'env> Scope<'env> {
+    /// Launch a closure on the worker lcore, which is joined before the scope returns.
+    ///
+    /// The lcore must be in the WAIT state.
+    pub fn spawn_on<F>(&self, worker_id: lcore::Id, callback: F) -> Result<()>
+    where
+        F: FnOnce() -> i32 + Send + 'env,
+    {
+        let panicked = Arc::new(Mutex::new(None));
+        let slot = panicked.clone();
+        let job = move || match panic::catch_unwind(AssertUnwindSafe(callback)) {
+            Ok(ret) => ret,
+            Err(err) => {
+                *slot.lock().unwrap() = Some(panic_message(&*err));
+
+                -1
+            }
+        };
+
+        // the lcore is joined before the scope returns or unwinds
+        unsafe { remote_launch_unchecked(job, worker_id) }?;
+
+        self.workers.borrow_mut().push((worker_id, panicked));
+
+        Ok(())
+    }
+
+    // Wait for the launched lcores in the launching order.
+    fn join(&self) -> Vec<Result<i32>> {
+        let workers = mem::take(&mut *self.workers.borrow_mut());
+
+        workers
+            .into_iter()
+            .map(|(worker_id, panicked)| {
+                let ret = match worker_id.wait() {
+                    JobState::Finished(ret) => ret,
+                    JobState::Wait => 0,
+                };
+
+                match panicked.lock().unwrap().take() {
+                    Some(msg) => Err(anyhow!("lcore {} panicked, {}", worker_id, msg)),
+                    None => Ok(ret),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Create a scope to launch the closures borrowing the local data on the worker lcores.
+///
+/// All the launched lcores are joined before returning, even if the closure panics,
+/// the return values or the panics of the lcores are returned in the launching order.
+///
+/// To be executed on the MAIN lcore only.
+///
+/// ```no_run
+/// use rte::{launch, lcore};
+///
+/// let mut counters = vec![0; lcore::count()];
+///
+/// let results = launch::scope(|s| {
+///     for (worker_id, counter) in lcore::enabled().into_iter().filter(|id| !id.is_main()).zip(&mut counters) {
+///         s.spawn_on(worker_id, move || {
+///             *counter += 1;
+///
+///             0
+///         })
+///         .expect("fail to launch task");
+///     }
+/// });
+///
+/// assert!(results.iter().all(|ret| ret.is_ok()));
+/// ```
+pub fn scope<'env, F>(callback: F) -> Vec<Result<i32>>
+where
+    F: FnOnce(&Scope<'env>),
+{
+    let scope = Scope {
+        workers: RefCell::new(vec![]),
+        _marker: PhantomData,
     };
 
-    unsafe { ffi::rte_eal_mp_remote_launch(Some(lcore_stub::<T>), ctxt, call_main) }
-        .as_result()
-        .map(|_| ())
+    callback(&scope);
+
+    scope.join()
 }
 
 impl lcore::Id {
@@ -71,9 +223,9 @@ impl lcore::Id {
 
     /// Wait until an lcore finishes its job.
     ///
-    /// To be executed on the MASTER lcore only.
+    /// To be executed on the MAIN lcore only.
     ///
-    /// If the slave lcore identified by the slave_id is in a FINISHED state,
+    /// If the worker lcore identified by the worker_id is in a FINISHED state,
     /// switch to the WAIT state. If the lcore is in RUNNING state, wait until
     /// the lcore finishes its job and moves to the FINISHED state.
     ///
@@ -96,7 +248,7 @@ pub enum JobState {
 
 /// Wait until all lcores finish their jobs.
 ///
-/// To be executed on the MASTER lcore only.
+/// To be executed on the MAIN lcore only.
 /// Issue an rte_eal_wait_lcore() for every lcore.
 /// The return values are ignored.
 pub fn mp_wait_lcore() {
@@ -11,7 +11,7 @@ use ffi;
 
 // use common::config;
 use errors::rte_error;
-use memory::SocketId;
+use memory::{SocketId, SOCKET_ID_ANY};
 
 pub use ffi::LCORE_ID_ANY;
 pub use ffi::RTE_MAX_LCORE;
@@ -138,7 +138,7 @@ pub fn enabled() -> Vec<Id> {
     foreach_lcores(false).collect()
 }
 
-/// Get the id of the master lcore
+/// Get the id of the main lcore
 pub fn main() -> Id {
     unsafe { Id(ffi::rte_get_main_lcore()) }
 }
@@ -161,7 +161,7 @@ pub fn index(lcore_id: u32) -> Option<usize> {
 }
 
 /// Get the next enabled lcore ID.
-pub fn next_id(lcore_id: u32, skip_master: bool, wrap: bool) -> Option<u32> {
+pub fn next_id(lcore_id: u32, skip_main: bool, wrap: bool) -> Option<u32> {
     let mut next_id = lcore_id;
 
     loop {
@@ -177,7 +177,7 @@ pub fn next_id(lcore_id: u32, skip_master: bool, wrap: bool) -> Option<u32> {
             continue;
         }
 
-        if skip_master && Id(next_id).is_main() {
+        if skip_main && Id(next_id).is_main() {
             continue;
         }
 
@@ -216,16 +216,38 @@ pub fn socket_id_by_idx(idx: u32) -> Result<SocketId> {
     }
 }
 
+/// Return the IDs of all the detected physical sockets.
+pub fn sockets() -> Vec<SocketId> {
+    (0..socket_count())
+        .filter_map(|idx| socket_id_by_idx(idx).ok())
+        .collect()
+}
+
+/// All the enabled lcores on the socket.
+///
+/// All the enabled lcores are returned if the socket is `SOCKET_ID_ANY`.
+pub fn on_socket(socket_id: SocketId) -> Vec<Id> {
+    foreach_lcores(false)
+        .filter(|lcore_id| socket_id == SOCKET_ID_ANY || lcore_id.socket_id() == socket_id)
+        .collect()
+}
+
 /// Browse all running lcores.
 pub fn foreach<F: FnMut(Id)>(f: F) {
     foreach_lcores(false).for_each(f)
 }
 
 /// Browse all running lcores except the main lcore.
-pub fn foreach_slave<F: FnMut(Id)>(f: F) {
+pub fn foreach_worker<F: FnMut(Id)>(f: F) {
     foreach_lcores(true).for_each(f)
 }
 
+/// Browse all running lcores except the main lcore.
+#[deprecated(note = "use `foreach_worker` instead")]
+pub fn foreach_slave<F: FnMut(Id)>(f: F) {
+    foreach_worker(f)
+}
+
 fn foreach_lcores(skip_main: bool) -> impl Iterator<Item = Id> {
     (0..ffi::RTE_MAX_LCORE)
         .map(Id)
@@ -0,0 +1,177 @@
+//! Bit and alignment helpers, ported from the inline functions in `rte_common.h`.
+
+/// Combines 32b inputs most significant set bits into the least significant bits
+/// to construct a value with the same MSBs as x but all 1's under it.
+#[inline]
+pub fn combine32ms1b(mut x: u32) -> u32 {
+    x |= x >> 1;
+    x |= x >> 2;
+    x |= x >> 4;
+    x |= x >> 8;
+    x |= x >> 16;
+
+    x
+}
+
+/// Combines 64b inputs most significant set bits into the least significant bits
+/// to construct a value with the same MSBs as x but all 1's under it.
+#[inline]
+pub fn combine64ms1b(mut v: u64) -> u64 {
+    v |= v >> 1;
+    v |= v >> 2;
+    v |= v >> 4;
+    v |= v >> 8;
+    v |= v >> 16;
+    v |= v >> 32;
+
+    v
+}
+
+/// Returns true if n is a power of 2
+#[inline]
+pub fn is_power_of_2(n: u32) -> bool {
+    n != 0 && (n & (n - 1)) == 0
+}
+
+/// Aligns input parameter to the next power of 2
+#[inline]
+pub fn align32pow2(x: u32) -> u32 {
+    combine32ms1b(x.wrapping_sub(1)).wrapping_add(1)
+}
+
+/// Aligns input parameter to the previous power of 2
+#[inline]
+pub fn align32prevpow2(x: u32) -> u32 {
+    let x = combine32ms1b(x);
+
+    x - (x >> 1)
+}
+
+/// Aligns 64b input parameter to the next power of 2
+#[inline]
+pub fn align64pow2(v: u64) -> u64 {
+    combine64ms1b(v.wrapping_sub(1)).wrapping_add(1)
+}
+
+/// Aligns 64b input parameter to the previous power of 2
+#[inline]
+pub fn align64prevpow2(v: u64) -> u64 {
+    let v = combine64ms1b(v);
+
+    v - (v >> 1)
+}
+
+/// Macro to align a value to a given power-of-two. The resultant value
+/// will be of the same type as the first parameter, and will be no
+/// bigger than the first parameter.
+#[inline]
+pub fn align_floor(val: usize, align: usize) -> usize {
+    debug_assert!(align.is_power_of_two());
+
+    val & !(align - 1)
+}
+
+/// Align a value to a given power-of-two. The resultant value
+/// will be no lower than the first parameter.
+#[inline]
+pub fn align_ceil(val: usize, align: usize) -> usize {
+    align_floor(val + align - 1, align)
+}
+
+/// Searches the input parameter for the least significant set bit (starting from zero).
+///
+/// If a least significant 1 bit is found, its bit index is returned.
+#[inline]
+pub fn bsf32(v: u32) -> Option<u32> {
+    if v == 0 {
+        None
+    } else {
+        Some(v.trailing_zeros())
+    }
+}
+
+/// Searches the input parameter for the least significant set bit (starting from zero).
+///
+/// If a least significant 1 bit is found, its bit index is returned.
+#[inline]
+pub fn bsf64(v: u64) -> Option<u32> {
+    if v == 0 {
+        None
+    } else {
+        Some(v.trailing_zeros())
+    }
+}
+
+/// Return the last (most-significant) bit set.
+///
+/// The last (most significant) bit is at position 32,
+/// e.g. `fls_u32(0) = 0`, `fls_u32(1) = 1`, `fls_u32(0x80000000) = 32`.
+#[inline]
+pub fn fls_u32(x: u32) -> u32 {
+    32 - x.leading_zeros()
+}
+
+/// Return the last (most-significant) bit set.
+///
+/// The last (most significant) bit is at position 64,
+/// e.g. `fls_u64(0) = 0`, `fls_u64(1) = 1`, `fls_u64(0x8000000000000000) = 64`.
+#[inline]
+pub fn fls_u64(x: u64) -> u32 {
+    64 - x.leading_zeros()
+}
+
+/// Return the rounded-up log2 of an integer.
+///
+/// If the input is 0, the output is 0.
+#[inline]
+pub fn log2_u32(v: u32) -> u32 {
+    if v == 0 {
+        0
+    } else {
+        align32pow2(v).trailing_zeros()
+    }
+}
+
+/// Return the rounded-up log2 of a 64-bit integer.
+///
+/// If the input is 0, the output is 0.
+#[inline]
+pub fn log2_u64(v: u64) -> u32 {
+    if v == 0 {
+        0
+    } else {
+        align64pow2(v).trailing_zeros()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align() {
+        assert!(is_power_of_2(1));
+        assert!(is_power_of_2(64));
+        assert!(!is_power_of_2(0));
+        assert!(!is_power_of_2(96));
+
+        assert_eq!(align32pow2(0), 0);
+        assert_eq!(align32pow2(1), 1);
+        assert_eq!(align32pow2(1000), 1024);
+        assert_eq!(align32pow2(1024), 1024);
+        assert_eq!(align32prevpow2(1000), 512);
+        assert_eq!(align64pow2(0x1_0000_0001), 0x2_0000_0000);
+        assert_eq!(align64prevpow2(0x1_0000_0001), 0x1_0000_0000);
+
+        assert_eq!(align_floor(1000, 64), 960);
+        assert_eq!(align_ceil(1000, 64), 1024);
+        assert_eq!(align_ceil(1024, 64), 1024);
+
+        assert_eq!(bsf32(0), None);
+        assert_eq!(bsf32(0x80), Some(7));
+        assert_eq!(fls_u32(0), 0);
+        assert_eq!(fls_u32(0x8000_0000), 32);
+        assert_eq!(log2_u32(1000), 10);
+        assert_eq!(log2_u64(1 << 40), 40);
+    }
+}
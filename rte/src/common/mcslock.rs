@@ -0,0 +1,144 @@
+//! RTE MCS lock
+//!
+//! This file defines the main data structure and APIs for MCS queued lock.
+//!
+//! The MCS lock (proposed by John M. Mellor-Crummey and Michael L. Scott)
+//! provides scalability by spinning on a CPU/thread local variable which
+//! avoids expensive cache bouncings. It provides fairness by maintaining
+//! a list of acquirers and passing the lock to each CPU/thread in the order
+//! they acquired the lock.
+//!
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::ptr;
+
+use ffi;
+
+pub type RawMcsLock = ffi::rte_mcslock_t;
+pub type RawMcsLockPtr = *mut ffi::rte_mcslock_t;
+
+// The queue node of an acquirer, which lives on its stack until the lock is released.
+struct McsNode(UnsafeCell<RawMcsLock>);
+
+impl McsNode {
+    fn new() -> Self {
+        McsNode(UnsafeCell::new(RawMcsLock {
+            next: ptr::null_mut(),
+            locked: 0,
+        }))
+    }
+}
+
+/// The RTE MCS lock protecting the data of type `T`.
+///
+/// The queue node of an acquirer must stay linked until the lock is released,
+/// so the lock is only held while the closure passed to `lock()` or `try_lock()` runs.
+pub struct McsLock<T: ?Sized> {
+    tail: UnsafeCell<RawMcsLockPtr>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for McsLock<T> {}
+unsafe impl<T: ?Sized + Send> Sync for McsLock<T> {}
+
+// Release the lock even if the closure panics.
+struct Unlock<'a, T: ?Sized + 'a> {
+    lock: &'a McsLock<T>,
+    node: &'a McsNode,
+}
+
+impl<'a, T: ?Sized> Drop for Unlock<'a, T> {
+    fn drop(&mut self) {
+        unsafe { ffi::_rte_mcslock_unlock(self.lock.tail.get(), self.node.0.get()) }
+    }
+}
+
+impl<T: Default> Default for McsLock<T> {
+    fn default() -> Self {
+        McsLock::new(T::default())
+    }
+}
+
+impl<T> From<T> for McsLock<T> {
+    fn from(data: T) -> Self {
+        McsLock::new(data)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for McsLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("McsLock").field("locked", &self.is_locked()).finish()
+    }
+}
+
+impl<T> McsLock<T> {
+    /// Create a new MCS lock in the unlocked state.
+    #[inline]
+    pub fn new(data: T) -> Self {
+        McsLock {
+            tail: UnsafeCell::new(ptr::null_mut()),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Consume the lock, returning the protected data.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> McsLock<T> {
+    /// Test if the lock is taken.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        unsafe { ffi::_rte_mcslock_is_locked(ptr::read_volatile(self.tail.get())) != 0 }
+    }
+
+    /// Take the lock, spinning on a queue node of the caller until the lock is passed,
+    /// and call the closure with the protected data.
+    #[inline]
+    pub fn lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let node = McsNode::new();
+
+        unsafe { ffi::_rte_mcslock_lock(self.tail.get(), node.0.get()) }
+
+        let _unlock = Unlock {
+            lock: self,
+            node: &node,
+        };
+
+        f(unsafe { &mut *self.data.get() })
+    }
+
+    /// Try to take the lock, and call the closure with the protected data if the lock was taken.
+    #[inline]
+    pub fn try_lock<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let node = McsNode::new();
+
+        if unsafe { ffi::_rte_mcslock_trylock(self.tail.get(), node.0.get()) } == 0 {
+            None
+        } else {
+            let _unlock = Unlock {
+                lock: self,
+                node: &node,
+            };
+
+            Some(f(unsafe { &mut *self.data.get() }))
+        }
+    }
+
+    /// Get a mutable reference to the protected data.
+    ///
+    /// Since this call borrows the lock mutably, no locking needs to take place.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
+    }
+}
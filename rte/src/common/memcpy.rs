@@ -0,0 +1,47 @@
+//! Functions for vectorised implementation of memcpy().
+//!
+use std::os::raw::c_void;
+
+use ffi;
+
+/// Copies below this size are done by `std::ptr::copy_nonoverlapping`.
+///
+/// `rte_memcpy()` is an inline function in DPDK, so every call from Rust goes through
+/// a C stub and costs a real function call. For short copies, like packet headers,
+/// that call dominates and the compiler generated code of `copy_nonoverlapping` wins.
+/// For bulk payload copies the SIMD code of `rte_memcpy()`, which is tuned for
+/// the CPU flags DPDK is built with, is faster on unaligned buffers.
+pub const MEMCPY_THRESHOLD: usize = 64;
+
+/// Copy bytes from one slice to another, the slices must have the same length.
+///
+/// Short copies use `std::ptr::copy_nonoverlapping`, others use `rte_memcpy()`,
+/// see `MEMCPY_THRESHOLD` for details.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+#[inline]
+pub fn memcpy(dst: &mut [u8], src: &[u8]) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "destination and source slices have different lengths"
+    );
+
+    if src.len() < MEMCPY_THRESHOLD {
+        dst.copy_from_slice(src)
+    } else {
+        unsafe { rte_memcpy(dst.as_mut_ptr(), src.as_ptr(), src.len()) }
+    }
+}
+
+/// Copy bytes from one location to another with `rte_memcpy()`.
+///
+/// # Safety
+///
+/// The locations must be valid for `n` bytes and must not overlap.
+#[inline]
+pub unsafe fn rte_memcpy(dst: *mut u8, src: *const u8, n: usize) {
+    ffi::_rte_memcpy(dst as *mut c_void, src as *const c_void, n);
+}
@@ -1,3 +1,15 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use errors::ErrorKind;
+use ffi;
+use lcore;
+use malloc;
+
 pub type SocketId = i32;
 
 pub const SOCKET_ID_ANY: SocketId = -1;
@@ -53,3 +65,379 @@ impl<'a, T: 'a, E> AsMutRef<'a, T> for Result<*mut T, E> {
         self.ok().map(|p| unsafe { &mut *p })
     }
 }
+
+const SYSFS_HUGEPAGES_DIR: &str = "/sys/kernel/mm/hugepages";
+const SYSFS_NODE_DIR: &str = "/sys/devices/system/node";
+const PROC_MOUNTS: &str = "/proc/mounts";
+const PROC_MEMINFO: &str = "/proc/meminfo";
+
+/// The hugepages of a page size.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hugepages {
+    /// The page size in bytes.
+    pub page_size: usize,
+    /// The number of pages reserved in the system.
+    pub total_pages: usize,
+    /// The number of free pages in the system.
+    pub free_pages: usize,
+    /// The number of free pages on each NUMA node.
+    pub free_pages_per_node: BTreeMap<u32, usize>,
+    /// The hugetlbfs mount points using this page size.
+    pub mount_points: Vec<PathBuf>,
+}
+
+impl Hugepages {
+    /// The size of the free pages in bytes.
+    pub fn free_size(&self) -> usize {
+        self.page_size * self.free_pages
+    }
+
+    /// Test if the pages can be used by EAL, which requires some free pages and a mount point.
+    pub fn is_usable(&self) -> bool {
+        self.free_pages > 0 && !self.mount_points.is_empty()
+    }
+}
+
+/// The hugepage information of the system.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HugepageInfo {
+    /// The hugepages of each page size, ordered by the page size.
+    pub sizes: Vec<Hugepages>,
+    /// The physical memory size used by EAL in bytes, or 0 if EAL hasn't been initialized.
+    pub physmem_size: u64,
+}
+
+impl HugepageInfo {
+    /// The size of the free pages which can be used by EAL in bytes.
+    pub fn free_size(&self) -> usize {
+        self.sizes
+            .iter()
+            .filter(|pages| pages.is_usable())
+            .map(Hugepages::free_size)
+            .sum()
+    }
+
+    /// Check there are enough usable hugepages, or return an error describing how to set them up.
+    pub fn require(&self, size: usize) -> Result<()> {
+        if self.sizes.is_empty() {
+            return Err(ErrorKind::InsufficientHugepages(
+                "hugepages are not supported by the kernel, enable CONFIG_HUGETLBFS".to_owned(),
+            )
+            .into());
+        }
+
+        if self.sizes.iter().all(|pages| pages.total_pages == 0) {
+            return Err(ErrorKind::InsufficientHugepages(format!(
+                "no hugepages are reserved, reserve them with `echo <count> > {}/hugepages-<size>kB/nr_hugepages`",
+                SYSFS_HUGEPAGES_DIR
+            ))
+            .into());
+        }
+
+        if self.sizes.iter().all(|pages| pages.mount_points.is_empty()) {
+            return Err(ErrorKind::InsufficientHugepages(
+                "no hugetlbfs is mounted, mount it with `mount -t hugetlbfs nodev /mnt/huge`".to_owned(),
+            )
+            .into());
+        }
+
+        let free_size = self.free_size();
+
+        if free_size < size {
+            return Err(ErrorKind::InsufficientHugepages(format!(
+                "{} MB free hugepages are available, but {} MB are required",
+                free_size >> 20,
+                size >> 20
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the physical memory size used by EAL in bytes.
+pub fn physmem_size() -> u64 {
+    unsafe { ffi::rte_eal_get_physmem_size() }
+}
+
+/// Query the hugepages reserved in the system and the hugetlbfs mount points.
+pub fn hugepage_info() -> Result<HugepageInfo> {
+    let default_size = fs::read_to_string(PROC_MEMINFO)
+        .ok()
+        .and_then(|meminfo| parse_default_hugepage_size(&meminfo));
+    let mounts = fs::read_to_string(PROC_MOUNTS)
+        .map(|mounts| parse_hugetlbfs_mounts(&mounts, default_size))
+        .unwrap_or_default();
+
+    let mut sizes = vec![];
+
+    if Path::new(SYSFS_HUGEPAGES_DIR).is_dir() {
+        for entry in fs::read_dir(SYSFS_HUGEPAGES_DIR)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let page_size = match name.to_str().and_then(parse_hugepages_dir) {
+                Some(page_size) => page_size,
+                None => continue,
+            };
+
+            sizes.push(Hugepages {
+                page_size,
+                total_pages: read_count(&entry.path().join("nr_hugepages"))?,
+                free_pages: read_count(&entry.path().join("free_hugepages"))?,
+                free_pages_per_node: free_pages_per_node(&name.to_string_lossy())?,
+                mount_points: mounts
+                    .iter()
+                    .filter(|&&(_, size)| size == page_size)
+                    .map(|&(ref path, _)| path.clone())
+                    .collect(),
+            });
+        }
+    }
+
+    sizes.sort_by_key(|pages| pages.page_size);
+
+    Ok(HugepageInfo {
+        sizes,
+        physmem_size: physmem_size(),
+    })
+}
+
+fn free_pages_per_node(dir_name: &str) -> Result<BTreeMap<u32, usize>> {
+    let mut nodes = BTreeMap::new();
+
+    if Path::new(SYSFS_NODE_DIR).is_dir() {
+        for entry in fs::read_dir(SYSFS_NODE_DIR)? {
+            let entry = entry?;
+            let node = entry.file_name();
+            let node_id = match node
+                .to_str()
+                .and_then(|s| s.strip_prefix("node"))
+                .and_then(|s| s.parse().ok())
+            {
+                Some(node_id) => node_id,
+                None => continue,
+            };
+            let path = entry.path().join("hugepages").join(dir_name).join("free_hugepages");
+
+            if path.exists() {
+                nodes.insert(node_id, read_count(&path)?);
+            }
+        }
+    }
+
+    Ok(nodes)
+}
+
+fn read_count(path: &Path) -> Result<usize> {
+    Ok(fs::read_to_string(path)?.trim().parse()?)
+}
+
+/// Parse the page size from the sysfs directory name, e.g. `hugepages-2048kB`.
+fn parse_hugepages_dir(name: &str) -> Option<usize> {
+    name.strip_prefix("hugepages-")
+        .and_then(|s| s.strip_suffix("kB"))
+        .and_then(|s| s.parse::<usize>().ok())
+        .map(|kb| kb << 10)
+}
+
+/// Parse the default hugepage size from `/proc/meminfo`, e.g. `Hugepagesize:    2048 kB`.
+fn parse_default_hugepage_size(meminfo: &str) -> Option<usize> {
+    meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("Hugepagesize:"))
+        .and_then(|s| s.trim().strip_suffix("kB"))
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .map(|kb| kb << 10)
+}
+
+/// Parse the page size with an optional unit suffix, e.g. `2M` or `1G`.
+fn parse_page_size(s: &str) -> Option<usize> {
+    let (num, shift) = match s.chars().last()? {
+        'k' | 'K' => (&s[..s.len() - 1], 10),
+        'm' | 'M' => (&s[..s.len() - 1], 20),
+        'g' | 'G' => (&s[..s.len() - 1], 30),
+        _ => (s, 0),
+    };
+
+    num.parse::<usize>().ok().map(|n| n << shift)
+}
+
+/// Parse the hugetlbfs mount points and their page sizes from `/proc/mounts`.
+fn parse_hugetlbfs_mounts(mounts: &str, default_size: Option<usize>) -> Vec<(PathBuf, usize)> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _source = fields.next()?;
+            let target = fields.next()?;
+            let fstype = fields.next()?;
+            let options = fields.next()?;
+
+            if fstype != "hugetlbfs" {
+                return None;
+            }
+
+            let page_size = options
+                .split(',')
+                .find_map(|opt| opt.strip_prefix("pagesize="))
+                .and_then(parse_page_size)
+                .or(default_size)?;
+
+            Some((PathBuf::from(target), page_size))
+        })
+        .collect()
+}
+
+/// The heap statistics of a NUMA socket.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SocketStats {
+    pub socket_id: SocketId,
+    /// Total bytes on heap.
+    pub total_size: usize,
+    /// Total free bytes on heap.
+    pub free_size: usize,
+    /// Total allocated bytes on heap.
+    pub alloc_size: usize,
+    /// Size in bytes of largest free block.
+    pub greatest_free_size: usize,
+    /// Number of free elements on heap.
+    pub free_count: usize,
+    /// Number of allocated elements on heap.
+    pub alloc_count: usize,
+}
+
+impl SocketStats {
+    fn from_raw(socket_id: SocketId, stats: ffi::rte_malloc_socket_stats) -> Self {
+        SocketStats {
+            socket_id,
+            total_size: stats.heap_totalsz_bytes,
+            free_size: stats.heap_freesz_bytes,
+            alloc_size: stats.heap_allocsz_bytes,
+            greatest_free_size: stats.greatest_free_size,
+            free_count: stats.free_count as usize,
+            alloc_count: stats.alloc_count as usize,
+        }
+    }
+}
+
+/// Get the heap statistics of a NUMA socket.
+pub fn socket_stats(socket_id: SocketId) -> Option<SocketStats> {
+    malloc::get_socket_stats(socket_id).map(|stats| SocketStats::from_raw(socket_id, stats))
+}
+
+/// The heap statistics of all the detected NUMA sockets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    pub sockets: Vec<SocketStats>,
+}
+
+impl MemoryReport {
+    /// Total bytes on the heaps of all the sockets.
+    pub fn total_size(&self) -> usize {
+        self.sockets.iter().map(|s| s.total_size).sum()
+    }
+
+    /// Total free bytes on the heaps of all the sockets.
+    pub fn free_size(&self) -> usize {
+        self.sockets.iter().map(|s| s.free_size).sum()
+    }
+
+    /// Total allocated bytes on the heaps of all the sockets.
+    pub fn alloc_size(&self) -> usize {
+        self.sockets.iter().map(|s| s.alloc_size).sum()
+    }
+
+    /// Number of allocated elements on the heaps of all the sockets.
+    pub fn alloc_count(&self) -> usize {
+        self.sockets.iter().map(|s| s.alloc_count).sum()
+    }
+
+    /// The allocated bytes and elements since an earlier report, which may indicate a leak if they keep growing.
+    pub fn growth_since(&self, earlier: &MemoryReport) -> (isize, isize) {
+        (
+            self.alloc_size() as isize - earlier.alloc_size() as isize,
+            self.alloc_count() as isize - earlier.alloc_count() as isize,
+        )
+    }
+}
+
+impl fmt::Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6} {:>14} {:>14} {:>14} {:>14} {:>10} {:>10}",
+            "socket", "total", "free", "allocated", "greatest free", "free elem", "alloc elem"
+        )?;
+
+        for s in &self.sockets {
+            writeln!(
+                f,
+                "{:>6} {:>14} {:>14} {:>14} {:>14} {:>10} {:>10}",
+                s.socket_id, s.total_size, s.free_size, s.alloc_size, s.greatest_free_size, s.free_count, s.alloc_count
+            )?;
+        }
+
+        write!(
+            f,
+            "{:>6} {:>14} {:>14} {:>14} {:>14} {:>10} {:>10}",
+            "total",
+            self.total_size(),
+            self.free_size(),
+            self.alloc_size(),
+            self.sockets
+                .iter()
+                .map(|s| s.greatest_free_size)
+                .max()
+                .unwrap_or_default(),
+            self.sockets.iter().map(|s| s.free_count).sum::<usize>(),
+            self.alloc_count()
+        )
+    }
+}
+
+/// Get the heap statistics of all the detected NUMA sockets.
+pub fn memory_report() -> MemoryReport {
+    MemoryReport {
+        sockets: (0..lcore::socket_count())
+            .filter_map(|idx| lcore::socket_id_by_idx(idx).ok())
+            .filter_map(socket_stats)
+            .collect(),
+    }
+}
+
+/// Format the heap statistics of all the detected NUMA sockets.
+pub fn report() -> String {
+    memory_report().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hugepages() {
+        assert_eq!(parse_hugepages_dir("hugepages-2048kB"), Some(2 << 20));
+        assert_eq!(parse_hugepages_dir("hugepages-1048576kB"), Some(1 << 30));
+        assert_eq!(parse_hugepages_dir("node0"), None);
+
+        assert_eq!(
+            parse_default_hugepage_size("HugePages_Free:        0\nHugepagesize:       2048 kB\n"),
+            Some(2 << 20)
+        );
+
+        let mounts = "sysfs /sys sysfs rw,nosuid 0 0\n\
+                      hugetlbfs /dev/hugepages hugetlbfs rw,relatime,pagesize=2M 0 0\n\
+                      nodev /mnt/huge hugetlbfs rw,relatime 0 0\n\
+                      nodev /mnt/huge-1G hugetlbfs rw,relatime,pagesize=1024M 0 0\n";
+
+        assert_eq!(
+            parse_hugetlbfs_mounts(mounts, Some(2 << 20)),
+            vec![
+                (PathBuf::from("/dev/hugepages"), 2 << 20),
+                (PathBuf::from("/mnt/huge"), 2 << 20),
+                (PathBuf::from("/mnt/huge-1G"), 1 << 30),
+            ]
+        );
+    }
+}
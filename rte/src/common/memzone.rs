@@ -1,4 +1,14 @@
-use ffi::rte_memzone;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::os::unix::io::AsRawFd;
+
+use anyhow::Result;
+use cfile;
+
+use errors::AsResult;
+use ffi::{self, rte_memzone};
+use memory::SocketId;
+use utils::AsCString;
 
 /// RTE Memzone
 ///
@@ -16,6 +26,106 @@ use ffi::rte_memzone;
 ///
 pub struct MemoryZone(*const rte_memzone);
 
+// The memzone descriptors are read-only and shared by all lcores.
+unsafe impl Send for MemoryZone {}
+unsafe impl Sync for MemoryZone {}
+
 pub fn from_raw(zone: *const rte_memzone) -> MemoryZone {
     MemoryZone(zone)
 }
+
+bitflags! {
+    pub struct MemzoneFlags: u32 {
+        /// Reserve the memzone from 2MB pages.
+        const PAGE_2MB = ffi::RTE_MEMZONE_2MB;
+        /// Reserve the memzone from 1GB pages.
+        const PAGE_1GB = ffi::RTE_MEMZONE_1GB;
+        /// Reserve the memzone from any page size, if the requested one is not available.
+        const SIZE_HINT_ONLY = ffi::RTE_MEMZONE_SIZE_HINT_ONLY;
+        /// The memzone must be IOVA-contiguous.
+        const IOVA_CONTIG = ffi::RTE_MEMZONE_IOVA_CONTIG;
+    }
+}
+
+impl MemoryZone {
+    /// Reserve a portion of physical memory with alignment on a specified boundary.
+    pub fn reserve<S: AsRef<str>>(
+        name: S,
+        len: usize,
+        socket_id: SocketId,
+        flags: MemzoneFlags,
+        align: u32,
+    ) -> Result<Self> {
+        let name = name.as_cstring();
+
+        unsafe {
+            ffi::rte_memzone_reserve_aligned(name.as_ptr(), len, socket_id, flags.bits, align) as *mut rte_memzone
+        }
+        .as_result()
+        .map(|mz| MemoryZone(mz.as_ptr()))
+    }
+
+    /// Lookup for a memzone by its name.
+    pub fn lookup<S: AsRef<str>>(name: S) -> Result<Self> {
+        let name = name.as_cstring();
+
+        unsafe { ffi::rte_memzone_lookup(name.as_ptr()) as *mut rte_memzone }
+            .as_result()
+            .map(|mz| MemoryZone(mz.as_ptr()))
+    }
+
+    /// Free a memzone.
+    pub fn free(self) -> Result<()> {
+        let ret = unsafe { ffi::rte_memzone_free(self.0) };
+
+        rte_check!(ret)
+    }
+
+    pub fn as_raw(&self) -> *const rte_memzone {
+        self.0
+    }
+
+    /// Name of the memory zone.
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.0).name.as_ptr()).to_str().unwrap() }
+    }
+
+    /// Start IO address.
+    pub fn iova(&self) -> ffi::rte_iova_t {
+        unsafe { (*self.0).iova }
+    }
+
+    /// Start virtual address.
+    pub fn addr(&self) -> *mut c_void {
+        unsafe { (*self.0).__bindgen_anon_1.addr }
+    }
+
+    /// Length of the memzone.
+    pub fn len(&self) -> usize {
+        unsafe { (*self.0).len }
+    }
+
+    /// Test if the memzone is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The page size of underlying memory
+    pub fn hugepage_size(&self) -> u64 {
+        unsafe { (*self.0).hugepage_sz }
+    }
+
+    /// NUMA socket ID.
+    pub fn socket_id(&self) -> SocketId {
+        unsafe { (*self.0).socket_id }
+    }
+}
+
+/// Dump all reserved memzones to a file.
+pub fn dump<S: AsRawFd>(s: &S) -> Result<()> {
+    let mut f = cfile::fdopen(s, "w")?;
+
+    unsafe { ffi::rte_memzone_dump(&mut **f as *mut _ as *mut _) };
+
+    Ok(())
+}
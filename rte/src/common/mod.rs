@@ -1,27 +1,54 @@
+pub mod alarm;
 pub mod bitmap;
+pub mod bitops;
+pub mod cpuflags;
 // mod config;
 pub mod eal;
+pub mod epoll;
+pub mod intr;
 pub mod keepalive;
 pub mod launch;
 pub mod lcore;
 pub mod log;
+pub mod mp;
 mod rand;
 mod version;
 #[macro_use]
 pub mod malloc;
 pub mod dev;
 pub mod devargs;
+pub mod kvargs;
 #[macro_use]
 pub mod debug;
+pub mod mcslock;
+pub mod pflock;
+pub mod rwlock;
+pub mod seqlock;
 pub mod spinlock;
+pub mod thread;
+pub mod ticketlock;
 #[macro_use]
 pub mod byteorder;
 mod cycles;
+mod hypervisor;
+mod math;
+mod memcpy;
 pub mod memory;
 pub mod memzone;
+mod pause;
+mod prefetch;
+pub mod reciprocal;
+pub mod shared;
+mod uuid;
 
 // pub use self::config::{config};
 pub use self::cycles::*;
+pub use self::hypervisor::{hypervisor, Hypervisor};
 pub use self::lcore::{socket_count, socket_id};
-pub use self::rand::{rand, srand};
+pub use self::math::*;
+pub use self::memcpy::{memcpy, rte_memcpy, MEMCPY_THRESHOLD};
+pub use self::pause::{pause, wait_until_equal_16, wait_until_equal_32, wait_until_equal_64};
+pub use self::prefetch::*;
+pub use self::rand::{rand, rand_max, srand, LcoreRng};
+pub use self::uuid::{Uuid, RTE_UUID_STRLEN};
 pub use self::version::version;
@@ -0,0 +1,317 @@
+//!
+//! RTE Multi-process Communication
+//!
+//! The primary and secondary processes exchange the control messages over the EAL IPC channel,
+//! a message is dispatched to the action registered with the same name in the peer process.
+//!
+//! The actions and the reply callbacks are called by the IPC thread, they must not block.
+//!
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::RawFd;
+use std::ptr;
+use std::slice;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use libc;
+
+use errors::rte_error;
+use ffi;
+use utils::{catch_panic, AsCString};
+
+pub use eal::{primary_proc_alive, process_type, ProcType};
+
+pub type RawMessage = ffi::rte_mp_msg;
+
+/// The maximum length of the message name.
+pub const MAX_NAME_LEN: usize = ffi::RTE_MP_MAX_NAME_LEN as usize;
+/// The maximum length of the message parameters.
+pub const MAX_PARAM_LEN: usize = ffi::RTE_MP_MAX_PARAM_LEN as usize;
+/// The maximum number of the file descriptors of a message.
+pub const MAX_FD_NUM: usize = ffi::RTE_MP_MAX_FD_NUM as usize;
+
+/// A message exchanged between the processes.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct Message(RawMessage);
+
+impl fmt::Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Message")
+            .field("name", &self.name())
+            .field("param", &self.param())
+            .field("fds", &self.fds())
+            .finish()
+    }
+}
+
+impl Message {
+    /// Create an empty message which is dispatched to the action with the name.
+    pub fn new<S: AsRef<str>>(name: S) -> Result<Self> {
+        let name = name.as_ref().as_bytes();
+
+        if name.is_empty() || name.len() >= MAX_NAME_LEN {
+            return Err(anyhow!("invalid message name length, {}", name.len()));
+        }
+
+        let mut msg = RawMessage::default();
+
+        for (dst, &src) in msg.name.iter_mut().zip(name) {
+            *dst = src as _;
+        }
+
+        Ok(Message(msg))
+    }
+
+    /// Create a message with the value as the parameters.
+    pub fn with_value<S: AsRef<str>, T: Copy>(name: S, value: &T) -> Result<Self> {
+        let mut msg = Self::new(name)?;
+
+        msg.set_value(value)?;
+
+        Ok(msg)
+    }
+
+    /// The name of the message.
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.0.name.as_ptr()).to_str().unwrap_or_default() }
+    }
+
+    /// The parameters of the message.
+    pub fn param(&self) -> &[u8] {
+        &self.0.param[..self.0.len_param as usize]
+    }
+
+    /// Set the parameters of the message.
+    pub fn set_param(&mut self, param: &[u8]) -> Result<&mut Self> {
+        if param.len() > MAX_PARAM_LEN {
+            return Err(anyhow!("message parameters too long, {}", param.len()));
+        }
+
+        self.0.param[..param.len()].copy_from_slice(param);
+        self.0.len_param = param.len() as c_int;
+
+        Ok(self)
+    }
+
+    /// Read the parameters as a value, return `None` if the length of the parameters doesn't match.
+    ///
+    /// The value is copied bit by bit, so the type must be valid for any bit pattern sent by the peer.
+    pub fn value<T: Copy>(&self) -> Option<T> {
+        if self.param().len() == mem::size_of::<T>() {
+            Some(unsafe { ptr::read_unaligned(self.0.param.as_ptr() as *const T) })
+        } else {
+            None
+        }
+    }
+
+    /// Set the value as the parameters of the message.
+    pub fn set_value<T: Copy>(&mut self, value: &T) -> Result<&mut Self> {
+        self.set_param(unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) })
+    }
+
+    /// The file descriptors carried by the message.
+    pub fn fds(&self) -> &[RawFd] {
+        &self.0.fds[..self.0.num_fds as usize]
+    }
+
+    /// Set the file descriptors carried by the message, which are duplicated to the peer process.
+    pub fn set_fds(&mut self, fds: &[RawFd]) -> Result<&mut Self> {
+        if fds.len() > MAX_FD_NUM {
+            return Err(anyhow!("too many file descriptors, {}", fds.len()));
+        }
+
+        self.0.fds[..fds.len()].copy_from_slice(fds);
+        self.0.num_fds = fds.len() as c_int;
+
+        Ok(self)
+    }
+
+    /// Send the message to the peer processes, which is not replied.
+    pub fn send(&mut self) -> Result<()> {
+        if unsafe { ffi::rte_mp_sendmsg(&mut self.0) } == 0 {
+            Ok(())
+        } else {
+            Err(rte_error().into())
+        }
+    }
+
+    /// Send the message as a request, and wait for the replies of the peer processes until timeout.
+    pub fn request(&mut self, timeout: Duration) -> Result<Reply> {
+        let mut reply = ffi::rte_mp_reply::default();
+        let ts = timespec(timeout);
+
+        if unsafe { ffi::rte_mp_request_sync(&mut self.0, &mut reply, &ts) } != 0 {
+            return Err(rte_error().into());
+        }
+
+        let msgs = Reply::from_raw(&reply);
+
+        unsafe { libc::free(reply.msgs as *mut c_void) };
+
+        Ok(msgs)
+    }
+
+    /// Send the message as a request, the callback is called with the replies
+    /// when all the peer processes have replied or the request is timeout.
+    ///
+    /// The callbacks of the requests with the same name are called in the order of the requests,
+    /// so those requests should be sent with the same timeout.
+    pub fn request_async<F>(&mut self, timeout: Duration, callback: F) -> Result<()>
+    where
+        F: FnOnce(&Message, &Reply) + Send + 'static,
+    {
+        let name = self.name().to_owned();
+        let ts = timespec(timeout);
+
+        PENDING_REQUESTS
+            .lock()
+            .unwrap()
+            .entry(name.clone())
+            .or_insert_with(VecDeque::new)
+            .push_back(Box::new(callback));
+
+        if unsafe { ffi::rte_mp_request_async(&mut self.0, &ts, Some(reply_callback_stub)) } == 0 {
+            Ok(())
+        } else {
+            let err = rte_error();
+
+            if let Some(pending) = PENDING_REQUESTS.lock().unwrap().get_mut(&name) {
+                pending.pop_back();
+            }
+
+            Err(err.into())
+        }
+    }
+}
+
+/// The replies of a request.
+#[derive(Clone, Debug, Default)]
+pub struct Reply {
+    /// The number of the peer processes which the request was sent to.
+    pub nb_sent: usize,
+    /// The messages replied by the peer processes.
+    pub msgs: Vec<Message>,
+}
+
+impl Reply {
+    fn from_raw(reply: &ffi::rte_mp_reply) -> Self {
+        let msgs = if reply.msgs.is_null() {
+            vec![]
+        } else {
+            unsafe { slice::from_raw_parts(reply.msgs, reply.nb_received as usize) }
+                .iter()
+                .map(|&msg| Message(msg))
+                .collect()
+        };
+
+        Reply {
+            nb_sent: reply.nb_sent as usize,
+            msgs,
+        }
+    }
+}
+
+/// The peer process which sent a request to the action.
+pub struct Peer(*const c_void);
+
+impl Peer {
+    /// Reply the request of the peer process, the request must be replied even if it is failed.
+    pub fn reply(&self, msg: &mut Message) -> Result<()> {
+        if unsafe { ffi::rte_mp_reply(&mut msg.0, self.0 as *const _) } == 0 {
+            Ok(())
+        } else {
+            Err(rte_error().into())
+        }
+    }
+}
+
+type ActionCallback = Arc<dyn Fn(&Message, &Peer) -> Result<()> + Send + Sync>;
+type ReplyCallback = Box<dyn FnOnce(&Message, &Reply) + Send>;
+
+lazy_static! {
+    static ref ACTIONS: Mutex<HashMap<String, ActionCallback>> = Mutex::new(HashMap::new());
+    static ref PENDING_REQUESTS: Mutex<HashMap<String, VecDeque<ReplyCallback>>> = Mutex::new(HashMap::new());
+}
+
+unsafe extern "C" fn action_callback_stub(msg: *const RawMessage, peer: *const c_void) -> c_int {
+    let msg = &*(msg as *const Message);
+
+    let action = ACTIONS.lock().unwrap().get(msg.name()).cloned();
+
+    match action {
+        Some(action) => catch_panic("mp action", -1, || match action(msg, &Peer(peer)) {
+            Ok(_) => 0,
+            Err(err) => {
+                debug!("mp action `{}` failed, {}", msg.name(), err);
+
+                -1
+            }
+        }),
+        None => -1,
+    }
+}
+
+unsafe extern "C" fn reply_callback_stub(request: *const RawMessage, reply: *const ffi::rte_mp_reply) -> c_int {
+    let request = &*(request as *const Message);
+    let callback = PENDING_REQUESTS
+        .lock()
+        .unwrap()
+        .get_mut(request.name())
+        .and_then(VecDeque::pop_front);
+
+    match callback {
+        Some(callback) => catch_panic("mp reply callback", -1, || {
+            callback(request, &Reply::from_raw(&*reply));
+
+            0
+        }),
+        None => -1,
+    }
+}
+
+/// Register an action which is called with the messages of the name sent by the peer processes.
+///
+/// The requests must be replied by the action with `Peer::reply`.
+pub fn action_register<S, F>(name: S, action: F) -> Result<()>
+where
+    S: AsRef<str>,
+    F: Fn(&Message, &Peer) -> Result<()> + Send + Sync + 'static,
+{
+    let name = name.as_ref();
+
+    ACTIONS.lock().unwrap().insert(name.to_owned(), Arc::new(action));
+
+    let ret = unsafe { ffi::rte_mp_action_register(name.as_cstring().as_ptr(), Some(action_callback_stub)) };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        let err = rte_error();
+
+        ACTIONS.lock().unwrap().remove(name);
+
+        Err(err.into())
+    }
+}
+
+/// Unregister the action of the name.
+pub fn action_unregister<S: AsRef<str>>(name: S) {
+    let name = name.as_ref();
+
+    unsafe { ffi::rte_mp_action_unregister(name.as_cstring().as_ptr()) };
+
+    ACTIONS.lock().unwrap().remove(name);
+}
+
+fn timespec(d: Duration) -> ffi::timespec {
+    ffi::timespec {
+        tv_sec: d.as_secs() as _,
+        tv_nsec: d.subsec_nanos() as _,
+    }
+}
@@ -0,0 +1,63 @@
+//! Spin-wait primitives.
+//!
+//! The busy loops polling a shared variable should pause the CPU between the loads,
+//! e.g. by the `PAUSE` instruction on x86 or the `WFE` instruction on ARM,
+//! which reduces the power consumption and yields the pipeline to the sibling hyper-thread.
+//!
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, Ordering};
+
+use ffi;
+
+// the memory orders of the GCC atomic builtins
+const ATOMIC_RELAXED: c_int = 0;
+const ATOMIC_ACQUIRE: c_int = 2;
+
+fn memorder(order: Ordering) -> c_int {
+    match order {
+        Ordering::Relaxed => ATOMIC_RELAXED,
+        Ordering::Acquire => ATOMIC_ACQUIRE,
+        _ => panic!(
+            "only the relaxed or acquire ordering can be used to wait, got {:?}",
+            order
+        ),
+    }
+}
+
+/// Pause CPU execution for a short while.
+///
+/// This call is intended for tight loops which poll a shared resource or wait for an event.
+#[inline]
+pub fn pause() {
+    unsafe { ffi::_rte_pause() }
+}
+
+/// Wait for the 16-bit variable to be updated with the expected value.
+///
+/// # Panics
+///
+/// Panics if `order` is not `Relaxed` or `Acquire`.
+#[inline]
+pub fn wait_until_equal_16(v: &AtomicU16, expected: u16, order: Ordering) {
+    unsafe { ffi::_rte_wait_until_equal_16(v as *const _ as *mut u16, expected, memorder(order)) }
+}
+
+/// Wait for the 32-bit variable to be updated with the expected value.
+///
+/// # Panics
+///
+/// Panics if `order` is not `Relaxed` or `Acquire`.
+#[inline]
+pub fn wait_until_equal_32(v: &AtomicU32, expected: u32, order: Ordering) {
+    unsafe { ffi::_rte_wait_until_equal_32(v as *const _ as *mut u32, expected, memorder(order)) }
+}
+
+/// Wait for the 64-bit variable to be updated with the expected value.
+///
+/// # Panics
+///
+/// Panics if `order` is not `Relaxed` or `Acquire`.
+#[inline]
+pub fn wait_until_equal_64(v: &AtomicU64, expected: u64, order: Ordering) {
+    unsafe { ffi::_rte_wait_until_equal_64(v as *const _ as *mut u64, expected, memorder(order)) }
+}
@@ -0,0 +1,211 @@
+//! RTE phase-fair reader-writer locks
+//!
+//! This file defines an API for phase-fair reader writer locks,
+//! which is a variant of typical reader-writer locks that prevent
+//! starvation. In this type of lock, readers and writers alternate.
+//! This significantly reduces the worst-case blocking for readers and writers.
+//!
+//! This is an implementation derived from FreeBSD
+//! based on the work described in:
+//!   Brandenburg, B. and Anderson, J. 2010. Spin-Based
+//!   Reader-Writer Synchronization for Multiprocessor Real-Time Systems
+//!
+//! `rte_pflock` only exists since DPDK 21.05, and its functions are all inline,
+//! so this is a port of the same algorithm.
+//!
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::hint;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU16, Ordering};
+
+// Allocation of bits to reader
+//
+// 15                 4 3 2 1 0
+// +-------------------+---+-+-+
+// | rin: reads issued |x|x| | |
+// +-------------------+---+-+-+
+//                          ^ ^
+//                          | |
+// PRES: writer present ----/ |
+// PHID: writer phase id -----/
+//
+// 15                4 3 2 1 0
+// +------------------+------+
+// |rout:read complete|unused|
+// +------------------+------+
+//
+// The maximum number of readers is 4095
+const PFLOCK_RINC: u16 = 0x100;
+const PFLOCK_WBITS: u16 = 0x3;
+const PFLOCK_PRES: u16 = 0x2;
+const PFLOCK_PHID: u16 = 0x1;
+const PFLOCK_LSB: u16 = 0xFFF0;
+
+#[inline]
+fn wait_until_equal(v: &AtomicU16, expected: u16) {
+    while v.load(Ordering::Acquire) != expected {
+        hint::spin_loop();
+    }
+}
+
+/// The phase-fair reader-writer lock protecting the data of type `T`.
+pub struct PfLock<T: ?Sized> {
+    rd_in: AtomicU16,
+    rd_out: AtomicU16,
+    wr_in: AtomicU16,
+    wr_out: AtomicU16,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for PfLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for PfLock<T> {}
+
+/// An RAII guard of the shared read access, the read lock will be released when it is dropped.
+#[must_use]
+pub struct PfLockReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a PfLock<T>,
+    _marker: PhantomData<*mut ()>,
+}
+
+unsafe impl<'a, T: ?Sized + Sync + 'a> Sync for PfLockReadGuard<'a, T> {}
+
+/// An RAII guard of the exclusive write access, the write lock will be released when it is dropped.
+#[must_use]
+pub struct PfLockWriteGuard<'a, T: ?Sized + 'a> {
+    lock: &'a PfLock<T>,
+    _marker: PhantomData<*mut ()>,
+}
+
+unsafe impl<'a, T: ?Sized + Sync + 'a> Sync for PfLockWriteGuard<'a, T> {}
+
+impl<'a, T: ?Sized> Drop for PfLockReadGuard<'a, T> {
+    fn drop(&mut self) {
+        self.lock.rd_out.fetch_add(PFLOCK_RINC, Ordering::Release);
+    }
+}
+
+impl<'a, T: ?Sized> Deref for PfLockReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> Drop for PfLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        // Migrate from write phase to read phase.
+        self.lock.rd_in.fetch_and(PFLOCK_LSB, Ordering::Release);
+
+        // Allow other writers to continue.
+        self.lock.wr_out.fetch_add(1, Ordering::Release);
+    }
+}
+
+impl<'a, T: ?Sized> Deref for PfLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> DerefMut for PfLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: Default> Default for PfLock<T> {
+    fn default() -> Self {
+        PfLock::new(T::default())
+    }
+}
+
+impl<T> From<T> for PfLock<T> {
+    fn from(data: T) -> Self {
+        PfLock::new(data)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for PfLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PfLock").field("data", &&*self.read()).finish()
+    }
+}
+
+impl<T> PfLock<T> {
+    /// Create a new phase-fair lock in the unlocked state.
+    #[inline]
+    pub fn new(data: T) -> Self {
+        PfLock {
+            rd_in: AtomicU16::new(0),
+            rd_out: AtomicU16::new(0),
+            wr_in: AtomicU16::new(0),
+            wr_out: AtomicU16::new(0),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Consume the lock, returning the protected data.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> PfLock<T> {
+    /// Take a read lock. Loop until the lock is held.
+    ///
+    /// A reader waits for the pending write phase to complete.
+    #[inline]
+    pub fn read(&self) -> PfLockReadGuard<'_, T> {
+        let w = self.rd_in.fetch_add(PFLOCK_RINC, Ordering::Acquire) & PFLOCK_WBITS;
+
+        if w != 0 {
+            // Wait for current write phase to complete.
+            while (self.rd_in.load(Ordering::Acquire) & PFLOCK_WBITS) == w {
+                hint::spin_loop();
+            }
+        }
+
+        PfLockReadGuard {
+            lock: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Take a write lock. Loop until the lock is held.
+    ///
+    /// The writers are served in order, and a writer only waits for the readers which came before it.
+    #[inline]
+    pub fn write(&self) -> PfLockWriteGuard<'_, T> {
+        // Acquire ownership of write-phase, this is the same as a ticket lock.
+        let ticket = self.wr_in.fetch_add(1, Ordering::Relaxed);
+
+        wait_until_equal(&self.wr_out, ticket);
+
+        // Acquire ticket on read-side in order to allow them to flush.
+        // Indicates to any incoming reader that a write-phase is pending.
+        let w = PFLOCK_PRES | (ticket & PFLOCK_PHID);
+        let ticket = self.rd_in.fetch_add(w, Ordering::Relaxed);
+
+        // Wait for any pending readers to flush.
+        wait_until_equal(&self.rd_out, ticket);
+
+        PfLockWriteGuard {
+            lock: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a mutable reference to the protected data.
+    ///
+    /// Since this call borrows the lock mutably, no locking needs to take place.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
+    }
+}
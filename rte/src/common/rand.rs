@@ -1,6 +1,9 @@
 //!  Pseudo-random Generators in RTE
 
+use rand::{Error, RngCore};
+
 use ffi;
+use utils::NotSend;
 
 /// Seed the pseudo-random generator.
 ///
@@ -18,3 +21,65 @@ pub fn srand(seed: u64) {
 pub fn rand() -> u64 {
     unsafe { ffi::_rte_rand() }
 }
+
+/// Generates a pseudo-random number with an upper bound.
+///
+/// This function returns an uniformly distributed (unbiased) random
+/// number less than a user-specified maximum value.
+pub fn rand_max(upper_bound: u64) -> u64 {
+    unsafe { ffi::rte_rand_max(upper_bound) }
+}
+
+/// A handle of the per-lcore pseudo-random generator.
+///
+/// The generator state is kept per lcore by EAL, so the handle has no state itself,
+/// but it should not be shared with the other threads, the unregistered non-EAL threads
+/// share one generator which is not thread-safe.
+///
+/// The generator is not cryptographically secure.
+#[derive(Clone, Debug, Default)]
+pub struct LcoreRng(NotSend);
+
+impl LcoreRng {
+    pub fn new() -> Self {
+        LcoreRng(NotSend::default())
+    }
+
+    /// Get a pseudo-random value between 0 and (upper_bound-1).
+    #[inline]
+    pub fn gen_max(&mut self, upper_bound: u64) -> u64 {
+        rand_max(upper_bound)
+    }
+
+    /// Return true with the probability of 1-in-`n`, e.g. to sample the packets.
+    #[inline]
+    pub fn one_in(&mut self, n: u64) -> bool {
+        n != 0 && rand_max(n) == 0
+    }
+}
+
+impl RngCore for LcoreRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        rand() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        rand()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let n = chunk.len();
+
+            chunk.copy_from_slice(&rand().to_ne_bytes()[..n]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
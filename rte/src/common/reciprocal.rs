@@ -0,0 +1,71 @@
+//! Reciprocal divide
+//!
+//! Dividing by a constant is replaced by a multiplication and shifts,
+//! which is much faster than the division on the data path.
+//!
+use std::ops::Div;
+
+use ffi;
+
+pub type RawReciprocal = ffi::rte_reciprocal;
+pub type RawReciprocalU64 = ffi::rte_reciprocal_u64;
+
+/// The precomputed reciprocal of an `u32` divisor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reciprocal(RawReciprocal);
+
+impl Reciprocal {
+    /// Compute the reciprocal of the divisor `d`, which must not be zero.
+    pub fn new(d: u32) -> Self {
+        assert!(d != 0, "divide by zero");
+
+        Reciprocal(unsafe { ffi::rte_reciprocal_value(d) })
+    }
+
+    /// Divide `a` by the divisor.
+    #[inline]
+    pub fn divide(&self, a: u32) -> u32 {
+        let t = ((u64::from(a) * u64::from(self.0.m)) >> 32) as u32;
+
+        (t + ((a - t) >> self.0.sh1)) >> self.0.sh2
+    }
+}
+
+impl Div<Reciprocal> for u32 {
+    type Output = u32;
+
+    #[inline]
+    fn div(self, rhs: Reciprocal) -> u32 {
+        rhs.divide(self)
+    }
+}
+
+/// The precomputed reciprocal of an `u64` divisor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReciprocalU64(RawReciprocalU64);
+
+impl ReciprocalU64 {
+    /// Compute the reciprocal of the divisor `d`, which must not be zero.
+    pub fn new(d: u64) -> Self {
+        assert!(d != 0, "divide by zero");
+
+        ReciprocalU64(unsafe { ffi::rte_reciprocal_value_u64(d) })
+    }
+
+    /// Divide `a` by the divisor.
+    #[inline]
+    pub fn divide(&self, a: u64) -> u64 {
+        let t = ((u128::from(a) * u128::from(self.0.m)) >> 64) as u64;
+
+        (t + ((a - t) >> self.0.sh1)) >> self.0.sh2
+    }
+}
+
+impl Div<ReciprocalU64> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn div(self, rhs: ReciprocalU64) -> u64 {
+        rhs.divide(self)
+    }
+}
@@ -0,0 +1,182 @@
+//! RTE Read-Write Locks
+//!
+//! This file defines an API for read-write locks. The lock is used to
+//! protect data that allows multiple readers in parallel, but only
+//! one writer. All readers are blocked until the writer is finished
+//! writing.
+//!
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use ffi;
+
+pub type RawRwLock = ffi::rte_rwlock_t;
+pub type RawRwLockPtr = *mut ffi::rte_rwlock_t;
+
+/// The RTE read-write lock protecting the data of type `T`.
+pub struct RwLock<T: ?Sized> {
+    raw: UnsafeCell<RawRwLock>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+/// An RAII guard of the shared read access, the read lock will be released when it is dropped.
+#[must_use]
+pub struct RwLockReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+    _marker: PhantomData<*mut ()>,
+}
+
+unsafe impl<'a, T: ?Sized + Sync + 'a> Sync for RwLockReadGuard<'a, T> {}
+
+/// An RAII guard of the exclusive write access, the write lock will be released when it is dropped.
+#[must_use]
+pub struct RwLockWriteGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+    _marker: PhantomData<*mut ()>,
+}
+
+unsafe impl<'a, T: ?Sized + Sync + 'a> Sync for RwLockWriteGuard<'a, T> {}
+
+impl<'a, T: ?Sized> Drop for RwLockReadGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { ffi::_rte_rwlock_read_unlock(self.lock.raw.get()) }
+    }
+}
+
+impl<'a, T: ?Sized> Deref for RwLockReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> Drop for RwLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { ffi::_rte_rwlock_write_unlock(self.lock.raw.get()) }
+    }
+}
+
+impl<'a, T: ?Sized> Deref for RwLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T: ?Sized> DerefMut for RwLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        RwLock::new(T::default())
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(data: T) -> Self {
+        RwLock::new(data)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.try_read() {
+            Some(guard) => f.debug_struct("RwLock").field("data", &&*guard).finish(),
+            None => f.debug_struct("RwLock").field("data", &"<locked>").finish(),
+        }
+    }
+}
+
+impl<T> RwLock<T> {
+    /// Create a new read-write lock in the unlocked state.
+    #[inline]
+    pub fn new(data: T) -> Self {
+        RwLock {
+            raw: UnsafeCell::new(RawRwLock { cnt: 0 }),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Consume the lock, returning the protected data.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    #[inline]
+    pub fn as_raw(&self) -> RawRwLockPtr {
+        self.raw.get()
+    }
+
+    /// Take a read lock. Loop until the lock is held.
+    #[inline]
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        unsafe { ffi::_rte_rwlock_read_lock(self.raw.get()) }
+
+        RwLockReadGuard {
+            lock: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Try to take a read lock.
+    ///
+    /// Return `None` if a writer holds the lock.
+    #[inline]
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        if unsafe { ffi::_rte_rwlock_read_trylock(self.raw.get()) } == 0 {
+            Some(RwLockReadGuard {
+                lock: self,
+                _marker: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Take a write lock. Loop until the lock is held.
+    #[inline]
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        unsafe { ffi::_rte_rwlock_write_lock(self.raw.get()) }
+
+        RwLockWriteGuard {
+            lock: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Try to take a write lock.
+    ///
+    /// Return `None` if the lock is already held for reading or writing.
+    #[inline]
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        if unsafe { ffi::_rte_rwlock_write_trylock(self.raw.get()) } == 0 {
+            Some(RwLockWriteGuard {
+                lock: self,
+                _marker: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the protected data.
+    ///
+    /// Since this call borrows the lock mutably, no locking needs to take place.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
+    }
+}
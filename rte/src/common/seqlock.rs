@@ -0,0 +1,162 @@
+//! RTE Seqlock
+//!
+//! A sequence lock (seqlock) is a synchronization primitive allowing
+//! multiple, parallel, readers to efficiently and safely (i.e., in a
+//! data-race free manner) access lock-protected data. The RTE seqlock
+//! permits multiple writers as well. A spinlock is used for
+//! writer-writer synchronization.
+//!
+//! A reader never blocks a writer. Very high frequency writes may
+//! prevent readers from making progress.
+//!
+//! A seqlock is not preemption-safe on the writer side. If a writer is
+//! preempted, it may block readers until the writer thread is allowed
+//! to continue. Heavy computations should be kept out of the
+//! writer-side critical section, to avoid delaying readers.
+//!
+//! Seqlocks are useful for data which are read by many cores, at a
+//! high frequency, and relatively infrequently written to.
+//!
+//! `rte_seqlock` only exists since DPDK 22.07, and its functions are all inline,
+//! so this is a port of the same algorithm on top of the RTE spinlock.
+//!
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::sync::atomic::{fence, AtomicU32, Ordering};
+
+use spinlock::{LockGuard, SpinLock, SpinLockImpl};
+
+/// The RTE seqlock protecting the data of type `T`.
+///
+/// The readers get a copy of the data, so `T` must be `Copy`.
+pub struct SeqLock<T: Copy> {
+    sn: AtomicU32,
+    lock: SpinLock<()>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: Copy + Send> Send for SeqLock<T> {}
+unsafe impl<T: Copy + Send> Sync for SeqLock<T> {}
+
+/// An RAII guard of the writer-side critical section.
+///
+/// The new data is made visible to the readers when the guard is dropped.
+#[must_use]
+pub struct SeqLockWriteGuard<'a, T: Copy + 'a> {
+    seqlock: &'a SeqLock<T>,
+    _guard: LockGuard<'a, SpinLockImpl, ()>,
+}
+
+impl<'a, T: Copy> Drop for SeqLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        let sn = self.seqlock.sn.load(Ordering::Relaxed) + 1;
+
+        self.seqlock.sn.store(sn, Ordering::Release);
+    }
+}
+
+impl<'a, T: Copy> Deref for SeqLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.seqlock.data.get() }
+    }
+}
+
+impl<'a, T: Copy> DerefMut for SeqLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.seqlock.data.get() }
+    }
+}
+
+impl<T: Copy + Default> Default for SeqLock<T> {
+    fn default() -> Self {
+        SeqLock::new(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for SeqLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SeqLock").field("data", &self.read()).finish()
+    }
+}
+
+impl<T: Copy> SeqLock<T> {
+    /// Create a new seqlock.
+    pub fn new(data: T) -> Self {
+        SeqLock {
+            sn: AtomicU32::new(0),
+            lock: SpinLock::new(()),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Begin a read-side critical section, returning the sequence number.
+    #[inline]
+    pub fn read_begin(&self) -> u32 {
+        self.sn.load(Ordering::Acquire)
+    }
+
+    /// Determine if a retry of the read-side critical section is needed.
+    ///
+    /// The retry is needed if a write happened during or before the critical section.
+    #[inline]
+    pub fn read_retry(&self, begin_sn: u32) -> bool {
+        // make sure the data loads happens before the sn load
+        fence(Ordering::Acquire);
+
+        let end_sn = self.sn.load(Ordering::Relaxed);
+
+        // an odd sequence number means a write was in progress
+        (begin_sn & 1) != 0 || begin_sn != end_sn
+    }
+
+    /// Read a consistent copy of the data, retrying while a writer is active.
+    #[inline]
+    pub fn read(&self) -> T {
+        loop {
+            let sn = self.read_begin();
+            // the copy may be torn by a writer, so it's only a valid `T` after the retry check.
+            let data = unsafe { ptr::read_volatile(self.data.get() as *const MaybeUninit<T>) };
+
+            if !self.read_retry(sn) {
+                return unsafe { data.assume_init() };
+            }
+        }
+    }
+
+    /// Begin a write-side critical section.
+    ///
+    /// The other writers are blocked until the returned guard is dropped.
+    #[inline]
+    pub fn write(&self) -> SeqLockWriteGuard<'_, T> {
+        let guard = self.lock.lock();
+
+        let sn = self.sn.load(Ordering::Relaxed) + 1;
+
+        self.sn.store(sn, Ordering::Relaxed);
+
+        // make sure the sn store happens before the data stores
+        fence(Ordering::Release);
+
+        SeqLockWriteGuard {
+            seqlock: self,
+            _guard: guard,
+        }
+    }
+
+    /// Replace the data as a single write-side critical section.
+    #[inline]
+    pub fn store(&self, data: T) {
+        *self.write() = data;
+    }
+
+    /// Get a mutable reference to the protected data.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
+    }
+}
@@ -0,0 +1,331 @@
+//!
+//! Typed data shared between the primary and secondary processes.
+//!
+//! The data is stored in a named memzone after a header recording the magic, the version,
+//! the type name and layout and the number of elements, which are validated when a process looks it up.
+//!
+use std::any;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Deref;
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{
+    AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
+};
+
+use anyhow::Result;
+
+use errors::ErrorKind;
+use ffi;
+use memory::SocketId;
+use memzone::{MemoryZone, MemzoneFlags};
+
+/// The magic number identifying the shared data, `RTE_SHM\0`.
+pub const SHARED_MAGIC: u64 = 0x5254_455f_5348_4d00;
+
+/// The types which can be looked up from the shared data published by another process.
+///
+/// # Safety
+///
+/// Any bit pattern of the type must be a valid value, and the type must not contain pointers,
+/// since the memzone may be mapped at a different address in the secondary process.
+pub unsafe trait SharedData {}
+
+macro_rules! shared_data {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl SharedData for $ty {}
+        )*
+    };
+}
+
+shared_data!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+shared_data!(
+    AtomicU8,
+    AtomicU16,
+    AtomicU32,
+    AtomicU64,
+    AtomicUsize,
+    AtomicI8,
+    AtomicI16,
+    AtomicI32,
+    AtomicI64,
+    AtomicIsize
+);
+
+unsafe impl<T: SharedData, const N: usize> SharedData for [T; N] {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct Header {
+    magic: u64,
+    type_hash: u64,
+    version: u32,
+    align: u32,
+    size: u64,
+    len: u64,
+}
+
+impl Header {
+    fn new<T>(version: u32, len: usize) -> Self {
+        Header {
+            magic: SHARED_MAGIC,
+            type_hash: type_hash::<T>(),
+            version,
+            align: mem::align_of::<T>() as u32,
+            size: mem::size_of::<T>() as u64,
+            len: len as u64,
+        }
+    }
+
+    // the data follows the header, aligned to the cache line.
+    fn data_offset<T>() -> usize {
+        let align = mem::align_of::<T>().max(ffi::RTE_CACHE_LINE_SIZE as usize);
+
+        (mem::size_of::<Header>() + align - 1) & !(align - 1)
+    }
+
+    fn zone_size<T>(len: usize) -> usize {
+        Self::data_offset::<T>() + mem::size_of::<T>() * len
+    }
+
+    fn validate<T>(&self, name: &str, version: u32, len: Option<usize>) -> Result<()> {
+        let expected = Header::new::<T>(version, len.unwrap_or(self.len as usize));
+
+        if self.magic != expected.magic {
+            Err(ErrorKind::InvalidSharedData(format!("{} is not a shared data memzone", name)).into())
+        } else if self.type_hash != expected.type_hash {
+            Err(
+                ErrorKind::InvalidSharedData(format!("{} stores another type than {}", name, any::type_name::<T>()))
+                    .into(),
+            )
+        } else if self.version != expected.version {
+            Err(ErrorKind::InvalidSharedData(format!(
+                "{} has version {}, but version {} is expected",
+                name, self.version, version
+            ))
+            .into())
+        } else if self.size != expected.size || self.align != expected.align {
+            Err(ErrorKind::InvalidSharedData(format!(
+                "{} stores {} bytes elements aligned to {}, but the type has {} bytes aligned to {}",
+                name, self.size, self.align, expected.size, expected.align
+            ))
+            .into())
+        } else if self.len != expected.len {
+            Err(ErrorKind::InvalidSharedData(format!(
+                "{} has {} elements, but {} is expected",
+                name, self.len, expected.len
+            ))
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// The FNV-1a hash of the type name, which identifies the type in all the processes built from the same code.
+fn type_hash<T>() -> u64 {
+    any::type_name::<T>().bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// A slice of `T` stored in a named memzone, which can be looked up by the other processes.
+///
+/// The type must have the same layout in all the processes and must not contain pointers,
+/// since the memzone may be mapped at a different address in the secondary process,
+/// which is required by `SharedData` to look it up.
+pub struct SharedSlice<T> {
+    mz: MemoryZone,
+    data: *mut T,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+unsafe impl<T: Sync> Send for SharedSlice<T> {}
+unsafe impl<T: Sync> Sync for SharedSlice<T> {}
+
+impl<T: Copy> SharedSlice<T> {
+    /// Publish a copy of the slice in a memzone named `name`.
+    pub fn create<S: AsRef<str>>(name: S, version: u32, values: &[T], socket_id: SocketId) -> Result<Self> {
+        let s = Self::reserve(name, version, values.len(), socket_id)?;
+
+        unsafe { ptr::copy_nonoverlapping(values.as_ptr(), s.data, values.len()) };
+
+        Ok(s)
+    }
+
+    /// Publish a slice of `len` copies of the value in a memzone named `name`.
+    pub fn from_elem<S: AsRef<str>>(name: S, version: u32, value: T, len: usize, socket_id: SocketId) -> Result<Self> {
+        let s = Self::reserve(name, version, len, socket_id)?;
+
+        for i in 0..len {
+            unsafe { ptr::write(s.data.add(i), value) };
+        }
+
+        Ok(s)
+    }
+}
+
+impl<T: SharedData> SharedSlice<T> {
+    /// Lookup the slice published by another process, and validate its type, version and layout.
+    pub fn lookup<S: AsRef<str>>(name: S, version: u32) -> Result<Self> {
+        Self::lookup_checked(name.as_ref(), version, None)
+    }
+}
+
+impl<T> SharedSlice<T> {
+    fn reserve<S: AsRef<str>>(name: S, version: u32, len: usize, socket_id: SocketId) -> Result<Self> {
+        let mz = MemoryZone::reserve(
+            name,
+            Header::zone_size::<T>(len),
+            socket_id,
+            MemzoneFlags::empty(),
+            ffi::RTE_CACHE_LINE_SIZE,
+        )?;
+
+        unsafe {
+            ptr::write(mz.addr() as *mut Header, Header::new::<T>(version, len));
+
+            Ok(Self::from_zone(mz, len))
+        }
+    }
+
+    fn lookup_checked(name: &str, version: u32, len: Option<usize>) -> Result<Self> {
+        let mz = MemoryZone::lookup(name)?;
+
+        if mz.len() < mem::size_of::<Header>() {
+            return Err(ErrorKind::InvalidSharedData(format!("{} is too small", name)).into());
+        }
+
+        let header = unsafe { ptr::read(mz.addr() as *const Header) };
+
+        header.validate::<T>(name, version, len)?;
+
+        if mz.len() < Header::zone_size::<T>(header.len as usize) {
+            return Err(ErrorKind::InvalidSharedData(format!("{} is truncated", name)).into());
+        }
+
+        Ok(unsafe { Self::from_zone(mz, header.len as usize) })
+    }
+
+    unsafe fn from_zone(mz: MemoryZone, len: usize) -> Self {
+        let data = (mz.addr() as *mut u8).add(Header::data_offset::<T>()) as *mut T;
+
+        SharedSlice {
+            mz,
+            data,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The version of the shared data.
+    pub fn version(&self) -> u32 {
+        unsafe { (*(self.mz.addr() as *const Header)).version }
+    }
+
+    /// The memzone storing the shared data.
+    pub fn memzone(&self) -> &MemoryZone {
+        &self.mz
+    }
+
+    /// Get a mutable reference to the shared data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure no other process accesses the data at the same time,
+    /// use atomic types for the data updated concurrently instead.
+    pub unsafe fn get_mut(&mut self) -> &mut [T] {
+        slice::from_raw_parts_mut(self.data, self.len)
+    }
+
+    /// Free the memzone, the other processes must not use the shared data any more.
+    pub fn free(self) -> Result<()> {
+        self.mz.free()
+    }
+}
+
+impl<T> Deref for SharedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.data, self.len) }
+    }
+}
+
+/// A value of `T` stored in a named memzone, which can be looked up by the other processes.
+///
+/// The type must have the same layout in all the processes and must not contain pointers,
+/// since the memzone may be mapped at a different address in the secondary process,
+/// which is required by `SharedData` to look it up.
+pub struct SharedBox<T>(SharedSlice<T>);
+
+impl<T: SharedData> SharedBox<T> {
+    /// Lookup the value published by another process, and validate its type, version and layout.
+    pub fn lookup<S: AsRef<str>>(name: S, version: u32) -> Result<Self> {
+        SharedSlice::lookup_checked(name.as_ref(), version, Some(1)).map(SharedBox)
+    }
+}
+
+impl<T> SharedBox<T> {
+    /// Publish the value in a memzone named `name`.
+    pub fn create<S: AsRef<str>>(name: S, version: u32, value: T, socket_id: SocketId) -> Result<Self> {
+        let s = SharedSlice::<T>::reserve(name, version, 1, socket_id)?;
+
+        unsafe { ptr::write(s.data, value) };
+
+        Ok(SharedBox(s))
+    }
+
+    /// The version of the shared data.
+    pub fn version(&self) -> u32 {
+        self.0.version()
+    }
+
+    /// The memzone storing the shared data.
+    pub fn memzone(&self) -> &MemoryZone {
+        self.0.memzone()
+    }
+
+    /// Get a mutable reference to the shared data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure no other process accesses the data at the same time,
+    /// use atomic types for the data updated concurrently instead.
+    pub unsafe fn get_mut(&mut self) -> &mut T {
+        &mut *self.0.data
+    }
+
+    /// Free the memzone, the other processes must not use the shared data any more.
+    pub fn free(self) -> Result<()> {
+        self.0.free()
+    }
+}
+
+impl<T> Deref for SharedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.0.data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header() {
+        let header = Header::new::<u64>(1, 4);
+
+        assert!(header.validate::<u64>("test", 1, Some(4)).is_ok());
+        assert!(header.validate::<u64>("test", 1, None).is_ok());
+        assert!(header.validate::<i64>("test", 1, Some(4)).is_err());
+        assert!(header.validate::<u64>("test", 2, Some(4)).is_err());
+        assert!(header.validate::<u64>("test", 1, Some(2)).is_err());
+        assert!(header.validate::<[u32; 2]>("test", 1, Some(4)).is_err());
+    }
+}
@@ -1,4 +1,8 @@
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::ptr;
 
 use libc;
 
@@ -11,9 +15,10 @@ pub type RawRecursiveSpinLock = ffi::rte_spinlock_recursive_t;
 pub type RawRecursiveSpinLockPtr = *mut ffi::rte_spinlock_recursive_t;
 
 pub trait LockImpl {
-    type RawLock: ?Sized;
+    type RawLock;
 
-    fn init(p: *mut Self::RawLock);
+    /// A raw lock in the unlocked state.
+    fn init() -> Self::RawLock;
 
     fn lock(p: *mut Self::RawLock);
 
@@ -24,95 +29,159 @@ pub trait LockImpl {
     fn is_locked(p: *mut Self::RawLock) -> libc::c_int;
 }
 
-pub struct Lock<T: LockImpl>(T::RawLock);
+/// A lock which can't be taken twice by the same lcore,
+/// so the guard may hand out a mutable reference to the protected data.
+pub trait ExclusiveLockImpl: LockImpl {}
+
+/// A lock protecting the data of type `T`.
+///
+/// The lock is initialized in the unlocked state when it is created,
+/// and the data can only be accessed through the guard returned by `lock()` or `try_lock()`.
+pub struct Lock<L: LockImpl, T: ?Sized> {
+    raw: UnsafeCell<L::RawLock>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<L: LockImpl, T: ?Sized + Send> Send for Lock<L, T> {}
+unsafe impl<L: LockImpl, T: ?Sized + Send> Sync for Lock<L, T> {}
+
+/// An RAII guard of the lock, the lock will be released when it is dropped.
+#[must_use]
+pub struct LockGuard<'a, L: LockImpl + 'a, T: ?Sized + 'a> {
+    lock: &'a Lock<L, T>,
+    // the recursive lock must be released by the lcore which took it.
+    _marker: PhantomData<*mut ()>,
+}
 
-pub struct LockGuard<'a, T: LockImpl + 'a>(&'a mut Lock<T>);
+unsafe impl<'a, L: LockImpl + 'a, T: ?Sized + Sync + 'a> Sync for LockGuard<'a, L, T> {}
 
-impl<'a, T: LockImpl> Drop for LockGuard<'a, T> {
+impl<'a, L: LockImpl, T: ?Sized> Drop for LockGuard<'a, L, T> {
     fn drop(&mut self) {
-        self.0.unlock();
+        L::unlock(self.lock.raw.get());
     }
 }
 
-impl<T: LockImpl> Deref for Lock<T> {
-    type Target = T::RawLock;
+impl<'a, L: LockImpl, T: ?Sized> Deref for LockGuard<'a, L, T> {
+    type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        unsafe { &*self.lock.data.get() }
     }
 }
 
-impl<T: LockImpl> DerefMut for Lock<T> {
+impl<'a, L: ExclusiveLockImpl, T: ?Sized> DerefMut for LockGuard<'a, L, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<'a, L: LockImpl, T: ?Sized + fmt::Debug> fmt::Debug for LockGuard<'a, L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
     }
 }
 
-/// Take the spinlock.
-impl<'a, T: LockImpl> Lock<T> {
+impl<L: LockImpl, T: Default> Default for Lock<L, T> {
+    fn default() -> Self {
+        Lock::new(T::default())
+    }
+}
+
+impl<L: LockImpl, T> From<T> for Lock<L, T> {
+    fn from(data: T) -> Self {
+        Lock::new(data)
+    }
+}
+
+impl<L: LockImpl, T: ?Sized + fmt::Debug> fmt::Debug for Lock<L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.try_lock() {
+            Some(guard) => f.debug_struct("Lock").field("data", &&*guard).finish(),
+            None => f.debug_struct("Lock").field("data", &"<locked>").finish(),
+        }
+    }
+}
+
+impl<L: LockImpl, T> Lock<L, T> {
+    /// Create a new lock in the unlocked state.
     #[inline]
-    pub fn as_raw(&mut self) -> *mut T::RawLock {
-        &mut self.0
+    pub fn new(data: T) -> Self {
+        Lock {
+            raw: UnsafeCell::new(L::init()),
+            data: UnsafeCell::new(data),
+        }
     }
 
-    /// Initialize the spinlock to an unlocked state.
+    /// Consume the lock, returning the protected data.
     #[inline]
-    pub fn init(&mut self) -> &Self {
-        T::init(&mut self.0);
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
 
-        self
+impl<L: LockImpl, T: ?Sized> Lock<L, T> {
+    #[inline]
+    pub fn as_raw(&self) -> *mut L::RawLock {
+        self.raw.get()
     }
 
     /// Test if the lock is taken.
     #[inline]
     pub fn is_locked(&self) -> bool {
-        T::is_locked(&self.0 as *const _ as *mut _) != 0
+        L::is_locked(self.raw.get()) != 0
     }
 
-    /// Take the spinlock.
+    /// Take the lock, spinning until it is available.
     #[inline]
-    pub fn lock(&'a mut self) -> LockGuard<'a, T> {
-        T::lock(&mut self.0);
+    pub fn lock(&self) -> LockGuard<'_, L, T> {
+        L::lock(self.raw.get());
 
-        LockGuard(self)
+        LockGuard {
+            lock: self,
+            _marker: PhantomData,
+        }
     }
 
     /// Try to take the lock.
     #[inline]
-    pub fn trylock(&'a mut self) -> Option<LockGuard<'a, T>> {
-        if T::trylock(&mut self.0) == 0 {
+    pub fn try_lock(&self) -> Option<LockGuard<'_, L, T>> {
+        if L::trylock(self.raw.get()) == 0 {
             None
         } else {
-            Some(LockGuard(self))
+            Some(LockGuard {
+                lock: self,
+                _marker: PhantomData,
+            })
         }
     }
 
-    /// Release the spinlock.
+    /// Get a mutable reference to the protected data.
+    ///
+    /// Since this call borrows the lock mutably, no locking needs to take place.
     #[inline]
-    pub fn unlock(&mut self) -> &Self {
-        T::unlock(&mut self.0);
-
-        self
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
     }
 }
 
 pub enum SpinLockImpl {}
 
-pub type SpinLock = Lock<SpinLockImpl>;
+/// The RTE spinlock protecting the data of type `T`.
+pub type SpinLock<T> = Lock<SpinLockImpl, T>;
+
+impl ExclusiveLockImpl for SpinLockImpl {}
 
 impl LockImpl for SpinLockImpl {
     type RawLock = RawSpinLock;
 
     #[inline]
-    fn init(p: *mut Self::RawLock) {
-        unsafe {
-            (*p).locked = 0;
-        }
+    fn init() -> Self::RawLock {
+        RawSpinLock { locked: 0 }
     }
 
     #[inline]
     fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
-        unsafe { (*p).locked }
+        unsafe { ptr::read_volatile(&(*p).locked) }
     }
 
     #[inline]
@@ -133,21 +202,22 @@ impl LockImpl for SpinLockImpl {
 
 pub enum TmSpinLockImpl {}
 
-pub type TmSpinLock = Lock<TmSpinLockImpl>;
+/// The RTE spinlock using hardware memory transactions if available.
+pub type TmSpinLock<T> = Lock<TmSpinLockImpl, T>;
+
+impl ExclusiveLockImpl for TmSpinLockImpl {}
 
 impl LockImpl for TmSpinLockImpl {
     type RawLock = RawSpinLock;
 
     #[inline]
-    fn init(p: *mut Self::RawLock) {
-        unsafe {
-            (*p).locked = 0;
-        }
+    fn init() -> Self::RawLock {
+        RawSpinLock { locked: 0 }
     }
 
     #[inline]
     fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
-        unsafe { (*p).locked }
+        unsafe { ptr::read_volatile(&(*p).locked) }
     }
 
     #[inline]
@@ -172,23 +242,28 @@ pub fn tm_supported() -> bool {
 
 pub enum RecursiveSpinLockImpl {}
 
-pub type RecursiveSpinLock = Lock<RecursiveSpinLockImpl>;
+/// The RTE recursive spinlock, which may be taken again by the lcore holding it.
+///
+/// Since the same lcore may hold several guards at the same time,
+/// the guard only gives a shared reference to the protected data,
+/// use a `Cell` or `RefCell` for interior mutability.
+pub type RecursiveSpinLock<T> = Lock<RecursiveSpinLockImpl, T>;
 
 impl LockImpl for RecursiveSpinLockImpl {
     type RawLock = RawRecursiveSpinLock;
 
     #[inline]
-    fn init(p: *mut Self::RawLock) {
-        unsafe {
-            (*p).sl.locked = 0;
-            (*p).user = -1;
-            (*p).count = 0;
+    fn init() -> Self::RawLock {
+        RawRecursiveSpinLock {
+            sl: RawSpinLock { locked: 0 },
+            user: -1,
+            count: 0,
         }
     }
 
     #[inline]
     fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
-        unsafe { (*p).sl.locked }
+        unsafe { ptr::read_volatile(&(*p).sl.locked) }
     }
 
     #[inline]
@@ -209,23 +284,24 @@ impl LockImpl for RecursiveSpinLockImpl {
 
 pub enum RecursiveTmSpinLockImpl {}
 
-pub type RecursiveTmSpinLock = Lock<RecursiveTmSpinLockImpl>;
+/// The RTE recursive spinlock using hardware memory transactions if available.
+pub type RecursiveTmSpinLock<T> = Lock<RecursiveTmSpinLockImpl, T>;
 
 impl LockImpl for RecursiveTmSpinLockImpl {
     type RawLock = RawRecursiveSpinLock;
 
     #[inline]
-    fn init(p: *mut Self::RawLock) {
-        unsafe {
-            (*p).sl.locked = 0;
-            (*p).user = -1;
-            (*p).count = 0;
+    fn init() -> Self::RawLock {
+        RawRecursiveSpinLock {
+            sl: RawSpinLock { locked: 0 },
+            user: -1,
+            count: 0,
         }
     }
 
     #[inline]
     fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
-        unsafe { (*p).sl.locked }
+        unsafe { ptr::read_volatile(&(*p).sl.locked) }
     }
 
     #[inline]
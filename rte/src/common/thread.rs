@@ -0,0 +1,183 @@
+//! Thread names, priorities and control threads.
+//!
+//! The control threads are created on the CPUs reserved for EAL at the initialization,
+//! which exclude the dataplane and service lcores, so the housekeeping jobs, e.g. statistics or telemetry,
+//! don't steal the cycles from the lcores polling the queues.
+//!
+use std::any::Any;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use anyhow::{anyhow, Result};
+use libc;
+
+use ffi;
+
+use errors::{rte_error, ErrorKind, RteError};
+use utils::AsCString;
+
+/// The maximum length of a thread name, including the terminating NUL.
+pub const THREAD_NAME_LEN: usize = 16;
+
+/// Set the name of the current thread.
+///
+/// The name is truncated to 15 bytes by the kernel.
+pub fn set_name<S: AsRef<str>>(name: S) -> Result<()> {
+    let name = name.as_cstring();
+
+    if unsafe { ffi::rte_thread_setname(libc::pthread_self(), name.as_ptr()) } < 0 {
+        Err(anyhow!("fail to set the name of thread to {:?}", name))
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the name of the current thread.
+pub fn name() -> Result<String> {
+    let mut buf = [0 as c_char; THREAD_NAME_LEN];
+
+    if unsafe { ffi::rte_thread_getname(libc::pthread_self(), buf.as_mut_ptr(), buf.len()) } < 0 {
+        Err(anyhow!("fail to get the name of thread"))
+    } else {
+        Ok(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_string_lossy().into_owned())
+    }
+}
+
+/// The scheduling priority of a thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    /// The normal time-sharing scheduling.
+    Normal,
+    /// The highest real-time round-robin scheduling.
+    ///
+    /// A busy-polling thread with this priority may starve the kernel threads on the same CPU.
+    RealtimeCritical,
+}
+
+/// Set the scheduling priority of the current thread.
+pub fn set_priority(priority: Priority) -> Result<()> {
+    let policy = match priority {
+        Priority::Normal => libc::SCHED_OTHER,
+        Priority::RealtimeCritical => libc::SCHED_RR,
+    };
+    let param = libc::sched_param {
+        sched_priority: match priority {
+            Priority::Normal => 0,
+            Priority::RealtimeCritical => unsafe { libc::sched_get_priority_max(policy) },
+        },
+    };
+
+    match unsafe { libc::pthread_setschedparam(libc::pthread_self(), policy, &param) } {
+        0 => Ok(()),
+        errno => Err(ErrorKind::OsError(errno).into()),
+    }
+}
+
+/// Get the scheduling priority of the current thread.
+pub fn priority() -> Result<Priority> {
+    let mut policy = 0;
+    let mut param: libc::sched_param = unsafe { mem::zeroed() };
+
+    match unsafe { libc::pthread_getschedparam(libc::pthread_self(), &mut policy, &mut param) } {
+        0 if policy == libc::SCHED_OTHER => Ok(Priority::Normal),
+        0 => Ok(Priority::RealtimeCritical),
+        errno => Err(ErrorKind::OsError(errno).into()),
+    }
+}
+
+/// Register the current non-EAL thread as an lcore.
+pub fn register() -> Result<()> {
+    rte_check!(unsafe { ffi::rte_thread_register() }; err => { anyhow!(rte_error()) })
+}
+
+/// Unregister the current thread and release the lcore if one was associated.
+pub fn unregister() {
+    unsafe { ffi::rte_thread_unregister() }
+}
+
+type ThreadResult<T> = ::std::result::Result<T, Box<dyn Any + Send + 'static>>;
+
+/// An owned permission to join on a control thread.
+pub struct JoinHandle<T> {
+    thread: libc::pthread_t,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for JoinHandle<T> {}
+
+impl<T> JoinHandle<T> {
+    /// The pthread ID of the control thread.
+    pub fn id(&self) -> libc::pthread_t {
+        self.thread
+    }
+
+    /// Wait for the control thread to finish, return its result or an error if it panicked.
+    pub fn join(self) -> Result<T> {
+        let mut ret = ptr::null_mut();
+
+        match unsafe { libc::pthread_join(self.thread, &mut ret) } {
+            0 => match *unsafe { Box::from_raw(ret as *mut ThreadResult<T>) } {
+                Ok(value) => Ok(value),
+                Err(_) => Err(anyhow!("control thread panicked")),
+            },
+            errno => Err(ErrorKind::OsError(errno).into()),
+        }
+    }
+}
+
+unsafe extern "C" fn ctrl_thread_stub<F, T>(arg: *mut c_void) -> *mut c_void
+where
+    F: FnOnce() -> T,
+{
+    let f = *Box::from_raw(arg as *mut F);
+    let res: ThreadResult<T> = panic::catch_unwind(AssertUnwindSafe(f));
+
+    Box::into_raw(Box::new(res)) as *mut c_void
+}
+
+/// Spawn a control thread with the name on the CPUs reserved for EAL.
+///
+/// The name is at most 15 bytes. The thread may be joined with the returned handle.
+pub fn spawn_ctrl<S, F, T>(name: S, f: F) -> Result<JoinHandle<T>>
+where
+    S: AsRef<str>,
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    if name.as_ref().len() >= THREAD_NAME_LEN {
+        return Err(anyhow!(
+            "control thread name `{}` exceeds {} bytes",
+            name.as_ref(),
+            THREAD_NAME_LEN - 1
+        ));
+    }
+
+    let name = name.as_cstring();
+    let arg = Box::into_raw(Box::new(f)) as *mut c_void;
+    let mut thread = 0;
+
+    let ret = unsafe {
+        ffi::rte_ctrl_thread_create(
+            &mut thread,
+            name.as_ptr(),
+            ptr::null(),
+            Some(ctrl_thread_stub::<F, T>),
+            arg,
+        )
+    };
+
+    if ret == 0 {
+        Ok(JoinHandle {
+            thread,
+            _marker: PhantomData,
+        })
+    } else {
+        drop(unsafe { Box::from_raw(arg as *mut F) });
+
+        Err(RteError(-ret).into())
+    }
+}
@@ -0,0 +1,99 @@
+//! RTE ticket locks
+//!
+//! This file defines an API for ticket locks, which give each waiting
+//! thread a ticket and take the lock one by one, first come, first
+//! serviced.
+//!
+//! All locks must be initialised before use, and only initialised once.
+//!
+use libc;
+
+use ffi;
+
+use spinlock::{ExclusiveLockImpl, Lock, LockImpl};
+
+pub type RawTicketLock = ffi::rte_ticketlock_t;
+pub type RawTicketLockPtr = *mut ffi::rte_ticketlock_t;
+
+pub type RawRecursiveTicketLock = ffi::rte_ticketlock_recursive_t;
+pub type RawRecursiveTicketLockPtr = *mut ffi::rte_ticketlock_recursive_t;
+
+pub enum TicketLockImpl {}
+
+/// The RTE ticket lock protecting the data of type `T`.
+///
+/// Unlike the spinlock, the waiting lcores take the lock in the order they asked for it.
+pub type TicketLock<T> = Lock<TicketLockImpl, T>;
+
+impl ExclusiveLockImpl for TicketLockImpl {}
+
+impl LockImpl for TicketLockImpl {
+    type RawLock = RawTicketLock;
+
+    #[inline]
+    fn init() -> Self::RawLock {
+        RawTicketLock { tickets: 0 }
+    }
+
+    #[inline]
+    fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
+        unsafe { ffi::_rte_ticketlock_is_locked(p) }
+    }
+
+    #[inline]
+    fn lock(p: *mut Self::RawLock) {
+        unsafe { ffi::_rte_ticketlock_lock(p) }
+    }
+
+    #[inline]
+    fn unlock(p: *mut Self::RawLock) {
+        unsafe { ffi::_rte_ticketlock_unlock(p) }
+    }
+
+    #[inline]
+    fn trylock(p: *mut Self::RawLock) -> libc::c_int {
+        unsafe { ffi::_rte_ticketlock_trylock(p) }
+    }
+}
+
+pub enum RecursiveTicketLockImpl {}
+
+/// The RTE recursive ticket lock, which may be taken again by the lcore holding it.
+///
+/// Since the same lcore may hold several guards at the same time,
+/// the guard only gives a shared reference to the protected data,
+/// use a `Cell` or `RefCell` for interior mutability.
+pub type RecursiveTicketLock<T> = Lock<RecursiveTicketLockImpl, T>;
+
+impl LockImpl for RecursiveTicketLockImpl {
+    type RawLock = RawRecursiveTicketLock;
+
+    #[inline]
+    fn init() -> Self::RawLock {
+        RawRecursiveTicketLock {
+            tlock: RawTicketLock { tickets: 0 },
+            user: -1,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    fn is_locked(p: *mut Self::RawLock) -> libc::c_int {
+        unsafe { ffi::_rte_ticketlock_is_locked(&mut (*p).tlock) }
+    }
+
+    #[inline]
+    fn lock(p: *mut Self::RawLock) {
+        unsafe { ffi::_rte_ticketlock_recursive_lock(p) }
+    }
+
+    #[inline]
+    fn unlock(p: *mut Self::RawLock) {
+        unsafe { ffi::_rte_ticketlock_recursive_unlock(p) }
+    }
+
+    #[inline]
+    fn trylock(p: *mut Self::RawLock) -> libc::c_int {
+        unsafe { ffi::_rte_ticketlock_recursive_trylock(p) }
+    }
+}
@@ -0,0 +1,82 @@
+//! UUID related functions originally from libuuid
+
+use std::cmp::Ordering;
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+
+use ffi;
+use utils::AsCString;
+
+pub use ffi::RTE_UUID_STRLEN;
+
+/// Universal Unique Identifier
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Uuid(pub ffi::rte_uuid_t);
+
+impl From<ffi::rte_uuid_t> for Uuid {
+    fn from(uu: ffi::rte_uuid_t) -> Self {
+        Uuid(uu)
+    }
+}
+
+impl Uuid {
+    /// The UUID which is all zeros.
+    pub fn null() -> Self {
+        Uuid::default()
+    }
+
+    /// Test if UUID is all zeros.
+    pub fn is_null(&self) -> bool {
+        unsafe { ffi::rte_uuid_is_null(self.0.as_ptr() as *mut _) }
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl PartialOrd for Uuid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uuid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe { ffi::rte_uuid_compare(self.0.as_ptr() as *mut _, other.0.as_ptr() as *mut _) }.cmp(&0)
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = Error;
+
+    /// Extract UUID from string
+    fn from_str(s: &str) -> Result<Self> {
+        let cs = s.as_cstring();
+        let mut uu = Uuid::default();
+
+        if unsafe { ffi::rte_uuid_parse(cs.as_ptr(), uu.0.as_mut_ptr()) } == 0 {
+            Ok(uu)
+        } else {
+            Err(anyhow!("invalid UUID, {}", s))
+        }
+    }
+}
+
+impl fmt::Display for Uuid {
+    /// Convert UUID to string
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0 as c_char; RTE_UUID_STRLEN as usize];
+
+        unsafe {
+            ffi::rte_uuid_unparse(self.0.as_ptr() as *mut _, buf.as_mut_ptr(), buf.len());
+
+            write!(f, "{}", CStr::from_ptr(buf.as_ptr()).to_string_lossy())
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//!
+//! RTE Cryptodev
+//!
+//! The cryptodev library provides a generic API to the crypto devices, either hardware accelerators
+//! or software PMDs, which process the crypto operations enqueued on their queue pairs.
+//!
+//! A device is configured with the number of queue pairs, each queue pair is set up with
+//! the session mempools, then the device is started and the crypto operations are
+//! enqueued and dequeued in burst.
+//!
+use std::ffi::CStr;
+use std::mem::MaybeUninit;
+use std::ops::Range;
+use std::ptr;
+use std::slice;
+
+use anyhow::Result;
+
+use dev;
+use ffi;
+use memory::{SocketId, SOCKET_ID_ANY};
+use mempool;
+use utils::{AsCString, AsRaw};
+
+pub mod op;
+pub mod sym;
+
+pub use self::op::{CryptoOp, OpStatus, OpType, RawCryptoOp, RawCryptoOpPtr};
+pub use self::sym::{
+    AeadAlgorithm, AeadOp, AeadXform, AuthAlgorithm, AuthOp, AuthXform, CipherAlgorithm, CipherOp, CipherXform,
+    SymSession, SymXform,
+};
+
+pub use ffi::{RTE_CRYPTODEV_NAME_MAX_LEN, RTE_CRYPTO_MAX_DEVS};
+
+pub type DevId = u8;
+pub type QueuePairId = u16;
+
+bitflags! {
+    /// The features supported by a crypto device.
+    pub struct FeatureFlags: u64 {
+        /// Symmetric crypto operations are supported.
+        const SYMMETRIC_CRYPTO       = ffi::RTE_CRYPTODEV_FF_SYMMETRIC_CRYPTO as u64;
+        /// Asymmetric crypto operations are supported.
+        const ASYMMETRIC_CRYPTO      = ffi::RTE_CRYPTODEV_FF_ASYMMETRIC_CRYPTO as u64;
+        /// Chaining symmetric crypto operations are supported.
+        const SYM_OPERATION_CHAINING = ffi::RTE_CRYPTODEV_FF_SYM_OPERATION_CHAINING as u64;
+        /// Utilises CPU SIMD SSE instructions.
+        const CPU_SSE                = ffi::RTE_CRYPTODEV_FF_CPU_SSE as u64;
+        /// Utilises CPU SIMD AVX instructions.
+        const CPU_AVX                = ffi::RTE_CRYPTODEV_FF_CPU_AVX as u64;
+        /// Utilises CPU SIMD AVX2 instructions.
+        const CPU_AVX2               = ffi::RTE_CRYPTODEV_FF_CPU_AVX2 as u64;
+        /// Utilises CPU AES-NI instructions.
+        const CPU_AESNI              = ffi::RTE_CRYPTODEV_FF_CPU_AESNI as u64;
+        /// Operations are off-loaded to an external hardware accelerator.
+        const HW_ACCELERATED         = ffi::RTE_CRYPTODEV_FF_HW_ACCELERATED as u64;
+        /// Utilises CPU SIMD AVX512 instructions.
+        const CPU_AVX512             = ffi::RTE_CRYPTODEV_FF_CPU_AVX512 as u64;
+        /// In-place Scatter-gather (SGL) buffers are supported.
+        const IN_PLACE_SGL           = ffi::RTE_CRYPTODEV_FF_IN_PLACE_SGL as u64;
+        /// Out-of-place SGL buffers are supported in input and output.
+        const OOP_SGL_IN_SGL_OUT     = ffi::RTE_CRYPTODEV_FF_OOP_SGL_IN_SGL_OUT as u64;
+        /// Out-of-place SGL buffers are supported in input, linear buffers in output.
+        const OOP_SGL_IN_LB_OUT      = ffi::RTE_CRYPTODEV_FF_OOP_SGL_IN_LB_OUT as u64;
+        /// Out-of-place linear buffers are supported in input, SGL buffers in output.
+        const OOP_LB_IN_SGL_OUT      = ffi::RTE_CRYPTODEV_FF_OOP_LB_IN_SGL_OUT as u64;
+        /// Out-of-place linear buffers are supported in input and output.
+        const OOP_LB_IN_LB_OUT       = ffi::RTE_CRYPTODEV_FF_OOP_LB_IN_LB_OUT as u64;
+        /// Utilises CPU NEON instructions.
+        const CPU_NEON               = ffi::RTE_CRYPTODEV_FF_CPU_NEON as u64;
+        /// Utilises ARM CPU Cryptographic Extensions.
+        const CPU_ARM_CE             = ffi::RTE_CRYPTODEV_FF_CPU_ARM_CE as u64;
+        /// Support Security Protocol Processing.
+        const SECURITY               = ffi::RTE_CRYPTODEV_FF_SECURITY as u64;
+        /// Support symmetric session-less operations.
+        const SYM_SESSIONLESS        = ffi::RTE_CRYPTODEV_FF_SYM_SESSIONLESS as u64;
+    }
+}
+
+/// Get the total number of crypto devices that have been successfully initialised.
+pub fn count() -> u8 {
+    unsafe { ffi::rte_cryptodev_count() }
+}
+
+pub fn devices() -> Range<DevId> {
+    0..count()
+}
+
+/// Get the device identifier of the named crypto device.
+pub fn get_dev_id<S: AsRef<str>>(name: S) -> Option<DevId> {
+    let name = name.as_cstring();
+
+    match unsafe { ffi::rte_cryptodev_get_dev_id(name.as_ptr()) } {
+        id if id < 0 => None,
+        id => Some(id as DevId),
+    }
+}
+
+/// Get the identifiers of the attached crypto devices that use the crypto driver.
+pub fn devices_by_driver<S: AsRef<str>>(driver_name: S) -> Vec<DevId> {
+    let driver_name = driver_name.as_cstring();
+    let mut devices = vec![0; RTE_CRYPTO_MAX_DEVS as usize];
+
+    let n = unsafe { ffi::rte_cryptodev_devices_get(driver_name.as_ptr(), devices.as_mut_ptr(), devices.len() as u8) };
+
+    devices.truncate(n as usize);
+    devices
+}
+
+/// Get the identifier of the crypto driver.
+pub fn driver_id<S: AsRef<str>>(name: S) -> Option<u8> {
+    let name = name.as_cstring();
+
+    match unsafe { ffi::rte_cryptodev_driver_id_get(name.as_ptr()) } {
+        id if id < 0 => None,
+        id => Some(id as u8),
+    }
+}
+
+/// The configuration of a crypto device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CryptoDevConf {
+    /// The NUMA socket to allocate the resources on.
+    pub socket_id: SocketId,
+    /// The number of queue pairs.
+    pub nb_queue_pairs: QueuePairId,
+    /// The features to be disabled, only the symmetric, asymmetric and security features can be disabled.
+    pub ff_disable: FeatureFlags,
+}
+
+impl Default for CryptoDevConf {
+    fn default() -> Self {
+        CryptoDevConf {
+            socket_id: SOCKET_ID_ANY,
+            nb_queue_pairs: 1,
+            ff_disable: FeatureFlags::empty(),
+        }
+    }
+}
+
+/// The crypto device API.
+///
+/// The control functions, e.g. configure, queue pair setup, start and stop, are not thread-safe,
+/// the enqueue and dequeue functions of a queue pair must not be called from several lcores at the same time.
+pub trait CryptoDevice {
+    fn dev_id(&self) -> DevId;
+
+    /// The name of the crypto device.
+    fn name(&self) -> Option<String>;
+
+    /// Return the NUMA socket to which the crypto device is connected.
+    fn socket_id(&self) -> SocketId;
+
+    /// Check if the device identifier is valid.
+    fn is_valid(&self) -> bool;
+
+    /// Retrieve the contextual information of the crypto device.
+    fn info(&self) -> RawCryptoDevInfo;
+
+    /// Configure the crypto device, which must be stopped.
+    fn configure(&self, conf: &CryptoDevConf) -> Result<&Self>;
+
+    /// Allocate and set up a queue pair of the crypto device.
+    ///
+    /// The sessions used on the queue pair are allocated from `session_pool`,
+    /// and their private data from `session_priv_pool`.
+    fn queue_pair_setup(
+        &self,
+        qp_id: QueuePairId,
+        nb_descriptors: u32,
+        session_pool: &mempool::MemoryPool,
+        session_priv_pool: &mempool::MemoryPool,
+        socket_id: SocketId,
+    ) -> Result<&Self>;
+
+    /// Get the number of queue pairs of the crypto device.
+    fn queue_pair_count(&self) -> QueuePairId;
+
+    /// Start the crypto device.
+    fn start(&self) -> Result<&Self>;
+
+    /// Stop the crypto device, which can be restarted with `start()`.
+    fn stop(&self) -> &Self;
+
+    /// Close the crypto device, which can't be restarted.
+    fn close(&self) -> Result<()>;
+
+    /// Retrieve the general I/O statistics of the crypto device.
+    fn stats(&self) -> Result<RawCryptoDevStats>;
+
+    /// Reset the general I/O statistics of the crypto device.
+    fn reset_stats(&self) -> &Self;
+
+    /// Get the size of the private data of a symmetric session on the crypto device.
+    fn sym_session_private_size(&self) -> usize;
+
+    /// Enqueue a burst of operations for processing on a queue pair of the crypto device.
+    ///
+    /// Return the number of operations actually enqueued, the remaining ones are still owned by the caller.
+    fn enqueue_burst<T: AsRaw<Raw = RawCryptoOp>>(&self, qp_id: QueuePairId, ops: &mut [T]) -> usize;
+
+    /// Dequeue a burst of processed operations from a queue pair of the crypto device.
+    fn dequeue_burst<'a>(
+        &self,
+        qp_id: QueuePairId,
+        ops: &'a mut [MaybeUninit<RawCryptoOpPtr>],
+    ) -> &'a mut [RawCryptoOpPtr];
+}
+
+impl CryptoDevice for DevId {
+    fn dev_id(&self) -> DevId {
+        *self
+    }
+
+    fn name(&self) -> Option<String> {
+        let name = unsafe { ffi::rte_cryptodev_name_get(*self) };
+
+        if name.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+        }
+    }
+
+    fn socket_id(&self) -> SocketId {
+        unsafe { ffi::rte_cryptodev_socket_id(*self) }
+    }
+
+    fn is_valid(&self) -> bool {
+        unsafe { ffi::rte_cryptodev_is_valid_dev(*self) != 0 }
+    }
+
+    fn info(&self) -> RawCryptoDevInfo {
+        let mut info = RawCryptoDevInfo::default();
+
+        unsafe { ffi::rte_cryptodev_info_get(*self, &mut info) }
+
+        info
+    }
+
+    fn configure(&self, conf: &CryptoDevConf) -> Result<&Self> {
+        let mut raw = ffi::rte_cryptodev_config {
+            socket_id: conf.socket_id,
+            nb_queue_pairs: conf.nb_queue_pairs,
+            ff_disable: conf.ff_disable.bits,
+        };
+
+        rte_check!(unsafe { ffi::rte_cryptodev_configure(*self, &mut raw) }; ok => { self })
+    }
+
+    fn queue_pair_setup(
+        &self,
+        qp_id: QueuePairId,
+        nb_descriptors: u32,
+        session_pool: &mempool::MemoryPool,
+        session_priv_pool: &mempool::MemoryPool,
+        socket_id: SocketId,
+    ) -> Result<&Self> {
+        let conf = ffi::rte_cryptodev_qp_conf {
+            nb_descriptors,
+            mp_session: session_pool.as_raw_mut(),
+            mp_session_private: session_priv_pool.as_raw_mut(),
+        };
+
+        rte_check!(unsafe {
+            ffi::rte_cryptodev_queue_pair_setup(*self, qp_id, &conf, socket_id)
+        }; ok => { self })
+    }
+
+    fn queue_pair_count(&self) -> QueuePairId {
+        unsafe { ffi::rte_cryptodev_queue_pair_count(*self) }
+    }
+
+    fn start(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_cryptodev_start(*self) }; ok => { self })
+    }
+
+    fn stop(&self) -> &Self {
+        unsafe { ffi::rte_cryptodev_stop(*self) };
+
+        self
+    }
+
+    fn close(&self) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_cryptodev_close(*self) })
+    }
+
+    fn stats(&self) -> Result<RawCryptoDevStats> {
+        let mut stats = RawCryptoDevStats::default();
+
+        rte_check!(unsafe { ffi::rte_cryptodev_stats_get(*self, &mut stats) }; ok => { stats })
+    }
+
+    fn reset_stats(&self) -> &Self {
+        unsafe { ffi::rte_cryptodev_stats_reset(*self) };
+
+        self
+    }
+
+    fn sym_session_private_size(&self) -> usize {
+        unsafe { ffi::rte_cryptodev_sym_get_private_session_size(*self) as usize }
+    }
+
+    #[inline]
+    fn enqueue_burst<T: AsRaw<Raw = RawCryptoOp>>(&self, qp_id: QueuePairId, ops: &mut [T]) -> usize {
+        unsafe {
+            if ops.is_empty() {
+                ffi::_rte_cryptodev_enqueue_burst(*self, qp_id, ptr::null_mut(), 0) as usize
+            } else {
+                ffi::_rte_cryptodev_enqueue_burst(*self, qp_id, ops.as_mut_ptr() as *mut _, ops.len() as u16) as usize
+            }
+        }
+    }
+
+    #[inline]
+    fn dequeue_burst<'a>(
+        &self,
+        qp_id: QueuePairId,
+        ops: &'a mut [MaybeUninit<RawCryptoOpPtr>],
+    ) -> &'a mut [RawCryptoOpPtr] {
+        unsafe {
+            let n = ffi::_rte_cryptodev_dequeue_burst(*self, qp_id, ops.as_mut_ptr() as *mut _, ops.len() as u16);
+
+            slice::from_raw_parts_mut(ops.as_mut_ptr() as *mut _, n as usize)
+        }
+    }
+}
+
+pub trait CryptoDevInfo {
+    /// Driver name.
+    fn driver_name(&self) -> &str;
+
+    /// The features supported by the device.
+    fn features(&self) -> FeatureFlags;
+
+    fn dev(&self) -> Option<dev::Device>;
+}
+
+pub type RawCryptoDevInfo = ffi::rte_cryptodev_info;
+
+impl CryptoDevInfo for RawCryptoDevInfo {
+    #[inline]
+    fn driver_name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.driver_name).to_str().unwrap() }
+    }
+
+    #[inline]
+    fn features(&self) -> FeatureFlags {
+        FeatureFlags::from_bits_truncate(self.feature_flags)
+    }
+
+    #[inline]
+    fn dev(&self) -> Option<dev::Device> {
+        if self.device.is_null() {
+            None
+        } else {
+            Some(self.device.into())
+        }
+    }
+}
+
+pub type RawCryptoDevStats = ffi::rte_cryptodev_stats;
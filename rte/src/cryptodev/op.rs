@@ -0,0 +1,230 @@
+//!
+//! Crypto operations.
+//!
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use anyhow::Result;
+
+use errors::AsResult;
+use ffi;
+use mbuf;
+use memory::SocketId;
+use mempool;
+use utils::{AsCString, AsRaw};
+
+use super::sym::SymSession;
+
+/// The offset of the IV, which is usually placed right after the symmetric operation.
+pub const IV_OFFSET: u16 = (mem::size_of::<ffi::rte_crypto_op>() + mem::size_of::<ffi::rte_crypto_sym_op>()) as u16;
+
+/// The types of the crypto operation.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpType {
+    Undefined = ffi::rte_crypto_op_type::RTE_CRYPTO_OP_TYPE_UNDEFINED,
+    Symmetric = ffi::rte_crypto_op_type::RTE_CRYPTO_OP_TYPE_SYMMETRIC,
+    Asymmetric = ffi::rte_crypto_op_type::RTE_CRYPTO_OP_TYPE_ASYMMETRIC,
+}
+
+/// The status of the crypto operation.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpStatus {
+    /// The operation completed successfully.
+    Success = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_SUCCESS,
+    /// The operation has not yet been processed by a crypto device.
+    NotProcessed = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_NOT_PROCESSED,
+    /// The authentication verification failed.
+    AuthFailed = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_AUTH_FAILED,
+    /// The operation failed due to the invalid session arguments.
+    InvalidSession = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_INVALID_SESSION,
+    /// The operation failed due to the invalid arguments in request.
+    InvalidArgs = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_INVALID_ARGS,
+    /// The operation failed.
+    Error = ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_ERROR,
+}
+
+impl From<u8> for OpStatus {
+    fn from(status: u8) -> Self {
+        match u32::from(status) {
+            ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_SUCCESS => OpStatus::Success,
+            ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_NOT_PROCESSED => OpStatus::NotProcessed,
+            ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_AUTH_FAILED => OpStatus::AuthFailed,
+            ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_INVALID_SESSION => OpStatus::InvalidSession,
+            ffi::rte_crypto_op_status::RTE_CRYPTO_OP_STATUS_INVALID_ARGS => OpStatus::InvalidArgs,
+            _ => OpStatus::Error,
+        }
+    }
+}
+
+/// Create a mempool of the crypto operations,
+/// `priv_size` is the size of the private data after the operation, e.g. the IV.
+pub fn pool_create<S: AsRef<str>>(
+    name: S,
+    ty: OpType,
+    nb_elts: u32,
+    cache_size: u32,
+    priv_size: u16,
+    socket_id: SocketId,
+) -> Result<mempool::MemoryPool> {
+    let name = name.as_cstring();
+
+    unsafe { ffi::rte_crypto_op_pool_create(name.as_ptr(), ty as u32, nb_elts, cache_size, priv_size, socket_id) }
+        .as_result()
+        .map(|p| p.as_ptr())
+        .map(mempool::MemoryPool::from)
+}
+
+pub type RawCryptoOp = ffi::rte_crypto_op;
+pub type RawCryptoOpPtr = *mut ffi::rte_crypto_op;
+pub type RawSymOp = ffi::rte_crypto_sym_op;
+
+raw!(pub CryptoOp(RawCryptoOp));
+
+// An operation may be passed to another lcore, but it can't be accessed by several lcores at the same time.
+unsafe impl Send for CryptoOp {}
+
+impl CryptoOp {
+    /// Allocate a crypto operation from the mempool.
+    pub fn alloc(pool: &mempool::MemoryPool, ty: OpType) -> Result<Self> {
+        unsafe { ffi::_rte_crypto_op_alloc(pool.as_raw_mut(), ty as u32) }
+            .as_result()
+            .map(CryptoOp)
+    }
+
+    /// Allocate a bulk of crypto operations from the mempool, either all or none of them are allocated.
+    pub fn alloc_bulk<'a>(
+        pool: &mempool::MemoryPool,
+        ty: OpType,
+        ops: &'a mut [MaybeUninit<RawCryptoOpPtr>],
+    ) -> Option<&'a mut [RawCryptoOpPtr]> {
+        let n = unsafe {
+            ffi::_rte_crypto_op_bulk_alloc(
+                pool.as_raw_mut(),
+                ty as u32,
+                ops.as_mut_ptr() as *mut _,
+                ops.len() as u16,
+            )
+        };
+
+        if n == 0 {
+            None
+        } else {
+            Some(unsafe { slice::from_raw_parts_mut(ops.as_mut_ptr() as *mut _, n as usize) })
+        }
+    }
+
+    /// Return the crypto operation to its mempool.
+    pub fn free(self) {
+        unsafe { ffi::_rte_crypto_op_free(self.0.as_ptr()) }
+    }
+
+    /// The status of the crypto operation.
+    #[inline]
+    pub fn status(&self) -> OpStatus {
+        unsafe { self.__bindgen_anon_1.__bindgen_anon_1.status }.into()
+    }
+
+    /// The crypto operation completed successfully.
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        self.status() == OpStatus::Success
+    }
+
+    /// The symmetric operation parameters.
+    #[inline]
+    pub fn sym(&self) -> &RawSymOp {
+        unsafe { &*self.__bindgen_anon_2.sym.as_ptr() }
+    }
+
+    /// The mutable symmetric operation parameters.
+    #[inline]
+    pub fn sym_mut(&mut self) -> &mut RawSymOp {
+        unsafe { &mut *self.__bindgen_anon_2.sym.as_mut_ptr() }
+    }
+
+    /// Attach the symmetric session to the crypto operation.
+    pub fn attach_session(&mut self, session: &SymSession) -> Result<&mut Self> {
+        rte_check!(unsafe {
+            ffi::_rte_crypto_op_attach_sym_session(self.0.as_ptr(), session.as_raw_mut())
+        }; ok => { self })
+    }
+
+    /// Set the source mbuf, and the destination mbuf for the out-of-place operation.
+    ///
+    /// The mbufs are still owned by the caller, which must keep them alive until the operation is dequeued.
+    pub fn set_mbufs(&mut self, src: &mbuf::MBuf, dst: Option<&mbuf::MBuf>) -> &mut Self {
+        let sym = self.sym_mut();
+
+        sym.m_src = src.as_raw_mut();
+        sym.m_dst = dst.map_or(ptr::null_mut(), |m| m.as_raw_mut());
+
+        self
+    }
+
+    /// The IV of `len` bytes at the offset from the start of the crypto operation.
+    ///
+    /// Return `None` if the IV isn't in the private data after the symmetric operation,
+    /// which is allocated with the operation from its mempool.
+    #[inline]
+    pub fn iv_mut(&mut self, offset: u16, len: u16) -> Option<&mut [u8]> {
+        let elt_size = unsafe { self.mempool.as_ref() }.map_or(0, |mp| mp.elt_size as usize);
+
+        if offset < IV_OFFSET || offset as usize + len as usize > elt_size {
+            None
+        } else {
+            Some(unsafe { slice::from_raw_parts_mut((self.0.as_ptr() as *mut u8).add(offset as usize), len as usize) })
+        }
+    }
+
+    /// Set the data of the source mbuf to be ciphered.
+    pub fn set_cipher_data(&mut self, offset: u32, length: u32) -> &mut Self {
+        let data = unsafe { &mut self.sym_mut().__bindgen_anon_2.__bindgen_anon_1.cipher.data };
+
+        data.offset = offset;
+        data.length = length;
+
+        self
+    }
+
+    /// Set the data of the source mbuf to be authenticated, and the digest at the offset of the mbuf data.
+    pub fn set_auth_data(&mut self, offset: u32, length: u32, m: &mbuf::MBuf, digest_offset: usize) -> &mut Self {
+        let auth = unsafe { &mut self.sym_mut().__bindgen_anon_2.__bindgen_anon_1.auth };
+
+        auth.data.offset = offset;
+        auth.data.length = length;
+        auth.digest.data = m.mtod_offset::<u8>(digest_offset).as_ptr();
+        auth.digest.phys_addr = m.iova_offset(digest_offset);
+
+        self
+    }
+
+    /// Set the data of the source mbuf to be processed by AEAD,
+    /// the digest at the offset of the mbuf data, and the additional authenticated data.
+    ///
+    /// The AAD must be kept alive until the operation is dequeued.
+    pub fn set_aead_data(
+        &mut self,
+        offset: u32,
+        length: u32,
+        m: &mbuf::MBuf,
+        digest_offset: usize,
+        aad: Option<(*mut u8, ffi::rte_iova_t)>,
+    ) -> &mut Self {
+        let aead = unsafe { &mut self.sym_mut().__bindgen_anon_2.aead };
+
+        aead.data.offset = offset;
+        aead.data.length = length;
+        aead.digest.data = m.mtod_offset::<u8>(digest_offset).as_ptr();
+        aead.digest.phys_addr = m.iova_offset(digest_offset);
+
+        if let Some((data, phys_addr)) = aad {
+            aead.aad.data = data;
+            aead.aad.phys_addr = phys_addr;
+        }
+
+        self
+    }
+}
@@ -0,0 +1,370 @@
+//!
+//! Symmetric crypto transforms and sessions.
+//!
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+
+use errors::AsResult;
+use ffi;
+use memory::SocketId;
+use mempool;
+use utils::{AsCString, AsRaw};
+
+use super::DevId;
+
+/// The symmetric cipher algorithms.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CipherAlgorithm {
+    Null = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_NULL,
+    TripleDesCbc = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_3DES_CBC,
+    TripleDesCtr = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_3DES_CTR,
+    TripleDesEcb = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_3DES_ECB,
+    AesCbc = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_CBC,
+    AesCtr = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_CTR,
+    AesEcb = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_ECB,
+    AesF8 = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_F8,
+    AesXts = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_XTS,
+    Arc4 = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_ARC4,
+    KasumiF8 = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_KASUMI_F8,
+    Snow3gUea2 = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_SNOW3G_UEA2,
+    ZucEea3 = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_ZUC_EEA3,
+    DesCbc = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_DES_CBC,
+    AesDocsisBpi = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_AES_DOCSISBPI,
+    DesDocsisBpi = ffi::rte_crypto_cipher_algorithm::RTE_CRYPTO_CIPHER_DES_DOCSISBPI,
+}
+
+/// The direction of a cipher operation.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CipherOp {
+    Encrypt = ffi::rte_crypto_cipher_operation::RTE_CRYPTO_CIPHER_OP_ENCRYPT,
+    Decrypt = ffi::rte_crypto_cipher_operation::RTE_CRYPTO_CIPHER_OP_DECRYPT,
+}
+
+/// The symmetric authentication / hash algorithms.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthAlgorithm {
+    Null = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_NULL,
+    AesCbcMac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_AES_CBC_MAC,
+    AesCmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_AES_CMAC,
+    AesGmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_AES_GMAC,
+    AesXcbcMac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_AES_XCBC_MAC,
+    KasumiF9 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_KASUMI_F9,
+    Md5 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_MD5,
+    Md5Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_MD5_HMAC,
+    Sha1 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA1,
+    Sha1Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA1_HMAC,
+    Sha224 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA224,
+    Sha224Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA224_HMAC,
+    Sha256 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA256,
+    Sha256Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA256_HMAC,
+    Sha384 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA384,
+    Sha384Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA384_HMAC,
+    Sha512 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA512,
+    Sha512Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA512_HMAC,
+    Snow3gUia2 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SNOW3G_UIA2,
+    ZucEia3 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_ZUC_EIA3,
+    Sha3_224 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_224,
+    Sha3_224Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_224_HMAC,
+    Sha3_256 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_256,
+    Sha3_256Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_256_HMAC,
+    Sha3_384 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_384,
+    Sha3_384Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_384_HMAC,
+    Sha3_512 = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_512,
+    Sha3_512Hmac = ffi::rte_crypto_auth_algorithm::RTE_CRYPTO_AUTH_SHA3_512_HMAC,
+}
+
+/// The authentication operations.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthOp {
+    Verify = ffi::rte_crypto_auth_operation::RTE_CRYPTO_AUTH_OP_VERIFY,
+    Generate = ffi::rte_crypto_auth_operation::RTE_CRYPTO_AUTH_OP_GENERATE,
+}
+
+/// The symmetric AEAD algorithms.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AeadAlgorithm {
+    AesCcm = ffi::rte_crypto_aead_algorithm::RTE_CRYPTO_AEAD_AES_CCM,
+    AesGcm = ffi::rte_crypto_aead_algorithm::RTE_CRYPTO_AEAD_AES_GCM,
+    Chacha20Poly1305 = ffi::rte_crypto_aead_algorithm::RTE_CRYPTO_AEAD_CHACHA20_POLY1305,
+}
+
+/// The AEAD operations.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AeadOp {
+    /// Encrypt and generate the digest.
+    Encrypt = ffi::rte_crypto_aead_operation::RTE_CRYPTO_AEAD_OP_ENCRYPT,
+    /// Verify the digest and decrypt.
+    Decrypt = ffi::rte_crypto_aead_operation::RTE_CRYPTO_AEAD_OP_DECRYPT,
+}
+
+/// The cipher transform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CipherXform {
+    pub op: CipherOp,
+    pub algo: CipherAlgorithm,
+    pub key: Vec<u8>,
+    /// The offset of the IV from the start of the crypto operation.
+    pub iv_offset: u16,
+    /// The length of the IV.
+    pub iv_length: u16,
+}
+
+/// The authentication transform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthXform {
+    pub op: AuthOp,
+    pub algo: AuthAlgorithm,
+    pub key: Vec<u8>,
+    /// The offset of the IV from the start of the crypto operation, which is used by some algorithms, e.g. GMAC.
+    pub iv_offset: u16,
+    /// The length of the IV.
+    pub iv_length: u16,
+    /// The length of the digest.
+    pub digest_length: u16,
+}
+
+/// The AEAD transform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AeadXform {
+    pub op: AeadOp,
+    pub algo: AeadAlgorithm,
+    pub key: Vec<u8>,
+    /// The offset of the IV from the start of the crypto operation.
+    pub iv_offset: u16,
+    /// The length of the IV.
+    pub iv_length: u16,
+    /// The length of the digest.
+    pub digest_length: u16,
+    /// The length of the additional authenticated data.
+    pub aad_length: u16,
+}
+
+/// A symmetric crypto transform, several transforms can be chained, e.g. cipher then authentication.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymXform {
+    Cipher(CipherXform),
+    Auth(AuthXform),
+    Aead(AeadXform),
+}
+
+impl From<CipherXform> for SymXform {
+    fn from(xform: CipherXform) -> Self {
+        SymXform::Cipher(xform)
+    }
+}
+
+impl From<AuthXform> for SymXform {
+    fn from(xform: AuthXform) -> Self {
+        SymXform::Auth(xform)
+    }
+}
+
+impl From<AeadXform> for SymXform {
+    fn from(xform: AeadXform) -> Self {
+        SymXform::Aead(xform)
+    }
+}
+
+impl SymXform {
+    fn to_raw(&self) -> ffi::rte_crypto_sym_xform {
+        let mut raw = ffi::rte_crypto_sym_xform::default();
+
+        match self {
+            SymXform::Cipher(xform) => {
+                raw.type_ = ffi::rte_crypto_sym_xform_type::RTE_CRYPTO_SYM_XFORM_CIPHER;
+                raw.__bindgen_anon_1.cipher = ffi::rte_crypto_cipher_xform {
+                    op: xform.op as u32,
+                    algo: xform.algo as u32,
+                    key: ffi::rte_crypto_cipher_xform__bindgen_ty_1 {
+                        data: xform.key.as_ptr(),
+                        length: xform.key.len() as u16,
+                    },
+                    iv: ffi::rte_crypto_cipher_xform__bindgen_ty_2 {
+                        offset: xform.iv_offset,
+                        length: xform.iv_length,
+                    },
+                };
+            }
+            SymXform::Auth(xform) => {
+                raw.type_ = ffi::rte_crypto_sym_xform_type::RTE_CRYPTO_SYM_XFORM_AUTH;
+                raw.__bindgen_anon_1.auth = ffi::rte_crypto_auth_xform {
+                    op: xform.op as u32,
+                    algo: xform.algo as u32,
+                    key: ffi::rte_crypto_auth_xform__bindgen_ty_1 {
+                        data: xform.key.as_ptr(),
+                        length: xform.key.len() as u16,
+                    },
+                    iv: ffi::rte_crypto_auth_xform__bindgen_ty_2 {
+                        offset: xform.iv_offset,
+                        length: xform.iv_length,
+                    },
+                    digest_length: xform.digest_length,
+                };
+            }
+            SymXform::Aead(xform) => {
+                raw.type_ = ffi::rte_crypto_sym_xform_type::RTE_CRYPTO_SYM_XFORM_AEAD;
+                raw.__bindgen_anon_1.aead = ffi::rte_crypto_aead_xform {
+                    op: xform.op as u32,
+                    algo: xform.algo as u32,
+                    key: ffi::rte_crypto_aead_xform__bindgen_ty_1 {
+                        data: xform.key.as_ptr(),
+                        length: xform.key.len() as u16,
+                    },
+                    iv: ffi::rte_crypto_aead_xform__bindgen_ty_2 {
+                        offset: xform.iv_offset,
+                        length: xform.iv_length,
+                    },
+                    digest_length: xform.digest_length,
+                    aad_length: xform.aad_length,
+                };
+            }
+        }
+
+        raw
+    }
+}
+
+/// Chain the raw transforms, which borrow the keys of the transforms.
+fn xforms_to_raw(xforms: &[SymXform]) -> Vec<ffi::rte_crypto_sym_xform> {
+    let mut raw = xforms.iter().map(SymXform::to_raw).collect::<Vec<_>>();
+
+    for i in 1..raw.len() {
+        let next = &mut raw[i] as *mut _;
+
+        raw[i - 1].next = next;
+    }
+
+    raw
+}
+
+/// Create a mempool of the symmetric sessions.
+///
+/// The size of the elements is the size of the session header if `elt_size` is 0.
+pub fn session_pool_create<S: AsRef<str>>(
+    name: S,
+    nb_elts: u32,
+    elt_size: u32,
+    cache_size: u32,
+    priv_size: u16,
+    socket_id: SocketId,
+) -> Result<mempool::MemoryPool> {
+    let name = name.as_cstring();
+
+    unsafe {
+        ffi::rte_cryptodev_sym_session_pool_create(name.as_ptr(), nb_elts, elt_size, cache_size, priv_size, socket_id)
+    }
+    .as_result()
+    .map(|p| p.as_ptr())
+    .map(mempool::MemoryPool::from)
+}
+
+pub type RawSymSession = ffi::rte_cryptodev_sym_session;
+
+/// A symmetric crypto session, which holds the immutable parameters of the operations.
+///
+/// The session must be cleared from all the devices before it's freed.
+#[derive(Debug)]
+pub struct SymSession(NonNull<RawSymSession>);
+
+unsafe impl Send for SymSession {}
+unsafe impl Sync for SymSession {}
+
+impl AsRaw for SymSession {
+    type Raw = RawSymSession;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl SymSession {
+    /// Get the size of the session header.
+    pub fn header_size() -> usize {
+        unsafe { ffi::rte_cryptodev_sym_get_header_session_size() as usize }
+    }
+
+    /// Create a session header from the session mempool.
+    pub fn create(pool: &mempool::MemoryPool) -> Result<Self> {
+        unsafe { ffi::rte_cryptodev_sym_session_create(pool.as_raw_mut()) }
+            .as_result()
+            .map(SymSession)
+    }
+
+    /// Initialize the private data of the session for the device with the chained transforms.
+    pub fn init(&self, dev_id: DevId, xforms: &[SymXform], priv_pool: &mempool::MemoryPool) -> Result<&Self> {
+        let mut raw = xforms_to_raw(xforms);
+
+        rte_check!(unsafe {
+            ffi::rte_cryptodev_sym_session_init(
+                dev_id,
+                self.0.as_ptr(),
+                raw.first_mut().map_or(ptr::null_mut(), |xform| xform as *mut _),
+                priv_pool.as_raw_mut(),
+            )
+        }; ok => { self })
+    }
+
+    /// Free the private data of the session for the device.
+    pub fn clear(&self, dev_id: DevId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_cryptodev_sym_session_clear(dev_id, self.0.as_ptr()) }; ok => { self })
+    }
+
+    /// Return the session header to its mempool.
+    pub fn free(self) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_cryptodev_sym_session_free(self.0.as_ptr()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xforms() {
+        let xforms = [
+            SymXform::from(CipherXform {
+                op: CipherOp::Encrypt,
+                algo: CipherAlgorithm::AesCbc,
+                key: vec![0; 16],
+                iv_offset: 160,
+                iv_length: 16,
+            }),
+            SymXform::from(AuthXform {
+                op: AuthOp::Generate,
+                algo: AuthAlgorithm::Sha1Hmac,
+                key: vec![0; 20],
+                iv_offset: 0,
+                iv_length: 0,
+                digest_length: 12,
+            }),
+        ];
+
+        let raw = xforms_to_raw(&xforms);
+
+        assert_eq!(raw.len(), 2);
+        assert_eq!(
+            raw[0].type_,
+            ffi::rte_crypto_sym_xform_type::RTE_CRYPTO_SYM_XFORM_CIPHER
+        );
+        assert_eq!(raw[0].next, &raw[1] as *const _ as *mut _);
+        assert_eq!(raw[1].type_, ffi::rte_crypto_sym_xform_type::RTE_CRYPTO_SYM_XFORM_AUTH);
+        assert!(raw[1].next.is_null());
+
+        unsafe {
+            assert_eq!(raw[0].__bindgen_anon_1.cipher.key.length, 16);
+            assert_eq!(raw[0].__bindgen_anon_1.cipher.iv.offset, 160);
+            assert_eq!(raw[1].__bindgen_anon_1.auth.digest_length, 12);
+        }
+    }
+}
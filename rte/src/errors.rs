@@ -123,6 +123,26 @@ pub enum ErrorKind {
     InvalidLogLevel(u32),
     #[error("cmdline parse error, {0}")]
     CmdLineParseError(i32),
+    #[error("unsupported CPU flags, {0}")]
+    UnsupportedCpuFlags(String),
+    #[error("invalid EAL config, {0}")]
+    InvalidEalConfig(String),
+    #[error("insufficient hugepages, {0}")]
+    InsufficientHugepages(String),
+    #[error("invalid shared data, {0}")]
+    InvalidSharedData(String),
+    #[error("invalid pipeline spec, {0}")]
+    InvalidPipelineSpec(String),
+    #[error("invalid table entry, {0}")]
+    InvalidTableEntry(String),
+    #[error("invalid RED params, {0}")]
+    InvalidRedParams(String),
+    #[error("invalid config file, {0}")]
+    InvalidConfigFile(String),
+    #[error("invalid port config, {0}")]
+    InvalidEthConf(String),
+    #[error("invalid port id, {0}")]
+    InvalidPortId(String),
     #[error("{0}")]
     OsError(i32),
 }
@@ -1,26 +1,112 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::ffi::CStr;
-use std::mem;
-use std::ops::Range;
-use std::os::raw::c_void;
-use std::ptr;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::num::ParseIntError;
+use std::ops;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::RawFd;
+use std::ptr::{self, NonNull};
+use std::result;
+use std::slice;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use anyhow::Result;
 use libc;
+use num_traits::FromPrimitive;
 
 use ffi;
 
 use dev;
-use errors::{AsResult, ErrorKind::OsError};
+use errors::{
+    rte_error, AsResult,
+    ErrorKind::{self, InvalidEthConf, InvalidPortId, OsError},
+    RteError,
+};
 use ether;
+use intr;
+use lcore;
 use malloc;
 use mbuf;
-use memory::SocketId;
+use memory::{SocketId, SOCKET_ID_ANY};
 use mempool;
-use utils::AsRaw;
+use ptype::{PacketType, PtypeMask};
+use utils::{catch_panic, AsCString, AsRaw, NotSync};
+
+/// The identifier of an Ethernet port.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortId(pub u16);
 
-pub type PortId = u16;
 pub type QueueId = u16;
 
+/// The maximum number of the Ethernet ports.
+pub const MAX_ETHPORTS: usize = ffi::RTE_MAX_ETHPORTS as usize;
+
+/// The number of the per-queue counters of the basic stats.
+pub const QUEUE_STAT_CNTRS: usize = ffi::RTE_ETHDEV_QUEUE_STAT_CNTRS as usize;
+
+/// The maximum length of the port names, including the trailing NUL.
+pub const MAX_NAME_LEN: usize = ffi::RTE_ETH_NAME_MAX_LEN as usize;
+
+impl fmt::Display for PortId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u16> for PortId {
+    fn from(port_id: u16) -> Self {
+        PortId(port_id)
+    }
+}
+
+impl From<PortId> for u16 {
+    fn from(port_id: PortId) -> Self {
+        port_id.0
+    }
+}
+
+impl From<PortId> for usize {
+    fn from(port_id: PortId) -> Self {
+        port_id.0 as usize
+    }
+}
+
+impl FromStr for PortId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        s.parse().map(PortId)
+    }
+}
+
+macro_rules! port_id_try_from {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<$ty> for PortId {
+                type Error = ErrorKind;
+
+                fn try_from(n: $ty) -> result::Result<Self, Self::Error> {
+                    if 0 <= n as i64 && (n as i64) < MAX_ETHPORTS as i64 {
+                        Ok(PortId(n as u16))
+                    } else {
+                        Err(InvalidPortId(n.to_string()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+port_id_try_from!(i32, u32, i64, u64, usize);
+
 /// A structure used to retrieve link-level information of an Ethernet port.
 pub struct EthLink {
     pub speed: u32,
@@ -29,13 +115,21 @@ pub struct EthLink {
     pub up: bool,
 }
 
+/// The Ethernet device API.
+///
+/// The control functions, e.g. configure, queue setup, start and stop, are not thread-safe,
+/// they should be called from one lcore, usually the main lcore, before the data path starts.
+/// The data path polls the queues with the `RxQueue` and `TxQueue` handles returned by the queue setup,
+/// each queue has at most one handle, and the port can't be configured, reset or closed
+/// until all the handles of its queues are dropped.
 pub trait EthDevice {
     fn portid(&self) -> PortId;
 
     /// Configure an Ethernet device.
     ///
     /// This function must be invoked first before any other function in the Ethernet API.
-    /// This function can also be re-invoked when a device is in the stopped state.
+    /// This function can also be re-invoked when a device is in the stopped state,
+    /// after all the handles of its queues are dropped.
     ///
     fn configure(&self, nb_rx_queue: QueueId, nb_tx_queue: QueueId, conf: &EthConf) -> Result<&Self>;
 
@@ -48,6 +142,19 @@ pub trait EthDevice {
     /// Reset the general I/O statistics of an Ethernet device.
     fn reset_stats(&self) -> &Self;
 
+    /// Map a receive queue to a per-queue counter of the basic stats, up to `QUEUE_STAT_CNTRS`,
+    /// which is required by the devices with fewer counter registers than queues.
+    fn set_rx_queue_stats_mapping(&self, rx_queue_id: QueueId, stat_idx: u8) -> Result<&Self>;
+
+    /// Map a transmit queue to a per-queue counter of the basic stats, e.g. `q_opackets[stat_idx]`.
+    fn set_tx_queue_stats_mapping(&self, tx_queue_id: QueueId, stat_idx: u8) -> Result<&Self>;
+
+    /// Turn on the LED of the port, e.g. to identify it in the field.
+    fn led_on(&self) -> Result<&Self>;
+
+    /// Turn off the LED of the port.
+    fn led_off(&self) -> Result<&Self>;
+
     /// Retrieve the Ethernet address of an Ethernet device.
     fn mac_addr(&self) -> ether::EtherAddr;
 
@@ -66,21 +173,28 @@ pub trait EthDevice {
     /// receive descriptors from a memory zone associated with *socket_id*
     /// and initializes each receive descriptor with a network buffer allocated
     /// from the memory pool *mb_pool*.
-    fn rx_queue_setup(
+    ///
+    /// Return the handle of the queue, which borrows the memory pool so it can't be dropped
+    /// while the queue is still receiving packets into its mbufs.
+    ///
+    /// Fail with `EBUSY` if the queue already has a handle.
+    fn rx_queue_setup<'a>(
         &self,
         rx_queue_id: QueueId,
         nb_rx_desc: u16,
         rx_conf: Option<ffi::rte_eth_rxconf>,
-        mb_pool: &mut mempool::MemoryPool,
-    ) -> Result<&Self>;
+        mb_pool: &'a mempool::MemoryPool,
+    ) -> Result<RxQueue<'a>>;
 
-    /// Allocate and set up a transmit queue for an Ethernet device.
+    /// Allocate and set up a transmit queue for an Ethernet device, return the handle of the queue.
+    ///
+    /// Fail with `EBUSY` if the queue already has a handle.
     fn tx_queue_setup(
         &self,
         tx_queue_id: QueueId,
         nb_tx_desc: u16,
         tx_conf: Option<ffi::rte_eth_txconf>,
-    ) -> Result<&Self>;
+    ) -> Result<TxQueue>;
 
     /// Enable receipt in promiscuous mode for an Ethernet device.
     fn promiscuous_enable(&self) -> &Self;
@@ -91,6 +205,25 @@ pub trait EthDevice {
     /// Return the value of promiscuous mode for an Ethernet device.
     fn is_promiscuous_enabled(&self) -> Result<bool>;
 
+    /// Add a MAC address to the receive filter of the VMDq pool, or the default pool 0,
+    /// up to `dev_info.max_mac_addrs` addresses including the default one.
+    fn add_mac_addr(&self, addr: &ether::EtherAddr, pool: u32) -> Result<&Self>;
+
+    /// Remove a MAC address from the receive filter, the default MAC address can't be removed.
+    fn remove_mac_addr(&self, addr: &ether::EtherAddr) -> Result<&Self>;
+
+    /// Set the multicast addresses to be received, which replaces the previous list, or flushes it if empty.
+    fn set_mc_addr_list(&self, addrs: &[ether::EtherAddr]) -> Result<&Self>;
+
+    /// Enable the receipt of all the multicast packets.
+    fn allmulticast_enable(&self) -> Result<&Self>;
+
+    /// Disable the receipt of all the multicast packets.
+    fn allmulticast_disable(&self) -> Result<&Self>;
+
+    /// Return the value of allmulticast mode for an Ethernet device.
+    fn is_allmulticast_enabled(&self) -> Result<bool>;
+
     /// Retrieve the MTU of an Ethernet device.
     fn mtu(&self) -> Result<u16>;
 
@@ -101,6 +234,16 @@ pub trait EthDevice {
     /// of received VLAN packets tagged with a given VLAN Tag Identifier.
     fn set_vlan_filter(&self, vlan_id: u16, on: bool) -> Result<&Self>;
 
+    /// Enable/Disable hardware VLAN stripping on a receive queue,
+    /// which is overridden by the port level `ETH_VLAN_STRIP_OFFLOAD` of `set_vlan_offload()`.
+    fn set_vlan_strip_on_queue(&self, rx_queue_id: QueueId, on: bool) -> Result<&Self>;
+
+    /// Set the Tag Protocol ID of the inner or outer VLAN, e.g. `0x88a8` for the QinQ outer VLAN.
+    fn set_vlan_ether_type(&self, vlan_type: VlanType, tpid: u16) -> Result<&Self>;
+
+    /// Set the port based VLAN ID inserted into the transmitted packets, or turn it off.
+    fn set_vlan_pvid(&self, pvid: u16, on: bool) -> Result<&Self>;
+
     /// Retrieve the Ethernet device link status
     #[inline]
     fn is_up(&self) -> bool {
@@ -149,297 +292,1852 @@ pub trait EthDevice {
     fn stop(&self) -> &Self;
 
     /// Close a stopped Ethernet device. The device cannot be restarted!
-    fn close(&self) -> &Self;
+    ///
+    /// Fail with `EBUSY` if a queue of the port still has a handle.
+    fn close(&self) -> Result<&Self>;
+
+    /// Stop and reset the device, e.g. after an `EthEvent::Reset` event of a VF,
+    /// it must be configured, its queues set up and started again.
+    ///
+    /// Fail with `EBUSY` if a queue of the port still has a handle.
+    fn reset(&self) -> Result<&Self>;
+
+    /// Reset the device, then configure it, set up the queues and start it again,
+    /// return the handles of the queues, the old handles must be dropped before.
+    ///
+    /// The receive queue `i` has `rx_queues[i]` descriptors of the memory pool,
+    /// and the transmit queue `i` has `tx_queues[i]` descriptors.
+    fn recover<'a>(
+        &self,
+        conf: &EthConf,
+        rx_queues: &[(u16, &'a mempool::MemoryPool)],
+        tx_queues: &[u16],
+    ) -> Result<(Vec<RxQueue<'a>>, Vec<TxQueue>)> {
+        self.reset()?
+            .configure(rx_queues.len() as QueueId, tx_queues.len() as QueueId, conf)?;
+
+        let rxqs = rx_queues
+            .iter()
+            .enumerate()
+            .map(|(queue_id, &(nb_rx_desc, mb_pool))| {
+                self.rx_queue_setup(queue_id as QueueId, nb_rx_desc, None, mb_pool)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let txqs = tx_queues
+            .iter()
+            .enumerate()
+            .map(|(queue_id, &nb_tx_desc)| self.tx_queue_setup(queue_id as QueueId, nb_tx_desc, None))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.start()?;
+
+        Ok((rxqs, txqs))
+    }
 
-    /// Retrieve a burst of input packets from a receive queue of an Ethernet device.
-    fn rx_burst(&self, queue_id: QueueId, rx_pkts: &mut [Option<mbuf::MBuf>]) -> usize;
+    /// Enable the RX interrupt of the queue, `intr_conf.rxq` must be set when configuring the device.
+    fn rx_intr_enable(&self, queue_id: QueueId) -> Result<&Self>;
 
-    /// Send a burst of output packets on a transmit queue of an Ethernet device.
-    fn tx_burst<T: AsRaw<Raw = mbuf::RawMBuf>>(&self, queue_id: QueueId, rx_pkts: &mut [T]) -> usize;
+    /// Disable the RX interrupt of the queue.
+    fn rx_intr_disable(&self, queue_id: QueueId) -> Result<&Self>;
+
+    /// Add or delete the RX interrupts of all the queues to or from the epoll instance.
+    fn rx_intr_ctl(&self, epfd: RawFd, op: intr::IntrOp, data: *mut c_void) -> Result<&Self>;
+
+    /// Add or delete the RX interrupt of the queue to or from the epoll instance,
+    /// `data` is returned as the user data of the epoll event.
+    fn rx_intr_ctl_q(&self, queue_id: QueueId, epfd: RawFd, op: intr::IntrOp, data: *mut c_void) -> Result<&Self>;
+
+    /// Get the file descriptor of the RX interrupt of the queue.
+    fn rx_intr_fd(&self, queue_id: QueueId) -> Result<RawFd>;
 
     /// Read VLAN Offload configuration from an Ethernet device
     fn vlan_offload(&self) -> Result<EthVlanOffloadMode>;
 
     /// Set VLAN offload configuration on an Ethernet device
     fn set_vlan_offload(&self, mode: EthVlanOffloadMode) -> Result<&Self>;
-}
 
-/// Get the total number of Ethernet devices that have been successfully initialized
-/// by the matching Ethernet driver during the PCI probing phase.
-///
-/// All devices whose port identifier is in the range [0, rte::ethdev::count() - 1]
-/// can be operated on by network applications immediately after invoking rte_eal_init().
-/// If the application unplugs a port using hotplug function,
-/// The enabled port numbers may be noncontiguous.
-/// In the case, the applications need to manage enabled port by themselves.
-pub fn count() -> u16 {
-    unsafe { ffi::rte_eth_dev_count_avail() }
-}
+    /// Set a traffic mirroring rule on an Ethernet device, or turn it off.
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn set_mirror_rule(&self, rule_id: u8, conf: &MirrorConf, on: bool) -> Result<&Self>;
 
-pub fn devices() -> Range<PortId> {
-    0..count()
-}
+    /// Reset a traffic mirroring rule on an Ethernet device.
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn reset_mirror_rule(&self, rule_id: u8) -> Result<&Self>;
 
-impl EthDevice for PortId {
-    fn portid(&self) -> PortId {
-        *self
-    }
+    /// Query the RSS redirection table of the device.
+    fn rss_reta(&self) -> Result<RssReta>;
 
-    fn configure(&self, nb_rx_queue: QueueId, nb_tx_queue: QueueId, conf: &EthConf) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_dev_configure(*self,
-                                       nb_rx_queue,
-                                       nb_tx_queue,
-                                       RawEthConf::from(conf).as_raw())
-        }; ok => { self })
-    }
+    /// Update the RSS redirection table of the device, whose size must match `dev_info.reta_size`.
+    fn set_rss_reta(&self, reta: &RssReta) -> Result<&Self>;
 
-    fn info(&self) -> RawEthDeviceInfo {
-        let mut info: RawEthDeviceInfo = Default::default();
+    /// Get the IP reassembly capabilities supported by the device.
+    #[cfg(feature = "dpdk-22_11")]
+    fn ip_reassembly_capability(&self) -> Result<IpReassemblyParams>;
 
-        unsafe {
-            ffi::rte_eth_dev_info_get(*self, &mut info);
-        }
+    /// Get the IP reassembly configuration of the device.
+    #[cfg(feature = "dpdk-22_11")]
+    fn ip_reassembly_conf(&self) -> Result<IpReassemblyParams>;
 
-        info
-    }
+    /// Set the IP reassembly configuration, which must be called after configuring and before starting the device.
+    #[cfg(feature = "dpdk-22_11")]
+    fn set_ip_reassembly_conf(&self, conf: &IpReassemblyParams) -> Result<&Self>;
 
-    fn stats(&self) -> Result<RawEthDeviceStats> {
-        let mut stats: RawEthDeviceStats = Default::default();
+    /// Get the packet types of the layers in the mask, which are recognized by the device.
+    fn supported_ptypes(&self, mask: PtypeMask) -> Result<Vec<PacketType>>;
 
-        rte_check!(unsafe {
-            ffi::rte_eth_stats_get(*self, &mut stats)
-        }; ok => { stats })
-    }
+    /// Restrict the packet type recognition of the device to the layers in the mask,
+    /// or disable it if the mask is empty, return the packet types recognized by the device.
+    ///
+    /// The packet types of the other layers are reported as unknown to save the cycles of the driver.
+    fn set_ptypes(&self, mask: PtypeMask) -> Result<Vec<PacketType>>;
 
-    fn reset_stats(&self) -> &Self {
-        unsafe { ffi::rte_eth_stats_reset(*self) };
+    /// Enable the IEEE1588/802.1AS timestamping of the PTP packets.
+    fn timesync_enable(&self) -> Result<&Self>;
 
-        self
-    }
+    /// Disable the IEEE1588/802.1AS timestamping.
+    fn timesync_disable(&self) -> Result<&Self>;
 
-    fn mac_addr(&self) -> ether::EtherAddr {
-        unsafe {
-            let mut addr: ffi::rte_ether_addr = mem::zeroed();
+    /// Read the timestamp of a received PTP packet, `flags` is the timestamp register of `MBuf::timesync()`.
+    fn read_rx_timestamp(&self, flags: u32) -> Result<Duration>;
 
-            ffi::rte_eth_macaddr_get(*self, &mut addr);
+    /// Read the timestamp of the last transmitted PTP packet with the `PKT_TX_IEEE1588_TMST` offload.
+    fn read_tx_timestamp(&self) -> Result<Duration>;
 
-            ether::EtherAddr::from(addr.addr_bytes)
-        }
-    }
+    /// Adjust the time of the timesync clock by the nanoseconds.
+    fn adjust_time(&self, delta: i64) -> Result<&Self>;
 
-    fn set_mac_addr(&self, addr: &[u8; ether::ETHER_ADDR_LEN]) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_dev_default_mac_addr_set(*self, addr.as_ptr() as * mut _)
-        }; ok => { self })
-    }
+    /// Read the time of the timesync clock.
+    fn read_time(&self) -> Result<Duration>;
 
-    fn socket_id(&self) -> SocketId {
-        unsafe { ffi::rte_eth_dev_socket_id(*self) }
-    }
+    /// Set the time of the timesync clock.
+    fn write_time(&self, time: Duration) -> Result<&Self>;
 
-    fn is_valid(&self) -> bool {
-        unsafe { ffi::rte_eth_dev_is_valid_port(*self) != 0 }
-    }
+    /// Retrieve the information of a receive queue, e.g. the mempool and the number of descriptors.
+    fn rx_queue_info(&self, queue_id: QueueId) -> Result<RawRxQueueInfo>;
 
-    fn rx_queue_setup(
-        &self,
-        rx_queue_id: QueueId,
-        nb_rx_desc: u16,
-        rx_conf: Option<ffi::rte_eth_rxconf>,
-        mb_pool: &mut mempool::MemoryPool,
-    ) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_rx_queue_setup(*self,
-                                        rx_queue_id,
-                                        nb_rx_desc,
-                                        self.socket_id() as u32,
-                                        rx_conf.as_ref().map(|conf| conf as *const _).unwrap_or(ptr::null()),
-                                        mb_pool.as_raw_mut())
-        }; ok => { self })
-    }
+    /// Retrieve the information of a transmit queue, e.g. the number of descriptors.
+    fn tx_queue_info(&self, queue_id: QueueId) -> Result<RawTxQueueInfo>;
 
-    fn tx_queue_setup(
-        &self,
-        tx_queue_id: QueueId,
-        nb_tx_desc: u16,
-        tx_conf: Option<ffi::rte_eth_txconf>,
-    ) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_tx_queue_setup(*self,
-                                        tx_queue_id,
-                                        nb_tx_desc,
-                                        self.socket_id() as u32,
-                                        tx_conf.as_ref().map(|conf| conf as *const _).unwrap_or(ptr::null()))
-        }; ok => { self })
-    }
+    /// Get the burst mode of a receive queue, e.g. the vector instructions used by the driver.
+    fn rx_burst_mode(&self, queue_id: QueueId) -> Result<BurstMode>;
 
-    fn promiscuous_enable(&self) -> &Self {
-        unsafe { ffi::rte_eth_promiscuous_enable(*self) };
+    /// Get the burst mode of a transmit queue.
+    fn tx_burst_mode(&self, queue_id: QueueId) -> Result<BurstMode>;
 
-        self
+    /// Adjust the numbers of the receive and transmit descriptors to the limits of the device,
+    /// i.e. the minimum, maximum and alignment of the rings.
+    fn adjust_nb_rx_tx_desc(&self, nb_rx_desc: u16, nb_tx_desc: u16) -> Result<(u16, u16)>;
+
+    /// Get the hairpin capabilities of the device, e.g. the maximum number of hairpin queues.
+    fn hairpin_capability(&self) -> Result<RawHairpinCap>;
+
+    /// Allocate and set up a hairpin receive queue, which forwards the packets to the peer transmit queues.
+    fn rx_hairpin_queue_setup(&self, rx_queue_id: QueueId, nb_rx_desc: u16, conf: &HairpinConf) -> Result<&Self>;
+
+    /// Allocate and set up a hairpin transmit queue, which sends the packets from the peer receive queues.
+    fn tx_hairpin_queue_setup(&self, tx_queue_id: QueueId, nb_tx_desc: u16, conf: &HairpinConf) -> Result<&Self>;
+
+    /// Bind the hairpin transmit queues of the port to the receive queues of the peer port,
+    /// which is only required for the queues set up with `manual_bind`, after both ports are started.
+    fn hairpin_bind(&self, rx_port: PortId) -> Result<&Self>;
+
+    /// Unbind the hairpin transmit queues of the port from the receive queues of the peer port.
+    fn hairpin_unbind(&self, rx_port: PortId) -> Result<&Self>;
+
+    /// Get the peer ports of the hairpin queues, the receive ports if the port is used as the transmit side,
+    /// or the transmit ports otherwise.
+    fn hairpin_peer_ports(&self, tx: bool) -> Result<Vec<PortId>>;
+
+    /// Get the device name of the port, e.g. `0000:03:00.0` or `net_vhost0`.
+    fn name(&self) -> Result<String>;
+
+    /// Get the identifier and name of the owner of the port, or `None` if the port has no owner.
+    fn owner(&self) -> Result<Option<(OwnerId, String)>>;
+
+    /// Check if the device of the port was removed, e.g. hot-unplugged.
+    fn is_removed(&self) -> bool;
+
+    /// Stop and close the port, then remove its device with all the ports of the device.
+    ///
+    /// In multi-process, the other processes are requested to remove the same device.
+    fn detach(&self) -> Result<()>;
+
+    /// Register a callback called in the interrupt thread when the event occurs on the port,
+    /// e.g. the link status changed or the device was removed, which is unregistered when dropped.
+    ///
+    /// The interrupt of the event must be enabled in `EthConf`, e.g. `lsc_interrupt(true)`.
+    fn register_event_callback<F>(&self, event: EthEvent, callback: F) -> Result<EventCallback>
+    where
+        F: FnMut(PortId, EthEvent) + Send + 'static,
+    {
+        EventCallback::register(Some(self.portid()), event, callback)
     }
+}
 
-    fn promiscuous_disable(&self) -> &Self {
-        unsafe { ffi::rte_eth_promiscuous_disable(*self) };
+lazy_static! {
+    // The queues which have a handle, as the port, the direction (true for TX) and the queue.
+    static ref QUEUE_HANDLES: Mutex<HashSet<(PortId, bool, QueueId)>> = Mutex::new(HashSet::new());
+}
 
-        self
+// Take the handle of a queue, fail with `EBUSY` if the queue already has one.
+fn acquire_queue(port_id: PortId, tx: bool, queue_id: QueueId) -> Result<()> {
+    if QUEUE_HANDLES.lock().unwrap().insert((port_id, tx, queue_id)) {
+        Ok(())
+    } else {
+        Err(OsError(libc::EBUSY).into())
     }
+}
 
-    fn is_promiscuous_enabled(&self) -> Result<bool> {
-        let ret = unsafe { ffi::rte_eth_promiscuous_get(*self) };
+fn release_queue(port_id: PortId, tx: bool, queue_id: QueueId) {
+    QUEUE_HANDLES.lock().unwrap().remove(&(port_id, tx, queue_id));
+}
 
-        rte_check!(ret; ok => { ret != 0 })
+// Fail with `EBUSY` if the queue has a handle.
+fn check_queue_released(port_id: PortId, tx: bool, queue_id: QueueId) -> Result<()> {
+    if QUEUE_HANDLES.lock().unwrap().contains(&(port_id, tx, queue_id)) {
+        Err(OsError(libc::EBUSY).into())
+    } else {
+        Ok(())
     }
+}
 
-    fn mtu(&self) -> Result<u16> {
-        let mut mtu: u16 = 0;
+// Fail with `EBUSY` if any queue of the port has a handle.
+fn check_port_released(port_id: PortId) -> Result<()> {
+    if QUEUE_HANDLES.lock().unwrap().iter().any(|&(id, _, _)| id == port_id) {
+        Err(OsError(libc::EBUSY).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// The handle of a receive queue of an Ethernet device.
+///
+/// The receive functions of a queue are not thread-safe,
+/// so the handle can be moved to another lcore, but it can't be shared.
+///
+/// The handle is returned by `EthDevice::rx_queue_setup`, a queue has at most one handle,
+/// which is released when dropped.
+///
+/// The handle borrows the memory pool of the queue, which must outlive it.
+#[derive(Debug)]
+pub struct RxQueue<'a> {
+    port_id: PortId,
+    queue_id: QueueId,
+    _pool: PhantomData<&'a mempool::MemoryPool>,
+    _marker: NotSync,
+}
 
-        rte_check!(unsafe { ffi::rte_eth_dev_get_mtu(*self, &mut mtu)}; ok => { mtu })
+impl<'a> Drop for RxQueue<'a> {
+    fn drop(&mut self) {
+        release_queue(self.port_id, false, self.queue_id)
     }
+}
 
-    fn set_mtu(&self, mtu: u16) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_set_mtu(*self, mtu) }; ok => { self })
+impl<'a> RxQueue<'a> {
+    // Take the handle of a receive queue before it's set up.
+    pub(crate) fn acquire(port_id: PortId, queue_id: QueueId) -> Result<Self> {
+        acquire_queue(port_id, false, queue_id)?;
+
+        Ok(RxQueue {
+            port_id,
+            queue_id,
+            _pool: PhantomData,
+            _marker: NotSync::default(),
+        })
     }
 
-    fn set_vlan_filter(&self, vlan_id: u16, on: bool) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_dev_vlan_filter(*self, vlan_id, bool_value!(on) as i32)
-        }; ok => { self })
+    pub fn port_id(&self) -> PortId {
+        self.port_id
     }
 
-    fn link(&self) -> EthLink {
-        let mut link = rte_sys::rte_eth_link::default();
+    pub fn queue_id(&self) -> QueueId {
+        self.queue_id
+    }
 
+    /// Check if the queue is polled from the NUMA socket of the port, warn if it's a remote socket.
+    pub fn check_socket(&self) -> bool {
+        check_port_socket(self.port_id)
+    }
+
+    /// Retrieve a burst of input packets from the receive queue.
+    ///
+    /// The packet array doesn't need to be initialized,
+    /// the received packets are returned as the initialized head of it.
+    #[inline]
+    pub fn rx_burst<'b>(&mut self, rx_pkts: &'b mut [MaybeUninit<mbuf::RawMBufPtr>]) -> &'b mut [mbuf::RawMBufPtr] {
         unsafe {
-            ffi::rte_eth_link_get(*self, &mut link as *mut _);
+            let n = rx_burst_raw(
+                self.port_id.0,
+                self.queue_id,
+                rx_pkts.as_mut_ptr() as *mut _,
+                burst_len(rx_pkts.len()),
+            );
+
+            slice::from_raw_parts_mut(rx_pkts.as_mut_ptr() as *mut _, n as usize)
         }
+    }
 
-        EthLink {
-            speed: link.link_speed,
-            duplex: link.link_duplex() != 0,
-            autoneg: link.link_autoneg() != 0,
-            up: link.link_status() != 0,
-        }
+    /// Retrieve a burst of input packets into a fixed-size array on the stack.
+    ///
+    /// Only the first returned count of slots are initialized.
+    #[inline]
+    pub fn rx_burst_array<const N: usize>(&mut self) -> ([MaybeUninit<mbuf::MBuf>; N], usize) {
+        let mut rx_pkts: [MaybeUninit<mbuf::MBuf>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        let n = unsafe {
+            rx_burst_raw(
+                self.port_id.0,
+                self.queue_id,
+                rx_pkts.as_mut_ptr() as *mut _,
+                burst_len(N),
+            )
+        };
+
+        (rx_pkts, n as usize)
     }
 
-    fn link_nowait(&self) -> EthLink {
-        let mut link = rte_sys::rte_eth_link::default();
+    /// Retrieve a burst of input packets into the free slots of a batch.
+    #[inline]
+    pub fn rx_burst_batch<const N: usize>(&mut self, batch: &mut mbuf::MBufBatch<N>) -> usize {
+        let len = batch.len();
 
         unsafe {
-            ffi::rte_eth_link_get_nowait(*self, &mut link as *mut _);
-        }
+            let n = rx_burst_raw(
+                self.port_id.0,
+                self.queue_id,
+                batch.as_raw_mut_ptr().add(len),
+                burst_len(N - len),
+            ) as usize;
 
-        EthLink {
-            speed: link.link_speed,
-            duplex: link.link_duplex() != 0,
-            autoneg: link.link_autoneg() != 0,
-            up: link.link_status() != 0,
+            batch.set_len(len + n);
+
+            n
         }
     }
 
-    fn set_link_up(&self) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_set_link_up(*self) }; ok => { self })
-    }
+    /// Retrieve a burst of up to `N` input packets as a batch of owned mbufs,
+    /// which are freed if they are dropped without being sent.
+    #[inline]
+    pub fn rx_burst_owned<const N: usize>(&mut self) -> mbuf::MBufBatch<N> {
+        let mut batch = mbuf::MBufBatch::new();
 
-    fn set_link_down(&self) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_set_link_down(*self) }; ok => { self })
-    }
+        self.rx_burst_batch(&mut batch);
 
-    fn rx_queue_start(&self, rx_queue_id: QueueId) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_rx_queue_start(*self, rx_queue_id) }; ok => { self })
+        batch
     }
 
-    fn rx_queue_stop(&self, rx_queue_id: QueueId) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_rx_queue_stop(*self, rx_queue_id) }; ok => { self })
-    }
+    /// Get the number of the used descriptors of the queue.
+    pub fn count(&self) -> Result<usize> {
+        let ret = unsafe { ffi::_rte_eth_rx_queue_count(self.port_id.0, self.queue_id) };
 
-    fn tx_queue_start(&self, tx_queue_id: QueueId) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_tx_queue_start(*self, tx_queue_id) }; ok => { self })
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(ret as usize)
+        }
     }
 
-    fn tx_queue_stop(&self, tx_queue_id: QueueId) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_tx_queue_stop(*self, tx_queue_id) }; ok => { self })
-    }
+    /// Check the status of the descriptor at the offset from the next one to be received.
+    pub fn descriptor_status(&self, offset: u16) -> Result<RxDescStatus> {
+        let ret = unsafe { ffi::_rte_eth_rx_descriptor_status(self.port_id.0, self.queue_id, offset) };
 
-    fn start(&self) -> Result<&Self> {
-        rte_check!(unsafe { ffi::rte_eth_dev_start(*self) }; ok => { self })
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            RxDescStatus::from_i32(ret).ok_or_else(|| OsError(libc::EINVAL).into())
+        }
     }
+}
 
-    fn stop(&self) -> &Self {
-        unsafe { ffi::rte_eth_dev_stop(*self) };
+// The number of packets of a burst is limited to `u16::MAX`, instead of being truncated.
+#[inline(always)]
+fn burst_len(n: usize) -> u16 {
+    n.min(u16::MAX as usize) as u16
+}
 
-        self
-    }
+// The Rx/Tx callbacks are only run by the burst functions if DPDK was built with them.
+const RXTX_CALLBACKS: bool = ffi::RTE_ETHDEV_RXTX_CALLBACKS != 0;
 
-    fn close(&self) -> &Self {
-        unsafe { ffi::rte_eth_dev_close(*self) };
+// Load a callback list head the way `rte_eth_rx_burst()` and `rte_eth_tx_burst()` do.
+#[inline(always)]
+unsafe fn load_callback<T>(cb: *const *mut T) -> *mut T {
+    (*(cb as *const AtomicPtr<T>)).load(Ordering::Relaxed)
+}
 
-        self
+/// Receive a burst of packets by calling the receive function of the driver directly,
+/// so the hot path is inlined instead of going through the out-of-line `_rte_eth_rx_burst` shim.
+///
+/// It falls back to the shim if any Rx callback is installed on the queue, which must be run by DPDK.
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+#[inline(always)]
+unsafe fn rx_burst_raw(port_id: u16, queue_id: QueueId, rx_pkts: *mut mbuf::RawMBufPtr, nb_pkts: u16) -> u16 {
+    let dev = &*(ptr::addr_of!(ffi::rte_eth_devices) as *const ffi::rte_eth_dev).add(port_id as usize);
+
+    if RXTX_CALLBACKS && !load_callback(&dev.post_rx_burst_cbs[queue_id as usize]).is_null() {
+        return ffi::_rte_eth_rx_burst(port_id, queue_id, rx_pkts, nb_pkts);
     }
 
-    fn rx_burst(&self, queue_id: QueueId, rx_pkts: &mut [Option<mbuf::MBuf>]) -> usize {
-        unsafe {
-            ffi::_rte_eth_rx_burst(*self, queue_id, rx_pkts.as_mut_ptr() as *mut _, rx_pkts.len() as u16) as usize
-        }
+    match dev.rx_pkt_burst {
+        Some(rx_pkt_burst) => rx_pkt_burst(*(*dev.data).rx_queues.add(queue_id as usize), rx_pkts, nb_pkts),
+        None => 0,
     }
+}
 
-    fn tx_burst<T: AsRaw<Raw = mbuf::RawMBuf>>(&self, queue_id: QueueId, rx_pkts: &mut [T]) -> usize {
-        unsafe {
-            if rx_pkts.is_empty() {
-                ffi::_rte_eth_tx_burst(*self, queue_id, ptr::null_mut(), 0) as usize
-            } else {
-                ffi::_rte_eth_tx_burst(*self, queue_id, rx_pkts.as_mut_ptr() as *mut _, rx_pkts.len() as u16) as usize
-            }
-        }
+/// Send a burst of packets by calling the transmit function of the driver directly.
+///
+/// It falls back to the `_rte_eth_tx_burst` shim if any Tx callback is installed on the queue.
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+#[inline(always)]
+unsafe fn tx_burst_raw(port_id: u16, queue_id: QueueId, tx_pkts: *mut mbuf::RawMBufPtr, nb_pkts: u16) -> u16 {
+    let dev = &*(ptr::addr_of!(ffi::rte_eth_devices) as *const ffi::rte_eth_dev).add(port_id as usize);
+
+    if RXTX_CALLBACKS && !load_callback(&dev.pre_tx_burst_cbs[queue_id as usize]).is_null() {
+        return ffi::_rte_eth_tx_burst(port_id, queue_id, tx_pkts, nb_pkts);
     }
 
-    fn vlan_offload(&self) -> Result<EthVlanOffloadMode> {
-        let mode = unsafe { ffi::rte_eth_dev_get_vlan_offload(*self) };
+    match dev.tx_pkt_burst {
+        Some(tx_pkt_burst) => tx_pkt_burst(*(*dev.data).tx_queues.add(queue_id as usize), tx_pkts, nb_pkts),
+        None => 0,
+    }
+}
 
-        rte_check!(mode; ok => { EthVlanOffloadMode::from_bits_truncate(mode) })
+/// Receive a burst of packets through the flat `rte_eth_fp_ops` array,
+/// which holds the fast-path functions and queues of the ports since DPDK 21.11.
+///
+/// It falls back to the `_rte_eth_rx_burst` shim if any Rx callback is installed on the queue.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+#[inline(always)]
+unsafe fn rx_burst_raw(port_id: u16, queue_id: QueueId, rx_pkts: *mut mbuf::RawMBufPtr, nb_pkts: u16) -> u16 {
+    let p = &*(ptr::addr_of!(ffi::rte_eth_fp_ops) as *const ffi::rte_eth_fp_ops).add(port_id as usize);
+
+    if RXTX_CALLBACKS && !load_callback(p.rxq.clbk.add(queue_id as usize)).is_null() {
+        return ffi::_rte_eth_rx_burst(port_id, queue_id, rx_pkts, nb_pkts);
     }
 
-    fn set_vlan_offload(&self, mode: EthVlanOffloadMode) -> Result<&Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_dev_set_vlan_offload(*self, mode.bits)
-        }; ok => { self })
+    match p.rx_pkt_burst {
+        Some(rx_pkt_burst) => rx_pkt_burst(*p.rxq.data.add(queue_id as usize), rx_pkts, nb_pkts),
+        None => 0,
     }
 }
 
-pub trait EthDeviceInfo {
-    /// Device Driver name.
-    fn driver_name(&self) -> &str;
+/// Send a burst of packets through the flat `rte_eth_fp_ops` array.
+///
+/// It falls back to the `_rte_eth_tx_burst` shim if any Tx callback is installed on the queue.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+#[inline(always)]
+unsafe fn tx_burst_raw(port_id: u16, queue_id: QueueId, tx_pkts: *mut mbuf::RawMBufPtr, nb_pkts: u16) -> u16 {
+    let p = &*(ptr::addr_of!(ffi::rte_eth_fp_ops) as *const ffi::rte_eth_fp_ops).add(port_id as usize);
+
+    if RXTX_CALLBACKS && !load_callback(p.txq.clbk.add(queue_id as usize)).is_null() {
+        return ffi::_rte_eth_tx_burst(port_id, queue_id, tx_pkts, nb_pkts);
+    }
 
-    fn dev(&self) -> Option<dev::Device>;
+    match p.tx_pkt_burst {
+        Some(tx_pkt_burst) => tx_pkt_burst(*p.txq.data.add(queue_id as usize), tx_pkts, nb_pkts),
+        None => 0,
+    }
 }
 
-pub type RawEthDeviceInfo = ffi::rte_eth_dev_info;
+/// The status of a receive descriptor.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum RxDescStatus {
+    /// The descriptor is available for the hardware to receive a packet.
+    Avail = ffi::RTE_ETH_RX_DESC_AVAIL,
+    /// The descriptor is filled by the hardware, but not yet processed by the driver.
+    Done = ffi::RTE_ETH_RX_DESC_DONE,
+    /// The descriptor is held by the driver and not yet returned to the hardware, or reserved.
+    Unavail = ffi::RTE_ETH_RX_DESC_UNAVAIL,
+}
 
-impl EthDeviceInfo for RawEthDeviceInfo {
-    #[inline]
-    fn driver_name(&self) -> &str {
-        unsafe { CStr::from_ptr(self.driver_name).to_str().unwrap() }
-    }
+/// The status of a transmit descriptor.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum TxDescStatus {
+    /// The descriptor is being processed by the hardware, i.e. in the transmit queue.
+    Full = ffi::RTE_ETH_TX_DESC_FULL,
+    /// The hardware is done with the descriptor, it can be reused by the driver.
+    Done = ffi::RTE_ETH_TX_DESC_DONE,
+    /// The descriptor is used by the driver or reserved.
+    Unavail = ffi::RTE_ETH_TX_DESC_UNAVAIL,
+}
 
-    #[inline]
-    fn dev(&self) -> Option<dev::Device> {
-        if self.device.is_null() {
-            None
-        } else {
-            Some(self.device.into())
+pub type RawRxQueueInfo = ffi::rte_eth_rxq_info;
+pub type RawTxQueueInfo = ffi::rte_eth_txq_info;
+pub type RawBurstMode = ffi::rte_eth_burst_mode;
+
+/// The packet burst mode of a receive or transmit queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BurstMode {
+    /// The burst mode is configured per queue, otherwise it's the same for all the queues of the port.
+    pub per_queue: bool,
+    /// The description of the burst mode, e.g. `Vector AVX2`.
+    pub info: String,
+}
+
+impl From<RawBurstMode> for BurstMode {
+    fn from(mode: RawBurstMode) -> Self {
+        BurstMode {
+            per_queue: (mode.flags & ffi::RTE_ETH_BURST_FLAG_PER_QUEUE as u64) != 0,
+            info: unsafe { CStr::from_ptr(mode.info.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
         }
     }
 }
 
-pub trait EthDeviceStats {}
+pub type RawHairpinCap = ffi::rte_eth_hairpin_cap;
+pub type RawHairpinConf = ffi::rte_eth_hairpin_conf;
 
-pub type RawEthDeviceStats = ffi::rte_eth_stats;
+pub const MAX_HAIRPIN_PEERS: usize = ffi::RTE_ETH_MAX_HAIRPIN_PEERS as usize;
 
-impl EthDeviceStats for RawEthDeviceStats {}
+/// The configuration of a hairpin queue, which forwards the packets between the ports in the hardware.
+#[derive(Clone, Debug, Default)]
+pub struct HairpinConf {
+    /// The peer queues, the transmit queues of a receive hairpin queue and vice versa.
+    pub peers: Vec<(PortId, QueueId)>,
+    /// The flow rules of the transmit side are inserted explicitly by the application.
+    pub tx_explicit: bool,
+    /// The queues are bound manually by `hairpin_bind` instead of when the port is started.
+    pub manual_bind: bool,
+}
 
-bitflags! {
-    /// Definitions used for VMDQ pool rx mode setting
+impl HairpinConf {
+    /// Create the configuration of a hairpin queue with a peer queue.
+    pub fn new(peer_port: PortId, peer_queue: QueueId) -> Self {
+        HairpinConf {
+            peers: vec![(peer_port, peer_queue)],
+            ..Default::default()
+        }
+    }
+
+    /// Add a peer queue to the configuration.
+    pub fn peer(mut self, peer_port: PortId, peer_queue: QueueId) -> Self {
+        self.peers.push((peer_port, peer_queue));
+        self
+    }
+
+    fn to_raw(&self) -> Result<RawHairpinConf> {
+        if self.peers.is_empty() || self.peers.len() > MAX_HAIRPIN_PEERS {
+            return Err(InvalidEthConf(format!("{} hairpin peers", self.peers.len())).into());
+        }
+
+        let mut conf = RawHairpinConf::default();
+
+        conf.set_peer_count(self.peers.len() as u32);
+        conf.set_tx_explicit(self.tx_explicit as u32);
+        conf.set_manual_bind(self.manual_bind as u32);
+
+        for (peer, &(port_id, queue_id)) in conf.peers.iter_mut().zip(self.peers.iter()) {
+            peer.port = port_id.0;
+            peer.queue = queue_id;
+        }
+
+        Ok(conf)
+    }
+}
+
+/// The handle of a transmit queue of an Ethernet device.
+///
+/// The transmit functions of a queue are not thread-safe,
+/// so the handle can be moved to another lcore, but it can't be shared.
+///
+/// The handle is returned by `EthDevice::tx_queue_setup`, a queue has at most one handle,
+/// which is released when dropped.
+#[derive(Debug)]
+pub struct TxQueue {
+    port_id: PortId,
+    queue_id: QueueId,
+    _marker: NotSync,
+}
+
+impl Drop for TxQueue {
+    fn drop(&mut self) {
+        release_queue(self.port_id, true, self.queue_id)
+    }
+}
+
+impl TxQueue {
+    // Take the handle of a transmit queue before it's set up.
+    pub(crate) fn acquire(port_id: PortId, queue_id: QueueId) -> Result<Self> {
+        acquire_queue(port_id, true, queue_id)?;
+
+        Ok(TxQueue {
+            port_id,
+            queue_id,
+            _marker: NotSync::default(),
+        })
+    }
+
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    pub fn queue_id(&self) -> QueueId {
+        self.queue_id
+    }
+
+    /// Check if the queue is used from the NUMA socket of the port, warn if it's a remote socket.
+    pub fn check_socket(&self) -> bool {
+        check_port_socket(self.port_id)
+    }
+
+    /// Send a burst of output packets on the transmit queue.
+    #[inline]
+    pub fn tx_burst<T: AsRaw<Raw = mbuf::RawMBuf>>(&mut self, tx_pkts: &mut [T]) -> usize {
+        unsafe {
+            if tx_pkts.is_empty() {
+                tx_burst_raw(self.port_id.0, self.queue_id, ptr::null_mut(), 0) as usize
+            } else {
+                tx_burst_raw(
+                    self.port_id.0,
+                    self.queue_id,
+                    tx_pkts.as_mut_ptr() as *mut _,
+                    burst_len(tx_pkts.len()),
+                ) as usize
+            }
+        }
+    }
+
+    /// Send a burst of output packets, and free the packets which could not be sent.
+    ///
+    /// Return the number of packets actually sent.
+    #[inline]
+    pub fn tx_burst_and_free_rest(&mut self, tx_pkts: &mut [mbuf::RawMBufPtr]) -> usize {
+        let n = self.tx_burst(tx_pkts);
+
+        mbuf::free_burst(&mut tx_pkts[n..]);
+
+        n
+    }
+
+    /// Send the packets of a batch, the sent packets are removed from the front of it.
+    #[inline]
+    pub fn tx_burst_batch<const N: usize>(&mut self, batch: &mut mbuf::MBufBatch<N>) -> usize {
+        unsafe {
+            let n = tx_burst_raw(
+                self.port_id.0,
+                self.queue_id,
+                batch.as_raw_mut_ptr(),
+                burst_len(batch.len()),
+            ) as usize;
+
+            // the sent mbufs are owned by the driver
+            batch.consume(n);
+
+            n
+        }
+    }
+
+    /// Send the packets of a batch, taking the ownership of them, and return the packets which could not be sent.
+    #[inline]
+    pub fn tx_burst_owned<const N: usize>(&mut self, mut batch: mbuf::MBufBatch<N>) -> mbuf::MBufBatch<N> {
+        self.tx_burst_batch(&mut batch);
+
+        batch
+    }
+
+    /// Check and prepare the offload metadata of a burst of output packets before `tx_burst()`,
+    /// e.g. the pseudo-header checksums required by the TSO or checksum offloads.
+    ///
+    /// Return the number of the valid packets at the front, the reason of the first invalid one is in `rte_errno`.
+    #[inline]
+    pub fn tx_prepare<T: AsRaw<Raw = mbuf::RawMBuf>>(&mut self, tx_pkts: &mut [T]) -> usize {
+        if tx_pkts.is_empty() {
+            0
+        } else {
+            unsafe {
+                ffi::_rte_eth_tx_prepare(
+                    self.port_id.0,
+                    self.queue_id,
+                    tx_pkts.as_mut_ptr() as *mut _,
+                    burst_len(tx_pkts.len()),
+                ) as usize
+            }
+        }
+    }
+
+    /// Free up to `free_cnt` transmitted mbufs of the queue, or as many as possible if it's 0,
+    /// which may be used to reclaim the mbufs when the mempool is running out.
+    ///
+    /// Return the number of the freed packets.
+    pub fn tx_done_cleanup(&mut self, free_cnt: u32) -> Result<usize> {
+        let ret = unsafe { ffi::rte_eth_tx_done_cleanup(self.port_id.0, self.queue_id, free_cnt) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    /// Check the status of the descriptor at the offset from the next one to be sent.
+    pub fn descriptor_status(&self, offset: u16) -> Result<TxDescStatus> {
+        let ret = unsafe { ffi::_rte_eth_tx_descriptor_status(self.port_id.0, self.queue_id, offset) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            TxDescStatus::from_i32(ret).ok_or_else(|| OsError(libc::EINVAL).into())
+        }
+    }
+}
+
+/// The enabled lcores on the NUMA socket of the port, which should be used to poll its queues.
+///
+/// All the enabled lcores are returned if the socket of the port is unknown.
+pub fn lcores_for_port(port_id: PortId) -> Vec<lcore::Id> {
+    lcore::on_socket(port_id.socket_id())
+}
+
+/// Check if the port is on the NUMA socket of the current lcore, warn if it's accessed from a remote socket.
+pub fn check_port_socket(port_id: PortId) -> bool {
+    let port_socket = port_id.socket_id();
+    let lcore_socket = lcore::socket_id() as SocketId;
+
+    if port_socket == SOCKET_ID_ANY || port_socket == lcore_socket {
+        true
+    } else {
+        warn!(
+            "port {} is on remote NUMA socket {} to the lcore on socket {}, performance will not be optimal.",
+            port_id, port_socket, lcore_socket
+        );
+
+        false
+    }
+}
+
+/// The event types of an Ethernet device.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum EthEvent {
+    /// Unknown event type.
+    Unknown = ffi::rte_eth_event_type::RTE_ETH_EVENT_UNKNOWN,
+    /// The link status changed.
+    LscChange = ffi::rte_eth_event_type::RTE_ETH_EVENT_INTR_LSC,
+    /// The queue state changed, e.g. enabled or disabled.
+    QueueState = ffi::rte_eth_event_type::RTE_ETH_EVENT_QUEUE_STATE,
+    /// The device must be reset by `reset()` or `recover()`, e.g. after a VF was reset by the PF.
+    Reset = ffi::rte_eth_event_type::RTE_ETH_EVENT_INTR_RESET,
+    /// A message from the VF was received by the PF.
+    VfMbox = ffi::rte_eth_event_type::RTE_ETH_EVENT_VF_MBOX,
+    /// A MACsec offload event.
+    Macsec = ffi::rte_eth_event_type::RTE_ETH_EVENT_MACSEC,
+    /// The device was removed, e.g. hot-unplugged.
+    Remove = ffi::rte_eth_event_type::RTE_ETH_EVENT_INTR_RMV,
+    /// A port was probed.
+    New = ffi::rte_eth_event_type::RTE_ETH_EVENT_NEW,
+    /// A port was released.
+    Destroy = ffi::rte_eth_event_type::RTE_ETH_EVENT_DESTROY,
+    /// An IPsec offload event.
+    Ipsec = ffi::rte_eth_event_type::RTE_ETH_EVENT_IPSEC,
+    /// New flow rules aged out.
+    FlowAged = ffi::rte_eth_event_type::RTE_ETH_EVENT_FLOW_AGED,
+}
+
+impl From<ffi::rte_eth_event_type::Type> for EthEvent {
+    fn from(event: ffi::rte_eth_event_type::Type) -> Self {
+        EthEvent::from_u32(event).unwrap_or(EthEvent::Unknown)
+    }
+}
+
+type EventHandler = Box<dyn FnMut(PortId, EthEvent) + Send>;
+
+unsafe extern "C" fn event_stub(
+    port_id: u16,
+    event: ffi::rte_eth_event_type::Type,
+    arg: *mut c_void,
+    _ret_param: *mut c_void,
+) -> c_int {
+    let callback = &mut *(arg as *mut EventHandler);
+
+    catch_panic("ethdev event callback", (), || {
+        callback(PortId(port_id), EthEvent::from(event))
+    });
+
+    0
+}
+
+/// The callback registered for an event of the Ethernet devices, which is unregistered when dropped.
+pub struct EventCallback {
+    port_id: u16,
+    event: EthEvent,
+    callback: *mut EventHandler,
+}
+
+unsafe impl Send for EventCallback {}
+
+impl Drop for EventCallback {
+    fn drop(&mut self) {
+        loop {
+            let ret = unsafe {
+                ffi::rte_eth_dev_callback_unregister(
+                    self.port_id,
+                    self.event as ffi::rte_eth_event_type::Type,
+                    Some(event_stub),
+                    self.callback as *mut c_void,
+                )
+            };
+
+            // the callback is executing in the interrupt thread
+            if ret != -libc::EAGAIN {
+                break;
+            }
+        }
+
+        drop(unsafe { Box::from_raw(self.callback) });
+    }
+}
+
+impl EventCallback {
+    /// Register a callback called in the interrupt thread when the event occurs on the port,
+    /// or on any port if `port_id` is `None`, e.g. `EthEvent::New` for the hotplugged ports.
+    pub fn register<F>(port_id: Option<PortId>, event: EthEvent, callback: F) -> Result<Self>
+    where
+        F: FnMut(PortId, EthEvent) + Send + 'static,
+    {
+        let port_id = port_id.map_or(ffi::RTE_ETH_ALL as u16, |port_id| port_id.0);
+        let callback = Box::into_raw(Box::new(Box::new(callback) as EventHandler));
+
+        let ret = unsafe {
+            ffi::rte_eth_dev_callback_register(
+                port_id,
+                event as ffi::rte_eth_event_type::Type,
+                Some(event_stub),
+                callback as *mut c_void,
+            )
+        };
+
+        if ret < 0 {
+            drop(unsafe { Box::from_raw(callback) });
+
+            return Err(OsError(-ret).into());
+        }
+
+        Ok(EventCallback {
+            port_id,
+            event,
+            callback,
+        })
+    }
+
+    /// The event of the callback.
+    pub fn event(&self) -> EthEvent {
+        self.event
+    }
+}
+
+/// Get the total number of Ethernet devices that have been successfully initialized
+/// by the matching Ethernet driver during the PCI probing phase.
+///
+/// The port identifiers may be noncontiguous if the application unplugs a port using hotplug function,
+/// so the ports should be iterated with `rte::ethdev::devices()`.
+pub fn count() -> u16 {
+    unsafe { ffi::rte_eth_dev_count_avail() }
+}
+
+/// Iterate the valid ports without owner, which may be noncontiguous after hotplug.
+pub fn devices() -> Devices {
+    Devices {
+        next: 0,
+        owner_id: NO_OWNER,
+    }
+}
+
+/// An iterator over the valid ports of an owner, like `RTE_ETH_FOREACH_DEV_OWNED_BY`.
+#[derive(Clone, Debug)]
+pub struct Devices {
+    next: u16,
+    owner_id: OwnerId,
+}
+
+impl Iterator for Devices {
+    type Item = PortId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let port_id = unsafe { ffi::rte_eth_find_next_owned_by(self.next, self.owner_id) } as usize;
+
+        if port_id < MAX_ETHPORTS {
+            self.next = port_id as u16 + 1;
+
+            Some(PortId(port_id as u16))
+        } else {
+            self.next = MAX_ETHPORTS as u16;
+
+            None
+        }
+    }
+}
+
+/// Probe the device of the devargs, e.g. `0000:03:00.1` or `net_vhost0,iface=/tmp/sock0`,
+/// and return the ports of the device.
+///
+/// In multi-process, the other processes are requested to probe the same device.
+pub fn probe<S: AsRef<str>>(devargs: S) -> Result<Vec<PortId>> {
+    let s = devargs.as_ref().as_cstring();
+
+    let ret = unsafe { ffi::rte_dev_probe(s.as_ptr()) };
+
+    if ret < 0 {
+        Err(OsError(-ret).into())
+    } else {
+        matching(devargs)
+    }
+}
+
+/// Get the ports matching the devargs whatever their owners, e.g. `class=eth,mac=00:11:22:33:44:55`.
+pub fn matching<S: AsRef<str>>(devargs: S) -> Result<Vec<PortId>> {
+    let devargs = devargs.as_ref().as_cstring();
+    let mut iter = ffi::rte_dev_iterator::default();
+
+    let ret = unsafe { ffi::rte_eth_iterator_init(&mut iter, devargs.as_ptr()) };
+
+    if ret < 0 {
+        return Err(OsError(-ret).into());
+    }
+
+    let mut ports = vec![];
+
+    // the iterator is cleaned up when no more port is found
+    loop {
+        let port_id = unsafe { ffi::rte_eth_iterator_next(&mut iter) };
+
+        if port_id as usize >= MAX_ETHPORTS {
+            break;
+        }
+
+        ports.push(PortId(port_id));
+    }
+
+    Ok(ports)
+}
+
+/// Get the port of the device name.
+pub fn port_by_name<S: AsRef<str>>(name: S) -> Result<PortId> {
+    let name = name.as_ref().as_cstring();
+    let mut port_id = 0;
+
+    let ret = unsafe { ffi::rte_eth_dev_get_port_by_name(name.as_ptr(), &mut port_id) };
+
+    if ret < 0 {
+        Err(OsError(-ret).into())
+    } else {
+        Ok(PortId(port_id))
+    }
+}
+
+/// The identifier of an owner of the ports.
+pub type OwnerId = u64;
+
+/// The owner identifier of the ports without owner.
+pub const NO_OWNER: OwnerId = ffi::RTE_ETH_DEV_NO_OWNER as OwnerId;
+
+/// The maximum length of the owner names, including the trailing NUL.
+pub const MAX_OWNER_NAME_LEN: usize = ffi::RTE_ETH_MAX_OWNER_NAME_LEN as usize;
+
+/// An owner of the ports, e.g. a failsafe or bonding device taking its sub-devices,
+/// the owned ports are skipped by `devices()`.
+///
+/// All the ports owned by the owner are released when dropped.
+pub struct Owner(ffi::rte_eth_dev_owner);
+
+impl fmt::Debug for Owner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Owner")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_eth_dev_owner_delete(self.0.id) };
+    }
+}
+
+impl Owner {
+    /// Allocate a unique owner identifier with the name, which is truncated to `MAX_OWNER_NAME_LEN - 1` bytes.
+    pub fn new<S: AsRef<str>>(name: S) -> Result<Self> {
+        let mut owner = ffi::rte_eth_dev_owner::default();
+
+        let ret = unsafe { ffi::rte_eth_dev_owner_new(&mut owner.id) };
+
+        if ret < 0 {
+            return Err(OsError(-ret).into());
+        }
+
+        let name = name.as_ref().as_bytes();
+        let len = cmp::min(name.len(), MAX_OWNER_NAME_LEN - 1);
+
+        for (dst, &src) in owner.name.iter_mut().zip(&name[..len]) {
+            *dst = src as _;
+        }
+
+        Ok(Owner(owner))
+    }
+
+    /// The identifier of the owner.
+    pub fn id(&self) -> OwnerId {
+        self.0.id
+    }
+
+    /// The name of the owner.
+    pub fn name(&self) -> String {
+        unsafe { CStr::from_ptr(self.0.name.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Take the ownership of the port, which fails if the port already has an owner.
+    pub fn take(&self, port_id: PortId) -> Result<()> {
+        let ret = unsafe { ffi::rte_eth_dev_owner_set(port_id.0, &self.0) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Release the ownership of the port.
+    pub fn release(&self, port_id: PortId) -> Result<()> {
+        let ret = unsafe { ffi::rte_eth_dev_owner_unset(port_id.0, self.0.id) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Iterate the valid ports owned by the owner.
+    pub fn devices(&self) -> Devices {
+        Devices {
+            next: 0,
+            owner_id: self.0.id,
+        }
+    }
+}
+
+fn timespec_to_duration(ts: &ffi::timespec) -> Duration {
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+impl EthDevice for PortId {
+    fn portid(&self) -> PortId {
+        *self
+    }
+
+    fn configure(&self, nb_rx_queue: QueueId, nb_tx_queue: QueueId, conf: &EthConf) -> Result<&Self> {
+        check_port_released(*self)?;
+
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_configure(self.0,
+                                       nb_rx_queue,
+                                       nb_tx_queue,
+                                       RawEthConf::from(conf).as_raw())
+        }; ok => { self })
+    }
+
+    fn info(&self) -> RawEthDeviceInfo {
+        let mut info: RawEthDeviceInfo = Default::default();
+
+        unsafe {
+            ffi::rte_eth_dev_info_get(self.0, &mut info);
+        }
+
+        info
+    }
+
+    fn stats(&self) -> Result<RawEthDeviceStats> {
+        let mut stats: RawEthDeviceStats = Default::default();
+
+        rte_check!(unsafe {
+            ffi::rte_eth_stats_get(self.0, &mut stats)
+        }; ok => { stats })
+    }
+
+    fn reset_stats(&self) -> &Self {
+        unsafe { ffi::rte_eth_stats_reset(self.0) };
+
+        self
+    }
+
+    fn set_rx_queue_stats_mapping(&self, rx_queue_id: QueueId, stat_idx: u8) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_dev_set_rx_queue_stats_mapping(self.0, rx_queue_id, stat_idx) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn set_tx_queue_stats_mapping(&self, tx_queue_id: QueueId, stat_idx: u8) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_dev_set_tx_queue_stats_mapping(self.0, tx_queue_id, stat_idx) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn led_on(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_led_on(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn led_off(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_led_off(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn mac_addr(&self) -> ether::EtherAddr {
+        unsafe {
+            let mut addr: ffi::rte_ether_addr = mem::zeroed();
+
+            ffi::rte_eth_macaddr_get(self.0, &mut addr);
+
+            ether::EtherAddr::from(addr.addr_bytes)
+        }
+    }
+
+    fn set_mac_addr(&self, addr: &[u8; ether::ETHER_ADDR_LEN]) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_default_mac_addr_set(self.0, addr.as_ptr() as * mut _)
+        }; ok => { self })
+    }
+
+    fn socket_id(&self) -> SocketId {
+        unsafe { ffi::rte_eth_dev_socket_id(self.0) }
+    }
+
+    fn is_valid(&self) -> bool {
+        unsafe { ffi::rte_eth_dev_is_valid_port(self.0) != 0 }
+    }
+
+    fn rx_queue_setup<'a>(
+        &self,
+        rx_queue_id: QueueId,
+        nb_rx_desc: u16,
+        rx_conf: Option<ffi::rte_eth_rxconf>,
+        mb_pool: &'a mempool::MemoryPool,
+    ) -> Result<RxQueue<'a>> {
+        let rxq = RxQueue::acquire(*self, rx_queue_id)?;
+
+        rte_check!(unsafe {
+            ffi::rte_eth_rx_queue_setup(self.0,
+                                        rx_queue_id,
+                                        nb_rx_desc,
+                                        self.socket_id() as u32,
+                                        rx_conf.as_ref().map(|conf| conf as *const _).unwrap_or(ptr::null()),
+                                        mb_pool.as_raw_mut())
+        }; ok => { rxq })
+    }
+
+    fn tx_queue_setup(
+        &self,
+        tx_queue_id: QueueId,
+        nb_tx_desc: u16,
+        tx_conf: Option<ffi::rte_eth_txconf>,
+    ) -> Result<TxQueue> {
+        let txq = TxQueue::acquire(*self, tx_queue_id)?;
+
+        rte_check!(unsafe {
+            ffi::rte_eth_tx_queue_setup(self.0,
+                                        tx_queue_id,
+                                        nb_tx_desc,
+                                        self.socket_id() as u32,
+                                        tx_conf.as_ref().map(|conf| conf as *const _).unwrap_or(ptr::null()))
+        }; ok => { txq })
+    }
+
+    fn promiscuous_enable(&self) -> &Self {
+        unsafe { ffi::rte_eth_promiscuous_enable(self.0) };
+
+        self
+    }
+
+    fn promiscuous_disable(&self) -> &Self {
+        unsafe { ffi::rte_eth_promiscuous_disable(self.0) };
+
+        self
+    }
+
+    fn is_promiscuous_enabled(&self) -> Result<bool> {
+        let ret = unsafe { ffi::rte_eth_promiscuous_get(self.0) };
+
+        rte_check!(ret; ok => { ret != 0 })
+    }
+
+    fn add_mac_addr(&self, addr: &ether::EtherAddr, pool: u32) -> Result<&Self> {
+        let mut addr = ether::RawEtherAddr::from(*addr);
+
+        let ret = unsafe { ffi::rte_eth_dev_mac_addr_add(self.0, &mut addr, pool) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn remove_mac_addr(&self, addr: &ether::EtherAddr) -> Result<&Self> {
+        let mut addr = ether::RawEtherAddr::from(*addr);
+
+        let ret = unsafe { ffi::rte_eth_dev_mac_addr_remove(self.0, &mut addr) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn set_mc_addr_list(&self, addrs: &[ether::EtherAddr]) -> Result<&Self> {
+        let mut addrs = addrs
+            .iter()
+            .map(|&addr| ether::RawEtherAddr::from(addr))
+            .collect::<Vec<_>>();
+
+        let ret = unsafe {
+            ffi::rte_eth_dev_set_mc_addr_list(
+                self.0,
+                if addrs.is_empty() {
+                    ptr::null_mut()
+                } else {
+                    addrs.as_mut_ptr()
+                },
+                addrs.len() as u32,
+            )
+        };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn allmulticast_enable(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_allmulticast_enable(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn allmulticast_disable(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_allmulticast_disable(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn is_allmulticast_enabled(&self) -> Result<bool> {
+        let ret = unsafe { ffi::rte_eth_allmulticast_get(self.0) };
+
+        rte_check!(ret; ok => { ret != 0 })
+    }
+
+    fn mtu(&self) -> Result<u16> {
+        let mut mtu: u16 = 0;
+
+        rte_check!(unsafe { ffi::rte_eth_dev_get_mtu(self.0, &mut mtu)}; ok => { mtu })
+    }
+
+    fn set_mtu(&self, mtu: u16) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_set_mtu(self.0, mtu) }; ok => { self })
+    }
+
+    fn set_vlan_filter(&self, vlan_id: u16, on: bool) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_vlan_filter(self.0, vlan_id, bool_value!(on) as i32)
+        }; ok => { self })
+    }
+
+    fn set_vlan_strip_on_queue(&self, rx_queue_id: QueueId, on: bool) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_dev_set_vlan_strip_on_queue(self.0, rx_queue_id, bool_value!(on) as i32) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn set_vlan_ether_type(&self, vlan_type: VlanType, tpid: u16) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_dev_set_vlan_ether_type(self.0, vlan_type as ffi::rte_vlan_type::Type, tpid) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn set_vlan_pvid(&self, pvid: u16, on: bool) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_dev_set_vlan_pvid(self.0, pvid, bool_value!(on) as i32) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn link(&self) -> EthLink {
+        let mut link = rte_sys::rte_eth_link::default();
+
+        unsafe {
+            ffi::rte_eth_link_get(self.0, &mut link as *mut _);
+        }
+
+        EthLink {
+            speed: link.link_speed,
+            duplex: link.link_duplex() != 0,
+            autoneg: link.link_autoneg() != 0,
+            up: link.link_status() != 0,
+        }
+    }
+
+    fn link_nowait(&self) -> EthLink {
+        let mut link = rte_sys::rte_eth_link::default();
+
+        unsafe {
+            ffi::rte_eth_link_get_nowait(self.0, &mut link as *mut _);
+        }
+
+        EthLink {
+            speed: link.link_speed,
+            duplex: link.link_duplex() != 0,
+            autoneg: link.link_autoneg() != 0,
+            up: link.link_status() != 0,
+        }
+    }
+
+    fn set_link_up(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_set_link_up(self.0) }; ok => { self })
+    }
+
+    fn set_link_down(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_set_link_down(self.0) }; ok => { self })
+    }
+
+    fn rx_queue_start(&self, rx_queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_rx_queue_start(self.0, rx_queue_id) }; ok => { self })
+    }
+
+    fn rx_queue_stop(&self, rx_queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_rx_queue_stop(self.0, rx_queue_id) }; ok => { self })
+    }
+
+    fn tx_queue_start(&self, tx_queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_tx_queue_start(self.0, tx_queue_id) }; ok => { self })
+    }
+
+    fn tx_queue_stop(&self, tx_queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_tx_queue_stop(self.0, tx_queue_id) }; ok => { self })
+    }
+
+    fn start(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_start(self.0) }; ok => { self })
+    }
+
+    fn stop(&self) -> &Self {
+        unsafe { ffi::rte_eth_dev_stop(self.0) };
+
+        self
+    }
+
+    fn close(&self) -> Result<&Self> {
+        check_port_released(*self)?;
+
+        unsafe { ffi::rte_eth_dev_close(self.0) };
+
+        Ok(self)
+    }
+
+    fn reset(&self) -> Result<&Self> {
+        check_port_released(*self)?;
+
+        let ret = unsafe { ffi::rte_eth_dev_reset(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn rx_intr_enable(&self, queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_rx_intr_enable(self.0, queue_id) }; ok => { self })
+    }
+
+    fn rx_intr_disable(&self, queue_id: QueueId) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_rx_intr_disable(self.0, queue_id) }; ok => { self })
+    }
+
+    fn rx_intr_ctl(&self, epfd: RawFd, op: intr::IntrOp, data: *mut c_void) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_eth_dev_rx_intr_ctl(self.0, epfd, op as i32, data) }; ok => { self })
+    }
+
+    fn rx_intr_ctl_q(&self, queue_id: QueueId, epfd: RawFd, op: intr::IntrOp, data: *mut c_void) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_rx_intr_ctl_q(self.0, queue_id, epfd, op as i32, data)
+        }; ok => { self })
+    }
+
+    fn rx_intr_fd(&self, queue_id: QueueId) -> Result<RawFd> {
+        let fd = unsafe { ffi::rte_eth_dev_rx_intr_ctl_q_get_fd(self.0, queue_id) };
+
+        if fd < 0 {
+            Err(rte_error().into())
+        } else {
+            Ok(fd)
+        }
+    }
+
+    fn vlan_offload(&self) -> Result<EthVlanOffloadMode> {
+        let mode = unsafe { ffi::rte_eth_dev_get_vlan_offload(self.0) };
+
+        rte_check!(mode; ok => { EthVlanOffloadMode::from_bits_truncate(mode) })
+    }
+
+    fn set_vlan_offload(&self, mode: EthVlanOffloadMode) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_set_vlan_offload(self.0, mode.bits)
+        }; ok => { self })
+    }
+
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn set_mirror_rule(&self, rule_id: u8, conf: &MirrorConf, on: bool) -> Result<&Self> {
+        let mut conf = conf.to_raw()?;
+
+        rte_check!(unsafe {
+            ffi::rte_eth_mirror_rule_set(self.0, &mut conf, rule_id, on as u8)
+        }; ok => { self })
+    }
+
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn reset_mirror_rule(&self, rule_id: u8) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_eth_mirror_rule_reset(self.0, rule_id)
+        }; ok => { self })
+    }
+
+    fn rss_reta(&self) -> Result<RssReta> {
+        let mut reta = RssReta::new(self.info().reta_size as usize);
+        let mut conf = reta.to_raw();
+
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_rss_reta_query(self.0, conf.as_mut_ptr(), reta.len() as u16)
+        }; ok => {
+            for (i, queue_id) in reta.entries.iter_mut().enumerate() {
+                *queue_id = conf[i / RETA_GROUP_SIZE].reta[i % RETA_GROUP_SIZE];
+            }
+
+            reta
+        })
+    }
+
+    fn set_rss_reta(&self, reta: &RssReta) -> Result<&Self> {
+        let mut conf = reta.to_raw();
+
+        rte_check!(unsafe {
+            ffi::rte_eth_dev_rss_reta_update(self.0, conf.as_mut_ptr(), reta.len() as u16)
+        }; ok => { self })
+    }
+
+    fn supported_ptypes(&self, mask: PtypeMask) -> Result<Vec<PacketType>> {
+        let n = unsafe { ffi::rte_eth_dev_get_supported_ptypes(self.0, mask.bits(), ptr::null_mut(), 0) };
+
+        if n < 0 {
+            return Err(OsError(-n).into());
+        }
+
+        let mut ptypes = vec![PacketType::default(); n as usize];
+
+        let n = unsafe { ffi::rte_eth_dev_get_supported_ptypes(self.0, mask.bits(), ptypes.as_mut_ptr() as *mut _, n) };
+
+        if n < 0 {
+            Err(OsError(-n).into())
+        } else {
+            ptypes.truncate(n as usize);
+
+            Ok(ptypes)
+        }
+    }
+
+    fn set_ptypes(&self, mask: PtypeMask) -> Result<Vec<PacketType>> {
+        // the set packet types are terminated by `RTE_PTYPE_UNKNOWN`
+        let mut ptypes = vec![PacketType::default(); self.supported_ptypes(mask)?.len() + 1];
+
+        let ret = unsafe {
+            ffi::rte_eth_dev_set_ptypes(self.0, mask.bits(), ptypes.as_mut_ptr() as *mut _, ptypes.len() as u32)
+        };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(ptypes.into_iter().take_while(|ptype| !ptype.is_unknown()).collect())
+        }
+    }
+
+    fn timesync_enable(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_timesync_enable(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn timesync_disable(&self) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_timesync_disable(self.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn read_rx_timestamp(&self, flags: u32) -> Result<Duration> {
+        let mut ts = ffi::timespec::default();
+
+        let ret = unsafe { ffi::rte_eth_timesync_read_rx_timestamp(self.0, &mut ts, flags) };
+
+        rte_check!(ret; ok => { timespec_to_duration(&ts) }; err => { OsError(-ret).into() })
+    }
+
+    fn read_tx_timestamp(&self) -> Result<Duration> {
+        let mut ts = ffi::timespec::default();
+
+        let ret = unsafe { ffi::rte_eth_timesync_read_tx_timestamp(self.0, &mut ts) };
+
+        rte_check!(ret; ok => { timespec_to_duration(&ts) }; err => { OsError(-ret).into() })
+    }
+
+    fn adjust_time(&self, delta: i64) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_timesync_adjust_time(self.0, delta) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn read_time(&self) -> Result<Duration> {
+        let mut ts = ffi::timespec::default();
+
+        let ret = unsafe { ffi::rte_eth_timesync_read_time(self.0, &mut ts) };
+
+        rte_check!(ret; ok => { timespec_to_duration(&ts) }; err => { OsError(-ret).into() })
+    }
+
+    fn write_time(&self, time: Duration) -> Result<&Self> {
+        let ts = ffi::timespec {
+            tv_sec: time.as_secs() as _,
+            tv_nsec: time.subsec_nanos() as _,
+        };
+
+        let ret = unsafe { ffi::rte_eth_timesync_write_time(self.0, &ts) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn rx_queue_info(&self, queue_id: QueueId) -> Result<RawRxQueueInfo> {
+        let mut info = RawRxQueueInfo::default();
+
+        let ret = unsafe { ffi::rte_eth_rx_queue_info_get(self.0, queue_id, &mut info) };
+
+        rte_check!(ret; ok => { info }; err => { OsError(-ret).into() })
+    }
+
+    fn tx_queue_info(&self, queue_id: QueueId) -> Result<RawTxQueueInfo> {
+        let mut info = RawTxQueueInfo::default();
+
+        let ret = unsafe { ffi::rte_eth_tx_queue_info_get(self.0, queue_id, &mut info) };
+
+        rte_check!(ret; ok => { info }; err => { OsError(-ret).into() })
+    }
+
+    fn rx_burst_mode(&self, queue_id: QueueId) -> Result<BurstMode> {
+        let mut mode = RawBurstMode::default();
+
+        let ret = unsafe { ffi::rte_eth_rx_burst_mode_get(self.0, queue_id, &mut mode) };
+
+        rte_check!(ret; ok => { mode.into() }; err => { OsError(-ret).into() })
+    }
+
+    fn tx_burst_mode(&self, queue_id: QueueId) -> Result<BurstMode> {
+        let mut mode = RawBurstMode::default();
+
+        let ret = unsafe { ffi::rte_eth_tx_burst_mode_get(self.0, queue_id, &mut mode) };
+
+        rte_check!(ret; ok => { mode.into() }; err => { OsError(-ret).into() })
+    }
+
+    fn adjust_nb_rx_tx_desc(&self, mut nb_rx_desc: u16, mut nb_tx_desc: u16) -> Result<(u16, u16)> {
+        let ret = unsafe { ffi::rte_eth_dev_adjust_nb_rx_tx_desc(self.0, &mut nb_rx_desc, &mut nb_tx_desc) };
+
+        rte_check!(ret; ok => { (nb_rx_desc, nb_tx_desc) }; err => { OsError(-ret).into() })
+    }
+
+    fn hairpin_capability(&self) -> Result<RawHairpinCap> {
+        let mut cap = RawHairpinCap::default();
+
+        let ret = unsafe { ffi::rte_eth_dev_hairpin_capability_get(self.0, &mut cap) };
+
+        rte_check!(ret; ok => { cap }; err => { OsError(-ret).into() })
+    }
+
+    fn rx_hairpin_queue_setup(&self, rx_queue_id: QueueId, nb_rx_desc: u16, conf: &HairpinConf) -> Result<&Self> {
+        let conf = conf.to_raw()?;
+
+        check_queue_released(*self, false, rx_queue_id)?;
+
+        let ret = unsafe { ffi::rte_eth_rx_hairpin_queue_setup(self.0, rx_queue_id, nb_rx_desc, &conf) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn tx_hairpin_queue_setup(&self, tx_queue_id: QueueId, nb_tx_desc: u16, conf: &HairpinConf) -> Result<&Self> {
+        let conf = conf.to_raw()?;
+
+        check_queue_released(*self, true, tx_queue_id)?;
+
+        let ret = unsafe { ffi::rte_eth_tx_hairpin_queue_setup(self.0, tx_queue_id, nb_tx_desc, &conf) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn hairpin_bind(&self, rx_port: PortId) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_hairpin_bind(self.0, rx_port.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn hairpin_unbind(&self, rx_port: PortId) -> Result<&Self> {
+        let ret = unsafe { ffi::rte_eth_hairpin_unbind(self.0, rx_port.0) };
+
+        rte_check!(ret; ok => { self }; err => { OsError(-ret).into() })
+    }
+
+    fn hairpin_peer_ports(&self, tx: bool) -> Result<Vec<PortId>> {
+        let mut ports = vec![PortId(0); ffi::RTE_MAX_ETHPORTS as usize];
+
+        let ret = unsafe {
+            ffi::rte_eth_hairpin_get_peer_ports(self.0, ports.as_mut_ptr() as *mut _, ports.len(), tx as u32)
+        };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            ports.truncate(ret as usize);
+
+            Ok(ports)
+        }
+    }
+
+    fn name(&self) -> Result<String> {
+        let mut name = [0; MAX_NAME_LEN];
+
+        let ret = unsafe { ffi::rte_eth_dev_get_name_by_port(self.0, name.as_mut_ptr()) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(unsafe { CStr::from_ptr(name.as_ptr()) }.to_string_lossy().into_owned())
+        }
+    }
+
+    fn owner(&self) -> Result<Option<(OwnerId, String)>> {
+        let mut owner = ffi::rte_eth_dev_owner::default();
+
+        let ret = unsafe { ffi::rte_eth_dev_owner_get(self.0, &mut owner) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else if owner.id == NO_OWNER {
+            Ok(None)
+        } else {
+            let name = unsafe { CStr::from_ptr(owner.name.as_ptr()) };
+
+            Ok(Some((owner.id, name.to_string_lossy().into_owned())))
+        }
+    }
+
+    fn is_removed(&self) -> bool {
+        unsafe { ffi::rte_eth_dev_is_removed(self.0) != 0 }
+    }
+
+    fn detach(&self) -> Result<()> {
+        let device = self
+            .info()
+            .dev()
+            .ok_or_else(|| InvalidPortId(format!("port {} has no device", self)))?;
+
+        self.stop().close()?;
+
+        device.remove()
+    }
+
+    #[cfg(feature = "dpdk-22_11")]
+    fn ip_reassembly_capability(&self) -> Result<IpReassemblyParams> {
+        let mut capa: ffi::rte_eth_ip_reassembly_params = unsafe { mem::zeroed() };
+
+        rte_check!(unsafe {
+            ffi::rte_eth_ip_reassembly_capability_get(self.0, &mut capa)
+        }; ok => { capa.into() })
+    }
+
+    #[cfg(feature = "dpdk-22_11")]
+    fn ip_reassembly_conf(&self) -> Result<IpReassemblyParams> {
+        let mut conf: ffi::rte_eth_ip_reassembly_params = unsafe { mem::zeroed() };
+
+        rte_check!(unsafe {
+            ffi::rte_eth_ip_reassembly_conf_get(self.0, &mut conf)
+        }; ok => { conf.into() })
+    }
+
+    #[cfg(feature = "dpdk-22_11")]
+    fn set_ip_reassembly_conf(&self, conf: &IpReassemblyParams) -> Result<&Self> {
+        let conf = ffi::rte_eth_ip_reassembly_params::from(conf);
+
+        rte_check!(unsafe {
+            ffi::rte_eth_ip_reassembly_conf_set(self.0, &conf)
+        }; ok => { self })
+    }
+}
+
+pub trait EthDeviceInfo {
+    /// Device Driver name.
+    fn driver_name(&self) -> &str;
+
+    fn dev(&self) -> Option<dev::Device>;
+
+    /// The Rx offloads supported by the device, including the per-queue ones.
+    fn rx_offload_capa(&self) -> RxOffload;
+
+    /// The Tx offloads supported by the device, including the per-queue ones.
+    fn tx_offload_capa(&self) -> TxOffload;
+
+    /// The Rx offloads which can be enabled per queue.
+    fn rx_queue_offload_capa(&self) -> RxOffload;
+
+    /// The Tx offloads which can be enabled per queue.
+    fn tx_queue_offload_capa(&self) -> TxOffload;
+
+    /// The RSS hash functions supported by the device.
+    fn rss_offloads(&self) -> RssHashFunc;
+
+    /// The link speeds supported by the device.
+    fn speed_capa(&self) -> LinkSpeed;
+
+    /// Maximum number of Rx queues.
+    fn max_rx_queues(&self) -> u16;
+
+    /// Maximum number of Tx queues.
+    fn max_tx_queues(&self) -> u16;
+
+    /// The limits of the Rx descriptors.
+    fn rx_desc_lim(&self) -> &RawDescLim;
+
+    /// The limits of the Tx descriptors.
+    fn tx_desc_lim(&self) -> &RawDescLim;
+
+    /// The size of the Rx rings preferred by the device, if any.
+    fn default_rx_ring_size(&self) -> Option<u16>;
+
+    /// The size of the Tx rings preferred by the device, if any.
+    fn default_tx_ring_size(&self) -> Option<u16>;
+
+    /// The burst size of the Rx queues preferred by the device, if any.
+    fn default_rx_burst_size(&self) -> Option<u16>;
+
+    /// The burst size of the Tx queues preferred by the device, if any.
+    fn default_tx_burst_size(&self) -> Option<u16>;
+
+    /// The default configuration of the Rx queues, which is used if no configuration is given.
+    fn default_rxconf(&self) -> &ffi::rte_eth_rxconf;
+
+    /// The default configuration of the Tx queues, which is used if no configuration is given.
+    fn default_txconf(&self) -> &ffi::rte_eth_txconf;
+}
+
+pub type RawDescLim = ffi::rte_eth_desc_lim;
+
+fn non_zero(n: u16) -> Option<u16> {
+    if n == 0 {
+        None
+    } else {
+        Some(n)
+    }
+}
+
+pub type RawEthDeviceInfo = ffi::rte_eth_dev_info;
+
+impl EthDeviceInfo for RawEthDeviceInfo {
+    #[inline]
+    fn driver_name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.driver_name).to_str().unwrap() }
+    }
+
+    #[inline]
+    fn dev(&self) -> Option<dev::Device> {
+        if self.device.is_null() {
+            None
+        } else {
+            Some(self.device.into())
+        }
+    }
+
+    #[inline]
+    fn rx_offload_capa(&self) -> RxOffload {
+        RxOffload::from_bits_truncate(self.rx_offload_capa)
+    }
+
+    #[inline]
+    fn tx_offload_capa(&self) -> TxOffload {
+        TxOffload::from_bits_truncate(self.tx_offload_capa)
+    }
+
+    #[inline]
+    fn rx_queue_offload_capa(&self) -> RxOffload {
+        RxOffload::from_bits_truncate(self.rx_queue_offload_capa)
+    }
+
+    #[inline]
+    fn tx_queue_offload_capa(&self) -> TxOffload {
+        TxOffload::from_bits_truncate(self.tx_queue_offload_capa)
+    }
+
+    #[inline]
+    fn rss_offloads(&self) -> RssHashFunc {
+        RssHashFunc::from_bits_truncate(self.flow_type_rss_offloads)
+    }
+
+    #[inline]
+    fn speed_capa(&self) -> LinkSpeed {
+        LinkSpeed::from_bits_truncate(self.speed_capa)
+    }
+
+    #[inline]
+    fn max_rx_queues(&self) -> u16 {
+        self.max_rx_queues
+    }
+
+    #[inline]
+    fn max_tx_queues(&self) -> u16 {
+        self.max_tx_queues
+    }
+
+    #[inline]
+    fn rx_desc_lim(&self) -> &RawDescLim {
+        &self.rx_desc_lim
+    }
+
+    #[inline]
+    fn tx_desc_lim(&self) -> &RawDescLim {
+        &self.tx_desc_lim
+    }
+
+    #[inline]
+    fn default_rx_ring_size(&self) -> Option<u16> {
+        non_zero(self.default_rxportconf.ring_size)
+    }
+
+    #[inline]
+    fn default_tx_ring_size(&self) -> Option<u16> {
+        non_zero(self.default_txportconf.ring_size)
+    }
+
+    #[inline]
+    fn default_rx_burst_size(&self) -> Option<u16> {
+        non_zero(self.default_rxportconf.burst_size)
+    }
+
+    #[inline]
+    fn default_tx_burst_size(&self) -> Option<u16> {
+        non_zero(self.default_txportconf.burst_size)
+    }
+
+    #[inline]
+    fn default_rxconf(&self) -> &ffi::rte_eth_rxconf {
+        &self.default_rxconf
+    }
+
+    #[inline]
+    fn default_txconf(&self) -> &ffi::rte_eth_txconf {
+        &self.default_txconf
+    }
+}
+
+pub trait EthDeviceStats {}
+
+pub type RawEthDeviceStats = ffi::rte_eth_stats;
+
+impl EthDeviceStats for RawEthDeviceStats {}
+
+bitflags! {
+    /// Definitions used for VMDQ pool rx mode setting
     pub struct EthVmdqRxMode : u16 {
         /// accept untagged packets.
         const ETH_VMDQ_ACCEPT_UNTAG     = 0x0001;
@@ -452,29 +2150,337 @@ bitflags! {
         /// multicast promiscuous.
         const ETH_VMDQ_ACCEPT_MULTICAST = 0x0010;
     }
-}
+}
+
+/// A set of values to identify what method is to be used to route packets to multiple queues.
+pub type EthRxMultiQueueMode = ffi::rte_eth_rx_mq_mode::Type;
+
+bitflags! {
+    /// Definitions used for VLAN Offload functionality
+    pub struct EthVlanOffloadMode: i32 {
+        /// VLAN Strip  On/Off
+        const ETH_VLAN_STRIP_OFFLOAD  = 0x0001;
+        /// VLAN Filter On/Off
+        const ETH_VLAN_FILTER_OFFLOAD = 0x0002;
+        /// VLAN Extend On/Off
+        const ETH_VLAN_EXTEND_OFFLOAD = 0x0004;
+        /// QINQ Strip On/Off
+        const ETH_QINQ_STRIP_OFFLOAD  = 0x0008;
+
+        /// VLAN Strip  setting mask
+        const ETH_VLAN_STRIP_MASK     = 0x0001;
+        /// VLAN Filter  setting mask
+        const ETH_VLAN_FILTER_MASK    = 0x0002;
+        /// VLAN Extend  setting mask
+        const ETH_VLAN_EXTEND_MASK    = 0x0004;
+        /// QINQ Strip  setting mask
+        const ETH_QINQ_STRIP_MASK     = 0x0008;
+        /// VLAN ID is in lower 12 bits
+        const ETH_VLAN_ID_MAX         = 0x0FFF;
+    }
+}
+
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+const VLAN_TYPE_INNER: ffi::rte_vlan_type::Type = ffi::rte_vlan_type::ETH_VLAN_TYPE_INNER;
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+const VLAN_TYPE_OUTER: ffi::rte_vlan_type::Type = ffi::rte_vlan_type::ETH_VLAN_TYPE_OUTER;
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+const VLAN_TYPE_INNER: ffi::rte_vlan_type::Type = ffi::rte_vlan_type::RTE_ETH_VLAN_TYPE_INNER;
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+const VLAN_TYPE_OUTER: ffi::rte_vlan_type::Type = ffi::rte_vlan_type::RTE_ETH_VLAN_TYPE_OUTER;
+
+/// The VLAN types of the Tag Protocol ID, the single VLAN is treated the same as the inner VLAN.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VlanType {
+    /// The inner VLAN, or the single VLAN.
+    Inner = VLAN_TYPE_INNER,
+    /// The outer VLAN of the QinQ packets.
+    Outer = VLAN_TYPE_OUTER,
+}
+
+/// The number of the RSS redirection table entries in a group.
+pub const RETA_GROUP_SIZE: usize = 64;
+
+/// The RSS redirection table, which maps the RSS hash of the packets to the receive queues.
+///
+/// The packet is received by the queue of the entry indexed by the lower bits of its RSS hash.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RssReta {
+    entries: Vec<QueueId>,
+}
+
+impl ops::Deref for RssReta {
+    type Target = [QueueId];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl ops::DerefMut for RssReta {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entries
+    }
+}
+
+impl RssReta {
+    /// Create a redirection table with the size, e.g. `dev_info.reta_size`, pointing all entries to the queue 0.
+    pub fn new(size: usize) -> Self {
+        RssReta { entries: vec![0; size] }
+    }
+
+    /// Create a redirection table spreading the entries over the queues in the round robin order.
+    pub fn round_robin(size: usize, nb_queues: QueueId) -> Self {
+        let mut reta = Self::new(size);
+
+        reta.spread(&(0..nb_queues).collect::<Vec<_>>());
+        reta
+    }
+
+    /// Spread the entries over the queues in the round robin order.
+    pub fn spread(&mut self, queues: &[QueueId]) {
+        for (queue_id, &q) in self.entries.iter_mut().zip(queues.iter().cycle()) {
+            *queue_id = q;
+        }
+    }
+
+    fn to_raw(&self) -> Vec<ffi::rte_eth_rss_reta_entry64> {
+        self.entries
+            .chunks(RETA_GROUP_SIZE)
+            .map(|chunk| {
+                let mut group = ffi::rte_eth_rss_reta_entry64 {
+                    mask: 0,
+                    reta: [0; RETA_GROUP_SIZE],
+                };
+
+                group.mask = if chunk.len() == RETA_GROUP_SIZE {
+                    u64::max_value()
+                } else {
+                    (1 << chunk.len()) - 1
+                };
+                group.reta[..chunk.len()].copy_from_slice(chunk);
+                group
+            })
+            .collect()
+    }
+}
+
+/// The maximum number of VLAN IDs of a mirroring rule.
+pub const ETH_MIRROR_MAX_VLANS: usize = 64;
+
+bitflags! {
+    /// Definitions used for the traffic mirroring rule types
+    pub struct MirrorType: u8 {
+        /// Virtual Pool uplink Mirroring.
+        const ETH_MIRROR_VIRTUAL_POOL_UP   = 0x01;
+        /// Uplink Port Mirroring.
+        const ETH_MIRROR_UPLINK_PORT       = 0x02;
+        /// Downlink Port Mirroring.
+        const ETH_MIRROR_DOWNLINK_PORT     = 0x04;
+        /// VLAN Mirroring.
+        const ETH_MIRROR_VLAN              = 0x08;
+        /// Virtual Pool downlink Mirroring.
+        const ETH_MIRROR_VIRTUAL_POOL_DOWN = 0x10;
+    }
+}
+
+impl Default for MirrorType {
+    fn default() -> Self {
+        MirrorType::empty()
+    }
+}
+
+/// A traffic mirroring rule, which mirrors the matched traffic to a destination pool, e.g. a monitoring VF.
+#[derive(Clone, Debug, Default)]
+pub struct MirrorConf {
+    /// The types of traffic to mirror.
+    pub rule_type: MirrorType,
+    /// The destination pool of the mirrored traffic.
+    pub dst_pool: u8,
+    /// The bitmap of the pools to mirror with `ETH_MIRROR_VIRTUAL_POOL_UP` or `ETH_MIRROR_VIRTUAL_POOL_DOWN`.
+    pub pool_mask: u64,
+    /// The VLAN IDs to mirror with `ETH_MIRROR_VLAN`, at most `ETH_MIRROR_MAX_VLANS`.
+    pub vlan_ids: Vec<u16>,
+}
+
+impl MirrorConf {
+    /// Mirror the traffic of the VLANs to the destination pool.
+    pub fn vlan(dst_pool: u8, vlan_ids: &[u16]) -> Self {
+        MirrorConf {
+            rule_type: MirrorType::ETH_MIRROR_VLAN,
+            dst_pool,
+            vlan_ids: vlan_ids.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// Mirror the uplink traffic of the pools to the destination pool.
+    pub fn pool(dst_pool: u8, pool_mask: u64) -> Self {
+        MirrorConf {
+            rule_type: MirrorType::ETH_MIRROR_VIRTUAL_POOL_UP,
+            dst_pool,
+            pool_mask,
+            ..Default::default()
+        }
+    }
+
+    /// Mirror the traffic received by the port to the destination pool.
+    pub fn uplink(dst_pool: u8) -> Self {
+        MirrorConf {
+            rule_type: MirrorType::ETH_MIRROR_UPLINK_PORT,
+            dst_pool,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn to_raw(&self) -> Result<ffi::rte_eth_mirror_conf> {
+        if self.vlan_ids.len() > ETH_MIRROR_MAX_VLANS {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        let mut conf: ffi::rte_eth_mirror_conf = unsafe { mem::zeroed() };
+
+        conf.rule_type = self.rule_type.bits;
+        conf.dst_pool = self.dst_pool;
+        conf.pool_mask = self.pool_mask;
+
+        for (i, &vlan_id) in self.vlan_ids.iter().enumerate() {
+            conf.vlan.vlan_mask |= 1 << i;
+            conf.vlan.vlan_id[i] = vlan_id;
+        }
+
+        Ok(conf)
+    }
+}
+
+#[cfg(feature = "dpdk-22_11")]
+bitflags! {
+    /// Definitions used for the IP reassembly offload
+    pub struct IpReassemblyFlags: u16 {
+        /// Reassemble the IPv4 fragments.
+        const RTE_ETH_DEV_REASSEMBLY_F_IPV4 = 0x0001;
+        /// Reassemble the IPv6 fragments.
+        const RTE_ETH_DEV_REASSEMBLY_F_IPV6 = 0x0002;
+    }
+}
+
+/// The parameters of the IP reassembly offload, used as both the capabilities and the configuration.
+#[cfg(feature = "dpdk-22_11")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IpReassemblyParams {
+    /// The maximum time in milliseconds to wait for all the fragments of a datagram.
+    pub timeout_ms: u32,
+    /// The maximum number of fragments of a datagram.
+    pub max_frags: u16,
+    /// The IP versions to reassemble.
+    pub flags: IpReassemblyFlags,
+}
+
+#[cfg(feature = "dpdk-22_11")]
+impl From<ffi::rte_eth_ip_reassembly_params> for IpReassemblyParams {
+    fn from(p: ffi::rte_eth_ip_reassembly_params) -> Self {
+        IpReassemblyParams {
+            timeout_ms: p.timeout_ms,
+            max_frags: p.max_frags,
+            flags: IpReassemblyFlags::from_bits_truncate(p.flags),
+        }
+    }
+}
+
+#[cfg(feature = "dpdk-22_11")]
+impl<'a> From<&'a IpReassemblyParams> for ffi::rte_eth_ip_reassembly_params {
+    fn from(p: &IpReassemblyParams) -> Self {
+        ffi::rte_eth_ip_reassembly_params {
+            timeout_ms: p.timeout_ms,
+            max_frags: p.max_frags,
+            flags: p.flags.bits,
+        }
+    }
+}
+
+/// The dynamic field and flag of the mbufs, which the IP reassembly offload failed to reassemble.
+///
+/// The incomplete datagram is delivered as the chain of its fragments,
+/// which should be reassembled by the software, e.g. `ip_frag`, as a fallback.
+#[cfg(feature = "dpdk-22_11")]
+#[derive(Clone, Copy, Debug)]
+pub struct IpReassemblyDynfield {
+    offset: usize,
+    flag: u64,
+}
+
+#[cfg(feature = "dpdk-22_11")]
+impl IpReassemblyDynfield {
+    /// Register the dynamic field and flag of the incomplete reassembly, or lookup them if they were registered.
+    ///
+    /// It must be called before the IP reassembly is configured.
+    pub fn register() -> Result<Self> {
+        let mut offset = 0;
+        let mut flag = 0;
+
+        rte_check!(unsafe {
+            ffi::rte_eth_ip_reassembly_dynfield_register(&mut offset, &mut flag)
+        }; ok => {
+            IpReassemblyDynfield {
+                offset: offset as usize,
+                flag: 1 << flag,
+            }
+        })
+    }
+
+    /// The offset of the dynamic field in the mbuf.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The mask of the dynamic flag in the offload flags of the mbuf.
+    pub fn flag(&self) -> u64 {
+        self.flag
+    }
+
+    #[inline]
+    fn field(&self, m: mbuf::RawMBufPtr) -> *mut ffi::rte_eth_ip_reassembly_dynfield_t {
+        unsafe { (m as *mut u8).add(self.offset) as *mut _ }
+    }
+
+    /// Test if the mbuf is the first fragment of an incomplete reassembly.
+    #[inline]
+    pub fn is_incomplete(&self, m: &mbuf::MBuf) -> bool {
+        m.ol_flags & self.flag != 0
+    }
+
+    /// The number of fragments and the time spent in milliseconds of an incomplete reassembly.
+    pub fn incomplete(&self, m: &mbuf::MBuf) -> Option<(u16, u16)> {
+        if self.is_incomplete(m) {
+            let field = unsafe { &*self.field(m.as_raw_mut()) };
+
+            Some((field.nb_frags, field.time_spent))
+        } else {
+            None
+        }
+    }
 
-/// A set of values to identify what method is to be used to route packets to multiple queues.
-pub type EthRxMultiQueueMode = ffi::rte_eth_rx_mq_mode::Type;
+    /// The fragments of an incomplete reassembly, starting from the first one.
+    ///
+    /// The fragments are owned by the caller and must be freed after being reassembled or dropped.
+    pub fn fragments(&self, m: &mbuf::MBuf) -> Vec<mbuf::RawMBufPtr> {
+        let mut frags = vec![m.as_raw_mut()];
 
-bitflags! {
-    /// Definitions used for VLAN Offload functionality
-    pub struct EthVlanOffloadMode: i32 {
-        /// VLAN Strip  On/Off
-        const ETH_VLAN_STRIP_OFFLOAD  = 0x0001;
-        /// VLAN Filter On/Off
-        const ETH_VLAN_FILTER_OFFLOAD = 0x0002;
-        /// VLAN Extend On/Off
-        const ETH_VLAN_EXTEND_OFFLOAD = 0x0004;
+        if let Some((nb_frags, _)) = self.incomplete(m) {
+            for _ in 1..nb_frags {
+                let next = unsafe { (*self.field(*frags.last().unwrap())).next_frag };
 
-        /// VLAN Strip  setting mask
-        const ETH_VLAN_STRIP_MASK     = 0x0001;
-        /// VLAN Filter  setting mask
-        const ETH_VLAN_FILTER_MASK    = 0x0002;
-        /// VLAN Extend  setting mask
-        const ETH_VLAN_EXTEND_MASK    = 0x0004;
-        /// VLAN ID is in lower 12 bits
-        const ETH_VLAN_ID_MAX         = 0x0FFF;
+                if next.is_null() {
+                    break;
+                }
+
+                frags.push(next);
+            }
+        }
+
+        frags
     }
 }
 
@@ -703,7 +2709,10 @@ pub struct EthRssConf {
 
 impl Default for EthRssConf {
     fn default() -> Self {
-        unsafe { mem::zeroed() }
+        EthRssConf {
+            key: None,
+            hash: RssHashFunc::empty(),
+        }
     }
 }
 
@@ -716,7 +2725,13 @@ pub struct RxAdvConf {
     pub vmdq_rx_conf: Option<ffi::rte_eth_vmdq_rx_conf>,
 }
 
-pub enum TxAdvConf {}
+/// Port TX DCB configuration.
+#[derive(Clone, Copy)]
+pub enum TxAdvConf {
+    VmdqDcb(ffi::rte_eth_vmdq_dcb_tx_conf),
+    Dcb(ffi::rte_eth_dcb_tx_conf),
+    Vmdq(ffi::rte_eth_vmdq_tx_conf),
+}
 
 /// Device supported speeds bitmap flags
 bitflags! {
@@ -752,6 +2767,91 @@ impl Default for LinkSpeed {
     }
 }
 
+bitflags! {
+    /// The Rx offload capabilities of a device or queue.
+    pub struct RxOffload: u64 {
+        const DEV_RX_OFFLOAD_VLAN_STRIP       = 1 <<  0;
+        const DEV_RX_OFFLOAD_IPV4_CKSUM       = 1 <<  1;
+        const DEV_RX_OFFLOAD_UDP_CKSUM        = 1 <<  2;
+        const DEV_RX_OFFLOAD_TCP_CKSUM        = 1 <<  3;
+        const DEV_RX_OFFLOAD_TCP_LRO          = 1 <<  4;
+        const DEV_RX_OFFLOAD_QINQ_STRIP       = 1 <<  5;
+        const DEV_RX_OFFLOAD_OUTER_IPV4_CKSUM = 1 <<  6;
+        const DEV_RX_OFFLOAD_MACSEC_STRIP     = 1 <<  7;
+        const DEV_RX_OFFLOAD_HEADER_SPLIT     = 1 <<  8;
+        const DEV_RX_OFFLOAD_VLAN_FILTER      = 1 <<  9;
+        const DEV_RX_OFFLOAD_VLAN_EXTEND      = 1 << 10;
+        /// Removed since DPDK 21.11, the jumbo frames are enabled by the MTU.
+        const DEV_RX_OFFLOAD_JUMBO_FRAME      = 1 << 11;
+        const DEV_RX_OFFLOAD_SCATTER          = 1 << 13;
+        const DEV_RX_OFFLOAD_TIMESTAMP        = 1 << 14;
+        const DEV_RX_OFFLOAD_SECURITY         = 1 << 15;
+        const DEV_RX_OFFLOAD_KEEP_CRC         = 1 << 16;
+        const DEV_RX_OFFLOAD_SCTP_CKSUM       = 1 << 17;
+        const DEV_RX_OFFLOAD_OUTER_UDP_CKSUM  = 1 << 18;
+        const DEV_RX_OFFLOAD_RSS_HASH         = 1 << 19;
+        const DEV_RX_OFFLOAD_BUFFER_SPLIT     = 1 << 20;
+
+        const DEV_RX_OFFLOAD_CHECKSUM =
+            Self::DEV_RX_OFFLOAD_IPV4_CKSUM.bits |
+            Self::DEV_RX_OFFLOAD_UDP_CKSUM.bits |
+            Self::DEV_RX_OFFLOAD_TCP_CKSUM.bits;
+
+        const DEV_RX_OFFLOAD_VLAN =
+            Self::DEV_RX_OFFLOAD_VLAN_STRIP.bits |
+            Self::DEV_RX_OFFLOAD_VLAN_FILTER.bits |
+            Self::DEV_RX_OFFLOAD_VLAN_EXTEND.bits |
+            Self::DEV_RX_OFFLOAD_QINQ_STRIP.bits;
+    }
+}
+
+bitflags! {
+    /// The Tx offload capabilities of a device or queue.
+    pub struct TxOffload: u64 {
+        const DEV_TX_OFFLOAD_VLAN_INSERT       = 1 <<  0;
+        const DEV_TX_OFFLOAD_IPV4_CKSUM        = 1 <<  1;
+        const DEV_TX_OFFLOAD_UDP_CKSUM         = 1 <<  2;
+        const DEV_TX_OFFLOAD_TCP_CKSUM         = 1 <<  3;
+        const DEV_TX_OFFLOAD_SCTP_CKSUM        = 1 <<  4;
+        const DEV_TX_OFFLOAD_TCP_TSO           = 1 <<  5;
+        const DEV_TX_OFFLOAD_UDP_TSO           = 1 <<  6;
+        const DEV_TX_OFFLOAD_OUTER_IPV4_CKSUM  = 1 <<  7;
+        const DEV_TX_OFFLOAD_QINQ_INSERT       = 1 <<  8;
+        const DEV_TX_OFFLOAD_VXLAN_TNL_TSO     = 1 <<  9;
+        const DEV_TX_OFFLOAD_GRE_TNL_TSO       = 1 << 10;
+        const DEV_TX_OFFLOAD_IPIP_TNL_TSO      = 1 << 11;
+        const DEV_TX_OFFLOAD_GENEVE_TNL_TSO    = 1 << 12;
+        const DEV_TX_OFFLOAD_MACSEC_INSERT     = 1 << 13;
+        /// Multiple threads can invoke `rte_eth_tx_burst()` concurrently on the same queue.
+        const DEV_TX_OFFLOAD_MT_LOCKFREE       = 1 << 14;
+        const DEV_TX_OFFLOAD_MULTI_SEGS        = 1 << 15;
+        /// The mbufs are from the same mempool, with the refcnt of 1 and direct.
+        const DEV_TX_OFFLOAD_MBUF_FAST_FREE    = 1 << 16;
+        const DEV_TX_OFFLOAD_SECURITY          = 1 << 17;
+        const DEV_TX_OFFLOAD_UDP_TNL_TSO       = 1 << 18;
+        const DEV_TX_OFFLOAD_IP_TNL_TSO        = 1 << 19;
+        const DEV_TX_OFFLOAD_OUTER_UDP_CKSUM   = 1 << 20;
+        const DEV_TX_OFFLOAD_SEND_ON_TIMESTAMP = 1 << 21;
+
+        const DEV_TX_OFFLOAD_CHECKSUM =
+            Self::DEV_TX_OFFLOAD_IPV4_CKSUM.bits |
+            Self::DEV_TX_OFFLOAD_UDP_CKSUM.bits |
+            Self::DEV_TX_OFFLOAD_TCP_CKSUM.bits;
+    }
+}
+
+impl Default for RxOffload {
+    fn default() -> Self {
+        RxOffload::empty()
+    }
+}
+
+impl Default for TxOffload {
+    fn default() -> Self {
+        TxOffload::empty()
+    }
+}
+
 pub type EthRxMode = ffi::rte_eth_rxmode;
 pub type EthTxMode = ffi::rte_eth_txmode;
 
@@ -799,6 +2899,10 @@ impl<'a> From<&'a EthConf> for RawEthConf {
     fn from(c: &EthConf) -> Self {
         let mut conf: ffi::rte_eth_conf = Default::default();
 
+        conf.link_speeds = c.link_speeds.bits;
+        conf.lpbk_mode = c.lpbk_mode;
+        conf.dcb_capability_en = c.dcb_capability_en;
+
         if let Some(ref rxmode) = c.rxmode {
             conf.rxmode = *rxmode
         }
@@ -809,20 +2913,295 @@ impl<'a> From<&'a EthConf> for RawEthConf {
 
         if let Some(ref adv_conf) = c.rx_adv_conf {
             if let Some(ref rss_conf) = adv_conf.rss_conf {
+                // the key is borrowed from `EthConf`, which outlives the raw configuration.
                 let (rss_key, rss_key_len) = rss_conf
                     .key
+                    .as_ref()
                     .map_or_else(|| (ptr::null(), 0), |key| (key.as_ptr(), key.len() as u8));
 
                 conf.rx_adv_conf.rss_conf.rss_key = rss_key as *mut _;
                 conf.rx_adv_conf.rss_conf.rss_key_len = rss_key_len;
                 conf.rx_adv_conf.rss_conf.rss_hf = rss_conf.hash.bits;
             }
+
+            if let Some(vmdq_dcb_conf) = adv_conf.vmdq_dcb_conf {
+                conf.rx_adv_conf.vmdq_dcb_conf = vmdq_dcb_conf;
+            }
+
+            if let Some(dcb_rx_conf) = adv_conf.dcb_rx_conf {
+                conf.rx_adv_conf.dcb_rx_conf = dcb_rx_conf;
+            }
+
+            if let Some(vmdq_rx_conf) = adv_conf.vmdq_rx_conf {
+                conf.rx_adv_conf.vmdq_rx_conf = vmdq_rx_conf;
+            }
+        }
+
+        match c.tx_adv_conf {
+            Some(TxAdvConf::VmdqDcb(vmdq_dcb_tx_conf)) => conf.tx_adv_conf.vmdq_dcb_tx_conf = vmdq_dcb_tx_conf,
+            Some(TxAdvConf::Dcb(dcb_tx_conf)) => conf.tx_adv_conf.dcb_tx_conf = dcb_tx_conf,
+            Some(TxAdvConf::Vmdq(vmdq_tx_conf)) => conf.tx_adv_conf.vmdq_tx_conf = vmdq_tx_conf,
+            None => {}
+        }
+
+        if let Some(fdir_conf) = c.fdir_conf {
+            conf.fdir_conf = fdir_conf;
+        }
+
+        if let Some(intr_conf) = c.intr_conf {
+            conf.intr_conf = intr_conf;
         }
 
         RawEthConf(conf)
     }
 }
 
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+const ETH_MQ_RX_RSS: EthRxMultiQueueMode = ffi::rte_eth_rx_mq_mode::ETH_MQ_RX_RSS;
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+const ETH_MQ_RX_RSS: EthRxMultiQueueMode = ffi::rte_eth_rx_mq_mode::RTE_ETH_MQ_RX_RSS;
+
+impl EthConf {
+    pub fn builder() -> EthConfBuilder {
+        EthConfBuilder::default()
+    }
+}
+
+/// The builder of the port configuration.
+///
+/// ```no_run
+/// use rte::ethdev::{self, EthConf, RssHashFunc};
+///
+/// let port_id = ethdev::PortId(0);
+/// let conf = EthConf::builder()
+///     .rss(RssHashFunc::ETH_RSS_IP)
+///     .jumbo(9000)
+///     .hw_checksum(true)
+///     .build_for(&port_id)
+///     .expect("unsupported port configuration");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EthConfBuilder {
+    link_speeds: LinkSpeed,
+    rss_hash: Option<RssHashFunc>,
+    rss_key: Option<[u8; 40]>,
+    max_rx_pkt_len: Option<u32>,
+    rx_offloads: RxOffload,
+    tx_offloads: TxOffload,
+    lpbk_mode: u32,
+    lsc_intr: bool,
+    rxq_intr: bool,
+    rmv_intr: bool,
+}
+
+impl EthConfBuilder {
+    /// The speeds to be advertised, or a fixed speed with `ETH_LINK_SPEED_FIXED`.
+    pub fn link_speeds(mut self, speeds: LinkSpeed) -> Self {
+        self.link_speeds = speeds;
+        self
+    }
+
+    /// Distribute the received packets to the queues with RSS, hashed on the protocol fields.
+    pub fn rss(mut self, hash: RssHashFunc) -> Self {
+        self.rss_hash = Some(hash);
+        self
+    }
+
+    /// The RSS hash key, or the default key of the driver is used.
+    pub fn rss_key(mut self, key: [u8; 40]) -> Self {
+        self.rss_key = Some(key);
+        self
+    }
+
+    /// Receive the jumbo frames up to the length, including the Ethernet header and CRC.
+    pub fn jumbo(mut self, max_rx_pkt_len: u32) -> Self {
+        self.max_rx_pkt_len = Some(max_rx_pkt_len);
+        #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+        {
+            self.rx_offloads |= RxOffload::DEV_RX_OFFLOAD_JUMBO_FRAME;
+        }
+        self
+    }
+
+    /// Offload the IPv4, UDP and TCP checksum of the received and transmitted packets to the hardware.
+    pub fn hw_checksum(mut self, enable: bool) -> Self {
+        self.rx_offloads.set(RxOffload::DEV_RX_OFFLOAD_CHECKSUM, enable);
+        self.tx_offloads.set(TxOffload::DEV_TX_OFFLOAD_CHECKSUM, enable);
+        self
+    }
+
+    /// Strip the VLAN tag of the received packets.
+    pub fn vlan_strip(mut self, enable: bool) -> Self {
+        self.rx_offloads.set(RxOffload::DEV_RX_OFFLOAD_VLAN_STRIP, enable);
+        self
+    }
+
+    /// Receive the packets spanning the multiple mbufs.
+    pub fn scatter(mut self, enable: bool) -> Self {
+        self.rx_offloads.set(RxOffload::DEV_RX_OFFLOAD_SCATTER, enable);
+        self
+    }
+
+    /// Transmit the packets spanning the multiple mbufs.
+    pub fn multi_segs(mut self, enable: bool) -> Self {
+        self.tx_offloads.set(TxOffload::DEV_TX_OFFLOAD_MULTI_SEGS, enable);
+        self
+    }
+
+    /// Enable the Rx offloads.
+    pub fn rx_offloads(mut self, offloads: RxOffload) -> Self {
+        self.rx_offloads |= offloads;
+        self
+    }
+
+    /// Enable the Tx offloads.
+    pub fn tx_offloads(mut self, offloads: TxOffload) -> Self {
+        self.tx_offloads |= offloads;
+        self
+    }
+
+    /// The loopback operation mode defined by the driver, 0 disables the loopback.
+    pub fn loopback(mut self, mode: u32) -> Self {
+        self.lpbk_mode = mode;
+        self
+    }
+
+    /// Enable the link status change interrupt.
+    pub fn lsc_interrupt(mut self, enable: bool) -> Self {
+        self.lsc_intr = enable;
+        self
+    }
+
+    /// Enable the Rx queue interrupts.
+    pub fn rxq_interrupt(mut self, enable: bool) -> Self {
+        self.rxq_intr = enable;
+        self
+    }
+
+    /// Enable the device removal interrupt.
+    pub fn rmv_interrupt(mut self, enable: bool) -> Self {
+        self.rmv_intr = enable;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.rss_key.is_some() && self.rss_hash.is_none() {
+            return Err(InvalidEthConf("RSS key without RSS".to_owned()).into());
+        }
+
+        if let Some(len) = self.max_rx_pkt_len {
+            if len <= ffi::RTE_ETHER_MAX_LEN || len > ffi::RTE_ETHER_MAX_JUMBO_FRAME_LEN {
+                return Err(InvalidEthConf(format!(
+                    "jumbo frame length {} not in ({}, {}]",
+                    len,
+                    ffi::RTE_ETHER_MAX_LEN,
+                    ffi::RTE_ETHER_MAX_JUMBO_FRAME_LEN
+                ))
+                .into());
+            }
+        }
+
+        if self.link_speeds.contains(LinkSpeed::ETH_LINK_SPEED_FIXED)
+            && (self.link_speeds - LinkSpeed::ETH_LINK_SPEED_FIXED).bits.count_ones() != 1
+        {
+            return Err(InvalidEthConf(format!("fixed link speed {:?} must be unique", self.link_speeds)).into());
+        }
+
+        Ok(())
+    }
+
+    /// Build the port configuration.
+    pub fn build(&self) -> Result<EthConf> {
+        self.validate()?;
+
+        let mut rxmode = EthRxMode::default();
+        let mut txmode = EthTxMode::default();
+
+        if self.rss_hash.is_some() {
+            rxmode.mq_mode = ETH_MQ_RX_RSS;
+        }
+
+        if let Some(len) = self.max_rx_pkt_len {
+            #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+            {
+                rxmode.max_rx_pkt_len = len;
+            }
+            #[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+            {
+                rxmode.mtu = len - ffi::RTE_ETHER_HDR_LEN - ffi::RTE_ETHER_CRC_LEN;
+            }
+        }
+
+        rxmode.offloads = self.rx_offloads.bits;
+        txmode.offloads = self.tx_offloads.bits;
+
+        let mut intr_conf = ffi::rte_intr_conf::default();
+
+        intr_conf.set_lsc(self.lsc_intr as u32);
+        intr_conf.set_rxq(self.rxq_intr as u32);
+        intr_conf.set_rmv(self.rmv_intr as u32);
+
+        Ok(EthConf {
+            link_speeds: self.link_speeds,
+            rxmode: Some(rxmode),
+            txmode: Some(txmode),
+            lpbk_mode: self.lpbk_mode,
+            rx_adv_conf: self.rss_hash.map(|hash| RxAdvConf {
+                rss_conf: Some(EthRssConf {
+                    key: self.rss_key,
+                    hash,
+                }),
+                ..RxAdvConf::default()
+            }),
+            intr_conf: Some(intr_conf),
+            ..EthConf::default()
+        })
+    }
+
+    /// Build the port configuration, which is validated against the capabilities of the device.
+    pub fn build_for<T: EthDevice>(&self, dev: &T) -> Result<EthConf> {
+        let info = dev.info();
+
+        self.check_capabilities(&info)?;
+        self.build()
+    }
+
+    fn check_capabilities(&self, info: &RawEthDeviceInfo) -> Result<()> {
+        let rx_capa = info.rx_offload_capa();
+        let tx_capa = info.tx_offload_capa();
+
+        if !rx_capa.contains(self.rx_offloads) {
+            return Err(InvalidEthConf(format!("unsupported Rx offloads {:?}", self.rx_offloads - rx_capa)).into());
+        }
+
+        if !tx_capa.contains(self.tx_offloads) {
+            return Err(InvalidEthConf(format!("unsupported Tx offloads {:?}", self.tx_offloads - tx_capa)).into());
+        }
+
+        if let Some(hash) = self.rss_hash {
+            let rss_capa = info.rss_offloads();
+
+            if !rss_capa.contains(hash) {
+                return Err(InvalidEthConf(format!("unsupported RSS hash functions {:?}", hash - rss_capa)).into());
+            }
+
+            if self.rss_key.is_some() && info.hash_key_size != 0 && info.hash_key_size != 40 {
+                return Err(InvalidEthConf(format!("RSS key size must be {}", info.hash_key_size)).into());
+            }
+        }
+
+        if let Some(len) = self.max_rx_pkt_len {
+            if len > info.max_rx_pktlen {
+                return Err(
+                    InvalidEthConf(format!("jumbo frame length {} exceeds {}", len, info.max_rx_pktlen)).into(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Calculate the size of the tx buffer.
 pub fn rte_eth_tx_buffer_size(size: usize) -> usize {
     mem::size_of::<ffi::rte_eth_dev_tx_buffer>() + mem::size_of::<*mut ffi::rte_mbuf>() * size
@@ -831,12 +3210,14 @@ pub fn rte_eth_tx_buffer_size(size: usize) -> usize {
 pub type RawTxBuffer = ffi::rte_eth_dev_tx_buffer;
 pub type RawTxBufferPtr = *mut ffi::rte_eth_dev_tx_buffer;
 
-pub type TxBufferErrorCallback<T> = fn(unsent: *mut *mut ffi::rte_mbuf, count: u16, userdata: &T);
+pub type TxBufferErrorCallback<T> = fn(unsent: *mut *mut ffi::rte_mbuf, count: u16, userdata: Option<&T>);
 
 pub trait TxBuffer {
     fn free(&mut self);
 
     /// Configure a callback for buffered packets which cannot be sent
+    ///
+    /// The user data is referenced until the callback is replaced or the buffer is freed.
     fn set_err_callback<T>(
         &mut self,
         callback: Option<TxBufferErrorCallback<T>>,
@@ -860,8 +3241,28 @@ pub fn alloc_buffer(size: usize, socket_id: i32) -> Result<RawTxBufferPtr> {
     }
 }
 
+type TxBufferErrorFn = Box<dyn FnMut(*mut *mut ffi::rte_mbuf, u16)>;
+
+unsafe extern "C" fn tx_buffer_error_stub(unsent: *mut *mut ffi::rte_mbuf, count: u16, userdata: *mut c_void) {
+    let callback = &mut *(userdata as *mut TxBufferErrorFn);
+
+    catch_panic("TX buffer error callback", (), || callback(unsent, count))
+}
+
+// Release the closure of the error callback, before the callback is replaced or the buffer is freed.
+unsafe fn release_err_callback(buf: &mut RawTxBuffer) {
+    if buf.error_callback.map(|f| f as usize) == Some(tx_buffer_error_stub as usize) {
+        drop(Box::from_raw(buf.error_userdata as *mut TxBufferErrorFn));
+
+        buf.error_callback = None;
+        buf.error_userdata = ptr::null_mut();
+    }
+}
+
 impl TxBuffer for RawTxBuffer {
     fn free(&mut self) {
+        unsafe { release_err_callback(self) };
+
         malloc::free(self as RawTxBufferPtr as *mut c_void);
     }
 
@@ -870,14 +3271,31 @@ impl TxBuffer for RawTxBuffer {
         callback: Option<TxBufferErrorCallback<T>>,
         userdata: Option<&T>,
     ) -> Result<&mut Self> {
-        rte_check!(unsafe {
-            ffi::rte_eth_tx_buffer_set_err_callback(self,
-                                                    mem::transmute(callback),
-                                                    mem::transmute(userdata))
-        }; ok => { self })
+        unsafe { release_err_callback(self) };
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return self.drop_err_packets(),
+        };
+        let userdata = userdata.map(|p| p as *const T);
+        let f = Box::new(move |unsent, count| callback(unsent, count, userdata.map(|p| unsafe { &*p })));
+        let ctxt = Box::into_raw(Box::new(f as Box<dyn FnMut(*mut *mut ffi::rte_mbuf, u16) + '_>));
+
+        let ret =
+            unsafe { ffi::rte_eth_tx_buffer_set_err_callback(self, Some(tx_buffer_error_stub), ctxt as *mut c_void) };
+
+        if ret == 0 {
+            Ok(self)
+        } else {
+            unsafe { drop(Box::from_raw(ctxt)) };
+
+            Err(RteError(ret).into())
+        }
     }
 
     fn drop_err_packets(&mut self) -> Result<&mut Self> {
+        unsafe { release_err_callback(self) };
+
         rte_check!(unsafe {
             ffi::rte_eth_tx_buffer_set_err_callback(self,
                                                     Some(ffi::rte_eth_tx_buffer_drop_callback),
@@ -886,6 +3304,8 @@ impl TxBuffer for RawTxBuffer {
     }
 
     fn count_err_packets(&mut self) -> Result<&mut Self> {
+        unsafe { release_err_callback(self) };
+
         rte_check!(unsafe {
             ffi::rte_eth_tx_buffer_set_err_callback(self,
                                                     Some(ffi::rte_eth_tx_buffer_count_callback),
@@ -893,3 +3313,327 @@ impl TxBuffer for RawTxBuffer {
         }; ok => { self })
     }
 }
+
+/// The packet counters of a port in a forwarding loop.
+///
+/// Each counter should be updated by only one lcore, and can be read by any lcore.
+#[repr(align(64))]
+#[derive(Debug, Default)]
+pub struct PortCounters {
+    rx: AtomicU64,
+    tx: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// The snapshot of the packet counters of a port.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PortStats {
+    pub rx: u64,
+    pub tx: u64,
+    pub dropped: u64,
+}
+
+impl ops::AddAssign for PortStats {
+    fn add_assign(&mut self, other: PortStats) {
+        self.rx += other.rx;
+        self.tx += other.tx;
+        self.dropped += other.dropped;
+    }
+}
+
+impl PortCounters {
+    /// Count the received packets.
+    #[inline]
+    pub fn add_rx(&self, n: usize) {
+        Self::add(&self.rx, n)
+    }
+
+    /// Count the sent packets.
+    #[inline]
+    pub fn add_tx(&self, n: usize) {
+        Self::add(&self.tx, n)
+    }
+
+    /// Count the dropped packets.
+    #[inline]
+    pub fn add_dropped(&self, n: usize) {
+        Self::add(&self.dropped, n)
+    }
+
+    /// Get the snapshot of the counters.
+    pub fn stats(&self) -> PortStats {
+        PortStats {
+            rx: self.rx.load(Ordering::Relaxed),
+            tx: self.tx.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    // the counter has only one writer, so it doesn't need the atomic read-modify-write.
+    #[inline]
+    fn add(counter: &AtomicU64, n: usize) {
+        if n > 0 {
+            counter.store(counter.load(Ordering::Relaxed) + n as u64, Ordering::Relaxed)
+        }
+    }
+}
+
+/// A transmit queue with a TX buffer, which sends the packets when the buffer is full or flushed.
+///
+/// The sent packets are counted in the port counters,
+/// and the packets which could not be sent are freed and counted as dropped.
+pub struct BufferedTxQueue {
+    txq: TxQueue,
+    buf: NonNull<RawTxBuffer>,
+    counters: Arc<PortCounters>,
+}
+
+// The TX buffer is only accessed by the owner of the queue.
+unsafe impl Send for BufferedTxQueue {}
+
+impl Drop for BufferedTxQueue {
+    fn drop(&mut self) {
+        self.flush();
+
+        malloc::free(self.buf.as_ptr() as *mut c_void);
+    }
+}
+
+impl BufferedTxQueue {
+    /// Create a TX buffer of `size` packets for the transmit queue on the NUMA socket of the port.
+    pub fn new(txq: TxQueue, size: usize, counters: Arc<PortCounters>) -> Result<Self> {
+        let buf = alloc_buffer(size, txq.port_id.socket_id())?;
+        let queue = BufferedTxQueue {
+            txq,
+            buf: unsafe { NonNull::new_unchecked(buf) },
+            counters,
+        };
+
+        rte_check!(unsafe {
+            ffi::rte_eth_tx_buffer_set_err_callback(
+                buf,
+                Some(count_dropped_packets),
+                &*queue.counters as *const PortCounters as *mut c_void,
+            )
+        }; ok => { queue })
+    }
+
+    pub fn port_id(&self) -> PortId {
+        self.txq.port_id
+    }
+
+    pub fn queue_id(&self) -> QueueId {
+        self.txq.queue_id
+    }
+
+    /// The packet counters of the port.
+    pub fn counters(&self) -> &PortCounters {
+        &self.counters
+    }
+
+    /// Buffer a packet, send the buffered packets if the buffer is full.
+    ///
+    /// Return the number of packets sent.
+    #[inline]
+    pub fn send(&mut self, m: mbuf::RawMBufPtr) -> usize {
+        let sent =
+            unsafe { ffi::_rte_eth_tx_buffer(self.txq.port_id.0, self.txq.queue_id, self.buf.as_ptr(), m) } as usize;
+
+        self.counters.add_tx(sent);
+
+        sent
+    }
+
+    /// Send all the buffered packets.
+    ///
+    /// Return the number of packets sent.
+    #[inline]
+    pub fn flush(&mut self) -> usize {
+        let sent =
+            unsafe { ffi::_rte_eth_tx_buffer_flush(self.txq.port_id.0, self.txq.queue_id, self.buf.as_ptr()) } as usize;
+
+        self.counters.add_tx(sent);
+
+        sent
+    }
+}
+
+unsafe extern "C" fn count_dropped_packets(pkts: *mut *mut ffi::rte_mbuf, unsent: u16, userdata: *mut c_void) {
+    let counters = &*(userdata as *const PortCounters);
+
+    mbuf::free_burst(slice::from_raw_parts_mut(pkts, unsent as usize));
+
+    counters.add_dropped(unsent as usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_id() {
+        assert_eq!(PortId::try_from(3u32).unwrap(), PortId(3));
+        assert_eq!(
+            PortId::try_from(MAX_ETHPORTS - 1).unwrap(),
+            PortId(MAX_ETHPORTS as u16 - 1)
+        );
+        assert!(PortId::try_from(MAX_ETHPORTS).is_err());
+        assert!(PortId::try_from(-1i32).is_err());
+        assert_eq!("7".parse::<PortId>().unwrap(), PortId(7));
+        assert!("port".parse::<PortId>().is_err());
+        assert_eq!(PortId(5).to_string(), "5");
+        assert_eq!(u16::from(PortId(5)), 5);
+    }
+
+    #[test]
+    fn test_burst_len() {
+        assert_eq!(burst_len(32), 32);
+        assert_eq!(burst_len(u16::MAX as usize), u16::MAX);
+        assert_eq!(burst_len(65536), u16::MAX);
+    }
+
+    #[test]
+    fn test_queue_handles() {
+        let port_id = PortId(MAX_ETHPORTS as u16 - 1);
+
+        let rxq = RxQueue::acquire(port_id, 0).unwrap();
+
+        assert!(RxQueue::acquire(port_id, 0).is_err());
+        assert!(check_queue_released(port_id, false, 0).is_err());
+        assert!(check_port_released(port_id).is_err());
+
+        let txq = TxQueue::acquire(port_id, 0).unwrap();
+
+        assert!(TxQueue::acquire(port_id, 0).is_err());
+
+        drop(rxq);
+
+        assert!(check_queue_released(port_id, false, 0).is_ok());
+        assert!(check_port_released(port_id).is_err());
+
+        drop(txq);
+
+        assert!(check_port_released(port_id).is_ok());
+    }
+
+    #[test]
+    fn test_eth_event() {
+        assert_eq!(
+            EthEvent::from(ffi::rte_eth_event_type::RTE_ETH_EVENT_INTR_LSC),
+            EthEvent::LscChange
+        );
+        assert_eq!(
+            EthEvent::from(ffi::rte_eth_event_type::RTE_ETH_EVENT_INTR_RMV),
+            EthEvent::Remove
+        );
+        assert_eq!(
+            EthEvent::from(ffi::rte_eth_event_type::RTE_ETH_EVENT_MAX),
+            EthEvent::Unknown
+        );
+    }
+
+    #[test]
+    fn test_rss_reta() {
+        let mut reta = RssReta::round_robin(128, 3);
+
+        assert_eq!(reta.len(), 128);
+        assert_eq!(&reta[..5], &[0, 1, 2, 0, 1]);
+
+        reta[127] = 7;
+
+        let conf = reta.to_raw();
+
+        assert_eq!(conf.len(), 2);
+        assert_eq!(conf[0].mask, u64::max_value());
+        assert_eq!(conf[1].reta[63], 7);
+
+        let conf = RssReta::new(8).to_raw();
+
+        assert_eq!(conf.len(), 1);
+        assert_eq!(conf[0].mask, 0xFF);
+    }
+
+    #[test]
+    fn test_eth_conf() {
+        let mut intr_conf = ffi::rte_intr_conf::default();
+
+        intr_conf.set_lsc(1);
+
+        let conf = EthConf {
+            link_speeds: LinkSpeed::ETH_LINK_SPEED_FIXED | LinkSpeed::ETH_LINK_SPEED_10G,
+            rx_adv_conf: Some(RxAdvConf {
+                rss_conf: Some(EthRssConf {
+                    key: Some([0x6d; 40]),
+                    hash: RssHashFunc::ETH_RSS_IP,
+                }),
+                ..RxAdvConf::default()
+            }),
+            tx_adv_conf: Some(TxAdvConf::Dcb(ffi::rte_eth_dcb_tx_conf {
+                nb_tcs: ffi::rte_eth_nb_tcs::ETH_4_TCS,
+                dcb_tc: [0, 1, 2, 3, 0, 1, 2, 3],
+            })),
+            intr_conf: Some(intr_conf),
+            ..EthConf::default()
+        };
+        let raw = RawEthConf::from(&conf);
+        let raw = unsafe { &*raw.as_raw() };
+
+        assert_eq!(raw.link_speeds, conf.link_speeds.bits);
+        assert_eq!(raw.rx_adv_conf.rss_conf.rss_key_len, 40);
+        assert_eq!(unsafe { *raw.rx_adv_conf.rss_conf.rss_key }, 0x6d);
+        assert_eq!(raw.rx_adv_conf.rss_conf.rss_hf, RssHashFunc::ETH_RSS_IP.bits);
+        assert_eq!(unsafe { raw.tx_adv_conf.dcb_tx_conf.dcb_tc }, [0, 1, 2, 3, 0, 1, 2, 3]);
+        assert_eq!(raw.intr_conf.lsc(), 1);
+        assert_eq!(raw.intr_conf.rxq(), 0);
+    }
+
+    #[test]
+    fn test_eth_conf_builder() {
+        let builder = EthConf::builder()
+            .rss(RssHashFunc::ETH_RSS_IP)
+            .jumbo(9000)
+            .hw_checksum(true)
+            .lsc_interrupt(true);
+        let conf = builder.build().unwrap();
+        let rxmode = conf.rxmode.unwrap();
+        let txmode = conf.txmode.unwrap();
+
+        assert_eq!(rxmode.mq_mode, ETH_MQ_RX_RSS);
+        assert!(RxOffload::from_bits_truncate(rxmode.offloads).contains(RxOffload::DEV_RX_OFFLOAD_CHECKSUM));
+        assert_eq!(
+            TxOffload::from_bits_truncate(txmode.offloads),
+            TxOffload::DEV_TX_OFFLOAD_CHECKSUM
+        );
+        assert_eq!(
+            conf.rx_adv_conf.unwrap().rss_conf.unwrap().hash,
+            RssHashFunc::ETH_RSS_IP
+        );
+        assert_eq!(conf.intr_conf.unwrap().lsc(), 1);
+
+        let mut info = RawEthDeviceInfo::default();
+
+        info.max_rx_pktlen = 9018;
+        info.rx_offload_capa = builder.rx_offloads.bits;
+        info.tx_offload_capa = TxOffload::DEV_TX_OFFLOAD_IPV4_CKSUM.bits;
+        info.flow_type_rss_offloads = RssHashFunc::ETH_RSS_PROTO_MASK.bits;
+
+        assert_eq!(info.rss_offloads(), RssHashFunc::ETH_RSS_PROTO_MASK);
+        assert_eq!(info.default_rx_ring_size(), None);
+        assert!(builder.check_capabilities(&info).is_err());
+
+        info.tx_offload_capa = TxOffload::DEV_TX_OFFLOAD_CHECKSUM.bits;
+
+        assert!(builder.check_capabilities(&info).is_ok());
+
+        info.max_rx_pktlen = 1518;
+
+        assert!(builder.check_capabilities(&info).is_err());
+
+        assert!(EthConf::builder().jumbo(1500).build().is_err());
+        assert!(EthConf::builder().rss_key([0; 40]).build().is_err());
+        assert!(EthConf::builder()
+            .link_speeds(LinkSpeed::ETH_LINK_SPEED_FIXED | LinkSpeed::ETH_LINK_SPEED_1G | LinkSpeed::ETH_LINK_SPEED_10G)
+            .build()
+            .is_err());
+    }
+}
@@ -11,6 +11,7 @@ use rand::{thread_rng, Rng};
 
 use ffi;
 
+use byteorder::be16;
 use mbuf;
 use utils::AsRaw;
 
@@ -210,6 +211,12 @@ impl From<RawEtherAddr> for EtherAddr {
     }
 }
 
+impl From<EtherAddr> for RawEtherAddr {
+    fn from(addr: EtherAddr) -> RawEtherAddr {
+        RawEtherAddr { addr_bytes: addr.0 }
+    }
+}
+
 impl str::FromStr for EtherAddr {
     type Err = AddrParseError;
 
@@ -226,21 +233,21 @@ impl str::FromStr for EtherAddr {
 // Ethernet frame types
 
 /// IPv4 Protocol.
-pub const ETHER_TYPE_IPV4_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_IPV4 as u16);
+pub const ETHER_TYPE_IPV4_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_IPV4 as u16);
 /// IPv6 Protocol.
-pub const ETHER_TYPE_IPV6_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_IPV6 as u16);
+pub const ETHER_TYPE_IPV6_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_IPV6 as u16);
 /// Arp Protocol.
-pub const ETHER_TYPE_ARP_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_ARP as u16);
+pub const ETHER_TYPE_ARP_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_ARP as u16);
 /// Reverse Arp Protocol.
-pub const ETHER_TYPE_RARP_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_RARP as u16);
+pub const ETHER_TYPE_RARP_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_RARP as u16);
 /// IEEE 802.1Q VLAN tagging.
-pub const ETHER_TYPE_VLAN_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_VLAN as u16);
+pub const ETHER_TYPE_VLAN_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_VLAN as u16);
 /// IEEE 802.1AS 1588 Precise Time Protocol.
-pub const ETHER_TYPE_1588_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_1588 as u16);
+pub const ETHER_TYPE_1588_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_1588 as u16);
 /// Slow protocols (LACP and Marker).
-pub const ETHER_TYPE_SLOW_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_SLOW as u16);
+pub const ETHER_TYPE_SLOW_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_SLOW as u16);
 /// Transparent Ethernet Bridging.
-pub const ETHER_TYPE_TEB_BE: u16 = rte_cpu_to_be_16!(ffi::RTE_ETHER_TYPE_TEB as u16);
+pub const ETHER_TYPE_TEB_BE: be16 = be16::new(ffi::RTE_ETHER_TYPE_TEB as u16);
 
 /// Ethernet header: Contains the destination address, source address and frame type.
 pub type EtherHdr = ffi::rte_ether_hdr;
@@ -251,6 +258,93 @@ pub type VlanHdr = ffi::rte_vlan_hdr;
 /// VXLAN protocol header.
 pub type VxlanHdr = ffi::rte_vxlan_hdr;
 
+/// The fields of the Ethernet header in the network byte order.
+pub trait EtherHdrExt {
+    /// The frame type.
+    fn ether_type(&self) -> be16;
+
+    /// Set the frame type.
+    fn set_ether_type(&mut self, ether_type: be16);
+
+    /// The destination address.
+    fn dst_addr(&self) -> EtherAddr;
+
+    /// Set the destination address.
+    fn set_dst_addr(&mut self, addr: &EtherAddr);
+
+    /// The source address.
+    fn src_addr(&self) -> EtherAddr;
+
+    /// Set the source address.
+    fn set_src_addr(&mut self, addr: &EtherAddr);
+
+    /// Rewrite both addresses, e.g. to forward the frame to the next hop from the output port.
+    #[inline]
+    fn rewrite_addrs(&mut self, dst: &EtherAddr, src: &EtherAddr) {
+        self.set_dst_addr(dst);
+        self.set_src_addr(src);
+    }
+}
+
+impl EtherHdrExt for EtherHdr {
+    fn ether_type(&self) -> be16 {
+        be16::from_raw(self.ether_type)
+    }
+
+    fn set_ether_type(&mut self, ether_type: be16) {
+        self.ether_type = ether_type.raw()
+    }
+
+    fn dst_addr(&self) -> EtherAddr {
+        EtherAddr::from(self.d_addr)
+    }
+
+    fn set_dst_addr(&mut self, addr: &EtherAddr) {
+        self.d_addr.addr_bytes = *addr.octets()
+    }
+
+    fn src_addr(&self) -> EtherAddr {
+        EtherAddr::from(self.s_addr)
+    }
+
+    fn set_src_addr(&mut self, addr: &EtherAddr) {
+        self.s_addr.addr_bytes = *addr.octets()
+    }
+}
+
+/// The fields of the VLAN header in the network byte order.
+pub trait VlanHdrExt {
+    /// Priority (3) + CFI (1) + Identifier Code (12).
+    fn vlan_tci(&self) -> be16;
+
+    /// Set the tag control information.
+    fn set_vlan_tci(&mut self, tci: be16);
+
+    /// The Ethernet type of the encapsulated frame.
+    fn eth_proto(&self) -> be16;
+
+    /// Set the Ethernet type of the encapsulated frame.
+    fn set_eth_proto(&mut self, eth_proto: be16);
+}
+
+impl VlanHdrExt for VlanHdr {
+    fn vlan_tci(&self) -> be16 {
+        be16::from_raw(self.vlan_tci)
+    }
+
+    fn set_vlan_tci(&mut self, tci: be16) {
+        self.vlan_tci = tci.raw()
+    }
+
+    fn eth_proto(&self) -> be16 {
+        be16::from_raw(self.eth_proto)
+    }
+
+    fn set_eth_proto(&mut self, eth_proto: be16) {
+        self.eth_proto = eth_proto.raw()
+    }
+}
+
 pub trait VlanExt {
     /// Extract VLAN tag information into mbuf
     fn vlan_strip(&mut self) -> Result<()>;
@@ -282,6 +376,7 @@ mod tests {
 
         assert_eq!(addr, EtherAddr::from([0x18, 0x2b, 0x3c, 0x4d, 0x5e, 0x6f]));
         assert_eq!(addr, EtherAddr::from_str("18:2b:3c:4d:5e:6f").unwrap());
+        assert_eq!(EtherAddr::from(RawEtherAddr::from(addr)), addr);
 
         assert!(!addr.is_zero());
         assert!(EtherAddr::zeroed().is_zero());
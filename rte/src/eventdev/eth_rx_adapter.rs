@@ -0,0 +1,159 @@
+//!
+//! Event Ethernet Rx Adapter
+//!
+//! The adapter injects the packets received from the ethernet device queues into an event device,
+//! either by the internal port of the event device, or by a service function running on a service core.
+//!
+use anyhow::Result;
+
+use ethdev;
+use ffi;
+
+use super::{DevId, Event, EventPortConf};
+
+pub type AdapterId = u8;
+
+bitflags! {
+    /// The capabilities of the Rx adapter between an event device and an ethernet device.
+    pub struct RxAdapterCaps: u32 {
+        /// The packets are transferred by the internal port of the event device instead of a service function.
+        const INTERNAL_PORT    = ffi::RTE_EVENT_ETH_RX_ADAPTER_CAP_INTERNAL_PORT;
+        /// The Rx queues of an ethernet device can be connected to different event queues.
+        const MULTI_EVENTQ     = ffi::RTE_EVENT_ETH_RX_ADAPTER_CAP_MULTI_EVENTQ;
+        /// The flow identifier of the event can be overridden by the queue configuration.
+        const OVERRIDE_FLOW_ID = ffi::RTE_EVENT_ETH_RX_ADAPTER_CAP_OVERRIDE_FLOW_ID;
+    }
+}
+
+bitflags! {
+    /// The flags of the received packets of an Rx queue.
+    pub struct RxQueueFlags: u32 {
+        /// The flow identifier of the queue configuration is used for all the packets.
+        const FLOW_ID_VALID = ffi::RTE_EVENT_ETH_RX_ADAPTER_QUEUE_FLOW_ID_VALID;
+    }
+}
+
+/// Retrieve the Rx adapter capabilities between the event device and the ethernet device.
+pub fn caps(dev_id: DevId, port_id: ethdev::PortId) -> Result<RxAdapterCaps> {
+    let mut caps = 0;
+
+    rte_check!(unsafe {
+        ffi::rte_event_eth_rx_adapter_caps_get(dev_id, port_id.0, &mut caps)
+    }; ok => { RxAdapterCaps::from_bits_truncate(caps) })
+}
+
+/// The configuration of an Rx queue added to the adapter.
+#[derive(Clone, Copy, Debug)]
+pub struct RxQueueConf {
+    /// The flags of the received packets.
+    pub flags: RxQueueFlags,
+    /// The relative polling frequency of the Rx queue by the service function,
+    /// the Rx queue is interrupt driven if it is zero.
+    pub servicing_weight: u16,
+    /// The template of the enqueued events, e.g. the event queue, priority and scheduling type.
+    pub ev: Event,
+}
+
+impl RxQueueConf {
+    /// Create a configuration of the Rx queue which packets are enqueued as the template event.
+    pub fn new(ev: Event) -> Self {
+        RxQueueConf {
+            flags: RxQueueFlags::empty(),
+            servicing_weight: 1,
+            ev,
+        }
+    }
+
+    fn to_raw(self) -> ffi::rte_event_eth_rx_adapter_queue_conf {
+        ffi::rte_event_eth_rx_adapter_queue_conf {
+            rx_queue_flags: self.flags.bits,
+            servicing_weight: self.servicing_weight,
+            ev: self.ev.0,
+        }
+    }
+}
+
+pub type RawRxAdapterStats = ffi::rte_event_eth_rx_adapter_stats;
+
+/// An Rx adapter which enqueues the packets of the ethernet device queues to an event device.
+#[derive(Debug)]
+pub struct EthRxAdapter(AdapterId);
+
+impl Drop for EthRxAdapter {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_event_eth_rx_adapter_free(self.0) };
+    }
+}
+
+impl EthRxAdapter {
+    /// Create an Rx adapter of the event device, the event port of the adapter is set up with `port_conf`.
+    pub fn create(id: AdapterId, dev_id: DevId, port_conf: &EventPortConf) -> Result<Self> {
+        let mut port_conf = port_conf.to_raw();
+
+        rte_check!(unsafe {
+            ffi::rte_event_eth_rx_adapter_create(id, dev_id, &mut port_conf)
+        }; ok => { EthRxAdapter(id) })
+    }
+
+    /// The identifier of the adapter.
+    pub fn id(&self) -> AdapterId {
+        self.0
+    }
+
+    /// Add the Rx queue of the ethernet device to the adapter, all the Rx queues are added if `queue_id` is `None`.
+    ///
+    /// The packets must not be received from the queue with `rx_burst()` after it was added.
+    pub fn queue_add(
+        &self,
+        port_id: ethdev::PortId,
+        queue_id: Option<ethdev::QueueId>,
+        conf: &RxQueueConf,
+    ) -> Result<&Self> {
+        let conf = conf.to_raw();
+
+        rte_check!(unsafe {
+            ffi::rte_event_eth_rx_adapter_queue_add(self.0, port_id.0, queue_id.map_or(-1, i32::from), &conf)
+        }; ok => { self })
+    }
+
+    /// Delete the Rx queue of the ethernet device from the adapter,
+    /// all the Rx queues are deleted if `queue_id` is `None`.
+    pub fn queue_del(&self, port_id: ethdev::PortId, queue_id: Option<ethdev::QueueId>) -> Result<&Self> {
+        rte_check!(unsafe {
+            ffi::rte_event_eth_rx_adapter_queue_del(self.0, port_id.0, queue_id.map_or(-1, i32::from))
+        }; ok => { self })
+    }
+
+    /// Start the adapter.
+    pub fn start(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_event_eth_rx_adapter_start(self.0) }; ok => { self })
+    }
+
+    /// Stop the adapter.
+    pub fn stop(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_event_eth_rx_adapter_stop(self.0) }; ok => { self })
+    }
+
+    /// Retrieve the statistics of the adapter.
+    pub fn stats(&self) -> Result<RawRxAdapterStats> {
+        let mut stats = RawRxAdapterStats::default();
+
+        rte_check!(unsafe { ffi::rte_event_eth_rx_adapter_stats_get(self.0, &mut stats) }; ok => { stats })
+    }
+
+    /// Reset the statistics of the adapter.
+    pub fn reset_stats(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_event_eth_rx_adapter_stats_reset(self.0) }; ok => { self })
+    }
+
+    /// Retrieve the service identifier of the adapter, if it uses a service function.
+    pub fn service_id(&self) -> Option<u32> {
+        let mut service_id = 0;
+
+        if unsafe { ffi::rte_event_eth_rx_adapter_service_id_get(self.0, &mut service_id) } == 0 {
+            Some(service_id)
+        } else {
+            None
+        }
+    }
+}
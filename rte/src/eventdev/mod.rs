@@ -0,0 +1,831 @@
+//!
+//! RTE Eventdev
+//!
+//! The event device library provides an event driven programming model,
+//! the events are enqueued to the event queues and scheduled to the event ports by the event device,
+//! according to the scheduling type of the event, e.g. atomic, ordered or parallel.
+//!
+//! A device is configured with the number of event queues and ports, each queue and port is set up,
+//! the queues are linked to the ports, then the device is started and the events are
+//! enqueued and dequeued in burst through the ports.
+//!
+use std::ffi::CStr;
+use std::fmt;
+use std::ops::Range;
+use std::os::unix::io::AsRawFd;
+use std::ptr;
+
+use anyhow::Result;
+use cfile;
+
+use dev;
+use errors::{AsResult, ErrorKind::OsError};
+use ffi;
+use mbuf;
+use memory::SocketId;
+use utils::{AsCString, IntoRaw};
+
+pub mod eth_rx_adapter;
+
+pub use self::eth_rx_adapter::{EthRxAdapter, RxAdapterCaps, RxQueueConf, RxQueueFlags};
+
+pub use ffi::{
+    RTE_EVENT_DEV_PRIORITY_HIGHEST, RTE_EVENT_DEV_PRIORITY_LOWEST, RTE_EVENT_DEV_PRIORITY_NORMAL,
+    RTE_EVENT_MAX_QUEUES_PER_DEV,
+};
+
+pub type DevId = u8;
+pub type QueueId = u8;
+pub type PortId = u8;
+
+bitflags! {
+    /// The capabilities of an event device.
+    pub struct DevCaps: u32 {
+        /// The event queues support the priority.
+        const QUEUE_QOS                = ffi::RTE_EVENT_DEV_CAP_QUEUE_QOS;
+        /// The events support the priority.
+        const EVENT_QOS                = ffi::RTE_EVENT_DEV_CAP_EVENT_QOS;
+        /// The scheduling is distributed to the lcores which dequeue the events.
+        const DISTRIBUTED_SCHED        = ffi::RTE_EVENT_DEV_CAP_DISTRIBUTED_SCHED;
+        /// An event queue can accept the events of all the scheduling types.
+        const QUEUE_ALL_TYPES          = ffi::RTE_EVENT_DEV_CAP_QUEUE_ALL_TYPES;
+        /// The events can be enqueued and dequeued in burst.
+        const BURST_MODE               = ffi::RTE_EVENT_DEV_CAP_BURST_MODE;
+        /// The implicit release of the dequeued events can be disabled.
+        const IMPLICIT_RELEASE_DISABLE = ffi::RTE_EVENT_DEV_CAP_IMPLICIT_RELEASE_DISABLE;
+        /// The events need not be forwarded in the order of the dequeue.
+        const NONSEQ_MODE              = ffi::RTE_EVENT_DEV_CAP_NONSEQ_MODE;
+        /// The queues can be linked to and unlinked from the ports at runtime.
+        const RUNTIME_PORT_LINK        = ffi::RTE_EVENT_DEV_CAP_RUNTIME_PORT_LINK;
+        /// A port can be linked to multiple queues.
+        const MULTIPLE_QUEUE_PORT      = ffi::RTE_EVENT_DEV_CAP_MULTIPLE_QUEUE_PORT;
+    }
+}
+
+bitflags! {
+    /// The configuration flags of an event device.
+    pub struct DevCfgFlags: u32 {
+        /// The dequeue timeout is specified per dequeue call.
+        const PER_DEQUEUE_TIMEOUT = ffi::RTE_EVENT_DEV_CFG_PER_DEQUEUE_TIMEOUT;
+    }
+}
+
+impl Default for DevCfgFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+bitflags! {
+    /// The configuration flags of an event queue.
+    pub struct QueueCfgFlags: u32 {
+        /// The queue accepts the events of all the scheduling types.
+        const ALL_TYPES   = ffi::RTE_EVENT_QUEUE_CFG_ALL_TYPES;
+        /// The queue is linked to a single port.
+        const SINGLE_LINK = ffi::RTE_EVENT_QUEUE_CFG_SINGLE_LINK;
+    }
+}
+
+bitflags! {
+    /// The configuration flags of an event port.
+    pub struct PortCfgFlags: u32 {
+        /// The dequeued events are not released implicitly by the next dequeue.
+        const DISABLE_IMPL_REL = ffi::RTE_EVENT_PORT_CFG_DISABLE_IMPL_REL;
+        /// The port is linked to a single queue.
+        const SINGLE_LINK      = ffi::RTE_EVENT_PORT_CFG_SINGLE_LINK;
+    }
+}
+
+/// The scheduling type of an event.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchedType {
+    /// The events of a flow are processed in parallel, and restored to the original order when forwarded.
+    Ordered = ffi::RTE_SCHED_TYPE_ORDERED as u8,
+    /// The events of a flow are processed by a single port at a time.
+    Atomic = ffi::RTE_SCHED_TYPE_ATOMIC as u8,
+    /// The events are processed in parallel without any ordering.
+    Parallel = ffi::RTE_SCHED_TYPE_PARALLEL as u8,
+}
+
+impl From<u8> for SchedType {
+    fn from(v: u8) -> Self {
+        match u32::from(v) {
+            ffi::RTE_SCHED_TYPE_ORDERED => SchedType::Ordered,
+            ffi::RTE_SCHED_TYPE_ATOMIC => SchedType::Atomic,
+            _ => SchedType::Parallel,
+        }
+    }
+}
+
+/// The operation of an enqueued event.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventOp {
+    /// A new event injected into the event device.
+    New = ffi::RTE_EVENT_OP_NEW as u8,
+    /// An event dequeued from the device and forwarded to another stage.
+    Forward = ffi::RTE_EVENT_OP_FORWARD as u8,
+    /// Release the flow context of a dequeued event.
+    Release = ffi::RTE_EVENT_OP_RELEASE as u8,
+}
+
+impl From<u8> for EventOp {
+    fn from(v: u8) -> Self {
+        match u32::from(v) {
+            ffi::RTE_EVENT_OP_NEW => EventOp::New,
+            ffi::RTE_EVENT_OP_FORWARD => EventOp::Forward,
+            _ => EventOp::Release,
+        }
+    }
+}
+
+/// The source of an event.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventType {
+    EthDev = ffi::RTE_EVENT_TYPE_ETHDEV as u8,
+    CryptoDev = ffi::RTE_EVENT_TYPE_CRYPTODEV as u8,
+    Timer = ffi::RTE_EVENT_TYPE_TIMER as u8,
+    Cpu = ffi::RTE_EVENT_TYPE_CPU as u8,
+    EthRxAdapter = ffi::RTE_EVENT_TYPE_ETH_RX_ADAPTER as u8,
+}
+
+pub type RawEvent = ffi::rte_event;
+
+/// An event enqueued to or dequeued from an event device.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct Event(RawEvent);
+
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("flow_id", &self.flow_id())
+            .field("event_type", &self.event_type())
+            .field("sub_event_type", &self.sub_event_type())
+            .field("op", &self.op())
+            .field("sched_type", &self.sched_type())
+            .field("queue_id", &self.queue_id())
+            .field("priority", &self.priority())
+            .field("u64", &self.u64())
+            .finish()
+    }
+}
+
+impl From<RawEvent> for Event {
+    fn from(ev: RawEvent) -> Self {
+        Event(ev)
+    }
+}
+
+impl Event {
+    /// Create a new event of the CPU type, which is enqueued to the event queue.
+    pub fn new(queue_id: QueueId, sched_type: SchedType) -> Self {
+        let mut ev = Event::default();
+
+        ev.set_event_type(EventType::Cpu as u8)
+            .set_op(EventOp::New)
+            .set_sched_type(sched_type)
+            .set_queue_id(queue_id)
+            .set_priority(RTE_EVENT_DEV_PRIORITY_NORMAL as u8);
+        ev
+    }
+
+    /// The flow identifier of the event.
+    #[inline]
+    pub fn flow_id(&self) -> u32 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.flow_id() }
+    }
+
+    /// Set the flow identifier of the event, only the lower 20 bits are used.
+    #[inline]
+    pub fn set_flow_id(&mut self, flow_id: u32) -> &mut Self {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.set_flow_id(flow_id) };
+        self
+    }
+
+    /// The type of the event source, see `EventType`.
+    #[inline]
+    pub fn event_type(&self) -> u8 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.event_type() as u8 }
+    }
+
+    /// Set the type of the event source, only the lower 4 bits are used.
+    #[inline]
+    pub fn set_event_type(&mut self, event_type: u8) -> &mut Self {
+        unsafe {
+            self.0
+                .__bindgen_anon_1
+                .__bindgen_anon_1
+                .set_event_type(event_type.into())
+        };
+        self
+    }
+
+    /// The application defined sub type of the event.
+    #[inline]
+    pub fn sub_event_type(&self) -> u8 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.sub_event_type() as u8 }
+    }
+
+    /// Set the application defined sub type of the event.
+    #[inline]
+    pub fn set_sub_event_type(&mut self, sub_event_type: u8) -> &mut Self {
+        unsafe {
+            self.0
+                .__bindgen_anon_1
+                .__bindgen_anon_1
+                .set_sub_event_type(sub_event_type.into())
+        };
+        self
+    }
+
+    /// The operation of the enqueued event.
+    #[inline]
+    pub fn op(&self) -> EventOp {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.op() }.into()
+    }
+
+    #[inline]
+    pub fn set_op(&mut self, op: EventOp) -> &mut Self {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.set_op(op as u8) };
+        self
+    }
+
+    /// The scheduling type of the event.
+    #[inline]
+    pub fn sched_type(&self) -> SchedType {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.sched_type() }.into()
+    }
+
+    #[inline]
+    pub fn set_sched_type(&mut self, sched_type: SchedType) -> &mut Self {
+        unsafe {
+            self.0
+                .__bindgen_anon_1
+                .__bindgen_anon_1
+                .set_sched_type(sched_type as u8)
+        };
+        self
+    }
+
+    /// The targeted event queue of the enqueued event, or the source event queue of the dequeued event.
+    #[inline]
+    pub fn queue_id(&self) -> QueueId {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.queue_id }
+    }
+
+    #[inline]
+    pub fn set_queue_id(&mut self, queue_id: QueueId) -> &mut Self {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.queue_id = queue_id };
+        self
+    }
+
+    /// The priority of the event relative to other events in the event queue.
+    #[inline]
+    pub fn priority(&self) -> u8 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.priority }
+    }
+
+    #[inline]
+    pub fn set_priority(&mut self, priority: u8) -> &mut Self {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.priority = priority };
+        self
+    }
+
+    /// The payload of the event.
+    #[inline]
+    pub fn u64(&self) -> u64 {
+        unsafe { self.0.__bindgen_anon_2.u64_ }
+    }
+
+    #[inline]
+    pub fn set_u64(&mut self, v: u64) -> &mut Self {
+        self.0.__bindgen_anon_2.u64_ = v;
+        self
+    }
+
+    /// The opaque pointer of the event.
+    #[inline]
+    pub fn event_ptr<T>(&self) -> *mut T {
+        unsafe { self.0.__bindgen_anon_2.event_ptr as *mut _ }
+    }
+
+    #[inline]
+    pub fn set_event_ptr<T>(&mut self, p: *mut T) -> &mut Self {
+        self.0.__bindgen_anon_2.event_ptr = p as *mut _;
+        self
+    }
+
+    /// The mbuf associated with the event.
+    #[inline]
+    pub fn mbuf(&self) -> mbuf::RawMBufPtr {
+        unsafe { self.0.__bindgen_anon_2.mbuf }
+    }
+
+    /// Take the ownership of the mbuf associated with the dequeued event.
+    #[inline]
+    pub fn into_mbuf(self) -> Option<mbuf::MBuf> {
+        let m = self.mbuf();
+
+        if m.is_null() {
+            None
+        } else {
+            Some(m.into())
+        }
+    }
+
+    /// Associate the mbuf with the event, the mbuf is owned by the event until it is dequeued.
+    #[inline]
+    pub fn set_mbuf(&mut self, m: mbuf::MBuf) -> &mut Self {
+        self.0.__bindgen_anon_2.mbuf = m.into_raw();
+        self
+    }
+}
+
+/// Get the total number of event devices that have been successfully initialised.
+pub fn count() -> u8 {
+    unsafe { ffi::rte_event_dev_count() }
+}
+
+pub fn devices() -> Range<DevId> {
+    0..count()
+}
+
+/// Get the device identifier of the named event device.
+pub fn get_dev_id<S: AsRef<str>>(name: S) -> Option<DevId> {
+    let name = name.as_cstring();
+
+    match unsafe { ffi::rte_event_dev_get_dev_id(name.as_ptr()) } {
+        id if id < 0 => None,
+        id => Some(id as DevId),
+    }
+}
+
+/// The configuration of an event device.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EventDevConf {
+    /// The dequeue timeout in nanoseconds, 0 means the default timeout of the device.
+    pub dequeue_timeout_ns: u32,
+    /// The maximum number of inflight events of a closed system, -1 for an open system.
+    pub nb_events_limit: i32,
+    /// The number of event queues.
+    pub nb_event_queues: u8,
+    /// The number of event ports.
+    pub nb_event_ports: u8,
+    /// The number of flows of any event queue.
+    pub nb_event_queue_flows: u32,
+    /// The maximum number of events dequeued at a time from an event port.
+    pub nb_event_port_dequeue_depth: u32,
+    /// The maximum number of events enqueued at a time to an event port.
+    pub nb_event_port_enqueue_depth: u32,
+    /// The configuration flags of the device.
+    pub flags: DevCfgFlags,
+    /// The number of the event ports and queues which will be singly linked to each other.
+    pub nb_single_link_event_port_queues: u8,
+}
+
+impl EventDevConf {
+    /// Create a configuration with the queues and ports, and the limits of the device.
+    pub fn new(info: &RawEventDevInfo, nb_event_queues: u8, nb_event_ports: u8) -> Self {
+        EventDevConf {
+            dequeue_timeout_ns: info.min_dequeue_timeout_ns,
+            nb_events_limit: info.max_num_events,
+            nb_event_queues,
+            nb_event_ports,
+            nb_event_queue_flows: info.max_event_queue_flows,
+            nb_event_port_dequeue_depth: info.max_event_port_dequeue_depth.into(),
+            nb_event_port_enqueue_depth: info.max_event_port_enqueue_depth,
+            flags: DevCfgFlags::empty(),
+            nb_single_link_event_port_queues: 0,
+        }
+    }
+}
+
+/// The configuration of an event queue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventQueueConf {
+    /// The maximum number of active flows of the atomic scheduling.
+    pub nb_atomic_flows: u32,
+    /// The maximum number of outstanding events waiting to be reordered by the ordered scheduling.
+    pub nb_atomic_order_sequences: u32,
+    /// The configuration flags of the queue.
+    pub flags: QueueCfgFlags,
+    /// The scheduling type of the queue, which is ignored if `QueueCfgFlags::ALL_TYPES` is set.
+    pub schedule_type: SchedType,
+    /// The priority of the queue relative to other queues.
+    pub priority: u8,
+}
+
+impl Default for EventQueueConf {
+    fn default() -> Self {
+        EventQueueConf {
+            nb_atomic_flows: 1024,
+            nb_atomic_order_sequences: 1024,
+            flags: QueueCfgFlags::empty(),
+            schedule_type: SchedType::Atomic,
+            priority: RTE_EVENT_DEV_PRIORITY_NORMAL as u8,
+        }
+    }
+}
+
+impl From<ffi::rte_event_queue_conf> for EventQueueConf {
+    fn from(conf: ffi::rte_event_queue_conf) -> Self {
+        EventQueueConf {
+            nb_atomic_flows: conf.nb_atomic_flows,
+            nb_atomic_order_sequences: conf.nb_atomic_order_sequences,
+            flags: QueueCfgFlags::from_bits_truncate(conf.event_queue_cfg),
+            schedule_type: conf.schedule_type.into(),
+            priority: conf.priority,
+        }
+    }
+}
+
+impl EventQueueConf {
+    fn to_raw(self) -> ffi::rte_event_queue_conf {
+        ffi::rte_event_queue_conf {
+            nb_atomic_flows: self.nb_atomic_flows,
+            nb_atomic_order_sequences: self.nb_atomic_order_sequences,
+            event_queue_cfg: self.flags.bits,
+            schedule_type: self.schedule_type as u8,
+            priority: self.priority,
+        }
+    }
+}
+
+/// The configuration of an event port.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventPortConf {
+    /// The backpressure threshold of the new events enqueued to the port, -1 means no threshold.
+    pub new_event_threshold: i32,
+    /// The maximum number of events dequeued at a time from the port.
+    pub dequeue_depth: u16,
+    /// The maximum number of events enqueued at a time to the port.
+    pub enqueue_depth: u16,
+    /// The configuration flags of the port.
+    pub flags: PortCfgFlags,
+}
+
+impl From<ffi::rte_event_port_conf> for EventPortConf {
+    fn from(conf: ffi::rte_event_port_conf) -> Self {
+        EventPortConf {
+            new_event_threshold: conf.new_event_threshold,
+            dequeue_depth: conf.dequeue_depth,
+            enqueue_depth: conf.enqueue_depth,
+            flags: PortCfgFlags::from_bits_truncate(conf.event_port_cfg),
+        }
+    }
+}
+
+impl EventPortConf {
+    fn to_raw(self) -> ffi::rte_event_port_conf {
+        ffi::rte_event_port_conf {
+            new_event_threshold: self.new_event_threshold,
+            dequeue_depth: self.dequeue_depth,
+            enqueue_depth: self.enqueue_depth,
+            event_port_cfg: self.flags.bits,
+        }
+    }
+}
+
+/// The event device API.
+///
+/// The control functions, e.g. configure, queue and port setup, start and stop, are not thread-safe,
+/// the enqueue and dequeue functions of a port must not be called from several lcores at the same time.
+pub trait EventDevice {
+    fn dev_id(&self) -> DevId;
+
+    /// Return the NUMA socket to which the event device is connected.
+    fn socket_id(&self) -> SocketId;
+
+    /// Retrieve the contextual information of the event device.
+    fn info(&self) -> Result<RawEventDevInfo>;
+
+    /// Configure the event device, which must be stopped.
+    fn configure(&self, conf: &EventDevConf) -> Result<&Self>;
+
+    /// Retrieve the default configuration of the event queue.
+    fn queue_default_conf(&self, queue_id: QueueId) -> Result<EventQueueConf>;
+
+    /// Allocate and set up an event queue, the default configuration is used if `conf` is `None`.
+    fn queue_setup(&self, queue_id: QueueId, conf: Option<&EventQueueConf>) -> Result<&Self>;
+
+    /// Retrieve the default configuration of the event port.
+    fn port_default_conf(&self, port_id: PortId) -> Result<EventPortConf>;
+
+    /// Allocate and set up an event port, the default configuration is used if `conf` is `None`.
+    fn port_setup(&self, port_id: PortId, conf: Option<&EventPortConf>) -> Result<&Self>;
+
+    /// Link the event queues with the priorities to the event port,
+    /// all the queues are linked with the normal priority if `queues` is empty.
+    ///
+    /// Return the number of links actually established.
+    fn port_link(&self, port_id: PortId, queues: &[(QueueId, u8)]) -> Result<usize>;
+
+    /// Unlink the event queues from the event port, all the queues are unlinked if `queues` is empty.
+    ///
+    /// Return the number of unlinks requested, which may be completed asynchronously.
+    fn port_unlink(&self, port_id: PortId, queues: &[QueueId]) -> Result<usize>;
+
+    /// Retrieve the event queues and their priorities linked to the event port.
+    fn port_links(&self, port_id: PortId) -> Result<Vec<(QueueId, u8)>>;
+
+    /// Retrieve the service identifier of the event device, if it uses a service core.
+    fn service_id(&self) -> Option<u32>;
+
+    /// Start the event device.
+    fn start(&self) -> Result<&Self>;
+
+    /// Stop the event device, which can be restarted with `start()`.
+    fn stop(&self) -> &Self;
+
+    /// Close the event device, which can't be restarted.
+    fn close(&self) -> Result<()>;
+
+    /// Convert the nanoseconds to the timeout ticks of `dequeue_burst()`.
+    fn dequeue_timeout_ticks(&self, ns: u64) -> Result<u64>;
+
+    /// Dump the internal information of the event device to the stream.
+    fn dump<S: AsRawFd>(&self, s: &S) -> Result<()>;
+
+    /// Enqueue a burst of events to the event device through the event port.
+    ///
+    /// Return the number of events actually enqueued.
+    fn enqueue_burst(&self, port_id: PortId, events: &[Event]) -> usize;
+
+    /// Enqueue a burst of the new events to the event device through the event port.
+    fn enqueue_new_burst(&self, port_id: PortId, events: &[Event]) -> usize;
+
+    /// Enqueue a burst of the forwarded events to the event device through the event port.
+    fn enqueue_forward_burst(&self, port_id: PortId, events: &[Event]) -> usize;
+
+    /// Dequeue a burst of events from the event port, wait at most `timeout_ticks` for the events.
+    fn dequeue_burst<'a>(&self, port_id: PortId, events: &'a mut [Event], timeout_ticks: u64) -> &'a mut [Event];
+}
+
+impl EventDevice for DevId {
+    fn dev_id(&self) -> DevId {
+        *self
+    }
+
+    fn socket_id(&self) -> SocketId {
+        unsafe { ffi::rte_event_dev_socket_id(*self) }
+    }
+
+    fn info(&self) -> Result<RawEventDevInfo> {
+        let mut info = RawEventDevInfo::default();
+
+        rte_check!(unsafe { ffi::rte_event_dev_info_get(*self, &mut info) }; ok => { info })
+    }
+
+    fn configure(&self, conf: &EventDevConf) -> Result<&Self> {
+        let raw = ffi::rte_event_dev_config {
+            dequeue_timeout_ns: conf.dequeue_timeout_ns,
+            nb_events_limit: conf.nb_events_limit,
+            nb_event_queues: conf.nb_event_queues,
+            nb_event_ports: conf.nb_event_ports,
+            nb_event_queue_flows: conf.nb_event_queue_flows,
+            nb_event_port_dequeue_depth: conf.nb_event_port_dequeue_depth,
+            nb_event_port_enqueue_depth: conf.nb_event_port_enqueue_depth,
+            event_dev_cfg: conf.flags.bits,
+            nb_single_link_event_port_queues: conf.nb_single_link_event_port_queues,
+        };
+
+        rte_check!(unsafe { ffi::rte_event_dev_configure(*self, &raw) }; ok => { self })
+    }
+
+    fn queue_default_conf(&self, queue_id: QueueId) -> Result<EventQueueConf> {
+        let mut conf = ffi::rte_event_queue_conf::default();
+
+        rte_check!(unsafe {
+            ffi::rte_event_queue_default_conf_get(*self, queue_id, &mut conf)
+        }; ok => { conf.into() })
+    }
+
+    fn queue_setup(&self, queue_id: QueueId, conf: Option<&EventQueueConf>) -> Result<&Self> {
+        let conf = conf.map(|conf| conf.to_raw());
+
+        rte_check!(unsafe {
+            ffi::rte_event_queue_setup(*self, queue_id, conf.as_ref().map_or(ptr::null(), |conf| conf as *const _))
+        }; ok => { self })
+    }
+
+    fn port_default_conf(&self, port_id: PortId) -> Result<EventPortConf> {
+        let mut conf = ffi::rte_event_port_conf::default();
+
+        rte_check!(unsafe {
+            ffi::rte_event_port_default_conf_get(*self, port_id, &mut conf)
+        }; ok => { conf.into() })
+    }
+
+    fn port_setup(&self, port_id: PortId, conf: Option<&EventPortConf>) -> Result<&Self> {
+        let conf = conf.map(|conf| conf.to_raw());
+
+        rte_check!(unsafe {
+            ffi::rte_event_port_setup(*self, port_id, conf.as_ref().map_or(ptr::null(), |conf| conf as *const _))
+        }; ok => { self })
+    }
+
+    fn port_link(&self, port_id: PortId, queues: &[(QueueId, u8)]) -> Result<usize> {
+        let n = if queues.is_empty() {
+            unsafe { ffi::rte_event_port_link(*self, port_id, ptr::null(), ptr::null(), 0) }
+        } else {
+            let (queues, priorities): (Vec<_>, Vec<_>) = queues.iter().cloned().unzip();
+
+            unsafe {
+                ffi::rte_event_port_link(
+                    *self,
+                    port_id,
+                    queues.as_ptr(),
+                    priorities.as_ptr(),
+                    queues.len() as u16,
+                )
+            }
+        };
+
+        if n < 0 {
+            Err(OsError(-n).into())
+        } else {
+            Ok(n as usize)
+        }
+    }
+
+    fn port_unlink(&self, port_id: PortId, queues: &[QueueId]) -> Result<usize> {
+        let mut queues = queues.to_vec();
+        let n = unsafe {
+            ffi::rte_event_port_unlink(
+                *self,
+                port_id,
+                if queues.is_empty() {
+                    ptr::null_mut()
+                } else {
+                    queues.as_mut_ptr()
+                },
+                queues.len() as u16,
+            )
+        };
+
+        if n < 0 {
+            Err(OsError(-n).into())
+        } else {
+            Ok(n as usize)
+        }
+    }
+
+    fn port_links(&self, port_id: PortId) -> Result<Vec<(QueueId, u8)>> {
+        let mut queues = [0; RTE_EVENT_MAX_QUEUES_PER_DEV as usize];
+        let mut priorities = [0; RTE_EVENT_MAX_QUEUES_PER_DEV as usize];
+
+        let n = unsafe { ffi::rte_event_port_links_get(*self, port_id, queues.as_mut_ptr(), priorities.as_mut_ptr()) };
+
+        if n < 0 {
+            Err(OsError(-n).into())
+        } else {
+            Ok(queues
+                .iter()
+                .cloned()
+                .zip(priorities.iter().cloned())
+                .take(n as usize)
+                .collect())
+        }
+    }
+
+    fn service_id(&self) -> Option<u32> {
+        let mut service_id = 0;
+
+        if unsafe { ffi::rte_event_dev_service_id_get(*self, &mut service_id) } == 0 {
+            Some(service_id)
+        } else {
+            None
+        }
+    }
+
+    fn start(&self) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_event_dev_start(*self) }; ok => { self })
+    }
+
+    fn stop(&self) -> &Self {
+        unsafe { ffi::rte_event_dev_stop(*self) };
+
+        self
+    }
+
+    fn close(&self) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_event_dev_close(*self) })
+    }
+
+    fn dequeue_timeout_ticks(&self, ns: u64) -> Result<u64> {
+        let mut ticks = 0;
+
+        rte_check!(unsafe { ffi::rte_event_dequeue_timeout_ticks(*self, ns, &mut ticks) }; ok => { ticks })
+    }
+
+    fn dump<S: AsRawFd>(&self, s: &S) -> Result<()> {
+        let mut f = cfile::fdopen(s, "w")?;
+
+        unsafe { ffi::rte_event_dev_dump(*self, &mut **f as *mut _ as *mut _) }.as_result()?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn enqueue_burst(&self, port_id: PortId, events: &[Event]) -> usize {
+        unsafe {
+            ffi::_rte_event_enqueue_burst(*self, port_id, events.as_ptr() as *const _, events.len() as u16) as usize
+        }
+    }
+
+    #[inline]
+    fn enqueue_new_burst(&self, port_id: PortId, events: &[Event]) -> usize {
+        unsafe {
+            ffi::_rte_event_enqueue_new_burst(*self, port_id, events.as_ptr() as *const _, events.len() as u16) as usize
+        }
+    }
+
+    #[inline]
+    fn enqueue_forward_burst(&self, port_id: PortId, events: &[Event]) -> usize {
+        unsafe {
+            ffi::_rte_event_enqueue_forward_burst(*self, port_id, events.as_ptr() as *const _, events.len() as u16)
+                as usize
+        }
+    }
+
+    #[inline]
+    fn dequeue_burst<'a>(&self, port_id: PortId, events: &'a mut [Event], timeout_ticks: u64) -> &'a mut [Event] {
+        let n = unsafe {
+            ffi::_rte_event_dequeue_burst(
+                *self,
+                port_id,
+                events.as_mut_ptr() as *mut _,
+                events.len() as u16,
+                timeout_ticks,
+            )
+        };
+
+        &mut events[..n as usize]
+    }
+}
+
+pub trait EventDevInfo {
+    /// Driver name.
+    fn driver_name(&self) -> &str;
+
+    /// The capabilities of the device.
+    fn capabilities(&self) -> DevCaps;
+
+    fn dev(&self) -> Option<dev::Device>;
+}
+
+pub type RawEventDevInfo = ffi::rte_event_dev_info;
+
+impl EventDevInfo for RawEventDevInfo {
+    #[inline]
+    fn driver_name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.driver_name).to_str().unwrap() }
+    }
+
+    #[inline]
+    fn capabilities(&self) -> DevCaps {
+        DevCaps::from_bits_truncate(self.event_dev_cap)
+    }
+
+    #[inline]
+    fn dev(&self) -> Option<dev::Device> {
+        if self.dev.is_null() {
+            None
+        } else {
+            Some(self.dev.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let mut ev = Event::new(3, SchedType::Ordered);
+
+        assert_eq!(ev.event_type(), EventType::Cpu as u8);
+        assert_eq!(ev.op(), EventOp::New);
+        assert_eq!(ev.sched_type(), SchedType::Ordered);
+        assert_eq!(ev.queue_id(), 3);
+        assert_eq!(ev.priority(), RTE_EVENT_DEV_PRIORITY_NORMAL as u8);
+
+        ev.set_flow_id(0xfffff)
+            .set_sub_event_type(0xab)
+            .set_op(EventOp::Forward)
+            .set_sched_type(SchedType::Parallel)
+            .set_u64(123);
+
+        assert_eq!(ev.flow_id(), 0xfffff);
+        assert_eq!(ev.sub_event_type(), 0xab);
+        assert_eq!(ev.event_type(), EventType::Cpu as u8);
+        assert_eq!(ev.op(), EventOp::Forward);
+        assert_eq!(ev.sched_type(), SchedType::Parallel);
+        assert_eq!(ev.queue_id(), 3);
+        assert_eq!(ev.u64(), 123);
+    }
+}
@@ -9,3 +9,88 @@ pub use rte_sys::*;
 pub fn rte_errno() -> i32 {
     unsafe { rte_sys::_rte_errno() }
 }
+
+/// Get the ID of the main lcore, which was known as the master lcore before DPDK 20.11.
+#[cfg(feature = "dpdk-19_11")]
+pub use rte_sys::rte_get_master_lcore as rte_get_main_lcore;
+
+/// State of the main lcore after the remote launch, which was known as the master lcore before DPDK 20.11.
+#[cfg(feature = "dpdk-19_11")]
+pub mod rte_rmt_call_main_t {
+    pub use rte_sys::rte_rmt_call_master_t::Type;
+    pub use rte_sys::rte_rmt_call_master_t::{CALL_MASTER as CALL_MAIN, SKIP_MASTER as SKIP_MAIN};
+}
+
+/// Get the total number of Ethernet devices that have been successfully initialized.
+#[deprecated(note = "use `rte_eth_dev_count_avail` instead")]
+pub unsafe fn rte_eth_dev_count() -> uint16_t {
+    rte_eth_dev_count_avail()
+}
+
+/// Ethernet address, which was renamed to `rte_ether_addr` in DPDK 19.08.
+#[deprecated(note = "use `rte_ether_addr` instead")]
+#[allow(non_camel_case_types)]
+pub type ether_addr = rte_ether_addr;
+
+/// The `PKT_*` mbuf offload flags were renamed to `RTE_MBUF_F_*` in DPDK 21.11.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub use rte_sys::{
+    RTE_MBUF_F_EXTERNAL as EXT_ATTACHED_MBUF, RTE_MBUF_F_INDIRECT as IND_ATTACHED_MBUF,
+    RTE_MBUF_F_RX_FDIR as PKT_RX_FDIR, RTE_MBUF_F_RX_FDIR_FLX as PKT_RX_FDIR_FLX,
+    RTE_MBUF_F_RX_FDIR_ID as PKT_RX_FDIR_ID, RTE_MBUF_F_RX_IEEE1588_PTP as PKT_RX_IEEE1588_PTP,
+    RTE_MBUF_F_RX_IEEE1588_TMST as PKT_RX_IEEE1588_TMST, RTE_MBUF_F_RX_IP_CKSUM_BAD as PKT_RX_IP_CKSUM_BAD,
+    RTE_MBUF_F_RX_IP_CKSUM_GOOD as PKT_RX_IP_CKSUM_GOOD, RTE_MBUF_F_RX_IP_CKSUM_MASK as PKT_RX_IP_CKSUM_MASK,
+    RTE_MBUF_F_RX_IP_CKSUM_NONE as PKT_RX_IP_CKSUM_NONE, RTE_MBUF_F_RX_IP_CKSUM_UNKNOWN as PKT_RX_IP_CKSUM_UNKNOWN,
+    RTE_MBUF_F_RX_L4_CKSUM_BAD as PKT_RX_L4_CKSUM_BAD, RTE_MBUF_F_RX_L4_CKSUM_GOOD as PKT_RX_L4_CKSUM_GOOD,
+    RTE_MBUF_F_RX_L4_CKSUM_MASK as PKT_RX_L4_CKSUM_MASK, RTE_MBUF_F_RX_L4_CKSUM_NONE as PKT_RX_L4_CKSUM_NONE,
+    RTE_MBUF_F_RX_L4_CKSUM_UNKNOWN as PKT_RX_L4_CKSUM_UNKNOWN, RTE_MBUF_F_RX_LRO as PKT_RX_LRO,
+    RTE_MBUF_F_RX_OUTER_IP_CKSUM_BAD as PKT_RX_EIP_CKSUM_BAD,
+    RTE_MBUF_F_RX_OUTER_L4_CKSUM_BAD as PKT_RX_OUTER_L4_CKSUM_BAD,
+    RTE_MBUF_F_RX_OUTER_L4_CKSUM_GOOD as PKT_RX_OUTER_L4_CKSUM_GOOD,
+    RTE_MBUF_F_RX_OUTER_L4_CKSUM_INVALID as PKT_RX_OUTER_L4_CKSUM_INVALID,
+    RTE_MBUF_F_RX_OUTER_L4_CKSUM_MASK as PKT_RX_OUTER_L4_CKSUM_MASK,
+    RTE_MBUF_F_RX_OUTER_L4_CKSUM_UNKNOWN as PKT_RX_OUTER_L4_CKSUM_UNKNOWN, RTE_MBUF_F_RX_QINQ as PKT_RX_QINQ,
+    RTE_MBUF_F_RX_QINQ_STRIPPED as PKT_RX_QINQ_STRIPPED, RTE_MBUF_F_RX_RSS_HASH as PKT_RX_RSS_HASH,
+    RTE_MBUF_F_RX_SEC_OFFLOAD as PKT_RX_SEC_OFFLOAD, RTE_MBUF_F_RX_SEC_OFFLOAD_FAILED as PKT_RX_SEC_OFFLOAD_FAILED,
+    RTE_MBUF_F_RX_VLAN as PKT_RX_VLAN, RTE_MBUF_F_RX_VLAN_STRIPPED as PKT_RX_VLAN_STRIPPED,
+    RTE_MBUF_F_TX_IEEE1588_TMST as PKT_TX_IEEE1588_TMST, RTE_MBUF_F_TX_IPV4 as PKT_TX_IPV4,
+    RTE_MBUF_F_TX_IPV6 as PKT_TX_IPV6, RTE_MBUF_F_TX_IP_CKSUM as PKT_TX_IP_CKSUM,
+    RTE_MBUF_F_TX_L4_MASK as PKT_TX_L4_MASK, RTE_MBUF_F_TX_L4_NO_CKSUM as PKT_TX_L4_NO_CKSUM,
+    RTE_MBUF_F_TX_MACSEC as PKT_TX_MACSEC, RTE_MBUF_F_TX_OFFLOAD_MASK as PKT_TX_OFFLOAD_MASK,
+    RTE_MBUF_F_TX_OUTER_IPV4 as PKT_TX_OUTER_IPV4, RTE_MBUF_F_TX_OUTER_IPV6 as PKT_TX_OUTER_IPV6,
+    RTE_MBUF_F_TX_OUTER_IP_CKSUM as PKT_TX_OUTER_IP_CKSUM, RTE_MBUF_F_TX_OUTER_UDP_CKSUM as PKT_TX_OUTER_UDP_CKSUM,
+    RTE_MBUF_F_TX_QINQ as PKT_TX_QINQ, RTE_MBUF_F_TX_QINQ as PKT_TX_QINQ_PKT,
+    RTE_MBUF_F_TX_SCTP_CKSUM as PKT_TX_SCTP_CKSUM, RTE_MBUF_F_TX_SEC_OFFLOAD as PKT_TX_SEC_OFFLOAD,
+    RTE_MBUF_F_TX_TCP_CKSUM as PKT_TX_TCP_CKSUM, RTE_MBUF_F_TX_TCP_SEG as PKT_TX_TCP_SEG,
+    RTE_MBUF_F_TX_TUNNEL_GENEVE as PKT_TX_TUNNEL_GENEVE, RTE_MBUF_F_TX_TUNNEL_GRE as PKT_TX_TUNNEL_GRE,
+    RTE_MBUF_F_TX_TUNNEL_IP as PKT_TX_TUNNEL_IP, RTE_MBUF_F_TX_TUNNEL_IPIP as PKT_TX_TUNNEL_IPIP,
+    RTE_MBUF_F_TX_TUNNEL_MASK as PKT_TX_TUNNEL_MASK, RTE_MBUF_F_TX_TUNNEL_MPLSINUDP as PKT_TX_TUNNEL_MPLSINUDP,
+    RTE_MBUF_F_TX_TUNNEL_UDP as PKT_TX_TUNNEL_UDP, RTE_MBUF_F_TX_TUNNEL_VXLAN as PKT_TX_TUNNEL_VXLAN,
+    RTE_MBUF_F_TX_TUNNEL_VXLAN_GPE as PKT_TX_TUNNEL_VXLAN_GPE, RTE_MBUF_F_TX_UDP_CKSUM as PKT_TX_UDP_CKSUM,
+    RTE_MBUF_F_TX_UDP_SEG as PKT_TX_UDP_SEG, RTE_MBUF_F_TX_VLAN as PKT_TX_VLAN, RTE_MBUF_F_TX_VLAN as PKT_TX_VLAN_PKT,
+};
+
+/// The `MEMPOOL_F_*` flags were renamed to `RTE_MEMPOOL_F_*` in DPDK 21.11.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub use rte_sys::{
+    RTE_MEMPOOL_F_NO_CACHE_ALIGN as MEMPOOL_F_NO_CACHE_ALIGN, RTE_MEMPOOL_F_NO_IOVA_CONTIG as MEMPOOL_F_NO_IOVA_CONTIG,
+    RTE_MEMPOOL_F_NO_SPREAD as MEMPOOL_F_NO_SPREAD, RTE_MEMPOOL_F_POOL_CREATED as MEMPOOL_F_POOL_CREATED,
+    RTE_MEMPOOL_F_SC_GET as MEMPOOL_F_SC_GET, RTE_MEMPOOL_F_SP_PUT as MEMPOOL_F_SP_PUT,
+};
+
+/// The shared flow actions were renamed to the indirect actions in DPDK 21.05.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub use rte_sys::{
+    rte_flow_action_handle as rte_flow_shared_action, rte_flow_action_handle_create as rte_flow_shared_action_create,
+    rte_flow_action_handle_destroy as rte_flow_shared_action_destroy,
+    rte_flow_action_handle_query as rte_flow_shared_action_query,
+    rte_flow_action_handle_update as rte_flow_shared_action_update,
+    rte_flow_indir_action_conf as rte_flow_shared_action_conf,
+};
+
+/// The `SHARED` flow action was renamed to the `INDIRECT` action in DPDK 21.05.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub mod rte_flow_action_type {
+    pub use rte_sys::rte_flow_action_type::RTE_FLOW_ACTION_TYPE_INDIRECT as RTE_FLOW_ACTION_TYPE_SHARED;
+    pub use rte_sys::rte_flow_action_type::*;
+}
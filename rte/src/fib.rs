@@ -0,0 +1,298 @@
+//!
+//! RTE FIB
+//!
+//! The FIB library provides the longest prefix match of the IPv4 addresses,
+//! which stores the routes in a RIB tree and compiles them into a DIR24-8 table for the fast lookup.
+//!
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ptr::NonNull;
+
+use anyhow::{anyhow, Result};
+
+use errors::AsResult;
+use ffi;
+use memory::SocketId;
+use rib::{Rib, Rib6};
+use utils::{AsCString, AsRaw};
+
+pub use ffi::{RTE_FIB6_MAXDEPTH, RTE_FIB_MAXDEPTH};
+
+pub type RawFib = ffi::rte_fib;
+
+/// The size of the next hop in the DIR24-8 table.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NextHopSize {
+    /// 1 byte, the next hop value is up to 7 bits.
+    Byte1 = ffi::rte_fib_dir24_8_nh_sz::RTE_FIB_DIR24_8_1B,
+    /// 2 bytes, the next hop value is up to 15 bits.
+    Byte2 = ffi::rte_fib_dir24_8_nh_sz::RTE_FIB_DIR24_8_2B,
+    /// 4 bytes, the next hop value is up to 31 bits.
+    Byte4 = ffi::rte_fib_dir24_8_nh_sz::RTE_FIB_DIR24_8_4B,
+    /// 8 bytes, the next hop value is up to 63 bits.
+    Byte8 = ffi::rte_fib_dir24_8_nh_sz::RTE_FIB_DIR24_8_8B,
+}
+
+impl NextHopSize {
+    /// The maximum next hop value.
+    pub fn max_next_hop(self) -> u64 {
+        (1 << ((8 << self as u32) - 1)) - 1
+    }
+}
+
+/// The configuration of a FIB with the DIR24-8 table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FibConf {
+    /// The next hop returned on lookup if there is no route.
+    pub default_nh: u64,
+    /// The maximum number of routes.
+    pub max_routes: u32,
+    /// The size of the next hop.
+    pub nh_size: NextHopSize,
+    /// The number of the tbl8 groups, which are used by the routes longer than 24 bits.
+    pub num_tbl8: u32,
+}
+
+impl Default for FibConf {
+    fn default() -> Self {
+        FibConf {
+            default_nh: 0,
+            max_routes: 1024,
+            nh_size: NextHopSize::Byte4,
+            num_tbl8: 256,
+        }
+    }
+}
+
+/// A FIB of the IPv4 routes.
+#[derive(Debug)]
+pub struct Fib(NonNull<RawFib>);
+
+unsafe impl Send for Fib {}
+unsafe impl Sync for Fib {}
+
+impl Drop for Fib {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_fib_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Fib {
+    type Raw = RawFib;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Fib {
+    /// Create a FIB named `name` with the DIR24-8 table allocated on the NUMA socket.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, conf: &FibConf) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let mut raw_conf = ffi::rte_fib_conf {
+            type_: ffi::rte_fib_type::RTE_FIB_DIR24_8,
+            default_nh: conf.default_nh,
+            max_routes: conf.max_routes as i32,
+            ..Default::default()
+        };
+
+        raw_conf.__bindgen_anon_1.dir24_8 = ffi::rte_fib_conf__bindgen_ty_1__bindgen_ty_1 {
+            nh_sz: conf.nh_size as u32,
+            num_tbl8: conf.num_tbl8,
+        };
+
+        unsafe { ffi::rte_fib_create(name.as_ptr(), socket_id, &mut raw_conf) }
+            .as_result()
+            .map(Fib)
+    }
+
+    /// Add a route of the prefix to the FIB.
+    pub fn add(&mut self, prefix: Ipv4Addr, depth: u8, next_hop: u64) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_fib_add(self.0.as_ptr(), prefix.into(), depth, next_hop) }; err => {
+            anyhow!("fail to add route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Delete the route of the prefix from the FIB.
+    pub fn delete(&mut self, prefix: Ipv4Addr, depth: u8) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_fib_delete(self.0.as_ptr(), prefix.into(), depth) }; err => {
+            anyhow!("fail to delete route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Lookup the next hop of the IP address, return the default next hop if there is no route.
+    #[inline]
+    pub fn lookup(&self, ip: Ipv4Addr) -> u64 {
+        let mut ip = u32::from(ip);
+        let mut next_hop = 0;
+
+        unsafe {
+            ffi::rte_fib_lookup_bulk(self.0.as_ptr(), &mut ip, &mut next_hop, 1);
+        }
+
+        next_hop
+    }
+
+    /// Lookup the next hops of the IP addresses in the host byte order.
+    #[inline]
+    pub fn lookup_bulk(&self, ips: &[u32], next_hops: &mut [u64]) {
+        let n = ips.len().min(next_hops.len());
+
+        unsafe {
+            ffi::rte_fib_lookup_bulk(
+                self.0.as_ptr(),
+                ips.as_ptr() as *mut _,
+                next_hops.as_mut_ptr(),
+                n as i32,
+            );
+        }
+    }
+
+    /// The RIB of the FIB, which stores the routes.
+    pub fn rib(&self) -> Rib<'_> {
+        unsafe { Rib::from_raw(ffi::rte_fib_get_rib(self.0.as_ptr())) }.expect("RIB of FIB")
+    }
+}
+
+pub type RawFib6 = ffi::rte_fib6;
+
+/// The size of the next hop in the trie.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrieNextHopSize {
+    /// 2 bytes, the next hop value is up to 15 bits.
+    Byte2 = ffi::rte_fib_trie_nh_sz::RTE_FIB6_TRIE_2B,
+    /// 4 bytes, the next hop value is up to 31 bits.
+    Byte4 = ffi::rte_fib_trie_nh_sz::RTE_FIB6_TRIE_4B,
+    /// 8 bytes, the next hop value is up to 63 bits.
+    Byte8 = ffi::rte_fib_trie_nh_sz::RTE_FIB6_TRIE_8B,
+}
+
+impl TrieNextHopSize {
+    /// The maximum next hop value.
+    pub fn max_next_hop(self) -> u64 {
+        (1 << ((8 << self as u32) - 1)) - 1
+    }
+}
+
+/// The configuration of a FIB6 with the trie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fib6Conf {
+    /// The next hop returned on lookup if there is no route.
+    pub default_nh: u64,
+    /// The maximum number of routes.
+    pub max_routes: u32,
+    /// The size of the next hop.
+    pub nh_size: TrieNextHopSize,
+    /// The number of the tbl8 groups.
+    pub num_tbl8: u32,
+}
+
+impl Default for Fib6Conf {
+    fn default() -> Self {
+        Fib6Conf {
+            default_nh: 0,
+            max_routes: 1024,
+            nh_size: TrieNextHopSize::Byte4,
+            num_tbl8: 256,
+        }
+    }
+}
+
+/// A FIB of the IPv6 routes.
+#[derive(Debug)]
+pub struct Fib6(NonNull<RawFib6>);
+
+unsafe impl Send for Fib6 {}
+unsafe impl Sync for Fib6 {}
+
+impl Drop for Fib6 {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_fib6_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Fib6 {
+    type Raw = RawFib6;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Fib6 {
+    /// Create a FIB6 named `name` with the trie allocated on the NUMA socket.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, conf: &Fib6Conf) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let mut raw_conf = ffi::rte_fib6_conf {
+            type_: ffi::rte_fib6_type::RTE_FIB6_TRIE,
+            default_nh: conf.default_nh,
+            max_routes: conf.max_routes as i32,
+            ..Default::default()
+        };
+
+        raw_conf.__bindgen_anon_1.trie = ffi::rte_fib6_conf__bindgen_ty_1__bindgen_ty_1 {
+            nh_sz: conf.nh_size as u32,
+            num_tbl8: conf.num_tbl8,
+        };
+
+        unsafe { ffi::rte_fib6_create(name.as_ptr(), socket_id, &mut raw_conf) }
+            .as_result()
+            .map(Fib6)
+    }
+
+    /// Add a route of the prefix to the FIB6.
+    pub fn add(&mut self, prefix: Ipv6Addr, depth: u8, next_hop: u64) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_fib6_add(self.0.as_ptr(), prefix.octets().as_ptr(), depth, next_hop) }; err => {
+            anyhow!("fail to add route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Delete the route of the prefix from the FIB6.
+    pub fn delete(&mut self, prefix: Ipv6Addr, depth: u8) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_fib6_delete(self.0.as_ptr(), prefix.octets().as_ptr(), depth) }; err => {
+            anyhow!("fail to delete route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Lookup the next hop of the IP address, return the default next hop if there is no route.
+    #[inline]
+    pub fn lookup(&self, ip: Ipv6Addr) -> u64 {
+        let mut ip = ip.octets();
+        let mut next_hop = 0;
+
+        unsafe {
+            ffi::rte_fib6_lookup_bulk(self.0.as_ptr(), &mut ip, &mut next_hop, 1);
+        }
+
+        next_hop
+    }
+
+    /// Lookup the next hops of the IP addresses.
+    #[inline]
+    pub fn lookup_bulk(&self, ips: &[[u8; 16]], next_hops: &mut [u64]) {
+        let n = ips.len().min(next_hops.len());
+
+        unsafe {
+            ffi::rte_fib6_lookup_bulk(
+                self.0.as_ptr(),
+                ips.as_ptr() as *mut _,
+                next_hops.as_mut_ptr(),
+                n as i32,
+            );
+        }
+    }
+
+    /// The RIB of the FIB6, which stores the routes.
+    pub fn rib(&self) -> Rib6<'_> {
+        unsafe { Rib6::from_raw(ffi::rte_fib6_get_rib(self.0.as_ptr())) }.expect("RIB of FIB6")
+    }
+}
@@ -0,0 +1,299 @@
+//!
+//! The indirect, aging and metering flow actions.
+//!
+//! An indirect action is created once and shared by many flow rules,
+//! it can be updated or queried without touching the flow rules referencing it.
+//!
+use std::os::raw::{c_int, c_void};
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+use libc;
+
+use errors::AsResult;
+use ethdev::PortId;
+use ffi::{self, rte_flow_action_type::*};
+
+use utils::catch_panic;
+
+use super::{flow_call, flow_create, Direction, Flow, FlowCount};
+
+/// The configuration of the `AGE` action.
+///
+/// The flow rule is reported as aged out if it isn't hit for `timeout` seconds.
+#[derive(Clone, Copy)]
+pub struct Age(ffi::rte_flow_action_age);
+
+impl Age {
+    /// The maximum timeout of the aging, in seconds.
+    pub const MAX_TIMEOUT: u32 = (1 << 24) - 1;
+
+    /// Age the flow rule out after `timeout` seconds without hit.
+    ///
+    /// The `context` is returned by `aged_flows()`, the flow rule is returned if it is zero.
+    pub fn new(timeout: u32, context: usize) -> Self {
+        let mut age = ffi::rte_flow_action_age::default();
+
+        age.set_timeout(timeout.min(Self::MAX_TIMEOUT));
+        age.context = context as *mut c_void;
+
+        Age(age)
+    }
+
+    /// The action referencing the configuration, which must outlive the action.
+    pub fn action(&self) -> ffi::rte_flow_action {
+        ffi::rte_flow_action {
+            type_: RTE_FLOW_ACTION_TYPE_AGE,
+            conf: &self.0 as *const _ as *const c_void,
+        }
+    }
+}
+
+/// The aging status of a flow rule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AgeStatus {
+    /// The flow rule has aged out.
+    pub aged: bool,
+    /// The number of seconds since the last hit, if supported by the device.
+    pub sec_since_last_hit: Option<u32>,
+}
+
+impl From<ffi::rte_flow_query_age> for AgeStatus {
+    fn from(age: ffi::rte_flow_query_age) -> Self {
+        AgeStatus {
+            aged: age.aged() != 0,
+            sec_since_last_hit: if age.sec_since_last_hit_valid() != 0 {
+                Some(age.sec_since_last_hit())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// The configuration of the `METER` action.
+///
+/// The packets matched by the flow rule are policed by the meter object
+/// created with the traffic metering and policing API.
+#[derive(Clone, Copy)]
+pub struct Meter(ffi::rte_flow_action_meter);
+
+impl Meter {
+    /// Police the packets with the meter object `mtr_id` of the port.
+    pub fn new(mtr_id: u32) -> Self {
+        Meter(ffi::rte_flow_action_meter { mtr_id })
+    }
+
+    /// The ID of the meter object.
+    pub fn mtr_id(&self) -> u32 {
+        self.0.mtr_id
+    }
+
+    /// The action referencing the configuration, which must outlive the action.
+    pub fn action(&self) -> ffi::rte_flow_action {
+        ffi::rte_flow_action {
+            type_: RTE_FLOW_ACTION_TYPE_METER,
+            conf: &self.0 as *const _ as *const c_void,
+        }
+    }
+}
+
+impl Flow {
+    /// Query the aging status of the flow rule, which must have an `AGE` action.
+    pub fn query_age(&self) -> Result<AgeStatus> {
+        let action = ffi::rte_flow_action {
+            type_: RTE_FLOW_ACTION_TYPE_AGE,
+            conf: ptr::null(),
+        };
+        let mut age = ffi::rte_flow_query_age::default();
+
+        unsafe { self.query(&action, &mut age) }?;
+
+        Ok(age.into())
+    }
+}
+
+pub type RawIndirectAction = ffi::rte_flow_shared_action;
+
+/// An action shared by the flow rules of a port, known as the shared action before DPDK 21.05.
+#[derive(Debug)]
+pub struct IndirectAction {
+    port_id: PortId,
+    raw: NonNull<RawIndirectAction>,
+}
+
+unsafe impl Send for IndirectAction {}
+
+impl IndirectAction {
+    /// Create an indirect action from the action configuration.
+    pub fn create(port_id: PortId, direction: &Direction, action: &ffi::rte_flow_action) -> Result<Self> {
+        let mut conf = ffi::rte_flow_shared_action_conf::default();
+
+        conf.set_ingress(direction.ingress as u32);
+        conf.set_egress(direction.egress as u32);
+        conf.set_transfer(direction.transfer as u32);
+
+        let raw = flow_create(|err| unsafe { ffi::rte_flow_shared_action_create(port_id.0, &conf, action, err) })?;
+
+        Ok(IndirectAction { port_id, raw })
+    }
+
+    /// The port the indirect action was created on.
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    pub fn as_raw(&self) -> *mut RawIndirectAction {
+        self.raw.as_ptr()
+    }
+
+    /// The action referencing the indirect action, which can be used by the flow rules.
+    pub fn action(&self) -> ffi::rte_flow_action {
+        ffi::rte_flow_action {
+            type_: RTE_FLOW_ACTION_TYPE_SHARED,
+            conf: self.raw.as_ptr() as *const c_void,
+        }
+    }
+
+    /// Update the configuration of the indirect action in place,
+    /// the flow rules referencing it are affected at once.
+    pub fn update(&self, action: &ffi::rte_flow_action) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_shared_action_update(self.port_id.0, self.raw.as_ptr(), action, err) })
+            .map(|_| ())
+    }
+
+    /// Query the counters of an indirect `COUNT` action.
+    pub fn query_count(&self) -> Result<FlowCount> {
+        let mut count = ffi::rte_flow_query_count::default();
+
+        unsafe { self.query(&mut count) }?;
+
+        Ok(FlowCount {
+            hits: if count.hits_set() != 0 { Some(count.hits) } else { None },
+            bytes: if count.bytes_set() != 0 {
+                Some(count.bytes)
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Query the aging status of an indirect `AGE` action.
+    pub fn query_age(&self) -> Result<AgeStatus> {
+        let mut age = ffi::rte_flow_query_age::default();
+
+        unsafe { self.query(&mut age) }?;
+
+        Ok(age.into())
+    }
+
+    /// Query the data of the indirect action.
+    ///
+    /// # Safety
+    ///
+    /// The type of `data` must match the query structure of the action.
+    pub unsafe fn query<T>(&self, data: &mut T) -> Result<()> {
+        flow_call(|err| {
+            ffi::rte_flow_shared_action_query(self.port_id.0, self.raw.as_ptr(), data as *mut T as *mut c_void, err)
+        })
+        .map(|_| ())
+    }
+
+    /// Destroy the indirect action, which must not be referenced by any flow rule.
+    pub fn destroy(self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_shared_action_destroy(self.port_id.0, self.raw.as_ptr(), err) })
+            .map(|_| ())
+    }
+}
+
+/// Get the number of the flow rules aged out on the port.
+pub fn aged_flows_count(port_id: PortId) -> Result<usize> {
+    flow_call(|err| unsafe { ffi::rte_flow_get_aged_flows(port_id.0, ptr::null_mut(), 0, err) }).map(|n| n as usize)
+}
+
+/// Retrieve the contexts of the flow rules aged out on the port, return the number of contexts.
+///
+/// The context is the one of the `AGE` action, or the flow rule itself if it was zero.
+/// The aged flow rules are reported once, the application should destroy them.
+pub fn aged_flows(port_id: PortId, contexts: &mut [usize]) -> Result<usize> {
+    flow_call(|err| unsafe {
+        ffi::rte_flow_get_aged_flows(
+            port_id.0,
+            contexts.as_mut_ptr() as *mut *mut c_void,
+            contexts.len() as u32,
+            err,
+        )
+    })
+    .map(|n| n as usize)
+}
+
+type AgedCallback = Box<dyn FnMut(PortId) + Send>;
+
+unsafe extern "C" fn aged_flows_stub(
+    port_id: u16,
+    _event: ffi::rte_eth_event_type::Type,
+    arg: *mut c_void,
+    _ret_param: *mut c_void,
+) -> c_int {
+    let callback = &mut *(arg as *mut AgedCallback);
+
+    catch_panic("aged flows callback", (), || callback(PortId(port_id)));
+
+    0
+}
+
+/// The callback registered for the aged flow rules event, which is unregistered when dropped.
+pub struct AgedFlowsCallback {
+    port_id: PortId,
+    callback: *mut AgedCallback,
+}
+
+unsafe impl Send for AgedFlowsCallback {}
+
+impl Drop for AgedFlowsCallback {
+    fn drop(&mut self) {
+        loop {
+            let ret = unsafe {
+                ffi::rte_eth_dev_callback_unregister(
+                    self.port_id.0,
+                    ffi::rte_eth_event_type::RTE_ETH_EVENT_FLOW_AGED,
+                    Some(aged_flows_stub),
+                    self.callback as *mut c_void,
+                )
+            };
+
+            // the callback is executing in the interrupt thread
+            if ret != -libc::EAGAIN {
+                break;
+            }
+        }
+
+        drop(unsafe { Box::from_raw(self.callback) });
+    }
+}
+
+/// Register a callback called in the interrupt thread when new flow rules aged out on the port,
+/// the callback should retrieve them with `aged_flows()`.
+pub fn on_aged_flows<F>(port_id: PortId, callback: F) -> Result<AgedFlowsCallback>
+where
+    F: FnMut(PortId) + Send + 'static,
+{
+    let callback = Box::into_raw(Box::new(Box::new(callback) as AgedCallback));
+
+    let ret = unsafe {
+        ffi::rte_eth_dev_callback_register(
+            port_id.0,
+            ffi::rte_eth_event_type::RTE_ETH_EVENT_FLOW_AGED,
+            Some(aged_flows_stub),
+            callback as *mut c_void,
+        )
+    };
+
+    if let Err(err) = ret.as_result() {
+        drop(unsafe { Box::from_raw(callback) });
+
+        return Err(err);
+    }
+
+    Ok(AgedFlowsCallback { port_id, callback })
+}
@@ -0,0 +1,104 @@
+//!
+//! The classification results delivered by the flow rules to the received packets.
+//!
+//! The `MARK` and `FLAG` actions tag the packets in the mbuf,
+//! while the `SET_META` action stores the metadata in a dynamic field,
+//! which must be registered by `register_metadata()` before the port is started.
+//!
+use std::ptr;
+
+use anyhow::{anyhow, Result};
+
+use errors::AsResult;
+use ffi;
+use mbuf::{MBuf, OffloadFlags};
+use utils::AsRaw;
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+use ethdev::PortId;
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+bitflags! {
+    /// The RX metadata delivered from the NIC to the mbuf.
+    pub struct RxMetadata: u64 {
+        /// The `FLAG` action is delivered.
+        const USER_FLAG = ffi::RTE_ETH_RX_METADATA_USER_FLAG as u64;
+        /// The `MARK` action is delivered.
+        const USER_MARK = ffi::RTE_ETH_RX_METADATA_USER_MARK as u64;
+        /// The tunnel ID of the tunnel offload is delivered.
+        const TUNNEL_ID = ffi::RTE_ETH_RX_METADATA_TUNNEL_ID as u64;
+    }
+}
+
+/// Negotiate the RX metadata delivered from the NIC to the mbuf, return the supported subset.
+///
+/// It must be called before the port is configured.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub fn negotiate(port_id: PortId, features: RxMetadata) -> Result<RxMetadata> {
+    let mut features = features.bits;
+
+    unsafe { ffi::rte_eth_rx_metadata_negotiate(port_id.0, &mut features) }
+        .as_result()
+        .map(|_| RxMetadata::from_bits_truncate(features))
+}
+
+/// Register the dynamic field and flag of the flow metadata.
+pub fn register_metadata() -> Result<()> {
+    unsafe { ffi::rte_flow_dynf_metadata_register() }
+        .as_result()
+        .map(|_| ())
+}
+
+/// Test if the dynamic field of the flow metadata has been registered.
+pub fn metadata_registered() -> bool {
+    unsafe { ffi::rte_flow_dynf_metadata_offs >= 0 }
+}
+
+impl MBuf {
+    /// The value of the `MARK` action, if the packet was marked by a flow rule.
+    pub fn flow_mark(&self) -> Option<u32> {
+        if self
+            .offload()
+            .contains(OffloadFlags::PKT_RX_FDIR | OffloadFlags::PKT_RX_FDIR_ID)
+        {
+            Some(unsafe { self.__bindgen_anon_2.hash.fdir.hi })
+        } else {
+            None
+        }
+    }
+
+    /// Test if the packet was flagged by the `FLAG` or `MARK` action of a flow rule.
+    pub fn has_flow_flag(&self) -> bool {
+        self.offload().contains(OffloadFlags::PKT_RX_FDIR)
+    }
+
+    /// The flow metadata of the packet, if it was set by the NIC or the application.
+    pub fn flow_metadata(&self) -> Option<u32> {
+        unsafe {
+            if metadata_registered() && self.ol_flags & ffi::rte_flow_dynf_metadata_mask != 0 {
+                Some(ptr::read(self.metadata_field()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Set the flow metadata of the packet, which is matched by the `META` item on the egress.
+    pub fn set_flow_metadata(&mut self, metadata: u32) -> Result<()> {
+        if !metadata_registered() {
+            return Err(anyhow!("the flow metadata is not registered"));
+        }
+
+        unsafe {
+            ptr::write(self.metadata_field(), metadata);
+
+            self.ol_flags |= ffi::rte_flow_dynf_metadata_mask;
+        }
+
+        Ok(())
+    }
+
+    unsafe fn metadata_field(&self) -> *mut u32 {
+        (self.as_raw_mut() as *mut u8).offset(ffi::rte_flow_dynf_metadata_offs as isize) as *mut u32
+    }
+}
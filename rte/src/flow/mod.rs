@@ -0,0 +1,254 @@
+//!
+//! RTE generic flow API
+//!
+//! This interface provides the ability to program packet matching and
+//! associated actions in hardware through flow rules.
+//!
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::AsRawFd;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+use cfile;
+use num_traits::FromPrimitive;
+
+use ethdev::PortId;
+use ffi::{self, rte_flow_error_type::*};
+
+#[cfg(not(feature = "dpdk-19_11"))]
+pub mod action;
+pub mod metadata;
+pub mod rule;
+#[cfg(feature = "dpdk-22_11")]
+pub mod template;
+#[cfg(not(feature = "dpdk-19_11"))]
+pub mod tunnel;
+
+pub use self::rule::{Action, Eth, FlowRule, Ipv4, Ipv6, Item, Ports, Rss, Vlan, Vxlan};
+
+pub type RawFlow = ffi::rte_flow;
+pub type RawFlowPtr = *mut ffi::rte_flow;
+pub type RawFlowError = ffi::rte_flow_error;
+
+/// Type of the object responsible for the flow error.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum FlowErrorType {
+    /// No error.
+    None = RTE_FLOW_ERROR_TYPE_NONE,
+    /// Cause unspecified.
+    Unspecified = RTE_FLOW_ERROR_TYPE_UNSPECIFIED,
+    /// Flow rule (handle).
+    Handle = RTE_FLOW_ERROR_TYPE_HANDLE,
+    /// Group field.
+    AttrGroup = RTE_FLOW_ERROR_TYPE_ATTR_GROUP,
+    /// Priority field.
+    AttrPriority = RTE_FLOW_ERROR_TYPE_ATTR_PRIORITY,
+    /// Ingress field.
+    AttrIngress = RTE_FLOW_ERROR_TYPE_ATTR_INGRESS,
+    /// Egress field.
+    AttrEgress = RTE_FLOW_ERROR_TYPE_ATTR_EGRESS,
+    /// Transfer field.
+    AttrTransfer = RTE_FLOW_ERROR_TYPE_ATTR_TRANSFER,
+    /// Attributes structure.
+    Attr = RTE_FLOW_ERROR_TYPE_ATTR,
+    /// Pattern length.
+    ItemNum = RTE_FLOW_ERROR_TYPE_ITEM_NUM,
+    /// Item specification.
+    ItemSpec = RTE_FLOW_ERROR_TYPE_ITEM_SPEC,
+    /// Item specification range.
+    ItemLast = RTE_FLOW_ERROR_TYPE_ITEM_LAST,
+    /// Item specification mask.
+    ItemMask = RTE_FLOW_ERROR_TYPE_ITEM_MASK,
+    /// Specific pattern item.
+    Item = RTE_FLOW_ERROR_TYPE_ITEM,
+    /// Number of actions.
+    ActionNum = RTE_FLOW_ERROR_TYPE_ACTION_NUM,
+    /// Action configuration.
+    ActionConf = RTE_FLOW_ERROR_TYPE_ACTION_CONF,
+    /// Specific action.
+    Action = RTE_FLOW_ERROR_TYPE_ACTION,
+}
+
+/// The error reported by the flow API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowError {
+    /// The negative errno value returned by the flow API.
+    pub errno: i32,
+    /// Type of the object responsible for the error.
+    pub kind: FlowErrorType,
+    /// Human-readable error message.
+    pub message: Option<String>,
+}
+
+impl fmt::Display for FlowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "flow error, {} ({:?}, {})",
+            self.message.as_ref().map_or("unknown cause", String::as_str),
+            self.kind,
+            self.errno
+        )
+    }
+}
+
+impl std::error::Error for FlowError {}
+
+impl FlowError {
+    fn from_raw(errno: c_int, err: &RawFlowError) -> Self {
+        FlowError {
+            errno,
+            kind: FlowErrorType::from_u32(err.type_).unwrap_or(FlowErrorType::Unspecified),
+            message: if err.message.is_null() {
+                None
+            } else {
+                Some(unsafe { CStr::from_ptr(err.message) }.to_string_lossy().into_owned())
+            },
+        }
+    }
+}
+
+/// Call the flow API with an error structure, and convert the failure to `FlowError`.
+pub(crate) fn flow_call<F>(f: F) -> Result<c_int>
+where
+    F: FnOnce(*mut RawFlowError) -> c_int,
+{
+    let mut err: RawFlowError = unsafe { mem::zeroed() };
+    let ret = f(&mut err);
+
+    if ret < 0 {
+        Err(FlowError::from_raw(ret, &err).into())
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Call the flow API creating an object, and convert the failure to `FlowError`.
+pub(crate) fn flow_create<T, F>(f: F) -> Result<NonNull<T>>
+where
+    F: FnOnce(*mut RawFlowError) -> *mut T,
+{
+    let mut err: RawFlowError = unsafe { mem::zeroed() };
+
+    NonNull::new(f(&mut err)).ok_or_else(|| FlowError::from_raw(-ffi::rte_errno(), &err).into())
+}
+
+/// The direction of the traffic a flow object applies to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Direction {
+    /// Valid for the ingress traffic.
+    pub ingress: bool,
+    /// Valid for the egress traffic.
+    pub egress: bool,
+    /// Valid for the transfer traffic.
+    pub transfer: bool,
+}
+
+/// The counters of a flow rule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FlowCount {
+    /// Number of hits for this rule, if supported by the device.
+    pub hits: Option<u64>,
+    /// Number of bytes through this rule, if supported by the device.
+    pub bytes: Option<u64>,
+}
+
+/// A flow rule created on a port.
+#[derive(Debug)]
+pub struct Flow {
+    port_id: PortId,
+    raw: NonNull<RawFlow>,
+}
+
+unsafe impl Send for Flow {}
+
+impl Flow {
+    /// Wrap a flow rule created on the port.
+    ///
+    /// # Safety
+    ///
+    /// The flow rule must be created on the port and not destroyed yet.
+    pub unsafe fn from_raw(port_id: PortId, raw: RawFlowPtr) -> Option<Self> {
+        NonNull::new(raw).map(|raw| Flow { port_id, raw })
+    }
+
+    /// The port the flow rule was created on.
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    pub fn as_raw(&self) -> RawFlowPtr {
+        self.raw.as_ptr()
+    }
+
+    /// Query the counters of the flow rule, which must have a `COUNT` action.
+    ///
+    /// The counters are reset after they are retrieved if `reset` is true.
+    pub fn query_count(&self, reset: bool) -> Result<FlowCount> {
+        let action = ffi::rte_flow_action {
+            type_: ffi::rte_flow_action_type::RTE_FLOW_ACTION_TYPE_COUNT,
+            conf: ptr::null(),
+        };
+        let mut count = ffi::rte_flow_query_count::default();
+
+        count.set_reset(reset as u32);
+
+        unsafe { self.query(&action, &mut count) }?;
+
+        Ok(FlowCount {
+            hits: if count.hits_set() != 0 { Some(count.hits) } else { None },
+            bytes: if count.bytes_set() != 0 {
+                Some(count.bytes)
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Query the data of an action of the flow rule.
+    ///
+    /// # Safety
+    ///
+    /// The type of `data` must match the query structure of the action.
+    pub unsafe fn query<T>(&self, action: &ffi::rte_flow_action, data: &mut T) -> Result<()> {
+        flow_call(|err| {
+            ffi::rte_flow_query(
+                self.port_id.0,
+                self.raw.as_ptr(),
+                action,
+                data as *mut T as *mut c_void,
+                err,
+            )
+        })
+        .map(|_| ())
+    }
+
+    /// Destroy the flow rule.
+    pub fn destroy(self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_destroy(self.port_id.0, self.raw.as_ptr(), err) }).map(|_| ())
+    }
+}
+
+/// Destroy all the flow rules associated with a port.
+pub fn flush(port_id: PortId) -> Result<()> {
+    flow_call(|err| unsafe { ffi::rte_flow_flush(port_id.0, err) }).map(|_| ())
+}
+
+/// Restrict ingress traffic to the defined flow rules.
+///
+/// Isolated mode should be set before the port is configured or started,
+/// since the device may not support changing it at runtime.
+pub fn isolate(port_id: PortId, enable: bool) -> Result<()> {
+    flow_call(|err| unsafe { ffi::rte_flow_isolate(port_id.0, enable as c_int, err) }).map(|_| ())
+}
+
+/// Dump the internal representation of the hardware flow rules of a port.
+pub fn dump<S: AsRawFd>(port_id: PortId, s: &S) -> Result<()> {
+    let mut f = cfile::fdopen(s, "w")?;
+
+    flow_call(|err| unsafe { ffi::rte_flow_dev_dump(port_id.0, &mut **f as *mut _ as *mut _, err) }).map(|_| ())
+}
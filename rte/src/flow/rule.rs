@@ -0,0 +1,587 @@
+//!
+//! The typed flow rules.
+//!
+//! A flow rule is built from the typed pattern items and actions,
+//! the C structures and unions are constructed when the rule is validated or created,
+//! so the pointers passed to the device always reference the rule itself.
+//!
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::os::raw::c_void;
+use std::ptr;
+
+use anyhow::Result;
+
+use ethdev::{PortId, QueueId, RssHashFunc};
+use ether::EtherAddr;
+use ffi::{self, rte_flow_action_type::*, rte_flow_item_type::*};
+
+use super::{flow_call, flow_create, Direction, Flow};
+
+/// Match the Ethernet header, the unspecified fields match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Eth {
+    /// The destination MAC address.
+    pub dst: Option<EtherAddr>,
+    /// The source MAC address.
+    pub src: Option<EtherAddr>,
+    /// The EtherType, in host byte order.
+    pub ether_type: Option<u16>,
+}
+
+/// Match the VLAN tag, the unspecified fields match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vlan {
+    /// The VLAN ID in the lower 12 bits of the tag control information.
+    pub vlan_id: Option<u16>,
+    /// The inner EtherType, in host byte order.
+    pub inner_type: Option<u16>,
+}
+
+/// Match the IPv4 header, the unspecified fields match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ipv4 {
+    /// The source address and its prefix length.
+    pub src: Option<(Ipv4Addr, u8)>,
+    /// The destination address and its prefix length.
+    pub dst: Option<(Ipv4Addr, u8)>,
+    /// The next protocol ID, e.g. `IPPROTO_TCP`.
+    pub proto: Option<u8>,
+}
+
+/// Match the IPv6 header, the unspecified fields match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ipv6 {
+    /// The source address and its prefix length.
+    pub src: Option<(Ipv6Addr, u8)>,
+    /// The destination address and its prefix length.
+    pub dst: Option<(Ipv6Addr, u8)>,
+    /// The next header, e.g. `IPPROTO_UDP`.
+    pub proto: Option<u8>,
+}
+
+/// Match the TCP or UDP ports, the unspecified ports match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ports {
+    /// The source port, in host byte order.
+    pub src: Option<u16>,
+    /// The destination port, in host byte order.
+    pub dst: Option<u16>,
+}
+
+/// Match the VXLAN header, the unspecified fields match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vxlan {
+    /// The 24 bits VXLAN network identifier.
+    pub vni: Option<u32>,
+}
+
+/// An item of the flow pattern, which owns its specification and mask.
+#[derive(Clone, Copy, Debug)]
+pub enum Item {
+    Eth(ffi::rte_flow_item_eth, ffi::rte_flow_item_eth),
+    Vlan(ffi::rte_flow_item_vlan, ffi::rte_flow_item_vlan),
+    Ipv4(ffi::rte_flow_item_ipv4, ffi::rte_flow_item_ipv4),
+    Ipv6(ffi::rte_flow_item_ipv6, ffi::rte_flow_item_ipv6),
+    Tcp(ffi::rte_flow_item_tcp, ffi::rte_flow_item_tcp),
+    Udp(ffi::rte_flow_item_udp, ffi::rte_flow_item_udp),
+    Vxlan(ffi::rte_flow_item_vxlan, ffi::rte_flow_item_vxlan),
+}
+
+fn prefix_mask(len: u8, bits: u8) -> u128 {
+    match len.min(bits) {
+        0 => 0,
+        len => !0u128 << (128 - u32::from(len)),
+    }
+}
+
+impl From<Eth> for Item {
+    fn from(eth: Eth) -> Self {
+        let mut spec = ffi::rte_flow_item_eth::default();
+        let mut mask = ffi::rte_flow_item_eth::default();
+
+        if let Some(addr) = eth.dst {
+            spec.dst.addr_bytes = *addr.octets();
+            mask.dst.addr_bytes = [0xFF; 6];
+        }
+        if let Some(addr) = eth.src {
+            spec.src.addr_bytes = *addr.octets();
+            mask.src.addr_bytes = [0xFF; 6];
+        }
+        if let Some(ether_type) = eth.ether_type {
+            spec.type_ = ether_type.to_be();
+            mask.type_ = 0xFFFF;
+        }
+
+        Item::Eth(spec, mask)
+    }
+}
+
+impl From<Vlan> for Item {
+    fn from(vlan: Vlan) -> Self {
+        let mut spec = ffi::rte_flow_item_vlan::default();
+        let mut mask = ffi::rte_flow_item_vlan::default();
+
+        if let Some(vlan_id) = vlan.vlan_id {
+            spec.tci = (vlan_id & 0x0FFF).to_be();
+            mask.tci = 0x0FFFu16.to_be();
+        }
+        if let Some(inner_type) = vlan.inner_type {
+            spec.inner_type = inner_type.to_be();
+            mask.inner_type = 0xFFFF;
+        }
+
+        Item::Vlan(spec, mask)
+    }
+}
+
+impl From<Ipv4> for Item {
+    fn from(ip: Ipv4) -> Self {
+        let mut spec = ffi::rte_flow_item_ipv4::default();
+        let mut mask = ffi::rte_flow_item_ipv4::default();
+
+        if let Some((addr, len)) = ip.src {
+            spec.hdr.src_addr = u32::from(addr).to_be();
+            mask.hdr.src_addr = ((prefix_mask(len, 32) >> 96) as u32).to_be();
+        }
+        if let Some((addr, len)) = ip.dst {
+            spec.hdr.dst_addr = u32::from(addr).to_be();
+            mask.hdr.dst_addr = ((prefix_mask(len, 32) >> 96) as u32).to_be();
+        }
+        if let Some(proto) = ip.proto {
+            spec.hdr.next_proto_id = proto;
+            mask.hdr.next_proto_id = 0xFF;
+        }
+
+        Item::Ipv4(spec, mask)
+    }
+}
+
+impl From<Ipv6> for Item {
+    fn from(ip: Ipv6) -> Self {
+        let mut spec = ffi::rte_flow_item_ipv6::default();
+        let mut mask = ffi::rte_flow_item_ipv6::default();
+
+        if let Some((addr, len)) = ip.src {
+            spec.hdr.src_addr = addr.octets();
+            mask.hdr.src_addr = prefix_mask(len, 128).to_be_bytes();
+        }
+        if let Some((addr, len)) = ip.dst {
+            spec.hdr.dst_addr = addr.octets();
+            mask.hdr.dst_addr = prefix_mask(len, 128).to_be_bytes();
+        }
+        if let Some(proto) = ip.proto {
+            spec.hdr.proto = proto;
+            mask.hdr.proto = 0xFF;
+        }
+
+        Item::Ipv6(spec, mask)
+    }
+}
+
+impl From<Vxlan> for Item {
+    fn from(vxlan: Vxlan) -> Self {
+        let mut spec = ffi::rte_flow_item_vxlan::default();
+        let mut mask = ffi::rte_flow_item_vxlan::default();
+
+        if let Some(vni) = vxlan.vni {
+            spec.vni.copy_from_slice(&vni.to_be_bytes()[1..]);
+            mask.vni = [0xFF; 3];
+        }
+
+        Item::Vxlan(spec, mask)
+    }
+}
+
+impl Item {
+    /// Match the TCP ports.
+    pub fn tcp(ports: Ports) -> Self {
+        let mut spec = ffi::rte_flow_item_tcp::default();
+        let mut mask = ffi::rte_flow_item_tcp::default();
+
+        if let Some(port) = ports.src {
+            spec.hdr.src_port = port.to_be();
+            mask.hdr.src_port = 0xFFFF;
+        }
+        if let Some(port) = ports.dst {
+            spec.hdr.dst_port = port.to_be();
+            mask.hdr.dst_port = 0xFFFF;
+        }
+
+        Item::Tcp(spec, mask)
+    }
+
+    /// Match the UDP ports.
+    pub fn udp(ports: Ports) -> Self {
+        let mut spec = ffi::rte_flow_item_udp::default();
+        let mut mask = ffi::rte_flow_item_udp::default();
+
+        if let Some(port) = ports.src {
+            spec.hdr.src_port = port.to_be();
+            mask.hdr.src_port = 0xFFFF;
+        }
+        if let Some(port) = ports.dst {
+            spec.hdr.dst_port = port.to_be();
+            mask.hdr.dst_port = 0xFFFF;
+        }
+
+        Item::Udp(spec, mask)
+    }
+
+    /// The raw item referencing the specification and mask, which must outlive the raw item.
+    pub fn as_raw(&self) -> ffi::rte_flow_item {
+        fn raw<T>(type_: ffi::rte_flow_item_type::Type, spec: &T, mask: &T) -> ffi::rte_flow_item {
+            ffi::rte_flow_item {
+                type_,
+                spec: spec as *const T as *const c_void,
+                last: ptr::null(),
+                mask: mask as *const T as *const c_void,
+            }
+        }
+
+        match *self {
+            Item::Eth(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_ETH, spec, mask),
+            Item::Vlan(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_VLAN, spec, mask),
+            Item::Ipv4(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_IPV4, spec, mask),
+            Item::Ipv6(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_IPV6, spec, mask),
+            Item::Tcp(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_TCP, spec, mask),
+            Item::Udp(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_UDP, spec, mask),
+            Item::Vxlan(ref spec, ref mask) => raw(RTE_FLOW_ITEM_TYPE_VXLAN, spec, mask),
+        }
+    }
+}
+
+/// The RSS action spreading the matched packets over the queues.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rss {
+    /// The RSS hash types, the device default is used if empty.
+    pub types: RssHashFunc,
+    /// The encapsulation level the hash types apply to, zero for the default behavior.
+    pub level: u32,
+    /// The RSS hash key, the device default is used if empty.
+    pub key: Vec<u8>,
+    /// The queues to spread the packets over.
+    pub queues: Vec<QueueId>,
+}
+
+impl Default for Rss {
+    fn default() -> Self {
+        Rss {
+            types: RssHashFunc::ETH_RSS_UNKNOWN,
+            level: 0,
+            key: vec![],
+            queues: vec![],
+        }
+    }
+}
+
+/// An action of the flow rule.
+#[derive(Debug)]
+pub enum Action {
+    /// Assign the packets to a queue.
+    Queue(ffi::rte_flow_action_queue),
+    /// Spread the packets over the queues.
+    Rss(Box<Rss>, ffi::rte_flow_action_rss),
+    /// Drop the packets.
+    Drop,
+    /// Attach a value to the packets, which is returned by `MBuf::flow_mark()`.
+    Mark(ffi::rte_flow_action_mark),
+    /// Count the packets, which is queried by `Flow::query_count()`.
+    Count(ffi::rte_flow_action_count),
+}
+
+impl Clone for Action {
+    fn clone(&self) -> Self {
+        match *self {
+            // the configuration references the queues and key of its own `Rss`
+            Action::Rss(ref rss, _) => Action::rss((**rss).clone()),
+            Action::Queue(conf) => Action::Queue(conf),
+            Action::Drop => Action::Drop,
+            Action::Mark(conf) => Action::Mark(conf),
+            Action::Count(conf) => Action::Count(conf),
+        }
+    }
+}
+
+impl Action {
+    /// Assign the packets to the queue.
+    pub fn queue(index: QueueId) -> Self {
+        Action::Queue(ffi::rte_flow_action_queue { index })
+    }
+
+    /// Spread the packets over the queues.
+    pub fn rss(rss: Rss) -> Self {
+        let rss = Box::new(rss);
+        let conf = ffi::rte_flow_action_rss {
+            func: ffi::rte_eth_hash_function::RTE_ETH_HASH_FUNCTION_DEFAULT,
+            level: rss.level,
+            types: rss.types.bits(),
+            key_len: rss.key.len() as u32,
+            queue_num: rss.queues.len() as u32,
+            key: if rss.key.is_empty() {
+                ptr::null()
+            } else {
+                rss.key.as_ptr()
+            },
+            queue: rss.queues.as_ptr(),
+        };
+
+        Action::Rss(rss, conf)
+    }
+
+    /// Drop the packets.
+    pub fn drop() -> Self {
+        Action::Drop
+    }
+
+    /// Attach the value to the packets.
+    pub fn mark(id: u32) -> Self {
+        Action::Mark(ffi::rte_flow_action_mark { id })
+    }
+
+    /// Count the packets.
+    pub fn count() -> Self {
+        Action::Count(ffi::rte_flow_action_count::default())
+    }
+
+    /// The raw action referencing the configuration, which must outlive the raw action.
+    pub fn as_raw(&self) -> ffi::rte_flow_action {
+        fn raw<T>(type_: ffi::rte_flow_action_type::Type, conf: &T) -> ffi::rte_flow_action {
+            ffi::rte_flow_action {
+                type_,
+                conf: conf as *const T as *const c_void,
+            }
+        }
+
+        match *self {
+            Action::Queue(ref conf) => raw(RTE_FLOW_ACTION_TYPE_QUEUE, conf),
+            Action::Rss(_, ref conf) => raw(RTE_FLOW_ACTION_TYPE_RSS, conf),
+            Action::Drop => ffi::rte_flow_action {
+                type_: RTE_FLOW_ACTION_TYPE_DROP,
+                conf: ptr::null(),
+            },
+            Action::Mark(ref conf) => raw(RTE_FLOW_ACTION_TYPE_MARK, conf),
+            Action::Count(ref conf) => raw(RTE_FLOW_ACTION_TYPE_COUNT, conf),
+        }
+    }
+}
+
+/// A flow rule built from the typed pattern items and actions.
+#[derive(Clone, Debug, Default)]
+pub struct FlowRule {
+    /// The direction of the traffic the rule applies to.
+    pub direction: Direction,
+    /// The priority group of the rule.
+    pub group: u32,
+    /// The priority level of the rule within the group, lower values denote higher priority.
+    pub priority: u32,
+    /// The pattern items, from the outermost header.
+    pub pattern: Vec<Item>,
+    /// The actions applied to the matched packets.
+    pub actions: Vec<Action>,
+}
+
+impl FlowRule {
+    /// A rule for the ingress traffic.
+    pub fn ingress() -> Self {
+        FlowRule {
+            direction: Direction {
+                ingress: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// A rule for the egress traffic.
+    pub fn egress() -> Self {
+        FlowRule {
+            direction: Direction {
+                egress: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Set the priority group of the rule.
+    pub fn group(mut self, group: u32) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Set the priority level of the rule.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Append an item to the pattern.
+    pub fn item<I: Into<Item>>(mut self, item: I) -> Self {
+        self.pattern.push(item.into());
+        self
+    }
+
+    /// Append an action to the rule.
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    fn with_raw<T, F>(&self, f: F) -> T
+    where
+        F: FnOnce(&ffi::rte_flow_attr, &[ffi::rte_flow_item], &[ffi::rte_flow_action]) -> T,
+    {
+        let mut attr = ffi::rte_flow_attr {
+            group: self.group,
+            priority: self.priority,
+            ..Default::default()
+        };
+
+        attr.set_ingress(self.direction.ingress as u32);
+        attr.set_egress(self.direction.egress as u32);
+        attr.set_transfer(self.direction.transfer as u32);
+
+        let pattern = self
+            .pattern
+            .iter()
+            .map(Item::as_raw)
+            .chain(Some(ffi::rte_flow_item {
+                type_: RTE_FLOW_ITEM_TYPE_END,
+                spec: ptr::null(),
+                last: ptr::null(),
+                mask: ptr::null(),
+            }))
+            .collect::<Vec<_>>();
+        let actions = self
+            .actions
+            .iter()
+            .map(Action::as_raw)
+            .chain(Some(ffi::rte_flow_action {
+                type_: RTE_FLOW_ACTION_TYPE_END,
+                conf: ptr::null(),
+            }))
+            .collect::<Vec<_>>();
+
+        f(&attr, &pattern, &actions)
+    }
+
+    /// Check whether the rule is valid and could be created on the port.
+    pub fn validate(&self, port_id: PortId) -> Result<()> {
+        self.with_raw(|attr, pattern, actions| {
+            flow_call(|err| unsafe { ffi::rte_flow_validate(port_id.0, attr, pattern.as_ptr(), actions.as_ptr(), err) })
+        })
+        .map(|_| ())
+    }
+
+    /// Create the rule on the port.
+    pub fn create(&self, port_id: PortId) -> Result<Flow> {
+        let raw = self.with_raw(|attr, pattern, actions| {
+            flow_create(|err| unsafe { ffi::rte_flow_create(port_id.0, attr, pattern.as_ptr(), actions.as_ptr(), err) })
+        })?;
+
+        Ok(Flow { port_id, raw })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        match Item::from(Ipv4 {
+            dst: Some((Ipv4Addr::new(192, 168, 1, 0), 24)),
+            proto: Some(17),
+            ..Default::default()
+        }) {
+            Item::Ipv4(spec, mask) => {
+                assert_eq!(u32::from_be(spec.hdr.dst_addr), 0xC0A8_0100);
+                assert_eq!(u32::from_be(mask.hdr.dst_addr), 0xFFFF_FF00);
+                let src_addr = { mask.hdr.src_addr };
+                assert_eq!(src_addr, 0);
+                assert_eq!(spec.hdr.next_proto_id, 17);
+            }
+            _ => unreachable!(),
+        }
+
+        match Item::from(Ipv6 {
+            src: Some(("2001:db8::".parse().unwrap(), 32)),
+            ..Default::default()
+        }) {
+            Item::Ipv6(_, mask) => {
+                assert_eq!(&mask.hdr.src_addr[..5], &[0xFF, 0xFF, 0xFF, 0xFF, 0]);
+                assert_eq!(mask.hdr.dst_addr, [0; 16]);
+            }
+            _ => unreachable!(),
+        }
+
+        match Item::from(Vxlan { vni: Some(0x123456) }) {
+            Item::Vxlan(spec, mask) => {
+                assert_eq!(spec.vni, [0x12, 0x34, 0x56]);
+                assert_eq!(mask.vni, [0xFF; 3]);
+            }
+            _ => unreachable!(),
+        }
+
+        match Item::udp(Ports {
+            dst: Some(4789),
+            ..Default::default()
+        }) {
+            Item::Udp(spec, mask) => {
+                assert_eq!(u16::from_be(spec.hdr.dst_port), 4789);
+                let src_port = { mask.hdr.src_port };
+                assert_eq!(src_port, 0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = FlowRule::ingress()
+            .priority(1)
+            .item(Eth::default())
+            .item(Ipv4::default())
+            .item(Item::tcp(Ports {
+                dst: Some(80),
+                ..Default::default()
+            }))
+            .action(Action::rss(Rss {
+                queues: vec![0, 1, 2, 3],
+                ..Default::default()
+            }))
+            .action(Action::mark(42))
+            .action(Action::count());
+
+        rule.with_raw(|attr, pattern, actions| {
+            assert_eq!(attr.ingress(), 1);
+            assert_eq!(attr.egress(), 0);
+            assert_eq!(attr.priority, 1);
+
+            assert_eq!(
+                pattern.iter().map(|item| item.type_).collect::<Vec<_>>(),
+                vec![
+                    RTE_FLOW_ITEM_TYPE_ETH,
+                    RTE_FLOW_ITEM_TYPE_IPV4,
+                    RTE_FLOW_ITEM_TYPE_TCP,
+                    RTE_FLOW_ITEM_TYPE_END
+                ]
+            );
+            assert_eq!(
+                actions.iter().map(|action| action.type_).collect::<Vec<_>>(),
+                vec![
+                    RTE_FLOW_ACTION_TYPE_RSS,
+                    RTE_FLOW_ACTION_TYPE_MARK,
+                    RTE_FLOW_ACTION_TYPE_COUNT,
+                    RTE_FLOW_ACTION_TYPE_END
+                ]
+            );
+
+            let rss = unsafe { &*(actions[0].conf as *const ffi::rte_flow_action_rss) };
+
+            assert_eq!(rss.queue_num, 4);
+            assert_eq!(unsafe { *rss.queue.add(3) }, 3);
+            assert!(rss.key.is_null());
+        });
+    }
+}
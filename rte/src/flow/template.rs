@@ -0,0 +1,335 @@
+//!
+//! The template-based asynchronous flow API, available since DPDK 22.03.
+//!
+//! The port is pre-configured with the flow queues and the resources,
+//! the flow rules are then created from the template tables through a flow queue,
+//! the operations are pushed to the hardware in batches and their results are pulled later.
+//!
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+
+use anyhow::{anyhow, Result};
+
+use ethdev::PortId;
+use ffi;
+use utils::NotSync;
+
+use super::{flow_call, flow_create, Direction, Flow};
+
+/// The resources pre-allocated by the port for the asynchronous flow rules.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PortAttr {
+    /// Number of counter actions pre-configured.
+    pub nb_counters: u32,
+    /// Number of aging objects pre-configured.
+    pub nb_aging_objects: u32,
+    /// Number of traffic metering actions pre-configured.
+    pub nb_meters: u32,
+}
+
+/// Configure the flow management resources and the flow queues of a port.
+///
+/// It must be called before the port is started.
+pub fn configure(port_id: PortId, attr: &PortAttr, nb_queues: u16, queue_size: u32) -> Result<()> {
+    let port_attr = ffi::rte_flow_port_attr {
+        nb_counters: attr.nb_counters,
+        nb_aging_objects: attr.nb_aging_objects,
+        nb_meters: attr.nb_meters,
+        ..Default::default()
+    };
+    let queue_attr = ffi::rte_flow_queue_attr { size: queue_size };
+    let mut queue_attrs = vec![&queue_attr as *const ffi::rte_flow_queue_attr; nb_queues as usize];
+
+    flow_call(|err| unsafe { ffi::rte_flow_configure(port_id.0, &port_attr, nb_queues, queue_attrs.as_mut_ptr(), err) })
+        .map(|_| ())
+}
+
+fn check_end<T, F: Fn(&T) -> bool>(what: &str, items: &[T], is_end: F) -> Result<()> {
+    if items.last().map_or(false, is_end) {
+        Ok(())
+    } else {
+        Err(anyhow!("the {} must be terminated by an END entry", what))
+    }
+}
+
+/// A template of the flow patterns, with the masks of the fields to match.
+#[derive(Debug)]
+pub struct PatternTemplate {
+    port_id: PortId,
+    raw: NonNull<ffi::rte_flow_pattern_template>,
+}
+
+impl PatternTemplate {
+    /// Create a pattern template, only the masks of the items are used.
+    pub fn create(
+        port_id: PortId,
+        attr: &Direction,
+        relaxed_matching: bool,
+        pattern: &[ffi::rte_flow_item],
+    ) -> Result<Self> {
+        check_end("pattern", pattern, |item| {
+            item.type_ == ffi::rte_flow_item_type::RTE_FLOW_ITEM_TYPE_END
+        })?;
+
+        let mut template_attr = ffi::rte_flow_pattern_template_attr::default();
+
+        template_attr.set_relaxed_matching(relaxed_matching as u32);
+        template_attr.set_ingress(attr.ingress as u32);
+        template_attr.set_egress(attr.egress as u32);
+        template_attr.set_transfer(attr.transfer as u32);
+
+        let raw = flow_create(|err| unsafe {
+            ffi::rte_flow_pattern_template_create(port_id.0, &template_attr, pattern.as_ptr(), err)
+        })?;
+
+        Ok(PatternTemplate { port_id, raw })
+    }
+
+    pub fn as_raw(&self) -> *mut ffi::rte_flow_pattern_template {
+        self.raw.as_ptr()
+    }
+
+    /// Destroy the pattern template, which must not be used by any template table.
+    pub fn destroy(self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_pattern_template_destroy(self.port_id.0, self.raw.as_ptr(), err) })
+            .map(|_| ())
+    }
+}
+
+/// A template of the flow actions, the masked action configurations are shared by all the flow rules.
+#[derive(Debug)]
+pub struct ActionsTemplate {
+    port_id: PortId,
+    raw: NonNull<ffi::rte_flow_actions_template>,
+}
+
+impl ActionsTemplate {
+    /// Create an actions template.
+    ///
+    /// The configuration of an action is constant if its mask is set,
+    /// otherwise it is provided when the flow rule is created.
+    pub fn create(
+        port_id: PortId,
+        attr: &Direction,
+        actions: &[ffi::rte_flow_action],
+        masks: &[ffi::rte_flow_action],
+    ) -> Result<Self> {
+        let is_end =
+            |action: &ffi::rte_flow_action| action.type_ == ffi::rte_flow_action_type::RTE_FLOW_ACTION_TYPE_END;
+
+        check_end("actions", actions, is_end)?;
+        check_end("masks", masks, is_end)?;
+
+        if actions.len() != masks.len() {
+            return Err(anyhow!("the actions and the masks have different lengths"));
+        }
+
+        let mut template_attr = ffi::rte_flow_actions_template_attr::default();
+
+        template_attr.set_ingress(attr.ingress as u32);
+        template_attr.set_egress(attr.egress as u32);
+        template_attr.set_transfer(attr.transfer as u32);
+
+        let raw = flow_create(|err| unsafe {
+            ffi::rte_flow_actions_template_create(port_id.0, &template_attr, actions.as_ptr(), masks.as_ptr(), err)
+        })?;
+
+        Ok(ActionsTemplate { port_id, raw })
+    }
+
+    pub fn as_raw(&self) -> *mut ffi::rte_flow_actions_template {
+        self.raw.as_ptr()
+    }
+
+    /// Destroy the actions template, which must not be used by any template table.
+    pub fn destroy(self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_actions_template_destroy(self.port_id.0, self.raw.as_ptr(), err) })
+            .map(|_| ())
+    }
+}
+
+/// The attributes of a template table.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TableAttr {
+    /// Priority group.
+    pub group: u32,
+    /// Rule priority level within group.
+    pub priority: u32,
+    /// The direction of the traffic.
+    pub direction: Direction,
+    /// Maximum number of flow rules that this table holds.
+    pub nb_flows: u32,
+}
+
+/// A table of the flow rules sharing the same pattern and actions templates.
+#[derive(Debug)]
+pub struct TemplateTable {
+    port_id: PortId,
+    raw: NonNull<ffi::rte_flow_template_table>,
+}
+
+impl TemplateTable {
+    /// Create a template table from the pattern and actions templates.
+    pub fn create(
+        port_id: PortId,
+        attr: &TableAttr,
+        patterns: &[&PatternTemplate],
+        actions: &[&ActionsTemplate],
+    ) -> Result<Self> {
+        let mut flow_attr = ffi::rte_flow_attr {
+            group: attr.group,
+            priority: attr.priority,
+            ..Default::default()
+        };
+
+        flow_attr.set_ingress(attr.direction.ingress as u32);
+        flow_attr.set_egress(attr.direction.egress as u32);
+        flow_attr.set_transfer(attr.direction.transfer as u32);
+
+        let table_attr = ffi::rte_flow_template_table_attr {
+            flow_attr,
+            nb_flows: attr.nb_flows,
+            ..Default::default()
+        };
+        let mut patterns = patterns.iter().map(|t| t.as_raw()).collect::<Vec<_>>();
+        let mut actions = actions.iter().map(|t| t.as_raw()).collect::<Vec<_>>();
+        let raw = flow_create(|err| unsafe {
+            ffi::rte_flow_template_table_create(
+                port_id.0,
+                &table_attr,
+                patterns.as_mut_ptr(),
+                patterns.len() as u8,
+                actions.as_mut_ptr(),
+                actions.len() as u8,
+                err,
+            )
+        })?;
+
+        Ok(TemplateTable { port_id, raw })
+    }
+
+    pub fn as_raw(&self) -> *mut ffi::rte_flow_template_table {
+        self.raw.as_ptr()
+    }
+
+    /// Destroy the template table, which must not hold any flow rule.
+    pub fn destroy(self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_template_table_destroy(self.port_id.0, self.raw.as_ptr(), err) })
+            .map(|_| ())
+    }
+}
+
+/// The result of an asynchronous flow operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpResult {
+    /// The operation was successfully completed.
+    pub success: bool,
+    /// The user data passed to the operation.
+    pub user_data: usize,
+}
+
+/// A flow queue of the port, which should only be used by a single lcore.
+#[derive(Debug)]
+pub struct FlowQueue {
+    port_id: PortId,
+    queue_id: u32,
+    _marker: NotSync,
+}
+
+impl FlowQueue {
+    /// Use a flow queue configured by `configure()`.
+    pub fn new(port_id: PortId, queue_id: u32) -> Self {
+        FlowQueue {
+            port_id,
+            queue_id,
+            _marker: NotSync::default(),
+        }
+    }
+
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    pub fn queue_id(&self) -> u32 {
+        self.queue_id
+    }
+
+    /// Enqueue a flow rule creation operation.
+    ///
+    /// The rule is created from the templates at the given indexes of the table,
+    /// the operation is kept in the queue until `push()` is called if `postpone` is set.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        &mut self,
+        table: &TemplateTable,
+        pattern: &[ffi::rte_flow_item],
+        pattern_template_index: u8,
+        actions: &[ffi::rte_flow_action],
+        actions_template_index: u8,
+        user_data: usize,
+        postpone: bool,
+    ) -> Result<Flow> {
+        let mut op_attr = ffi::rte_flow_op_attr::default();
+
+        op_attr.set_postpone(postpone as u32);
+
+        let raw = flow_create(|err| unsafe {
+            ffi::rte_flow_async_create(
+                self.port_id.0,
+                self.queue_id,
+                &op_attr,
+                table.as_raw(),
+                pattern.as_ptr(),
+                pattern_template_index,
+                actions.as_ptr(),
+                actions_template_index,
+                user_data as *mut c_void,
+                err,
+            )
+        })?;
+
+        Ok(unsafe { Flow::from_raw(self.port_id, raw.as_ptr()) }.unwrap())
+    }
+
+    /// Enqueue a flow rule destruction operation.
+    pub fn destroy(&mut self, flow: Flow, user_data: usize, postpone: bool) -> Result<()> {
+        let mut op_attr = ffi::rte_flow_op_attr::default();
+
+        op_attr.set_postpone(postpone as u32);
+
+        flow_call(|err| unsafe {
+            ffi::rte_flow_async_destroy(
+                self.port_id.0,
+                self.queue_id,
+                &op_attr,
+                flow.as_raw(),
+                user_data as *mut c_void,
+                err,
+            )
+        })
+        .map(|_| ())
+    }
+
+    /// Push all the postponed operations to the hardware.
+    pub fn push(&mut self) -> Result<()> {
+        flow_call(|err| unsafe { ffi::rte_flow_push(self.port_id.0, self.queue_id, err) }).map(|_| ())
+    }
+
+    /// Pull the results of the completed operations, return the number of results.
+    pub fn pull(&mut self, results: &mut [OpResult]) -> Result<usize> {
+        let mut raw = vec![ffi::rte_flow_op_result::default(); results.len()];
+
+        let n = flow_call(|err| unsafe {
+            ffi::rte_flow_pull(self.port_id.0, self.queue_id, raw.as_mut_ptr(), raw.len() as u16, err)
+        })? as usize;
+
+        for (res, raw) in results.iter_mut().zip(&raw[..n]) {
+            *res = OpResult {
+                success: raw.status == ffi::rte_flow_op_status::RTE_FLOW_OP_SUCCESS,
+                user_data: raw.user_data as usize,
+            };
+        }
+
+        Ok(n)
+    }
+}
@@ -0,0 +1,211 @@
+//!
+//! The tunnel offload API.
+//!
+//! The hardware decapsulates the tunnel packets matched by the flow rules,
+//! the packets missed in the hardware are passed to the application with the restore information,
+//! so the software slow path can process them as if they were still encapsulated.
+//!
+use std::ops::Deref;
+use std::ptr;
+use std::slice;
+
+use anyhow::Result;
+
+use ethdev::PortId;
+use ffi;
+use mbuf::MBuf;
+use utils::AsRaw;
+
+use super::flow_call;
+
+/// The description of a tunnel offloaded to the hardware.
+#[derive(Clone, Copy)]
+pub struct Tunnel(ffi::rte_flow_tunnel);
+
+impl Tunnel {
+    /// A tunnel of the item type, e.g. `RTE_FLOW_ITEM_TYPE_VXLAN`, with the tunnel ID.
+    pub fn new(kind: ffi::rte_flow_item_type::Type, tun_id: u64) -> Self {
+        Tunnel(ffi::rte_flow_tunnel {
+            type_: kind,
+            tun_id,
+            ..Default::default()
+        })
+    }
+
+    /// Set the outer IPv4 addresses, in network byte order.
+    pub fn with_ipv4(mut self, src_addr: u32, dst_addr: u32) -> Self {
+        self.0.is_ipv6 = false;
+        self.0.__bindgen_anon_1.ipv4 = ffi::rte_flow_tunnel__bindgen_ty_1__bindgen_ty_1 { src_addr, dst_addr };
+        self
+    }
+
+    /// Set the outer IPv6 addresses.
+    pub fn with_ipv6(mut self, src_addr: [u8; 16], dst_addr: [u8; 16]) -> Self {
+        self.0.is_ipv6 = true;
+        self.0.__bindgen_anon_1.ipv6 = ffi::rte_flow_tunnel__bindgen_ty_1__bindgen_ty_2 { src_addr, dst_addr };
+        self
+    }
+
+    /// Set the outer transport ports, in network byte order.
+    pub fn with_ports(mut self, tp_src: u16, tp_dst: u16) -> Self {
+        self.0.tp_src = tp_src;
+        self.0.tp_dst = tp_dst;
+        self
+    }
+
+    /// The item type of the tunnel.
+    pub fn kind(&self) -> ffi::rte_flow_item_type::Type {
+        self.0.type_
+    }
+
+    /// The tunnel ID, e.g. the VNI of VXLAN.
+    pub fn tun_id(&self) -> u64 {
+        self.0.tun_id
+    }
+
+    /// The outer IPv4 addresses, in network byte order.
+    pub fn ipv4(&self) -> Option<(u32, u32)> {
+        if self.0.is_ipv6 {
+            None
+        } else {
+            let addr = unsafe { self.0.__bindgen_anon_1.ipv4 };
+
+            Some((addr.src_addr, addr.dst_addr))
+        }
+    }
+
+    /// The outer IPv6 addresses.
+    pub fn ipv6(&self) -> Option<([u8; 16], [u8; 16])> {
+        if self.0.is_ipv6 {
+            let addr = unsafe { self.0.__bindgen_anon_1.ipv6 };
+
+            Some((addr.src_addr, addr.dst_addr))
+        } else {
+            None
+        }
+    }
+
+    /// The outer transport ports, in network byte order.
+    pub fn ports(&self) -> (u16, u16) {
+        (self.0.tp_src, self.0.tp_dst)
+    }
+
+    pub fn as_raw(&self) -> &ffi::rte_flow_tunnel {
+        &self.0
+    }
+}
+
+/// The PMD actions decapsulating the tunnel,
+/// which must be prepended to the actions of the tunnel steering rule.
+///
+/// The actions are released to the PMD when dropped.
+pub struct DecapActions {
+    port_id: PortId,
+    actions: *mut ffi::rte_flow_action,
+    len: u32,
+}
+
+impl Drop for DecapActions {
+    fn drop(&mut self) {
+        let _ = flow_call(|err| unsafe {
+            ffi::rte_flow_tunnel_action_decap_release(self.port_id.0, self.actions, self.len, err)
+        });
+    }
+}
+
+impl Deref for DecapActions {
+    type Target = [ffi::rte_flow_action];
+
+    fn deref(&self) -> &Self::Target {
+        if self.actions.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.actions, self.len as usize) }
+        }
+    }
+}
+
+/// The PMD items matching the tunnel,
+/// which must be prepended to the pattern of the tunnel match rule.
+///
+/// The items are released to the PMD when dropped.
+pub struct MatchItems {
+    port_id: PortId,
+    items: *mut ffi::rte_flow_item,
+    len: u32,
+}
+
+impl Drop for MatchItems {
+    fn drop(&mut self) {
+        let _ =
+            flow_call(|err| unsafe { ffi::rte_flow_tunnel_item_release(self.port_id.0, self.items, self.len, err) });
+    }
+}
+
+impl Deref for MatchItems {
+    type Target = [ffi::rte_flow_item];
+
+    fn deref(&self) -> &Self::Target {
+        if self.items.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.items, self.len as usize) }
+        }
+    }
+}
+
+/// Get the PMD actions for the tunnel steering rule, which decapsulates the tunnel packets.
+pub fn decap_set(port_id: PortId, tunnel: &Tunnel) -> Result<DecapActions> {
+    let mut tunnel = tunnel.0;
+    let mut actions = ptr::null_mut();
+    let mut len = 0;
+
+    flow_call(|err| unsafe { ffi::rte_flow_tunnel_decap_set(port_id.0, &mut tunnel, &mut actions, &mut len, err) })?;
+
+    Ok(DecapActions { port_id, actions, len })
+}
+
+/// Get the PMD items for the tunnel match rule, which matches the decapsulated packets of the tunnel.
+pub fn tunnel_match(port_id: PortId, tunnel: &Tunnel) -> Result<MatchItems> {
+    let mut tunnel = tunnel.0;
+    let mut items = ptr::null_mut();
+    let mut len = 0;
+
+    flow_call(|err| unsafe { ffi::rte_flow_tunnel_match(port_id.0, &mut tunnel, &mut items, &mut len, err) })?;
+
+    Ok(MatchItems { port_id, items, len })
+}
+
+/// The information to restore the state of a packet missed in the hardware.
+#[derive(Clone, Copy)]
+pub struct RestoreInfo {
+    /// The tunnel the packet belongs to.
+    pub tunnel: Option<Tunnel>,
+    /// The packet is still encapsulated.
+    pub encapsulated: bool,
+    /// The group of the flow rule the packet missed.
+    pub group_id: Option<u32>,
+}
+
+/// Get the restore information of a packet received from the port.
+pub fn restore_info(port_id: PortId, m: &MBuf) -> Result<RestoreInfo> {
+    let mut info: ffi::rte_flow_restore_info = Default::default();
+
+    flow_call(|err| unsafe { ffi::rte_flow_get_restore_info(port_id.0, m.as_raw_mut(), &mut info, err) })?;
+
+    let flags = info.flags;
+
+    Ok(RestoreInfo {
+        tunnel: if flags & u64::from(ffi::RTE_FLOW_RESTORE_INFO_TUNNEL) != 0 {
+            Some(Tunnel(info.tunnel))
+        } else {
+            None
+        },
+        encapsulated: flags & u64::from(ffi::RTE_FLOW_RESTORE_INFO_ENCAPSULATED) != 0,
+        group_id: if flags & u64::from(ffi::RTE_FLOW_RESTORE_INFO_GROUP_ID) != 0 {
+            Some(info.group_id)
+        } else {
+            None
+        },
+    })
+}
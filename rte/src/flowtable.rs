@@ -0,0 +1,427 @@
+//!
+//! Flow table
+//!
+//! The flow table tracks the flows by their 5-tuple, the keys are stored in a `DpdkHashMap`,
+//! and the per-flow states are allocated from a mempool with an `rte_timer`,
+//! which evicts the flow when it is idle for too long or has been active for too long.
+//!
+//! Each table has its own timer list, whose timers are only run by `FlowTable::manage()`,
+//! which should be called periodically on the lcore that inserts the flows,
+//! and the evicted flows are passed to the eviction callback.
+//! The table can't be moved to another thread, since its timers are bound to the inserting lcore.
+//!
+use std::fmt;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use libc;
+
+use byteorder::{be16, be32};
+use common::{get_tsc_hz, rdtsc};
+use errors::{AsResult, RteError};
+use ffi;
+use hash::DpdkHashMap;
+use memory::SocketId;
+use utils::{catch_panic, AsCString};
+
+/// The 5-tuple of a flow.
+///
+/// The key is hashed and compared as bytes, so it has no implicit padding.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FlowKey {
+    pub src_addr: be32,
+    pub dst_addr: be32,
+    pub src_port: be16,
+    pub dst_port: be16,
+    pub proto: u8,
+    pad: [u8; 3],
+}
+
+impl FlowKey {
+    pub fn new(src_addr: be32, dst_addr: be32, src_port: be16, dst_port: be16, proto: u8) -> Self {
+        FlowKey {
+            src_addr,
+            dst_addr,
+            src_port,
+            dst_port,
+            proto,
+            pad: [0; 3],
+        }
+    }
+
+    /// The key of the reverse direction.
+    pub fn reverse(&self) -> Self {
+        FlowKey::new(self.dst_addr, self.src_addr, self.dst_port, self.src_port, self.proto)
+    }
+}
+
+/// The reason why a flow was evicted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expiry {
+    /// No packet was seen during the idle timeout.
+    Idle,
+    /// The flow has been active longer than the active timeout.
+    Active,
+}
+
+/// The configuration of a flow table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlowTableConf {
+    /// The maximum number of flows.
+    pub capacity: u32,
+    /// Evict the flow if no packet was seen during the timeout.
+    pub idle_timeout: Duration,
+    /// Evict the flow once it has been active for the timeout, e.g. to export the long-lived flows.
+    pub active_timeout: Option<Duration>,
+    /// The NUMA socket of the table memory.
+    pub socket_id: SocketId,
+}
+
+/// The callback of the evicted flows, which takes the ownership of the flow state.
+pub type EvictCallback<T> = Box<dyn FnMut(&FlowKey, T, Expiry) + Send>;
+
+/// A flow table with the timer-driven expiry.
+pub struct FlowTable<T> {
+    // boxed, since the flow entries point back to it from the timer callbacks.
+    inner: Box<Inner<T>>,
+}
+
+struct Inner<T> {
+    flows: DpdkHashMap<FlowKey, NonNull<FlowEntry<T>>>,
+    pool: NonNull<ffi::rte_mempool>,
+    timer_data_id: u32,
+    idle_cycles: u64,
+    active_cycles: Option<u64>,
+    on_evict: Option<EvictCallback<T>>,
+}
+
+struct FlowEntry<T> {
+    timer: ffi::rte_timer,
+    key: FlowKey,
+    created: u64,
+    last_seen: u64,
+    table: *mut Inner<T>,
+    state: T,
+}
+
+impl<T> Drop for FlowTable<T> {
+    fn drop(&mut self) {
+        let entries = self.inner.flows.values().cloned().collect::<Vec<_>>();
+
+        self.inner.flows.clear();
+
+        for entry in entries {
+            unsafe {
+                ffi::rte_timer_alt_stop(self.inner.timer_data_id, &mut (*entry.as_ptr()).timer);
+
+                self.inner.release(entry);
+            }
+        }
+
+        unsafe {
+            ffi::rte_mempool_free(self.inner.pool.as_ptr());
+            ffi::rte_timer_data_dealloc(self.inner.timer_data_id);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for FlowTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.inner
+                    .flows
+                    .iter()
+                    .map(|(key, entry)| (key, unsafe { &entry.as_ref().state })),
+            )
+            .finish()
+    }
+}
+
+impl<T> FlowTable<T> {
+    /// Create a flow table named `name`.
+    pub fn new<S: AsRef<str>>(name: S, conf: &FlowTableConf) -> Result<Self> {
+        let ret = unsafe { ffi::rte_timer_subsystem_init() };
+
+        if ret != 0 && ret != -libc::EALREADY {
+            return Err(RteError(-ret).into());
+        }
+
+        let name = name.as_ref();
+        let flows = DpdkHashMap::new(name, conf.capacity as usize, conf.socket_id)?;
+        let pool_name = format!("FT_{}", name).as_cstring();
+        let pool = unsafe {
+            ffi::rte_mempool_create(
+                pool_name.as_ptr(),
+                conf.capacity,
+                mem::size_of::<FlowEntry<T>>() as u32,
+                0,
+                0,
+                None,
+                ptr::null_mut(),
+                None,
+                ptr::null_mut(),
+                conf.socket_id,
+                0,
+            )
+        }
+        .as_result()?;
+        let mut timer_data_id = 0;
+        let ret = unsafe { ffi::rte_timer_data_alloc(&mut timer_data_id) };
+
+        if ret != 0 {
+            unsafe { ffi::rte_mempool_free(pool.as_ptr()) };
+
+            return Err(RteError(-ret).into());
+        }
+
+        let hz = get_tsc_hz();
+
+        Ok(FlowTable {
+            inner: Box::new(Inner {
+                flows,
+                pool,
+                timer_data_id,
+                idle_cycles: duration_to_cycles(conf.idle_timeout, hz),
+                active_cycles: conf.active_timeout.map(|d| duration_to_cycles(d, hz)),
+                on_evict: None,
+            }),
+        })
+    }
+
+    /// Set the callback of the evicted flows, otherwise the flow states are dropped.
+    pub fn on_evict<F>(&mut self, f: F)
+    where
+        F: FnMut(&FlowKey, T, Expiry) + Send + 'static,
+    {
+        self.inner.on_evict = Some(Box::new(f));
+    }
+
+    /// The number of flows.
+    pub fn len(&self) -> usize {
+        self.inner.flows.len()
+    }
+
+    /// Test if the table has no flow.
+    pub fn is_empty(&self) -> bool {
+        self.inner.flows.is_empty()
+    }
+
+    /// Get the flow state without refreshing the idle timeout.
+    pub fn get(&self, key: &FlowKey) -> Option<&T> {
+        self.inner
+            .flows
+            .get(key)
+            .map(|entry| unsafe { &(*entry.as_ptr()).state })
+    }
+
+    /// Get the flow state of a received packet, which refreshes the idle timeout.
+    #[inline]
+    pub fn lookup(&mut self, key: &FlowKey) -> Option<&mut T> {
+        let now = rdtsc();
+
+        self.inner.flows.get(key).map(|entry| unsafe {
+            let entry = &mut *entry.as_ptr();
+
+            entry.last_seen = now;
+
+            &mut entry.state
+        })
+    }
+
+    /// Get the flow state of a received packet, or create the flow if it does not exist.
+    ///
+    /// Return an error if the table is full.
+    #[inline]
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, key: FlowKey, f: F) -> Result<&mut T> {
+        if self.inner.flows.contains_key(&key) {
+            Ok(self.lookup(&key).unwrap())
+        } else {
+            self.insert(key, f())
+        }
+    }
+
+    /// Create a flow, and start its timer on the current lcore.
+    ///
+    /// Return an error if the flow exists or the table is full.
+    pub fn insert(&mut self, key: FlowKey, state: T) -> Result<&mut T> {
+        if self.inner.flows.contains_key(&key) {
+            return Err(anyhow!("flow {:?} exists", key));
+        }
+
+        let now = rdtsc();
+        let table = &mut *self.inner as *mut Inner<T>;
+        let mut obj = ptr::null_mut();
+
+        if unsafe { ffi::_rte_mempool_get(self.inner.pool.as_ptr(), &mut obj) } != 0 {
+            return Err(RteError(libc::ENOBUFS).into());
+        }
+
+        let entry = unsafe {
+            let entry = obj as *mut FlowEntry<T>;
+
+            ptr::write(
+                entry,
+                FlowEntry {
+                    timer: Default::default(),
+                    key,
+                    created: now,
+                    last_seen: now,
+                    table,
+                    state,
+                },
+            );
+
+            ffi::rte_timer_init(&mut (*entry).timer);
+
+            NonNull::new_unchecked(entry)
+        };
+
+        if let Err(err) = self.inner.flows.insert(key, entry) {
+            unsafe { self.inner.release(entry) };
+
+            return Err(err);
+        }
+
+        if let Err(err) = self.inner.arm(entry, self.inner.idle_cycles) {
+            self.inner.flows.remove(&key);
+
+            unsafe { self.inner.release(entry) };
+
+            return Err(err);
+        }
+
+        Ok(unsafe { &mut (*entry.as_ptr()).state })
+    }
+
+    /// Remove the flow, return its state.
+    pub fn remove(&mut self, key: &FlowKey) -> Option<T> {
+        self.inner.flows.remove(key).map(|entry| unsafe {
+            ffi::rte_timer_alt_stop(self.inner.timer_data_id, &mut (*entry.as_ptr()).timer);
+
+            self.inner.take(entry)
+        })
+    }
+
+    /// Run the expired timers of the table on the current lcore, which evict the expired flows.
+    pub fn manage(&mut self) -> Result<()> {
+        let mut lcore_id = unsafe { ffi::_rte_lcore_id() };
+
+        rte_check!(unsafe {
+            ffi::rte_timer_alt_manage(self.inner.timer_data_id, &mut lcore_id, 1, Some(run_flow_timer::<T>))
+        })
+    }
+}
+
+impl<T> Inner<T> {
+    fn deadline(&self, entry: &FlowEntry<T>) -> (u64, Expiry) {
+        let idle = entry.last_seen + self.idle_cycles;
+
+        match self.active_cycles {
+            Some(active) if entry.created + active < idle => (entry.created + active, Expiry::Active),
+            _ => (idle, Expiry::Idle),
+        }
+    }
+
+    fn arm(&self, entry: NonNull<FlowEntry<T>>, ticks: u64) -> Result<()> {
+        rte_check!(unsafe {
+            ffi::rte_timer_alt_reset(
+                self.timer_data_id,
+                &mut (*entry.as_ptr()).timer,
+                ticks,
+                ffi::rte_timer_type::SINGLE,
+                ffi::_rte_lcore_id(),
+                Some(expire_flow::<T>),
+                entry.as_ptr() as *mut c_void,
+            )
+        })
+    }
+
+    fn evict(&mut self, entry: NonNull<FlowEntry<T>>, expiry: Expiry) {
+        let key = unsafe { entry.as_ref().key };
+
+        self.flows.remove(&key);
+
+        let state = unsafe { self.take(entry) };
+
+        if let Some(ref mut on_evict) = self.on_evict {
+            on_evict(&key, state, expiry);
+        }
+    }
+
+    unsafe fn take(&mut self, entry: NonNull<FlowEntry<T>>) -> T {
+        let state = ptr::read(&entry.as_ref().state);
+
+        ffi::_rte_mempool_put(self.pool.as_ptr(), entry.as_ptr() as *mut c_void);
+
+        state
+    }
+
+    unsafe fn release(&mut self, entry: NonNull<FlowEntry<T>>) {
+        drop(self.take(entry))
+    }
+}
+
+// `rte_timer_alt_manage()` calls the given function instead of the callback of the timer.
+unsafe extern "C" fn run_flow_timer<T>(timer: *mut ffi::rte_timer) {
+    expire_flow::<T>(timer, (*timer).arg)
+}
+
+unsafe extern "C" fn expire_flow<T>(timer: *mut ffi::rte_timer, arg: *mut c_void) {
+    let entry = NonNull::new_unchecked(arg as *mut FlowEntry<T>);
+    let table = &mut *entry.as_ref().table;
+    let (deadline, expiry) = table.deadline(entry.as_ref());
+    let now = rdtsc();
+
+    if now < deadline {
+        if let Err(err) = table.arm(entry, deadline - now) {
+            error!("fail to rearm the timer of flow {:?}, {}", entry.as_ref().key, err);
+        }
+    } else {
+        // stop the running timer, so the timer library will not touch it after it is freed.
+        ffi::rte_timer_alt_stop(table.timer_data_id, timer);
+
+        catch_panic("flow expiry callback", (), || table.evict(entry, expiry));
+    }
+}
+
+fn duration_to_cycles(d: Duration, hz: u64) -> u64 {
+    d.as_secs() * hz + u64::from(d.subsec_nanos()) * hz / 1_000_000_000
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    #[test]
+    fn test_flow_key() {
+        assert_eq!(mem::size_of::<FlowKey>(), 16);
+
+        let key = FlowKey::new(
+            be32::new(0x0a00_0001),
+            be32::new(0x0a00_0002),
+            be16::new(1234),
+            be16::new(80),
+            libc::IPPROTO_TCP as u8,
+        );
+        let reversed = key.reverse();
+
+        assert_eq!(reversed.src_addr, key.dst_addr);
+        assert_eq!(reversed.dst_port, key.src_port);
+        assert_eq!(reversed.reverse(), key);
+    }
+
+    #[test]
+    fn test_duration_to_cycles() {
+        assert_eq!(
+            duration_to_cycles(Duration::from_millis(1500), 2_000_000_000),
+            3_000_000_000
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//!
+//! RTE GRO
+//!
+//! The Generic Receive Offload library merges the small TCP/UDP packets of the same flow into large packets,
+//! which may be inserted between `rx_burst()` and the application to reduce the per-packet processing overhead.
+//!
+//! The merged packets don't have the correct checksums, which are not recalculated.
+//!
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::slice;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use common::get_tsc_hz;
+use errors::AsResult;
+use ffi;
+use mbuf::{self, RawMBufPtr};
+use memory::SocketId;
+
+bitflags! {
+    /// The packet types to be merged.
+    pub struct GroTypes: u64 {
+        /// TCP/IPv4 packets.
+        const TCP_IPV4            = ffi::RTE_GRO_TCP_IPV4 as u64;
+        /// TCP/IPv4 packets encapsulated in VXLAN over IPv4.
+        const IPV4_VXLAN_TCP_IPV4 = ffi::RTE_GRO_IPV4_VXLAN_TCP_IPV4 as u64;
+        /// UDP/IPv4 fragments.
+        const UDP_IPV4            = ffi::RTE_GRO_UDP_IPV4 as u64;
+        /// UDP/IPv4 fragments encapsulated in VXLAN over IPv4.
+        const IPV4_VXLAN_UDP_IPV4 = ffi::RTE_GRO_IPV4_VXLAN_UDP_IPV4 as u64;
+    }
+}
+
+/// The maximum number of the packets merged by `reassemble_burst()` at a time.
+pub const MAX_BURST_ITEM_NUM: usize = ffi::RTE_GRO_MAX_BURST_ITEM_NUM as usize;
+
+pub type RawGroParam = ffi::rte_gro_param;
+
+/// The parameters of the reassembly tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroParam {
+    /// The packet types to be merged.
+    pub gro_types: GroTypes,
+    /// The maximum number of the flows.
+    pub max_flow_num: u16,
+    /// The maximum number of the packets per flow.
+    pub max_item_per_flow: u16,
+    /// The NUMA socket of the reassembly tables, which is ignored by `reassemble_burst()`.
+    pub socket_id: SocketId,
+}
+
+impl GroParam {
+    /// Create the parameters merging the packet types, which are enough for a burst of packets.
+    pub fn new(gro_types: GroTypes) -> Self {
+        GroParam {
+            gro_types,
+            max_flow_num: MAX_BURST_ITEM_NUM as u16,
+            max_item_per_flow: MAX_BURST_ITEM_NUM as u16,
+            socket_id: 0,
+        }
+    }
+
+    fn to_raw(self) -> RawGroParam {
+        RawGroParam {
+            gro_types: self.gro_types.bits,
+            max_flow_num: self.max_flow_num,
+            max_item_per_flow: self.max_item_per_flow,
+            socket_id: self.socket_id as u16,
+        }
+    }
+}
+
+/// Merge the packets of a burst, return the packets after being merged at the head of `pkts`.
+///
+/// The merged packets are freed or chained to the remaining packets, so the tail of `pkts` must not be used.
+pub fn reassemble_burst<'a>(pkts: &'a mut [RawMBufPtr], param: &GroParam) -> &'a mut [RawMBufPtr] {
+    let param = param.to_raw();
+    let n = unsafe { ffi::rte_gro_reassemble_burst(pkts.as_mut_ptr(), pkts.len() as u16, &param) };
+
+    &mut pkts[..n as usize]
+}
+
+/// A GRO context, which keeps the packets in its reassembly tables until they are flushed.
+///
+/// The context isn't thread-safe, it should be owned by the lcore polling the RX queue.
+#[derive(Debug)]
+pub struct GroCtx {
+    ctx: NonNull<c_void>,
+    gro_types: GroTypes,
+}
+
+unsafe impl Send for GroCtx {}
+
+impl Drop for GroCtx {
+    fn drop(&mut self) {
+        // the packets in the reassembly tables are not freed by the context.
+        let mut pkts: [MaybeUninit<RawMBufPtr>; 32] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        loop {
+            let flushed = self.timeout_flush(Duration::from_secs(0), self.gro_types, &mut pkts);
+
+            if flushed.is_empty() {
+                break;
+            }
+
+            mbuf::free_burst(flushed);
+        }
+
+        unsafe { ffi::rte_gro_ctx_destroy(self.ctx.as_ptr()) }
+    }
+}
+
+impl GroCtx {
+    /// Create a GRO context with the reassembly tables allocated on the NUMA socket of the parameters.
+    pub fn create(param: &GroParam) -> Result<Self> {
+        let raw = param.to_raw();
+
+        unsafe { ffi::rte_gro_ctx_create(&raw) }.as_result().map(|ctx| GroCtx {
+            ctx,
+            gro_types: param.gro_types,
+        })
+    }
+
+    /// Merge the packets into the reassembly tables,
+    /// return the packets which can't be processed, e.g. the unsupported types, at the head of `pkts`.
+    ///
+    /// The processed packets are owned by the context until they are flushed, so the tail of `pkts` must not be used.
+    pub fn reassemble<'a>(&mut self, pkts: &'a mut [RawMBufPtr]) -> &'a mut [RawMBufPtr] {
+        let n = unsafe { ffi::rte_gro_reassemble(pkts.as_mut_ptr(), pkts.len() as u16, self.ctx.as_ptr()) };
+
+        &mut pkts[..n as usize]
+    }
+
+    /// Flush the packets of the types which have stayed in the reassembly tables longer than `timeout`,
+    /// return the flushed packets at the head of `out`.
+    ///
+    /// All the packets of the types are flushed if `timeout` is zero.
+    pub fn timeout_flush<'a>(
+        &mut self,
+        timeout: Duration,
+        gro_types: GroTypes,
+        out: &'a mut [MaybeUninit<RawMBufPtr>],
+    ) -> &'a mut [RawMBufPtr] {
+        let hz = get_tsc_hz();
+        let timeout_cycles = timeout.as_secs() * hz + u64::from(timeout.subsec_nanos()) * hz / 1_000_000_000;
+
+        unsafe {
+            let n = ffi::rte_gro_timeout_flush(
+                self.ctx.as_ptr(),
+                timeout_cycles,
+                gro_types.bits,
+                out.as_mut_ptr() as *mut _,
+                out.len() as u16,
+            );
+
+            slice::from_raw_parts_mut(out.as_mut_ptr() as *mut _, n as usize)
+        }
+    }
+
+    /// The number of the packets in the reassembly tables.
+    pub fn pkt_count(&self) -> u64 {
+        unsafe { ffi::rte_gro_get_pkt_count(self.ctx.as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gro_param() {
+        let param = GroParam {
+            socket_id: 1,
+            ..GroParam::new(GroTypes::TCP_IPV4 | GroTypes::IPV4_VXLAN_TCP_IPV4)
+        };
+        let raw = param.to_raw();
+
+        assert_eq!(raw.gro_types, 0b11);
+        assert_eq!(raw.max_flow_num, 128);
+        assert_eq!(raw.max_item_per_flow, 128);
+        assert_eq!(raw.socket_id, 1);
+    }
+}
@@ -0,0 +1,145 @@
+//!
+//! RTE GSO
+//!
+//! The Generic Segmentation Offload library segments the large TCP/UDP packets into the packets of the MSS size,
+//! which may be inserted before `tx_burst()` for the NICs without the TSO capabilities.
+//!
+//! The packets to be segmented must have the `PKT_TX_TCP_SEG` or `PKT_TX_UDP_SEG` flags and the header lengths set,
+//! and the checksums of the segments are not recalculated.
+//!
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::slice;
+
+use anyhow::Result;
+
+use errors::ErrorKind::OsError;
+use ffi;
+use mbuf::RawMBufPtr;
+use mempool::MemoryPool;
+use utils::AsRaw;
+
+bitflags! {
+    /// The packet types to be segmented, which are the same as the `DEV_TX_OFFLOAD_*_TSO` flags.
+    pub struct GsoTypes: u64 {
+        /// TCP/IPv4 packets.
+        const TCP_TSO       = 0x0000_0020;
+        /// UDP/IPv4 packets.
+        const UDP_TSO       = 0x0000_0040;
+        /// TCP/IPv4 or UDP/IPv4 packets encapsulated in VXLAN over IPv4.
+        const VXLAN_TNL_TSO = 0x0000_0200;
+        /// TCP/IPv4 packets encapsulated in GRE over IPv4.
+        const GRE_TNL_TSO   = 0x0000_0400;
+    }
+}
+
+bitflags! {
+    /// The flags of the GSO segments.
+    pub struct GsoFlags: u8 {
+        /// Keep the IPv4 ID fields of the segments the same as the input packet.
+        const IPID_FIXED = ffi::RTE_GSO_FLAG_IPID_FIXED as u8;
+    }
+}
+
+/// The minimum size of the GSO segments, including the headers and the payload.
+pub const SEG_SIZE_MIN: u16 = ffi::RTE_GSO_SEG_SIZE_MIN as u16;
+
+pub type RawGsoCtx = ffi::rte_gso_ctx;
+
+/// A GSO context, which allocates the segments from the mempools.
+///
+/// Each segment is made of a direct mbuf copying the headers and an indirect mbuf
+/// pointing to the payload of the input packet, so the NIC must support the multi-segment packets.
+#[derive(Debug)]
+pub struct GsoCtx<'a> {
+    raw: RawGsoCtx,
+    _pools: PhantomData<&'a MemoryPool>,
+}
+
+// The context is read only, and the mempools are multi-thread safe.
+unsafe impl<'a> Send for GsoCtx<'a> {}
+unsafe impl<'a> Sync for GsoCtx<'a> {}
+
+impl<'a> GsoCtx<'a> {
+    /// Create a GSO context segmenting the packets of the types into the segments of `gso_size` bytes.
+    ///
+    /// The headers are copied into the mbufs of `direct_pool`,
+    /// and the payload is attached to the mbufs of `indirect_pool`, which should have no data room.
+    pub fn new(direct_pool: &'a MemoryPool, indirect_pool: &'a MemoryPool, gso_types: GsoTypes, gso_size: u16) -> Self {
+        GsoCtx {
+            raw: RawGsoCtx {
+                direct_pool: direct_pool.as_raw_mut(),
+                indirect_pool: indirect_pool.as_raw_mut(),
+                gso_types: gso_types.bits,
+                gso_size,
+                flag: 0,
+            },
+            _pools: PhantomData,
+        }
+    }
+
+    /// Set the flags of the GSO segments.
+    pub fn with_flags(mut self, flags: GsoFlags) -> Self {
+        self.raw.flag = flags.bits;
+        self
+    }
+
+    /// The packet types to be segmented.
+    pub fn gso_types(&self) -> GsoTypes {
+        GsoTypes::from_bits_truncate(self.raw.gso_types)
+    }
+
+    /// The maximum size of the GSO segments.
+    pub fn gso_size(&self) -> u16 {
+        self.raw.gso_size
+    }
+
+    /// The flags of the GSO segments.
+    pub fn flags(&self) -> GsoFlags {
+        GsoFlags::from_bits_truncate(self.raw.flag)
+    }
+
+    /// Segment the packet, return the packets to be sent at the head of `out`.
+    ///
+    /// The packet is released after being segmented, and returned as is if it doesn't need to be segmented.
+    /// The packet is still owned by the caller on error, e.g. `out` or the mempools are exhausted.
+    pub fn segment<'b>(&self, pkt: RawMBufPtr, out: &'b mut [MaybeUninit<RawMBufPtr>]) -> Result<&'b mut [RawMBufPtr]> {
+        let ret = unsafe { ffi::rte_gso_segment(pkt, &self.raw, out.as_mut_ptr() as *mut _, out.len() as u16) };
+
+        if ret < 0 {
+            return Err(OsError(-ret).into());
+        }
+
+        let n = match ret {
+            0 => {
+                out[0] = MaybeUninit::new(pkt);
+
+                1
+            }
+            _ => {
+                // the segments hold the references to the payload of the packet.
+                unsafe { ffi::_rte_pktmbuf_free(pkt) };
+
+                ret as usize
+            }
+        };
+
+        Ok(unsafe { slice::from_raw_parts_mut(out.as_mut_ptr() as *mut _, n) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gso_types() {
+        assert_eq!(
+            (GsoTypes::TCP_TSO | GsoTypes::VXLAN_TNL_TSO).bits(),
+            0x220,
+            "DEV_TX_OFFLOAD_TCP_TSO | DEV_TX_OFFLOAD_VXLAN_TNL_TSO"
+        );
+        assert_eq!(GsoFlags::IPID_FIXED.bits(), 1);
+        assert_eq!(SEG_SIZE_MIN, 256);
+    }
+}
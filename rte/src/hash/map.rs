@@ -0,0 +1,366 @@
+use std::cmp;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+use libc;
+
+use errors::{AsResult, RteError};
+use ffi;
+use malloc;
+use memory::SocketId;
+use utils::AsCString;
+
+use super::{HashFlags, JHASH};
+
+/// A hash map which keys are stored in the `rte_hash` table,
+/// and values are stored in an array allocated from the huge pages on the NUMA socket.
+///
+/// The keys are hashed and compared as bytes, so `K` should not contain any padding or pointer.
+///
+/// The lookup operations are thread safe with `&self`,
+/// while the insert and remove operations need `&mut self`.
+pub struct DpdkHashMap<K, V> {
+    raw: NonNull<ffi::rte_hash>,
+    values: NonNull<V>,
+    capacity: usize,
+    phantom: PhantomData<(K, V)>,
+}
+
+unsafe impl<K: Send, V: Send> Send for DpdkHashMap<K, V> {}
+unsafe impl<K: Sync, V: Sync> Sync for DpdkHashMap<K, V> {}
+
+impl<K, V> Drop for DpdkHashMap<K, V> {
+    fn drop(&mut self) {
+        self.drop_values();
+
+        unsafe {
+            ffi::rte_hash_free(self.raw.as_ptr());
+        }
+
+        malloc::free(self.values.as_ptr() as *mut c_void);
+    }
+}
+
+impl<K, V> DpdkHashMap<K, V> {
+    /// The maximum number of the entries in the map.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of the entries in the map.
+    pub fn len(&self) -> usize {
+        unsafe { ffi::rte_hash_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Test if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all the entries in the map.
+    pub fn clear(&mut self) {
+        self.drop_values();
+
+        unsafe { ffi::rte_hash_reset(self.raw.as_ptr()) }
+    }
+
+    /// An iterator visiting all the key-value pairs in arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { map: self, next: 0 }
+    }
+
+    /// An iterator visiting all the keys in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// An iterator visiting all the values in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    fn drop_values(&mut self) {
+        if mem::needs_drop::<V>() {
+            let mut next = 0;
+
+            while let Some((_, pos)) = self.next_position(&mut next) {
+                unsafe { ptr::drop_in_place(self.slot(pos)) }
+            }
+        }
+    }
+
+    fn next_position(&self, next: &mut u32) -> Option<(*const K, usize)> {
+        let mut key = ptr::null();
+        let mut data = ptr::null_mut();
+
+        let pos = unsafe { ffi::rte_hash_iterate(self.raw.as_ptr(), &mut key, &mut data, next) };
+
+        if pos < 0 {
+            None
+        } else {
+            Some((key as *const K, pos as usize))
+        }
+    }
+
+    fn slot(&self, pos: usize) -> *mut V {
+        debug_assert!(pos < self.capacity);
+
+        unsafe { self.values.as_ptr().add(pos) }
+    }
+}
+
+impl<K: Hash + Eq + Copy, V> DpdkHashMap<K, V> {
+    /// Create a map named `name`, which can hold `capacity` entries allocated on the NUMA socket.
+    pub fn new<S: AsRef<str>>(name: S, capacity: usize, socket_id: SocketId) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let params = ffi::rte_hash_parameters {
+            name: name.as_ptr(),
+            entries: capacity as u32,
+            key_len: mem::size_of::<K>() as u32,
+            hash_func: JHASH,
+            socket_id,
+            extra_flag: HashFlags::EXT_TABLE.bits,
+            ..Default::default()
+        };
+
+        let raw = unsafe { ffi::rte_hash_create(&params) }.as_result()?;
+
+        let values = NonNull::new(malloc::zmalloc_socket(
+            "hash_map",
+            cmp::max(mem::size_of::<V>() * capacity, 1),
+            cmp::max(mem::align_of::<V>() as u32, ffi::RTE_CACHE_LINE_SIZE),
+            socket_id,
+        ));
+
+        match values {
+            Some(values) => Ok(DpdkHashMap {
+                raw,
+                values: values.cast(),
+                capacity,
+                phantom: PhantomData,
+            }),
+            None => {
+                unsafe { ffi::rte_hash_free(raw.as_ptr()) };
+
+                Err(RteError(libc::ENOMEM).into())
+            }
+        }
+    }
+
+    /// Insert a key-value pair into the map, return the old value if the key was present.
+    ///
+    /// Return an error if the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => entry.insert(value).map(|_| None),
+        }
+    }
+
+    /// Get the entry of the key for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.position(&key) {
+            Some(pos) => Entry::Occupied(OccupiedEntry { map: self, key, pos }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    /// Get a reference to the value of the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.position(key).map(|pos| unsafe { &*self.slot(pos) })
+    }
+
+    /// Get a mutable reference to the value of the key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.position(key).map(|pos| unsafe { &mut *self.slot(pos) })
+    }
+
+    /// Test if the map contains the key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Remove the key from the map, return its value if the key was present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let pos = unsafe { ffi::rte_hash_del_key(self.raw.as_ptr(), key as *const K as *const c_void) };
+
+        if pos < 0 {
+            None
+        } else {
+            Some(unsafe { ptr::read(self.slot(pos as usize)) })
+        }
+    }
+
+    fn position(&self, key: &K) -> Option<usize> {
+        let pos = unsafe { ffi::rte_hash_lookup(self.raw.as_ptr(), key as *const K as *const c_void) };
+
+        if pos < 0 {
+            None
+        } else {
+            Some(pos as usize)
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for DpdkHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a DpdkHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a `DpdkHashMap`.
+pub struct Iter<'a, K, V> {
+    map: &'a DpdkHashMap<K, V>,
+    next: u32,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let map = self.map;
+
+        map.next_position(&mut self.next)
+            .map(|(key, pos)| unsafe { (&*key, &*map.slot(pos)) })
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K: Hash + Eq + Copy, V> Entry<'a, K, V> {
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Insert the default value if the entry is vacant, return a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> Result<&'a mut V> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of the function if the entry is vacant, return a mutable reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Modify the value if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K: Hash + Eq + Copy, V: Default> Entry<'a, K, V> {
+    /// Insert the default value if the entry is vacant, return a mutable reference to the value.
+    pub fn or_default(self) -> Result<&'a mut V> {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// A view into an occupied entry in a `DpdkHashMap`.
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut DpdkHashMap<K, V>,
+    key: K,
+    pos: usize,
+}
+
+impl<'a, K: Hash + Eq + Copy, V> OccupiedEntry<'a, K, V> {
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// The position of the key in the hash table.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Get a reference to the value.
+    pub fn get(&self) -> &V {
+        unsafe { &*self.map.slot(self.pos) }
+    }
+
+    /// Get a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut *self.map.slot(self.pos) }
+    }
+
+    /// Convert the entry into a mutable reference to the value, with the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut *self.map.slot(self.pos) }
+    }
+
+    /// Set the value of the entry, return the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove the entry from the map, return its value.
+    pub fn remove(self) -> V {
+        self.map.remove(&self.key).expect("occupied entry")
+    }
+}
+
+/// A view into a vacant entry in a `DpdkHashMap`.
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut DpdkHashMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: Hash + Eq + Copy, V> VacantEntry<'a, K, V> {
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take the ownership of the key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Set the value of the entry, return a mutable reference to the value.
+    ///
+    /// Return an error if the map is full.
+    pub fn insert(self, value: V) -> Result<&'a mut V> {
+        let pos = unsafe { ffi::rte_hash_add_key(self.map.raw.as_ptr(), &self.key as *const K as *const c_void) };
+
+        if pos < 0 {
+            return Err(RteError(-pos).into());
+        }
+
+        let slot = self.map.slot(pos as usize);
+
+        unsafe {
+            ptr::write(slot, value);
+
+            Ok(&mut *slot)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//!
+//! RTE Hash
+//!
+//! The hash library provides a cuckoo hash table with fixed-length keys,
+//! which returns a unique position for each key that can be used as an index into an array of user data.
+//!
+use std::os::raw::c_void;
+
+use ffi;
+
+mod map;
+mod table;
+
+pub use self::map::{DpdkHashMap, Entry, Iter, OccupiedEntry, VacantEntry};
+pub use self::table::{HashIter, HashMapLike, HashParams, HashTable};
+
+pub use ffi::{RTE_HASH_ENTRIES_MAX, RTE_HASH_LOOKUP_BULK_MAX, RTE_HASH_NAMESIZE};
+
+bitflags! {
+    /// The extra flags of the hash table.
+    pub struct HashFlags: u8 {
+        /// Use the hardware transactional memory if supported.
+        const TRANS_MEM_SUPPORT = ffi::RTE_HASH_EXTRA_FLAGS_TRANS_MEM_SUPPORT as u8;
+        /// The add operation is multi-writer safe.
+        const MULTI_WRITER_ADD = ffi::RTE_HASH_EXTRA_FLAGS_MULTI_WRITER_ADD as u8;
+        /// The readers and writers are allowed to access the table concurrently.
+        const RW_CONCURRENCY = ffi::RTE_HASH_EXTRA_FLAGS_RW_CONCURRENCY as u8;
+        /// Use the extendable bucket table, so the insertion never fails before the table is full.
+        const EXT_TABLE = ffi::RTE_HASH_EXTRA_FLAGS_EXT_TABLE as u8;
+        /// The key is not freed on delete, but by `free_key_with_position()`.
+        const NO_FREE_ON_DEL = ffi::RTE_HASH_EXTRA_FLAGS_NO_FREE_ON_DEL as u8;
+        /// The readers and writers are allowed to access the table concurrently with the lock-free algorithm.
+        const RW_CONCURRENCY_LF = ffi::RTE_HASH_EXTRA_FLAGS_RW_CONCURRENCY_LF as u8;
+    }
+}
+
+/// The function calculating the hash value of a key.
+pub type HashFunc = ffi::rte_hash_function;
+
+/// The Jenkins hash function.
+pub const JHASH: HashFunc = Some(ffi::_rte_jhash);
+
+/// The CRC32 hash function, which uses the CRC32 instruction if supported.
+pub const HASH_CRC: HashFunc = Some(ffi::_rte_hash_crc);
+
+/// Calculate the Jenkins hash of the data.
+#[inline]
+pub fn jhash(data: &[u8], init_val: u32) -> u32 {
+    unsafe { ffi::_rte_jhash(data.as_ptr() as *const c_void, data.len() as u32, init_val) }
+}
+
+/// Calculate the CRC32 hash of the data.
+#[inline]
+pub fn hash_crc(data: &[u8], init_val: u32) -> u32 {
+    unsafe { ffi::_rte_hash_crc(data.as_ptr() as *const c_void, data.len() as u32, init_val) }
+}
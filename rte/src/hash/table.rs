@@ -0,0 +1,397 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+use std::slice;
+
+use anyhow::Result;
+
+use errors::{AsResult, RteError};
+use ffi;
+use memory::{SocketId, SOCKET_ID_ANY};
+use utils::AsCString;
+
+use super::{HashFlags, HashFunc, JHASH, RTE_HASH_LOOKUP_BULK_MAX};
+
+/// The parameters to create a hash table.
+#[derive(Clone, Debug)]
+pub struct HashParams<S> {
+    /// The name of the hash table.
+    pub name: S,
+    /// The total number of the entries in the hash table.
+    pub entries: usize,
+    /// The length of the keys in bytes.
+    pub key_len: usize,
+    /// The function calculating the hash value of a key.
+    pub hash_func: HashFunc,
+    /// The initial value used by the hash function.
+    pub hash_func_init_val: u32,
+    /// The NUMA socket to allocate the memory for the hash table.
+    pub socket_id: SocketId,
+    /// The extra flags of the hash table.
+    pub extra_flag: HashFlags,
+}
+
+impl<S: AsRef<str>> HashParams<S> {
+    /// The parameters of a hash table with the Jenkins hash function.
+    pub fn new(name: S, entries: usize, key_len: usize) -> Self {
+        HashParams {
+            name,
+            entries,
+            key_len,
+            hash_func: JHASH,
+            hash_func_init_val: 0,
+            socket_id: SOCKET_ID_ANY,
+            extra_flag: HashFlags::empty(),
+        }
+    }
+}
+
+/// A cuckoo hash table with fixed-length keys, which may store a user data pointer with each key.
+///
+/// The position returned for a key is unique and can be used as an index into an array of user data.
+#[derive(Debug)]
+pub struct HashTable {
+    raw: NonNull<ffi::rte_hash>,
+    key_len: usize,
+}
+
+unsafe impl Send for HashTable {}
+unsafe impl Sync for HashTable {}
+
+impl HashTable {
+    /// Create a new hash table.
+    pub fn create<S: AsRef<str>>(params: &HashParams<S>) -> Result<Self> {
+        let name = params.name.as_cstring();
+        let raw_params = ffi::rte_hash_parameters {
+            name: name.as_ptr(),
+            entries: params.entries as u32,
+            key_len: params.key_len as u32,
+            hash_func: params.hash_func,
+            hash_func_init_val: params.hash_func_init_val,
+            socket_id: params.socket_id,
+            extra_flag: params.extra_flag.bits,
+            ..Default::default()
+        };
+
+        unsafe { ffi::rte_hash_create(&raw_params) }
+            .as_result()
+            .map(|raw| HashTable {
+                raw,
+                key_len: params.key_len,
+            })
+    }
+
+    /// Find an existing hash table by its name, which keys are `key_len` bytes.
+    pub fn find_existing<S: AsRef<str>>(name: S, key_len: usize) -> Result<Self> {
+        let name = name.as_cstring();
+
+        unsafe { ffi::rte_hash_find_existing(name.as_ptr()) }
+            .as_result()
+            .map(|raw| HashTable { raw, key_len })
+    }
+
+    pub fn as_raw(&self) -> *mut ffi::rte_hash {
+        self.raw.as_ptr()
+    }
+
+    /// Free all memory used by the hash table.
+    pub fn free(self) {
+        unsafe { ffi::rte_hash_free(self.raw.as_ptr()) }
+    }
+
+    /// The length of the keys in bytes.
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    /// The number of the keys in the hash table.
+    pub fn count(&self) -> usize {
+        unsafe { ffi::rte_hash_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Remove all the keys from the hash table.
+    pub fn reset(&mut self) {
+        unsafe { ffi::rte_hash_reset(self.raw.as_ptr()) }
+    }
+
+    #[inline]
+    fn key_ptr(&self, key: &[u8]) -> *const c_void {
+        assert_eq!(key.len(), self.key_len, "key length mismatch");
+
+        key.as_ptr() as *const c_void
+    }
+
+    #[inline]
+    fn position(pos: i32) -> Option<usize> {
+        if pos < 0 {
+            None
+        } else {
+            Some(pos as usize)
+        }
+    }
+
+    /// Calculate the hash value of a key.
+    #[inline]
+    pub fn hash(&self, key: &[u8]) -> u32 {
+        unsafe { ffi::rte_hash_hash(self.raw.as_ptr(), self.key_ptr(key)) }
+    }
+
+    /// Add a key to the hash table, return its position.
+    pub fn add_key(&mut self, key: &[u8]) -> Result<usize> {
+        let pos = unsafe { ffi::rte_hash_add_key(self.raw.as_ptr(), self.key_ptr(key)) };
+
+        Self::position(pos).ok_or_else(|| RteError(-pos).into())
+    }
+
+    /// Add a key with its precomputed hash value to the hash table, return its position.
+    pub fn add_key_with_hash(&mut self, key: &[u8], sig: u32) -> Result<usize> {
+        let pos = unsafe { ffi::rte_hash_add_key_with_hash(self.raw.as_ptr(), self.key_ptr(key), sig) };
+
+        Self::position(pos).ok_or_else(|| RteError(-pos).into())
+    }
+
+    /// Add a key with a user data pointer to the hash table, the data is updated if the key exists.
+    pub fn add_key_data(&mut self, key: &[u8], data: *mut c_void) -> Result<()> {
+        unsafe { ffi::rte_hash_add_key_data(self.raw.as_ptr(), self.key_ptr(key), data) }
+            .as_result()
+            .map(|_| ())
+    }
+
+    /// Remove a key from the hash table, return its position if the key was present.
+    pub fn del_key(&mut self, key: &[u8]) -> Option<usize> {
+        Self::position(unsafe { ffi::rte_hash_del_key(self.raw.as_ptr(), self.key_ptr(key)) })
+    }
+
+    /// Find the position of a key.
+    #[inline]
+    pub fn lookup(&self, key: &[u8]) -> Option<usize> {
+        Self::position(unsafe { ffi::rte_hash_lookup(self.raw.as_ptr(), self.key_ptr(key)) })
+    }
+
+    /// Find the position of a key with its precomputed hash value.
+    #[inline]
+    pub fn lookup_with_hash(&self, key: &[u8], sig: u32) -> Option<usize> {
+        Self::position(unsafe { ffi::rte_hash_lookup_with_hash(self.raw.as_ptr(), self.key_ptr(key), sig) })
+    }
+
+    /// Find the user data pointer of a key.
+    #[inline]
+    pub fn lookup_data(&self, key: &[u8]) -> Option<*mut c_void> {
+        let mut data = ptr::null_mut();
+
+        if unsafe { ffi::rte_hash_lookup_data(self.raw.as_ptr(), self.key_ptr(key), &mut data) } < 0 {
+            None
+        } else {
+            Some(data)
+        }
+    }
+
+    /// Find the positions of multiple keys, which are looked up in batches of `RTE_HASH_LOOKUP_BULK_MAX`.
+    pub fn lookup_bulk(&self, keys: &[&[u8]], positions: &mut [Option<usize>]) {
+        assert!(positions.len() >= keys.len());
+
+        for (keys, positions) in keys
+            .chunks(RTE_HASH_LOOKUP_BULK_MAX as usize)
+            .zip(positions.chunks_mut(RTE_HASH_LOOKUP_BULK_MAX as usize))
+        {
+            let mut key_ptrs = [ptr::null(); RTE_HASH_LOOKUP_BULK_MAX as usize];
+            let mut pos = [0i32; RTE_HASH_LOOKUP_BULK_MAX as usize];
+
+            for (p, key) in key_ptrs.iter_mut().zip(keys) {
+                *p = self.key_ptr(key);
+            }
+
+            unsafe {
+                ffi::rte_hash_lookup_bulk(
+                    self.raw.as_ptr(),
+                    key_ptrs.as_mut_ptr(),
+                    keys.len() as u32,
+                    pos.as_mut_ptr(),
+                );
+            }
+
+            for (dst, &pos) in positions.iter_mut().zip(&pos[..keys.len()]) {
+                *dst = Self::position(pos);
+            }
+        }
+    }
+
+    /// Find the user data pointers of multiple keys, which are looked up in batches of `RTE_HASH_LOOKUP_BULK_MAX`.
+    ///
+    /// Return the number of keys found.
+    pub fn lookup_bulk_data(&self, keys: &[&[u8]], data: &mut [Option<*mut c_void>]) -> usize {
+        assert!(data.len() >= keys.len());
+
+        let mut hits = 0;
+
+        for (keys, data) in keys
+            .chunks(RTE_HASH_LOOKUP_BULK_MAX as usize)
+            .zip(data.chunks_mut(RTE_HASH_LOOKUP_BULK_MAX as usize))
+        {
+            let mut key_ptrs = [ptr::null(); RTE_HASH_LOOKUP_BULK_MAX as usize];
+            let mut values = [ptr::null_mut(); RTE_HASH_LOOKUP_BULK_MAX as usize];
+            let mut hit_mask = 0u64;
+
+            for (p, key) in key_ptrs.iter_mut().zip(keys) {
+                *p = self.key_ptr(key);
+            }
+
+            hits += unsafe {
+                ffi::rte_hash_lookup_bulk_data(
+                    self.raw.as_ptr(),
+                    key_ptrs.as_mut_ptr(),
+                    keys.len() as u32,
+                    &mut hit_mask,
+                    values.as_mut_ptr(),
+                )
+            } as usize;
+
+            for (i, dst) in data.iter_mut().take(keys.len()).enumerate() {
+                *dst = if hit_mask & (1 << i) != 0 {
+                    Some(values[i])
+                } else {
+                    None
+                };
+            }
+        }
+
+        hits
+    }
+
+    /// An iterator visiting all the keys, user data pointers and positions in arbitrary order.
+    pub fn iter(&self) -> HashIter<'_> {
+        HashIter { table: self, next: 0 }
+    }
+}
+
+/// An iterator over the entries of a `HashTable`.
+pub struct HashIter<'a> {
+    table: &'a HashTable,
+    next: u32,
+}
+
+impl<'a> Iterator for HashIter<'a> {
+    type Item = (&'a [u8], *mut c_void, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut key = ptr::null();
+        let mut data = ptr::null_mut();
+
+        let pos = unsafe { ffi::rte_hash_iterate(self.table.raw.as_ptr(), &mut key, &mut data, &mut self.next) };
+
+        if pos < 0 {
+            None
+        } else {
+            let key = unsafe { slice::from_raw_parts(key as *const u8, self.table.key_len) };
+
+            Some((key, data, pos as usize))
+        }
+    }
+}
+
+/// A hash map which stores the boxed values as the user data pointers of a `HashTable`.
+///
+/// The keys are hashed and compared as bytes, so `K` should not contain any padding or pointer.
+pub struct HashMapLike<K, V> {
+    table: HashTable,
+    phantom: PhantomData<(K, Box<V>)>,
+}
+
+unsafe impl<K: Send, V: Send> Send for HashMapLike<K, V> {}
+unsafe impl<K: Sync, V: Sync> Sync for HashMapLike<K, V> {}
+
+impl<K, V> Drop for HashMapLike<K, V> {
+    fn drop(&mut self) {
+        self.clear();
+
+        unsafe { ffi::rte_hash_free(self.table.raw.as_ptr()) }
+    }
+}
+
+fn key_bytes<K>(key: &K) -> &[u8] {
+    unsafe { slice::from_raw_parts(key as *const K as *const u8, mem::size_of::<K>()) }
+}
+
+impl<K, V> HashMapLike<K, V> {
+    /// Create a map named `name`, which can hold `entries` keys allocated on the NUMA socket.
+    pub fn new<S: AsRef<str>>(name: S, entries: usize, socket_id: SocketId) -> Result<Self> {
+        let params = HashParams {
+            socket_id,
+            ..HashParams::new(name, entries, mem::size_of::<K>())
+        };
+
+        HashTable::create(&params).map(|table| HashMapLike {
+            table,
+            phantom: PhantomData,
+        })
+    }
+
+    /// The underlying hash table.
+    pub fn table(&self) -> &HashTable {
+        &self.table
+    }
+
+    /// The number of the entries in the map.
+    pub fn len(&self) -> usize {
+        self.table.count()
+    }
+
+    /// Test if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert a key-value pair into the map, return the old value if the key was present.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        let old = self.remove(&key);
+        let data = Box::into_raw(Box::new(value));
+
+        if let Err(err) = self.table.add_key_data(key_bytes(&key), data as *mut c_void) {
+            drop(unsafe { Box::from_raw(data) });
+
+            return Err(err);
+        }
+
+        Ok(old)
+    }
+
+    /// Get a reference to the value of the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.table
+            .lookup_data(key_bytes(key))
+            .map(|data| unsafe { &*(data as *const V) })
+    }
+
+    /// Get a mutable reference to the value of the key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.table
+            .lookup_data(key_bytes(key))
+            .map(|data| unsafe { &mut *(data as *mut V) })
+    }
+
+    /// Remove the key from the map, return its value if the key was present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let data = self.table.lookup_data(key_bytes(key))?;
+
+        self.table.del_key(key_bytes(key));
+
+        Some(*unsafe { Box::from_raw(data as *mut V) })
+    }
+
+    /// Remove all the entries in the map.
+    pub fn clear(&mut self) {
+        for (_, data, _) in self.table.iter() {
+            drop(unsafe { Box::from_raw(data as *mut V) });
+        }
+
+        self.table.reset();
+    }
+
+    /// An iterator visiting all the key-value pairs in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.table
+            .iter()
+            .map(|(key, data, _)| unsafe { (&*(key.as_ptr() as *const K), &*(data as *const V)) })
+    }
+}
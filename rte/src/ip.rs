@@ -1,7 +1,83 @@
 use ffi;
 
+use byteorder::{be16, be32};
+use mbuf::OffloadFlags;
+
 /// IPv4 Header
 pub type Ipv4Hdr = ffi::rte_ipv4_hdr;
 
 /// IPv6 Header
 pub type Ipv6Hdr = ffi::rte_ipv6_hdr;
+
+/// The fields of the IPv4 header in the network byte order.
+pub trait Ipv4HdrExt {
+    /// The length of the packet.
+    fn total_length(&self) -> be16;
+
+    /// Set the length of the packet.
+    fn set_total_length(&mut self, len: be16);
+
+    /// The packet ID.
+    fn packet_id(&self) -> be16;
+
+    /// The source address.
+    fn src_addr(&self) -> be32;
+
+    /// Set the source address.
+    fn set_src_addr(&mut self, addr: be32);
+
+    /// The destination address.
+    fn dst_addr(&self) -> be32;
+
+    /// Set the destination address.
+    fn set_dst_addr(&mut self, addr: be32);
+
+    /// The non-complemented pseudo-header checksum to set in the L4 header for the Tx offload flags.
+    fn phdr_cksum(&self, ol_flags: OffloadFlags) -> u16;
+}
+
+impl Ipv4HdrExt for Ipv4Hdr {
+    fn total_length(&self) -> be16 {
+        be16::from_raw(self.total_length)
+    }
+
+    fn set_total_length(&mut self, len: be16) {
+        self.total_length = len.raw()
+    }
+
+    fn packet_id(&self) -> be16 {
+        be16::from_raw(self.packet_id)
+    }
+
+    fn src_addr(&self) -> be32 {
+        be32::from_raw(self.src_addr)
+    }
+
+    fn set_src_addr(&mut self, addr: be32) {
+        self.src_addr = addr.raw()
+    }
+
+    fn dst_addr(&self) -> be32 {
+        be32::from_raw(self.dst_addr)
+    }
+
+    fn set_dst_addr(&mut self, addr: be32) {
+        self.dst_addr = addr.raw()
+    }
+
+    fn phdr_cksum(&self, ol_flags: OffloadFlags) -> u16 {
+        unsafe { ffi::_rte_ipv4_phdr_cksum(self, ol_flags.bits()) }
+    }
+}
+
+/// The fields of the IPv6 header.
+pub trait Ipv6HdrExt {
+    /// The non-complemented pseudo-header checksum to set in the L4 header for the Tx offload flags.
+    fn phdr_cksum(&self, ol_flags: OffloadFlags) -> u16;
+}
+
+impl Ipv6HdrExt for Ipv6Hdr {
+    fn phdr_cksum(&self, ol_flags: OffloadFlags) -> u16 {
+        unsafe { ffi::_rte_ipv6_phdr_cksum(self, ol_flags.bits()) }
+    }
+}
@@ -3,6 +3,7 @@ use std::ffi::CStr;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use anyhow::{anyhow, Result};
 use libc;
@@ -114,6 +115,9 @@ pub type RawKniDevicePtr = *mut ffi::rte_kni;
 
 pub struct KniDevice(RawKniDevicePtr);
 
+// The KNI FIFOs are single producer and single consumer, so the device can't be shared.
+unsafe impl Send for KniDevice {}
+
 impl Drop for KniDevice {
     fn drop(&mut self) {
         self.release().expect("fail to release KNI device")
@@ -201,7 +205,7 @@ impl KniDevice {
     /// It handles allocating the mbufs for KNI interface alloc queue.
     ///
     pub fn tx_burst(&self, mbufs: &mut [mbuf::RawMBufPtr]) -> usize {
-        unsafe { ffi::rte_kni_rx_burst(self.0, mbufs.as_mut_ptr(), mbufs.len() as u32) as usize }
+        unsafe { ffi::rte_kni_tx_burst(self.0, mbufs.as_mut_ptr(), mbufs.len() as u32) as usize }
     }
 
     /// Register KNI request handling for a specified port,
@@ -217,3 +221,107 @@ impl KniDevice {
         rte_check!(unsafe { ffi::rte_kni_unregister_handlers(self.0) }; ok => { self })
     }
 }
+
+/// The packet statistics of a KNI interface.
+///
+/// The counters are updated by the RX and TX lcores of the interface,
+/// and can be read or reset by any lcore.
+#[repr(align(64))]
+#[derive(Debug, Default)]
+pub struct InterfaceStats {
+    rx_packets: AtomicU64,
+    rx_dropped: AtomicU64,
+    tx_packets: AtomicU64,
+    tx_dropped: AtomicU64,
+}
+
+/// The snapshot of the packet statistics of a KNI interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InterfaceStatsSnapshot {
+    /// number of pkts received from NIC, and sent to KNI
+    pub rx_packets: u64,
+    /// number of pkts received from NIC, but failed to send to KNI
+    pub rx_dropped: u64,
+    /// number of pkts received from KNI, and sent to NIC
+    pub tx_packets: u64,
+    /// number of pkts received from KNI, but failed to send to NIC
+    pub tx_dropped: u64,
+}
+
+impl InterfaceStats {
+    pub const fn new() -> Self {
+        InterfaceStats {
+            rx_packets: AtomicU64::new(0),
+            rx_dropped: AtomicU64::new(0),
+            tx_packets: AtomicU64::new(0),
+            tx_dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Count the packets received from NIC, which were sent to KNI or dropped.
+    #[inline]
+    pub fn add_rx(&self, sent: usize, dropped: usize) {
+        Self::add(&self.rx_packets, sent);
+        Self::add(&self.rx_dropped, dropped);
+    }
+
+    /// Count the packets received from KNI, which were sent to NIC or dropped.
+    #[inline]
+    pub fn add_tx(&self, sent: usize, dropped: usize) {
+        Self::add(&self.tx_packets, sent);
+        Self::add(&self.tx_dropped, dropped);
+    }
+
+    /// Get the snapshot of the statistics.
+    pub fn snapshot(&self) -> InterfaceStatsSnapshot {
+        InterfaceStatsSnapshot {
+            rx_packets: self.rx_packets.load(Ordering::Relaxed),
+            rx_dropped: self.rx_dropped.load(Ordering::Relaxed),
+            tx_packets: self.tx_packets.load(Ordering::Relaxed),
+            tx_dropped: self.tx_dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset the statistics, return the snapshot before reset.
+    pub fn reset(&self) -> InterfaceStatsSnapshot {
+        InterfaceStatsSnapshot {
+            rx_packets: self.rx_packets.swap(0, Ordering::Relaxed),
+            rx_dropped: self.rx_dropped.swap(0, Ordering::Relaxed),
+            tx_packets: self.tx_packets.swap(0, Ordering::Relaxed),
+            tx_dropped: self.tx_dropped.swap(0, Ordering::Relaxed),
+        }
+    }
+
+    // the counters may be reset by another lcore, so they need the atomic read-modify-write.
+    #[inline]
+    fn add(counter: &AtomicU64, n: usize) {
+        if n > 0 {
+            counter.fetch_add(n as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interface_stats() {
+        let stats = InterfaceStats::new();
+
+        stats.add_rx(30, 2);
+        stats.add_tx(20, 0);
+        stats.add_rx(1, 0);
+
+        let snapshot = InterfaceStatsSnapshot {
+            rx_packets: 31,
+            rx_dropped: 2,
+            tx_packets: 20,
+            tx_dropped: 0,
+        };
+
+        assert_eq!(stats.snapshot(), snapshot);
+        assert_eq!(stats.reset(), snapshot);
+        assert_eq!(stats.snapshot(), InterfaceStatsSnapshot::default());
+    }
+}
@@ -39,19 +39,50 @@ mod common;
 #[macro_use]
 pub mod utils;
 
+pub mod acl;
+pub mod fib;
+pub mod hash;
+pub mod lpm;
 pub mod mbuf;
 pub mod mempool;
+pub mod ptype;
+pub mod rcu;
+pub mod rib;
 pub mod ring;
+pub mod stack;
 
 pub mod bond;
+pub mod cryptodev;
 pub mod ethdev;
+pub mod eventdev;
+pub mod flow;
+pub mod flowtable;
 pub mod kni;
+pub mod mtr;
 pub mod pci;
+pub mod softnic;
+pub mod tm;
 
 pub mod arp;
 pub mod ether;
+pub mod gro;
+pub mod gso;
 pub mod ip;
+pub mod net_crc;
+pub mod reorder;
+pub mod route;
 
+pub mod metrics;
+pub mod pdump;
+pub mod power;
+pub mod stats;
+
+pub mod swx;
+
+pub mod red;
+pub mod sched;
+
+pub mod cfgfile;
 #[macro_use]
 pub mod cmdline;
 
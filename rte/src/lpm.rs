@@ -0,0 +1,254 @@
+//!
+//! RTE LPM
+//!
+//! The LPM library implements the longest prefix match of the IPv4 addresses,
+//! the routes with up to 24 bits are stored in a 2^24 entries table,
+//! and the longer ones are stored in the tbl8 groups.
+//!
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ptr::NonNull;
+
+use anyhow::{anyhow, Result};
+
+use errors::AsResult;
+use ffi;
+use memory::SocketId;
+use utils::{AsCString, AsRaw};
+
+pub use ffi::{RTE_LPM6_MAX_DEPTH, RTE_LPM_LOOKUP_SUCCESS, RTE_LPM_MAX_DEPTH, RTE_LPM_NAMESIZE};
+
+pub type RawLpm = ffi::rte_lpm;
+pub type RawLpm6 = ffi::rte_lpm6;
+
+/// The configuration of an LPM table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LpmConfig {
+    /// The maximum number of routes.
+    pub max_rules: u32,
+    /// The number of the tbl8 groups, which are used by the routes longer than 24 bits.
+    pub number_tbl8s: u32,
+}
+
+impl Default for LpmConfig {
+    fn default() -> Self {
+        LpmConfig {
+            max_rules: 1024,
+            number_tbl8s: 256,
+        }
+    }
+}
+
+/// An LPM table of the IPv4 routes, which next hops are up to 24 bits.
+#[derive(Debug)]
+pub struct Lpm(NonNull<RawLpm>);
+
+unsafe impl Send for Lpm {}
+unsafe impl Sync for Lpm {}
+
+impl Drop for Lpm {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_lpm_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Lpm {
+    type Raw = RawLpm;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Lpm {
+    /// Create an LPM table named `name` allocated on the NUMA socket.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, conf: &LpmConfig) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let raw_conf = ffi::rte_lpm_config {
+            max_rules: conf.max_rules,
+            number_tbl8s: conf.number_tbl8s,
+            flags: 0,
+        };
+
+        unsafe { ffi::rte_lpm_create(name.as_ptr(), socket_id, &raw_conf) }
+            .as_result()
+            .map(Lpm)
+    }
+
+    /// Add a route of the prefix to the table, the next hop of an existing route is updated.
+    pub fn add(&mut self, prefix: Ipv4Addr, depth: u8, next_hop: u32) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_lpm_add(self.0.as_ptr(), prefix.into(), depth, next_hop) }; err => {
+            anyhow!("fail to add route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Get the next hop of the route if it is present in the table.
+    pub fn get(&self, prefix: Ipv4Addr, depth: u8) -> Option<u32> {
+        let mut next_hop = 0;
+
+        match unsafe { ffi::rte_lpm_is_rule_present(self.0.as_ptr(), prefix.into(), depth, &mut next_hop) } {
+            1 => Some(next_hop),
+            _ => None,
+        }
+    }
+
+    /// Delete the route of the prefix from the table.
+    pub fn delete(&mut self, prefix: Ipv4Addr, depth: u8) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_lpm_delete(self.0.as_ptr(), prefix.into(), depth) }; err => {
+            anyhow!("fail to delete route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Delete all the routes from the table.
+    pub fn clear(&mut self) {
+        unsafe { ffi::rte_lpm_delete_all(self.0.as_ptr()) }
+    }
+
+    /// Lookup the next hop of the IP address.
+    #[inline]
+    pub fn lookup(&self, ip: Ipv4Addr) -> Option<u32> {
+        let mut next_hop = 0;
+
+        if unsafe { ffi::_rte_lpm_lookup(self.0.as_ptr(), ip.into(), &mut next_hop) } == 0 {
+            Some(next_hop)
+        } else {
+            None
+        }
+    }
+
+    /// Lookup the next hops of the IP addresses in the host byte order.
+    ///
+    /// The next hop of an IP address is valid only if `RTE_LPM_LOOKUP_SUCCESS` is set,
+    /// which can be checked with `lookup_hit()`.
+    #[inline]
+    pub fn lookup_bulk(&self, ips: &[u32], next_hops: &mut [u32]) {
+        let n = ips.len().min(next_hops.len());
+
+        unsafe {
+            ffi::_rte_lpm_lookup_bulk(self.0.as_ptr(), ips.as_ptr(), next_hops.as_mut_ptr(), n as u32);
+        }
+    }
+
+    /// Lookup the next hops of four IP addresses in the host byte order, return `defv` if there is no route.
+    #[inline]
+    pub fn lookupx4(&self, ips: [u32; 4], defv: u32) -> [u32; 4] {
+        let mut hops = [0; 4];
+
+        unsafe {
+            ffi::_rte_lpm_lookupx4(self.0.as_ptr(), ips.as_ptr(), hops.as_mut_ptr(), defv);
+        }
+
+        hops
+    }
+}
+
+/// Get the next hop from the result of `Lpm::lookup_bulk()`, if the lookup was successful.
+#[inline]
+pub fn lookup_hit(next_hop: u32) -> Option<u32> {
+    if next_hop & RTE_LPM_LOOKUP_SUCCESS != 0 {
+        Some(next_hop & (RTE_LPM_LOOKUP_SUCCESS - 1))
+    } else {
+        None
+    }
+}
+
+/// An LPM table of the IPv6 routes, which next hops are up to 21 bits.
+#[derive(Debug)]
+pub struct Lpm6(NonNull<RawLpm6>);
+
+unsafe impl Send for Lpm6 {}
+unsafe impl Sync for Lpm6 {}
+
+impl Drop for Lpm6 {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_lpm6_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Lpm6 {
+    type Raw = RawLpm6;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Lpm6 {
+    /// Create an LPM6 table named `name` allocated on the NUMA socket.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, conf: &LpmConfig) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let raw_conf = ffi::rte_lpm6_config {
+            max_rules: conf.max_rules,
+            number_tbl8s: conf.number_tbl8s,
+            flags: 0,
+        };
+
+        unsafe { ffi::rte_lpm6_create(name.as_ptr(), socket_id, &raw_conf) }
+            .as_result()
+            .map(Lpm6)
+    }
+
+    /// Add a route of the prefix to the table, the next hop of an existing route is updated.
+    pub fn add(&mut self, prefix: Ipv6Addr, depth: u8, next_hop: u32) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_lpm6_add(self.0.as_ptr(), prefix.octets().as_ptr(), depth, next_hop) }; err => {
+            anyhow!("fail to add route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Get the next hop of the route if it is present in the table.
+    pub fn get(&self, prefix: Ipv6Addr, depth: u8) -> Option<u32> {
+        let mut next_hop = 0;
+
+        match unsafe { ffi::rte_lpm6_is_rule_present(self.0.as_ptr(), prefix.octets().as_ptr(), depth, &mut next_hop) }
+        {
+            1 => Some(next_hop),
+            _ => None,
+        }
+    }
+
+    /// Delete the route of the prefix from the table.
+    pub fn delete(&mut self, prefix: Ipv6Addr, depth: u8) -> Result<()> {
+        rte_check!(unsafe { ffi::rte_lpm6_delete(self.0.as_ptr(), prefix.octets().as_ptr(), depth) }; err => {
+            anyhow!("fail to delete route {}/{}", prefix, depth)
+        })
+    }
+
+    /// Delete all the routes from the table.
+    pub fn clear(&mut self) {
+        unsafe { ffi::rte_lpm6_delete_all(self.0.as_ptr()) }
+    }
+
+    /// Lookup the next hop of the IP address.
+    #[inline]
+    pub fn lookup(&self, ip: Ipv6Addr) -> Option<u32> {
+        let mut next_hop = 0;
+
+        if unsafe { ffi::rte_lpm6_lookup(self.0.as_ptr(), ip.octets().as_ptr(), &mut next_hop) } == 0 {
+            Some(next_hop)
+        } else {
+            None
+        }
+    }
+
+    /// Lookup the next hops of the IP addresses, the next hop is -1 if there is no route.
+    #[inline]
+    pub fn lookup_bulk(&self, ips: &[[u8; 16]], next_hops: &mut [i32]) {
+        let n = ips.len().min(next_hops.len());
+
+        unsafe {
+            ffi::rte_lpm6_lookup_bulk_func(
+                self.0.as_ptr(),
+                ips.as_ptr() as *mut _,
+                next_hops.as_mut_ptr(),
+                n as u32,
+            );
+        }
+    }
+}
@@ -22,19 +22,28 @@
 //! http://www.kohala.com/start/tcpipiv2.html
 //!
 use std::ffi::CStr;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
 use std::os::raw::c_void;
 use std::os::unix::io::AsRawFd;
 use std::ptr::{self, NonNull};
 use std::slice;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use cfile;
+use libc;
 
 use ffi;
 
+use common::{memcpy, prefetch0};
 use errors::AsResult;
+use ethdev::{EthDevice, PortId};
+use lcore;
+use memory::{SocketId, SOCKET_ID_ANY};
 use mempool;
-use utils::{AsCString, AsRaw, CallbackContext, IntoRaw};
+use ptype::PacketType;
+use utils::{catch_panic, AsCString, AsRaw, CallbackContext, IntoRaw};
 
 pub use ffi::{RTE_MBUF_DEFAULT_BUF_SIZE, RTE_MBUF_DEFAULT_DATAROOM, RTE_MBUF_MAX_NB_SEGS, RTE_MBUF_PRIV_ALIGN};
 
@@ -297,21 +306,17 @@ pub type RawMBufPtr = *mut ffi::rte_mbuf;
 
 raw!(pub MBuf(RawMBuf));
 
-impl mempool::Pooled<RawMBuf> for MBuf {}
+// A packet may be passed to another lcore, but it can't be accessed by several lcores at the same time,
+// so it isn't `Clone`; the data is shared with `MBufPool::clone()`, which attaches it to a new header.
+unsafe impl Send for MBuf {}
 
-impl Clone for MBuf {
-    fn clone(&self) -> Self {
-        let mut m = MBuf(self.0);
-        m.refcnt_update(1);
-        m
-    }
-}
+impl mempool::Pooled<RawMBuf> for MBuf {}
 
 impl Drop for MBuf {
     fn drop(&mut self) {
-        if self.refcnt_update(-1) == 0 {
-            self.free()
-        }
+        // `rte_pktmbuf_free()` decreases the reference counter of each segment,
+        // and only puts the segments back to the mempool when it reaches zero.
+        unsafe { ffi::_rte_pktmbuf_free(self.as_raw_mut()) }
     }
 }
 
@@ -327,9 +332,21 @@ impl MBuf {
         unsafe { ffi::_rte_mbuf_prefetch_part2(self.as_raw_mut()) }
     }
 
+    /// Prefetch the first cache line of the packet data into all cache levels.
+    #[inline]
+    pub fn prefetch_data(&self) {
+        prefetch0(self.mtod::<u8>().as_ptr())
+    }
+
     /// Return the mbuf owning the data buffer address of an indirect mbuf.
-    pub fn from_indirect(other: &MBuf) -> Self {
-        unsafe { ffi::_rte_mbuf_from_indirect(other.as_raw_mut()) }.into()
+    ///
+    /// The direct mbuf is borrowed from the indirect one, which holds a reference of it.
+    pub fn from_indirect<'a>(other: &'a MBuf) -> Option<MBufRef<'a>> {
+        if other.is_indirect() {
+            Some(unsafe { MBufRef::new(ffi::_rte_mbuf_from_indirect(other.as_raw_mut())) })
+        } else {
+            None
+        }
     }
 
     /// Return the buffer address embedded in the given mbuf.
@@ -352,6 +369,140 @@ impl MBuf {
         OffloadFlags::from_bits_truncate(self.ol_flags)
     }
 
+    /// Set the offload features.
+    #[inline]
+    pub fn set_offload(&mut self, flags: OffloadFlags) -> &mut Self {
+        self.ol_flags = flags.bits;
+        self
+    }
+
+    /// Enable the offload features in addition to the current ones.
+    #[inline]
+    pub fn add_offload(&mut self, flags: OffloadFlags) -> &mut Self {
+        self.ol_flags |= flags.bits;
+        self
+    }
+
+    /// The packet type recognized by the hardware or the driver.
+    #[inline]
+    pub fn ptype(&self) -> PacketType {
+        PacketType(unsafe { self.__bindgen_anon_1.packet_type })
+    }
+
+    /// Set the packet type, e.g. for the Tx offloads of the tunnel packets.
+    #[inline]
+    pub fn set_ptype(&mut self, ptype: PacketType) -> &mut Self {
+        self.__bindgen_anon_1.packet_type = ptype.0;
+        self
+    }
+
+    /// Check if the packet is an IEEE1588 PTP packet recognized by the hardware.
+    #[inline]
+    pub fn is_ieee1588_ptp(&self) -> bool {
+        self.offload().contains(OffloadFlags::PKT_RX_IEEE1588_PTP)
+    }
+
+    /// The timestamp register of the PTP packet timestamped by the hardware,
+    /// which should be passed to `EthDevice::read_rx_timestamp()`.
+    #[inline]
+    pub fn timesync(&self) -> Option<u32> {
+        if self.offload().contains(OffloadFlags::PKT_RX_IEEE1588_TMST) {
+            Some(u32::from(self.timesync))
+        } else {
+            None
+        }
+    }
+
+    /// The length of the L2 header, used by the Tx offloads.
+    #[inline]
+    pub fn l2_len(&self) -> u8 {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.l2_len() as u8 }
+    }
+
+    /// Set the length of the L2 header, which is up to 7 bits.
+    #[inline]
+    pub fn set_l2_len(&mut self, len: u8) -> &mut Self {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.set_l2_len(len.into()) };
+        self
+    }
+
+    /// The length of the L3 header, used by the Tx offloads.
+    #[inline]
+    pub fn l3_len(&self) -> u16 {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.l3_len() as u16 }
+    }
+
+    /// Set the length of the L3 header, which is up to 9 bits.
+    #[inline]
+    pub fn set_l3_len(&mut self, len: u16) -> &mut Self {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.set_l3_len(len.into()) };
+        self
+    }
+
+    /// The length of the L4 header, used by TSO.
+    #[inline]
+    pub fn l4_len(&self) -> u8 {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.l4_len() as u8 }
+    }
+
+    /// Set the length of the L4 header.
+    #[inline]
+    pub fn set_l4_len(&mut self, len: u8) -> &mut Self {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.set_l4_len(len.into()) };
+        self
+    }
+
+    /// The TCP segment size of TSO.
+    #[inline]
+    pub fn tso_segsz(&self) -> u16 {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.tso_segsz() as u16 }
+    }
+
+    /// Set the TCP segment size of TSO.
+    #[inline]
+    pub fn set_tso_segsz(&mut self, segsz: u16) -> &mut Self {
+        unsafe { self.__bindgen_anon_3.__bindgen_anon_1.set_tso_segsz(segsz.into()) };
+        self
+    }
+
+    /// The queue ID of the QoS scheduler, written by the classification stage.
+    #[inline]
+    pub fn sched_queue_id(&self) -> u32 {
+        unsafe { self.__bindgen_anon_2.hash.sched.queue_id }
+    }
+
+    /// The traffic class of the QoS scheduler.
+    #[inline]
+    pub fn sched_traffic_class(&self) -> u8 {
+        unsafe { self.__bindgen_anon_2.hash.sched.traffic_class }
+    }
+
+    /// The color of the QoS scheduler.
+    #[inline]
+    pub fn sched_color(&self) -> u8 {
+        unsafe { self.__bindgen_anon_2.hash.sched.color }
+    }
+
+    /// Set the queue ID, the traffic class and the color of the QoS scheduler, which overwrites the RSS hash.
+    #[inline]
+    pub fn set_sched(&mut self, queue_id: u32, traffic_class: u8, color: u8) -> &mut Self {
+        self.__bindgen_anon_2.hash.sched = ffi::rte_mbuf_sched {
+            queue_id,
+            traffic_class,
+            color,
+            reserved: 0,
+        };
+        self
+    }
+
+    /// Fill the pseudo-header checksum of the TCP or UDP header for the Tx checksum offload or TSO.
+    ///
+    /// The offload flags, `l2_len` and `l3_len` (and `l4_len` for TSO) must be set before,
+    /// and the headers must be in the first segment.
+    pub fn prepare_cksum(&mut self) -> Result<()> {
+        rte_check!(unsafe { ffi::_rte_net_intel_cksum_flags_prepare(self.as_raw_mut(), self.ol_flags) })
+    }
+
     /// The mbuf is cloned by mbuf indirection.
     #[inline]
     pub fn has_cloned(&self) -> bool {
@@ -373,7 +524,8 @@ impl MBuf {
     /// The mbuf is direct
     #[inline]
     pub fn is_direct(&self) -> bool {
-        self.offload()
+        !self
+            .offload()
             .intersects(OffloadFlags::IND_ATTACHED_MBUF | OffloadFlags::EXT_ATTACHED_MBUF)
     }
 
@@ -388,26 +540,26 @@ impl MBuf {
     }
 
     /// Free a segment of a packet mbuf into its original mempool.
-    pub fn free_seg(&mut self) {
-        unsafe { ffi::_rte_pktmbuf_free_seg(self.as_raw_mut()) }
+    pub fn free_seg(self) {
+        unsafe { ffi::_rte_pktmbuf_free_seg(self.into_raw()) }
     }
 
     /// Free a packet mbuf back into its original mempool.
     ///
     /// Free an mbuf, and all its segments in case of chained buffers.
     /// Each segment is added back into its original mempool.
-    pub fn free(&mut self) {
-        unsafe { ffi::_rte_pktmbuf_free(self.as_raw_mut()) }
+    pub fn free(self) {
+        unsafe { ffi::_rte_pktmbuf_free(self.into_raw()) }
     }
 
     /// Put mbuf back into its original mempool.
-    pub fn raw_free(&mut self) {
+    pub fn raw_free(self) {
         debug_assert!(self.is_direct());
         debug_assert_eq!(self.refcnt_read(), 1);
         debug_assert!(self.next.is_null());
         debug_assert_eq!(self.nb_segs, 1);
 
-        unsafe { ffi::_rte_mbuf_raw_free(self.as_raw_mut()) }
+        unsafe { ffi::_rte_mbuf_raw_free(self.into_raw()) }
     }
 
     /// Reads the value of an mbuf's refcnt.
@@ -425,6 +577,41 @@ impl MBuf {
         unsafe { ffi::_rte_mbuf_refcnt_update(self.as_raw_mut(), new) }
     }
 
+    /// Adds given value to the refcnt of all the segments of a packet mbuf.
+    pub fn pktmbuf_refcnt_update(&mut self, v: i16) {
+        unsafe { ffi::_rte_pktmbuf_refcnt_update(self.as_raw_mut(), v) }
+    }
+
+    /// The mbuf is shared by more than one owner, e.g. cloned or attached by an indirect mbuf.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        self.refcnt_read() > 1
+    }
+
+    /// The data of all the segments is owned only by the packet, so it can be modified.
+    #[inline]
+    pub fn is_writable(&self) -> bool {
+        let mut next = self.as_raw();
+
+        while let Some(m) = unsafe { next.as_ref() } {
+            if !unsafe { is_writable_seg(m) } {
+                return false;
+            }
+
+            next = m.next;
+        }
+
+        true
+    }
+
+    fn check_writable(&self) -> Result<()> {
+        if self.is_writable() {
+            Ok(())
+        } else {
+            Err(anyhow!("the packet data is shared"))
+        }
+    }
+
     /// Sanity checks on an mbuf.
     ///
     /// Check the consistency of the given mbuf.
@@ -453,9 +640,9 @@ impl MBuf {
         unsafe { ffi::_rte_pktmbuf_tailroom(self.as_raw()) }
     }
 
-    /// Get the last segment of the packet.
-    pub fn lastseg(&self) -> Self {
-        unsafe { ffi::_rte_pktmbuf_lastseg(self.as_raw_mut()) }.into()
+    /// Get the last segment of the packet, which is borrowed from the packet.
+    pub fn lastseg<'a>(&'a self) -> MBufRef<'a> {
+        unsafe { MBufRef::new(ffi::_rte_pktmbuf_lastseg(self.as_raw_mut())) }
     }
 
     /// Get a pointer which points to an offset into the data in the mbuf.
@@ -508,32 +695,122 @@ impl MBuf {
         self.data_len as usize
     }
 
-    /// Prepend len bytes to an mbuf data area.
-    pub fn prepend(&mut self, len: usize) -> Result<NonNull<u8>> {
-        unsafe { ffi::_rte_pktmbuf_prepend(self.as_raw_mut(), len as u16) }
-            .as_result()
-            .map(|p| p.cast())
+    /// The data of the first segment.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.mtod::<u8>().as_ptr(), self.data_len()) }
     }
 
-    /// Append len bytes to an mbuf.
-    pub fn append(&mut self, len: usize) -> Result<NonNull<u8>> {
-        unsafe { ffi::_rte_pktmbuf_append(self.as_raw_mut(), len as u16) }
-            .as_result()
-            .map(|p| p.cast())
+    /// The mutable data of the first segment, or `None` if the data is shared with another mbuf.
+    #[inline]
+    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+        if unsafe { is_writable_seg(self.as_raw()) } {
+            Some(unsafe { slice::from_raw_parts_mut(self.mtod::<u8>().as_ptr(), self.data_len()) })
+        } else {
+            None
+        }
     }
 
-    /// Remove len bytes at the beginning of an mbuf.
-    pub fn adj(&mut self, len: usize) -> Result<NonNull<u8>> {
-        unsafe { ffi::_rte_pktmbuf_adj(self.as_raw_mut(), len as u16) }
-            .as_result()
-            .map(|p| p.cast())
+    /// Returns the number of segments of the packet.
+    #[inline]
+    pub fn nb_segs(&self) -> usize {
+        self.nb_segs as usize
     }
 
-    /// Remove len bytes of data at the end of the mbuf.
-    pub fn trim(&mut self, len: usize) -> Result<()> {
-        unsafe { ffi::_rte_pktmbuf_trim(self.as_raw_mut(), len as u16) }
-            .as_result()
-            .map(|_| ())
+    /// Returns an iterator over the data of the segments of the packet.
+    #[inline]
+    pub fn segments(&self) -> Segments {
+        Segments {
+            next: self.as_raw(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the mutable data of the segments of the packet,
+    /// or `None` if the data of a segment is shared with another mbuf.
+    #[inline]
+    pub fn segments_mut(&mut self) -> Option<SegmentsMut> {
+        if self.is_writable() {
+            Some(SegmentsMut {
+                next: self.as_raw_mut(),
+                phantom: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Prepend len bytes to an mbuf data area, return the prepended data.
+    pub fn prepend(&mut self, len: usize) -> Result<&mut [u8]> {
+        self.check_writable()?;
+
+        if len > self.headroom() as usize {
+            return Err(anyhow!("not enough headroom to prepend {} bytes", len));
+        }
+
+        let p = unsafe { ffi::_rte_pktmbuf_prepend(self.as_raw_mut(), len as u16) }.as_result()?;
+
+        Ok(unsafe { slice::from_raw_parts_mut(p.as_ptr() as *mut u8, len) })
+    }
+
+    /// Append len bytes to an mbuf, return the appended data in the last segment.
+    pub fn append(&mut self, len: usize) -> Result<&mut [u8]> {
+        self.check_writable()?;
+
+        let tailroom = unsafe { ffi::_rte_pktmbuf_tailroom(ffi::_rte_pktmbuf_lastseg(self.as_raw_mut())) };
+
+        if len > tailroom as usize {
+            return Err(anyhow!("not enough tailroom to append {} bytes", len));
+        }
+
+        let p = unsafe { ffi::_rte_pktmbuf_append(self.as_raw_mut(), len as u16) }.as_result()?;
+
+        Ok(unsafe { slice::from_raw_parts_mut(p.as_ptr() as *mut u8, len) })
+    }
+
+    /// Remove len bytes at the beginning of an mbuf, return the remaining data of the first segment.
+    pub fn adj(&mut self, len: usize) -> Result<&mut [u8]> {
+        self.check_writable()?;
+
+        if len > self.data_len() {
+            return Err(anyhow!(
+                "can't remove {} bytes from the {} bytes segment",
+                len,
+                self.data_len()
+            ));
+        }
+
+        unsafe { ffi::_rte_pktmbuf_adj(self.as_raw_mut(), len as u16) }.as_result()?;
+
+        Ok(unsafe { slice::from_raw_parts_mut(self.mtod::<u8>().as_ptr(), self.data_len()) })
+    }
+
+    /// Remove len bytes of data at the end of the mbuf, return the remaining data of the last segment.
+    pub fn trim(&mut self, len: usize) -> Result<&mut [u8]> {
+        self.check_writable()?;
+
+        let last = unsafe { ffi::_rte_pktmbuf_lastseg(self.as_raw_mut()) };
+        let data_len = unsafe { (*last).data_len };
+
+        if len > data_len as usize {
+            return Err(anyhow!(
+                "can't remove {} bytes from the {} bytes segment",
+                len,
+                data_len
+            ));
+        }
+
+        unsafe { ffi::_rte_pktmbuf_trim(self.as_raw_mut(), len as u16) }.as_result()?;
+
+        // the last segment is only referenced after it has been updated by `rte_pktmbuf_trim()`.
+        Ok(unsafe {
+            let last = &*last;
+
+            slice::from_raw_parts_mut(
+                (last.buf_addr as *mut u8).add(last.data_off as usize),
+                last.data_len as usize,
+            )
+        })
     }
 
     /// Test if mbuf data is contiguous.
@@ -553,10 +830,14 @@ impl MBuf {
     }
 
     /// Chain an mbuf to another, thereby creating a segmented packet.
-    pub fn chain(&self, tail: &Self) -> Result<()> {
+    ///
+    /// The tail is owned by the packet once chained, or freed if the packet has too many segments.
+    pub fn chain(&mut self, tail: Self) -> Result<()> {
         unsafe { ffi::_rte_pktmbuf_chain(self.as_raw_mut(), tail.as_raw_mut()) }
             .as_result()
-            .map(|_| ())
+            .map(|_| {
+                mem::forget(tail);
+            })
     }
 
     /// Validate general requirements for Tx offload in mbuf.
@@ -578,8 +859,19 @@ impl MBuf {
             .map(|_| ())
     }
 
-    /// Dump an mbuf structure to the console.
-    pub fn dump<S: AsRawFd>(&self, s: &S, dump_len: usize) {
+    /// Dump an mbuf structure and at most `dump_len` bytes of the packet data to the console.
+    pub fn dump(&self, dump_len: usize) {
+        unsafe {
+            let f = ffi::_rte_stdout();
+
+            ffi::rte_pktmbuf_dump(f, self.as_raw(), dump_len as u32);
+
+            libc::fflush(f as *mut libc::FILE);
+        }
+    }
+
+    /// Dump an mbuf structure and at most `dump_len` bytes of the packet data to the stream.
+    pub fn dump_to<S: AsRawFd>(&self, s: &S, dump_len: usize) {
         if let Ok(f) = cfile::fdopen(s, "w") {
             unsafe {
                 ffi::rte_pktmbuf_dump(f.stream() as *mut ffi::FILE, self.as_raw(), dump_len as u32);
@@ -588,6 +880,86 @@ impl MBuf {
     }
 }
 
+/// A segment or a direct mbuf borrowed from a packet, which isn't freed on drop.
+pub struct MBufRef<'a> {
+    m: mem::ManuallyDrop<MBuf>,
+    phantom: PhantomData<&'a MBuf>,
+}
+
+impl<'a> ::std::ops::Deref for MBufRef<'a> {
+    type Target = MBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.m
+    }
+}
+
+impl<'a> fmt::Debug for MBufRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.m.fmt(f)
+    }
+}
+
+impl<'a> MBufRef<'a> {
+    unsafe fn new(m: RawMBufPtr) -> Self {
+        MBufRef {
+            m: mem::ManuallyDrop::new(MBuf(NonNull::new_unchecked(m))),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the data of the segments of a packet.
+pub struct Segments<'a> {
+    next: *const RawMBuf,
+    phantom: PhantomData<&'a MBuf>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe { self.next.as_ref() }.map(|m| {
+            self.next = m.next;
+
+            unsafe { slice::from_raw_parts((m.buf_addr as *const u8).add(m.data_off as usize), m.data_len as usize) }
+        })
+    }
+}
+
+// The data of a segment can be modified only if no other mbuf refers to it.
+unsafe fn is_writable_seg(m: *const RawMBuf) -> bool {
+    let m = &*m;
+
+    if ffi::_rte_mbuf_refcnt_read(m) > 1 || m.ol_flags & ffi::IND_ATTACHED_MBUF != 0 {
+        false
+    } else if m.ol_flags & ffi::EXT_ATTACHED_MBUF != 0 {
+        ffi::_rte_mbuf_ext_refcnt_read(m.shinfo) == 1
+    } else {
+        true
+    }
+}
+
+/// An iterator over the mutable data of the segments of a packet.
+pub struct SegmentsMut<'a> {
+    next: *mut RawMBuf,
+    phantom: PhantomData<&'a mut MBuf>,
+}
+
+impl<'a> Iterator for SegmentsMut<'a> {
+    type Item = &'a mut [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe { self.next.as_mut() }.map(|m| {
+            self.next = m.next;
+
+            unsafe { slice::from_raw_parts_mut((m.buf_addr as *mut u8).add(m.data_off as usize), m.data_len as usize) }
+        })
+    }
+}
+
 pub type RawExtSharedInfo = ffi::rte_mbuf_ext_shared_info;
 pub type RawExtSharedInfoPtr = *mut ffi::rte_mbuf_ext_shared_info;
 
@@ -596,6 +968,10 @@ pub type ExtBufFreeCallback<T> = fn(buf: *mut u8, arg: Option<T>);
 
 raw!(pub ExtSharedInfo(RawExtSharedInfo));
 
+// The reference counter of the shared info is updated atomically.
+unsafe impl Send for ExtSharedInfo {}
+unsafe impl Sync for ExtSharedInfo {}
+
 impl ExtSharedInfo {
     /// Initialize shared data at the end of an external buffer before attaching
     /// to a mbuf by ``rte_pktmbuf_attach_extbuf()``. This is not a mandatory
@@ -653,7 +1029,9 @@ type ExtBufFreeContext<T> = CallbackContext<ExtBufFreeCallback<T>, Option<T>>;
 unsafe extern "C" fn ext_buf_free_callback_stub<T>(addr: *mut c_void, arg: *mut c_void) {
     let ctxt = ExtBufFreeContext::<T>::from_raw(arg);
 
-    (ctxt.callback)(addr as *mut u8, ctxt.arg)
+    catch_panic("external buffer free callback", (), || {
+        (ctxt.callback)(addr as *mut u8, ctxt.arg)
+    })
 }
 
 impl MBuf {
@@ -686,6 +1064,272 @@ impl MBuf {
     }
 }
 
+/// A fixed-size batch of packet mbufs living on the stack.
+///
+/// The batch is meant to be filled by a burst receive and drained by a burst
+/// transmit, so hot loops don't need a heap allocation or zero-initialized
+/// `Option<MBuf>` arrays. Only the first `len()` slots are initialized.
+pub struct MBufBatch<const N: usize> {
+    pkts: [MaybeUninit<MBuf>; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for MBufBatch<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Drop for MBufBatch<N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<const N: usize> MBufBatch<N> {
+    /// Create an empty batch.
+    #[inline]
+    pub fn new() -> Self {
+        MBufBatch {
+            pkts: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Create a batch from the first `len` slots of an array filled by a burst.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` slots of `pkts` must be initialized.
+    #[inline]
+    pub unsafe fn from_array(pkts: [MaybeUninit<MBuf>; N], len: usize) -> Self {
+        debug_assert!(len <= N);
+
+        MBufBatch { pkts, len }
+    }
+
+    /// The maximum number of mbufs in the batch.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of mbufs in the batch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Set the number of initialized mbufs in the batch.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` slots must be initialized and `len` must not exceed `N`.
+    #[inline]
+    pub unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= N);
+
+        self.len = len
+    }
+
+    /// Append a mbuf to the batch, returning it back if the batch is full.
+    #[inline]
+    pub fn push(&mut self, m: MBuf) -> ::std::result::Result<(), MBuf> {
+        if self.is_full() {
+            Err(m)
+        } else {
+            self.pkts[self.len] = MaybeUninit::new(m);
+            self.len += 1;
+
+            Ok(())
+        }
+    }
+
+    /// Remove the last mbuf from the batch.
+    #[inline]
+    pub fn pop(&mut self) -> Option<MBuf> {
+        if self.is_empty() {
+            None
+        } else {
+            self.len -= 1;
+
+            Some(unsafe { self.pkts.get_unchecked(self.len).as_ptr().read() })
+        }
+    }
+
+    /// Free all the mbufs in the batch back into their original mempools.
+    pub fn clear(&mut self) {
+        let len = self.len;
+
+        self.len = 0;
+
+        free_burst(unsafe { slice::from_raw_parts_mut(self.as_raw_mut_ptr(), len) })
+    }
+
+    /// Remove the first `n` mbufs from the batch without dropping them,
+    /// shifting the remaining mbufs to the front.
+    ///
+    /// It is used after a burst transmit, since the transmitted mbufs are owned by the driver.
+    ///
+    /// # Safety
+    ///
+    /// The ownership of the first `n` mbufs must have been taken by someone else, or they are leaked.
+    pub(crate) unsafe fn consume(&mut self, n: usize) {
+        let n = n.min(self.len);
+
+        ptr::copy(self.pkts.as_ptr().add(n), self.pkts.as_mut_ptr(), self.len - n);
+
+        self.len -= n;
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[MBuf] {
+        unsafe { slice::from_raw_parts(self.pkts.as_ptr() as *const MBuf, self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [MBuf] {
+        unsafe { slice::from_raw_parts_mut(self.pkts.as_mut_ptr() as *mut MBuf, self.len) }
+    }
+
+    /// A pointer to the raw mbuf array, suitable for burst functions.
+    #[inline]
+    pub fn as_raw_mut_ptr(&mut self) -> *mut RawMBufPtr {
+        self.pkts.as_mut_ptr() as *mut _
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, MBuf> {
+        self.as_slice().iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, MBuf> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<const N: usize> ::std::ops::Deref for MBufBatch<N> {
+    type Target = [MBuf];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> ::std::ops::DerefMut for MBufBatch<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a MBufBatch<N> {
+    type Item = &'a MBuf;
+    type IntoIter = slice::Iter<'a, MBuf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a mut MBufBatch<N> {
+    type Item = &'a mut MBuf;
+    type IntoIter = slice::IterMut<'a, MBuf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<const N: usize> IntoIterator for MBufBatch<N> {
+    type Item = MBuf;
+    type IntoIter = MBufBatchIntoIter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let batch = mem::ManuallyDrop::new(self);
+
+        MBufBatchIntoIter {
+            pkts: unsafe { ptr::read(&batch.pkts) },
+            pos: 0,
+            len: batch.len,
+        }
+    }
+}
+
+/// An owning iterator over the mbufs of a `MBufBatch`.
+pub struct MBufBatchIntoIter<const N: usize> {
+    pkts: [MaybeUninit<MBuf>; N],
+    pos: usize,
+    len: usize,
+}
+
+impl<const N: usize> Iterator for MBufBatchIntoIter<N> {
+    type Item = MBuf;
+
+    #[inline]
+    fn next(&mut self) -> Option<MBuf> {
+        if self.pos < self.len {
+            let m = unsafe { self.pkts.get_unchecked(self.pos).as_ptr().read() };
+
+            self.pos += 1;
+
+            Some(m)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.pos, Some(self.len - self.pos))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for MBufBatchIntoIter<N> {}
+
+impl<const N: usize> Drop for MBufBatchIntoIter<N> {
+    fn drop(&mut self) {
+        free_burst(unsafe {
+            slice::from_raw_parts_mut(
+                (self.pkts.as_mut_ptr() as *mut RawMBufPtr).add(self.pos),
+                self.len - self.pos,
+            )
+        })
+    }
+}
+
+/// Append the data to the tail of the mbuf.
+///
+/// The data is copied with `memcpy()`, so bulk payload copies use `rte_memcpy()`.
+pub fn copy_into_mbuf(m: &mut MBuf, data: &[u8]) -> Result<()> {
+    memcpy(m.append(data.len())?, data);
+
+    Ok(())
+}
+
+/// Free a burst of packet mbufs back into their original mempools.
+///
+/// The mbufs and all their segments are grouped by the mempool they come from,
+/// and each group is put back with a single bulk operation,
+/// which is much cheaper than freeing the packets one by one.
+///
+/// The array may contain NULL pointers, which are skipped.
+pub fn free_burst(pkts: &mut [RawMBufPtr]) {
+    if !pkts.is_empty() {
+        unsafe { ffi::rte_pktmbuf_free_bulk(pkts.as_mut_ptr(), pkts.len() as u32) }
+    }
+}
+
 pub trait MBufPool {
     /// Get the data room size of mbufs stored in a pktmbuf_pool
     fn data_room_size(&self) -> usize;
@@ -700,7 +1344,14 @@ pub trait MBufPool {
     fn alloc_bulk(&mut self, mbufs: &mut [Option<MBuf>]) -> Result<()>;
 
     /// Creates a "clone" of the given packet mbuf.
+    ///
+    /// The indirect mbufs of the clone are attached to the segments of the packet,
+    /// which data are shared and must not be modified while the clone is alive.
     fn clone(&mut self, mbuf: &MBuf) -> Result<MBuf>;
+
+    /// Creates a full copy of `len` bytes at the offset of the given packet mbuf,
+    /// the whole data after the offset is copied if `len` is `None`.
+    fn copy(&mut self, mbuf: &MBuf, off: usize, len: Option<usize>) -> Result<MBuf>;
 }
 
 impl MBufPool for mempool::MemoryPool {
@@ -729,6 +1380,19 @@ impl MBufPool for mempool::MemoryPool {
             .as_result()
             .map(MBuf)
     }
+
+    fn copy(&mut self, mbuf: &MBuf, off: usize, len: Option<usize>) -> Result<MBuf> {
+        unsafe {
+            ffi::rte_pktmbuf_copy(
+                mbuf.as_raw(),
+                self.as_raw_mut(),
+                off as u32,
+                len.map_or(u32::max_value(), |len| len as u32),
+            )
+        }
+        .as_result()
+        .map(MBuf)
+    }
 }
 
 /// Create a mbuf pool.
@@ -783,3 +1447,63 @@ pub fn pool_create_by_ops<S: AsRef<str>>(
     .map(|p| p.as_ptr())
     .map(mempool::MemoryPool::from)
 }
+
+/// The packet mbuf pools created on each NUMA socket, so the packets can be allocated from the local memory.
+pub struct SocketPools {
+    pools: Vec<(SocketId, mempool::MemoryPool)>,
+}
+
+impl SocketPools {
+    /// Create a packet mbuf pool on each socket which has the enabled lcores.
+    ///
+    /// The pools are named as `<prefix>_<socket_id>`.
+    pub fn create<S: AsRef<str>>(
+        prefix: S,
+        n: u32,
+        cache_size: u32,
+        priv_size: u16,
+        data_room_size: u16,
+    ) -> Result<Self> {
+        let mut sockets = lcore::enabled()
+            .into_iter()
+            .map(|lcore_id| lcore_id.socket_id())
+            .collect::<Vec<_>>();
+
+        sockets.sort();
+        sockets.dedup();
+
+        let pools = sockets
+            .into_iter()
+            .map(|socket_id| {
+                let name = format!("{}_{}", prefix.as_ref(), socket_id);
+
+                pool_create(name, n, cache_size, priv_size, data_room_size, socket_id).map(|pool| (socket_id, pool))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SocketPools { pools })
+    }
+
+    /// The sockets which have a pool.
+    pub fn sockets(&self) -> impl Iterator<Item = SocketId> + '_ {
+        self.pools.iter().map(|&(socket_id, _)| socket_id)
+    }
+
+    /// Get the pool on the socket, or the first pool if the socket is `SOCKET_ID_ANY`.
+    pub fn get(&mut self, socket_id: SocketId) -> Option<&mut mempool::MemoryPool> {
+        self.pools
+            .iter_mut()
+            .find(|(id, _)| socket_id == SOCKET_ID_ANY || *id == socket_id)
+            .map(|(_, pool)| pool)
+    }
+
+    /// Get the pool on the socket of the port, e.g. to set up its receive queues.
+    pub fn for_port(&mut self, port_id: PortId) -> Option<&mut mempool::MemoryPool> {
+        self.get(port_id.socket_id())
+    }
+
+    /// Get the pool on the socket of the current lcore.
+    pub fn local(&mut self) -> Option<&mut mempool::MemoryPool> {
+        self.get(lcore::socket_id() as SocketId)
+    }
+}
@@ -23,9 +23,13 @@
 //! rte_mempool_generic_get() or rte_mempool_generic_put() with a user cache
 //! created with rte_mempool_cache_create().
 //!
+use std::cell::Cell;
 use std::ffi::CStr;
+use std::fmt;
+use std::marker::PhantomData;
 use std::mem;
-use std::os::raw::{c_uint, c_void};
+use std::ops::{Deref, DerefMut};
+use std::os::raw::{c_int, c_uint, c_void};
 use std::os::unix::io::AsRawFd;
 use std::ptr::{self, NonNull};
 
@@ -34,11 +38,11 @@ use cfile;
 use ffi;
 use libc;
 
-use errors::AsResult;
+use errors::{AsResult, ErrorKind::OsError};
 use lcore;
 use memory::SocketId;
 use ring;
-use utils::{AsCString, AsRaw, CallbackContext, FromRaw, IntoRaw, Raw};
+use utils::{catch_panic, AsCString, AsRaw, CallbackContext, FromRaw, IntoRaw, Raw};
 
 pub use ffi::{
     MEMPOOL_PG_NUM_DEFAULT, RTE_MEMPOOL_ALIGN, RTE_MEMPOOL_ALIGN_MASK, RTE_MEMPOOL_HEADER_COOKIE1,
@@ -95,6 +99,10 @@ pub type RawMemoryPoolPtr = *mut ffi::rte_mempool;
 /// The RTE mempool structure.
 raw!(pub MemoryPool(RawMemoryPool));
 
+// The mempool get/put functions are multi-thread safe.
+unsafe impl Send for MemoryPool {}
+unsafe impl Sync for MemoryPool {}
+
 impl MemoryPool {
     /// Search a mempool from its name
     pub fn lookup<S: AsRef<str>>(name: S) -> Result<Self> {
@@ -147,6 +155,32 @@ impl MemoryPool {
         self.avail_count() == 0
     }
 
+    /// Set the ops of the empty mempool by its name, e.g. `ring_mp_mc` or `stack`.
+    ///
+    /// The ops must be set before the mempool is populated.
+    pub fn set_ops_byname<S: AsRef<str>>(&mut self, name: S) -> Result<()> {
+        let name = name.as_cstring();
+
+        let ret = unsafe { ffi::rte_mempool_set_ops_byname(self.as_raw_mut(), name.as_ptr(), ptr::null_mut()) };
+
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Populate the empty mempool with the default memory allocator, return the number of objects added.
+    pub fn populate_default(&mut self) -> Result<usize> {
+        let ret = unsafe { ffi::rte_mempool_populate_default(self.as_raw_mut()) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
     /// Check the consistency of mempool objects.
     ///
     /// Verify the coherency of fields in the mempool structure.
@@ -302,7 +336,7 @@ unsafe extern "C" fn mp_init_stub<T>(mp: *mut ffi::rte_mempool, arg: *mut c_void
     let mp = MemoryPool::from(mp);
     let ctx = ConstructorContext::<T>::from_raw(arg);
 
-    (ctx.callback)(&mp, ctx.arg);
+    catch_panic("mempool constructor", (), || (ctx.callback)(&mp, ctx.arg));
 
     mem::forget(mp);
 }
@@ -313,7 +347,9 @@ unsafe extern "C" fn obj_cb_stub<T, O>(mp: *mut ffi::rte_mempool, arg: *mut c_vo
     let mp = MemoryPool::from(mp);
     let ctx = ObjectContext::<T, O>::from_raw(arg);
 
-    (ctx.callback)(&mp, ctx.arg, (obj as *mut O).as_mut().unwrap(), obj_idx as usize);
+    catch_panic("mempool object callback", (), || {
+        (ctx.callback)(&mp, ctx.arg, (obj as *mut O).as_mut().unwrap(), obj_idx as usize)
+    });
 
     mem::forget(mp);
 }
@@ -329,7 +365,9 @@ unsafe extern "C" fn mem_cb_stub<T>(
     let mp = MemoryPool::from(mp);
     let ctx = MemoryChunkContext::<T>::from_raw(arg);
 
-    (ctx.callback)(&mp, ctx.arg, &*memhdr, mem_idx as usize);
+    catch_panic("mempool memory chunk callback", (), || {
+        (ctx.callback)(&mp, ctx.arg, &*memhdr, mem_idx as usize)
+    });
 
     mem::forget(mp);
 }
@@ -365,7 +403,7 @@ unsafe extern "C" fn pool_walk_stub<T>(mp: *mut ffi::rte_mempool, arg: *mut libc
     let mp = MemoryPool::from(mp);
     let ctxt = PoolWalkContext::<T>::from_raw(arg);
 
-    (ctxt.callback)(&mp, ctxt.arg);
+    catch_panic("mempool walk callback", (), || (ctxt.callback)(&mp, ctxt.arg));
 
     mem::forget(mp)
 }
@@ -375,6 +413,9 @@ pub type RawCachePtr = *mut ffi::rte_mempool_cache;
 
 raw!(pub Cache(RawCache));
 
+// The user-owned cache may be moved to another lcore, but can't be shared.
+unsafe impl Send for Cache {}
+
 impl Cache {
     /// Create a user-owned mempool cache.
     ///
@@ -406,7 +447,7 @@ impl MemoryPool {
     /// Put several objects back in the mempool.
     pub fn generic_put<T: Pooled<R>, R>(&mut self, objs: &[T], cache: Option<Cache>) {
         unsafe {
-            ffi::_rte_mempool_generic_put(
+            generic_put_raw(
                 self.as_raw_mut(),
                 objs.as_ptr() as *const _,
                 objs.len() as u32,
@@ -421,7 +462,7 @@ impl MemoryPool {
     /// version depending on the default behavior that was specified at
     /// mempool creation time (see flags).
     pub fn put_bulk<T: Pooled<R>, R>(&mut self, objs: &[T]) {
-        unsafe { ffi::_rte_mempool_put_bulk(self.as_raw_mut(), objs.as_ptr() as *const _, objs.len() as u32) }
+        unsafe { put_raw(self.as_raw_mut(), objs.as_ptr() as *const _, objs.len() as u32) }
     }
 
     /// Put several objects back in the mempool.
@@ -430,7 +471,7 @@ impl MemoryPool {
     /// version depending on the default behavior that was specified at
     /// mempool creation time (see flags).
     pub fn put<T: Pooled<R>, R>(&mut self, obj: T) {
-        unsafe { ffi::_rte_mempool_put(self.as_raw_mut(), obj.as_raw() as *mut _) }
+        unsafe { put_raw(self.as_raw_mut(), &(obj.as_raw() as *mut c_void), 1) }
     }
 
     /// Get several objects from the mempool.
@@ -441,7 +482,7 @@ impl MemoryPool {
     /// lcores are full.
     pub fn generic_get<T: Pooled<R>, R>(&mut self, objs: &mut [T], cache: Option<Cache>) -> Result<()> {
         unsafe {
-            ffi::_rte_mempool_generic_get(
+            generic_get_raw(
                 self.as_raw_mut(),
                 objs.as_mut_ptr() as *mut _,
                 objs.len() as u32,
@@ -463,7 +504,7 @@ impl MemoryPool {
     /// the local cache and common pool are empty, even if cache from other
     /// lcores are full.
     pub fn get_bulk<T: Pooled<R>, R>(&mut self, objs: &mut [T]) -> Result<()> {
-        unsafe { ffi::_rte_mempool_get_bulk(self.as_raw_mut(), objs.as_mut_ptr() as *mut _, objs.len() as u32) }
+        unsafe { get_raw(self.as_raw_mut(), objs.as_mut_ptr() as *mut _, objs.len() as u32) }
             .as_result()
             .map(|_| ())
     }
@@ -481,7 +522,7 @@ impl MemoryPool {
     pub fn get<T: Pooled<R>, R>(&mut self) -> Result<T> {
         let mut obj = ptr::null_mut();
 
-        unsafe { ffi::_rte_mempool_get(self.as_raw_mut(), &mut obj) }
+        unsafe { get_raw(self.as_raw_mut(), &mut obj, 1) }
             .as_result()
             .map(|_| (obj as *mut T::Raw).into())
     }
@@ -502,3 +543,294 @@ impl MemoryPool {
         .map(|_| ())
     }
 }
+
+// The objects are got from and put to the mempool cache inline, the same way as `rte_mempool_generic_get()`
+// and `rte_mempool_generic_put()` of a non-debug DPDK build do, instead of calling the out-of-line shims.
+// Anything the cache can't serve, e.g. a refill or a flush, is left to the shims.
+
+thread_local! {
+    // The lcore id of an EAL thread, which never changes once it was launched.
+    static EAL_LCORE_ID: Cell<Option<c_uint>> = const { Cell::new(None) };
+}
+
+#[inline(always)]
+fn current_lcore_id() -> c_uint {
+    EAL_LCORE_ID.with(|id| {
+        id.get().unwrap_or_else(|| {
+            let lcore_id = unsafe { ffi::_rte_lcore_id() };
+
+            if lcore_id < ffi::RTE_MAX_LCORE
+                && unsafe { ffi::rte_eal_lcore_role(lcore_id) } == ffi::rte_lcore_role_t::ROLE_RTE
+            {
+                id.set(Some(lcore_id));
+            }
+
+            lcore_id
+        })
+    })
+}
+
+// Get the per-lcore cache of the current lcore, or NULL if the mempool has no cache or it isn't an lcore.
+#[inline(always)]
+unsafe fn default_cache_raw(mp: *mut RawMemoryPool) -> *mut ffi::rte_mempool_cache {
+    let lcore_id = current_lcore_id();
+
+    if (*mp).cache_size == 0 || lcore_id >= ffi::RTE_MAX_LCORE {
+        ptr::null_mut()
+    } else {
+        (*mp).local_cache.add(lcore_id as usize)
+    }
+}
+
+#[inline(always)]
+unsafe fn generic_get_raw(
+    mp: *mut RawMemoryPool,
+    objs: *mut *mut c_void,
+    n: c_uint,
+    cache: *mut ffi::rte_mempool_cache,
+) -> c_int {
+    if let Some(cache) = cache.as_mut() {
+        if n < cache.size && n <= cache.len {
+            for i in 0..n as usize {
+                *objs.add(i) = cache.objs[cache.len as usize - 1 - i];
+            }
+
+            cache.len -= n;
+
+            return 0;
+        }
+    }
+
+    ffi::_rte_mempool_generic_get(mp, objs, n, cache)
+}
+
+#[inline(always)]
+unsafe fn generic_put_raw(
+    mp: *mut RawMemoryPool,
+    objs: *const *mut c_void,
+    n: c_uint,
+    cache: *mut ffi::rte_mempool_cache,
+) {
+    if let Some(cache) = cache.as_mut() {
+        if cache.len + n < cache.flushthresh {
+            ptr::copy_nonoverlapping(objs, cache.objs.as_mut_ptr().add(cache.len as usize), n as usize);
+
+            cache.len += n;
+
+            return;
+        }
+    }
+
+    ffi::_rte_mempool_generic_put(mp, objs, n, cache)
+}
+
+#[inline(always)]
+unsafe fn get_raw(mp: *mut RawMemoryPool, objs: *mut *mut c_void, n: c_uint) -> c_int {
+    generic_get_raw(mp, objs, n, default_cache_raw(mp))
+}
+
+#[inline(always)]
+unsafe fn put_raw(mp: *mut RawMemoryPool, objs: *const *mut c_void, n: c_uint) {
+    generic_put_raw(mp, objs, n, default_cache_raw(mp))
+}
+
+/// The maximum size of the per-lcore cache of a mempool.
+pub const CACHE_MAX_SIZE: usize = ffi::RTE_MEMPOOL_CACHE_MAX_SIZE as usize;
+
+/// A mempool of the fixed-size objects of type `T`, e.g. crypto operations or flow states.
+///
+/// The objects are allocated from the pool as `PoolBox<T>` guards, which return them to the pool on drop.
+pub struct MemPool<T> {
+    pool: MemoryPool,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for MemPool<T> {}
+unsafe impl<T: Send> Sync for MemPool<T> {}
+
+impl<T> Drop for MemPool<T> {
+    fn drop(&mut self) {
+        self.pool.free()
+    }
+}
+
+impl<T> Deref for MemPool<T> {
+    type Target = MemoryPool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
+
+impl<T> MemPool<T> {
+    /// Create a mempool of `n` objects of type `T`.
+    ///
+    /// The `cache_size` objects are cached per lcore to avoid the contention on the common pool,
+    /// it must be lower or equal to `CACHE_MAX_SIZE` and `n / 1.5`, or zero to disable the cache.
+    pub fn create<S: AsRef<str>>(
+        name: S,
+        n: u32,
+        cache_size: u32,
+        socket_id: SocketId,
+        flags: MemoryPoolFlags,
+    ) -> Result<Self> {
+        let align = if flags.contains(MemoryPoolFlags::MEMPOOL_F_NO_CACHE_ALIGN) {
+            mem::size_of::<usize>()
+        } else {
+            ffi::RTE_CACHE_LINE_SIZE as usize
+        };
+
+        if mem::align_of::<T>() > align || cache_size as usize > CACHE_MAX_SIZE {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        create_empty::<S, T>(name, n, cache_size, 0, socket_id, flags)
+            .and_then(|mut pool| match pool.populate_default() {
+                Ok(_) => Ok(pool),
+                Err(err) => {
+                    pool.free();
+
+                    Err(err)
+                }
+            })
+            .map(|pool| MemPool {
+                pool,
+                _marker: PhantomData,
+            })
+    }
+
+    /// The size of the per-lcore cache.
+    pub fn cache_size(&self) -> usize {
+        self.pool.cache_size as usize
+    }
+
+    /// Get an object from the mempool and move the value into it.
+    pub fn get(&self, value: T) -> Result<PoolBox<'_, T>> {
+        let mut obj = ptr::null_mut();
+
+        let ret = unsafe { get_raw(self.pool.as_raw_mut(), &mut obj, 1) };
+
+        rte_check!(ret; ok => { unsafe { PoolBox::new(self, obj as *mut T, value) } }; err => { OsError(-ret).into() })
+    }
+
+    /// Get an object from the mempool through a user-owned cache, e.g. on a non-EAL thread.
+    pub fn get_with_cache(&self, cache: &Cache, value: T) -> Result<PoolBox<'_, T>> {
+        let mut obj = ptr::null_mut();
+
+        let ret = unsafe { generic_get_raw(self.pool.as_raw_mut(), &mut obj, 1, cache.as_raw_mut()) };
+
+        rte_check!(ret; ok => { unsafe { PoolBox::new(self, obj as *mut T, value) } }; err => { OsError(-ret).into() })
+    }
+
+    /// Get the objects from the mempool in bulk and move the values into them.
+    ///
+    /// Either all the objects are retrieved or none of them.
+    pub fn get_bulk<I>(&self, values: I) -> Result<Vec<PoolBox<'_, T>>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // collect the values first, so each retrieved object gets exactly one value
+        let values = values.into_iter().collect::<Vec<_>>();
+        let mut objs = vec![ptr::null_mut(); values.len()];
+
+        let ret = unsafe { get_raw(self.pool.as_raw_mut(), objs.as_mut_ptr(), objs.len() as u32) };
+
+        if ret < 0 {
+            Err(OsError(-ret).into())
+        } else {
+            Ok(objs
+                .into_iter()
+                .zip(values)
+                .map(|(obj, value)| unsafe { PoolBox::new(self, obj as *mut T, value) })
+                .collect())
+        }
+    }
+
+    /// Drop the values and put the objects back in the mempool in bulk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any object was allocated from another mempool.
+    pub fn put_bulk(&self, objs: Vec<PoolBox<T>>) {
+        assert!(
+            objs.iter().all(|obj| obj.pool.as_raw() == self.pool.as_raw()),
+            "the objects must be allocated from the mempool"
+        );
+
+        let objs = objs
+            .into_iter()
+            .map(|obj| {
+                let obj = mem::ManuallyDrop::new(obj);
+
+                unsafe { ptr::drop_in_place(obj.obj.as_ptr()) };
+
+                obj.obj.as_ptr() as *mut c_void
+            })
+            .collect::<Vec<_>>();
+
+        unsafe { put_raw(self.pool.as_raw_mut(), objs.as_ptr(), objs.len() as u32) }
+    }
+}
+
+/// An object allocated from a `MemPool<T>`, which is dropped and returned to the pool on drop.
+pub struct PoolBox<'a, T> {
+    obj: NonNull<T>,
+    pool: &'a MemoryPool,
+}
+
+unsafe impl<'a, T: Send> Send for PoolBox<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for PoolBox<'a, T> {}
+
+impl<'a, T> Drop for PoolBox<'a, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.obj.as_ptr());
+
+            put_raw(self.pool.as_raw_mut(), &(self.obj.as_ptr() as *mut c_void), 1)
+        }
+    }
+}
+
+impl<'a, T> Deref for PoolBox<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.obj.as_ref() }
+    }
+}
+
+impl<'a, T> DerefMut for PoolBox<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.obj.as_mut() }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for PoolBox<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<'a, T> PoolBox<'a, T> {
+    unsafe fn new(pool: &'a MemPool<T>, obj: *mut T, value: T) -> Self {
+        obj.write(value);
+
+        PoolBox {
+            obj: NonNull::new_unchecked(obj),
+            pool: &pool.pool,
+        }
+    }
+
+    /// Move the value out of the object, and return the object to the pool.
+    pub fn into_inner(self) -> T {
+        let obj = mem::ManuallyDrop::new(self);
+
+        unsafe {
+            let value = obj.obj.as_ptr().read();
+
+            put_raw(obj.pool.as_raw_mut(), &(obj.obj.as_ptr() as *mut c_void), 1);
+
+            value
+        }
+    }
+}
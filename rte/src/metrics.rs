@@ -0,0 +1,212 @@
+//!
+//! RTE Metrics
+//!
+//! The metrics library shares the named counters between the processes, the values are set
+//! per port or globally by the producers, e.g. the latency and bitrate statistics,
+//! and read by the consumers, e.g. the telemetry or `dpdk-proc-info`.
+//!
+//! The library must be initialized by the primary process before the metrics are registered.
+//!
+use std::ffi::CStr;
+use std::os::raw::c_int;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+
+use errors::{rte_error, AsResult, ErrorKind::OsError};
+use ethdev::PortId;
+use ffi;
+use memory::SocketId;
+use utils::{AsCString, AsRaw};
+
+/// The maximum number of the metrics.
+pub const MAX_METRICS: usize = ffi::RTE_METRICS_MAX_METRICS as usize;
+
+/// The maximum length of the metric names, including the trailing NUL.
+pub const MAX_NAME_LEN: usize = ffi::RTE_METRICS_MAX_NAME_LEN as usize;
+
+/// The key of a metric, which is the index in the registered metrics.
+pub type Key = u16;
+
+fn port_or_global(port_id: Option<PortId>) -> c_int {
+    port_id.map_or(ffi::RTE_METRICS_GLOBAL, |port_id| c_int::from(port_id.0))
+}
+
+fn check(ret: c_int) -> Result<c_int> {
+    if ret < 0 {
+        Err(OsError(-ret).into())
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Initialize the metrics library with the shared memory allocated on the NUMA socket.
+pub fn init(socket_id: SocketId) {
+    unsafe { ffi::rte_metrics_init(socket_id as c_int) }
+}
+
+/// Release the shared memory of the metrics library.
+pub fn deinit() -> Result<()> {
+    check(unsafe { ffi::rte_metrics_deinit() }).map(|_| ())
+}
+
+/// Register a metric, return its key.
+pub fn reg_name<S: AsRef<str>>(name: S) -> Result<Key> {
+    let name = name.as_ref().as_cstring();
+
+    check(unsafe { ffi::rte_metrics_reg_name(name.as_ptr()) }).map(|key| key as Key)
+}
+
+/// Register a set of metrics, return the key of the first one, the others have the consecutive keys.
+pub fn reg_names<S: AsRef<str>>(names: &[S]) -> Result<Key> {
+    let names = names.iter().map(|name| name.as_ref().as_cstring()).collect::<Vec<_>>();
+    let ptrs = names.iter().map(|name| name.as_ptr()).collect::<Vec<_>>();
+
+    check(unsafe { ffi::rte_metrics_reg_names(ptrs.as_ptr(), ptrs.len() as u16) }).map(|key| key as Key)
+}
+
+/// Update the value of the metric of the port, or the global one if `port_id` is `None`.
+pub fn update_value(port_id: Option<PortId>, key: Key, value: u64) -> Result<()> {
+    check(unsafe { ffi::rte_metrics_update_value(port_or_global(port_id), key, value) }).map(|_| ())
+}
+
+/// Update the values of the metrics starting from `key`, which must be in the same set.
+pub fn update_values(port_id: Option<PortId>, key: Key, values: &[u64]) -> Result<()> {
+    check(unsafe { ffi::rte_metrics_update_values(port_or_global(port_id), key, values.as_ptr(), values.len() as u32) })
+        .map(|_| ())
+}
+
+fn metric_name(name: &ffi::rte_metric_name) -> String {
+    unsafe { CStr::from_ptr(name.name.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Query the table with `f`, which returns the required size if the capacity is too small.
+fn get_table<T, F>(f: F) -> Result<Vec<T>>
+where
+    T: Default + Clone,
+    F: Fn(*mut T, u16) -> c_int,
+{
+    let n = check(f(ptr::null_mut(), 0))? as usize;
+    let mut table = vec![T::default(); n];
+    let n = check(f(table.as_mut_ptr(), table.len() as u16))? as usize;
+
+    table.truncate(n);
+
+    Ok(table)
+}
+
+/// The names of the registered metrics, indexed by their keys.
+pub fn names() -> Result<Vec<String>> {
+    get_table(|names, capacity| unsafe { ffi::rte_metrics_get_names(names, capacity) })
+        .map(|names| names.iter().map(metric_name).collect())
+}
+
+/// The values of the metrics of the port, or the global ones if `port_id` is `None`.
+pub fn values(port_id: Option<PortId>) -> Result<Vec<(Key, u64)>> {
+    let port_id = port_or_global(port_id);
+
+    get_table(|values, capacity| unsafe { ffi::rte_metrics_get_values(port_id, values, capacity) })
+        .map(|values| values.iter().map(|v| (v.key, v.value)).collect())
+}
+
+/// All the registered global metrics by their names.
+pub fn snapshot() -> Result<Vec<(String, u64)>> {
+    snapshot_of(None)
+}
+
+/// All the registered metrics of the port by their names.
+pub fn port_snapshot(port_id: PortId) -> Result<Vec<(String, u64)>> {
+    snapshot_of(Some(port_id))
+}
+
+fn snapshot_of(port_id: Option<PortId>) -> Result<Vec<(String, u64)>> {
+    let names = names()?;
+
+    Ok(values(port_id)?
+        .into_iter()
+        .filter_map(|(key, value)| names.get(key as usize).map(|name| (name.clone(), value)))
+        .collect())
+}
+
+fn latencystats_check(ret: c_int) -> Result<()> {
+    match ret {
+        0 => Ok(()),
+        -1 => Err(rte_error().into()),
+        _ => Err(OsError(-ret).into()),
+    }
+}
+
+/// Register the RX/TX callbacks of all the ports and queues to measure the latency,
+/// one of every `sample_interval` nanoseconds packets is timestamped.
+///
+/// The latency statistics are registered as the global metrics.
+pub fn latencystats_init(sample_interval: u64) -> Result<()> {
+    latencystats_check(unsafe { ffi::rte_latencystats_init(sample_interval, None) })
+}
+
+/// Update the latency and jitter values of the metrics.
+pub fn latencystats_update() -> Result<()> {
+    check(unsafe { ffi::rte_latencystats_update() }).map(|_| ())
+}
+
+/// Remove the RX/TX callbacks registered by `latencystats_init()`.
+pub fn latencystats_uninit() -> Result<()> {
+    latencystats_check(unsafe { ffi::rte_latencystats_uninit() })
+}
+
+/// The latency statistics by their names, in nanoseconds.
+pub fn latencystats() -> Result<Vec<(String, u64)>> {
+    let names = get_table(|names, size| unsafe { ffi::rte_latencystats_get_names(names, size) })?;
+    let values = get_table(|values, size| unsafe { ffi::rte_latencystats_get(values, size) })?;
+
+    Ok(names
+        .iter()
+        .map(metric_name)
+        .zip(values.iter().map(|v| v.value))
+        .collect())
+}
+
+pub type RawBitrates = ffi::rte_stats_bitrates;
+
+/// The bitrate statistics of the ports, which are calculated in the sampling window
+/// of the period calling `calc()`, e.g. once per second.
+#[derive(Debug)]
+pub struct Bitrates(NonNull<RawBitrates>);
+
+unsafe impl Send for Bitrates {}
+
+impl Drop for Bitrates {
+    fn drop(&mut self) {
+        unsafe { ffi::rte_stats_bitrate_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Bitrates {
+    type Raw = RawBitrates;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Bitrates {
+    /// Create the bitrate statistics and register them as the metrics.
+    pub fn new() -> Result<Self> {
+        let bitrates = unsafe { ffi::rte_stats_bitrate_create() }.as_result().map(Bitrates)?;
+
+        check(unsafe { ffi::rte_stats_bitrate_reg(bitrates.0.as_ptr()) })?;
+
+        Ok(bitrates)
+    }
+
+    /// Calculate the bitrates of the port in the current window, and update the metrics of the port.
+    pub fn calc(&mut self, port_id: PortId) -> Result<()> {
+        check(unsafe { ffi::rte_stats_bitrate_calc(self.0.as_ptr(), port_id.0) }).map(|_| ())
+    }
+}
@@ -0,0 +1,385 @@
+//!
+//! RTE Traffic Metering and Policing
+//!
+//! The ethdev metering API configures the meters of the NICs, which police the packets
+//! matched by the flow rules with the `METER` action, instead of metering them on the CPU.
+//!
+//! A meter object is created with a meter profile of the port, and referenced by the flow rules with its ID.
+//!
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::os::raw::c_int;
+use std::ptr;
+
+use anyhow::Result;
+use num_traits::FromPrimitive;
+
+use ethdev::PortId;
+use ffi::{self, rte_mtr_error_type::*};
+use red::{Color, COLORS};
+
+pub type RawMtrError = ffi::rte_mtr_error;
+
+/// The capabilities of the meters of a port.
+pub type Capabilities = ffi::rte_mtr_capabilities;
+
+/// The statistics counters of a meter object.
+pub type Stats = ffi::rte_mtr_stats;
+
+/// The number of the entries of a DSCP table.
+pub const DSCP_TABLE_SIZE: usize = 64;
+
+/// Type of the object responsible for the meter error.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum MtrErrorType {
+    /// No error.
+    None = RTE_MTR_ERROR_TYPE_NONE,
+    /// Cause unspecified.
+    Unspecified = RTE_MTR_ERROR_TYPE_UNSPECIFIED,
+    /// Meter profile ID.
+    MeterProfileId = RTE_MTR_ERROR_TYPE_METER_PROFILE_ID,
+    /// Meter profile.
+    MeterProfile = RTE_MTR_ERROR_TYPE_METER_PROFILE,
+    /// Meter object ID.
+    MtrId = RTE_MTR_ERROR_TYPE_MTR_ID,
+    /// Meter object parameters.
+    MtrParams = RTE_MTR_ERROR_TYPE_MTR_PARAMS,
+    /// Policer action of the green packets.
+    PolicerActionGreen = RTE_MTR_ERROR_TYPE_POLICER_ACTION_GREEN,
+    /// Policer action of the yellow packets.
+    PolicerActionYellow = RTE_MTR_ERROR_TYPE_POLICER_ACTION_YELLOW,
+    /// Policer action of the red packets.
+    PolicerActionRed = RTE_MTR_ERROR_TYPE_POLICER_ACTION_RED,
+    /// Statistics counters mask.
+    StatsMask = RTE_MTR_ERROR_TYPE_STATS_MASK,
+    /// Statistics counters.
+    Stats = RTE_MTR_ERROR_TYPE_STATS,
+    /// Shared meter object.
+    Shared = RTE_MTR_ERROR_TYPE_SHARED,
+}
+
+/// The error reported by the metering API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MtrError {
+    /// The negative errno value returned by the metering API.
+    pub errno: i32,
+    /// Type of the object responsible for the error.
+    pub kind: MtrErrorType,
+    /// Human-readable error message.
+    pub message: Option<String>,
+}
+
+impl fmt::Display for MtrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "meter error, {} ({:?}, {})",
+            self.message.as_ref().map_or("unknown cause", String::as_str),
+            self.kind,
+            self.errno
+        )
+    }
+}
+
+impl std::error::Error for MtrError {}
+
+impl MtrError {
+    fn from_raw(errno: c_int, err: &RawMtrError) -> Self {
+        MtrError {
+            errno,
+            kind: MtrErrorType::from_u32(err.type_).unwrap_or(MtrErrorType::Unspecified),
+            message: if err.message.is_null() {
+                None
+            } else {
+                Some(unsafe { CStr::from_ptr(err.message) }.to_string_lossy().into_owned())
+            },
+        }
+    }
+}
+
+/// Call the metering API with an error structure, and convert the failure to `MtrError`.
+fn mtr_call<F>(f: F) -> Result<()>
+where
+    F: FnOnce(*mut RawMtrError) -> c_int,
+{
+    let mut err = RawMtrError::default();
+    let ret = f(&mut err);
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(MtrError::from_raw(ret, &err).into())
+    }
+}
+
+/// The metering algorithm and its parameters, the rates are in bytes per second and the bursts are in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeterProfile {
+    /// No metering, the output color is the same as the input color.
+    None,
+    /// Single Rate Three Color Marker, RFC 2697.
+    SrTcm { cir: u64, cbs: u64, ebs: u64 },
+    /// Two Rate Three Color Marker, RFC 2698.
+    TrTcm { cir: u64, pir: u64, cbs: u64, pbs: u64 },
+    /// Two Rate Three Color Marker, RFC 4115.
+    TrTcmRfc4115 { cir: u64, eir: u64, cbs: u64, ebs: u64 },
+}
+
+impl MeterProfile {
+    fn to_raw(self) -> ffi::rte_mtr_meter_profile {
+        use ffi::rte_mtr_algorithm::*;
+
+        let mut raw: ffi::rte_mtr_meter_profile = unsafe { mem::zeroed() };
+
+        match self {
+            MeterProfile::None => raw.alg = RTE_MTR_NONE,
+            MeterProfile::SrTcm { cir, cbs, ebs } => {
+                raw.alg = RTE_MTR_SRTCM_RFC2697;
+                raw.__bindgen_anon_1.srtcm_rfc2697 =
+                    ffi::rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_1 { cir, cbs, ebs };
+            }
+            MeterProfile::TrTcm { cir, pir, cbs, pbs } => {
+                raw.alg = RTE_MTR_TRTCM_RFC2698;
+                raw.__bindgen_anon_1.trtcm_rfc2698 =
+                    ffi::rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_2 { cir, pir, cbs, pbs };
+            }
+            MeterProfile::TrTcmRfc4115 { cir, eir, cbs, ebs } => {
+                raw.alg = RTE_MTR_TRTCM_RFC4115;
+                raw.__bindgen_anon_1.trtcm_rfc4115 =
+                    ffi::rte_mtr_meter_profile__bindgen_ty_1__bindgen_ty_3 { cir, eir, cbs, ebs };
+            }
+        }
+
+        raw
+    }
+}
+
+/// The action of the policer on the packets of a meter output color.
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicerAction {
+    /// Recolor the packet as green.
+    Green = ffi::rte_mtr_policer_action::MTR_POLICER_ACTION_COLOR_GREEN,
+    /// Recolor the packet as yellow.
+    Yellow = ffi::rte_mtr_policer_action::MTR_POLICER_ACTION_COLOR_YELLOW,
+    /// Recolor the packet as red.
+    Red = ffi::rte_mtr_policer_action::MTR_POLICER_ACTION_COLOR_RED,
+    /// Drop the packet.
+    Drop = ffi::rte_mtr_policer_action::MTR_POLICER_ACTION_DROP,
+}
+
+bitflags! {
+    /// The statistics counters of a meter object.
+    pub struct StatsMask: u64 {
+        const N_PKTS_GREEN    = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_PKTS_GREEN as u64;
+        const N_PKTS_YELLOW   = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_PKTS_YELLOW as u64;
+        const N_PKTS_RED      = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_PKTS_RED as u64;
+        const N_PKTS_DROPPED  = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_PKTS_DROPPED as u64;
+        const N_BYTES_GREEN   = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_BYTES_GREEN as u64;
+        const N_BYTES_YELLOW  = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_BYTES_YELLOW as u64;
+        const N_BYTES_RED     = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_BYTES_RED as u64;
+        const N_BYTES_DROPPED = ffi::rte_mtr_stats_type::RTE_MTR_STATS_N_BYTES_DROPPED as u64;
+    }
+}
+
+/// The parameters of a meter object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MtrParams {
+    /// The ID of the meter profile.
+    pub meter_profile_id: u32,
+    /// Use the color of the previous meter object of the flow as the input color.
+    pub use_prev_mtr_color: bool,
+    /// The input color of each DSCP value, all green (color blind) if `None`.
+    pub dscp_table: Option<[Color; DSCP_TABLE_SIZE]>,
+    /// Enable the meter on creation.
+    pub meter_enable: bool,
+    /// The policer actions of each meter output color.
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    pub actions: [PolicerAction; COLORS],
+    /// The ID of the meter policy.
+    #[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+    pub meter_policy_id: u32,
+    /// The statistics counters to be enabled.
+    pub stats_mask: StatsMask,
+}
+
+impl MtrParams {
+    /// The enabled meter of the profile, which keeps the color of the packets and collects no statistics.
+    pub fn new(meter_profile_id: u32) -> Self {
+        MtrParams {
+            meter_profile_id,
+            use_prev_mtr_color: false,
+            dscp_table: None,
+            meter_enable: true,
+            #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+            actions: [PolicerAction::Green, PolicerAction::Yellow, PolicerAction::Red],
+            #[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+            meter_policy_id: 0,
+            stats_mask: StatsMask::empty(),
+        }
+    }
+
+    fn to_raw(&self, dscp_table: *mut ffi::rte_color::Type) -> ffi::rte_mtr_params {
+        let mut raw: ffi::rte_mtr_params = unsafe { mem::zeroed() };
+
+        raw.meter_profile_id = self.meter_profile_id;
+        raw.use_prev_mtr_color = self.use_prev_mtr_color as c_int;
+        raw.dscp_table = dscp_table;
+        raw.meter_enable = self.meter_enable as c_int;
+        raw.stats_mask = self.stats_mask.bits;
+
+        self.set_policy(&mut raw);
+
+        raw
+    }
+
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    fn set_policy(&self, raw: &mut ffi::rte_mtr_params) {
+        for (dst, &action) in raw.action.iter_mut().zip(self.actions.iter()) {
+            *dst = action as ffi::rte_mtr_policer_action::Type;
+        }
+    }
+
+    #[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+    fn set_policy(&self, raw: &mut ffi::rte_mtr_params) {
+        raw.meter_policy_id = self.meter_policy_id;
+    }
+}
+
+/// Get the capabilities of the meters of the port.
+pub fn capabilities(port_id: PortId) -> Result<Capabilities> {
+    let mut cap = Capabilities::default();
+
+    mtr_call(|err| unsafe { ffi::rte_mtr_capabilities_get(port_id.0, &mut cap, err) }).map(|_| cap)
+}
+
+/// Add a meter profile to the port, which can be used by the meter objects.
+pub fn meter_profile_add(port_id: PortId, meter_profile_id: u32, profile: &MeterProfile) -> Result<()> {
+    let mut raw = profile.to_raw();
+
+    mtr_call(|err| unsafe { ffi::rte_mtr_meter_profile_add(port_id.0, meter_profile_id, &mut raw, err) })
+}
+
+/// Delete the meter profile, which must not be used by any meter object.
+pub fn meter_profile_delete(port_id: PortId, meter_profile_id: u32) -> Result<()> {
+    mtr_call(|err| unsafe { ffi::rte_mtr_meter_profile_delete(port_id.0, meter_profile_id, err) })
+}
+
+/// Add a meter policy to the port, the flow actions of each meter output color must end with the `END` action.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub fn meter_policy_add(port_id: PortId, policy_id: u32, actions: [&[ffi::rte_flow_action]; COLORS]) -> Result<()> {
+    let mut policy = ffi::rte_mtr_meter_policy_params {
+        actions: [actions[0].as_ptr(), actions[1].as_ptr(), actions[2].as_ptr()],
+    };
+
+    mtr_call(|err| unsafe { ffi::rte_mtr_meter_policy_add(port_id.0, policy_id, &mut policy, err) })
+}
+
+/// Delete the meter policy, which must not be used by any meter object.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub fn meter_policy_delete(port_id: PortId, policy_id: u32) -> Result<()> {
+    mtr_call(|err| unsafe { ffi::rte_mtr_meter_policy_delete(port_id.0, policy_id, err) })
+}
+
+fn dscp_table(table: &[Color; DSCP_TABLE_SIZE]) -> [ffi::rte_color::Type; DSCP_TABLE_SIZE] {
+    let mut raw = [ffi::rte_color::RTE_COLOR_GREEN; DSCP_TABLE_SIZE];
+
+    for (dst, &color) in raw.iter_mut().zip(table.iter()) {
+        *dst = color as ffi::rte_color::Type;
+    }
+
+    raw
+}
+
+/// A meter object of a port, which is referenced by the flow rules with the `METER` action.
+#[derive(Debug)]
+pub struct Mtr {
+    port_id: PortId,
+    mtr_id: u32,
+}
+
+impl Mtr {
+    /// Create a meter object of the port, which can be referenced by multiple flow rules if `shared` is true.
+    pub fn create(port_id: PortId, mtr_id: u32, params: &MtrParams, shared: bool) -> Result<Self> {
+        let mut table = params.dscp_table.as_ref().map(dscp_table);
+        let mut raw = params.to_raw(table.as_mut().map_or(ptr::null_mut(), |table| table.as_mut_ptr()));
+
+        mtr_call(|err| unsafe { ffi::rte_mtr_create(port_id.0, mtr_id, &mut raw, shared as c_int, err) })
+            .map(|_| Mtr { port_id, mtr_id })
+    }
+
+    /// The port the meter object was created on.
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    /// The ID of the meter object.
+    pub fn id(&self) -> u32 {
+        self.mtr_id
+    }
+
+    /// Enable the meter.
+    pub fn enable(&self) -> Result<()> {
+        mtr_call(|err| unsafe { ffi::rte_mtr_meter_enable(self.port_id.0, self.mtr_id, err) })
+    }
+
+    /// Disable the meter, the output color of the packets is the same as the input color.
+    pub fn disable(&self) -> Result<()> {
+        mtr_call(|err| unsafe { ffi::rte_mtr_meter_disable(self.port_id.0, self.mtr_id, err) })
+    }
+
+    /// Change the meter profile of the meter object.
+    pub fn profile_update(&self, meter_profile_id: u32) -> Result<()> {
+        mtr_call(|err| unsafe { ffi::rte_mtr_meter_profile_update(self.port_id.0, self.mtr_id, meter_profile_id, err) })
+    }
+
+    /// Change the input color of each DSCP value, all green if `None`.
+    pub fn dscp_table_update(&self, table: Option<&[Color; DSCP_TABLE_SIZE]>) -> Result<()> {
+        let mut table = table.map(dscp_table);
+        let table = table.as_mut().map_or(ptr::null_mut(), |table| table.as_mut_ptr());
+
+        mtr_call(|err| unsafe { ffi::rte_mtr_meter_dscp_table_update(self.port_id.0, self.mtr_id, table, err) })
+    }
+
+    /// Change the policer actions of the meter output colors.
+    #[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+    pub fn policer_actions_update(&self, actions: &[(Color, PolicerAction)]) -> Result<()> {
+        let mut mask = 0;
+        let mut raw = [ffi::rte_mtr_policer_action::MTR_POLICER_ACTION_COLOR_GREEN; COLORS];
+
+        for &(color, action) in actions {
+            mask |= 1 << color as u32;
+            raw[color as usize] = action as ffi::rte_mtr_policer_action::Type;
+        }
+
+        mtr_call(|err| unsafe {
+            ffi::rte_mtr_policer_actions_update(self.port_id.0, self.mtr_id, mask, raw.as_mut_ptr(), err)
+        })
+    }
+
+    /// Change the statistics counters to be enabled.
+    pub fn stats_update(&self, stats_mask: StatsMask) -> Result<()> {
+        mtr_call(|err| unsafe { ffi::rte_mtr_stats_update(self.port_id.0, self.mtr_id, stats_mask.bits, err) })
+    }
+
+    /// Read the statistics counters and the mask of the valid ones.
+    ///
+    /// The counters are reset after they are retrieved if `clear` is true.
+    pub fn stats_read(&self, clear: bool) -> Result<(Stats, StatsMask)> {
+        let mut stats = Stats::default();
+        let mut mask = 0;
+
+        mtr_call(|err| unsafe {
+            ffi::rte_mtr_stats_read(self.port_id.0, self.mtr_id, &mut stats, &mut mask, clear as c_int, err)
+        })
+        .map(|_| (stats, StatsMask::from_bits_truncate(mask)))
+    }
+
+    /// Destroy the meter object, which must not be referenced by any flow rule.
+    pub fn destroy(self) -> Result<()> {
+        mtr_call(|err| unsafe { ffi::rte_mtr_destroy(self.port_id.0, self.mtr_id, err) })
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! RTE Net CRC.
+//!
+//! Compute the CRC16-CCITT and CRC32-Ethernet of the packet data,
+//! using the SSE4.2 or ARM NEON intrinsics when they are available.
+//!
+use ffi;
+
+/// CRC types
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, FromPrimitive, ToPrimitive)]
+pub enum CrcType {
+    Crc16Ccitt = ffi::rte_net_crc_type::RTE_NET_CRC16_CCITT,
+    Crc32Eth = ffi::rte_net_crc_type::RTE_NET_CRC32_ETH,
+}
+
+/// CRC compute algorithm
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, FromPrimitive, ToPrimitive)]
+pub enum CrcAlg {
+    Scalar = ffi::rte_net_crc_alg::RTE_NET_CRC_SCALAR,
+    /// Use 64-bit SSE4.2 intrinsic
+    Sse42 = ffi::rte_net_crc_alg::RTE_NET_CRC_SSE42,
+    /// Use ARM Neon intrinsic
+    Neon = ffi::rte_net_crc_alg::RTE_NET_CRC_NEON,
+}
+
+/// Set the CRC computation algorithm.
+///
+/// If the algorithm is not supported by the CPU, the scalar version is used.
+pub fn set_alg(alg: CrcAlg) {
+    unsafe { ffi::rte_net_crc_set_alg(alg as u32) }
+}
+
+/// Select the fastest CRC computation algorithm of the target architecture.
+pub fn set_best_alg() {
+    set_alg(if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        CrcAlg::Sse42
+    } else if cfg!(target_arch = "aarch64") {
+        CrcAlg::Neon
+    } else {
+        CrcAlg::Scalar
+    })
+}
+
+/// Compute the CRC of the data.
+pub fn calc(data: &[u8], crc_type: CrcType) -> u32 {
+    unsafe { ffi::rte_net_crc_calc(data.as_ptr() as *const _, data.len() as u32, crc_type as u32) }
+}
+
+/// Compute the CRC16-CCITT of the data.
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    calc(data, CrcType::Crc16Ccitt) as u16
+}
+
+/// Compute the CRC32-Ethernet of the data, e.g. the FCS of an Ethernet frame.
+pub fn crc32_eth(data: &[u8]) -> u32 {
+    calc(data, CrcType::Crc32Eth)
+}
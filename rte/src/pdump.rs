@@ -0,0 +1,252 @@
+//!
+//! RTE Packet Capture
+//!
+//! The pdump framework mirrors the packets of the RX/TX queues of the primary process to a ring,
+//! which is requested by a secondary process, e.g. `dpdk-pdump` or `dpdk-dumpcap`,
+//! so the primary process must initialize it to be captured.
+//!
+//! The captured packets may be written to the pcapng files with `Pcapng` since DPDK 21.11.
+//!
+use std::os::raw::c_void;
+use std::ptr;
+
+use anyhow::Result;
+
+use errors::rte_error;
+use ethdev::{PortId, QueueId};
+use ffi;
+use mempool::MemoryPool;
+use ring::Ring;
+use utils::AsRaw;
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub use self::pcapng::*;
+
+bitflags! {
+    /// The directions of the packets to be captured.
+    pub struct Flags: u32 {
+        /// The received packets.
+        const RX   = ffi::RTE_PDUMP_FLAG_RX;
+        /// The transmitted packets.
+        const TX   = ffi::RTE_PDUMP_FLAG_TX;
+        /// Both the received and transmitted packets.
+        const RXTX = ffi::RTE_PDUMP_FLAG_RXTX;
+    }
+}
+
+/// Capture the packets of all the queues of the port.
+pub const ALL_QUEUES: QueueId = QueueId::MAX;
+
+/// Initialize the packet capture framework, which handles the requests from the secondary processes.
+pub fn init() -> Result<()> {
+    if unsafe { ffi::rte_pdump_init() } == 0 {
+        Ok(())
+    } else {
+        Err(rte_error().into())
+    }
+}
+
+/// Uninitialize the packet capture framework.
+pub fn uninit() -> Result<()> {
+    if unsafe { ffi::rte_pdump_uninit() } == 0 {
+        Ok(())
+    } else {
+        Err(rte_error().into())
+    }
+}
+
+/// Request to mirror the packets of the queue to the ring, which is called by a secondary process.
+///
+/// The packets are copied into the mbufs of the mempool, and dropped if the ring is full.
+pub fn enable(
+    port_id: PortId,
+    queue_id: QueueId,
+    flags: Flags,
+    ring: &Ring<ffi::rte_mbuf>,
+    mp: &MemoryPool,
+) -> Result<()> {
+    let ret = unsafe {
+        ffi::rte_pdump_enable(
+            port_id.0,
+            queue_id,
+            flags.bits,
+            ring.as_raw_mut(),
+            mp.as_raw_mut(),
+            ptr::null_mut::<c_void>(),
+        )
+    };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(rte_error().into())
+    }
+}
+
+/// Request to stop mirroring the packets of the queue.
+pub fn disable(port_id: PortId, queue_id: QueueId, flags: Flags) -> Result<()> {
+    if unsafe { ffi::rte_pdump_disable(port_id.0, queue_id, flags.bits) } == 0 {
+        Ok(())
+    } else {
+        Err(rte_error().into())
+    }
+}
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+mod pcapng {
+    use std::fs::File;
+    use std::os::unix::io::{FromRawFd, IntoRawFd};
+    use std::path::Path;
+    use std::ptr::{self, NonNull};
+
+    use anyhow::Result;
+
+    use errors::{os_error, rte_error, AsResult};
+    use ethdev::{PortId, QueueId};
+    use ffi;
+    use mbuf::{MBuf, RawMBufPtr};
+    use mempool::MemoryPool;
+    use utils::{AsCString, AsRaw};
+
+    pub type RawPcapng = ffi::rte_pcapng_t;
+
+    /// The direction of a captured packet.
+    #[repr(u32)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Direction {
+        /// Unknown direction.
+        Unknown = ffi::rte_pcapng_direction::RTE_PCAPNG_DIRECTION_UNKNOWN,
+        /// The received packet.
+        In = ffi::rte_pcapng_direction::RTE_PCAPNG_DIRECTION_IN,
+        /// The transmitted packet.
+        Out = ffi::rte_pcapng_direction::RTE_PCAPNG_DIRECTION_OUT,
+    }
+
+    /// The size of the mbufs holding the captured packets truncated to `length` bytes, with the pcapng metadata.
+    pub fn mbuf_size(length: u32) -> u32 {
+        unsafe { ffi::rte_pcapng_mbuf_size(length) }
+    }
+
+    /// Copy the packet into an mbuf of the mempool with its port, queue, direction and TSC timestamp,
+    /// which can be written to a pcapng file.
+    ///
+    /// The packet is truncated to `length` bytes.
+    pub fn copy(
+        port_id: PortId,
+        queue_id: QueueId,
+        m: &MBuf,
+        mp: &MemoryPool,
+        length: u32,
+        timestamp: u64,
+        direction: Direction,
+    ) -> Result<MBuf> {
+        unsafe {
+            ffi::rte_pcapng_copy(
+                port_id.0,
+                u32::from(queue_id),
+                m.as_raw(),
+                mp.as_raw_mut(),
+                length,
+                timestamp,
+                direction as ffi::rte_pcapng_direction::Type,
+            )
+        }
+        .as_result()
+        .map(|p| MBuf::from(p.as_ptr()))
+    }
+
+    /// A pcapng file, which has the interface descriptions of all the ports.
+    #[derive(Debug)]
+    pub struct Pcapng(NonNull<RawPcapng>);
+
+    unsafe impl Send for Pcapng {}
+
+    impl Drop for Pcapng {
+        fn drop(&mut self) {
+            // the file is closed as well.
+            unsafe { ffi::rte_pcapng_close(self.0.as_ptr()) }
+        }
+    }
+
+    impl AsRaw for Pcapng {
+        type Raw = RawPcapng;
+
+        fn as_raw(&self) -> *const Self::Raw {
+            self.0.as_ptr()
+        }
+
+        fn as_raw_mut(&self) -> *mut Self::Raw {
+            self.0.as_ptr()
+        }
+    }
+
+    impl Pcapng {
+        /// Create a pcapng file with an optional comment in the section header.
+        pub fn create<P: AsRef<Path>>(path: P, comment: Option<&str>) -> Result<Self> {
+            let fd = File::create(path)?.into_raw_fd();
+            let comment = comment.map(|s| s.as_cstring());
+
+            let p = unsafe {
+                ffi::rte_pcapng_fdopen(
+                    fd,
+                    ptr::null(),
+                    ptr::null(),
+                    ptr::null(),
+                    comment.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                )
+            };
+
+            NonNull::new(p).map(Pcapng).ok_or_else(|| {
+                drop(unsafe { File::from_raw_fd(fd) });
+
+                rte_error().into()
+            })
+        }
+
+        /// Write the packets copied by `copy()`, return the number of the written bytes.
+        ///
+        /// The packets are still owned by the caller.
+        pub fn write_packets(&mut self, pkts: &mut [RawMBufPtr]) -> Result<usize> {
+            let ret = unsafe { ffi::rte_pcapng_write_packets(self.0.as_ptr(), pkts.as_mut_ptr(), pkts.len() as u16) };
+
+            if ret < 0 {
+                Err(os_error().into())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+
+        /// Write the statistics of the port, e.g. the numbers of the received and dropped packets of the capture.
+        ///
+        /// The start and end time of the capture are in nanoseconds since the epoch.
+        pub fn write_stats(
+            &mut self,
+            port_id: PortId,
+            comment: Option<&str>,
+            start_time: u64,
+            end_time: u64,
+            ifrecv: u64,
+            ifdrop: u64,
+        ) -> Result<usize> {
+            let comment = comment.map(|s| s.as_cstring());
+
+            let ret = unsafe {
+                ffi::rte_pcapng_write_stats(
+                    self.0.as_ptr(),
+                    port_id.0,
+                    comment.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                    start_time,
+                    end_time,
+                    ifrecv,
+                    ifdrop,
+                )
+            };
+
+            if ret < 0 {
+                Err(os_error().into())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! Power management.
+//!
+//! The frequency of an lcore can be scaled with the power library after `init()`,
+//! which is backed by the ACPI or P-state cpufreq driver of the host, or the power agent of a KVM guest.
+//!
+//! The `AdaptivePoller` tracks the empty polls of an RX queue,
+//! and switches between busy polling, pausing between the polls and sleeping on the RX interrupt,
+//! so an idle lcore doesn't burn the CPU cycles while a busy one still polls at the full speed.
+//!
+use std::mem;
+use std::os::raw::c_int;
+use std::time::Duration;
+
+use anyhow::Result;
+use libc;
+use num_traits::FromPrimitive;
+
+use errors::{ErrorKind::OsError, RteError};
+use ffi;
+
+use common::pause;
+use ethdev::{PortId, QueueId};
+use lcore;
+
+/// The power management environment.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum Env {
+    /// Not initialized, which is auto-detected by `init()`.
+    NotSet = ffi::power_management_env::PM_ENV_NOT_SET,
+    /// The ACPI cpufreq driver of the host.
+    AcpiCpufreq = ffi::power_management_env::PM_ENV_ACPI_CPUFREQ,
+    /// The power agent of the host, which is requested by a KVM guest through virtio-serial.
+    KvmVm = ffi::power_management_env::PM_ENV_KVM_VM,
+    /// The intel_pstate cpufreq driver of the host.
+    PstateCpufreq = ffi::power_management_env::PM_ENV_PSTATE_CPUFREQ,
+}
+
+/// Set the power management environment, instead of auto-detecting it by `init()`.
+pub fn set_env(env: Env) -> Result<()> {
+    let ret = unsafe { ffi::rte_power_set_env(env as ffi::power_management_env::Type) };
+
+    rte_check!(ret)
+}
+
+/// Unset the power management environment, after all the lcores exited the power management.
+pub fn unset_env() {
+    unsafe { ffi::rte_power_unset_env() }
+}
+
+/// The power management environment.
+pub fn env() -> Env {
+    Env::from_u32(unsafe { ffi::rte_power_get_env() }).unwrap_or(Env::NotSet)
+}
+
+/// Initialize the power management of the lcore, which takes over the cpufreq governor of the core.
+pub fn init(lcore_id: lcore::Id) -> Result<()> {
+    let ret = unsafe { ffi::rte_power_init(*lcore_id) };
+
+    rte_check!(ret)
+}
+
+/// Exit the power management of the lcore, which restores the original cpufreq governor of the core.
+pub fn exit(lcore_id: lcore::Id) -> Result<()> {
+    let ret = unsafe { ffi::rte_power_exit(*lcore_id) };
+
+    rte_check!(ret)
+}
+
+/// The available frequencies of the lcore in kHz, from the highest to the lowest.
+pub fn freqs(lcore_id: lcore::Id) -> Result<Vec<u32>> {
+    let f = unsafe { ffi::rte_power_freqs }.ok_or(OsError(libc::ENOTSUP))?;
+    let mut freqs = vec![0; ffi::RTE_MAX_LCORE_FREQS as usize];
+    let n = unsafe { f(*lcore_id, freqs.as_mut_ptr(), freqs.len() as u32) };
+
+    freqs.truncate(n as usize);
+
+    Ok(freqs)
+}
+
+/// The index of the current frequency of the lcore in `freqs()`.
+pub fn freq(lcore_id: lcore::Id) -> Result<u32> {
+    let f = unsafe { ffi::rte_power_get_freq }.ok_or(OsError(libc::ENOTSUP))?;
+
+    Ok(unsafe { f(*lcore_id) })
+}
+
+/// Set the frequency of the lcore by its index in `freqs()`, return whether the frequency was changed.
+pub fn set_freq(lcore_id: lcore::Id, index: u32) -> Result<bool> {
+    let f = unsafe { ffi::rte_power_set_freq }.ok_or(OsError(libc::ENOTSUP))?;
+
+    freq_changed(unsafe { f(*lcore_id, index) })
+}
+
+fn freq_changed(ret: c_int) -> Result<bool> {
+    if ret < 0 {
+        Err(RteError(ret).into())
+    } else {
+        Ok(ret > 0)
+    }
+}
+
+macro_rules! freq_change {
+    ($(#[$attr:meta])* $name:ident => $f:ident) => {
+        $(#[$attr])*
+        pub fn $name(lcore_id: lcore::Id) -> Result<bool> {
+            let f = unsafe { ffi::$f }.ok_or(OsError(libc::ENOTSUP))?;
+
+            freq_changed(unsafe { f(*lcore_id) })
+        }
+    };
+}
+
+freq_change!(
+    /// Scale up the frequency of the lcore by one step, return whether the frequency was changed.
+    freq_up => rte_power_freq_up
+);
+freq_change!(
+    /// Scale down the frequency of the lcore by one step, return whether the frequency was changed.
+    freq_down => rte_power_freq_down
+);
+freq_change!(
+    /// Scale the frequency of the lcore to the maximum, return whether the frequency was changed.
+    freq_max => rte_power_freq_max
+);
+freq_change!(
+    /// Scale the frequency of the lcore to the minimum, return whether the frequency was changed.
+    freq_min => rte_power_freq_min
+);
+freq_change!(
+    /// Check whether the turbo boost of the lcore is enabled.
+    turbo_status => rte_power_turbo_status
+);
+freq_change!(
+    /// Enable the turbo boost of the lcore.
+    enable_turbo => rte_power_freq_enable_turbo
+);
+freq_change!(
+    /// Disable the turbo boost of the lcore.
+    disable_turbo => rte_power_freq_disable_turbo
+);
+
+/// The power management scheme of an RX queue, which is applied by the RX callback on the empty polls.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PmgmtType {
+    /// Sleep on the monitored RX descriptor until the NIC writes it back, with the `UMWAIT` instruction.
+    Monitor = ffi::rte_power_pmd_mgmt_type::RTE_POWER_MGMT_TYPE_MONITOR,
+    /// Pause the CPU with the `TPAUSE` instruction or the `rte_pause()` loop.
+    Pause = ffi::rte_power_pmd_mgmt_type::RTE_POWER_MGMT_TYPE_PAUSE,
+    /// Scale down the frequency of the lcore, which must be initialized with `init()`.
+    Scale = ffi::rte_power_pmd_mgmt_type::RTE_POWER_MGMT_TYPE_SCALE,
+}
+
+/// Enable the power management of the RX queue polled by the lcore, before the port is started.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub fn pmgmt_queue_enable(lcore_id: lcore::Id, port_id: PortId, queue_id: QueueId, mode: PmgmtType) -> Result<()> {
+    let ret = unsafe {
+        ffi::rte_power_ethdev_pmgmt_queue_enable(
+            *lcore_id,
+            port_id.0,
+            queue_id,
+            mode as ffi::rte_power_pmd_mgmt_type::Type,
+        )
+    };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(OsError(-ret).into())
+    }
+}
+
+/// Disable the power management of the RX queue polled by the lcore, after the port is stopped.
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+pub fn pmgmt_queue_disable(lcore_id: lcore::Id, port_id: PortId, queue_id: QueueId) -> Result<()> {
+    let ret = unsafe { ffi::rte_power_ethdev_pmgmt_queue_disable(*lcore_id, port_id, queue_id) };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(OsError(-ret).into())
+    }
+}
+
+/// The way an RX queue is polled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PollMode {
+    /// Poll the queue continuously.
+    BusyPoll,
+    /// Pause the CPU for a short while after each empty poll.
+    Pause,
+    /// Sleep on the RX interrupt of the queue until a packet arrives or the timeout expires.
+    Interrupt,
+}
+
+/// The thresholds of the adaptive poller.
+#[derive(Clone, Copy, Debug)]
+pub struct PollerConfig {
+    /// The number of polls to compute the empty-poll ratio.
+    pub window: u32,
+    /// Switch to the pause mode when the empty-poll ratio of a window reaches it.
+    pub pause_ratio: f64,
+    /// The number of pauses after each empty poll in the pause mode.
+    pub pause_count: u32,
+    /// Switch to the interrupt mode after the number of consecutive empty polls, zero to disable.
+    ///
+    /// The RX interrupt must be enabled with `intr_conf.rxq` when configuring the device,
+    /// otherwise the poller stays in the pause mode.
+    pub intr_threshold: u32,
+    /// The maximum time to sleep on the RX interrupt.
+    pub intr_timeout: Duration,
+}
+
+impl Default for PollerConfig {
+    fn default() -> Self {
+        PollerConfig {
+            window: 1024,
+            pause_ratio: 0.9,
+            pause_count: 16,
+            intr_threshold: 10_000,
+            intr_timeout: Duration::from_millis(10),
+        }
+    }
+}
+
+/// An RX queue poller which adapts the poll mode to the load of the queue.
+pub struct AdaptivePoller {
+    port_id: PortId,
+    queue_id: QueueId,
+    conf: PollerConfig,
+    mode: PollMode,
+    polls: u32,
+    empty_polls: u32,
+    consecutive_empty_polls: u32,
+    empty_ratio: f64,
+    intr: IntrState,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IntrState {
+    Unregistered,
+    Registered,
+    Unsupported,
+}
+
+impl Drop for AdaptivePoller {
+    fn drop(&mut self) {
+        if self.intr == IntrState::Registered {
+            unsafe {
+                ffi::rte_eth_dev_rx_intr_ctl_q(
+                    self.port_id.0,
+                    self.queue_id,
+                    ffi::RTE_EPOLL_PER_THREAD,
+                    ffi::RTE_INTR_EVENT_DEL as c_int,
+                    ::std::ptr::null_mut(),
+                );
+            }
+        }
+    }
+}
+
+impl AdaptivePoller {
+    /// Create a poller for the RX queue, which starts in the busy poll mode.
+    pub fn new(port_id: PortId, queue_id: QueueId, conf: PollerConfig) -> Self {
+        AdaptivePoller {
+            port_id,
+            queue_id,
+            conf,
+            mode: PollMode::BusyPoll,
+            polls: 0,
+            empty_polls: 0,
+            consecutive_empty_polls: 0,
+            empty_ratio: 0.0,
+            intr: if conf.intr_threshold == 0 {
+                IntrState::Unsupported
+            } else {
+                IntrState::Unregistered
+            },
+        }
+    }
+
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    pub fn queue_id(&self) -> QueueId {
+        self.queue_id
+    }
+
+    /// The current poll mode.
+    pub fn mode(&self) -> PollMode {
+        self.mode
+    }
+
+    /// The empty-poll ratio of the last completed window.
+    pub fn empty_ratio(&self) -> f64 {
+        self.empty_ratio
+    }
+
+    /// Poll the queue with the closure which returns the number of received packets,
+    /// and wait according to the poll mode if nothing was received.
+    #[inline]
+    pub fn poll<F: FnOnce() -> usize>(&mut self, f: F) -> usize {
+        let nb_rx = f();
+
+        self.update(nb_rx);
+
+        if nb_rx == 0 {
+            self.idle();
+        }
+
+        nb_rx
+    }
+
+    /// Record the number of packets received by a poll, return the poll mode for the next poll.
+    pub fn update(&mut self, nb_rx: usize) -> PollMode {
+        self.polls += 1;
+
+        if nb_rx == 0 {
+            self.empty_polls += 1;
+            self.consecutive_empty_polls = self.consecutive_empty_polls.saturating_add(1);
+        } else {
+            self.consecutive_empty_polls = 0;
+        }
+
+        if self.polls >= self.conf.window {
+            self.empty_ratio = f64::from(self.empty_polls) / f64::from(self.polls);
+            self.polls = 0;
+            self.empty_polls = 0;
+        }
+
+        self.mode = if nb_rx > 0 {
+            PollMode::BusyPoll
+        } else if self.intr != IntrState::Unsupported && self.consecutive_empty_polls >= self.conf.intr_threshold {
+            PollMode::Interrupt
+        } else if self.mode == PollMode::BusyPoll && self.empty_ratio < self.conf.pause_ratio {
+            PollMode::BusyPoll
+        } else {
+            PollMode::Pause
+        };
+
+        self.mode
+    }
+
+    /// Wait according to the poll mode, should be called after an empty poll.
+    pub fn idle(&mut self) {
+        match self.mode {
+            PollMode::BusyPoll => {}
+            PollMode::Pause => {
+                for _ in 0..self.conf.pause_count {
+                    pause();
+                }
+            }
+            PollMode::Interrupt => {
+                if self.wait_intr().is_err() {
+                    warn!(
+                        "RX interrupt is unavailable for port {} queue {}, fallback to pause mode",
+                        self.port_id, self.queue_id
+                    );
+
+                    self.intr = IntrState::Unsupported;
+                    self.mode = PollMode::Pause;
+                }
+            }
+        }
+    }
+
+    // Sleep on the RX interrupt with the per-thread epoll instance.
+    fn wait_intr(&mut self) -> Result<(), c_int> {
+        if self.intr == IntrState::Unregistered {
+            let ret = unsafe {
+                ffi::rte_eth_dev_rx_intr_ctl_q(
+                    self.port_id.0,
+                    self.queue_id,
+                    ffi::RTE_EPOLL_PER_THREAD,
+                    ffi::RTE_INTR_EVENT_ADD as c_int,
+                    ::std::ptr::null_mut(),
+                )
+            };
+
+            if ret != 0 {
+                return Err(ret);
+            }
+
+            self.intr = IntrState::Registered;
+        }
+
+        let ret = unsafe { ffi::rte_eth_dev_rx_intr_enable(self.port_id.0, self.queue_id) };
+
+        if ret != 0 {
+            return Err(ret);
+        }
+
+        let mut event: ffi::rte_epoll_event = unsafe { mem::zeroed() };
+        let timeout = self.conf.intr_timeout.as_millis().min(c_int::max_value() as u128) as c_int;
+
+        unsafe {
+            ffi::rte_epoll_wait(ffi::RTE_EPOLL_PER_THREAD, &mut event, 1, timeout);
+            ffi::rte_eth_dev_rx_intr_disable(self.port_id.0, self.queue_id);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_mode() {
+        let conf = PollerConfig {
+            window: 10,
+            pause_ratio: 0.8,
+            pause_count: 1,
+            intr_threshold: 25,
+            intr_timeout: Duration::from_millis(1),
+        };
+        let mut poller = AdaptivePoller::new(PortId(0), 0, conf);
+
+        assert_eq!(poller.mode(), PollMode::BusyPoll);
+
+        // the empty polls of the first window
+        for _ in 0..9 {
+            assert_eq!(poller.update(0), PollMode::BusyPoll);
+        }
+        assert_eq!(poller.update(0), PollMode::Pause);
+        assert_eq!(poller.empty_ratio(), 1.0);
+
+        for _ in 10..24 {
+            assert_eq!(poller.update(0), PollMode::Pause);
+        }
+        assert_eq!(poller.update(0), PollMode::Interrupt);
+
+        // a packet wakes up the poller
+        assert_eq!(poller.update(1), PollMode::BusyPoll);
+        assert_eq!(poller.update(0), PollMode::Pause);
+
+        // the interrupt mode is disabled
+        let conf = PollerConfig {
+            intr_threshold: 0,
+            ..conf
+        };
+        let mut poller = AdaptivePoller::new(PortId(0), 0, conf);
+
+        for _ in 0..100 {
+            assert_ne!(poller.update(0), PollMode::Interrupt);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//!
+//! RTE Mbuf Packet Types
+//!
+//! The packet type of an mbuf is recognized by the hardware or the driver on the RX side,
+//! which is split into the L2/L3/L4 types, the tunnel type and the inner L2/L3/L4 types.
+//!
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+
+use num_traits::FromPrimitive;
+
+use ffi;
+
+bitflags! {
+    /// The layers of the packet types, used to query or select the packet types recognized by a device.
+    pub struct PtypeMask: u32 {
+        /// The L2 packet types.
+        const L2       = ffi::RTE_PTYPE_L2_MASK;
+        /// The L3 packet types.
+        const L3       = ffi::RTE_PTYPE_L3_MASK;
+        /// The L4 packet types.
+        const L4       = ffi::RTE_PTYPE_L4_MASK;
+        /// The tunnel packet types.
+        const TUNNEL   = ffi::RTE_PTYPE_TUNNEL_MASK;
+        /// The inner L2 packet types.
+        const INNER_L2 = ffi::RTE_PTYPE_INNER_L2_MASK;
+        /// The inner L3 packet types.
+        const INNER_L3 = ffi::RTE_PTYPE_INNER_L3_MASK;
+        /// The inner L4 packet types.
+        const INNER_L4 = ffi::RTE_PTYPE_INNER_L4_MASK;
+        /// All the packet types.
+        const ALL      = ffi::RTE_PTYPE_ALL_MASK;
+    }
+}
+
+/// The L2 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum L2Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// Ethernet packet type, used for the outer packet of the tunnel cases.
+    Ether = ffi::RTE_PTYPE_L2_ETHER,
+    /// Ethernet packet type for time sync.
+    Timesync = ffi::RTE_PTYPE_L2_ETHER_TIMESYNC,
+    /// ARP packet type.
+    Arp = ffi::RTE_PTYPE_L2_ETHER_ARP,
+    /// LLDP packet type.
+    Lldp = ffi::RTE_PTYPE_L2_ETHER_LLDP,
+    /// NSH packet type.
+    Nsh = ffi::RTE_PTYPE_L2_ETHER_NSH,
+    /// VLAN packet type.
+    Vlan = ffi::RTE_PTYPE_L2_ETHER_VLAN,
+    /// QinQ packet type.
+    Qinq = ffi::RTE_PTYPE_L2_ETHER_QINQ,
+    /// PPPoE packet type.
+    Pppoe = ffi::RTE_PTYPE_L2_ETHER_PPPOE,
+    /// FCoE packet type.
+    Fcoe = ffi::RTE_PTYPE_L2_ETHER_FCOE,
+    /// MPLS packet type.
+    Mpls = ffi::RTE_PTYPE_L2_ETHER_MPLS,
+}
+
+/// The L3 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum L3Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// IPv4 packet type without any extension.
+    Ipv4 = ffi::RTE_PTYPE_L3_IPV4,
+    /// IPv4 packet type with the IP options.
+    Ipv4Ext = ffi::RTE_PTYPE_L3_IPV4_EXT,
+    /// IPv6 packet type without any extension header.
+    Ipv6 = ffi::RTE_PTYPE_L3_IPV6,
+    /// IPv4 packet type which may or may not have the IP options.
+    Ipv4ExtUnknown = ffi::RTE_PTYPE_L3_IPV4_EXT_UNKNOWN,
+    /// IPv6 packet type with the extension headers.
+    Ipv6Ext = ffi::RTE_PTYPE_L3_IPV6_EXT,
+    /// IPv6 packet type which may or may not have the extension headers.
+    Ipv6ExtUnknown = ffi::RTE_PTYPE_L3_IPV6_EXT_UNKNOWN,
+}
+
+/// The L4 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum L4Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// TCP packet type.
+    Tcp = ffi::RTE_PTYPE_L4_TCP,
+    /// UDP packet type.
+    Udp = ffi::RTE_PTYPE_L4_UDP,
+    /// Fragmented IP packet type.
+    Frag = ffi::RTE_PTYPE_L4_FRAG,
+    /// SCTP packet type.
+    Sctp = ffi::RTE_PTYPE_L4_SCTP,
+    /// ICMP packet type.
+    Icmp = ffi::RTE_PTYPE_L4_ICMP,
+    /// Non-fragmented IP packet type of the other L4 protocols.
+    NonFrag = ffi::RTE_PTYPE_L4_NONFRAG,
+    /// IGMP packet type.
+    Igmp = ffi::RTE_PTYPE_L4_IGMP,
+}
+
+/// The tunnel packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum TunnelType {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// IP in IP tunneling packet type.
+    Ip = ffi::RTE_PTYPE_TUNNEL_IP,
+    /// GRE tunneling packet type.
+    Gre = ffi::RTE_PTYPE_TUNNEL_GRE,
+    /// VXLAN tunneling packet type.
+    Vxlan = ffi::RTE_PTYPE_TUNNEL_VXLAN,
+    /// NVGRE tunneling packet type.
+    Nvgre = ffi::RTE_PTYPE_TUNNEL_NVGRE,
+    /// GENEVE tunneling packet type.
+    Geneve = ffi::RTE_PTYPE_TUNNEL_GENEVE,
+    /// Teredo, VXLAN or GRE tunneling packet type with an inner Ethernet header.
+    Grenat = ffi::RTE_PTYPE_TUNNEL_GRENAT,
+    /// GTP-C tunneling packet type.
+    Gtpc = ffi::RTE_PTYPE_TUNNEL_GTPC,
+    /// GTP-U tunneling packet type.
+    Gtpu = ffi::RTE_PTYPE_TUNNEL_GTPU,
+    /// ESP (IPsec) tunneling packet type.
+    Esp = ffi::RTE_PTYPE_TUNNEL_ESP,
+    /// L2TP tunneling packet type.
+    L2tp = ffi::RTE_PTYPE_TUNNEL_L2TP,
+    /// VXLAN-GPE tunneling packet type.
+    VxlanGpe = ffi::RTE_PTYPE_TUNNEL_VXLAN_GPE,
+    /// MPLS-in-GRE tunneling packet type.
+    MplsInGre = ffi::RTE_PTYPE_TUNNEL_MPLS_IN_GRE,
+    /// MPLS-in-UDP tunneling packet type.
+    MplsInUdp = ffi::RTE_PTYPE_TUNNEL_MPLS_IN_UDP,
+}
+
+/// The inner L2 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum InnerL2Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// Ethernet packet type.
+    Ether = ffi::RTE_PTYPE_INNER_L2_ETHER,
+    /// Ethernet packet type with VLAN tag.
+    Vlan = ffi::RTE_PTYPE_INNER_L2_ETHER_VLAN,
+    /// QinQ packet type.
+    Qinq = ffi::RTE_PTYPE_INNER_L2_ETHER_QINQ,
+}
+
+/// The inner L3 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum InnerL3Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// IPv4 packet type without any extension.
+    Ipv4 = ffi::RTE_PTYPE_INNER_L3_IPV4,
+    /// IPv4 packet type with the IP options.
+    Ipv4Ext = ffi::RTE_PTYPE_INNER_L3_IPV4_EXT,
+    /// IPv6 packet type without any extension header.
+    Ipv6 = ffi::RTE_PTYPE_INNER_L3_IPV6,
+    /// IPv4 packet type which may or may not have the IP options.
+    Ipv4ExtUnknown = ffi::RTE_PTYPE_INNER_L3_IPV4_EXT_UNKNOWN,
+    /// IPv6 packet type with the extension headers.
+    Ipv6Ext = ffi::RTE_PTYPE_INNER_L3_IPV6_EXT,
+    /// IPv6 packet type which may or may not have the extension headers.
+    Ipv6ExtUnknown = ffi::RTE_PTYPE_INNER_L3_IPV6_EXT_UNKNOWN,
+}
+
+/// The inner L4 packet type.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum InnerL4Type {
+    /// Unknown packet type.
+    Unknown = ffi::RTE_PTYPE_UNKNOWN,
+    /// TCP packet type.
+    Tcp = ffi::RTE_PTYPE_INNER_L4_TCP,
+    /// UDP packet type.
+    Udp = ffi::RTE_PTYPE_INNER_L4_UDP,
+    /// Fragmented IP packet type.
+    Frag = ffi::RTE_PTYPE_INNER_L4_FRAG,
+    /// SCTP packet type.
+    Sctp = ffi::RTE_PTYPE_INNER_L4_SCTP,
+    /// ICMP packet type.
+    Icmp = ffi::RTE_PTYPE_INNER_L4_ICMP,
+    /// Non-fragmented IP packet type of the other L4 protocols.
+    NonFrag = ffi::RTE_PTYPE_INNER_L4_NONFRAG,
+}
+
+/// The packet type of an mbuf, e.g. `ETHER | IPV4 | UDP | VXLAN | INNER_ETHER | INNER_IPV6 | INNER_TCP`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PacketType(pub u32);
+
+impl From<u32> for PacketType {
+    fn from(ptype: u32) -> Self {
+        PacketType(ptype)
+    }
+}
+
+impl From<PacketType> for u32 {
+    fn from(ptype: PacketType) -> Self {
+        ptype.0
+    }
+}
+
+impl fmt::Display for PacketType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0 as c_char; 256];
+
+        if unsafe { ffi::rte_get_ptype_name(self.0, buf.as_mut_ptr(), buf.len()) } < 0 {
+            return write!(f, "{:#x}", self.0);
+        }
+
+        write!(f, "{}", unsafe { CStr::from_ptr(buf.as_ptr()) }.to_string_lossy())
+    }
+}
+
+impl PacketType {
+    /// The L2 packet type.
+    pub fn l2(&self) -> L2Type {
+        L2Type::from_u32(self.0 & ffi::RTE_PTYPE_L2_MASK).unwrap_or(L2Type::Unknown)
+    }
+
+    /// The L3 packet type.
+    pub fn l3(&self) -> L3Type {
+        L3Type::from_u32(self.0 & ffi::RTE_PTYPE_L3_MASK).unwrap_or(L3Type::Unknown)
+    }
+
+    /// The L4 packet type.
+    pub fn l4(&self) -> L4Type {
+        L4Type::from_u32(self.0 & ffi::RTE_PTYPE_L4_MASK).unwrap_or(L4Type::Unknown)
+    }
+
+    /// The tunnel packet type.
+    pub fn tunnel(&self) -> TunnelType {
+        TunnelType::from_u32(self.0 & ffi::RTE_PTYPE_TUNNEL_MASK).unwrap_or(TunnelType::Unknown)
+    }
+
+    /// The inner L2 packet type.
+    pub fn inner_l2(&self) -> InnerL2Type {
+        InnerL2Type::from_u32(self.0 & ffi::RTE_PTYPE_INNER_L2_MASK).unwrap_or(InnerL2Type::Unknown)
+    }
+
+    /// The inner L3 packet type.
+    pub fn inner_l3(&self) -> InnerL3Type {
+        InnerL3Type::from_u32(self.0 & ffi::RTE_PTYPE_INNER_L3_MASK).unwrap_or(InnerL3Type::Unknown)
+    }
+
+    /// The inner L4 packet type.
+    pub fn inner_l4(&self) -> InnerL4Type {
+        InnerL4Type::from_u32(self.0 & ffi::RTE_PTYPE_INNER_L4_MASK).unwrap_or(InnerL4Type::Unknown)
+    }
+
+    /// Check if the packet type is unknown.
+    pub fn is_unknown(&self) -> bool {
+        self.0 == ffi::RTE_PTYPE_UNKNOWN
+    }
+
+    /// Check if the outer L3 is IPv4, with or without the IP options.
+    pub fn is_ipv4(&self) -> bool {
+        match self.l3() {
+            L3Type::Ipv4 | L3Type::Ipv4Ext | L3Type::Ipv4ExtUnknown => true,
+            _ => false,
+        }
+    }
+
+    /// Check if the outer L3 is IPv6, with or without the extension headers.
+    pub fn is_ipv6(&self) -> bool {
+        match self.l3() {
+            L3Type::Ipv6 | L3Type::Ipv6Ext | L3Type::Ipv6ExtUnknown => true,
+            _ => false,
+        }
+    }
+
+    /// Check if the packet is tunneled.
+    pub fn is_tunnel(&self) -> bool {
+        self.tunnel() != TunnelType::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_type() {
+        let ptype = PacketType(
+            ffi::RTE_PTYPE_L2_ETHER
+                | ffi::RTE_PTYPE_L3_IPV4_EXT_UNKNOWN
+                | ffi::RTE_PTYPE_L4_UDP
+                | ffi::RTE_PTYPE_TUNNEL_VXLAN
+                | ffi::RTE_PTYPE_INNER_L2_ETHER
+                | ffi::RTE_PTYPE_INNER_L3_IPV6
+                | ffi::RTE_PTYPE_INNER_L4_TCP,
+        );
+
+        assert_eq!(ptype.l2(), L2Type::Ether);
+        assert_eq!(ptype.l3(), L3Type::Ipv4ExtUnknown);
+        assert_eq!(ptype.l4(), L4Type::Udp);
+        assert_eq!(ptype.tunnel(), TunnelType::Vxlan);
+        assert_eq!(ptype.inner_l2(), InnerL2Type::Ether);
+        assert_eq!(ptype.inner_l3(), InnerL3Type::Ipv6);
+        assert_eq!(ptype.inner_l4(), InnerL4Type::Tcp);
+        assert!(ptype.is_ipv4());
+        assert!(!ptype.is_ipv6());
+        assert!(ptype.is_tunnel());
+
+        let ptype = PacketType::default();
+
+        assert!(ptype.is_unknown());
+        assert_eq!(ptype.l2(), L2Type::Unknown);
+        assert!(!ptype.is_tunnel());
+    }
+}
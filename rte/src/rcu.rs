@@ -0,0 +1,398 @@
+//!
+//! RTE Quiescent State Based Reclamation (QSBR).
+//!
+//! Quiescent State (QS) is any point in the thread execution
+//! where the thread does not hold a reference to a data structure
+//! in shared memory. While using lock-less data structures, the writer
+//! can safely free memory once all the reader threads have entered
+//! quiescent state.
+//!
+//! This library provides the ability for the readers to report quiescent
+//! state and for the writers to identify when all the readers have
+//! entered quiescent state.
+//!
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::{c_uint, c_void};
+use std::os::unix::io::AsRawFd;
+use std::ptr::{self, NonNull};
+
+use anyhow::{anyhow, Result};
+use cfile;
+
+use errors::{rte_error, AsResult};
+use ffi;
+use malloc;
+use utils::{catch_panic, AsCString, AsRaw, FromRaw, IntoRaw};
+
+pub use ffi::RTE_QSBR_THRID_INVALID;
+#[cfg(not(feature = "dpdk-19_11"))]
+pub use ffi::{RTE_HASH_RCU_DQ_RECLAIM_MAX, RTE_RCU_QSBR_DQ_NAMESIZE};
+
+pub type RawQsbr = ffi::rte_rcu_qsbr;
+pub type RawQsbrPtr = *mut ffi::rte_rcu_qsbr;
+
+/// The token returned by `Qsbr::start()`, which should be passed to `Qsbr::check()`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Token(pub u64);
+
+/// The RTE Quiescent State variable.
+raw!(pub Qsbr(RawQsbr));
+
+unsafe impl Send for Qsbr {}
+unsafe impl Sync for Qsbr {}
+
+impl Qsbr {
+    /// Return the size of the memory occupied by a Quiescent State variable.
+    pub fn memsize(max_threads: u32) -> Result<usize> {
+        let size = unsafe { ffi::rte_rcu_qsbr_get_memsize(max_threads) };
+
+        if size == 1 {
+            Err(anyhow!(rte_error()))
+        } else {
+            Ok(size)
+        }
+    }
+
+    /// Allocate and initialize a Quiescent State variable for up to `max_threads` reader threads.
+    pub fn create(max_threads: u32) -> Result<Self> {
+        let size = Self::memsize(max_threads)?;
+        let p = malloc::zmalloc("rcu_qsbr", size, ffi::RTE_CACHE_LINE_SIZE) as RawQsbrPtr;
+        let v = p.as_result()?;
+
+        if unsafe { ffi::rte_rcu_qsbr_init(p, max_threads) } == 0 {
+            Ok(Qsbr(v))
+        } else {
+            let err = rte_error();
+
+            malloc::free(p as *mut _);
+
+            Err(anyhow!(err))
+        }
+    }
+
+    /// Free the Quiescent State variable.
+    ///
+    /// All the reader threads should have been unregistered.
+    pub fn free(self) {
+        malloc::free(self.into_raw() as *mut _)
+    }
+
+    /// Register a reader thread to report its quiescent state on the QS variable.
+    ///
+    /// `thread_id` is a value between 0 and (max_threads - 1).
+    pub fn register_thread(&self, thread_id: u32) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_rcu_qsbr_thread_register(self.as_raw_mut(), thread_id) }; ok => { self })
+    }
+
+    /// Remove a reader thread from the list of threads reporting their quiescent state on the QS variable.
+    pub fn unregister_thread(&self, thread_id: u32) -> Result<&Self> {
+        rte_check!(unsafe { ffi::rte_rcu_qsbr_thread_unregister(self.as_raw_mut(), thread_id) }; ok => { self })
+    }
+
+    /// Add a registered reader thread to the list of threads reporting their quiescent state.
+    ///
+    /// The reader thread must be online before calling `quiescent()`.
+    #[inline]
+    pub fn thread_online(&self, thread_id: u32) {
+        unsafe { ffi::_rte_rcu_qsbr_thread_online(self.as_raw_mut(), thread_id) }
+    }
+
+    /// Remove a registered reader thread from the list of threads reporting their quiescent state.
+    ///
+    /// The reader thread should go offline before calling any functions that block,
+    /// to ensure that `check()` does not wait indefinitely for it.
+    #[inline]
+    pub fn thread_offline(&self, thread_id: u32) {
+        unsafe { ffi::_rte_rcu_qsbr_thread_offline(self.as_raw_mut(), thread_id) }
+    }
+
+    /// Take the reader thread online, it will be taken offline when the returned guard is dropped.
+    pub fn online(&self, thread_id: u32) -> OnlineGuard<'_> {
+        self.thread_online(thread_id);
+
+        OnlineGuard { qsbr: self, thread_id }
+    }
+
+    /// Ask the reader threads to report the quiescent state status.
+    #[inline]
+    pub fn start(&self) -> Token {
+        Token(unsafe { ffi::_rte_rcu_qsbr_start(self.as_raw_mut()) })
+    }
+
+    /// Update quiescent state for a reader thread.
+    #[inline]
+    pub fn quiescent(&self, thread_id: u32) {
+        unsafe { ffi::_rte_rcu_qsbr_quiescent(self.as_raw_mut(), thread_id) }
+    }
+
+    /// Checks if all the reader threads have entered the quiescent state referenced by token.
+    ///
+    /// If `wait` is true, block till all the reader threads have completed entering the quiescent state.
+    #[inline]
+    pub fn check(&self, token: Token, wait: bool) -> bool {
+        unsafe { ffi::_rte_rcu_qsbr_check(self.as_raw_mut(), token.0, wait) != 0 }
+    }
+
+    /// Wait till the reader threads have entered quiescent state.
+    ///
+    /// `thread_id` is the thread ID of the caller if it is also registered as a reader.
+    pub fn synchronize(&self, thread_id: Option<u32>) {
+        unsafe { ffi::rte_rcu_qsbr_synchronize(self.as_raw_mut(), thread_id.unwrap_or(RTE_QSBR_THRID_INVALID)) }
+    }
+
+    /// Dump the details of the QS variable to a file.
+    pub fn dump<S: AsRawFd>(&self, s: &S) -> Result<()> {
+        let mut f = cfile::fdopen(s, "w")?;
+
+        rte_check!(unsafe { ffi::rte_rcu_qsbr_dump(&mut **f as *mut _ as *mut _, self.as_raw_mut()) })
+    }
+
+    /// Associate the QS variable with a hash table, to enable the integrated lock-free reclamation.
+    ///
+    /// It should be called immediately after creating the hash table.
+    #[cfg(not(feature = "dpdk-19_11"))]
+    pub fn attach_hash(&self, h: *mut ffi::rte_hash, conf: &HashRcuConfig) -> Result<()> {
+        let mut cfg = ffi::rte_hash_rcu_config {
+            v: self.as_raw_mut(),
+            mode: conf.mode as u32,
+            dq_size: conf.dq_size,
+            trigger_reclaim_limit: conf.trigger_reclaim_limit,
+            max_reclaim_size: conf.max_reclaim_size,
+            key_data_ptr: conf.key_data_ptr,
+            free_key_data_func: conf.free_key_data_func,
+        };
+
+        rte_check!(unsafe { ffi::rte_hash_rcu_qsbr_add(h, &mut cfg) })
+    }
+}
+
+/// An RAII guard of an online reader thread, the thread will be taken offline when it is dropped.
+pub struct OnlineGuard<'a> {
+    qsbr: &'a Qsbr,
+    thread_id: u32,
+}
+
+impl<'a> OnlineGuard<'a> {
+    /// Update quiescent state for the reader thread.
+    #[inline]
+    pub fn quiescent(&self) {
+        self.qsbr.quiescent(self.thread_id)
+    }
+}
+
+impl<'a> Drop for OnlineGuard<'a> {
+    fn drop(&mut self) {
+        self.qsbr.thread_offline(self.thread_id)
+    }
+}
+
+/// The reclamation mode of a hash table.
+#[cfg(not(feature = "dpdk-19_11"))]
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashRcuMode {
+    /// Create defer queue for reclaim.
+    DeferQueue = ffi::rte_hash_qsbr_mode::RTE_HASH_QSBR_MODE_DQ,
+    /// Use blocking mode reclaim. No defer queue created.
+    Sync = ffi::rte_hash_qsbr_mode::RTE_HASH_QSBR_MODE_SYNC,
+}
+
+/// The RCU configuration of a hash table.
+#[cfg(not(feature = "dpdk-19_11"))]
+#[derive(Clone, Copy, Debug)]
+pub struct HashRcuConfig {
+    pub mode: HashRcuMode,
+    /// RCU defer queue size, default: total hash table entries.
+    pub dq_size: u32,
+    /// Threshold to trigger auto reclaim.
+    pub trigger_reclaim_limit: u32,
+    /// Max entries to reclaim in one go, default: `RTE_HASH_RCU_DQ_RECLAIM_MAX`.
+    pub max_reclaim_size: u32,
+    /// Pointer passed to the free function.
+    pub key_data_ptr: *mut c_void,
+    /// Function to call to free the key-data.
+    pub free_key_data_func: ffi::rte_hash_free_key_data,
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+impl Default for HashRcuConfig {
+    fn default() -> Self {
+        HashRcuConfig {
+            mode: HashRcuMode::DeferQueue,
+            dq_size: 0,
+            trigger_reclaim_limit: 0,
+            max_reclaim_size: 0,
+            key_data_ptr: ptr::null_mut(),
+            free_key_data_func: None,
+        }
+    }
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+bitflags! {
+    pub struct DeferQueueFlags: u32 {
+        /// The defer queue is not multi-thread safe.
+        const MT_UNSAFE = ffi::RTE_RCU_QSBR_DQ_MT_UNSAFE;
+    }
+}
+
+/// The parameters used when creating the defer queue.
+#[cfg(not(feature = "dpdk-19_11"))]
+#[derive(Clone, Copy, Debug)]
+pub struct DeferQueueConfig {
+    pub flags: DeferQueueFlags,
+    /// Number of entries in queue.
+    pub size: u32,
+    /// Trigger the reclamation automatically when the queue has more entries than this limit.
+    pub trigger_reclaim_limit: u32,
+    /// When automatic reclamation is enabled, reclaim at the max these many resources.
+    pub max_reclaim_size: u32,
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+impl Default for DeferQueueConfig {
+    fn default() -> Self {
+        DeferQueueConfig {
+            flags: DeferQueueFlags::empty(),
+            size: 0,
+            trigger_reclaim_limit: 0,
+            max_reclaim_size: 0,
+        }
+    }
+}
+
+/// The result of the reclamation.
+#[cfg(not(feature = "dpdk-19_11"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Reclaimed {
+    /// Number of resources that were freed.
+    pub freed: usize,
+    /// Number of resources pending on the defer queue.
+    pub pending: usize,
+    /// Number of resources that can be added to the defer queue.
+    pub available: usize,
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+type FreeFn<T> = Box<dyn FnMut(T)>;
+
+/// A queue storing the resources of type `T`, which will be freed after a grace period.
+#[cfg(not(feature = "dpdk-19_11"))]
+pub struct DeferQueue<T> {
+    raw: NonNull<ffi::rte_rcu_qsbr_dq>,
+    free_fn: *mut FreeFn<T>,
+    phantom: PhantomData<T>,
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+impl<T: Copy> DeferQueue<T> {
+    /// Create a defer queue on the QS variable, `free_fn` is called to free the resources.
+    pub fn create<S, F>(name: S, qsbr: &Qsbr, conf: &DeferQueueConfig, free_fn: F) -> Result<Self>
+    where
+        S: AsRef<str>,
+        F: FnMut(T) + 'static,
+    {
+        let esize = mem::size_of::<T>();
+
+        if esize == 0 || !esize.is_multiple_of(4) {
+            return Err(anyhow!("element size must be multiple of 4 bytes, {}", esize));
+        }
+
+        let name = name.as_cstring();
+        let free_fn = Box::into_raw(Box::new(Box::new(free_fn) as FreeFn<T>));
+        let params = ffi::rte_rcu_qsbr_dq_parameters {
+            name: name.as_ptr(),
+            flags: conf.flags.bits,
+            size: conf.size,
+            esize: esize as u32,
+            trigger_reclaim_limit: conf.trigger_reclaim_limit,
+            max_reclaim_size: conf.max_reclaim_size,
+            free_fn: Some(free_resource_stub::<T>),
+            p: free_fn as *mut _,
+            v: qsbr.as_raw_mut(),
+        };
+
+        match unsafe { ffi::rte_rcu_qsbr_dq_create(&params) }.as_result() {
+            Ok(raw) => Ok(DeferQueue {
+                raw,
+                free_fn,
+                phantom: PhantomData,
+            }),
+            Err(err) => {
+                unsafe { Box::from_raw(free_fn) };
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Enqueue one resource to the defer queue and start the grace period.
+    ///
+    /// The resource will be freed later after at least one grace period is over.
+    pub fn enqueue(&self, mut e: T) -> Result<()> {
+        if unsafe { ffi::rte_rcu_qsbr_dq_enqueue(self.raw.as_ptr(), &mut e as *mut T as *mut _) } == 0 {
+            Ok(())
+        } else {
+            Err(anyhow!(rte_error()))
+        }
+    }
+
+    /// Free at most `n` resources which completed the grace period from the defer queue.
+    pub fn reclaim(&self, n: usize) -> Result<Reclaimed> {
+        let mut freed = 0;
+        let mut pending = 0;
+        let mut available = 0;
+
+        if unsafe {
+            ffi::rte_rcu_qsbr_dq_reclaim(self.raw.as_ptr(), n as u32, &mut freed, &mut pending, &mut available)
+        } == 0
+        {
+            Ok(Reclaimed {
+                freed: freed as usize,
+                pending: pending as usize,
+                available: available as usize,
+            })
+        } else {
+            Err(anyhow!(rte_error()))
+        }
+    }
+
+    /// Delete the defer queue, reclaiming all the resources on it.
+    ///
+    /// If any of the resources have not completed the grace period, the defer queue is returned.
+    pub fn delete(self) -> ::std::result::Result<(), Self> {
+        if unsafe { ffi::rte_rcu_qsbr_dq_delete(self.raw.as_ptr()) } == 0 {
+            unsafe { Box::from_raw(self.free_fn) };
+
+            mem::forget(self);
+
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+impl<T> Drop for DeferQueue<T> {
+    fn drop(&mut self) {
+        // the free function must outlive the defer queue, leak it if some resources are still pending.
+        if unsafe { ffi::rte_rcu_qsbr_dq_delete(self.raw.as_ptr()) } == 0 {
+            unsafe { Box::from_raw(self.free_fn) };
+        }
+    }
+}
+
+#[cfg(not(feature = "dpdk-19_11"))]
+unsafe extern "C" fn free_resource_stub<T>(p: *mut c_void, e: *mut c_void, n: c_uint) {
+    let free_fn = &mut *(p as *mut FreeFn<T>);
+
+    catch_panic("defer queue free function", (), || {
+        // the resources stored on the defer queue are only 4 bytes aligned.
+        for i in 0..n as usize {
+            free_fn(ptr::read_unaligned((e as *const T).add(i)))
+        }
+    })
+}
@@ -0,0 +1,193 @@
+//!
+//! Random Early Detection (RED)
+//!
+//! RED is a congestion avoidance mechanism, which drops the packets with a probability
+//! growing with the average queue size between the minimum and maximum thresholds,
+//! so the queue is kept short before the tail drop happens.
+//!
+//! The drop profiles are configured per traffic class and packet color of the QoS scheduler,
+//! or used standalone with the run-time data of a software queue.
+//!
+use std::ops::{Index, IndexMut};
+
+use anyhow::Result;
+
+use errors::ErrorKind;
+use ffi;
+
+pub use ffi::{
+    RTE_RED_MAXP_INV_MAX, RTE_RED_MAXP_INV_MIN, RTE_RED_MAX_TH_MAX, RTE_RED_SCALING, RTE_RED_WQ_LOG2_MAX,
+    RTE_RED_WQ_LOG2_MIN,
+};
+
+/// The RED parameters of a queue.
+pub type RedParams = ffi::rte_red_params;
+
+/// The color of a packet, assigned by the traffic metering.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Green,
+    Yellow,
+    Red,
+}
+
+/// The number of packet colors.
+pub const COLORS: usize = 3;
+
+/// The drop profile of a traffic class, which has RED parameters for each packet color.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RedProfile(pub [RedParams; COLORS]);
+
+impl RedProfile {
+    /// The same RED parameters for all the colors.
+    pub fn new(params: RedParams) -> Self {
+        RedProfile([params; COLORS])
+    }
+
+    /// Set the RED parameters of the color.
+    pub fn with_color(mut self, color: Color, params: RedParams) -> Self {
+        self[color] = params;
+        self
+    }
+
+    /// Check the RED parameters of all the colors.
+    pub fn validate(&self) -> Result<()> {
+        self.0.iter().try_for_each(|params| RedConfig::new(params).map(|_| ()))
+    }
+}
+
+impl Index<Color> for RedProfile {
+    type Output = RedParams;
+
+    fn index(&self, color: Color) -> &Self::Output {
+        &self.0[color as usize]
+    }
+}
+
+impl IndexMut<Color> for RedProfile {
+    fn index_mut(&mut self, color: Color) -> &mut Self::Output {
+        &mut self.0[color as usize]
+    }
+}
+
+/// The RED configuration of a queue, which is pre-calculated from the `RedParams`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RedConfig(ffi::rte_red_config);
+
+impl RedConfig {
+    /// Create the RED configuration.
+    ///
+    /// The thresholds are in number of packets, `min_th` must be less than `max_th`,
+    /// and `max_th` must not exceed `RTE_RED_MAX_TH_MAX`.
+    pub fn new(params: &RedParams) -> Result<Self> {
+        let mut cfg = RedConfig::default();
+
+        let ret = unsafe {
+            ffi::rte_red_config_init(
+                &mut cfg.0,
+                params.wq_log2,
+                params.min_th,
+                params.max_th,
+                params.maxp_inv,
+            )
+        };
+
+        rte_check!(ret; ok => { cfg }; err => {
+            ErrorKind::InvalidRedParams(format!("{:?}, error {}", params, ret)).into()
+        })
+    }
+
+    /// The minimum threshold, in number of packets.
+    pub fn min_th(&self) -> u32 {
+        self.0.min_th >> (RTE_RED_SCALING + u32::from(self.0.wq_log2))
+    }
+
+    /// The maximum threshold, in number of packets.
+    pub fn max_th(&self) -> u32 {
+        self.0.max_th >> (RTE_RED_SCALING + u32::from(self.0.wq_log2))
+    }
+
+    /// The inverse of the maximum drop probability.
+    pub fn maxp_inv(&self) -> u8 {
+        self.0.maxp_inv
+    }
+
+    /// The negated log2 of the queue weight.
+    pub fn wq_log2(&self) -> u8 {
+        self.0.wq_log2
+    }
+
+    pub fn as_raw(&self) -> &ffi::rte_red_config {
+        &self.0
+    }
+}
+
+/// The verdict of a packet enqueued into a queue with RED.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// Enqueue the packet.
+    Enqueue,
+    /// Drop the packet since the average queue size exceeds the maximum threshold.
+    DropMaxThreshold,
+    /// Drop the packet based on the drop probability.
+    DropProbability,
+}
+
+impl Verdict {
+    /// Test if the packet should be dropped.
+    pub fn is_drop(self) -> bool {
+        self != Verdict::Enqueue
+    }
+}
+
+/// The RED run-time data of a queue.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Red(ffi::rte_red);
+
+impl Red {
+    pub fn new() -> Self {
+        Red::default()
+    }
+
+    /// Update the average queue size with the current queue size, and decide the verdict of a new packet.
+    ///
+    /// The `time` is the current timestamp in CPU cycles, e.g. `rdtsc()`.
+    #[inline]
+    pub fn enqueue(&mut self, cfg: &RedConfig, queue_size: u32, time: u64) -> Verdict {
+        match unsafe { ffi::_rte_red_enqueue(&cfg.0, &mut self.0, queue_size, time) } {
+            0 => Verdict::Enqueue,
+            1 => Verdict::DropMaxThreshold,
+            _ => Verdict::DropProbability,
+        }
+    }
+
+    /// Record the time the queue became empty, which decays the average queue size on the next enqueue.
+    #[inline]
+    pub fn mark_queue_empty(&mut self, time: u64) {
+        unsafe { ffi::_rte_red_mark_queue_empty(&mut self.0, time) }
+    }
+
+    /// The average queue size, scaled in fixed-point format by `RTE_RED_SCALING`.
+    pub fn avg(&self) -> u32 {
+        self.0.avg
+    }
+
+    /// The number of packets since the last dropped packet.
+    pub fn count(&self) -> u32 {
+        self.0.count
+    }
+
+    /// Reset the run-time data.
+    pub fn reset(&mut self) {
+        unsafe {
+            ffi::rte_red_rt_data_init(&mut self.0);
+        }
+    }
+
+    pub fn as_raw(&self) -> &ffi::rte_red {
+        &self.0
+    }
+}
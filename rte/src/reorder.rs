@@ -0,0 +1,166 @@
+//!
+//! RTE Reorder
+//!
+//! The reorder library restores the packets processed by multiple worker lcores to the order they were received,
+//! the sequence number of each packet is stamped in a dynamic field of the mbuf before being distributed.
+//!
+use std::mem::{self, MaybeUninit};
+use std::os::raw::c_uint;
+use std::ptr::NonNull;
+use std::slice;
+
+use anyhow::Result;
+
+use errors::{rte_error, AsResult};
+use ffi;
+use mbuf::{MBuf, RawMBufPtr};
+use memory::SocketId;
+use utils::{AsCString, AsRaw};
+
+pub type RawReorderBuffer = ffi::rte_reorder_buffer;
+
+/// The sequence number of the packets.
+pub type SeqNum = ffi::rte_reorder_seqn_t;
+
+/// The name of the dynamic field of the sequence number.
+pub const SEQN_DYNFIELD_NAME: &str = "rte_reorder_seqn_dynfield";
+
+/// The dynamic field of the sequence number in the mbufs.
+#[derive(Clone, Copy, Debug)]
+pub struct SeqnDynfield {
+    offset: usize,
+}
+
+impl SeqnDynfield {
+    /// Register the dynamic field of the sequence number, or lookup it if it was registered.
+    pub fn register() -> Result<Self> {
+        let mut params = ffi::rte_mbuf_dynfield {
+            name: [0; 64],
+            size: mem::size_of::<SeqNum>(),
+            align: mem::align_of::<SeqNum>(),
+            flags: 0,
+        };
+
+        for (dst, &src) in params.name.iter_mut().zip(SEQN_DYNFIELD_NAME.as_bytes()) {
+            *dst = src as _;
+        }
+
+        let offset = unsafe { ffi::rte_mbuf_dynfield_register(&params) }.as_result()?;
+
+        Ok(SeqnDynfield {
+            offset: offset as usize,
+        })
+    }
+
+    /// The offset of the dynamic field in the mbuf.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn field(&self, m: RawMBufPtr) -> *mut SeqNum {
+        unsafe { (m as *mut u8).add(self.offset) as *mut SeqNum }
+    }
+
+    /// Get the sequence number of the mbuf.
+    #[inline]
+    pub fn seqn(&self, m: &MBuf) -> SeqNum {
+        unsafe { *self.field(m.as_raw_mut()) }
+    }
+
+    /// Set the sequence number of the mbuf.
+    #[inline]
+    pub fn set_seqn(&self, m: &mut MBuf, seqn: SeqNum) {
+        unsafe { *self.field(m.as_raw_mut()) = seqn }
+    }
+
+    /// Stamp a burst of the received mbufs with the sequence numbers starting from `next`,
+    /// return the sequence number of the next burst.
+    #[inline]
+    pub fn stamp_burst(&self, pkts: &[RawMBufPtr], mut next: SeqNum) -> SeqNum {
+        for &m in pkts {
+            unsafe { *self.field(m) = next }
+
+            next = next.wrapping_add(1);
+        }
+
+        next
+    }
+}
+
+/// A reorder buffer, which isn't thread-safe and should be owned by the lcore transmitting the packets.
+#[derive(Debug)]
+pub struct ReorderBuffer {
+    raw: NonNull<RawReorderBuffer>,
+    seqn: SeqnDynfield,
+}
+
+unsafe impl Send for ReorderBuffer {}
+
+impl Drop for ReorderBuffer {
+    fn drop(&mut self) {
+        // the mbufs in the buffer are freed as well.
+        unsafe { ffi::rte_reorder_free(self.raw.as_ptr()) }
+    }
+}
+
+impl AsRaw for ReorderBuffer {
+    type Raw = RawReorderBuffer;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.raw.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.raw.as_ptr()
+    }
+}
+
+impl ReorderBuffer {
+    /// Create a reorder buffer named `name` on the NUMA socket, which holds up to `size` packets.
+    ///
+    /// The size must be a power of 2, and the dynamic field of the sequence number is registered as well.
+    pub fn create<S: AsRef<str>>(name: S, socket_id: SocketId, size: usize) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let seqn = SeqnDynfield::register()?;
+
+        unsafe { ffi::rte_reorder_create(name.as_ptr(), socket_id as c_uint, size as c_uint) }
+            .as_result()
+            .map(|raw| ReorderBuffer { raw, seqn })
+    }
+
+    /// The dynamic field of the sequence number, which must be stamped before the packets are inserted.
+    pub fn seqn_dynfield(&self) -> SeqnDynfield {
+        self.seqn
+    }
+
+    /// Reset the buffer to the initial state, the mbufs in the buffer are freed.
+    pub fn reset(&mut self) {
+        unsafe { ffi::rte_reorder_reset(self.raw.as_ptr()) }
+    }
+
+    /// Insert the mbuf into the buffer by its sequence number, which is owned by the buffer on success.
+    ///
+    /// On error the mbuf is still owned by the caller, and `rte_errno` is set to
+    ///
+    /// - `ENOSPC` if the mbuf is too early, it can be inserted again after the buffer is drained.
+    /// - `ERANGE` if the mbuf is out of the window of the buffer, it should be sent or dropped directly.
+    pub fn insert(&mut self, m: RawMBufPtr) -> Result<()> {
+        if unsafe { ffi::rte_reorder_insert(self.raw.as_ptr(), m) } == 0 {
+            Ok(())
+        } else {
+            Err(rte_error().into())
+        }
+    }
+
+    /// Drain the in-order mbufs from the buffer, return them at the head of `out`.
+    ///
+    /// The sequence numbers of the drained mbufs may have gaps if the packets were dropped or too late.
+    pub fn drain<'a>(&mut self, out: &'a mut [MaybeUninit<RawMBufPtr>]) -> &'a mut [RawMBufPtr] {
+        unsafe {
+            let n = ffi::rte_reorder_drain(self.raw.as_ptr(), out.as_mut_ptr() as *mut _, out.len() as c_uint);
+
+            slice::from_raw_parts_mut(out.as_mut_ptr() as *mut _, n as usize)
+        }
+    }
+}
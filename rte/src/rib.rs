@@ -0,0 +1,231 @@
+//!
+//! RTE RIB
+//!
+//! The RIB is the routing information base of a FIB, which stores the routes in a binary tree,
+//! so the routes can be enumerated or the less and more specific routes of a prefix can be found.
+//!
+use std::marker::PhantomData;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::os::raw::c_int;
+use std::ptr::{self, NonNull};
+
+use ffi;
+
+/// A route of the RIB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Route<A> {
+    /// The prefix of the route.
+    pub prefix: A,
+    /// The depth of the prefix.
+    pub depth: u8,
+    /// The next hop of the route.
+    pub next_hop: u64,
+}
+
+/// The IPv4 RIB of a FIB, which borrows the FIB.
+#[derive(Debug)]
+pub struct Rib<'a> {
+    raw: NonNull<ffi::rte_rib>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Rib<'a> {
+    /// Wrap the RIB of a FIB.
+    ///
+    /// # Safety
+    ///
+    /// The RIB must outlive the lifetime `'a`.
+    pub unsafe fn from_raw(raw: *mut ffi::rte_rib) -> Option<Self> {
+        NonNull::new(raw).map(|raw| Rib {
+            raw,
+            phantom: PhantomData,
+        })
+    }
+
+    fn route(node: *mut ffi::rte_rib_node) -> Option<Route<Ipv4Addr>> {
+        if node.is_null() {
+            return None;
+        }
+
+        let mut ip = 0;
+        let mut depth = 0;
+        let mut next_hop = 0;
+
+        unsafe {
+            ffi::rte_rib_get_ip(node, &mut ip);
+            ffi::rte_rib_get_depth(node, &mut depth);
+            ffi::rte_rib_get_nh(node, &mut next_hop);
+        }
+
+        Some(Route {
+            prefix: ip.into(),
+            depth,
+            next_hop,
+        })
+    }
+
+    /// Find the most specific route matching the IP address.
+    pub fn lookup(&self, ip: Ipv4Addr) -> Option<Route<Ipv4Addr>> {
+        Self::route(unsafe { ffi::rte_rib_lookup(self.raw.as_ptr(), ip.into()) })
+    }
+
+    /// Find the route of the exact prefix.
+    pub fn lookup_exact(&self, prefix: Ipv4Addr, depth: u8) -> Option<Route<Ipv4Addr>> {
+        Self::route(unsafe { ffi::rte_rib_lookup_exact(self.raw.as_ptr(), prefix.into(), depth) })
+    }
+
+    /// Find the less specific route of the prefix.
+    pub fn lookup_parent(&self, prefix: Ipv4Addr, depth: u8) -> Option<Route<Ipv4Addr>> {
+        unsafe {
+            let node = ffi::rte_rib_lookup_exact(self.raw.as_ptr(), prefix.into(), depth);
+
+            if node.is_null() {
+                None
+            } else {
+                Self::route(ffi::rte_rib_lookup_parent(node))
+            }
+        }
+    }
+
+    /// An iterator over the routes covered by the prefix, e.g. `0.0.0.0/0` for all the routes.
+    pub fn covered(&self, prefix: Ipv4Addr, depth: u8) -> RibIter<'_> {
+        RibIter {
+            rib: self.raw,
+            prefix: prefix.into(),
+            depth,
+            last: ptr::null_mut(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the routes covered by a prefix in a `Rib`.
+pub struct RibIter<'a> {
+    rib: NonNull<ffi::rte_rib>,
+    prefix: u32,
+    depth: u8,
+    last: *mut ffi::rte_rib_node,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Iterator for RibIter<'a> {
+    type Item = Route<Ipv4Addr>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.last = unsafe {
+            ffi::rte_rib_get_nxt(
+                self.rib.as_ptr(),
+                self.prefix,
+                self.depth,
+                self.last,
+                ffi::rte_rib_get_nxt_flag::RTE_RIB_GET_NXT_ALL as c_int,
+            )
+        };
+
+        Rib::route(self.last)
+    }
+}
+
+/// The IPv6 RIB of a FIB6, which borrows the FIB6.
+#[derive(Debug)]
+pub struct Rib6<'a> {
+    raw: NonNull<ffi::rte_rib6>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Rib6<'a> {
+    /// Wrap the RIB of a FIB6.
+    ///
+    /// # Safety
+    ///
+    /// The RIB must outlive the lifetime `'a`.
+    pub unsafe fn from_raw(raw: *mut ffi::rte_rib6) -> Option<Self> {
+        NonNull::new(raw).map(|raw| Rib6 {
+            raw,
+            phantom: PhantomData,
+        })
+    }
+
+    fn route(node: *mut ffi::rte_rib6_node) -> Option<Route<Ipv6Addr>> {
+        if node.is_null() {
+            return None;
+        }
+
+        let mut ip = [0; 16];
+        let mut depth = 0;
+        let mut next_hop = 0;
+
+        unsafe {
+            ffi::rte_rib6_get_ip(node, ip.as_mut_ptr());
+            ffi::rte_rib6_get_depth(node, &mut depth);
+            ffi::rte_rib6_get_nh(node, &mut next_hop);
+        }
+
+        Some(Route {
+            prefix: ip.into(),
+            depth,
+            next_hop,
+        })
+    }
+
+    /// Find the most specific route matching the IP address.
+    pub fn lookup(&self, ip: Ipv6Addr) -> Option<Route<Ipv6Addr>> {
+        Self::route(unsafe { ffi::rte_rib6_lookup(self.raw.as_ptr(), ip.octets().as_ptr()) })
+    }
+
+    /// Find the route of the exact prefix.
+    pub fn lookup_exact(&self, prefix: Ipv6Addr, depth: u8) -> Option<Route<Ipv6Addr>> {
+        Self::route(unsafe { ffi::rte_rib6_lookup_exact(self.raw.as_ptr(), prefix.octets().as_ptr(), depth) })
+    }
+
+    /// Find the less specific route of the prefix.
+    pub fn lookup_parent(&self, prefix: Ipv6Addr, depth: u8) -> Option<Route<Ipv6Addr>> {
+        unsafe {
+            let node = ffi::rte_rib6_lookup_exact(self.raw.as_ptr(), prefix.octets().as_ptr(), depth);
+
+            if node.is_null() {
+                None
+            } else {
+                Self::route(ffi::rte_rib6_lookup_parent(node))
+            }
+        }
+    }
+
+    /// An iterator over the routes covered by the prefix, e.g. `::/0` for all the routes.
+    pub fn covered(&self, prefix: Ipv6Addr, depth: u8) -> Rib6Iter<'_> {
+        Rib6Iter {
+            rib: self.raw,
+            prefix: prefix.octets(),
+            depth,
+            last: ptr::null_mut(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the routes covered by a prefix in a `Rib6`.
+pub struct Rib6Iter<'a> {
+    rib: NonNull<ffi::rte_rib6>,
+    prefix: [u8; 16],
+    depth: u8,
+    last: *mut ffi::rte_rib6_node,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Iterator for Rib6Iter<'a> {
+    type Item = Route<Ipv6Addr>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.last = unsafe {
+            ffi::rte_rib6_get_nxt(
+                self.rib.as_ptr(),
+                self.prefix.as_ptr(),
+                self.depth,
+                self.last,
+                ffi::rte_rib6_get_nxt_flag::RTE_RIB6_GET_NXT_ALL as c_int,
+            )
+        };
+
+        Rib6::route(self.last)
+    }
+}
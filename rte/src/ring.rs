@@ -1,5 +1,351 @@
+//!
+//! RTE Ring.
+//!
+//! The Ring Manager is a fixed-size queue, implemented as a table of pointers.
+//! Head and tail pointers are modified atomically, allowing concurrent access to it.
+//! The ring can be single or multi producer/consumer, and the objects can be enqueued or dequeued
+//! in bulk (all or none of them) or in burst (as many as possible).
+//!
+//! The default producer and consumer modes are encoded in the type, e.g. `Ring<T, Single, Multi>`,
+//! only the multi producer and consumer ring can be shared between threads.
+//!
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+use libc;
+
+use errors::{AsResult, ErrorKind::OsError};
 use ffi;
+use memory::SocketId;
+use utils::{AsCString, AsRaw};
 
 lazy_static! {
     pub static ref RTE_RING_NAMESIZE: usize = ffi::RTE_MEMZONE_NAMESIZE as usize - ffi::RTE_RING_MZ_PREFIX.len() + 1;
 }
+
+bitflags! {
+    pub struct RingFlags: u32 {
+        /// The default enqueue is "single-producer".
+        const RING_F_SP_ENQ     = 0x0001;
+        /// The default dequeue is "single-consumer".
+        const RING_F_SC_DEQ     = 0x0002;
+        /// The ring holds exactly the requested number of entries.
+        const RING_F_EXACT_SZ   = 0x0004;
+        /// The default enqueue is "MP RTS".
+        const RING_F_MP_RTS_ENQ = 0x0008;
+        /// The default dequeue is "MC RTS".
+        const RING_F_MC_RTS_DEQ = 0x0010;
+        /// The default enqueue is "MP HTS".
+        const RING_F_MP_HTS_ENQ = 0x0020;
+        /// The default dequeue is "MC HTS".
+        const RING_F_MC_HTS_DEQ = 0x0040;
+    }
+}
+
+/// The maximum size of a ring.
+pub const RTE_RING_SZ_MASK: usize = ffi::RTE_RING_SZ_MASK as usize;
+
+pub type RawRing = ffi::rte_ring;
+pub type RawRingPtr = *mut ffi::rte_ring;
+
+/// The producer or consumer mode of a ring.
+pub trait SyncMode {
+    /// The ring is used by a single thread, e.g. `RING_F_SP_ENQ` for the producer.
+    const SINGLE: bool;
+}
+
+/// The multi-thread safe producer or consumer mode, the default mode of a ring.
+#[derive(Debug)]
+pub enum Multi {}
+
+/// The single-thread producer or consumer mode.
+#[derive(Debug)]
+pub enum Single {}
+
+impl SyncMode for Multi {
+    const SINGLE: bool = false;
+}
+
+impl SyncMode for Single {
+    const SINGLE: bool = true;
+}
+
+/// A fixed-size queue of pointers to `T`, with the default producer mode `P` and consumer mode `C`.
+#[derive(Debug)]
+pub struct Ring<T, P: SyncMode = Multi, C: SyncMode = Multi> {
+    raw: NonNull<RawRing>,
+    phantom: PhantomData<(*mut T, P, C)>,
+}
+
+unsafe impl<T: Send, P: SyncMode, C: SyncMode> Send for Ring<T, P, C> {}
+
+// Only the multi-thread safe enqueue and dequeue can be called from several threads at the same time.
+unsafe impl<T: Send> Sync for Ring<T, Multi, Multi> {}
+
+impl<T, P: SyncMode, C: SyncMode> AsRaw for Ring<T, P, C> {
+    type Raw = RawRing;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.raw.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.raw.as_ptr()
+    }
+}
+
+macro_rules! enqueue {
+    ($f:ident, $ring:expr, $objs:expr) => {
+        unsafe {
+            ffi::$f(
+                $ring.raw.as_ptr(),
+                $objs.as_ptr() as *const *mut c_void,
+                $objs.len() as u32,
+                ptr::null_mut(),
+            ) as usize
+        }
+    };
+}
+
+macro_rules! dequeue {
+    ($f:ident, $ring:expr, $objs:expr) => {
+        unsafe {
+            ffi::$f(
+                $ring.raw.as_ptr(),
+                $objs.as_mut_ptr() as *mut *mut c_void,
+                $objs.len() as u32,
+                ptr::null_mut(),
+            ) as usize
+        }
+    };
+}
+
+impl<T> Ring<T> {
+    /// Lookup a multi producer and consumer ring by its name.
+    ///
+    /// Fail with `EINVAL` if the ring was created with another producer or consumer mode.
+    pub fn lookup<S: AsRef<str>>(name: S) -> Result<Self> {
+        unsafe { Self::lookup_single(name) }
+    }
+}
+
+impl<T, P: SyncMode, C: SyncMode> Ring<T, P, C> {
+    /// Create a new ring named `name` in memory.
+    ///
+    /// The real usable ring size is `count - 1` if `count` is a power of 2,
+    /// otherwise `RING_F_EXACT_SZ` must be specified to hold exactly `count` objects.
+    ///
+    /// `RING_F_SP_ENQ` and `RING_F_SC_DEQ` are set by the producer and consumer modes of the ring.
+    pub fn create<S: AsRef<str>>(name: S, count: usize, socket_id: SocketId, flags: RingFlags) -> Result<Self> {
+        let name = name.as_cstring();
+        let flags = (flags - (RingFlags::RING_F_SP_ENQ | RingFlags::RING_F_SC_DEQ)) | Self::sync_flags();
+
+        unsafe { ffi::rte_ring_create(name.as_ptr(), count as u32, socket_id, flags.bits) }
+            .as_result()
+            .map(|raw| Ring {
+                raw,
+                phantom: PhantomData,
+            })
+    }
+
+    /// Lookup a ring by its name.
+    ///
+    /// Fail with `EINVAL` if the ring was created with another producer or consumer mode.
+    ///
+    /// # Safety
+    ///
+    /// The single-thread producer or consumer must only be used from the returned handle.
+    pub unsafe fn lookup_single<S: AsRef<str>>(name: S) -> Result<Self> {
+        let name = name.as_cstring();
+        let raw = ffi::rte_ring_lookup(name.as_ptr()).as_result()?;
+        let flags = RingFlags::from_bits_truncate(raw.as_ref().flags as u32);
+
+        if flags & (RingFlags::RING_F_SP_ENQ | RingFlags::RING_F_SC_DEQ) != Self::sync_flags() {
+            return Err(OsError(libc::EINVAL).into());
+        }
+
+        Ok(Ring {
+            raw,
+            phantom: PhantomData,
+        })
+    }
+
+    fn sync_flags() -> RingFlags {
+        let mut flags = RingFlags::empty();
+
+        if P::SINGLE {
+            flags |= RingFlags::RING_F_SP_ENQ;
+        }
+        if C::SINGLE {
+            flags |= RingFlags::RING_F_SC_DEQ;
+        }
+
+        flags
+    }
+
+    /// Free all memory used by the ring.
+    pub fn free(self) {
+        unsafe { ffi::rte_ring_free(self.raw.as_ptr()) }
+    }
+
+    /// Reset the ring to the empty state, the objects left in the ring are discarded without being dequeued.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may enqueue or dequeue the ring, through this or another handle, until it returns.
+    pub unsafe fn reset(&self) {
+        ffi::rte_ring_reset(self.raw.as_ptr())
+    }
+
+    /// Enqueue several objects with the default producer mode of the ring, either all or none of them are enqueued.
+    ///
+    /// Return the actual number of objects enqueued (either 0 or `objs.len()`).
+    #[inline]
+    pub fn enqueue_bulk(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_enqueue_bulk, self, objs)
+    }
+
+    /// Enqueue several objects (multi-producers safe), either all or none of them are enqueued.
+    #[inline]
+    pub fn mp_enqueue_bulk(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_mp_enqueue_bulk, self, objs)
+    }
+
+    /// Enqueue several objects (NOT multi-producers safe), either all or none of them are enqueued.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may enqueue on the ring at the same time.
+    #[inline]
+    pub unsafe fn sp_enqueue_bulk(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_sp_enqueue_bulk, self, objs)
+    }
+
+    /// Enqueue as many objects as possible with the default producer mode of the ring.
+    ///
+    /// Return the actual number of objects enqueued.
+    #[inline]
+    pub fn enqueue_burst(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_enqueue_burst, self, objs)
+    }
+
+    /// Enqueue as many objects as possible (multi-producers safe).
+    #[inline]
+    pub fn mp_enqueue_burst(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_mp_enqueue_burst, self, objs)
+    }
+
+    /// Enqueue as many objects as possible (NOT multi-producers safe).
+    ///
+    /// # Safety
+    ///
+    /// No other thread may enqueue on the ring at the same time.
+    #[inline]
+    pub unsafe fn sp_enqueue_burst(&self, objs: &[*mut T]) -> usize {
+        enqueue!(_rte_ring_sp_enqueue_burst, self, objs)
+    }
+
+    /// Dequeue several objects with the default consumer mode of the ring, either all or none of them are dequeued.
+    ///
+    /// Return the actual number of objects dequeued (either 0 or `objs.len()`).
+    #[inline]
+    pub fn dequeue_bulk(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_dequeue_bulk, self, objs)
+    }
+
+    /// Dequeue several objects (multi-consumers safe), either all or none of them are dequeued.
+    #[inline]
+    pub fn mc_dequeue_bulk(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_mc_dequeue_bulk, self, objs)
+    }
+
+    /// Dequeue several objects (NOT multi-consumers safe), either all or none of them are dequeued.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may dequeue from the ring at the same time.
+    #[inline]
+    pub unsafe fn sc_dequeue_bulk(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_sc_dequeue_bulk, self, objs)
+    }
+
+    /// Dequeue as many objects as possible with the default consumer mode of the ring.
+    ///
+    /// Return the actual number of objects dequeued.
+    #[inline]
+    pub fn dequeue_burst(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_dequeue_burst, self, objs)
+    }
+
+    /// Dequeue as many objects as possible (multi-consumers safe).
+    #[inline]
+    pub fn mc_dequeue_burst(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_mc_dequeue_burst, self, objs)
+    }
+
+    /// Dequeue as many objects as possible (NOT multi-consumers safe).
+    ///
+    /// # Safety
+    ///
+    /// No other thread may dequeue from the ring at the same time.
+    #[inline]
+    pub unsafe fn sc_dequeue_burst(&self, objs: &mut [*mut T]) -> usize {
+        dequeue!(_rte_ring_sc_dequeue_burst, self, objs)
+    }
+
+    /// Enqueue one object with the default producer mode of the ring.
+    #[inline]
+    pub fn enqueue(&self, obj: *mut T) -> bool {
+        self.enqueue_bulk(&[obj]) == 1
+    }
+
+    /// Dequeue one object with the default consumer mode of the ring.
+    #[inline]
+    pub fn dequeue(&self) -> Option<*mut T> {
+        let mut objs = [ptr::null_mut()];
+
+        if self.dequeue_bulk(&mut objs) == 1 {
+            Some(objs[0])
+        } else {
+            None
+        }
+    }
+
+    /// Return the number of entries in the ring.
+    #[inline]
+    pub fn count(&self) -> usize {
+        unsafe { ffi::_rte_ring_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Return the number of free entries in the ring.
+    #[inline]
+    pub fn free_count(&self) -> usize {
+        unsafe { ffi::_rte_ring_free_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Test if the ring is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        unsafe { ffi::_rte_ring_full(self.raw.as_ptr()) != 0 }
+    }
+
+    /// Test if the ring is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        unsafe { ffi::_rte_ring_empty(self.raw.as_ptr()) != 0 }
+    }
+
+    /// Return the size of the data store used by the ring.
+    #[inline]
+    pub fn size(&self) -> usize {
+        unsafe { ffi::_rte_ring_get_size(self.raw.as_ptr()) as usize }
+    }
+
+    /// Return the number of objects which can be stored in the ring.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        unsafe { ffi::_rte_ring_get_capacity(self.raw.as_ptr()) as usize }
+    }
+}
@@ -0,0 +1,208 @@
+//!
+//! Routing table
+//!
+//! The routing table maps the IPv4 prefixes to the next hop objects,
+//! the routes are compiled into a FIB for the longest prefix match,
+//! which stores the index of the next hop object as its next hop value.
+//!
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+
+use anyhow::{anyhow, Result};
+
+use ethdev::PortId;
+use ether::EtherAddr;
+use fib::{Fib, FibConf, NextHopSize, RTE_FIB_MAXDEPTH};
+use memory::SocketId;
+
+/// The maximum number of the addresses looked up by FIB at a time.
+const LOOKUP_BURST: usize = 64;
+
+/// The next hop value of the FIB if there is no route.
+const NO_ROUTE: u64 = 0;
+
+/// The identifier of a next hop object in the routing table.
+pub type NextHopId = usize;
+
+/// The next hop of a route.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NextHop {
+    /// The output port.
+    pub port_id: PortId,
+    /// The destination MAC address of the gateway.
+    pub mac: EtherAddr,
+    /// The MTU of the output link.
+    pub mtu: u16,
+}
+
+/// A routing table of the IPv4 prefixes.
+#[derive(Debug)]
+pub struct Table<T = NextHop> {
+    fib: Fib,
+    next_hops: Vec<T>,
+    routes: BTreeMap<(Ipv4Addr, u8), NextHopId>,
+}
+
+impl<T> Table<T> {
+    /// Create a routing table named `name`, which can hold `max_routes` routes.
+    pub fn new<S: AsRef<str>>(name: S, max_routes: u32, socket_id: SocketId) -> Result<Self> {
+        let conf = FibConf {
+            default_nh: NO_ROUTE,
+            max_routes,
+            nh_size: NextHopSize::Byte4,
+            ..Default::default()
+        };
+
+        Ok(Table {
+            fib: Fib::create(name, socket_id, &conf)?,
+            next_hops: Vec::new(),
+            routes: BTreeMap::new(),
+        })
+    }
+
+    /// Add a next hop object, which can be shared by the routes.
+    pub fn add_next_hop(&mut self, next_hop: T) -> Result<NextHopId> {
+        let id = self.next_hops.len();
+
+        if Self::fib_next_hop(id) > NextHopSize::Byte4.max_next_hop() {
+            return Err(anyhow!("too many next hops"));
+        }
+
+        self.next_hops.push(next_hop);
+
+        Ok(id)
+    }
+
+    /// Get the next hop object.
+    pub fn next_hop(&self, id: NextHopId) -> Option<&T> {
+        self.next_hops.get(id)
+    }
+
+    /// Get the mutable next hop object, e.g. to update the MAC address after ARP resolution.
+    pub fn next_hop_mut(&mut self, id: NextHopId) -> Option<&mut T> {
+        self.next_hops.get_mut(id)
+    }
+
+    /// Add or replace the route of the prefix.
+    pub fn add(&mut self, prefix: Ipv4Addr, depth: u8, next_hop: NextHopId) -> Result<()> {
+        if next_hop >= self.next_hops.len() {
+            return Err(anyhow!("unknown next hop {}", next_hop));
+        }
+
+        let prefix = Self::network(prefix, depth)?;
+
+        self.fib.add(prefix, depth, Self::fib_next_hop(next_hop))?;
+        self.routes.insert((prefix, depth), next_hop);
+
+        Ok(())
+    }
+
+    /// Delete the route of the prefix, return the next hop of the route.
+    pub fn delete(&mut self, prefix: Ipv4Addr, depth: u8) -> Result<NextHopId> {
+        let prefix = Self::network(prefix, depth)?;
+        let next_hop = self
+            .routes
+            .remove(&(prefix, depth))
+            .ok_or_else(|| anyhow!("route {}/{} not found", prefix, depth))?;
+
+        self.fib.delete(prefix, depth)?;
+
+        Ok(next_hop)
+    }
+
+    /// The number of the routes.
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Test if the routing table has no route.
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// An iterator visiting the routes, ordered by the prefix.
+    pub fn routes(&self) -> impl Iterator<Item = (Ipv4Addr, u8, &T)> {
+        self.routes
+            .iter()
+            .map(move |(&(prefix, depth), &id)| (prefix, depth, &self.next_hops[id]))
+    }
+
+    /// Lookup the next hop of the destination address.
+    #[inline]
+    pub fn lookup<A: Into<u32>>(&self, dst: A) -> Option<&T> {
+        self.resolve(self.fib.lookup(Ipv4Addr::from(dst.into())))
+    }
+
+    /// Lookup the next hops of a burst of the destination addresses, e.g. `be32` from the IPv4 headers,
+    /// and return the number of the addresses with a route.
+    #[inline]
+    pub fn lookup_bulk<'a, A: Copy + Into<u32>>(&'a self, dst: &[A], next_hops: &mut [Option<&'a T>]) -> usize {
+        let mut ips = [0u32; LOOKUP_BURST];
+        let mut nhs = [NO_ROUTE; LOOKUP_BURST];
+        let mut hits = 0;
+
+        for (dst, next_hops) in dst.chunks(LOOKUP_BURST).zip(next_hops.chunks_mut(LOOKUP_BURST)) {
+            let n = dst.len().min(next_hops.len());
+
+            for (ip, &addr) in ips.iter_mut().zip(dst) {
+                *ip = addr.into();
+            }
+
+            self.fib.lookup_bulk(&ips[..n], &mut nhs[..n]);
+
+            for (next_hop, &nh) in next_hops.iter_mut().zip(&nhs[..n]) {
+                *next_hop = self.resolve(nh);
+
+                if next_hop.is_some() {
+                    hits += 1;
+                }
+            }
+        }
+
+        hits
+    }
+
+    fn resolve(&self, nh: u64) -> Option<&T> {
+        if nh == NO_ROUTE {
+            None
+        } else {
+            self.next_hops.get(nh as usize - 1)
+        }
+    }
+
+    fn fib_next_hop(id: NextHopId) -> u64 {
+        id as u64 + 1
+    }
+
+    fn network(prefix: Ipv4Addr, depth: u8) -> Result<Ipv4Addr> {
+        if u32::from(depth) > RTE_FIB_MAXDEPTH {
+            return Err(anyhow!("invalid depth {}", depth));
+        }
+
+        let mask = u32::max_value().checked_shl(32 - u32::from(depth)).unwrap_or(0);
+
+        Ok(Ipv4Addr::from(u32::from(prefix) & mask))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network() {
+        assert_eq!(
+            Table::<NextHop>::network(Ipv4Addr::new(10, 1, 2, 3), 16).unwrap(),
+            Ipv4Addr::new(10, 1, 0, 0)
+        );
+        assert_eq!(
+            Table::<NextHop>::network(Ipv4Addr::new(10, 1, 2, 3), 0).unwrap(),
+            Ipv4Addr::new(0, 0, 0, 0)
+        );
+        assert_eq!(
+            Table::<NextHop>::network(Ipv4Addr::new(10, 1, 2, 3), 32).unwrap(),
+            Ipv4Addr::new(10, 1, 2, 3)
+        );
+        assert!(Table::<NextHop>::network(Ipv4Addr::new(10, 1, 2, 3), 33).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+//!
+//! RTE Hierarchical Scheduler
+//!
+//! The QoS scheduler shapes the traffic of an output port with a hierarchy of port, subport, pipe,
+//! traffic class and queue, which is usually inserted between the packet classification and `tx_burst()`.
+//!
+//! The classification stage writes the tree path and the color of each packet into the mbuf,
+//! then the packets are enqueued into the queues of the path and dequeued in the order of the scheduling.
+//!
+use std::mem::MaybeUninit;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+use std::slice;
+
+use anyhow::Result;
+
+use errors::{AsResult, ErrorKind::OsError};
+use ffi;
+use mbuf::{MBuf, RawMBufPtr};
+use memory::SocketId;
+use red::Color;
+use utils::{AsCString, AsRaw};
+
+/// The number of the queues per pipe.
+pub const QUEUES_PER_PIPE: usize = ffi::RTE_SCHED_QUEUES_PER_PIPE as usize;
+/// The number of the best-effort queues per pipe.
+pub const BE_QUEUES_PER_PIPE: usize = ffi::RTE_SCHED_BE_QUEUES_PER_PIPE as usize;
+/// The number of the traffic classes per pipe, the strict priority ones and the best-effort one.
+pub const TRAFFIC_CLASSES_PER_PIPE: usize = ffi::RTE_SCHED_TRAFFIC_CLASSES_PER_PIPE as usize;
+/// The best-effort traffic class, which has the lowest priority.
+pub const TRAFFIC_CLASS_BE: u32 = ffi::RTE_SCHED_TRAFFIC_CLASS_BE;
+/// The default framing overhead of Ethernet, including the preamble, the FCS and the inter-frame gap.
+pub const FRAME_OVERHEAD_DEFAULT: u32 = ffi::RTE_SCHED_FRAME_OVERHEAD_DEFAULT;
+
+pub type RawPort = ffi::rte_sched_port;
+
+/// The parameters of a pipe profile, the rates are in bytes per second.
+pub type PipeParams = ffi::rte_sched_pipe_params;
+
+/// The statistics of a subport.
+pub type SubportStats = ffi::rte_sched_subport_stats;
+
+/// The statistics of a queue.
+pub type QueueStats = ffi::rte_sched_queue_stats;
+
+/// The configuration of a port.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PortConf {
+    /// The output rate, in bytes per second.
+    pub rate: u64,
+    /// The maximum Ethernet frame size, not including the framing overhead.
+    pub mtu: u32,
+    /// The framing overhead per packet, in bytes.
+    pub frame_overhead: u32,
+    /// The number of the subports.
+    pub n_subports_per_port: u32,
+    /// The maximum number of the pipes per subport, which must be a power of 2.
+    pub n_pipes_per_subport: u32,
+}
+
+impl Default for PortConf {
+    fn default() -> Self {
+        PortConf {
+            rate: 1_250_000_000,
+            mtu: 1522,
+            frame_overhead: FRAME_OVERHEAD_DEFAULT,
+            n_subports_per_port: 1,
+            n_pipes_per_subport: 4096,
+        }
+    }
+}
+
+/// The configuration of a subport, the rates are in bytes per second.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubportConf {
+    /// The token bucket rate.
+    pub tb_rate: u64,
+    /// The token bucket size, in credits.
+    pub tb_size: u64,
+    /// The rates of the traffic classes.
+    pub tc_rate: [u64; TRAFFIC_CLASSES_PER_PIPE],
+    /// The enforcement period of the rates, in milliseconds.
+    pub tc_period: u64,
+    /// The number of the pipes enabled in the subport.
+    pub n_pipes_per_subport_enabled: u32,
+    /// The queue size of each traffic class, which must be a power of 2.
+    pub qsize: [u16; TRAFFIC_CLASSES_PER_PIPE],
+    /// The pipe profiles of the subport.
+    pub pipe_profiles: Vec<PipeParams>,
+    /// The maximum number of the pipe profiles, including the ones added later.
+    pub n_max_pipe_profiles: u32,
+}
+
+/// The path of a queue in the hierarchy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TreePath {
+    /// The subport in the port.
+    pub subport: u32,
+    /// The pipe in the subport.
+    pub pipe: u32,
+    /// The traffic class in the pipe, up to `TRAFFIC_CLASS_BE`.
+    pub traffic_class: u32,
+    /// The queue in the traffic class, which is 0 except for the best-effort traffic class.
+    pub queue: u32,
+}
+
+/// A port of the hierarchical scheduler, which isn't thread-safe.
+#[derive(Debug)]
+pub struct Port(NonNull<RawPort>);
+
+unsafe impl Send for Port {}
+
+impl Drop for Port {
+    fn drop(&mut self) {
+        // the packets in the queues are freed as well.
+        unsafe { ffi::rte_sched_port_free(self.0.as_ptr()) }
+    }
+}
+
+impl AsRaw for Port {
+    type Raw = RawPort;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.0.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.0.as_ptr()
+    }
+}
+
+impl Port {
+    /// Create a port named `name` with the scheduler data allocated on the NUMA socket.
+    ///
+    /// The subports must be configured before the packets are enqueued.
+    pub fn config<S: AsRef<str>>(name: S, socket_id: SocketId, conf: &PortConf) -> Result<Self> {
+        let name = name.as_ref().as_cstring();
+        let mut params = ffi::rte_sched_port_params {
+            name: name.as_ptr(),
+            socket: socket_id as c_int,
+            rate: conf.rate,
+            mtu: conf.mtu,
+            frame_overhead: conf.frame_overhead,
+            n_subports_per_port: conf.n_subports_per_port,
+            n_pipes_per_subport: conf.n_pipes_per_subport,
+        };
+
+        unsafe { ffi::rte_sched_port_config(&mut params) }.as_result().map(Port)
+    }
+
+    /// Configure the subport with its pipe profiles.
+    pub fn subport_config(&mut self, subport_id: u32, conf: &SubportConf) -> Result<()> {
+        let mut params = ffi::rte_sched_subport_params {
+            tb_rate: conf.tb_rate,
+            tb_size: conf.tb_size,
+            tc_rate: conf.tc_rate,
+            tc_period: conf.tc_period,
+            n_pipes_per_subport_enabled: conf.n_pipes_per_subport_enabled,
+            qsize: conf.qsize,
+            pipe_profiles: conf.pipe_profiles.as_ptr() as *mut _,
+            n_pipe_profiles: conf.pipe_profiles.len() as u32,
+            n_max_pipe_profiles: conf.n_max_pipe_profiles,
+        };
+
+        let ret = unsafe { ffi::rte_sched_subport_config(self.0.as_ptr(), subport_id, &mut params) };
+
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Add a pipe profile to the subport, return the ID of the profile.
+    pub fn pipe_profile_add(&mut self, subport_id: u32, params: &PipeParams) -> Result<u32> {
+        let mut params = *params;
+        let mut profile_id = 0;
+
+        let ret = unsafe {
+            ffi::rte_sched_subport_pipe_profile_add(self.0.as_ptr(), subport_id, &mut params, &mut profile_id)
+        };
+
+        if ret == 0 {
+            Ok(profile_id)
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Configure the pipe with a pipe profile of the subport, or disable the pipe if the profile is `None`.
+    pub fn pipe_config(&mut self, subport_id: u32, pipe_id: u32, profile_id: Option<u32>) -> Result<()> {
+        let profile = profile_id.map_or(-1, |id| id as i32);
+
+        let ret = unsafe { ffi::rte_sched_pipe_config(self.0.as_ptr(), subport_id, pipe_id, profile) };
+
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Write the tree path and the color into the mbuf, which is called by the classification stage.
+    #[inline]
+    pub fn pkt_write(&self, m: &mut MBuf, path: &TreePath, color: Color) {
+        unsafe {
+            ffi::rte_sched_port_pkt_write(
+                self.0.as_ptr(),
+                m.as_raw_mut(),
+                path.subport,
+                path.pipe,
+                path.traffic_class,
+                path.queue,
+                color as ffi::rte_color::Type,
+            )
+        }
+    }
+
+    /// Read the tree path from the mbuf.
+    #[inline]
+    pub fn pkt_read_tree_path(&self, m: &MBuf) -> TreePath {
+        let mut path = TreePath::default();
+
+        unsafe {
+            ffi::rte_sched_port_pkt_read_tree_path(
+                self.0.as_ptr(),
+                m.as_raw(),
+                &mut path.subport,
+                &mut path.pipe,
+                &mut path.traffic_class,
+                &mut path.queue,
+            )
+        }
+
+        path
+    }
+
+    /// Enqueue the packets into the queues of their tree paths, return the number of the enqueued packets.
+    ///
+    /// All the packets are owned by the port, the ones which can't be enqueued are dropped.
+    #[inline]
+    pub fn enqueue(&mut self, pkts: &mut [RawMBufPtr]) -> usize {
+        unsafe { ffi::rte_sched_port_enqueue(self.0.as_ptr(), pkts.as_mut_ptr(), pkts.len() as u32) as usize }
+    }
+
+    /// Dequeue the scheduled packets, return them at the head of `out`.
+    #[inline]
+    pub fn dequeue<'a>(&mut self, out: &'a mut [MaybeUninit<RawMBufPtr>]) -> &'a mut [RawMBufPtr] {
+        unsafe {
+            let n = ffi::rte_sched_port_dequeue(self.0.as_ptr(), out.as_mut_ptr() as *mut _, out.len() as u32);
+
+            slice::from_raw_parts_mut(out.as_mut_ptr() as *mut _, n as usize)
+        }
+    }
+
+    /// Read the statistics of the subport and the oversubscription status of the best-effort traffic class.
+    ///
+    /// The counters are reset after being read.
+    pub fn subport_stats(&mut self, subport_id: u32) -> Result<(SubportStats, bool)> {
+        let mut stats = SubportStats::default();
+        let mut tc_ov = [0; TRAFFIC_CLASSES_PER_PIPE];
+
+        let ret =
+            unsafe { ffi::rte_sched_subport_read_stats(self.0.as_ptr(), subport_id, &mut stats, tc_ov.as_mut_ptr()) };
+
+        if ret == 0 {
+            Ok((stats, tc_ov[0] != 0))
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+
+    /// Read the statistics and the current length of the queue.
+    ///
+    /// The counters are reset after being read.
+    pub fn queue_stats(&mut self, queue_id: u32) -> Result<(QueueStats, u16)> {
+        let mut stats = QueueStats::default();
+        let mut qlen = 0;
+
+        let ret = unsafe { ffi::rte_sched_queue_read_stats(self.0.as_ptr(), queue_id, &mut stats, &mut qlen) };
+
+        if ret == 0 {
+            Ok((stats, qlen))
+        } else {
+            Err(OsError(-ret).into())
+        }
+    }
+}
+
+/// Read the color written into the mbuf by the classification stage.
+#[inline]
+pub fn pkt_read_color(m: &MBuf) -> Color {
+    match unsafe { ffi::rte_sched_port_pkt_read_color(m.as_raw()) } {
+        ffi::rte_color::RTE_COLOR_GREEN => Color::Green,
+        ffi::rte_color::RTE_COLOR_YELLOW => Color::Yellow,
+        _ => Color::Red,
+    }
+}
@@ -0,0 +1,212 @@
+//!
+//! Soft NIC PMD
+//!
+//! The Soft NIC is a virtual device running the packet framework pipelines described by a firmware script,
+//! which connects to the physical ports and provides the hierarchical QoS and the programmable pipelines
+//! behind a regular ethdev port.
+//!
+//! The device is run by `run()` on its lcore, or on a service core if `service_core` is set.
+//!
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use dev;
+use errors::AsResult;
+use ethdev::PortId;
+use ffi;
+use memory::SocketId;
+use utils::AsCString;
+
+/// The name of the Soft NIC driver.
+pub const DRIVER_NAME: &str = "net_softnic";
+
+/// The configuration of a Soft NIC device.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SoftNicConfig {
+    /// The firmware script executed when the device is started.
+    pub firmware: PathBuf,
+    /// The TCP port of the management connection, which accepts the firmware CLI commands.
+    pub conn_port: Option<u16>,
+    /// The CPU running the device, which is used to allocate the pipelines.
+    pub cpu_id: Option<u32>,
+    /// Run the device on a service core.
+    pub service_core: bool,
+    /// The NUMA node of the device memory.
+    pub numa_node: Option<SocketId>,
+    /// The number of the traffic management queues.
+    pub tm_n_queues: Option<u32>,
+    /// The sizes of the traffic management queues of each traffic class.
+    pub tm_qsize: Option<[u32; 4]>,
+}
+
+impl SoftNicConfig {
+    /// The configuration running the firmware script.
+    pub fn new<P: AsRef<Path>>(firmware: P) -> Self {
+        SoftNicConfig {
+            firmware: firmware.as_ref().to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    /// The device arguments of the configuration.
+    pub fn to_args(&self) -> String {
+        let mut args = vec![format!("firmware={}", self.firmware.display())];
+
+        if let Some(conn_port) = self.conn_port {
+            args.push(format!("conn_port={}", conn_port));
+        }
+        if let Some(cpu_id) = self.cpu_id {
+            args.push(format!("cpu_id={}", cpu_id));
+        }
+        if self.service_core {
+            args.push("sc=on".to_owned());
+        }
+        if let Some(numa_node) = self.numa_node {
+            args.push(format!("numa_node={}", numa_node));
+        }
+        if let Some(n_queues) = self.tm_n_queues {
+            args.push(format!("tm_n_queues={}", n_queues));
+        }
+        if let Some(qsize) = self.tm_qsize {
+            args.extend(
+                qsize
+                    .iter()
+                    .enumerate()
+                    .map(|(i, size)| format!("tm_qsize{}={}", i, size)),
+            );
+        }
+
+        args.join(",")
+    }
+}
+
+/// The device a firmware link connects to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkDev {
+    /// The device name, e.g. the PCI address.
+    Name(String),
+    /// The ethdev port.
+    Port(PortId),
+}
+
+/// A firmware script of the Soft NIC, built from the CLI commands.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Firmware {
+    commands: Vec<String>,
+}
+
+impl Firmware {
+    pub fn new() -> Self {
+        Firmware::default()
+    }
+
+    /// Connect the link to a physical device.
+    pub fn link(&mut self, name: &str, dev: LinkDev) -> &mut Self {
+        self.command(match dev {
+            LinkDev::Name(dev_name) => format!("link {} dev {}", name, dev_name),
+            LinkDev::Port(port_id) => format!("link {} port {}", name, port_id),
+        })
+    }
+
+    /// Run the pipeline on the data plane thread.
+    pub fn enable_pipeline(&mut self, thread_id: u32, pipeline: &str) -> &mut Self {
+        self.command(format!("thread {} pipeline {} enable", thread_id, pipeline))
+    }
+
+    /// Append a CLI command, e.g. the pipeline and table definitions.
+    pub fn command<S: Into<String>>(&mut self, command: S) -> &mut Self {
+        self.commands.push(command.into());
+        self
+    }
+
+    /// Write the firmware script to the file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, self.to_string())?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Firmware {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for command in &self.commands {
+            writeln!(f, "{}", command)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Create a Soft NIC device named `name`, e.g. `net_softnic0`, and return its port.
+pub fn create(name: &str, conf: &SoftNicConfig) -> Result<PortId> {
+    dev::hotplug_add("vdev", name, &conf.to_args())?;
+
+    let devname = name.as_cstring();
+    let mut port_id = 0;
+
+    unsafe { ffi::rte_eth_dev_get_port_by_name(devname.as_ptr(), &mut port_id) }
+        .as_result()
+        .map(|_| PortId(port_id))
+}
+
+/// Remove the Soft NIC device.
+pub fn free(name: &str) -> Result<()> {
+    dev::hotplug_remove("vdev", name)
+}
+
+/// Run the pipelines of the Soft NIC device once, which should be called in a loop on its lcore.
+#[inline]
+pub fn run(port_id: PortId) -> Result<()> {
+    unsafe { ffi::rte_pmd_softnic_run(port_id.0) }.as_result().map(|_| ())
+}
+
+/// Process the pending requests of the management connection.
+pub fn manage(port_id: PortId) -> Result<()> {
+    unsafe { ffi::rte_pmd_softnic_manage(port_id.0) }
+        .as_result()
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        let mut conf = SoftNicConfig::new("/etc/softnic/firmware.cli");
+
+        assert_eq!(conf.to_args(), "firmware=/etc/softnic/firmware.cli");
+
+        conf.conn_port = Some(8086);
+        conf.cpu_id = Some(1);
+        conf.service_core = true;
+        conf.tm_qsize = Some([64, 64, 64, 128]);
+
+        assert_eq!(
+            conf.to_args(),
+            "firmware=/etc/softnic/firmware.cli,conn_port=8086,cpu_id=1,sc=on,\
+             tm_qsize0=64,tm_qsize1=64,tm_qsize2=64,tm_qsize3=128"
+        );
+    }
+
+    #[test]
+    fn test_firmware() {
+        let mut fw = Firmware::new();
+
+        fw.link("LINK0", LinkDev::Name("0000:02:00.0".to_owned()))
+            .link("LINK1", LinkDev::Port(PortId(1)))
+            .command("pipeline RX period 10 offset_port_id 0")
+            .enable_pipeline(1, "RX");
+
+        assert_eq!(
+            fw.to_string(),
+            "link LINK0 dev 0000:02:00.0\n\
+             link LINK1 port 1\n\
+             pipeline RX period 10 offset_port_id 0\n\
+             thread 1 pipeline RX enable\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//!
+//! RTE Stack.
+//!
+//! librte_stack provides an API for configuration and use of a bounded stack of
+//! pointers. Push and pop operations are MT-safe, allowing concurrent access,
+//! and the interface supports pushing and popping multiple pointers at a time.
+//!
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+
+use errors::AsResult;
+use ffi;
+use memory::SocketId;
+use mempool::{self, MemoryPool, MemoryPoolFlags};
+use utils::{AsCString, AsRaw};
+
+pub use ffi::{RTE_STACK_F_LF, RTE_STACK_MZ_PREFIX};
+
+lazy_static! {
+    pub static ref RTE_STACK_NAMESIZE: usize = ffi::RTE_MEMZONE_NAMESIZE as usize - RTE_STACK_MZ_PREFIX.len() + 1;
+}
+
+bitflags! {
+    pub struct StackFlags: u32 {
+        /// The stack uses lock-free variants of the push and pop functions.
+        const LOCK_FREE = RTE_STACK_F_LF;
+    }
+}
+
+/// The name of the mempool ops backed by a lock-based stack.
+pub const MEMPOOL_OPS: &str = "stack";
+
+/// The name of the mempool ops backed by a lock-free stack.
+pub const LF_MEMPOOL_OPS: &str = "lf_stack";
+
+pub type RawStack = ffi::rte_stack;
+pub type RawStackPtr = *mut ffi::rte_stack;
+
+/// A bounded stack of pointers to `T`.
+#[derive(Debug)]
+pub struct Stack<T> {
+    raw: NonNull<RawStack>,
+    phantom: PhantomData<*mut T>,
+}
+
+unsafe impl<T: Send> Send for Stack<T> {}
+unsafe impl<T: Send> Sync for Stack<T> {}
+
+impl<T> AsRaw for Stack<T> {
+    type Raw = RawStack;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        self.raw.as_ptr()
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        self.raw.as_ptr()
+    }
+}
+
+impl<T> Stack<T> {
+    /// Create a new stack named `name` in memory, which can hold `count` pointers.
+    pub fn create<S: AsRef<str>>(name: S, count: usize, socket_id: SocketId, flags: StackFlags) -> Result<Self> {
+        let name = name.as_cstring();
+
+        unsafe { ffi::rte_stack_create(name.as_ptr(), count as u32, socket_id, flags.bits) }
+            .as_result()
+            .map(|raw| Stack {
+                raw,
+                phantom: PhantomData,
+            })
+    }
+
+    /// Lookup a stack by its name.
+    pub fn lookup<S: AsRef<str>>(name: S) -> Result<Self> {
+        let name = name.as_cstring();
+
+        unsafe { ffi::rte_stack_lookup(name.as_ptr()) }
+            .as_result()
+            .map(|raw| Stack {
+                raw,
+                phantom: PhantomData,
+            })
+    }
+
+    /// Free all memory used by the stack.
+    pub fn free(self) {
+        unsafe { ffi::rte_stack_free(self.raw.as_ptr()) }
+    }
+
+    /// Push several objects on the stack, either all or none of them are pushed.
+    ///
+    /// Return the actual number of objects pushed (either 0 or `objs.len()`).
+    #[inline]
+    pub fn push(&self, objs: &[*mut T]) -> usize {
+        unsafe {
+            ffi::_rte_stack_push(
+                self.raw.as_ptr(),
+                objs.as_ptr() as *const *mut c_void,
+                objs.len() as u32,
+            ) as usize
+        }
+    }
+
+    /// Pop several objects from the stack, either all or none of them are popped.
+    ///
+    /// Return the actual number of objects popped (either 0 or `objs.len()`).
+    #[inline]
+    pub fn pop(&self, objs: &mut [*mut T]) -> usize {
+        unsafe {
+            ffi::_rte_stack_pop(
+                self.raw.as_ptr(),
+                objs.as_mut_ptr() as *mut *mut c_void,
+                objs.len() as u32,
+            ) as usize
+        }
+    }
+
+    /// Push one object on the stack.
+    #[inline]
+    pub fn push_one(&self, obj: *mut T) -> bool {
+        self.push(&[obj]) == 1
+    }
+
+    /// Pop one object from the stack.
+    #[inline]
+    pub fn pop_one(&self) -> Option<*mut T> {
+        let mut objs = [ptr::null_mut()];
+
+        if self.pop(&mut objs) == 1 {
+            Some(objs[0])
+        } else {
+            None
+        }
+    }
+
+    /// Return the number of used entries in the stack.
+    #[inline]
+    pub fn count(&self) -> usize {
+        unsafe { ffi::_rte_stack_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Return the number of free entries in the stack.
+    #[inline]
+    pub fn free_count(&self) -> usize {
+        unsafe { ffi::_rte_stack_free_count(self.raw.as_ptr()) as usize }
+    }
+
+    /// Test if the stack is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+}
+
+/// Create a mempool of `n` objects backed by a stack instead of a ring, which is populated with the default allocator.
+///
+/// The stack returns the most recently freed objects first, which are likely to be still in the CPU cache,
+/// and the lock-free stack doesn't suffer from the preemption of the lcores sharing the mempool.
+pub fn create_mempool<S, O>(
+    name: S,
+    n: u32,
+    cache_size: u32,
+    socket_id: SocketId,
+    flags: StackFlags,
+) -> Result<MemoryPool>
+where
+    S: AsRef<str>,
+{
+    let mut mp = mempool::create_empty::<S, O>(name, n, cache_size, 0, socket_id, MemoryPoolFlags::empty())?;
+    let ops = if flags.contains(StackFlags::LOCK_FREE) {
+        LF_MEMPOOL_OPS
+    } else {
+        MEMPOOL_OPS
+    };
+
+    let ret = mp.set_ops_byname(ops).and_then(|_| mp.populate_default());
+
+    if let Err(err) = ret {
+        unsafe { ffi::rte_mempool_free(mp.as_raw_mut()) };
+
+        return Err(err);
+    }
+
+    Ok(mp)
+}
@@ -0,0 +1,504 @@
+//!
+//! Datapath statistics
+//!
+//! The `Histogram` records the value distribution with the HdrHistogram-style log-linear buckets,
+//! each lcore records into its own counters without the atomic read-modify-write,
+//! and the counters are merged into a `Snapshot` on read.
+//!
+//! The `Timestamper` stamps the mbufs with the TSC in a dynamic field on RX,
+//! so the latency can be recorded at TX.
+//!
+use std::cmp;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Result;
+
+use common::rdtsc;
+use errors::AsResult;
+use ffi;
+use lcore;
+use mbuf::{MBuf, RawMBufPtr};
+use utils::AsRaw;
+
+/// The name of the mbuf dynamic field of the RX timestamp in TSC cycles.
+pub const LATENCY_TSC_DYNFIELD_NAME: &str = "rte_dynfield_latency_tsc";
+
+/// The maximum precision of the histogram in bits.
+pub const MAX_PRECISION: u32 = 14;
+
+/// The value distribution of an lcore.
+struct Counters {
+    counts: Box<[AtomicU64]>,
+    total: AtomicU64,
+    sum: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+}
+
+impl Counters {
+    fn new(buckets: usize) -> Self {
+        Counters {
+            counts: (0..buckets).map(|_| AtomicU64::new(0)).collect(),
+            total: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+        }
+    }
+
+    /// Record the value by the only writer of the counters.
+    #[inline]
+    fn record(&self, index: usize, value: u64, n: u64) {
+        let inc = |c: &AtomicU64, n: u64| c.store(c.load(Ordering::Relaxed).wrapping_add(n), Ordering::Relaxed);
+
+        inc(&self.counts[index], n);
+        inc(&self.total, n);
+        inc(&self.sum, value.wrapping_mul(n));
+
+        if value < self.min.load(Ordering::Relaxed) {
+            self.min.store(value, Ordering::Relaxed);
+        }
+        if value > self.max.load(Ordering::Relaxed) {
+            self.max.store(value, Ordering::Relaxed);
+        }
+    }
+
+    /// Record the value by any thread.
+    fn record_shared(&self, index: usize, value: u64, n: u64) {
+        self.counts[index].fetch_add(n, Ordering::Relaxed);
+        self.total.fetch_add(n, Ordering::Relaxed);
+        self.sum.fetch_add(value.wrapping_mul(n), Ordering::Relaxed);
+        self.min.fetch_min(value, Ordering::Relaxed);
+        self.max.fetch_max(value, Ordering::Relaxed);
+    }
+
+    fn merge_into(&self, snapshot: &mut Snapshot) {
+        for (count, c) in snapshot.counts.iter_mut().zip(self.counts.iter()) {
+            *count += c.load(Ordering::Relaxed);
+        }
+
+        snapshot.total += self.total.load(Ordering::Relaxed);
+        snapshot.sum = snapshot.sum.wrapping_add(self.sum.load(Ordering::Relaxed));
+        snapshot.min = cmp::min(snapshot.min, self.min.load(Ordering::Relaxed));
+        snapshot.max = cmp::max(snapshot.max, self.max.load(Ordering::Relaxed));
+    }
+
+    fn reset(&self) {
+        for c in self.counts.iter() {
+            c.store(0, Ordering::Relaxed);
+        }
+
+        self.total.store(0, Ordering::Relaxed);
+        self.sum.store(0, Ordering::Relaxed);
+        self.min.store(u64::MAX, Ordering::Relaxed);
+        self.max.store(0, Ordering::Relaxed);
+    }
+}
+
+/// A histogram of the `u64` values with the per-lcore counters.
+///
+/// The values are bucketed with `precision` significant bits,
+/// so the relative error of the reported values is less than `2^(1 - precision)`.
+///
+/// The values recorded on the EAL lcores are counted without contention,
+/// while the values recorded on the other threads are counted with the atomic operations.
+pub struct Histogram {
+    precision: u32,
+    lcores: Vec<Counters>,
+    shared: Counters,
+}
+
+impl fmt::Debug for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Histogram")
+            .field("precision", &self.precision)
+            .field("lcores", &self.lcores.len())
+            .finish()
+    }
+}
+
+impl Histogram {
+    /// Create a histogram with `precision` significant bits for the enabled lcores.
+    pub fn new(precision: u32) -> Self {
+        Self::with_lcores(precision, lcore::count())
+    }
+
+    /// Create a histogram with `precision` significant bits for `lcores` lcores indexed by `lcore::index()`.
+    pub fn with_lcores(precision: u32, lcores: usize) -> Self {
+        assert!(
+            precision > 0 && precision <= MAX_PRECISION,
+            "invalid histogram precision {}",
+            precision
+        );
+
+        let buckets = buckets(precision);
+
+        Histogram {
+            precision,
+            lcores: (0..lcores).map(|_| Counters::new(buckets)).collect(),
+            shared: Counters::new(buckets),
+        }
+    }
+
+    /// The precision of the histogram in bits.
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Record a value on the current lcore.
+    #[inline]
+    pub fn record(&self, value: u64) {
+        self.record_n(value, 1)
+    }
+
+    /// Record a value `n` times on the current lcore.
+    #[inline]
+    pub fn record_n(&self, value: u64, n: u64) {
+        let index = bucket_index(value, self.precision);
+
+        match lcore::index(ffi::LCORE_ID_ANY).and_then(|idx| self.lcores.get(idx)) {
+            Some(counters) => counters.record(index, value, n),
+            None => self.shared.record_shared(index, value, n),
+        }
+    }
+
+    /// Merge the counters of all the lcores.
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(self.precision);
+
+        for counters in self.lcores.iter().chain(Some(&self.shared)) {
+            counters.merge_into(&mut snapshot);
+        }
+
+        snapshot
+    }
+
+    /// Get the counters of an lcore indexed by `lcore::index()`.
+    pub fn lcore_snapshot(&self, index: usize) -> Option<Snapshot> {
+        self.lcores.get(index).map(|counters| {
+            let mut snapshot = Snapshot::new(self.precision);
+
+            counters.merge_into(&mut snapshot);
+
+            snapshot
+        })
+    }
+
+    /// Clear the counters.
+    ///
+    /// The values recorded concurrently may be partially lost.
+    pub fn reset(&self) {
+        for counters in self.lcores.iter().chain(Some(&self.shared)) {
+            counters.reset()
+        }
+    }
+}
+
+/// The merged counters of a `Histogram`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    precision: u32,
+    counts: Vec<u64>,
+    total: u64,
+    sum: u64,
+    min: u64,
+    max: u64,
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("count", &self.count())
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .field("mean", &self.mean())
+            .field("p50", &self.percentile(50.0))
+            .field("p99", &self.percentile(99.0))
+            .finish()
+    }
+}
+
+impl Snapshot {
+    /// Create an empty snapshot with `precision` significant bits.
+    pub fn new(precision: u32) -> Self {
+        Snapshot {
+            precision,
+            counts: vec![0; buckets(precision)],
+            total: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    /// The number of the recorded values.
+    pub fn count(&self) -> u64 {
+        self.total
+    }
+
+    /// Test if no value was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// The minimum recorded value.
+    pub fn min(&self) -> Option<u64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// The maximum recorded value.
+    pub fn max(&self) -> Option<u64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// The mean of the recorded values.
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.sum as f64 / self.total as f64)
+        }
+    }
+
+    /// The value at the percentile, which is the highest value of the bucket, limited by the maximum value.
+    pub fn percentile(&self, percentile: f64) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let percentile = percentile.clamp(0.0, 100.0);
+        let rank = cmp::max((percentile / 100.0 * self.total as f64).ceil() as u64, 1);
+        let mut seen = 0;
+
+        for (index, &count) in self.counts.iter().enumerate() {
+            seen += count;
+
+            if seen >= rank {
+                return Some(cmp::min(bucket_highest(index, self.precision), self.max));
+            }
+        }
+
+        Some(self.max)
+    }
+
+    /// An iterator over the non-empty buckets, yields the lowest and highest value of the bucket and its count.
+    pub fn buckets(&self) -> impl Iterator<Item = (u64, u64, u64)> + '_ {
+        let precision = self.precision;
+
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(move |(index, &count)| (bucket_lowest(index, precision), bucket_highest(index, precision), count))
+    }
+
+    /// Merge another snapshot with the same precision.
+    pub fn merge(&mut self, other: &Snapshot) {
+        assert_eq!(
+            self.precision, other.precision,
+            "merge histograms with different precisions"
+        );
+
+        for (count, &other) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other;
+        }
+
+        self.total += other.total;
+        self.sum = self.sum.wrapping_add(other.sum);
+        self.min = cmp::min(self.min, other.min);
+        self.max = cmp::max(self.max, other.max);
+    }
+}
+
+/// The number of the buckets of the precision.
+fn buckets(precision: u32) -> usize {
+    bucket_index(u64::MAX, precision) + 1
+}
+
+/// The values less than `2^precision` have their own buckets,
+/// and the larger values are bucketed by the exponent and the `precision` significant bits.
+#[inline]
+fn bucket_index(value: u64, precision: u32) -> usize {
+    let sub_buckets = 1u64 << precision;
+
+    if value < sub_buckets {
+        value as usize
+    } else {
+        let shift = 64 - value.leading_zeros() - precision;
+
+        (u64::from(shift) * (sub_buckets / 2) + (value >> shift)) as usize
+    }
+}
+
+fn bucket_lowest(index: usize, precision: u32) -> u64 {
+    let sub_buckets = 1usize << precision;
+
+    if index < sub_buckets {
+        index as u64
+    } else {
+        let shift = index / (sub_buckets / 2) - 1;
+
+        ((index - shift * (sub_buckets / 2)) as u64) << shift
+    }
+}
+
+fn bucket_highest(index: usize, precision: u32) -> u64 {
+    if index + 1 < buckets(precision) {
+        bucket_lowest(index + 1, precision) - 1
+    } else {
+        u64::MAX
+    }
+}
+
+/// Stamp the mbufs with the TSC on RX, and compute the latency at TX.
+#[derive(Clone, Copy, Debug)]
+pub struct Timestamper {
+    offset: usize,
+}
+
+impl Timestamper {
+    /// Register the dynamic field of the timestamp, or lookup it if it was registered.
+    pub fn register() -> Result<Self> {
+        let mut params = ffi::rte_mbuf_dynfield {
+            name: [0; 64],
+            size: 8,
+            align: 8,
+            flags: 0,
+        };
+
+        for (dst, &src) in params.name.iter_mut().zip(LATENCY_TSC_DYNFIELD_NAME.as_bytes()) {
+            *dst = src as _;
+        }
+
+        let offset = unsafe { ffi::rte_mbuf_dynfield_register(&params) }.as_result()?;
+
+        Ok(Timestamper {
+            offset: offset as usize,
+        })
+    }
+
+    /// The offset of the dynamic field in the mbuf.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn field(&self, m: RawMBufPtr) -> *mut u64 {
+        unsafe { (m as *mut u8).add(self.offset) as *mut u64 }
+    }
+
+    /// Stamp the mbuf with the TSC.
+    #[inline]
+    pub fn stamp(&self, m: &mut MBuf, tsc: u64) {
+        unsafe { *self.field(m.as_raw_mut()) = tsc }
+    }
+
+    /// Stamp a burst of the received mbufs with the current TSC.
+    #[inline]
+    pub fn stamp_burst(&self, pkts: &[RawMBufPtr]) {
+        let now = rdtsc();
+
+        for &m in pkts {
+            unsafe { *self.field(m) = now }
+        }
+    }
+
+    /// Get the timestamp of the mbuf.
+    #[inline]
+    pub fn timestamp(&self, m: &MBuf) -> u64 {
+        unsafe { *self.field(m.as_raw_mut()) }
+    }
+
+    /// The cycles elapsed since the mbuf was stamped.
+    #[inline]
+    pub fn elapsed(&self, m: &MBuf, now: u64) -> u64 {
+        now.saturating_sub(self.timestamp(m))
+    }
+
+    /// Record the latency of a burst of the mbufs in TSC cycles before they are transmitted.
+    #[inline]
+    pub fn record_burst(&self, histogram: &Histogram, pkts: &[RawMBufPtr]) {
+        let now = rdtsc();
+
+        for &m in pkts {
+            histogram.record(now.saturating_sub(unsafe { *self.field(m) }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_index() {
+        for precision in 1..MAX_PRECISION + 1 {
+            let mut last = 0;
+
+            for &value in &[0, 1, 2, 3, 100, 1000, 12345, 1 << 20, u64::MAX / 3, u64::MAX] {
+                let index = bucket_index(value, precision);
+
+                assert!(index >= last);
+                assert!(index < buckets(precision));
+                assert!(bucket_lowest(index, precision) <= value);
+                assert!(bucket_highest(index, precision) >= value);
+
+                last = index;
+            }
+        }
+
+        assert_eq!(bucket_index(127, 7), 127);
+        assert_eq!(bucket_index(128, 7), 128);
+        assert_eq!(bucket_index(129, 7), 128);
+        assert_eq!(bucket_index(130, 7), 129);
+        assert_eq!(bucket_lowest(129, 7), 130);
+        assert_eq!(bucket_highest(128, 7), 129);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let h = Histogram::with_lcores(7, 0);
+
+        assert!(h.snapshot().is_empty());
+        assert_eq!(h.snapshot().percentile(50.0), None);
+
+        for v in 1..1001 {
+            h.record(v);
+        }
+
+        let s = h.snapshot();
+
+        assert_eq!(s.count(), 1000);
+        assert_eq!(s.min(), Some(1));
+        assert_eq!(s.max(), Some(1000));
+        assert_eq!(s.mean(), Some(500.5));
+        assert_eq!(s.percentile(100.0), Some(1000));
+
+        let p50 = s.percentile(50.0).unwrap();
+        let p99 = s.percentile(99.0).unwrap();
+
+        assert!(p50 >= 500 && p50 < 500 + 500 / 64);
+        assert!(p99 >= 990 && p99 < 990 + 990 / 64);
+        assert_eq!(s.buckets().map(|(_, _, count)| count).sum::<u64>(), 1000);
+
+        let mut merged = s.clone();
+
+        merged.merge(&s);
+
+        assert_eq!(merged.count(), 2000);
+        assert_eq!(merged.percentile(50.0), Some(p50));
+
+        h.reset();
+
+        assert!(h.snapshot().is_empty());
+    }
+}
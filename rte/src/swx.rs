@@ -0,0 +1,352 @@
+//!
+//! RTE SWX Pipeline
+//!
+//! The software pipeline is described by a P4-like specification file,
+//! which declares the headers, the metadata, the actions, the tables and the instructions,
+//! and is translated to the pipeline at runtime.
+//!
+//! The pipeline is run by a data plane lcore, while its tables are updated by the control plane,
+//! the table updates are scheduled and then committed atomically.
+//!
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::ptr::{self, NonNull};
+
+use anyhow::Result;
+use cfile;
+use libc;
+
+use errors::{AsResult, ErrorKind};
+use ffi;
+use memory::SocketId;
+use utils::AsCString;
+
+pub type RawPipeline = ffi::rte_swx_pipeline;
+pub type RawCtlPipeline = ffi::rte_swx_ctl_pipeline;
+
+/// The name of the Ethernet device port type.
+pub const PORT_TYPE_ETHDEV: &str = "ethdev";
+
+/// The name of the exact match table type.
+pub const TABLE_TYPE_EXACT: &str = "exact";
+
+/// The statistics counters of an input port.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PortInStats {
+    /// Number of packets.
+    pub packets: u64,
+    /// Number of bytes.
+    pub bytes: u64,
+    /// Number of empty polls.
+    pub empty: u64,
+}
+
+/// The statistics counters of an output port.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PortOutStats {
+    /// Number of packets.
+    pub packets: u64,
+    /// Number of bytes.
+    pub bytes: u64,
+}
+
+/// A software pipeline built from a specification file.
+pub struct Pipeline {
+    raw: NonNull<RawPipeline>,
+    ctl: Option<NonNull<RawCtlPipeline>>,
+}
+
+unsafe impl Send for Pipeline {}
+
+impl Drop for Pipeline {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(ctl) = self.ctl.take() {
+                ffi::rte_swx_ctl_pipeline_free(ctl.as_ptr());
+            }
+
+            ffi::rte_swx_pipeline_free(self.raw.as_ptr());
+        }
+    }
+}
+
+impl Pipeline {
+    /// Create a pipeline on the NUMA node, with the `ethdev` port type and the `exact` table type registered.
+    pub fn new(socket_id: SocketId) -> Result<Self> {
+        let mut raw = ptr::null_mut();
+
+        unsafe { ffi::rte_swx_pipeline_config(&mut raw, socket_id) }.as_result()?;
+
+        let p = Pipeline {
+            raw: NonNull::new(raw).ok_or(ErrorKind::InvalidPipelineSpec("pipeline not created".to_owned()))?,
+            ctl: None,
+        };
+
+        let ethdev = PORT_TYPE_ETHDEV.as_cstring();
+        let exact = TABLE_TYPE_EXACT.as_cstring();
+
+        unsafe {
+            ffi::rte_swx_pipeline_port_in_type_register(
+                raw,
+                ethdev.as_ptr(),
+                ptr::addr_of_mut!(ffi::rte_swx_port_ethdev_reader_ops),
+            )
+            .as_result()?;
+            ffi::rte_swx_pipeline_port_out_type_register(
+                raw,
+                ethdev.as_ptr(),
+                ptr::addr_of_mut!(ffi::rte_swx_port_ethdev_writer_ops),
+            )
+            .as_result()?;
+            ffi::rte_swx_pipeline_table_type_register(
+                raw,
+                exact.as_ptr(),
+                ffi::rte_swx_table_match_type::RTE_SWX_TABLE_MATCH_EXACT,
+                ptr::addr_of_mut!(ffi::rte_swx_table_exact_match_ops),
+            )
+            .as_result()?;
+        }
+
+        Ok(p)
+    }
+
+    pub fn as_raw(&self) -> *mut RawPipeline {
+        self.raw.as_ptr()
+    }
+
+    /// Configure the input port to receive the packets from the queue of an Ethernet device.
+    pub fn port_in_ethdev(&mut self, port_id: u32, dev_name: &str, queue_id: u16, burst_size: u32) -> Result<()> {
+        let ethdev = PORT_TYPE_ETHDEV.as_cstring();
+        let dev_name = dev_name.as_cstring();
+        let mut params = ffi::rte_swx_port_ethdev_reader_params {
+            dev_name: dev_name.as_ptr(),
+            queue_id,
+            burst_size,
+        };
+
+        unsafe {
+            ffi::rte_swx_pipeline_port_in_config(
+                self.as_raw(),
+                port_id,
+                ethdev.as_ptr(),
+                &mut params as *mut _ as *mut c_void,
+            )
+        }
+        .as_result()
+        .map(|_| ())
+    }
+
+    /// Configure the output port to transmit the packets to the queue of an Ethernet device.
+    pub fn port_out_ethdev(&mut self, port_id: u32, dev_name: &str, queue_id: u16, burst_size: u32) -> Result<()> {
+        let ethdev = PORT_TYPE_ETHDEV.as_cstring();
+        let dev_name = dev_name.as_cstring();
+        let mut params = ffi::rte_swx_port_ethdev_writer_params {
+            dev_name: dev_name.as_ptr(),
+            queue_id,
+            burst_size,
+        };
+
+        unsafe {
+            ffi::rte_swx_pipeline_port_out_config(
+                self.as_raw(),
+                port_id,
+                ethdev.as_ptr(),
+                &mut params as *mut _ as *mut c_void,
+            )
+        }
+        .as_result()
+        .map(|_| ())
+    }
+
+    /// Build the pipeline from the specification file, the ports must be configured before.
+    ///
+    /// Once built, the pipeline configuration is no longer allowed to change,
+    /// only the table entries can be updated.
+    pub fn build_from_spec<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        if self.ctl.is_some() {
+            return Err(ErrorKind::InvalidPipelineSpec("pipeline already built".to_owned()).into());
+        }
+
+        let path = path.as_ref();
+        let mut f = cfile::open(path, "r")?;
+        let mut err_line = 0;
+        let mut err_msg = ptr::null();
+
+        let ret = unsafe {
+            ffi::rte_swx_pipeline_build_from_spec(
+                self.as_raw(),
+                &mut **f as *mut _ as *mut _,
+                &mut err_line,
+                &mut err_msg,
+            )
+        };
+
+        if ret != 0 {
+            let msg = if err_msg.is_null() {
+                "unknown error".into()
+            } else {
+                unsafe { CStr::from_ptr(err_msg) }.to_string_lossy()
+            };
+
+            return Err(ErrorKind::InvalidPipelineSpec(format!("{}:{}: {}", path.display(), err_line, msg)).into());
+        }
+
+        unsafe { ffi::rte_swx_pipeline_build(self.as_raw()) }.as_result()?;
+
+        self.ctl = Some(unsafe { ffi::rte_swx_ctl_pipeline_create(self.as_raw()) }.as_result()?);
+
+        Ok(())
+    }
+
+    /// Test if the pipeline has been built.
+    pub fn is_built(&self) -> bool {
+        self.ctl.is_some()
+    }
+
+    /// Run the pipeline for the number of instructions.
+    #[inline]
+    pub fn run(&self, n_instructions: u32) {
+        unsafe { ffi::rte_swx_pipeline_run(self.as_raw(), n_instructions) }
+    }
+
+    /// Flush the packets buffered by the output ports.
+    #[inline]
+    pub fn flush(&self) {
+        unsafe { ffi::rte_swx_pipeline_flush(self.as_raw()) }
+    }
+
+    /// Read the statistics counters of an input port.
+    pub fn port_in_stats(&self, port_id: u32) -> Result<PortInStats> {
+        let mut stats = ffi::rte_swx_port_in_stats::default();
+
+        unsafe { ffi::rte_swx_ctl_pipeline_port_in_stats_read(self.as_raw(), port_id, &mut stats) }.as_result()?;
+
+        Ok(PortInStats {
+            packets: stats.n_pkts,
+            bytes: stats.n_bytes,
+            empty: stats.n_empty,
+        })
+    }
+
+    /// Read the statistics counters of an output port.
+    pub fn port_out_stats(&self, port_id: u32) -> Result<PortOutStats> {
+        let mut stats = ffi::rte_swx_port_out_stats::default();
+
+        unsafe { ffi::rte_swx_ctl_pipeline_port_out_stats_read(self.as_raw(), port_id, &mut stats) }.as_result()?;
+
+        Ok(PortOutStats {
+            packets: stats.n_pkts,
+            bytes: stats.n_bytes,
+        })
+    }
+
+    /// Schedule a table entry, in the syntax of the pipeline CLI, to be added or updated by the next commit.
+    ///
+    /// e.g. `match 0x0a000001 action fwd port 1`
+    pub fn table_entry_add(&mut self, table: &str, entry: &str) -> Result<()> {
+        self.table_update(table, entry, ffi::rte_swx_ctl_pipeline_table_entry_add)
+    }
+
+    /// Schedule the default entry of the table to be set by the next commit.
+    ///
+    /// e.g. `action drop`
+    pub fn table_default_entry_add(&mut self, table: &str, entry: &str) -> Result<()> {
+        self.table_update(table, entry, ffi::rte_swx_ctl_pipeline_table_default_entry_add)
+    }
+
+    /// Schedule a table entry to be deleted by the next commit, only the key of the entry is used.
+    pub fn table_entry_delete(&mut self, table: &str, entry: &str) -> Result<()> {
+        self.table_update(table, entry, ffi::rte_swx_ctl_pipeline_table_entry_delete)
+    }
+
+    /// Apply all the scheduled table updates.
+    ///
+    /// All the updates are discarded on failure if `abort_on_fail` is set,
+    /// otherwise they are kept scheduled for the next commit.
+    pub fn commit(&mut self, abort_on_fail: bool) -> Result<()> {
+        unsafe { ffi::rte_swx_ctl_pipeline_commit(self.ctl()?, abort_on_fail as i32) }
+            .as_result()
+            .map(|_| ())
+    }
+
+    /// Discard all the scheduled table updates.
+    pub fn abort(&mut self) {
+        if let Some(ctl) = self.ctl {
+            unsafe { ffi::rte_swx_ctl_pipeline_abort(ctl.as_ptr()) }
+        }
+    }
+
+    /// Dump the entries of the table.
+    pub fn table_dump<S: AsRawFd>(&self, s: &S, table: &str) -> Result<()> {
+        let ctl = self.ctl()?;
+        let table = table.as_cstring();
+        let mut f = cfile::fdopen(s, "w")?;
+
+        unsafe { ffi::rte_swx_ctl_pipeline_table_fprintf(&mut **f as *mut _ as *mut _, ctl, table.as_ptr()) }
+            .as_result()
+            .map(|_| ())
+    }
+
+    fn ctl(&self) -> Result<*mut RawCtlPipeline> {
+        self.ctl
+            .map(NonNull::as_ptr)
+            .ok_or_else(|| ErrorKind::InvalidPipelineSpec("pipeline not built".to_owned()).into())
+    }
+
+    fn table_update(
+        &mut self,
+        table: &str,
+        entry: &str,
+        update: unsafe extern "C" fn(*mut RawCtlPipeline, *const c_char, *mut ffi::rte_swx_table_entry) -> c_int,
+    ) -> Result<()> {
+        let ctl = self.ctl()?;
+        let table_name = table.as_cstring();
+        let s = entry.as_cstring();
+        let raw = unsafe { table_entry_read(ctl, table_name.as_ptr(), s.as_ptr()) };
+
+        if raw.is_null() {
+            return Err(ErrorKind::InvalidTableEntry(format!("{}: {}", table, entry)).into());
+        }
+
+        // the entry is duplicated by the pipeline.
+        let ret = unsafe { update(ctl, table_name.as_ptr(), raw) };
+
+        unsafe { table_entry_free(raw) };
+
+        ret.as_result().map(|_| ())
+    }
+}
+
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+unsafe fn table_entry_read(
+    ctl: *mut RawCtlPipeline,
+    table_name: *const c_char,
+    s: *const c_char,
+) -> *mut ffi::rte_swx_table_entry {
+    ffi::rte_swx_ctl_pipeline_table_entry_read(ctl, table_name, s)
+}
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+unsafe fn table_entry_read(
+    ctl: *mut RawCtlPipeline,
+    table_name: *const c_char,
+    s: *const c_char,
+) -> *mut ffi::rte_swx_table_entry {
+    ffi::rte_swx_ctl_pipeline_table_entry_read(ctl, table_name, s, ptr::null_mut())
+}
+
+// the table entry is allocated by libc, and freed by the pipeline CLI before DPDK 21.05.
+#[cfg(not(any(feature = "dpdk-21_11", feature = "dpdk-22_11")))]
+unsafe fn table_entry_free(entry: *mut ffi::rte_swx_table_entry) {
+    libc::free((*entry).key as *mut c_void);
+    libc::free((*entry).key_mask as *mut c_void);
+    libc::free((*entry).action_data as *mut c_void);
+    libc::free(entry as *mut c_void);
+}
+
+#[cfg(any(feature = "dpdk-21_11", feature = "dpdk-22_11"))]
+unsafe fn table_entry_free(entry: *mut ffi::rte_swx_table_entry) {
+    ffi::rte_swx_table_entry_free(entry)
+}
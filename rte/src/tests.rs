@@ -1,7 +1,9 @@
 extern crate num_cpus;
 extern crate pretty_env_logger;
 
+use std::ffi::CStr;
 use std::os::raw::c_void;
+use std::ptr;
 use std::sync::{Arc, Mutex};
 
 use cfile;
@@ -11,12 +13,15 @@ use ffi;
 
 use common::memory::SOCKET_ID_ANY;
 use eal::{self, ProcType};
+use hash::{DpdkHashMap, Entry};
 use launch;
 use lcore;
-use mbuf;
+use mbuf::{self, MBufPool};
 use memory::AsMutRef;
 use mempool::{self, MemoryPool, MemoryPoolFlags};
-use utils::AsRaw;
+use spinlock::{RecursiveSpinLock, SpinLock};
+use stack::{self, StackFlags};
+use utils::{AsRaw, IntoRaw};
 
 #[test]
 fn test_eal() {
@@ -47,7 +52,15 @@ fn test_eal() {
 
     test_mempool();
 
+    test_typed_mempool();
+
+    test_stack_mempool();
+
     test_mbuf();
+
+    test_spinlock();
+
+    test_hash_map();
 }
 
 // fn test_config() {
@@ -90,10 +103,9 @@ fn test_lcore() {
 }
 
 fn test_launch() {
-    fn slave_main(mutex: Option<Arc<Mutex<usize>>>) -> i32 {
+    fn slave_main(mutex: Arc<Mutex<usize>>) -> i32 {
         debug!("lcore {} is running", lcore::current().unwrap());
 
-        let mutex = mutex.unwrap();
         let mut data = mutex.lock().unwrap();
 
         *data += 1;
@@ -115,7 +127,9 @@ fn test_launch() {
 
         debug!("remote launch lcore {}", slave_id);
 
-        launch::remote_launch(slave_main, Some(mutex.clone()), slave_id).unwrap();
+        let mutex = mutex.clone();
+
+        launch::remote_launch(move || slave_main(mutex), slave_id).unwrap();
 
         assert_eq!(slave_id.state(), launch::State::Running);
     }
@@ -139,7 +153,9 @@ fn test_launch() {
 
         debug!("remote launch lcores");
 
-        launch::mp_remote_launch(slave_main, Some(mutex.clone()), true).unwrap();
+        let mutex = mutex.clone();
+
+        launch::mp_remote_launch(move || slave_main(mutex), true).unwrap();
     }
 
     launch::mp_wait_lcore();
@@ -151,6 +167,28 @@ fn test_launch() {
 
         assert_eq!(*data, num_cpus::get());
     }
+
+    let mut counter = 0;
+
+    let results = launch::scope(|s| {
+        s.spawn_on(slave_id, || {
+            counter += 1;
+
+            counter
+        })
+        .unwrap();
+    });
+
+    assert_eq!(counter, 1);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_ref().unwrap(), &1);
+
+    let results = launch::scope(|s| {
+        s.spawn_on(slave_id, || panic!("worker panicked")).unwrap();
+    });
+
+    assert!(results[0].is_err());
+    assert_eq!(slave_id.state(), launch::State::Wait);
 }
 
 fn test_mempool() {
@@ -221,13 +259,103 @@ fn test_mempool() {
     }
 }
 
+fn test_typed_mempool() {
+    let p =
+        mempool::MemPool::<(u64, String)>::create("test_typed", 8, 0, SOCKET_ID_ANY, MemoryPoolFlags::empty()).unwrap();
+
+    assert_eq!(p.cache_size(), 0);
+    assert_eq!(p.avail_count(), 8);
+
+    {
+        let mut obj = p.get((1, String::from("one"))).unwrap();
+
+        obj.0 += 1;
+
+        assert_eq!(*obj, (2, String::from("one")));
+        assert_eq!(p.in_use_count(), 1);
+
+        assert_eq!(obj.into_inner(), (2, String::from("one")));
+        assert_eq!(p.in_use_count(), 0);
+    }
+
+    {
+        let objs = p.get_bulk((0..4).map(|i| (i, i.to_string()))).unwrap();
+
+        assert_eq!(objs.len(), 4);
+        assert_eq!(objs[3].1, "3");
+        assert_eq!(p.in_use_count(), 4);
+
+        // either all or none of the objects are retrieved
+        assert!(p.get_bulk((0..5).map(|i| (i, i.to_string()))).is_err());
+        assert_eq!(p.in_use_count(), 4);
+
+        p.put_bulk(objs);
+
+        assert_eq!(p.in_use_count(), 0);
+    }
+
+    {
+        // the values of an iterator without an exact size
+        let values = (0..8).map(|i| (i, i.to_string())).filter(|_| true).take(3);
+
+        let objs = p.get_bulk(values).unwrap();
+
+        assert_eq!(objs.len(), 3);
+        assert_eq!(p.in_use_count(), 3);
+
+        drop(objs);
+
+        assert_eq!(p.in_use_count(), 0);
+    }
+}
+
+fn test_stack_mempool() {
+    for &(name, flags, ops) in &[
+        ("test_stack", StackFlags::empty(), stack::MEMPOOL_OPS),
+        ("test_lf_stack", StackFlags::LOCK_FREE, stack::LF_MEMPOOL_OPS),
+    ] {
+        let p = stack::create_mempool::<_, u64>(name, 16, 0, SOCKET_ID_ANY, flags).unwrap();
+
+        let ops_name = unsafe {
+            let table = ptr::addr_of!(ffi::rte_mempool_ops_table);
+
+            CStr::from_ptr((*table).ops[p.ops_index as usize].name.as_ptr())
+        };
+
+        assert_eq!(ops_name.to_str().unwrap(), ops);
+        assert_eq!(p.avail_count(), 16);
+
+        let mut objs = [ptr::null_mut(); 4];
+
+        assert_eq!(
+            unsafe { ffi::_rte_mempool_get_bulk(p.as_raw_mut(), objs.as_mut_ptr(), 4) },
+            0
+        );
+        assert_eq!(p.in_use_count(), 4);
+
+        // the stack returns the most recently freed object first
+        let mut obj = ptr::null_mut();
+
+        unsafe { ffi::_rte_mempool_put(p.as_raw_mut(), objs[2]) };
+
+        assert_eq!(unsafe { ffi::_rte_mempool_get(p.as_raw_mut(), &mut obj) }, 0);
+        assert_eq!(obj, objs[2]);
+
+        unsafe { ffi::_rte_mempool_put_bulk(p.as_raw_mut(), objs.as_ptr(), 4) };
+
+        assert_eq!(p.in_use_count(), 0);
+
+        unsafe { ffi::rte_mempool_free(p.as_raw_mut()) };
+    }
+}
+
 fn test_mbuf() {
     const NB_MBUF: u32 = 1024;
     const CACHE_SIZE: u32 = 32;
     const PRIV_SIZE: u32 = 0;
     const MBUF_SIZE: u32 = 128;
 
-    let p = mbuf::pool_create(
+    let mut p = mbuf::pool_create(
         "mbuf_pool",
         NB_MBUF,
         CACHE_SIZE,
@@ -250,5 +378,174 @@ fn test_mbuf() {
     assert!(p.is_full());
     assert!(!p.is_empty());
 
+    {
+        let mut m = p.alloc().unwrap();
+
+        assert_eq!(p.in_use_count(), 1);
+        assert!(m.data().is_empty());
+
+        mbuf::copy_into_mbuf(&mut m, b"hello").unwrap();
+        m.data_mut().unwrap()[0] = b'H';
+
+        assert_eq!(m.data(), b"Hello");
+        assert_eq!(m.pkt_len(), 5);
+
+        let mut tail = p.alloc().unwrap();
+
+        mbuf::copy_into_mbuf(&mut tail, b" world").unwrap();
+        m.chain(tail).unwrap();
+
+        assert_eq!(m.nb_segs(), 2);
+        assert_eq!(m.pkt_len(), 11);
+        assert_eq!(m.data_len(), 5);
+        assert_eq!(m.segments().collect::<Vec<_>>(), vec![&b"Hello"[..], &b" world"[..]]);
+        assert_eq!(m.lastseg().data(), b" world");
+
+        m.linearize().unwrap();
+
+        assert_eq!(m.nb_segs(), 1);
+        assert_eq!(m.data(), b"Hello world");
+        assert_eq!(p.in_use_count(), 1);
+
+        m.pktmbuf_refcnt_update(1);
+
+        assert!(m.is_shared());
+        assert!(!m.is_writable());
+        assert!(m.data_mut().is_none());
+        assert!(m.segments_mut().is_none());
+        assert!(m.append(1).is_err());
+        assert_eq!(m.refcnt_read(), 2);
+
+        m.pktmbuf_refcnt_update(-1);
+
+        assert!(!m.is_shared());
+        assert!(m.is_writable());
+        assert!(m.data_mut().is_some());
+
+        let cloned = p.clone(&m).unwrap();
+
+        assert!(cloned.is_indirect());
+        assert_eq!(
+            mbuf::MBuf::from_indirect(&cloned).map(|md| md.as_raw()),
+            Some(m.as_raw())
+        );
+        assert!(mbuf::MBuf::from_indirect(&m).is_none());
+        assert!(!cloned.is_writable());
+        assert!(m.is_direct());
+        assert!(m.is_shared());
+        assert_eq!(cloned.data(), b"Hello world");
+
+        drop(cloned);
+
+        let copied = p.copy(&m, 6, None).unwrap();
+
+        assert!(copied.is_direct());
+        assert!(!m.is_shared());
+        assert_eq!(copied.data(), b"world");
+    }
+
+    {
+        let mut m = p.alloc().unwrap();
+        let headroom = m.headroom() as usize;
+        let tailroom = m.tailroom() as usize;
+
+        assert_eq!(headroom, ffi::RTE_PKTMBUF_HEADROOM as usize);
+        assert!(m.prepend(headroom + 1).is_err());
+        assert!(m.append(tailroom + 1).is_err());
+
+        m.append(4).unwrap().copy_from_slice(b"data");
+        m.prepend(2).unwrap().copy_from_slice(b"hd");
+
+        assert_eq!(m.data(), b"hddata");
+        assert_eq!(m.headroom() as usize, headroom - 2);
+        assert_eq!(m.tailroom() as usize, tailroom - 4);
+
+        assert!(m.adj(7).is_err());
+        assert_eq!(m.adj(2).unwrap(), b"data");
+        assert!(m.trim(5).is_err());
+        assert_eq!(m.trim(2).unwrap(), b"da");
+        assert_eq!(m.pkt_len(), 2);
+
+        m.set_l2_len(14)
+            .set_l3_len(20)
+            .set_l4_len(20)
+            .set_tso_segsz(1460)
+            .add_offload(mbuf::OffloadFlags::PKT_TX_IPV4 | mbuf::OffloadFlags::PKT_TX_TCP_SEG);
+
+        assert_eq!(m.l2_len(), 14);
+        assert_eq!(m.l3_len(), 20);
+        assert_eq!(m.l4_len(), 20);
+        assert_eq!(m.tso_segsz(), 1460);
+        assert!(m.offload().contains(mbuf::OffloadFlags::PKT_TX_TCP_SEG));
+    }
+
+    assert_eq!(p.in_use_count(), 0);
+
     p.audit();
 }
+
+fn test_spinlock() {
+    let lock = SpinLock::new(0);
+
+    assert!(!lock.is_locked());
+
+    {
+        let mut data = lock.lock();
+
+        *data += 1;
+
+        assert!(lock.is_locked());
+        assert!(lock.try_lock().is_none());
+    }
+
+    assert!(!lock.is_locked());
+    assert_eq!(*lock.try_lock().unwrap(), 1);
+    assert_eq!(lock.into_inner(), 1);
+
+    let lock = RecursiveSpinLock::new(0);
+
+    {
+        let outer = lock.lock();
+        let inner = lock.try_lock().unwrap();
+
+        assert_eq!(*outer, *inner);
+    }
+
+    assert!(!lock.is_locked());
+}
+
+fn test_hash_map() {
+    let mut m = DpdkHashMap::<u32, String>::new("test_hash_map", 64, SOCKET_ID_ANY).unwrap();
+
+    assert!(m.is_empty());
+    assert_eq!(m.capacity(), 64);
+
+    assert_eq!(m.insert(1, "one".to_owned()).unwrap(), None);
+    assert_eq!(m.insert(2, "two".to_owned()).unwrap(), None);
+    assert_eq!(m.insert(1, "uno".to_owned()).unwrap(), Some("one".to_owned()));
+    assert_eq!(m.len(), 2);
+
+    assert_eq!(m.get(&1).map(|s| s.as_str()), Some("uno"));
+    assert!(m.contains_key(&2));
+    assert!(!m.contains_key(&3));
+
+    m.entry(3).or_insert_with(|| "three".to_owned()).unwrap();
+    m.entry(3).and_modify(|s| s.push('!')).or_default().unwrap();
+
+    match m.entry(3) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), "three!"),
+        Entry::Vacant(_) => unreachable!(),
+    }
+
+    let mut keys = m.keys().cloned().collect::<Vec<_>>();
+
+    keys.sort();
+
+    assert_eq!(keys, vec![1, 2]);
+    assert_eq!(m.remove(&2), Some("two".to_owned()));
+    assert_eq!(m.remove(&2), None);
+
+    m.clear();
+
+    assert!(m.is_empty());
+}
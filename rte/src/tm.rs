@@ -0,0 +1,537 @@
+//!
+//! RTE Traffic Management
+//!
+//! The ethdev traffic management API configures the egress scheduling hierarchy of the NICs,
+//! which shapes the traffic of the TX queues in hardware instead of scheduling them on the CPU.
+//!
+//! The leaf nodes of the hierarchy are the TX queues of the port, with their node IDs set to 0 .. (N-1),
+//! and the non-leaf nodes are created by the application with the IDs out of this range.
+//! The hierarchy must be committed before the port is started.
+//!
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_int;
+use std::ptr;
+
+use anyhow::Result;
+use num_traits::FromPrimitive;
+
+use ethdev::PortId;
+use ffi::{self, rte_tm_error_type::*};
+use red::COLORS;
+
+pub type RawTmError = ffi::rte_tm_error;
+pub type RawNodeParams = ffi::rte_tm_node_params;
+
+/// The statistics counters of a node.
+pub type NodeStats = ffi::rte_tm_node_stats;
+
+/// A token bucket, the rate is in bytes (packets) per second and the size is in bytes (packets).
+pub type TokenBucket = ffi::rte_tm_token_bucket;
+
+/// The RED parameters of a packet color.
+pub type RedParams = ffi::rte_tm_red_params;
+
+/// The ID of the parent of the root node.
+pub const NODE_ID_NULL: u32 = ffi::RTE_TM_NODE_ID_NULL;
+/// No private shaper of a node.
+pub const SHAPER_PROFILE_ID_NONE: u32 = ffi::RTE_TM_SHAPER_PROFILE_ID_NONE;
+/// No private WRED context of a leaf node.
+pub const WRED_PROFILE_ID_NONE: u32 = ffi::RTE_TM_WRED_PROFILE_ID_NONE;
+/// Let the driver pick the hierarchy level of a node.
+pub const NODE_LEVEL_ID_ANY: u32 = ffi::RTE_TM_NODE_LEVEL_ID_ANY;
+/// The Ethernet framing overhead, including the preamble and the inter-frame gap.
+pub const ETH_FRAMING_OVERHEAD: i32 = ffi::RTE_TM_ETH_FRAMING_OVERHEAD as i32;
+/// The Ethernet framing overhead, including the preamble, the inter-frame gap and the FCS.
+pub const ETH_FRAMING_OVERHEAD_FCS: i32 = ffi::RTE_TM_ETH_FRAMING_OVERHEAD_FCS as i32;
+
+/// Type of the object responsible for the traffic management error.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum TmErrorType {
+    /// No error.
+    None = RTE_TM_ERROR_TYPE_NONE,
+    /// Cause unspecified.
+    Unspecified = RTE_TM_ERROR_TYPE_UNSPECIFIED,
+    /// Capabilities.
+    Capabilities = RTE_TM_ERROR_TYPE_CAPABILITIES,
+    /// Hierarchy level ID.
+    LevelId = RTE_TM_ERROR_TYPE_LEVEL_ID,
+    /// WRED profile.
+    WredProfile = RTE_TM_ERROR_TYPE_WRED_PROFILE,
+    /// WRED parameters of the green packets.
+    WredProfileGreen = RTE_TM_ERROR_TYPE_WRED_PROFILE_GREEN,
+    /// WRED parameters of the yellow packets.
+    WredProfileYellow = RTE_TM_ERROR_TYPE_WRED_PROFILE_YELLOW,
+    /// WRED parameters of the red packets.
+    WredProfileRed = RTE_TM_ERROR_TYPE_WRED_PROFILE_RED,
+    /// WRED profile ID.
+    WredProfileId = RTE_TM_ERROR_TYPE_WRED_PROFILE_ID,
+    /// Shared WRED context ID.
+    SharedWredContextId = RTE_TM_ERROR_TYPE_SHARED_WRED_CONTEXT_ID,
+    /// Shaper profile.
+    ShaperProfile = RTE_TM_ERROR_TYPE_SHAPER_PROFILE,
+    /// Committed rate of the shaper profile.
+    ShaperProfileCommittedRate = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_COMMITTED_RATE,
+    /// Committed size of the shaper profile.
+    ShaperProfileCommittedSize = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_COMMITTED_SIZE,
+    /// Peak rate of the shaper profile.
+    ShaperProfilePeakRate = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PEAK_RATE,
+    /// Peak size of the shaper profile.
+    ShaperProfilePeakSize = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PEAK_SIZE,
+    /// Packet length adjustment of the shaper profile.
+    ShaperProfilePktAdjustLen = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PKT_ADJUST_LEN,
+    /// Packet mode of the shaper profile.
+    ShaperProfilePacketMode = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_PACKET_MODE,
+    /// Shaper profile ID.
+    ShaperProfileId = RTE_TM_ERROR_TYPE_SHAPER_PROFILE_ID,
+    /// Shared shaper ID.
+    SharedShaperId = RTE_TM_ERROR_TYPE_SHARED_SHAPER_ID,
+    /// Parent node ID.
+    NodeParentNodeId = RTE_TM_ERROR_TYPE_NODE_PARENT_NODE_ID,
+    /// Node priority.
+    NodePriority = RTE_TM_ERROR_TYPE_NODE_PRIORITY,
+    /// Node weight.
+    NodeWeight = RTE_TM_ERROR_TYPE_NODE_WEIGHT,
+    /// Node parameters.
+    NodeParams = RTE_TM_ERROR_TYPE_NODE_PARAMS,
+    /// Shaper profile ID of the node parameters.
+    NodeParamsShaperProfileId = RTE_TM_ERROR_TYPE_NODE_PARAMS_SHAPER_PROFILE_ID,
+    /// Shared shaper ID of the node parameters.
+    NodeParamsSharedShaperId = RTE_TM_ERROR_TYPE_NODE_PARAMS_SHARED_SHAPER_ID,
+    /// Number of the shared shapers of the node parameters.
+    NodeParamsNSharedShapers = RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SHARED_SHAPERS,
+    /// WFQ weight mode of the node parameters.
+    NodeParamsWfqWeightMode = RTE_TM_ERROR_TYPE_NODE_PARAMS_WFQ_WEIGHT_MODE,
+    /// Number of the SP priorities of the node parameters.
+    NodeParamsNSpPriorities = RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SP_PRIORITIES,
+    /// Congestion management mode of the node parameters.
+    NodeParamsCman = RTE_TM_ERROR_TYPE_NODE_PARAMS_CMAN,
+    /// WRED profile ID of the node parameters.
+    NodeParamsWredProfileId = RTE_TM_ERROR_TYPE_NODE_PARAMS_WRED_PROFILE_ID,
+    /// Shared WRED context ID of the node parameters.
+    NodeParamsSharedWredContextId = RTE_TM_ERROR_TYPE_NODE_PARAMS_SHARED_WRED_CONTEXT_ID,
+    /// Number of the shared WRED contexts of the node parameters.
+    NodeParamsNSharedWredContexts = RTE_TM_ERROR_TYPE_NODE_PARAMS_N_SHARED_WRED_CONTEXTS,
+    /// Statistics counters of the node parameters.
+    NodeParamsStats = RTE_TM_ERROR_TYPE_NODE_PARAMS_STATS,
+    /// Node ID.
+    NodeId = RTE_TM_ERROR_TYPE_NODE_ID,
+}
+
+/// The error reported by the traffic management API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TmError {
+    /// The negative errno value returned by the traffic management API.
+    pub errno: i32,
+    /// Type of the object responsible for the error.
+    pub kind: TmErrorType,
+    /// Human-readable error message.
+    pub message: Option<String>,
+}
+
+impl fmt::Display for TmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "traffic management error, {} ({:?}, {})",
+            self.message.as_ref().map_or("unknown cause", String::as_str),
+            self.kind,
+            self.errno
+        )
+    }
+}
+
+impl std::error::Error for TmError {}
+
+impl TmError {
+    fn from_raw(errno: c_int, err: &RawTmError) -> Self {
+        TmError {
+            errno,
+            kind: TmErrorType::from_u32(err.type_).unwrap_or(TmErrorType::Unspecified),
+            message: if err.message.is_null() {
+                None
+            } else {
+                Some(unsafe { CStr::from_ptr(err.message) }.to_string_lossy().into_owned())
+            },
+        }
+    }
+}
+
+/// Call the traffic management API with an error structure, and convert the failure to `TmError`.
+fn tm_call<F>(f: F) -> Result<()>
+where
+    F: FnOnce(*mut RawTmError) -> c_int,
+{
+    let mut err = RawTmError::default();
+    let ret = f(&mut err);
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(TmError::from_raw(ret, &err).into())
+    }
+}
+
+bitflags! {
+    /// The statistics counters of a node, the dropped and queued ones are only valid for the leaf nodes.
+    pub struct StatsMask: u64 {
+        const N_PKTS                 = ffi::rte_tm_stats_type::RTE_TM_STATS_N_PKTS as u64;
+        const N_BYTES                = ffi::rte_tm_stats_type::RTE_TM_STATS_N_BYTES as u64;
+        const N_PKTS_GREEN_DROPPED   = ffi::rte_tm_stats_type::RTE_TM_STATS_N_PKTS_GREEN_DROPPED as u64;
+        const N_PKTS_YELLOW_DROPPED  = ffi::rte_tm_stats_type::RTE_TM_STATS_N_PKTS_YELLOW_DROPPED as u64;
+        const N_PKTS_RED_DROPPED     = ffi::rte_tm_stats_type::RTE_TM_STATS_N_PKTS_RED_DROPPED as u64;
+        const N_BYTES_GREEN_DROPPED  = ffi::rte_tm_stats_type::RTE_TM_STATS_N_BYTES_GREEN_DROPPED as u64;
+        const N_BYTES_YELLOW_DROPPED = ffi::rte_tm_stats_type::RTE_TM_STATS_N_BYTES_YELLOW_DROPPED as u64;
+        const N_BYTES_RED_DROPPED    = ffi::rte_tm_stats_type::RTE_TM_STATS_N_BYTES_RED_DROPPED as u64;
+        const N_PKTS_QUEUED          = ffi::rte_tm_stats_type::RTE_TM_STATS_N_PKTS_QUEUED as u64;
+        const N_BYTES_QUEUED         = ffi::rte_tm_stats_type::RTE_TM_STATS_N_BYTES_QUEUED as u64;
+    }
+}
+
+/// The parameters of a shaper profile.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ShaperParams {
+    /// The committed token bucket.
+    pub committed: TokenBucket,
+    /// The peak token bucket, which is disabled if its rate is zero.
+    pub peak: TokenBucket,
+    /// The bytes added to the length of each packet, e.g. `ETH_FRAMING_OVERHEAD_FCS`, ignored in the packet mode.
+    pub pkt_length_adjust: i32,
+    /// The token buckets are in packets instead of bytes.
+    pub packet_mode: bool,
+}
+
+impl ShaperParams {
+    /// The single rate shaper in bytes, which counts the Ethernet framing overhead.
+    pub fn new(rate: u64, size: u64) -> Self {
+        ShaperParams {
+            committed: TokenBucket { rate, size },
+            pkt_length_adjust: ETH_FRAMING_OVERHEAD_FCS,
+            ..Default::default()
+        }
+    }
+
+    fn to_raw(self) -> ffi::rte_tm_shaper_params {
+        ffi::rte_tm_shaper_params {
+            committed: self.committed,
+            peak: self.peak,
+            pkt_length_adjust: self.pkt_length_adjust,
+            packet_mode: self.packet_mode as c_int,
+        }
+    }
+}
+
+/// The parameters of a WRED profile.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WredParams {
+    /// The RED parameters of each packet color.
+    pub red_params: [RedParams; COLORS],
+    /// The thresholds are in packets instead of bytes.
+    pub packet_mode: bool,
+}
+
+impl WredParams {
+    fn to_raw(self) -> ffi::rte_tm_wred_params {
+        ffi::rte_tm_wred_params {
+            red_params: self.red_params,
+            packet_mode: self.packet_mode as c_int,
+        }
+    }
+}
+
+/// The congestion management mode of a leaf node.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CmanMode {
+    /// Drop the incoming packets when the queue is full.
+    TailDrop = ffi::rte_tm_cman_mode::RTE_TM_CMAN_TAIL_DROP,
+    /// Drop the packets at the head of the queue when the queue is full.
+    HeadDrop = ffi::rte_tm_cman_mode::RTE_TM_CMAN_HEAD_DROP,
+    /// Weighted Random Early Detection.
+    Wred = ffi::rte_tm_cman_mode::RTE_TM_CMAN_WRED,
+}
+
+/// The parameters only valid for the leaf or non-leaf nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeKind {
+    /// A non-leaf node, which schedules its children with the SP priorities and the WFQ weights.
+    NonLeaf {
+        /// The number of the SP priorities of the children.
+        n_sp_priorities: u32,
+        /// The WFQ of each SP priority is in bytes if true and in packets if false,
+        /// the default mode of the driver is used if empty.
+        wfq_byte_mode: Vec<bool>,
+    },
+    /// A leaf node, which is a TX queue of the port.
+    Leaf {
+        /// The congestion management mode.
+        cman: CmanMode,
+        /// The WRED profile of the private WRED context, only valid in the WRED mode.
+        wred_profile_id: u32,
+        /// The shared WRED contexts, only valid in the WRED mode.
+        shared_wred_context_ids: Vec<u32>,
+    },
+}
+
+/// The parameters of a node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeParams {
+    /// The shaper profile of the private shaper, or `SHAPER_PROFILE_ID_NONE`.
+    pub shaper_profile_id: u32,
+    /// The shared shapers of the node.
+    pub shared_shaper_ids: Vec<u32>,
+    /// The parameters of the leaf or non-leaf node.
+    pub kind: NodeKind,
+    /// The statistics counters to be enabled.
+    pub stats_mask: StatsMask,
+}
+
+impl NodeParams {
+    /// The non-leaf node shaped by the shaper profile, whose children have the same SP priority.
+    pub fn nonleaf(shaper_profile_id: u32) -> Self {
+        NodeParams {
+            shaper_profile_id,
+            shared_shaper_ids: vec![],
+            kind: NodeKind::NonLeaf {
+                n_sp_priorities: 1,
+                wfq_byte_mode: vec![],
+            },
+            stats_mask: StatsMask::empty(),
+        }
+    }
+
+    /// The leaf node without shaper, which drops the packets at the tail of the queue.
+    pub fn leaf() -> Self {
+        NodeParams {
+            shaper_profile_id: SHAPER_PROFILE_ID_NONE,
+            shared_shaper_ids: vec![],
+            kind: NodeKind::Leaf {
+                cman: CmanMode::TailDrop,
+                wred_profile_id: WRED_PROFILE_ID_NONE,
+                shared_wred_context_ids: vec![],
+            },
+            stats_mask: StatsMask::empty(),
+        }
+    }
+
+    fn to_raw(&self, wfq_weight_mode: &mut Vec<c_int>) -> RawNodeParams {
+        let mut raw = RawNodeParams::default();
+
+        raw.shaper_profile_id = self.shaper_profile_id;
+        raw.shared_shaper_id = ids_ptr(&self.shared_shaper_ids);
+        raw.n_shared_shapers = self.shared_shaper_ids.len() as u32;
+        raw.stats_mask = self.stats_mask.bits;
+
+        match self.kind {
+            NodeKind::NonLeaf {
+                n_sp_priorities,
+                ref wfq_byte_mode,
+            } => {
+                wfq_weight_mode.extend(wfq_byte_mode.iter().map(|&byte_mode| byte_mode as c_int));
+
+                raw.__bindgen_anon_1.nonleaf = ffi::rte_tm_node_params__bindgen_ty_1__bindgen_ty_1 {
+                    wfq_weight_mode: if wfq_weight_mode.is_empty() {
+                        ptr::null_mut()
+                    } else {
+                        wfq_weight_mode.as_mut_ptr()
+                    },
+                    n_sp_priorities,
+                };
+            }
+            NodeKind::Leaf {
+                cman,
+                wred_profile_id,
+                ref shared_wred_context_ids,
+            } => {
+                raw.__bindgen_anon_1.leaf = ffi::rte_tm_node_params__bindgen_ty_1__bindgen_ty_2 {
+                    cman: cman as ffi::rte_tm_cman_mode::Type,
+                    wred: ffi::rte_tm_node_params__bindgen_ty_1__bindgen_ty_2__bindgen_ty_1 {
+                        wred_profile_id,
+                        shared_wred_context_id: ids_ptr(shared_wred_context_ids),
+                        n_shared_wred_contexts: shared_wred_context_ids.len() as u32,
+                    },
+                };
+            }
+        }
+
+        raw
+    }
+}
+
+fn ids_ptr(ids: &[u32]) -> *mut u32 {
+    if ids.is_empty() {
+        ptr::null_mut()
+    } else {
+        ids.as_ptr() as *mut _
+    }
+}
+
+/// Get the number of the leaf nodes, which is the number of the TX queues of the port.
+pub fn number_of_leaf_nodes(port_id: PortId) -> Result<u32> {
+    let mut n = 0;
+
+    tm_call(|err| unsafe { ffi::rte_tm_get_number_of_leaf_nodes(port_id.0, &mut n, err) }).map(|_| n)
+}
+
+/// Check whether the node is a leaf node.
+pub fn node_is_leaf(port_id: PortId, node_id: u32) -> Result<bool> {
+    let mut is_leaf = 0;
+
+    tm_call(|err| unsafe { ffi::rte_tm_node_type_get(port_id.0, node_id, &mut is_leaf, err) }).map(|_| is_leaf != 0)
+}
+
+/// Add a WRED profile to the port, which can be used by the WRED contexts.
+pub fn wred_profile_add(port_id: PortId, wred_profile_id: u32, params: &WredParams) -> Result<()> {
+    let mut raw = params.to_raw();
+
+    tm_call(|err| unsafe { ffi::rte_tm_wred_profile_add(port_id.0, wred_profile_id, &mut raw, err) })
+}
+
+/// Delete the WRED profile, which must not be used by any WRED context.
+pub fn wred_profile_delete(port_id: PortId, wred_profile_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_wred_profile_delete(port_id.0, wred_profile_id, err) })
+}
+
+/// Add a shaper profile to the port, which can be used by the private and shared shapers.
+pub fn shaper_profile_add(port_id: PortId, shaper_profile_id: u32, params: &ShaperParams) -> Result<()> {
+    let mut raw = params.to_raw();
+
+    tm_call(|err| unsafe { ffi::rte_tm_shaper_profile_add(port_id.0, shaper_profile_id, &mut raw, err) })
+}
+
+/// Delete the shaper profile, which must not be used by any shaper.
+pub fn shaper_profile_delete(port_id: PortId, shaper_profile_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_shaper_profile_delete(port_id.0, shaper_profile_id, err) })
+}
+
+/// Create a shared shaper with the shaper profile, or change the shaper profile of the existing one.
+pub fn shared_shaper_add_update(port_id: PortId, shared_shaper_id: u32, shaper_profile_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_shared_shaper_add_update(port_id.0, shared_shaper_id, shaper_profile_id, err) })
+}
+
+/// Delete the shared shaper, which must not be used by any node.
+pub fn shared_shaper_delete(port_id: PortId, shared_shaper_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_shared_shaper_delete(port_id.0, shared_shaper_id, err) })
+}
+
+/// Add a node as the child of the parent node, which is scheduled with the SP `priority` and the WFQ `weight`.
+///
+/// The first node is the root node, whose parent is `NODE_ID_NULL`,
+/// and the leaf nodes are configured rather than created.
+pub fn node_add(
+    port_id: PortId,
+    node_id: u32,
+    parent_node_id: u32,
+    priority: u32,
+    weight: u32,
+    level_id: u32,
+    params: &NodeParams,
+) -> Result<()> {
+    let mut wfq_weight_mode = vec![];
+    let mut raw = params.to_raw(&mut wfq_weight_mode);
+
+    tm_call(|err| unsafe {
+        ffi::rte_tm_node_add(
+            port_id.0,
+            node_id,
+            parent_node_id,
+            priority,
+            weight,
+            level_id,
+            &mut raw,
+            err,
+        )
+    })
+}
+
+/// Delete the node, which must not have any child.
+pub fn node_delete(port_id: PortId, node_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_node_delete(port_id.0, node_id, err) })
+}
+
+/// Suspend the node, no packet is scheduled from the node and its descendants until it is resumed.
+pub fn node_suspend(port_id: PortId, node_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_node_suspend(port_id.0, node_id, err) })
+}
+
+/// Resume the suspended node.
+pub fn node_resume(port_id: PortId, node_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_node_resume(port_id.0, node_id, err) })
+}
+
+/// Move the node and its descendants to the parent node, or change its SP priority and WFQ weight.
+pub fn node_parent_update(
+    port_id: PortId,
+    node_id: u32,
+    parent_node_id: u32,
+    priority: u32,
+    weight: u32,
+) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_node_parent_update(port_id.0, node_id, parent_node_id, priority, weight, err) })
+}
+
+/// Change the shaper profile of the private shaper of the node.
+pub fn node_shaper_update(port_id: PortId, node_id: u32, shaper_profile_id: u32) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_node_shaper_update(port_id.0, node_id, shaper_profile_id, err) })
+}
+
+/// Read the statistics counters of the node and the mask of the valid ones.
+///
+/// The counters are reset after they are retrieved if `clear` is true.
+pub fn node_stats_read(port_id: PortId, node_id: u32, clear: bool) -> Result<(NodeStats, StatsMask)> {
+    let mut stats = NodeStats::default();
+    let mut mask = 0;
+
+    tm_call(|err| unsafe {
+        ffi::rte_tm_node_stats_read(port_id.0, node_id, &mut stats, &mut mask, clear as c_int, err)
+    })
+    .map(|_| (stats, StatsMask::from_bits_truncate(mask)))
+}
+
+/// Validate the hierarchy and install it to the port, which is called before the port is started.
+///
+/// The hierarchy is cleared on failure if `clear_on_fail` is true, otherwise it can be modified and committed again.
+pub fn hierarchy_commit(port_id: PortId, clear_on_fail: bool) -> Result<()> {
+    tm_call(|err| unsafe { ffi::rte_tm_hierarchy_commit(port_id.0, clear_on_fail as c_int, err) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_params() {
+        let mut wfq_weight_mode = vec![];
+        let params = NodeParams {
+            kind: NodeKind::NonLeaf {
+                n_sp_priorities: 2,
+                wfq_byte_mode: vec![true, false],
+            },
+            ..NodeParams::nonleaf(1)
+        };
+        let raw = params.to_raw(&mut wfq_weight_mode);
+
+        assert_eq!(raw.shaper_profile_id, 1);
+        assert!(raw.shared_shaper_id.is_null());
+        assert_eq!(unsafe { raw.__bindgen_anon_1.nonleaf.n_sp_priorities }, 2);
+        assert_eq!(wfq_weight_mode, vec![1, 0]);
+
+        let mut wfq_weight_mode = vec![];
+        let params = NodeParams {
+            shared_shaper_ids: vec![3],
+            stats_mask: StatsMask::N_PKTS | StatsMask::N_PKTS_QUEUED,
+            ..NodeParams::leaf()
+        };
+        let raw = params.to_raw(&mut wfq_weight_mode);
+        let leaf = unsafe { raw.__bindgen_anon_1.leaf };
+
+        assert_eq!(raw.shaper_profile_id, SHAPER_PROFILE_ID_NONE);
+        assert_eq!(raw.n_shared_shapers, 1);
+        assert_eq!(raw.stats_mask, 0x101);
+        assert_eq!(leaf.cman, ffi::rte_tm_cman_mode::RTE_TM_CMAN_TAIL_DROP);
+        assert_eq!(leaf.wred.wred_profile_id, WRED_PROFILE_ID_NONE);
+        assert!(leaf.wred.shared_wred_context_id.is_null());
+        assert!(wfq_weight_mode.is_empty());
+    }
+}
@@ -1,8 +1,18 @@
+use std::any::Any;
 use std::borrow::Borrow;
+use std::cell::Cell;
 use std::ffi::CString;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
 
+/// A marker making the type `!Send`, for the objects which must stay on the lcore which created them.
+pub type NotSend = PhantomData<*mut ()>;
+
+/// A marker making the type `!Sync`, for the objects which can't be used by several lcores at the same time.
+pub type NotSync = PhantomData<Cell<()>>;
+
 pub trait Raw<T>: Deref<Target = T> + DerefMut + AsRaw<Raw = T> + IntoRaw + FromRaw + From<*mut T> {}
 
 pub trait AsRaw {
@@ -37,6 +47,18 @@ impl<T: AsRaw> AsRaw for Option<T> {
     }
 }
 
+impl<T> AsRaw for *mut T {
+    type Raw = T;
+
+    fn as_raw(&self) -> *const Self::Raw {
+        *self
+    }
+
+    fn as_raw_mut(&self) -> *mut Self::Raw {
+        *self
+    }
+}
+
 pub trait IntoRaw: AsRaw {
     fn into_raw(self) -> *mut Self::Raw;
 }
@@ -84,7 +106,11 @@ macro_rules! raw {
 
         impl $crate::utils::IntoRaw for $wrapper {
             fn into_raw(self) -> *mut Self::Raw {
-                self.0.as_ptr()
+                let p = self.0.as_ptr();
+
+                ::std::mem::forget(self);
+
+                p
             }
         }
 
@@ -137,3 +163,31 @@ impl<F, T> CallbackContext<F, T> {
         unsafe { Box::from_raw(raw as *mut _) }
     }
 }
+
+/// Call the closure in a callback invoked by DPDK, the panic must not unwind into the C frames.
+///
+/// The panic is logged, and `default` is returned to DPDK instead.
+pub fn catch_panic<F, R>(name: &str, default: R, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(ret) => ret,
+        Err(err) => {
+            error!("{} panicked, {}", name, panic_message(&*err));
+
+            default
+        }
+    }
+}
+
+/// The message of a panic payload.
+pub fn panic_message(err: &(dyn Any + Send)) -> String {
+    if let Some(msg) = err.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = err.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}